@@ -15,13 +15,21 @@
 //! cargo bench --all-features -- --html target/criterion
 //! ```
 
+#![allow(
+    clippy::needless_borrow,
+    clippy::needless_borrows_for_generic_args,
+    clippy::redundant_closure
+)]
+
 use std::hint::black_box;
 
 use criterion::{
     criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
     SamplingMode, Throughput,
 };
-use japanese_codepoints::{contains_all_in_any, CodePoints};
+use japanese_codepoints::const_codepoints::{ConstCodePoints, RangeSet};
+use japanese_codepoints::data::{jisx0208, jisx0213kanji};
+use japanese_codepoints::{contains_all_in_any, Backend, CodePoints};
 
 // ============================================================================
 // Core Operations Group
@@ -111,6 +119,22 @@ fn group_exclusion_queries(c: &mut Criterion) {
         b.iter(|| cp.all_excluded(black_box("あえいおうか")))
     });
 
+    // iter_excluded_chars (lazy, non-allocating) vs all_excluded
+    let many_invalid = "アイウエオカキクケコあいう";
+    group.bench_function("all_excluded/many", |b| {
+        b.iter(|| cp.all_excluded(black_box(many_invalid)))
+    });
+    group.bench_function("iter_excluded_chars/many", |b| {
+        b.iter(|| cp.iter_excluded_chars(black_box(many_invalid)).count())
+    });
+    group.bench_function("iter_excluded_chars/take_3", |b| {
+        b.iter(|| {
+            cp.iter_excluded_chars(black_box(many_invalid))
+                .take(3)
+                .count()
+        })
+    });
+
     group.finish();
 }
 
@@ -144,6 +168,120 @@ fn group_construction(c: &mut Criterion) {
     group.finish();
 }
 
+// ============================================================================
+// Backends Group
+// ============================================================================
+
+/// Compares the storage backends on `contains`, `first_excluded` and `union`
+/// over sets of very different size and density.
+fn group_backends(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backends");
+
+    let kana: Vec<u32> = jisx0208::HIRAGANA
+        .iter()
+        .chain(jisx0208::KATAKANA)
+        .copied()
+        .collect();
+    let sets: [(&str, Vec<u32>, String); 3] = [
+        (
+            "ascii",
+            (0x20..=0x7E).collect(),
+            "The quick brown fox jumps over the lazy dog. ".repeat(20),
+        ),
+        ("kana", kana, "ひらがなとカタカナのテキストです".repeat(20)),
+        (
+            "jisx0213_kanji",
+            jisx0213kanji::JISX0213_KANJI.to_vec(),
+            "漢字仮名交字表記日本語文章𠀋".repeat(20),
+        ),
+    ];
+    let other = CodePoints::new((0x3041..=0x30FF).chain(0x4E00..=0x4FFF).collect());
+
+    for (name, members, text) in &sets {
+        let excluded_last = format!("{text}\u{FFFD}");
+        for backend in [
+            Backend::HashSet,
+            Backend::BTreeSet,
+            Backend::SortedVec,
+            Backend::Bitmap,
+        ] {
+            let cp = CodePoints::with_backend(members.clone(), backend);
+            let id = format!("{name}/{backend:?}");
+            group.throughput(Throughput::Bytes(text.len() as u64));
+            group.bench_function(format!("contains/{id}"), |b| {
+                b.iter(|| cp.contains(black_box(text)))
+            });
+            group.bench_function(format!("first_excluded/{id}"), |b| {
+                b.iter(|| cp.first_excluded(black_box(&excluded_last)))
+            });
+            group.throughput(Throughput::Elements(members.len() as u64));
+            group.bench_function(format!("union/{id}"), |b| {
+                b.iter(|| cp.union(black_box(&other)))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+// ============================================================================
+// Range Sets Group
+// ============================================================================
+
+/// Compares an inline `RangeSet` with the `HashSet` and `ConstCodePoints`
+/// paths on keystroke-sized input: one to three characters per check, as an
+/// IME filter sees while the user types.
+fn group_range_sets(c: &mut Criterion) {
+    const KANA_RANGES: RangeSet<2> = RangeSet::new([(0x3041, 0x3093), (0x30A1, 0x30F6)]);
+    const KANA_TABLE: [u32; 169] = concat_codepoints(jisx0208::HIRAGANA, jisx0208::KATAKANA);
+    const KANA_CONST: ConstCodePoints = ConstCodePoints::new(&KANA_TABLE);
+
+    let mut group = c.benchmark_group("range_sets");
+    let hash_set = CodePoints::from(KANA_RANGES);
+    let keystrokes = [
+        "か", "きゃ", "ン", "a", "ー", "ティ", "ぁ", "漢", "ゔ", "ヴァ",
+    ];
+    group.throughput(Throughput::Elements(keystrokes.len() as u64));
+
+    group.bench_function("keystrokes/RangeSet", |b| {
+        b.iter(|| {
+            black_box(keystrokes)
+                .iter()
+                .filter(|k| KANA_RANGES.contains_str(k))
+                .count()
+        })
+    });
+    group.bench_function("keystrokes/ConstCodePoints", |b| {
+        b.iter(|| {
+            black_box(keystrokes)
+                .iter()
+                .filter(|k| KANA_CONST.contains_str(k))
+                .count()
+        })
+    });
+    group.bench_function("keystrokes/HashSet", |b| {
+        b.iter(|| {
+            black_box(keystrokes)
+                .iter()
+                .filter(|k| hash_set.contains(k))
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
+/// Concatenates two tables of total length `N` at compile time.
+const fn concat_codepoints<const N: usize>(a: &[u32], b: &[u32]) -> [u32; N] {
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = if i < a.len() { a[i] } else { b[i - a.len()] };
+        i += 1;
+    }
+    out
+}
+
 // ============================================================================
 // ASCII Group
 // ============================================================================
@@ -487,6 +625,20 @@ fn group_large_text(c: &mut Criterion) {
 
     let ascii = CodePoints::ascii_printable_cached();
 
+    // Early exit: a 1 MB string whose second character is invalid should cost
+    // about as much as a two-character one.
+    let early_invalid = format!("Hあ{}", "a".repeat(1_000_000));
+    group.bench_function("ascii/early_exit_1m", |b| {
+        b.iter(|| ascii.contains(black_box(&early_invalid)))
+    });
+    group.bench_function("ascii/validate_early_exit_1m", |b| {
+        b.iter(|| ascii.validate(black_box(&early_invalid)))
+    });
+    let long_valid = "a".repeat(1_000_000);
+    group.bench_function("ascii/contains_within_1m_limit_1k", |b| {
+        b.iter(|| ascii.contains_within(black_box(&long_valid), 1_000))
+    });
+
     // Different text sizes
     let sizes = [
         ("100", 100),
@@ -575,6 +727,61 @@ fn group_real_world(c: &mut Criterion) {
     group.finish();
 }
 
+// ============================================================================
+// Memoization
+// ============================================================================
+
+/// Compares `CachedValidator` with plain `contains` on a Zipf-distributed
+/// workload (a few strings dominate, as with prefecture names) and on a
+/// workload where every key is new, which is the worst case for the cache.
+fn group_memoization(c: &mut Criterion) {
+    use japanese_codepoints::validator::CachedValidator;
+
+    let mut group = c.benchmark_group("memoization");
+    let ascii = CodePoints::ascii_printable_cached();
+
+    // 1 000 distinct 48-character strings, sampled 10 000 times.
+    let vocabulary: Vec<String> = (0..1000)
+        .map(|i| format!("{:04}-{}", i, "ward-block-building-room-".repeat(2)))
+        .collect();
+    let zipf = zipf_workload(&vocabulary, 10_000);
+    let unique: Vec<String> = (0..10_000).map(|i| format!("{:048}", i)).collect();
+
+    group.throughput(Throughput::Elements(zipf.len() as u64));
+    group.bench_function("zipf/uncached", |b| {
+        b.iter(|| zipf.iter().filter(|s| ascii.contains(black_box(s))).count())
+    });
+    let cached = CachedValidator::new(ascii, 256);
+    group.bench_function("zipf/cached_256", |b| {
+        b.iter(|| {
+            zipf.iter()
+                .filter(|s| cached.contains(black_box(s)))
+                .count()
+        })
+    });
+
+    group.throughput(Throughput::Elements(unique.len() as u64));
+    group.bench_function("unique/uncached", |b| {
+        b.iter(|| {
+            unique
+                .iter()
+                .filter(|s| ascii.contains(black_box(s)))
+                .count()
+        })
+    });
+    let cached = CachedValidator::new(ascii, 256);
+    group.bench_function("unique/cached_256", |b| {
+        b.iter(|| {
+            unique
+                .iter()
+                .filter(|s| cached.contains(black_box(s)))
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -587,6 +794,32 @@ where
     group.bench_function(name, move |b| b.iter(&mut f));
 }
 
+/// Draws `n` items from `vocabulary` with Zipf(s = 1) weights using a fixed
+/// LCG seed, so every run sees the same sequence.
+fn zipf_workload(vocabulary: &[String], n: usize) -> Vec<&str> {
+    let cumulative: Vec<f64> = vocabulary
+        .iter()
+        .enumerate()
+        .scan(0.0, |sum, (rank, _)| {
+            *sum += 1.0 / (rank + 1) as f64;
+            Some(*sum)
+        })
+        .collect();
+    let total = cumulative[cumulative.len() - 1];
+
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    (0..n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let x = (state >> 11) as f64 / (1u64 << 53) as f64 * total;
+            let i = cumulative.partition_point(|&c| c < x);
+            vocabulary[i.min(vocabulary.len() - 1)].as_str()
+        })
+        .collect()
+}
+
 // ============================================================================
 // Criterion Groups
 // ============================================================================
@@ -597,7 +830,8 @@ criterion_group!(
         .sample_size(200)
         .warm_up_time(std::time::Duration::from_secs(1))
         .measurement_time(std::time::Duration::from_secs(3));
-    targets = group_core_ops, group_set_operations, group_exclusion_queries, group_construction
+    targets = group_core_ops, group_set_operations, group_exclusion_queries, group_construction,
+        group_backends, group_range_sets
 );
 
 criterion_group!(
@@ -629,7 +863,7 @@ criterion_group!(
     config = Criterion::default()
         .sample_size(200)
         .measurement_time(std::time::Duration::from_secs(3));
-    targets = group_real_world
+    targets = group_real_world, group_memoization
 );
 
 criterion_main!(core_ops, character_sets, japanese_sets, scaling, scenarios,);
@@ -0,0 +1,112 @@
+//! Checks text files against a registered character set.
+//!
+//! Prints `file: ok` or one `file:line:col: ...` line per disallowed
+//! character (`--format text`, the default); with `--format json` each file
+//! is one JSON object (NDJSON) following the schema documented in
+//! `japanese_codepoints::validation`.  Any other format is an error.
+//!
+//! With `--deny-invisibles`, a file containing a bidi control, zero-width
+//! or interlinear annotation character (`presets::dangerous_invisibles`)
+//! fails before the set is checked, with one `file: ...` line (or a JSON
+//! object with a `denied` error) naming the first such character.
+//!
+//! Usage: `check_files [--format text|json] [--set NAME] [--deny-invisibles] FILE...`
+//!
+//! Run: `cargo run --example check_files --features serde,codepoints-jisx0208 -- --format json --set ascii_printable README.md`
+
+use std::process::ExitCode;
+
+use japanese_codepoints::presets;
+use japanese_codepoints::registry;
+use japanese_codepoints::validation::validate_multiline;
+use japanese_codepoints::validator::Validator;
+
+fn main() -> ExitCode {
+    let mut json = false;
+    let mut deny_invisibles = false;
+    let mut set_name = String::from("ascii_printable");
+    let mut files = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next().as_deref() {
+                Some("json") => json = true,
+                Some("text") => json = false,
+                Some(other) => {
+                    eprintln!("unknown format {:?}; expected \"text\" or \"json\"", other);
+                    return ExitCode::FAILURE;
+                }
+                None => {
+                    eprintln!("--format needs a value: \"text\" or \"json\"");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "--set" => set_name = args.next().unwrap_or_default(),
+            "--deny-invisibles" => deny_invisibles = true,
+            _ => files.push(arg),
+        }
+    }
+    let Some(set) = registry::get(&set_name) else {
+        eprintln!(
+            "unknown set {:?}; available: {:?}",
+            set_name,
+            registry::names().collect::<Vec<_>>()
+        );
+        return ExitCode::FAILURE;
+    };
+    let deny = deny_invisibles.then(|| Validator::new().deny_set(presets::dangerous_invisibles()));
+
+    let mut all_valid = true;
+    for file in &files {
+        let text = match std::fs::read_to_string(file) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("{}: {}", file, err);
+                all_valid = false;
+                continue;
+            }
+        };
+        if let Some(Err(err)) = deny.as_ref().map(|deny| deny.validate(&text)) {
+            all_valid = false;
+            if json {
+                let line = serde_json::json!({
+                    "file": file,
+                    "set": set_name,
+                    "valid": false,
+                    "denied": err,
+                });
+                println!("{}", line);
+            } else {
+                println!("{}: {}", file, err);
+            }
+            continue;
+        }
+        if json {
+            let result = set.check(&text);
+            all_valid &= result.is_ok();
+            let line = serde_json::json!({
+                "file": file,
+                "set": set_name,
+                "valid": result.is_ok(),
+                "report": result.err(),
+            });
+            println!("{}", line);
+        } else {
+            match validate_multiline(&text, set) {
+                Ok(()) => println!("{}: ok", file),
+                Err(errors) => {
+                    all_valid = false;
+                    for err in errors {
+                        println!("{}:{}  {}", file, err, err.excerpt(&text, 60));
+                    }
+                }
+            }
+        }
+    }
+
+    if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,259 @@
+//! Asynchronous validation and filtering of UTF-8 streams (`tokio` feature).
+//!
+//! Input is read in 4 KB chunks.  A multi-byte character split across two
+//! chunks is carried over and decoded once the rest of it arrives, so chunk
+//! boundaries never affect the result: both methods behave exactly like their
+//! in-memory counterparts applied to the whole stream.
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::validation::ValidationError;
+use crate::CodePoints;
+
+const CHUNK_SIZE: usize = 4096;
+
+/// Returns the longest valid UTF-8 prefix of `bytes` and whether it is
+/// followed by an invalid sequence (as opposed to an incomplete one).
+fn valid_prefix(bytes: &[u8]) -> (&str, bool) {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => (s, false),
+        Err(e) => {
+            let (valid, _) = bytes.split_at(e.valid_up_to());
+            let valid = std::str::from_utf8(valid).unwrap_or_default();
+            (valid, e.error_len().is_some())
+        }
+    }
+}
+
+fn stream_error(char_offset: usize, byte_offset: usize, message: String) -> ValidationError {
+    ValidationError::with_message(0xFFFD, char_offset, message).with_byte_index(byte_offset)
+}
+
+impl CodePoints {
+    /// Asynchronously validates UTF-8 text read from `reader`.
+    ///
+    /// The result is identical to calling [`validate`](Self::validate) on the
+    /// whole input: on failure the error identifies the first offending
+    /// character and its zero-based *character* index in the stream.
+    ///
+    /// I/O errors and malformed UTF-8 are also reported as a
+    /// [`ValidationError`], with `code_point` set to U+FFFD, `position` set to
+    /// the number of characters decoded so far, and the byte offset in
+    /// `byte_index` and in the message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let cp = CodePoints::ascii_printable();
+    /// assert!(cp.validate_async_reader(&b"hello"[..]).await.is_ok());
+    ///
+    /// let err = cp.validate_async_reader(&b"hello\0"[..]).await.unwrap_err();
+    /// assert_eq!(err.position, 5);
+    /// # });
+    /// ```
+    pub async fn validate_async_reader(
+        &self,
+        mut reader: impl AsyncRead + Unpin,
+    ) -> Result<(), ValidationError> {
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut pending = Vec::with_capacity(CHUNK_SIZE + 3);
+        let mut byte_offset = 0;
+        let mut char_offset = 0;
+
+        loop {
+            let n = reader.read(&mut chunk).await.map_err(|e| {
+                stream_error(
+                    char_offset,
+                    byte_offset,
+                    format!("I/O error at byte offset {}: {}", byte_offset, e),
+                )
+            })?;
+            if n == 0 {
+                if !pending.is_empty() {
+                    return Err(stream_error(
+                        char_offset,
+                        byte_offset,
+                        format!("incomplete UTF-8 sequence at byte offset {}", byte_offset),
+                    ));
+                }
+                return Ok(());
+            }
+            pending.extend(chunk.iter().take(n));
+
+            let (valid, invalid) = valid_prefix(&pending);
+            let len = valid.len();
+            for (byte, c) in valid.char_indices() {
+                if !self.contains_char(c) {
+                    return Err(ValidationError::new(c as u32, char_offset)
+                        .with_byte_index(byte_offset + byte));
+                }
+                char_offset += 1;
+            }
+            byte_offset += len;
+            if invalid {
+                return Err(stream_error(
+                    char_offset,
+                    byte_offset,
+                    format!("invalid UTF-8 sequence at byte offset {}", byte_offset),
+                ));
+            }
+            pending.drain(..len);
+        }
+    }
+
+    /// Asynchronously copies the characters of `reader` that belong to this
+    /// set to `writer`, dropping all others.
+    ///
+    /// The output is identical to collecting
+    /// `text.chars().filter(|&c| cp.contains_char(c))` over the whole input.
+    /// `writer` is flushed before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from `reader` or `writer`, or an error of kind
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) if the input is not
+    /// valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let cp = CodePoints::ascii_printable();
+    /// let mut out = Vec::new();
+    /// cp.filter_async_reader("aあb".as_bytes(), &mut out).await.unwrap();
+    /// assert_eq!(out, b"ab");
+    /// # });
+    /// ```
+    pub async fn filter_async_reader(
+        &self,
+        mut reader: impl AsyncRead + Unpin,
+        mut writer: impl AsyncWrite + Unpin,
+    ) -> tokio::io::Result<()> {
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut pending = Vec::with_capacity(CHUNK_SIZE + 3);
+        let mut out = String::with_capacity(CHUNK_SIZE);
+        let mut byte_offset = 0;
+
+        loop {
+            let n = reader.read(&mut chunk).await?;
+            if n == 0 {
+                if !pending.is_empty() {
+                    return Err(tokio::io::Error::new(
+                        tokio::io::ErrorKind::InvalidData,
+                        format!("incomplete UTF-8 sequence at byte offset {}", byte_offset),
+                    ));
+                }
+                return writer.flush().await;
+            }
+            pending.extend(chunk.iter().take(n));
+
+            let (valid, invalid) = valid_prefix(&pending);
+            let len = valid.len();
+            out.clear();
+            out.extend(valid.chars().filter(|&c| self.contains_char(c)));
+            writer.write_all(out.as_bytes()).await?;
+            byte_offset += len;
+            if invalid {
+                return Err(tokio::io::Error::new(
+                    tokio::io::ErrorKind::InvalidData,
+                    format!("invalid UTF-8 sequence at byte offset {}", byte_offset),
+                ));
+            }
+            pending.drain(..len);
+        }
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use tokio::io::BufReader;
+
+    fn reader(bytes: &[u8]) -> BufReader<Cursor<&[u8]>> {
+        BufReader::new(Cursor::new(bytes))
+    }
+
+    /// Long enough to span several chunks; 3-byte characters guarantee that
+    /// some of them straddle a 4096-byte boundary.
+    fn long_text() -> String {
+        "あいうえおabc".repeat(1000)
+    }
+
+    #[tokio::test]
+    async fn test_validate_matches_sync() {
+        let cp = CodePoints::from_string("あいうえおabc");
+        let text = long_text();
+        assert!(cp.validate(&text).is_ok());
+        assert!(cp
+            .validate_async_reader(reader(text.as_bytes()))
+            .await
+            .is_ok());
+
+        let bad = format!("{}か{}", text, text);
+        let expected = cp.validate(&bad).unwrap_err();
+        let actual = cp
+            .validate_async_reader(reader(bad.as_bytes()))
+            .await
+            .unwrap_err();
+        assert_eq!(actual, expected);
+        assert_eq!(actual.position, 8000);
+    }
+
+    #[tokio::test]
+    async fn test_validate_empty() {
+        let cp = CodePoints::new(vec![]);
+        assert!(cp.validate_async_reader(reader(b"")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validate_invalid_utf8() {
+        let cp = CodePoints::ascii_printable();
+        let err = cp
+            .validate_async_reader(reader(b"ab\xFFcd"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code_point, 0xFFFD);
+        assert_eq!(err.position, 2);
+        assert!(err.message.contains("byte offset 2"));
+
+        // Truncated multi-byte sequence at end of input.
+        let err = cp
+            .validate_async_reader(reader(b"ab\xE3\x81"))
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("incomplete"));
+    }
+
+    #[tokio::test]
+    async fn test_filter_matches_sync() {
+        let cp = CodePoints::from_string("あおc");
+        let text = long_text();
+        let expected: String = text.chars().filter(|&c| cp.contains_char(c)).collect();
+
+        let mut out = Vec::new();
+        cp.filter_async_reader(reader(text.as_bytes()), &mut out)
+            .await
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_filter_invalid_utf8() {
+        let cp = CodePoints::ascii_printable();
+        let mut out = Vec::new();
+        let err = cp
+            .filter_async_reader(reader(b"ab\xFF"), &mut out)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(out, b"ab");
+    }
+}
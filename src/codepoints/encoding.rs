@@ -0,0 +1,202 @@
+//! Repertoires of the WHATWG Japanese encodings, for
+//! [`CodePoints::for_encoding`].
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use super::CodePoints;
+use crate::data::{ascii, ibm, jisx0201, jisx0208, jisx0208kanji, nec, shift_jis};
+
+/// Legacy Japanese encodings known to [`CodePoints::for_encoding`].
+#[derive(Clone, Copy)]
+enum Encoding {
+    ShiftJis,
+    EucJp,
+    Iso2022Jp,
+}
+
+impl Encoding {
+    /// Resolves a label as the WHATWG Encoding Standard does: surrounding
+    /// ASCII whitespace is ignored and case does not matter.
+    fn from_label(label: &str) -> Option<Self> {
+        const LABELS: &[(&str, Encoding)] = &[
+            ("csshiftjis", Encoding::ShiftJis),
+            ("ms932", Encoding::ShiftJis),
+            ("ms_kanji", Encoding::ShiftJis),
+            ("shift-jis", Encoding::ShiftJis),
+            ("shift_jis", Encoding::ShiftJis),
+            ("sjis", Encoding::ShiftJis),
+            ("windows-31j", Encoding::ShiftJis),
+            ("x-sjis", Encoding::ShiftJis),
+            ("cseucpkdfmtjapanese", Encoding::EucJp),
+            ("euc-jp", Encoding::EucJp),
+            ("x-euc-jp", Encoding::EucJp),
+            ("csiso2022jp", Encoding::Iso2022Jp),
+            ("iso-2022-jp", Encoding::Iso2022Jp),
+        ];
+        let label = label.trim_matches(|c: char| c.is_ascii_whitespace());
+        LABELS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(label))
+            .map(|&(_, encoding)| encoding)
+    }
+}
+
+/// The double-byte repertoire shared by the WHATWG Japanese encodings: JIS
+/// X 0208 with the CP932 mappings, NEC row 13 and the IBM extensions.
+fn cp932_double_byte() -> HashSet<u32> {
+    let mut set: HashSet<u32> = jisx0208::ALL_JISX0208.iter().copied().collect();
+    set.extend(jisx0208kanji::JISX0208_CHARS);
+    set.extend(nec::NEC_ROW13);
+    set.extend(ibm::IBM_EXTENSIONS);
+    for cp in shift_jis::JIS_MAPPINGS {
+        set.remove(cp);
+    }
+    set.extend(shift_jis::CP932_MAPPINGS);
+    set
+}
+
+impl CodePoints {
+    /// Returns the characters that survive a round trip through the named
+    /// encoding, or `None` for an unknown label.
+    ///
+    /// Labels are matched as in the WHATWG Encoding Standard, ignoring case
+    /// and surrounding whitespace: `"shift_jis"`, `"sjis"`, `"windows-31j"`,
+    /// `"euc-jp"`, `"iso-2022-jp"` and their other aliases.  As in browsers
+    /// and `encoding_rs`, Shift_JIS is CP932 (Windows-31J), and all three
+    /// encodings share its double-byte repertoire:
+    ///
+    /// * JIS X 0208 with the CP932 mappings — `～` (U+FF5E) rather than `〜`
+    ///   (U+301C), `－` rather than `−`, `￠￡￢∥―` rather than `¢£¬‖—` (see
+    ///   [`crate::data::shift_jis::CP932_MAPPINGS`]);
+    /// * NEC row 13 (`①`, `㍉`) and the IBM extensions (`髙`, `﨑`, `ⅰ`).
+    ///
+    /// On top of that, Shift_JIS and EUC-JP add ASCII and halfwidth
+    /// katakana; ISO-2022-JP adds ASCII without the escape and shift
+    /// controls, plus `¥` and `‾` from JIS X 0201 Roman.  Characters the
+    /// encoders accept but decode as something else, such as `¥` in
+    /// Shift_JIS or halfwidth katakana in ISO-2022-JP, are not included.
+    ///
+    /// "Can this text be sent as Shift_JIS?" then becomes an ordinary
+    /// [`contains`](Self::contains) or [`validate`](Self::validate) call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let sjis = CodePoints::for_encoding("Shift_JIS").unwrap();
+    /// assert!(sjis.contains("髙島屋 ①ｶﾞ～"));
+    /// assert!(!sjis.contains("〜"));
+    /// assert_eq!(sjis.validate("𠮷野家").unwrap_err().position, 0);
+    ///
+    /// assert!(std::ptr::eq(sjis, CodePoints::for_encoding("windows-31j").unwrap()));
+    /// assert!(!CodePoints::for_encoding("iso-2022-jp").unwrap().contains("ｶ"));
+    /// assert!(CodePoints::for_encoding("utf-16").is_none());
+    /// ```
+    pub fn for_encoding(label: &str) -> Option<&'static CodePoints> {
+        static SHIFT_JIS: OnceLock<CodePoints> = OnceLock::new();
+        static EUC_JP: OnceLock<CodePoints> = OnceLock::new();
+        static ISO_2022_JP: OnceLock<CodePoints> = OnceLock::new();
+        Some(match Encoding::from_label(label)? {
+            Encoding::ShiftJis => SHIFT_JIS.get_or_init(|| {
+                let mut set = cp932_double_byte();
+                set.extend(ascii::CONTROL_CHARS);
+                set.extend(ascii::PRINTABLE_CHARS);
+                set.extend(jisx0201::KATAKANA);
+                // 0x80 decodes to U+0080, and U+0080 encodes back to it.
+                set.insert(0x80);
+                Self {
+                    codepoints: set.into(),
+                }
+            }),
+            Encoding::EucJp => EUC_JP.get_or_init(|| {
+                let mut set = cp932_double_byte();
+                set.extend(ascii::CONTROL_CHARS);
+                set.extend(ascii::PRINTABLE_CHARS);
+                set.extend(jisx0201::KATAKANA);
+                Self {
+                    codepoints: set.into(),
+                }
+            }),
+            Encoding::Iso2022Jp => ISO_2022_JP.get_or_init(|| {
+                let mut set = cp932_double_byte();
+                set.extend(ascii::CONTROL_CHARS);
+                set.extend(ascii::PRINTABLE_CHARS);
+                set.extend(jisx0201::LATIN_LETTERS);
+                // SO, SI and ESC would be read as control sequences.
+                for cp in [0x0E, 0x0F, 0x1B] {
+                    set.remove(&cp);
+                }
+                Self {
+                    codepoints: set.into(),
+                }
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_encoding_labels() {
+        let sjis = CodePoints::for_encoding("shift_jis").unwrap();
+        for label in ["Shift_JIS", "SJIS", "windows-31j", " MS932\t", "x-sjis"] {
+            assert!(std::ptr::eq(CodePoints::for_encoding(label).unwrap(), sjis));
+        }
+        let euc = CodePoints::for_encoding("EUC-JP").unwrap();
+        assert!(std::ptr::eq(
+            CodePoints::for_encoding("x-euc-jp").unwrap(),
+            euc
+        ));
+        let jis = CodePoints::for_encoding("ISO-2022-JP").unwrap();
+        assert!(std::ptr::eq(
+            CodePoints::for_encoding("csISO2022JP").unwrap(),
+            jis
+        ));
+        assert!(!std::ptr::eq(sjis, euc));
+        for label in [
+            "",
+            "utf-8",
+            "shift jis",
+            "cp932",
+            "euc-jis-2004",
+            "iso-2022-jp-2",
+        ] {
+            assert!(CodePoints::for_encoding(label).is_none(), "{:?}", label);
+        }
+    }
+
+    #[test]
+    fn test_for_encoding_matches_encoding_rs() {
+        // Every BMP scalar value plus a few supplementary ones: a character
+        // is representable iff encoding_rs encodes it without error and
+        // decodes the bytes back to the same character.
+        let corpus = (0..=0xFFFF)
+            .chain([0x20B9F, 0x2A6B2, 0x1F389])
+            .filter_map(char::from_u32);
+        let encodings = [
+            ("shift_jis", encoding_rs::SHIFT_JIS),
+            ("euc-jp", encoding_rs::EUC_JP),
+            ("iso-2022-jp", encoding_rs::ISO_2022_JP),
+        ];
+        let mut buf = [0u8; 4];
+        for c in corpus {
+            let s = &*c.encode_utf8(&mut buf);
+            for (label, encoding) in encodings {
+                let (bytes, _, unmappable) = encoding.encode(s);
+                let round_trips =
+                    !unmappable && encoding.decode_without_bom_handling(&bytes).0 == s;
+                assert_eq!(
+                    CodePoints::for_encoding(label).unwrap().contains(s),
+                    round_trips,
+                    "{} U+{:04X}",
+                    label,
+                    c as u32
+                );
+            }
+        }
+    }
+}
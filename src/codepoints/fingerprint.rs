@@ -0,0 +1,130 @@
+//! Content fingerprints and audit manifests for [`CodePoints`].
+
+use super::{sorted_ranges, CodePoints};
+
+/// FNV-1a (64-bit) offset basis, the fingerprint of the empty set.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// FNV-1a (64-bit) prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A record of exactly which set was used, returned by
+/// [`CodePoints::export_manifest`].
+///
+/// With the `serde` feature it implements `serde::Serialize` as
+/// `{fingerprint, len, ranges, crate_data_version}`, where `fingerprint` is
+/// 16 lower-case hex digits and `ranges` uses the range entries of the
+/// [JSON schema](crate::validation#json-schema).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetManifest {
+    /// [`CodePoints::fingerprint`] of the set.
+    pub fingerprint: u64,
+    /// Number of code points in the set.
+    pub len: usize,
+    /// The set as inclusive ranges in ascending order.
+    pub ranges: Vec<(u32, u32)>,
+    /// [`data_version`](crate::data_version) of the crate that exported it.
+    pub crate_data_version: &'static str,
+}
+
+impl CodePoints {
+    /// Returns a fingerprint of the set's contents, for audit logs.
+    ///
+    /// The value is FNV-1a (64-bit) over the code points in ascending order,
+    /// each as four little-endian bytes.  It depends only on the contents —
+    /// not on the backend, insertion order, platform or process — and this
+    /// definition will not change between crate versions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let a = CodePoints::from_string("あいう");
+    /// let b = CodePoints::from_string("ういあ");
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// assert_ne!(a.fingerprint(), CodePoints::from_string("あい").fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut sorted: Vec<u32> = self.codepoints.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted
+            .iter()
+            .flat_map(|cp| cp.to_le_bytes())
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Returns a [`SetManifest`] recording this set's fingerprint, size,
+    /// ranges and the crate's data version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let manifest = CodePoints::from_string("あいうx").export_manifest();
+    /// assert_eq!(manifest.len, 4);
+    /// assert_eq!(manifest.ranges, [(0x78, 0x78), (0x3042, 0x3042), (0x3044, 0x3044), (0x3046, 0x3046)]);
+    /// assert_eq!(manifest.crate_data_version, japanese_codepoints::data_version());
+    /// ```
+    pub fn export_manifest(&self) -> SetManifest {
+        SetManifest {
+            fingerprint: self.fingerprint(),
+            len: self.len(),
+            ranges: sorted_ranges(self.codepoints.iter().copied()),
+            crate_data_version: crate::data_version(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codepoints::Backend;
+
+    #[test]
+    fn test_fingerprint() {
+        // FNV-1a known answers: the offset basis for the empty set, and
+        // "a" hashed as the bytes 61 00 00 00.
+        assert_eq!(CodePoints::new(vec![]).fingerprint(), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(
+            CodePoints::from_string("a").fingerprint(),
+            0xac80_4b82_0e4f_e984
+        );
+
+        let members = vec![0x3042, 0x61, 0x20BB7, 0x3044];
+        let expected = CodePoints::new(members.clone()).fingerprint();
+        for backend in [
+            Backend::HashSet,
+            Backend::BTreeSet,
+            Backend::SortedVec,
+            Backend::Bitmap,
+        ] {
+            let mut reversed = members.clone();
+            reversed.reverse();
+            let cp = CodePoints::with_backend(reversed, backend);
+            assert_eq!(cp.fingerprint(), expected);
+        }
+        assert_ne!(
+            CodePoints::new(vec![0x3042, 0x61]).fingerprint(),
+            CodePoints::new(vec![0x3042, 0x62]).fingerprint()
+        );
+    }
+
+    #[test]
+    fn test_export_manifest() {
+        let cp = CodePoints::from_string("abcあ𠮷");
+        let manifest = cp.export_manifest();
+        assert_eq!(manifest.fingerprint, cp.fingerprint());
+        assert_eq!(manifest.len, 5);
+        assert_eq!(
+            manifest.ranges,
+            [(0x61, 0x63), (0x3042, 0x3042), (0x20BB7, 0x20BB7)]
+        );
+        assert_eq!(manifest.crate_data_version, crate::data_version());
+
+        let empty = CodePoints::new(vec![]).export_manifest();
+        assert_eq!((empty.len, empty.ranges.len()), (0, 0));
+    }
+}
@@ -0,0 +1,265 @@
+//! Loading and writing sorted table files (`mmap` feature).
+//!
+//! The file format, validation and mapping live in `crate::mapped`; this
+//! module builds [`CodePoints`] from them.
+
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::{CodePoints, Storage};
+use crate::mapped::{self, SortedFileError, SortedTable};
+
+impl CodePoints {
+    /// Loads a set from a sorted table file into memory (`mmap` feature).
+    ///
+    /// The file holds the code points as little-endian `u32`s in strictly
+    /// ascending order, with no header; [`write_sorted_file`] produces it.
+    /// Every entry is validated, and the members are stored as a
+    /// [`Backend::SortedVec`].  To share one copy of a large table between
+    /// processes instead, see [`map_sorted_file`](Self::map_sorted_file).
+    ///
+    /// [`write_sorted_file`]: Self::write_sorted_file
+    /// [`Backend::SortedVec`]: super::Backend::SortedVec
+    ///
+    /// # Errors
+    ///
+    /// * [`SortedFileError::Io`] if the file cannot be read.
+    /// * [`SortedFileError::InvalidLength`] if the length is not a multiple
+    ///   of 4.
+    /// * [`SortedFileError::Unsorted`] if an entry is not greater than the
+    ///   one before it.
+    /// * [`SortedFileError::InvalidCodePoint`] if an entry is not a Unicode
+    ///   scalar value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let path = std::env::temp_dir().join(format!("jcp-doc-{}.u32", std::process::id()));
+    /// CodePoints::from_string("あいう").write_sorted_file(&path)?;
+    ///
+    /// let loaded = CodePoints::from_sorted_file(&path)?;
+    /// assert!(loaded.contains("ういあ"));
+    /// assert_eq!(loaded, CodePoints::from_string("あいう"));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_sorted_file(path: impl AsRef<Path>) -> Result<Self, SortedFileError> {
+        Ok(Self {
+            codepoints: Storage::Sorted(mapped::read(path.as_ref())?),
+        })
+    }
+
+    /// Loads a set from a sorted table file, memory-mapped read-only
+    /// (`mmap` feature).
+    ///
+    /// Same format and validation as [`from_sorted_file`], but lookups
+    /// binary-search the mapped pages, so processes loading the same table
+    /// share one copy of it instead of each building a set.  On big-endian
+    /// targets the table is decoded into a [`Backend::SortedVec`] instead.
+    ///
+    /// Clones share the mapping.  Set operations return owned sets, and the
+    /// first insertion or removal copies the members into a
+    /// [`Backend::SortedVec`].
+    ///
+    /// [`from_sorted_file`]: Self::from_sorted_file
+    /// [`Backend::SortedVec`]: super::Backend::SortedVec
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other
+    /// process, while the returned set or a clone of it is alive.  Changed
+    /// bytes would be read without validation, and a truncated file makes
+    /// lookups fault (`SIGBUS`).  Replacing the file by renaming a new one
+    /// over it, as [`write_sorted_file`](Self::write_sorted_file) does, is
+    /// safe: the mapping keeps the old contents.
+    ///
+    /// # Errors
+    ///
+    /// As [`from_sorted_file`], with [`SortedFileError::Io`] also covering
+    /// a failure to map the file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let path = std::env::temp_dir().join(format!("jcp-doc-map-{}.u32", std::process::id()));
+    /// CodePoints::from_string("あいう").write_sorted_file(&path)?;
+    ///
+    /// // SAFETY: nothing else writes to this file while `mapped` is alive.
+    /// let mapped = unsafe { CodePoints::map_sorted_file(&path)? };
+    /// assert!(mapped.contains("ういあ"));
+    /// assert_eq!(mapped, CodePoints::from_string("あいう"));
+    /// # drop(mapped);
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub unsafe fn map_sorted_file(path: impl AsRef<Path>) -> Result<Self, SortedFileError> {
+        // SAFETY: forwarded to the caller.
+        let codepoints = match unsafe { mapped::map(path.as_ref()) }? {
+            SortedTable::Mapped(table) => Storage::Mapped(Arc::new(table)),
+            SortedTable::Owned(members) => Storage::Sorted(members),
+        };
+        Ok(Self { codepoints })
+    }
+
+    /// Writes this set as a sorted table file for
+    /// [`from_sorted_file`](Self::from_sorted_file) and
+    /// [`map_sorted_file`](Self::map_sorted_file), replacing any existing
+    /// file at `path`.
+    ///
+    /// The table is written to a temporary file next to `path` and renamed
+    /// over it, so sets currently mapped from `path` keep their contents.
+    ///
+    /// Members that are not Unicode scalar values are written too, and make
+    /// the file fail to load.
+    ///
+    /// # Errors
+    ///
+    /// Returns any I/O error from creating or writing the file.
+    pub fn write_sorted_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut members: Vec<u32> = self.codepoints.iter().copied().collect();
+        members.sort_unstable();
+        mapped::write(path.as_ref(), &members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codepoints::tests::temp_table;
+    use crate::codepoints::Backend;
+
+    #[test]
+    fn test_sorted_file_parity() {
+        let members: Vec<u32> = (0x20..0x7F).chain((0x4E00..0x9FA0).step_by(3)).collect();
+        let owned = CodePoints::new(members.clone());
+        let path = temp_table("parity");
+        owned.write_sorted_file(&path).unwrap();
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().len(),
+            members.len() as u64 * 4
+        );
+
+        assert_eq!(CodePoints::from_sorted_file(&path).unwrap(), owned);
+        assert_eq!(
+            CodePoints::from_sorted_file(&path).unwrap().backend(),
+            Backend::SortedVec
+        );
+
+        // SAFETY: only this test touches the file, and not while mapped.
+        let mapped = unsafe { CodePoints::map_sorted_file(&path) }.unwrap();
+        if cfg!(target_endian = "little") {
+            assert_eq!(mapped.backend(), Backend::Mapped);
+        }
+        assert_eq!(mapped, owned);
+        assert_eq!(mapped.len(), owned.len());
+        assert!(mapped.iter().copied().eq(members.iter().copied()));
+        for c in ('\0'..='\u{11000}').chain(['\u{10FFFF}']) {
+            assert_eq!(mapped.contains_char(c), owned.contains_char(c), "{:?}", c);
+        }
+        assert_eq!(
+            mapped.count_in(0x4E00..0x5000),
+            owned.count_in(0x4E00..0x5000)
+        );
+        assert_eq!(mapped.restrict_to(..0x80), CodePoints::ascii_printable());
+
+        // Set operations materialize owned results.
+        let kana = CodePoints::from_string("あいうABC");
+        let union = mapped.union(&kana);
+        assert_eq!(union, owned.union(&kana));
+        assert_eq!(union.backend(), Backend::SortedVec);
+        assert_eq!(mapped.difference(&kana), owned.difference(&kana));
+        assert_eq!(mapped.intersection(&kana), owned.intersection(&kana));
+
+        // Clones share the mapping; mutation copies it first.
+        let mut copy = mapped.clone();
+        assert_eq!(copy.insert_char_range('A'..='A'), 0);
+        assert_eq!(copy.backend(), mapped.backend());
+        assert_eq!(copy.insert_char_range('あ'..='あ'), 1);
+        assert_eq!(copy.backend(), Backend::SortedVec);
+        assert!(copy.contains("あ"));
+        assert!(!mapped.contains("あ"));
+
+        drop((mapped, copy));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_sorted_file_errors() {
+        let path = temp_table("errors");
+        let load = |bytes: &[u8]| {
+            std::fs::write(&path, bytes).unwrap();
+            let read = CodePoints::from_sorted_file(&path);
+            // SAFETY: the file is not changed until the map is dropped.
+            let mapped = unsafe { CodePoints::map_sorted_file(&path) };
+            assert_eq!(
+                format!("{:?}", read.as_ref().map(CodePoints::len)),
+                format!("{:?}", mapped.as_ref().map(CodePoints::len))
+            );
+            read
+        };
+        let entries =
+            |cps: &[u32]| -> Vec<u8> { cps.iter().flat_map(|cp| cp.to_le_bytes()).collect() };
+
+        assert!(load(&[]).unwrap().is_empty());
+        assert!(matches!(
+            load(&[0x41, 0, 0, 0, 0x42, 0]),
+            Err(SortedFileError::InvalidLength(6))
+        ));
+        assert!(matches!(
+            load(&entries(&[0x41, 0x43, 0x42])),
+            Err(SortedFileError::Unsorted { index: 2 })
+        ));
+        assert!(matches!(
+            load(&entries(&[0x41, 0x41])),
+            Err(SortedFileError::Unsorted { index: 1 })
+        ));
+        assert!(matches!(
+            load(&entries(&[0x41, 0xD800])),
+            Err(SortedFileError::InvalidCodePoint(0xD800))
+        ));
+        // A big-endian file reads as values above U+10FFFF.
+        assert!(matches!(
+            load(&0x3042u32.to_be_bytes()),
+            Err(SortedFileError::InvalidCodePoint(0x4230_0000))
+        ));
+        std::fs::remove_file(&path).unwrap();
+
+        let missing = CodePoints::from_sorted_file(temp_table("missing"));
+        assert!(matches!(missing, Err(SortedFileError::Io(_))));
+        assert!(missing.unwrap_err().to_string().starts_with("cannot load"));
+    }
+
+    #[test]
+    fn test_write_sorted_file_replaces_mapped_table() {
+        let path = temp_table("rewrite");
+        let old = CodePoints::from_string("あいう");
+        old.write_sorted_file(&path).unwrap();
+        // SAFETY: the file is only ever replaced by rename below.
+        let mapped = unsafe { CodePoints::map_sorted_file(&path) }.unwrap();
+
+        // Rewriting a loaded table, even with a shorter one, must not
+        // truncate the pages the old set is reading.
+        let new = CodePoints::from_string("A");
+        new.write_sorted_file(&path).unwrap();
+        assert_eq!(mapped, old);
+        assert!(mapped.contains("ういあ"));
+        assert_eq!(CodePoints::from_sorted_file(&path).unwrap(), new);
+
+        // No temporary file is left behind.
+        let dir = path.parent().unwrap();
+        let stem = path.file_name().unwrap().to_str().unwrap();
+        assert!(!std::fs::read_dir(dir).unwrap().any(|e| {
+            let name = e.unwrap().file_name();
+            let name = name.to_string_lossy();
+            name.starts_with('.') && name.contains(stem)
+        }));
+
+        drop(mapped);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,6033 @@
+//! Core code-point collection type and multi-set membership helper.
+//!
+//! [`CodePoints`] is the central data structure: an immutable set of Unicode
+//! scalar values that can efficiently test membership for individual
+//! characters or entire strings.
+//!
+//! The free function [`contains_all_in_any`] extends membership testing to
+//! multiple sets at once — useful when a string may legally contain characters
+//! from several scripts simultaneously.
+
+use std::collections::{btree_set, hash_set, BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+use std::sync::{OnceLock, RwLock};
+
+#[cfg(feature = "mmap")]
+use std::sync::Arc;
+
+use crate::data::{ascii, jisx0208, unicode, unicode_blocks};
+
+#[cfg(feature = "mmap")]
+use crate::mapped::MappedTable;
+
+mod encoding;
+mod fingerprint;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod parse;
+
+pub use fingerprint::SetManifest;
+pub use parse::{ParseSpecError, ParseUnicodeSetError};
+
+// ── main type ─────────────────────────────────────────────────────────────────
+
+/// An immutable collection of Unicode code points.
+///
+/// The primary use-case is character-set validation: given a policy (e.g.
+/// "only JIS X 0208 hiragana"), quickly determine whether a string conforms.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+///
+/// let allowed = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+/// assert!(allowed.contains("あい"));
+/// assert!(!allowed.contains("う"));
+/// ```
+///
+/// # Allow-lists and deny-lists
+///
+/// A set can describe either the characters that are **allowed** or the
+/// characters that are **forbidden**:
+///
+/// * As an *allow-list*, use [`contains`](Self::contains),
+///   [`validate`](Self::validate) and the `*_excluded` family to find
+///   characters that are missing from the set.
+/// * As a *deny-list*, use [`any_included`](Self::any_included) and the
+///   `*_included` family to find characters that are present in the set.
+///
+/// [`CodePoints::ascii_control`] is the canonical deny-list: free-form text
+/// may contain almost anything except control characters.
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+///
+/// let forbidden = CodePoints::ascii_control();
+/// assert!(!forbidden.any_included("こんにちは"));
+/// assert_eq!(forbidden.first_included_with_position("ab\tc"), Some((0x09, 2)));
+/// ```
+///
+/// # Ordered sets
+///
+/// Sets are hash-based by default, so [`iter`](Self::iter) visits members in
+/// an unspecified order.  Sets built with [`new_ordered`](Self::new_ordered)
+/// (or converted with [`to_ordered`](Self::to_ordered)) are tree-based and
+/// iterate in **ascending** code-point order, which keeps derived artifacts
+/// reproducible without sorting.  Every API behaves identically for both;
+/// set operations return a set with the backend of `self`.
+///
+/// # Backends
+///
+/// [`with_backend`](Self::with_backend) and
+/// [`convert_backend`](Self::convert_backend) select any [`Backend`]: a
+/// sorted `Vec` is the most compact, a bitmap has the fastest lookups for
+/// dense sets.  The `backends` group of the Criterion benches compares them
+/// on the ASCII, kana and JIS X 0213 kanji sets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodePoints {
+    codepoints: Storage,
+}
+
+/// Backing storage of a [`CodePoints`]: hash-based by default, tree-based for
+/// ordered sets, or one of the other [`Backend`]s.
+///
+/// Mirrors the parts of the `HashSet` API the crate uses, so most code is
+/// agnostic of the backend.
+#[derive(Clone)]
+enum Storage {
+    Hash(HashSet<u32>),
+    Ordered(BTreeSet<u32>),
+    /// Sorted and de-duplicated.
+    Sorted(Vec<u32>),
+    Bitmap(Bitmap),
+    /// A sorted table file mapped read-only; mutations copy it into
+    /// [`Storage::Sorted`] first.
+    #[cfg(feature = "mmap")]
+    Mapped(Arc<MappedTable>),
+}
+
+/// One bit per code point up to the largest member, plus the sorted members
+/// so iteration can hand out `&u32`.
+///
+/// Bits stop at U+10FFFF, so a stray `u32::MAX` cannot blow up the
+/// allocation; members above it are only in `members`.
+#[derive(Clone)]
+struct Bitmap {
+    words: Vec<u64>,
+    members: Vec<u32>,
+}
+
+impl Bitmap {
+    /// Builds a bitmap from sorted, de-duplicated members.
+    fn from_sorted(members: Vec<u32>) -> Self {
+        let max = members.iter().rev().find(|&&cp| cp <= char::MAX as u32);
+        let mut words = vec![0u64; max.map_or(0, |&max| max as usize / 64 + 1)];
+        for &cp in &members {
+            if let Some(word) = words.get_mut(cp as usize / 64) {
+                *word |= 1 << (cp % 64);
+            }
+        }
+        Self { words, members }
+    }
+
+    fn contains(&self, cp: u32) -> bool {
+        if cp > char::MAX as u32 {
+            return self.members.binary_search(&cp).is_ok();
+        }
+        self.words
+            .get(cp as usize / 64)
+            .is_some_and(|word| word & (1 << (cp % 64)) != 0)
+    }
+
+    fn insert(&mut self, cp: u32) -> bool {
+        if self.contains(cp) {
+            return false;
+        }
+        let index = cp as usize / 64;
+        if index >= self.words.len() && cp <= char::MAX as u32 {
+            self.words.resize(index + 1, 0);
+        }
+        if let Some(word) = self.words.get_mut(index) {
+            *word |= 1 << (cp % 64);
+        }
+        let at = self.members.partition_point(|&m| m < cp);
+        self.members.insert(at, cp);
+        true
+    }
+
+    fn remove(&mut self, cp: u32) -> bool {
+        if !self.contains(cp) {
+            return false;
+        }
+        if let Some(word) = self.words.get_mut(cp as usize / 64) {
+            *word &= !(1 << (cp % 64));
+        }
+        let at = self.members.partition_point(|&m| m < cp);
+        self.members.remove(at);
+        true
+    }
+}
+
+/// Sorts and de-duplicates `iter`.
+fn sorted_unique(iter: impl IntoIterator<Item = u32>) -> Vec<u32> {
+    let mut members: Vec<u32> = iter.into_iter().collect();
+    members.sort_unstable();
+    members.dedup();
+    members
+}
+
+/// Converts `range` to inclusive bounds, or `None` if it holds no `u32`.
+fn inclusive_bounds(range: &impl RangeBounds<u32>) -> Option<(u32, u32)> {
+    let lo = match range.start_bound() {
+        Bound::Included(&lo) => lo,
+        Bound::Excluded(&lo) => lo.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let hi = match range.end_bound() {
+        Bound::Included(&hi) => hi,
+        Bound::Excluded(&hi) => hi.checked_sub(1)?,
+        Bound::Unbounded => u32::MAX,
+    };
+    (lo <= hi).then_some((lo, hi))
+}
+
+/// Returns the part of the sorted slice `members` within `lo..=hi`.
+fn sorted_range(members: &[u32], lo: u32, hi: u32) -> &[u32] {
+    let start = members.partition_point(|&cp| cp < lo);
+    let end = members.partition_point(|&cp| cp <= hi);
+    members.get(start..end).unwrap_or_default()
+}
+
+impl Storage {
+    /// Collects `iter` into storage with the given backend.
+    fn collect_as(backend: Backend, iter: impl IntoIterator<Item = u32>) -> Storage {
+        match backend {
+            Backend::HashSet => Storage::Hash(iter.into_iter().collect()),
+            Backend::BTreeSet => Storage::Ordered(iter.into_iter().collect()),
+            Backend::SortedVec => Storage::Sorted(sorted_unique(iter)),
+            Backend::Bitmap => Storage::Bitmap(Bitmap::from_sorted(sorted_unique(iter))),
+            #[cfg(feature = "mmap")]
+            Backend::Mapped => Storage::Sorted(sorted_unique(iter)),
+        }
+    }
+
+    /// Collects `iter` into storage with the same backend as `self`.
+    fn collect_like(&self, iter: impl IntoIterator<Item = u32>) -> Storage {
+        Storage::collect_as(self.representation(), iter)
+    }
+
+    /// Splits `self` into the members that are in `other` and those that
+    /// are not, both with the backend of `self`.
+    fn partition(&self, other: &Storage) -> (Storage, Storage) {
+        let mut inside = self.collect_like(None);
+        let mut outside = self.collect_like(None);
+        for &cp in self {
+            if other.contains(&cp) {
+                inside.insert(cp);
+            } else {
+                outside.insert(cp);
+            }
+        }
+        (inside, outside)
+    }
+
+    fn contains(&self, cp: &u32) -> bool {
+        match self {
+            Storage::Hash(set) => set.contains(cp),
+            Storage::Ordered(set) => set.contains(cp),
+            Storage::Sorted(members) => members.binary_search(cp).is_ok(),
+            Storage::Bitmap(bitmap) => bitmap.contains(*cp),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => table.members().binary_search(cp).is_ok(),
+        }
+    }
+
+    fn insert(&mut self, cp: u32) -> bool {
+        match self {
+            Storage::Hash(set) => set.insert(cp),
+            Storage::Ordered(set) => set.insert(cp),
+            Storage::Sorted(members) => match members.binary_search(&cp) {
+                Ok(_) => false,
+                Err(at) => {
+                    members.insert(at, cp);
+                    true
+                }
+            },
+            Storage::Bitmap(bitmap) => bitmap.insert(cp),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => {
+                if table.members().binary_search(&cp).is_ok() {
+                    return false;
+                }
+                *self = Storage::Sorted(table.members().to_vec());
+                self.insert(cp)
+            }
+        }
+    }
+
+    fn remove(&mut self, cp: &u32) -> bool {
+        match self {
+            Storage::Hash(set) => set.remove(cp),
+            Storage::Ordered(set) => set.remove(cp),
+            Storage::Sorted(members) => match members.binary_search(cp) {
+                Ok(at) => {
+                    members.remove(at);
+                    true
+                }
+                Err(_) => false,
+            },
+            Storage::Bitmap(bitmap) => bitmap.remove(*cp),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => {
+                if table.members().binary_search(cp).is_err() {
+                    return false;
+                }
+                *self = Storage::Sorted(table.members().to_vec());
+                self.remove(cp)
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Storage::Hash(set) => set.len(),
+            Storage::Ordered(set) => set.len(),
+            Storage::Sorted(members) => members.len(),
+            Storage::Bitmap(bitmap) => bitmap.members.len(),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => table.members().len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slots allocated; a tree or a mapped table has no spare capacity.
+    fn capacity(&self) -> usize {
+        match self {
+            Storage::Hash(set) => set.capacity(),
+            Storage::Ordered(set) => set.len(),
+            Storage::Sorted(members) => members.capacity(),
+            Storage::Bitmap(bitmap) => bitmap.members.capacity(),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => table.members().len(),
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        match self {
+            Storage::Hash(set) => set.shrink_to_fit(),
+            Storage::Ordered(_) => {}
+            Storage::Sorted(members) => members.shrink_to_fit(),
+            Storage::Bitmap(bitmap) => {
+                let used = bitmap
+                    .words
+                    .iter()
+                    .rposition(|&w| w != 0)
+                    .map_or(0, |i| i + 1);
+                bitmap.words.truncate(used);
+                bitmap.words.shrink_to_fit();
+                bitmap.members.shrink_to_fit();
+            }
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(_) => {}
+        }
+    }
+
+    fn iter(&self) -> Iter<'_> {
+        Iter(match self {
+            Storage::Hash(set) => IterInner::Hash(set.iter()),
+            Storage::Ordered(set) => IterInner::Ordered(set.iter()),
+            Storage::Sorted(members) => IterInner::Slice(members.iter()),
+            Storage::Bitmap(bitmap) => IterInner::Slice(bitmap.members.iter()),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => IterInner::Slice(table.members().iter()),
+        })
+    }
+
+    /// Returns the members within `lo..=hi` (`lo <= hi`), with the backend
+    /// of `self`; ordered backends look the bounds up instead of scanning.
+    fn restrict(&self, lo: u32, hi: u32) -> Storage {
+        match self {
+            Storage::Hash(set) => Storage::Hash(
+                set.iter()
+                    .copied()
+                    .filter(|cp| (lo..=hi).contains(cp))
+                    .collect(),
+            ),
+            Storage::Ordered(set) => Storage::Ordered(set.range(lo..=hi).copied().collect()),
+            Storage::Sorted(members) => Storage::Sorted(sorted_range(members, lo, hi).to_vec()),
+            Storage::Bitmap(bitmap) => Storage::Bitmap(Bitmap::from_sorted(
+                sorted_range(&bitmap.members, lo, hi).to_vec(),
+            )),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => {
+                Storage::Sorted(sorted_range(table.members(), lo, hi).to_vec())
+            }
+        }
+    }
+
+    /// Counts the members within `lo..=hi` (`lo <= hi`); `O(log n)` for the
+    /// sorted-slice backends.  A `HashSet` probes each value of a range
+    /// narrower than the set and scans the set otherwise.
+    fn count_in(&self, lo: u32, hi: u32) -> usize {
+        match self {
+            Storage::Hash(set) if ((hi - lo) as usize) < set.len() => {
+                (lo..=hi).filter(|cp| set.contains(cp)).count()
+            }
+            Storage::Hash(set) => set.iter().filter(|cp| (lo..=hi).contains(*cp)).count(),
+            Storage::Ordered(set) => set.range(lo..=hi).count(),
+            Storage::Sorted(members) => sorted_range(members, lo, hi).len(),
+            Storage::Bitmap(bitmap) => sorted_range(&bitmap.members, lo, hi).len(),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => sorted_range(table.members(), lo, hi).len(),
+        }
+    }
+
+    /// Returns `true` if any member lies within `lo..=hi` (`lo <= hi`); the
+    /// short-circuiting form of [`Self::count_in`].
+    fn has_any_in(&self, lo: u32, hi: u32) -> bool {
+        match self {
+            Storage::Hash(set) if ((hi - lo) as usize) < set.len() => {
+                (lo..=hi).any(|cp| set.contains(&cp))
+            }
+            Storage::Hash(set) => set.iter().any(|cp| (lo..=hi).contains(cp)),
+            Storage::Ordered(set) => set.range(lo..=hi).next().is_some(),
+            Storage::Sorted(members) => !sorted_range(members, lo, hi).is_empty(),
+            Storage::Bitmap(bitmap) => !sorted_range(&bitmap.members, lo, hi).is_empty(),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => !sorted_range(table.members(), lo, hi).is_empty(),
+        }
+    }
+
+    fn intersection(&self, other: &Storage) -> Storage {
+        self.collect_like(self.iter().copied().filter(|cp| other.contains(cp)))
+    }
+
+    fn difference(&self, other: &Storage) -> Storage {
+        self.collect_like(self.iter().copied().filter(|cp| !other.contains(cp)))
+    }
+
+    fn symmetric_difference(&self, other: &Storage) -> Storage {
+        let only_self = self.iter().copied().filter(|cp| !other.contains(cp));
+        let only_other = other.iter().copied().filter(|cp| !self.contains(cp));
+        self.collect_like(only_self.chain(only_other))
+    }
+
+    fn is_subset(&self, other: &Storage) -> bool {
+        self.len() <= other.len() && self.iter().all(|cp| other.contains(cp))
+    }
+
+    fn is_superset(&self, other: &Storage) -> bool {
+        other.is_subset(self)
+    }
+
+    fn is_disjoint(&self, other: &Storage) -> bool {
+        let (small, large) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        !small.iter().any(|cp| large.contains(cp))
+    }
+
+    fn representation(&self) -> Representation {
+        match self {
+            Storage::Hash(_) => Representation::HashSet,
+            Storage::Ordered(_) => Representation::BTreeSet,
+            Storage::Sorted(_) => Representation::SortedVec,
+            Storage::Bitmap(_) => Representation::Bitmap,
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(_) => Representation::Mapped,
+        }
+    }
+}
+
+impl From<HashSet<u32>> for Storage {
+    fn from(set: HashSet<u32>) -> Self {
+        Storage::Hash(set)
+    }
+}
+
+impl FromIterator<u32> for Storage {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        Storage::Hash(iter.into_iter().collect())
+    }
+}
+
+impl Extend<u32> for Storage {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+        match self {
+            Storage::Hash(set) => set.extend(iter),
+            Storage::Ordered(set) => set.extend(iter),
+            Storage::Sorted(members) => {
+                members.extend(iter);
+                members.sort_unstable();
+                members.dedup();
+            }
+            Storage::Bitmap(bitmap) => {
+                let members = std::mem::take(&mut bitmap.members);
+                *bitmap = Bitmap::from_sorted(sorted_unique(members.into_iter().chain(iter)));
+            }
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(table) => {
+                *self = Storage::Sorted(table.members().to_vec());
+                self.extend(iter);
+            }
+        }
+    }
+}
+
+impl<'a> Extend<&'a u32> for Storage {
+    fn extend<I: IntoIterator<Item = &'a u32>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a> IntoIterator for &'a Storage {
+    type Item = &'a u32;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Equal when the members are equal, whatever the backends.
+impl PartialEq for Storage {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl Eq for Storage {}
+
+impl fmt::Debug for Storage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the members of a [`CodePoints`], returned by
+/// [`CodePoints::iter`].
+///
+/// Ascending for ordered sets; unspecified otherwise.
+#[derive(Clone, Debug)]
+pub struct Iter<'a>(IterInner<'a>);
+
+#[derive(Clone, Debug)]
+enum IterInner<'a> {
+    Hash(hash_set::Iter<'a, u32>),
+    Ordered(btree_set::Iter<'a, u32>),
+    Slice(std::slice::Iter<'a, u32>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a u32;
+
+    fn next(&mut self) -> Option<&'a u32> {
+        match &mut self.0 {
+            IterInner::Hash(iter) => iter.next(),
+            IterInner::Ordered(iter) => iter.next(),
+            IterInner::Slice(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            IterInner::Hash(iter) => iter.size_hint(),
+            IterInner::Ordered(iter) => iter.size_hint(),
+            IterInner::Slice(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl std::iter::FusedIterator for Iter<'_> {}
+
+// ── constructors ──────────────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Creates a `CodePoints` from a `Vec` of code-point values.
+    ///
+    /// Duplicate values are silently de-duplicated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3042, 0x3044]);
+    /// assert_eq!(cp.len(), 2);
+    /// ```
+    pub fn new(codepoints: Vec<u32>) -> Self {
+        Self {
+            codepoints: codepoints.into_iter().collect(),
+        }
+    }
+
+    /// Creates an **ordered** `CodePoints` from a `Vec` of code-point values.
+    ///
+    /// Ordered sets are stored in a `BTreeSet`, so [`iter`](Self::iter),
+    /// [`chars`](Self::chars) and the other iterators yield members in
+    /// ascending code-point order with no sorting step.  Lookups are
+    /// `O(log n)` instead of `O(1)`; prefer [`Self::new`] for hot validation
+    /// paths.
+    ///
+    /// Set operations keep the backend of the left-hand side, so
+    /// `ordered.union(&hashed)` is ordered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new_ordered(vec![0x3044, 0x3042, 0x3044]);
+    /// assert_eq!(cp.len(), 2);
+    /// assert_eq!(cp.chars().collect::<String>(), "あい");
+    /// assert!(cp.is_ordered());
+    /// ```
+    pub fn new_ordered(codepoints: Vec<u32>) -> Self {
+        Self {
+            codepoints: Storage::Ordered(codepoints.into_iter().collect()),
+        }
+    }
+
+    /// Returns an ordered copy of this set; see [`Self::new_ordered`].
+    #[must_use]
+    pub fn to_ordered(&self) -> Self {
+        Self {
+            codepoints: Storage::Ordered(self.codepoints.iter().copied().collect()),
+        }
+    }
+
+    /// Returns `true` if this set iterates in ascending order, i.e. it was
+    /// built by [`Self::new_ordered`] or [`Self::to_ordered`], or uses the
+    /// [`Backend::SortedVec`] or [`Backend::Bitmap`] backend (or was derived
+    /// from such a set by a set operation).
+    pub fn is_ordered(&self) -> bool {
+        !matches!(self.codepoints, Storage::Hash(_))
+    }
+
+    /// Creates a `CodePoints` stored in the given [`Backend`].
+    ///
+    /// Every backend behaves identically; they differ only in lookup speed,
+    /// memory and iteration order (ascending for all but
+    /// [`Backend::HashSet`]).  Set operations keep the backend of the
+    /// left-hand side.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{Backend, CodePoints};
+    ///
+    /// let cp = CodePoints::with_backend(vec![0x3044, 0x3042], Backend::Bitmap);
+    /// assert!(cp.contains("あい"));
+    /// assert_eq!(cp.backend(), Backend::Bitmap);
+    /// assert_eq!(cp, CodePoints::new(vec![0x3042, 0x3044]));
+    /// ```
+    pub fn with_backend(codepoints: Vec<u32>, backend: Backend) -> Self {
+        Self {
+            codepoints: Storage::collect_as(backend, codepoints),
+        }
+    }
+
+    /// Moves the members of this set into the given [`Backend`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{Backend, CodePoints};
+    ///
+    /// let mut cp = CodePoints::ascii_printable();
+    /// cp.convert_backend(Backend::SortedVec);
+    /// assert_eq!(cp.backend(), Backend::SortedVec);
+    /// assert_eq!(cp.iter().next(), Some(&0x20));
+    /// ```
+    pub fn convert_backend(&mut self, backend: Backend) {
+        if self.backend() != backend {
+            self.codepoints = Storage::collect_as(backend, self.codepoints.iter().copied());
+        }
+    }
+
+    /// Returns the [`Backend`] this set is stored in.
+    pub fn backend(&self) -> Backend {
+        self.codepoints.representation()
+    }
+
+    /// Creates a `CodePoints` from a slice of code-point values.
+    ///
+    /// This is the preferred constructor when the source data is a static or
+    /// borrowed `&[u32]` because it avoids an intermediate `Vec` allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// const HIRAGANA_AI: &[u32] = &[0x3042, 0x3044];
+    /// let cp = CodePoints::from_slice(HIRAGANA_AI);
+    /// assert!(cp.contains("あい"));
+    /// ```
+    pub fn from_slice(slice: &[u32]) -> Self {
+        Self {
+            codepoints: slice.iter().copied().collect(),
+        }
+    }
+
+    /// Creates a `CodePoints` by extracting every unique code point from a
+    /// string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あいあ");
+    /// assert_eq!(cp.len(), 2); // あ deduplicated
+    /// ```
+    pub fn from_string(s: &str) -> Self {
+        Self {
+            codepoints: s.chars().map(|c| c as u32).collect(),
+        }
+    }
+
+    /// Like [`Self::from_string`], but skips every character in `exclude`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string_filtered("あい\tう\n", CodePoints::ascii_control_cached());
+    /// assert_eq!(cp, CodePoints::from_string("あいう"));
+    /// ```
+    pub fn from_string_filtered(s: &str, exclude: &CodePoints) -> Self {
+        Self {
+            codepoints: s
+                .chars()
+                .filter(|&c| !exclude.contains_char(c))
+                .map(|c| c as u32)
+                .collect(),
+        }
+    }
+
+    /// Creates a `CodePoints` holding every code point that appears in any
+    /// of `samples`.
+    ///
+    /// The samples are collected into a single set, without building one
+    /// per sample.  Combine with [`Self::difference`] to drop characters
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let history = ["山田太郎", "山田花子\r\n", "田中"];
+    /// let cp = CodePoints::from_strings(history).difference(CodePoints::ascii_control_cached());
+    /// assert_eq!(cp.len(), 7);
+    /// assert!(cp.contains("山田中"));
+    /// ```
+    pub fn from_strings<S: AsRef<str>>(samples: impl IntoIterator<Item = S>) -> Self {
+        let mut codepoints = HashSet::new();
+        for sample in samples {
+            codepoints.extend(sample.as_ref().chars().map(|c| c as u32));
+        }
+        Self {
+            codepoints: codepoints.into(),
+        }
+    }
+
+    /// Creates a `CodePoints` from an inclusive range of characters.
+    ///
+    /// Unlike a `u32` range, a `char` range cannot contain surrogates:
+    /// `'\u{D7FF}'..='\u{E000}'` has two members, not 2 050.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let hiragana = CodePoints::from_char_range('ぁ'..='ん');
+    /// assert_eq!(hiragana.len(), 83);
+    /// assert!(hiragana.contains("ひらがな"));
+    /// assert_eq!(CodePoints::from('ぁ'..='ん'), hiragana);
+    /// ```
+    pub fn from_char_range(range: RangeInclusive<char>) -> Self {
+        Self {
+            codepoints: range.map(|c| c as u32).collect(),
+        }
+    }
+
+    /// Adds every character of `range` to the set, keeping its backend, and
+    /// returns how many were not already members.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let mut kana = CodePoints::from_char_range('ぁ'..='ん');
+    /// assert_eq!(kana.insert_char_range('ァ'..='ヶ'), 86);
+    /// assert_eq!(kana.insert_char_range('ん'..='ァ'), 0x30A1 - 0x3093 - 1);
+    /// assert!(kana.contains("ひらがなカタカナ"));
+    /// ```
+    pub fn insert_char_range(&mut self, range: RangeInclusive<char>) -> usize {
+        range.filter(|&c| self.codepoints.insert(c as u32)).count()
+    }
+}
+
+// ── membership ────────────────────────────────────────────────────────────────
+
+/// Result of [`CodePoints::contains_within`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainsOutcome {
+    /// The whole string was checked and every character is in the set.
+    AllValid,
+    /// The character at this zero-based character index, with this code
+    /// point, is not in the set.
+    InvalidAt(u32, usize),
+    /// The limit was reached without finding a violation; the rest of the
+    /// string was not checked.
+    TruncatedValid,
+}
+
+/// A character of the input that is not in the set, as returned by
+/// [`CodePoints::first_excluded_detail`] and [`CodePoints::excluded`].
+///
+/// The `char` comes straight from the input, so no fallible
+/// `char::from_u32` conversion is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExcludedChar {
+    /// The excluded character.
+    pub ch: char,
+    /// Its code point; always `ch as u32`.
+    pub code_point: u32,
+    /// Zero-based character index (not byte index) within the input.
+    pub position: usize,
+    /// Zero-based UTF-8 byte index within the input.
+    pub byte_index: usize,
+}
+
+/// Output order of [`CodePoints::excluded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Order {
+    /// Input order, by character position.
+    #[default]
+    Occurrence,
+    /// Ascending code point; occurrences of the same code point stay in
+    /// input order.
+    CodePoint,
+}
+
+/// Options for [`CodePoints::excluded`].
+///
+/// The default (`dedup: true`, `order: Order::Occurrence`, `limit: None`)
+/// reports each excluded code point once, at its first occurrence, exactly
+/// like [`CodePoints::all_excluded`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{ExcludedOptions, Order};
+///
+/// let every_occurrence = ExcludedOptions {
+///     dedup: false,
+///     ..ExcludedOptions::default()
+/// };
+/// assert_eq!(every_occurrence.order, Order::Occurrence);
+/// assert_eq!(every_occurrence.limit, None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExcludedOptions {
+    /// Report each excluded code point once, at its first occurrence,
+    /// instead of every occurrence.
+    pub dedup: bool,
+    /// Order of the returned characters.
+    pub order: Order,
+    /// Return at most this many characters.  Applied after `dedup` and
+    /// `order`, so with [`Order::CodePoint`] it keeps the lowest code
+    /// points rather than the earliest.
+    pub limit: Option<usize>,
+}
+
+impl Default for ExcludedOptions {
+    fn default() -> Self {
+        Self {
+            dedup: true,
+            order: Order::Occurrence,
+            limit: None,
+        }
+    }
+}
+
+impl CodePoints {
+    /// Returns `true` if **every** character in `text` belongs to this set.
+    ///
+    /// An empty string is always considered valid (vacuously true).
+    ///
+    /// Scanning stops at the first character outside the set, so the cost
+    /// of rejecting a string is proportional to the position of its first
+    /// violation, not its length.  To bound the cost of *accepting* a
+    /// string as well, use [`Self::contains_within`].
+    ///
+    /// `text` may be any string type — `&str`, `String`, `Cow<str>`,
+    /// `Box<str>` or a reference to one — and is only borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// assert!(cp.contains("あい"));
+    /// assert!(!cp.contains("う"));
+    /// assert!(cp.contains(""));   // empty string
+    ///
+    /// assert!(cp.contains(String::from("いあ")));
+    /// assert!(cp.contains(Cow::Borrowed("あ")));
+    /// assert!(cp.contains(Box::<str>::from("い")));
+    /// ```
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.contains_chars(text.as_ref().chars())
+    }
+
+    /// Short-circuiting core of [`Self::contains`]; stops pulling from
+    /// `chars` at the first character outside the set.
+    fn contains_chars(&self, mut chars: impl Iterator<Item = char>) -> bool {
+        chars.all(|c| self.codepoints.contains(&(c as u32)))
+    }
+
+    /// Checks at most the first `max_chars` characters of `s`.
+    ///
+    /// Returns [`ContainsOutcome::InvalidAt`] with the code point and
+    /// character index of the first violation among them,
+    /// [`ContainsOutcome::TruncatedValid`] if they are all valid but `s` is
+    /// longer, and [`ContainsOutcome::AllValid`] if all of `s` was checked.
+    /// Characters beyond the limit are never decoded, which bounds the cost
+    /// of validating adversarially long input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{CodePoints, ContainsOutcome};
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// assert_eq!(cp.contains_within("abc", 3), ContainsOutcome::AllValid);
+    /// assert_eq!(cp.contains_within("abcd", 3), ContainsOutcome::TruncatedValid);
+    /// assert_eq!(cp.contains_within("aあc", 3), ContainsOutcome::InvalidAt(0x3042, 1));
+    /// assert_eq!(cp.contains_within("abcあ", 3), ContainsOutcome::TruncatedValid);
+    /// ```
+    pub fn contains_within(&self, s: &str, max_chars: usize) -> ContainsOutcome {
+        let mut chars = s.chars();
+        for i in 0..max_chars {
+            match chars.next() {
+                None => return ContainsOutcome::AllValid,
+                Some(c) if !self.codepoints.contains(&(c as u32)) => {
+                    return ContainsOutcome::InvalidAt(c as u32, i)
+                }
+                Some(_) => {}
+            }
+        }
+        if chars.next().is_none() {
+            ContainsOutcome::AllValid
+        } else {
+            ContainsOutcome::TruncatedValid
+        }
+    }
+
+    /// Returns `true` if the single character `c` belongs to this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// assert!(cp.contains_char('あ'));
+    /// assert!(!cp.contains_char('い'));
+    /// ```
+    pub fn contains_char(&self, c: char) -> bool {
+        self.codepoints.contains(&(c as u32))
+    }
+
+    /// Returns the first code point in `text` that is **not** in this set,
+    /// together with its zero-based character index (not byte index).
+    ///
+    /// Returns `None` when every character is allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// assert_eq!(cp.first_excluded_with_position("あいう"), Some((0x3046, 2)));
+    /// assert_eq!(cp.first_excluded_with_position("あい"),   None);
+    /// ```
+    pub fn first_excluded_with_position(&self, s: &str) -> Option<(u32, usize)> {
+        s.chars().enumerate().find_map(|(i, c)| {
+            let cp = c as u32;
+            if self.codepoints.contains(&cp) {
+                None
+            } else {
+                Some((cp, i))
+            }
+        })
+    }
+
+    /// Returns the first code point in `text` that is **not** in this set.
+    ///
+    /// This is [`Self::excluded`] with a limit of one, keeping only the
+    /// code point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// assert_eq!(cp.first_excluded("あいう"), Some(0x3046)); // う
+    /// assert_eq!(cp.first_excluded("あい"),   None);
+    /// ```
+    pub fn first_excluded(&self, s: &str) -> Option<u32> {
+        let options = ExcludedOptions {
+            dedup: false,
+            order: Order::Occurrence,
+            limit: Some(1),
+        };
+        self.excluded(s, options).first().map(|e| e.code_point)
+    }
+
+    /// Returns the first character in `text` that is **not** in this set.
+    ///
+    /// Like [`Self::first_excluded`], but returns the `char` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// assert_eq!(cp.first_excluded_char("あ𠮷"), Some('𠮷'));
+    /// assert_eq!(cp.first_excluded_char("あ"), None);
+    /// ```
+    pub fn first_excluded_char(&self, s: &str) -> Option<char> {
+        self.iter_excluded_chars(s).next().map(|(c, _)| c)
+    }
+
+    /// Returns the first character in `text` that is **not** in this set,
+    /// with its code point and its character and byte indices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// let excluded = cp.first_excluded_detail("ああ𠮷").unwrap();
+    /// assert_eq!(excluded.ch, '𠮷');
+    /// assert_eq!(excluded.code_point, 0x20BB7);
+    /// assert_eq!(excluded.position, 2);
+    /// assert_eq!(excluded.byte_index, 6);
+    /// ```
+    pub fn first_excluded_detail(&self, s: &str) -> Option<ExcludedChar> {
+        self.iter_excluded_chars(s).next_excluded()
+    }
+
+    /// Returns all unique code points in `text` that are **not** in this set.
+    ///
+    /// The returned vector preserves **first-occurrence order**: the first
+    /// excluded character encountered while scanning `text` left-to-right
+    /// appears first.  Each excluded code point appears exactly once even if
+    /// it occurs multiple times in the input.  Use [`Self::excluded`] for every occurrence or
+    /// code-point order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// // う then え, first-occurrence order
+    /// assert_eq!(cp.all_excluded("あいうえ"), vec![0x3046, 0x3048]);
+    /// ```
+    pub fn all_excluded(&self, s: &str) -> Vec<u32> {
+        self.excluded(s, ExcludedOptions::default())
+            .into_iter()
+            .map(|e| e.code_point)
+            .collect()
+    }
+
+    /// Returns the characters of `text` that are **not** in this set,
+    /// deduplicated, ordered and truncated as `options` says.
+    ///
+    /// This is the general form of [`Self::all_excluded`] and
+    /// [`Self::first_excluded`].  With the default [`ExcludedOptions`] each
+    /// excluded code point is reported once, at its first occurrence, in
+    /// input order.  Set `dedup: false` to get every occurrence, e.g. for a
+    /// replacement pass, and `order: Order::CodePoint` for sorted reports.
+    ///
+    /// In [`Order::Occurrence`] scanning stops once `limit` characters are
+    /// found; [`Order::CodePoint`] always scans the whole input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{CodePoints, ExcludedOptions, Order};
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// let found = |options| -> Vec<(char, usize)> {
+    ///     cp.excluded("えあうえ", options).iter().map(|e| (e.ch, e.position)).collect()
+    /// };
+    ///
+    /// assert_eq!(found(ExcludedOptions::default()), vec![('え', 0), ('う', 2)]);
+    ///
+    /// let every = ExcludedOptions { dedup: false, ..ExcludedOptions::default() };
+    /// assert_eq!(found(every), vec![('え', 0), ('う', 2), ('え', 3)]);
+    ///
+    /// let sorted = ExcludedOptions { order: Order::CodePoint, limit: Some(1), ..every };
+    /// assert_eq!(found(sorted), vec![('う', 2)]);
+    /// ```
+    pub fn excluded(&self, s: &str, options: ExcludedOptions) -> Vec<ExcludedChar> {
+        let limit = options.limit.unwrap_or(usize::MAX);
+        let mut found: Vec<ExcludedChar> = match (options.order, options.dedup) {
+            (Order::Occurrence, true) => return self.iter_excluded_unique(s).take(limit).collect(),
+            (Order::Occurrence, false) => return self.iter_excluded(s).take(limit).collect(),
+            (Order::CodePoint, true) => self.iter_excluded_unique(s).collect(),
+            (Order::CodePoint, false) => self.iter_excluded(s).collect(),
+        };
+        // Stable, so occurrences of one code point stay in input order.
+        found.sort_by_key(|e| e.code_point);
+        found.truncate(limit);
+        found
+    }
+
+    /// Returns a lazy iterator over every character of `text` that is
+    /// **not** in this set, as [`ExcludedChar`]s in input order.
+    ///
+    /// Duplicates are kept and nothing is buffered: the input is scanned
+    /// only as far as the consumer asks, so `take(10)` stops after the
+    /// tenth violation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// let first_two: Vec<(char, usize)> = cp
+    ///     .iter_excluded("あうあうえ")
+    ///     .take(2)
+    ///     .map(|e| (e.ch, e.position))
+    ///     .collect();
+    /// assert_eq!(first_two, vec![('う', 1), ('う', 3)]);
+    /// ```
+    pub fn iter_excluded<'a>(&'a self, s: &'a str) -> ExcludedIter<'a> {
+        ExcludedIter {
+            inner: self.iter_excluded_chars(s),
+        }
+    }
+
+    /// Like [`iter_excluded`](Self::iter_excluded), but yields only the
+    /// first occurrence of each excluded code point — the lazy form of
+    /// [`all_excluded`](Self::all_excluded).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// let unique: Vec<(char, usize)> = cp
+    ///     .iter_excluded_unique("あうあうえ")
+    ///     .map(|e| (e.ch, e.position))
+    ///     .collect();
+    /// assert_eq!(unique, vec![('う', 1), ('え', 4)]);
+    /// ```
+    pub fn iter_excluded_unique<'a>(&'a self, s: &'a str) -> ExcludedUniqueIter<'a> {
+        ExcludedUniqueIter {
+            inner: self.iter_excluded_chars(s),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Returns `true` if **any** character in `text` belongs to this set.
+    ///
+    /// This is the deny-list counterpart of [`Self::contains`].  An empty
+    /// string never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let forbidden = CodePoints::ascii_control();
+    /// assert!(forbidden.any_included("line\nbreak"));
+    /// assert!(!forbidden.any_included("no breaks"));
+    /// assert!(!forbidden.any_included(""));
+    /// ```
+    pub fn any_included(&self, s: &str) -> bool {
+        s.chars().any(|c| self.codepoints.contains(&(c as u32)))
+    }
+
+    /// Returns the first code point in `text` that **is** in this set,
+    /// together with its zero-based character index (not byte index).
+    ///
+    /// Returns `None` when no character belongs to the set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// assert_eq!(cp.first_included_with_position("うあい"), Some((0x3042, 1)));
+    /// assert_eq!(cp.first_included_with_position("うえ"),   None);
+    /// ```
+    pub fn first_included_with_position(&self, s: &str) -> Option<(u32, usize)> {
+        s.chars().enumerate().find_map(|(i, c)| {
+            let cp = c as u32;
+            if self.codepoints.contains(&cp) {
+                Some((cp, i))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the first code point in `text` that **is** in this set.
+    ///
+    /// This is a convenience wrapper around [`Self::first_included_with_position`]
+    /// that discards the position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// assert_eq!(cp.first_included("うあい"), Some(0x3042)); // あ
+    /// assert_eq!(cp.first_included("うえ"),   None);
+    /// ```
+    pub fn first_included(&self, s: &str) -> Option<u32> {
+        self.first_included_with_position(s).map(|(cp, _)| cp)
+    }
+
+    /// Returns **every** occurrence of a code point in `text` that is in this
+    /// set, paired with its zero-based character index.
+    ///
+    /// Unlike [`Self::all_excluded`], duplicates are kept so that each
+    /// offending position can be reported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let forbidden = CodePoints::ascii_control();
+    /// assert_eq!(
+    ///     forbidden.all_included_with_positions("a\tb\tc\n"),
+    ///     vec![(0x09, 1), (0x09, 3), (0x0A, 5)]
+    /// );
+    /// ```
+    pub fn all_included_with_positions(&self, s: &str) -> Vec<(u32, usize)> {
+        self.iter_valid_chars(s)
+            .map(|(c, i)| (c as u32, i))
+            .collect()
+    }
+
+    /// Returns a lazy iterator over the characters of `text` that are **not**
+    /// in this set, paired with their zero-based character index.
+    ///
+    /// Unlike [`Self::all_excluded`], nothing is allocated and duplicates are
+    /// not removed, so callers can stop early (e.g. after the first few
+    /// errors).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// let excluded: Vec<_> = cp.iter_excluded_chars("あうい").collect();
+    /// assert_eq!(excluded, vec![('う', 1)]);
+    /// ```
+    pub fn iter_excluded_chars<'a>(&'a self, s: &'a str) -> ExcludedCharIter<'a> {
+        ExcludedCharIter {
+            codepoints: self,
+            chars: s.chars(),
+            index: 0,
+            byte_index: 0,
+        }
+    }
+
+    /// Returns a lazy iterator over the characters of `text` that **are** in
+    /// this set, paired with their zero-based character index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// let valid: Vec<_> = cp.iter_valid_chars("あうい").collect();
+    /// assert_eq!(valid, vec![('あ', 0), ('い', 2)]);
+    /// ```
+    pub fn iter_valid_chars<'a>(&'a self, s: &'a str) -> ValidCharIter<'a> {
+        ValidCharIter {
+            codepoints: self,
+            chars: s.chars(),
+            index: 0,
+        }
+    }
+
+    /// Counts how often each excluded code point occurs in `text`.
+    ///
+    /// Returns `(code point, count)` pairs sorted by descending count, with
+    /// ties broken by ascending code point.  Memory use is proportional to the
+    /// number of *distinct* excluded characters.  For input that arrives in
+    /// chunks, use [`ExcludedHistogram`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042]); // あ
+    /// assert_eq!(
+    ///     cp.excluded_histogram("あいうい"),
+    ///     vec![(0x3044, 2), (0x3046, 1)] // い ×2, う ×1
+    /// );
+    /// ```
+    pub fn excluded_histogram(&self, s: &str) -> Vec<(u32, usize)> {
+        let mut histogram = ExcludedHistogram::new(self);
+        histogram.feed(s);
+        histogram.into_sorted_vec()
+    }
+}
+
+// ── ASCII case-insensitive matching ───────────────────────────────────────────
+
+impl CodePoints {
+    /// Returns `true` if the character belongs to this set, accepting an ASCII
+    /// letter when either of its cases does.
+    fn contains_char_ascii_ci(&self, c: char) -> bool {
+        self.contains_char(c)
+            || (c.is_ascii_alphabetic()
+                && (self.contains_char(c.to_ascii_uppercase())
+                    || self.contains_char(c.to_ascii_lowercase())))
+    }
+
+    /// Like [`contains`](Self::contains), but an ASCII letter is accepted if
+    /// the set contains it in either case.
+    ///
+    /// Every other character, including fullwidth letters, is matched
+    /// exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("ABC-0123456789");
+    /// assert!(cp.contains_ascii_case_insensitive("abc-123"));
+    /// assert!(!cp.contains("abc-123"));
+    /// assert!(!cp.contains_ascii_case_insensitive("ａ")); // fullwidth
+    /// ```
+    pub fn contains_ascii_case_insensitive(&self, s: &str) -> bool {
+        s.chars().all(|c| self.contains_char_ascii_ci(c))
+    }
+
+    /// Like [`first_excluded_with_position`](Self::first_excluded_with_position),
+    /// but ASCII letters are matched case-insensitively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("ABC");
+    /// assert_eq!(cp.first_excluded_ascii_case_insensitive("abcd"), Some((0x64, 3)));
+    /// assert_eq!(cp.first_excluded_ascii_case_insensitive("cab"), None);
+    /// ```
+    pub fn first_excluded_ascii_case_insensitive(&self, s: &str) -> Option<(u32, usize)> {
+        s.chars()
+            .enumerate()
+            .find(|&(_, c)| !self.contains_char_ascii_ci(c))
+            .map(|(i, c)| (c as u32, i))
+    }
+
+    /// Returns a copy of this set that also contains the opposite case of
+    /// every ASCII letter it contains.
+    ///
+    /// Plain [`contains`](Self::contains) on the result behaves like
+    /// [`contains_ascii_case_insensitive`](Self::contains_ascii_case_insensitive)
+    /// on the original.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let folded = CodePoints::from_string("Ab1").fold_ascii_case();
+    /// assert_eq!(folded, CodePoints::from_string("AaBb1"));
+    /// ```
+    #[must_use]
+    pub fn fold_ascii_case(&self) -> CodePoints {
+        let mut codepoints = self.codepoints.clone();
+        for &cp in &self.codepoints {
+            if let Some(c) = char::from_u32(cp).filter(char::is_ascii_alphabetic) {
+                codepoints.insert(c.to_ascii_uppercase() as u32);
+                codepoints.insert(c.to_ascii_lowercase() as u32);
+            }
+        }
+        CodePoints { codepoints }
+    }
+
+    /// Returns a copy of this set that also contains the opposite case of
+    /// every fullwidth Latin letter (`Ａ`–`Ｚ`, `ａ`–`ｚ`) it contains.
+    ///
+    /// The fullwidth counterpart of [`fold_ascii_case`](Self::fold_ascii_case);
+    /// other members are copied unchanged.  For the reverse pipeline —
+    /// normalize the input, then validate strictly — see
+    /// [`to_fullwidth_upper`](crate::conversion::to_fullwidth_upper) and
+    /// [`to_fullwidth_lower`](crate::conversion::to_fullwidth_lower).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let upper_only = CodePoints::from_string("ＡＢＣＤＥＦＧＨＩＪＫＬＭＮＯＰＱＲＳＴＵＶＷＸＹＺ");
+    /// assert!(!upper_only.contains("ｔｏｋｙｏ"));
+    /// assert!(upper_only.fold_fullwidth_case().contains("ｔｏｋｙｏ"));
+    /// ```
+    #[must_use]
+    pub fn fold_fullwidth_case(&self) -> CodePoints {
+        let mut codepoints = self.codepoints.clone();
+        for &cp in &self.codepoints {
+            if let Some(c) = char::from_u32(cp).and_then(crate::conversion::fullwidth_swap_case) {
+                codepoints.insert(c as u32);
+            }
+        }
+        CodePoints { codepoints }
+    }
+}
+
+// ── per-character masks ───────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Returns one entry per character of `s`: `true` if the character
+    /// belongs to this set.
+    ///
+    /// Intended for columnar processing, where violation counts and
+    /// positions are computed downstream from the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あい");
+    /// assert_eq!(cp.mask("あxい"), vec![true, false, true]);
+    /// ```
+    pub fn mask(&self, s: &str) -> Vec<bool> {
+        let mut out = Vec::with_capacity(s.len());
+        self.mask_into(s, &mut out);
+        out
+    }
+
+    /// Like [`mask`](Self::mask), but writes into `out` so its allocation can
+    /// be reused.  `out` is cleared first.
+    pub fn mask_into(&self, s: &str, out: &mut Vec<bool>) {
+        out.clear();
+        out.extend(s.chars().map(|c| self.contains_char(c)));
+    }
+
+    /// Returns one entry per **byte** of `s`: each byte of a character gets
+    /// the character's membership, so the mask lines up with byte offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("a");
+    /// assert_eq!(cp.mask_bytes("aあ"), vec![true, false, false, false]);
+    /// ```
+    pub fn mask_bytes(&self, s: &str) -> Vec<bool> {
+        let mut out = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            let allowed = self.contains_char(c);
+            out.extend(std::iter::repeat(allowed).take(c.len_utf8()));
+        }
+        out
+    }
+}
+
+// ── similarity scores ─────────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Returns the fraction of the characters of `s` that belong to this
+    /// set, from 0.0 (none) to 1.0 (all).
+    ///
+    /// An empty string scores 1.0, as [`Self::contains`] accepts it.  Use
+    /// the score to route text that is *mostly* in a set, e.g. "97% JIS X
+    /// 0208"; see [`Self::weighted_similarity`] to count some characters
+    /// more than others.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あいう");
+    /// assert_eq!(cp.similarity("あいxy"), 0.5);
+    /// assert_eq!(cp.similarity(""), 1.0);
+    /// ```
+    pub fn similarity(&self, s: &str) -> f64 {
+        self.weighted_similarity(s, &|_| 1.0)
+    }
+
+    /// Like [`Self::similarity`], but each character counts `weights(c)`
+    /// instead of 1: the score is the weight of the characters in the set
+    /// over the weight of all characters.
+    ///
+    /// Negative, NaN and infinite weights count as 0.  If the total weight
+    /// is 0 — an empty string, or only zero-weight characters — the score
+    /// is 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let kana = CodePoints::from_string("かなの");
+    /// // Ignore ASCII; count kanji double.
+    /// let weights = |c: char| match c {
+    ///     c if c.is_ascii() => 0.0,
+    ///     '\u{4E00}'..='\u{9FFF}' => 2.0,
+    ///     _ => 1.0,
+    /// };
+    /// assert_eq!(kana.weighted_similarity("かなの 漢", &weights), 0.6); // 3 / (3 + 2)
+    /// assert_eq!(kana.weighted_similarity("   ", &weights), 1.0);
+    /// ```
+    pub fn weighted_similarity(&self, s: &str, weights: &dyn Fn(char) -> f64) -> f64 {
+        let (inside, total) = self.coverage(s, weights);
+        if total > 0.0 {
+            (inside / total).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Sums `weights` over the characters of `s` in one pass, returning the
+    /// weight of those in the set and the total weight.
+    fn coverage(&self, s: &str, weights: &dyn Fn(char) -> f64) -> (f64, f64) {
+        s.chars().fold((0.0, 0.0), |(inside, total), c| {
+            let weight = weights(c);
+            let weight = if weight.is_finite() && weight > 0.0 {
+                weight
+            } else {
+                0.0
+            };
+            if self.contains_char(c) {
+                (inside + weight, total + weight)
+            } else {
+                (inside, total + weight)
+            }
+        })
+    }
+}
+
+// ── excluded-character histogram ──────────────────────────────────────────────
+
+/// Streaming frequency count of characters excluded from a [`CodePoints`] set.
+///
+/// Feed it any number of chunks with [`feed`](Self::feed), combine partial
+/// results from parallel workers with [`merge`](Self::merge), and read the
+/// result with [`into_sorted_vec`](Self::into_sorted_vec).  Memory is bounded
+/// by the number of distinct excluded code points, not by input length.
+///
+/// Chunks are counted independently, so they may be split at any character
+/// boundary.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::codepoints::ExcludedHistogram;
+/// use japanese_codepoints::CodePoints;
+///
+/// let cp = CodePoints::ascii_printable();
+/// let mut a = ExcludedHistogram::new(&cp);
+/// a.feed("abc\t");
+/// let mut b = ExcludedHistogram::new(&cp);
+/// b.feed("\t\n");
+///
+/// a.merge(b);
+/// assert_eq!(a.into_sorted_vec(), vec![(0x09, 2), (0x0A, 1)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExcludedHistogram<'a> {
+    codepoints: &'a CodePoints,
+    counts: HashMap<u32, usize>,
+}
+
+impl<'a> ExcludedHistogram<'a> {
+    /// Creates an empty histogram for characters excluded from `codepoints`.
+    pub fn new(codepoints: &'a CodePoints) -> Self {
+        Self {
+            codepoints,
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Counts the excluded characters of `s`.
+    pub fn feed(&mut self, s: &str) {
+        for (c, _) in self.codepoints.iter_excluded_chars(s) {
+            *self.counts.entry(c as u32).or_insert(0) += 1;
+        }
+    }
+
+    /// Adds the counts of `other` to this histogram.
+    ///
+    /// `other` is expected to have been built against the same set; its
+    /// counts are added as-is.
+    pub fn merge(&mut self, other: ExcludedHistogram<'_>) {
+        for (cp, count) in other.counts {
+            *self.counts.entry(cp).or_insert(0) += count;
+        }
+    }
+
+    /// Returns how many times `code_point` has been seen (0 if never).
+    pub fn count(&self, code_point: u32) -> usize {
+        self.counts.get(&code_point).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of distinct excluded code points seen so far.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if no excluded character has been seen.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the total number of excluded characters seen so far.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Consumes the histogram, returning `(code point, count)` pairs sorted
+    /// by descending count, with ties broken by ascending code point.
+    pub fn into_sorted_vec(self) -> Vec<(u32, usize)> {
+        let mut entries: Vec<_> = self.counts.into_iter().collect();
+        entries.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        entries
+    }
+}
+
+// ── UTF-8 byte input ──────────────────────────────────────────────────────────
+
+/// Error returned when byte input passed to [`CodePoints::contains_utf8`] or
+/// [`CodePoints::first_excluded_utf8`] is not valid UTF-8.
+///
+/// Built from the [`std::str::Utf8Error`] of [`std::str::from_utf8`], so
+/// overlong encodings, encoded surrogates and values above U+10FFFF are all
+/// rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utf8ErrorAt {
+    /// Byte offset of the first byte of the offending sequence.
+    pub byte_offset: usize,
+    /// `true` if the input ended in the middle of an otherwise valid
+    /// multi-byte sequence, `false` if the sequence itself is invalid.
+    pub incomplete: bool,
+}
+
+impl fmt::Display for Utf8ErrorAt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let what = if self.incomplete {
+            "incomplete"
+        } else {
+            "invalid"
+        };
+        write!(f, "{} UTF-8 sequence at byte {}", what, self.byte_offset)
+    }
+}
+
+impl std::error::Error for Utf8ErrorAt {}
+
+impl CodePoints {
+    /// Returns `Ok(true)` if `bytes` is UTF-8 whose every character belongs to
+    /// this set.
+    ///
+    /// There is no need to convert to `&str` first.  The input is decoded
+    /// with [`std::str::from_utf8`] and only its valid prefix is checked, so
+    /// the result reflects whichever comes first: a disallowed character
+    /// (`Ok(false)`) or an invalid sequence (`Err`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あい");
+    /// assert_eq!(cp.contains_utf8("あい".as_bytes()), Ok(true));
+    /// assert_eq!(cp.contains_utf8("あう".as_bytes()), Ok(false));
+    /// assert_eq!(cp.contains_utf8(b"\xE3\x81").unwrap_err().byte_offset, 0);
+    /// ```
+    pub fn contains_utf8(&self, bytes: &[u8]) -> Result<bool, Utf8ErrorAt> {
+        self.first_excluded_utf8(bytes).map(|found| found.is_none())
+    }
+
+    /// Returns the first code point of UTF-8 `bytes` that is **not** in this
+    /// set, together with its byte offset.
+    ///
+    /// Returns `Ok(None)` if every character is allowed, and `Err` if an
+    /// invalid sequence is reached before any disallowed character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あい");
+    /// assert_eq!(cp.first_excluded_utf8("あいう".as_bytes()), Ok(Some((0x3046, 6))));
+    ///
+    /// let err = cp.first_excluded_utf8(b"\xE3\x81\x82\xFF").unwrap_err();
+    /// assert_eq!(err.byte_offset, 3);
+    /// assert!(!err.incomplete);
+    /// ```
+    pub fn first_excluded_utf8(&self, bytes: &[u8]) -> Result<Option<(u32, usize)>, Utf8ErrorAt> {
+        let (valid, error) = match std::str::from_utf8(bytes) {
+            Ok(text) => (text, None),
+            Err(e) => {
+                let (valid, _) = bytes.split_at(e.valid_up_to());
+                let error = Utf8ErrorAt {
+                    byte_offset: e.valid_up_to(),
+                    incomplete: e.error_len().is_none(),
+                };
+                (std::str::from_utf8(valid).unwrap_or_default(), Some(error))
+            }
+        };
+        if let Some((at, c)) = valid.char_indices().find(|&(_, c)| !self.contains_char(c)) {
+            return Ok(Some((c as u32, at)));
+        }
+        error.map_or(Ok(None), Err)
+    }
+}
+
+// ── character iterators ───────────────────────────────────────────────────────
+
+/// Lazy iterator returned by [`CodePoints::iter_excluded_chars`].
+///
+/// Yields `(char, usize)` pairs of excluded characters and their character
+/// index.
+#[derive(Clone, Debug)]
+pub struct ExcludedCharIter<'a> {
+    codepoints: &'a CodePoints,
+    chars: std::str::Chars<'a>,
+    index: usize,
+    byte_index: usize,
+}
+
+impl ExcludedCharIter<'_> {
+    fn next_excluded(&mut self) -> Option<ExcludedChar> {
+        for ch in self.chars.by_ref() {
+            let position = self.index;
+            let byte_index = self.byte_index;
+            self.index += 1;
+            self.byte_index += ch.len_utf8();
+            if !self.codepoints.contains_char(ch) {
+                return Some(ExcludedChar {
+                    ch,
+                    code_point: ch as u32,
+                    position,
+                    byte_index,
+                });
+            }
+        }
+        None
+    }
+}
+
+impl Iterator for ExcludedCharIter<'_> {
+    type Item = (char, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_excluded().map(|e| (e.ch, e.position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.chars.size_hint().1)
+    }
+}
+
+impl std::iter::FusedIterator for ExcludedCharIter<'_> {}
+
+/// Lazy iterator returned by [`CodePoints::iter_excluded`].
+#[derive(Clone, Debug)]
+pub struct ExcludedIter<'a> {
+    inner: ExcludedCharIter<'a>,
+}
+
+impl Iterator for ExcludedIter<'_> {
+    type Item = ExcludedChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_excluded()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl std::iter::FusedIterator for ExcludedIter<'_> {}
+
+/// Lazy iterator returned by [`CodePoints::iter_excluded_unique`].
+///
+/// Remembers the code points already yielded, so its memory grows with the
+/// number of distinct violations, not with the input.
+#[derive(Clone, Debug)]
+pub struct ExcludedUniqueIter<'a> {
+    inner: ExcludedCharIter<'a>,
+    seen: HashSet<u32>,
+}
+
+impl Iterator for ExcludedUniqueIter<'_> {
+    type Item = ExcludedChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(excluded) = self.inner.next_excluded() {
+            if self.seen.insert(excluded.code_point) {
+                return Some(excluded);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl std::iter::FusedIterator for ExcludedUniqueIter<'_> {}
+
+/// Lazy iterator returned by [`CodePoints::iter_valid_chars`].
+///
+/// Yields `(char, usize)` pairs of allowed characters and their character
+/// index.
+#[derive(Clone, Debug)]
+pub struct ValidCharIter<'a> {
+    codepoints: &'a CodePoints,
+    chars: std::str::Chars<'a>,
+    index: usize,
+}
+
+impl Iterator for ValidCharIter<'_> {
+    type Item = (char, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for c in self.chars.by_ref() {
+            let i = self.index;
+            self.index += 1;
+            if self.codepoints.contains_char(c) {
+                return Some((c, i));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.chars.size_hint().1)
+    }
+}
+
+impl std::iter::FusedIterator for ValidCharIter<'_> {}
+
+// ── validation ────────────────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Validates that every character in `text` belongs to this set.
+    ///
+    /// Returns `Ok(())` if all characters are valid.  On failure, returns an
+    /// error that identifies the first offending character and its position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// assert!(cp.validate("hello").is_ok());
+    ///
+    /// let err = cp.validate("hello\0world").unwrap_err();
+    /// assert_eq!(err.code_point, 0);  // NULL
+    /// assert_eq!(err.position, 5);
+    /// ```
+    pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
+        self.validate_as(text, None)
+    }
+
+    /// [`Self::validate`] for any [`ValidateInput`](crate::validation::ValidateInput):
+    /// a string, a single `char` or `u32`, or a `&[char]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// assert!(cp.validate_input('a').is_ok());
+    /// assert_eq!(cp.validate_input('\0').unwrap_err().position, 0);
+    /// ```
+    pub fn validate_input(
+        &self,
+        text: impl crate::validation::ValidateInput,
+    ) -> Result<(), crate::validation::ValidationError> {
+        text.validate_in(self, None)
+    }
+
+    /// [`Self::validate`], reporting failures under the set name `set`.
+    ///
+    /// Stops at the first violation; [`Self::check_with_limit`] continues
+    /// the same scan past it, so the validation macros and [`Self::check`]
+    /// agree on the first violation.
+    pub(crate) fn validate_as(
+        &self,
+        text: &str,
+        set: Option<&'static str>,
+    ) -> Result<(), crate::validation::ValidationError> {
+        match self.first_violation(text) {
+            None => Ok(()),
+            Some(first) => {
+                let err =
+                    crate::validation::ValidationError::new(first.code_point, first.char_index)
+                        .with_byte_index(first.byte_index);
+                crate::validation::report_failure(set, &err, text.len());
+                Err(err)
+            }
+        }
+    }
+
+    /// Returns the first character of `text` outside this set, without
+    /// looking further.
+    fn first_violation(&self, text: &str) -> Option<crate::validation::Violation> {
+        text.char_indices()
+            .enumerate()
+            .find(|&(_, (_, c))| !self.contains_char(c))
+            .map(
+                |(char_index, (byte_index, c))| crate::validation::Violation {
+                    code_point: c as u32,
+                    char_index,
+                    byte_index,
+                },
+            )
+    }
+
+    /// Checks `text` in a single pass and, on failure, returns a full
+    /// [`ExclusionReport`](crate::validation::ExclusionReport).
+    ///
+    /// The report records the first violation (with character and byte
+    /// index), up to
+    /// [`ExclusionReport::DEFAULT_LIMIT`](crate::validation::ExclusionReport::DEFAULT_LIMIT)
+    /// violations with positions, the total violation count and the unique
+    /// excluded code points.  Use [`check_with_limit`](Self::check_with_limit)
+    /// to change how many violations are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// assert!(cp.check("hello").is_ok());
+    ///
+    /// let report = cp.check("aあbあc\0").unwrap_err();
+    /// assert_eq!(report.total_chars, 6);
+    /// assert_eq!(report.violation_count, 3);
+    /// assert_eq!(report.first().char_index, 1);
+    /// assert_eq!(report.first().byte_index, 1);
+    /// assert_eq!(report.unique_excluded, vec![0x0000, 0x3042]);
+    /// assert_eq!(report.valid_prefix_len(), 1);
+    /// ```
+    pub fn check(&self, text: &str) -> Result<(), crate::validation::ExclusionReport> {
+        self.check_with_limit(text, crate::validation::ExclusionReport::DEFAULT_LIMIT)
+    }
+
+    /// Like [`check`](Self::check), but keeps at most `limit` violations in
+    /// [`ExclusionReport::violations`](crate::validation::ExclusionReport::violations).
+    ///
+    /// The first violation is always recorded, even when `limit` is zero, and
+    /// the counts and unique code points always cover the whole input.
+    pub fn check_with_limit(
+        &self,
+        text: &str,
+        limit: usize,
+    ) -> Result<(), crate::validation::ExclusionReport> {
+        use crate::validation::{ExclusionReport, Violation};
+
+        let Some(first) = self.first_violation(text) else {
+            return Ok(());
+        };
+        let mut violations = Vec::new();
+        let mut violation_count = 0;
+        let mut unique = HashSet::new();
+        let mut total_chars = first.char_index;
+
+        // Resume the scan at the first violation.
+        let rest = text.get(first.byte_index..).unwrap_or_default();
+        for (offset, (byte_offset, c)) in rest.char_indices().enumerate() {
+            total_chars += 1;
+            if self.contains_char(c) {
+                continue;
+            }
+            if violations.len() < limit {
+                violations.push(Violation {
+                    code_point: c as u32,
+                    char_index: first.char_index + offset,
+                    byte_index: first.byte_index + byte_offset,
+                });
+            }
+            violation_count += 1;
+            unique.insert(c as u32);
+        }
+
+        let mut unique_excluded: Vec<u32> = unique.into_iter().collect();
+        unique_excluded.sort_unstable();
+        Err(ExclusionReport {
+            total_chars,
+            first,
+            violations,
+            violation_count,
+            unique_excluded,
+            set_fingerprint: None,
+        })
+    }
+}
+
+// ── batch validation ──────────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Returns `true` if every string in `items` passes [`Self::contains`].
+    ///
+    /// Stops at the first failing item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// assert!(cp.contains_all(["Taro", "Hanako"]));
+    /// assert!(!cp.contains_all(vec!["Taro", "花子"]));
+    /// ```
+    pub fn contains_all<'a>(&self, items: impl IntoIterator<Item = &'a str>) -> bool {
+        items.into_iter().all(|item| self.contains(item))
+    }
+
+    /// Returns the index of the first string in `items` that contains a
+    /// character outside this set, with that character's details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// let (index, excluded) = cp.first_failing_item(["ok", "fine", "bad\t"]).unwrap();
+    /// assert_eq!(index, 2);
+    /// assert_eq!(excluded.ch, '\t');
+    /// assert_eq!(excluded.position, 3);
+    /// ```
+    pub fn first_failing_item<'a>(
+        &self,
+        items: impl IntoIterator<Item = &'a str>,
+    ) -> Option<(usize, ExcludedChar)> {
+        items
+            .into_iter()
+            .enumerate()
+            .find_map(|(index, item)| Some((index, self.first_excluded_detail(item)?)))
+    }
+
+    /// Runs [`Self::check`] on every string in `items`, in order.
+    ///
+    /// Valid items cost one scan and no allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// let results = cp.check_all(["a", "あ", "b"]);
+    /// assert!(results[0].is_ok() && results[2].is_ok());
+    /// assert_eq!(results[1].as_ref().unwrap_err().violation_count, 1);
+    /// ```
+    pub fn check_all<'a>(
+        &self,
+        items: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<Result<(), crate::validation::ExclusionReport>> {
+        items.into_iter().map(|item| self.check(item)).collect()
+    }
+}
+
+// ── set operations ────────────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Returns a new set that is the **union** of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let a = CodePoints::new(vec![0x3042]);          // あ
+    /// let b = CodePoints::new(vec![0x3044]);          // い
+    /// assert!(a.union(&b).contains("あい"));
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &CodePoints) -> CodePoints {
+        let mut codepoints = self.codepoints.clone();
+        codepoints.extend(&other.codepoints);
+        CodePoints { codepoints }
+    }
+
+    /// Returns a new set containing only the code points present in **both**
+    /// `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let a = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// let b = CodePoints::new(vec![0x3044, 0x3046]); // い, う
+    /// let i = a.intersection(&b);
+    /// assert!(i.contains("い"));
+    /// assert!(!i.contains("あ"));
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &CodePoints) -> CodePoints {
+        CodePoints {
+            codepoints: self.codepoints.intersection(&other.codepoints),
+        }
+    }
+
+    /// Returns a new set containing code points in `self` but **not** in
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let a = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// let b = CodePoints::new(vec![0x3044, 0x3046]); // い, う
+    /// let d = a.difference(&b);
+    /// assert!(d.contains("あ"));
+    /// assert!(!d.contains("い"));
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &CodePoints) -> CodePoints {
+        CodePoints {
+            codepoints: self.codepoints.difference(&other.codepoints),
+        }
+    }
+
+    /// Returns a new set containing code points that are in **either** `self`
+    /// or `other`, but not in both (symmetric difference / XOR).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let a = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// let b = CodePoints::new(vec![0x3044, 0x3046]); // い, う
+    /// let s = a.symmetric_difference(&b);
+    /// assert!(s.contains("あ"));
+    /// assert!(s.contains("う"));
+    /// assert!(!s.contains("い"));
+    /// ```
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &CodePoints) -> CodePoints {
+        CodePoints {
+            codepoints: self.codepoints.symmetric_difference(&other.codepoints),
+        }
+    }
+
+    /// Compares two versions of a set, returning `(added, removed)`:
+    ///
+    /// * `added` — code points in `other` but not in `self`
+    /// * `removed` — code points in `self` but not in `other`
+    ///
+    /// This is `(other.difference(self), self.difference(other))`; together the
+    /// two halves make up the [symmetric difference](Self::symmetric_difference).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let v1 = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+    /// let v2 = CodePoints::new(vec![0x3044, 0x3046]); // い, う
+    /// let (added, removed) = v1.delta(&v2);
+    /// assert_eq!(added, CodePoints::new(vec![0x3046]));
+    /// assert_eq!(removed, CodePoints::new(vec![0x3042]));
+    /// ```
+    #[must_use]
+    pub fn delta(&self, other: &CodePoints) -> (CodePoints, CodePoints) {
+        (other.difference(self), self.difference(other))
+    }
+
+    /// Splits `self` in one pass into `(in_both, only_in_self)`.
+    ///
+    /// Equivalent to `(self.intersection(other), self.difference(other))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let mine = CodePoints::from_string("あいう");
+    /// let theirs = CodePoints::from_string("いうえ");
+    /// let (shared, only_mine) = mine.partition_by(&theirs);
+    /// assert_eq!(shared, CodePoints::from_string("いう"));
+    /// assert_eq!(only_mine, CodePoints::from_string("あ"));
+    /// ```
+    #[must_use]
+    pub fn partition_by(&self, other: &CodePoints) -> (CodePoints, CodePoints) {
+        let (both, only) = self.codepoints.partition(&other.codepoints);
+        (
+            CodePoints { codepoints: both },
+            CodePoints { codepoints: only },
+        )
+    }
+
+    /// Compares two sets three ways, e.g. to report what an upgrade from one
+    /// policy to another adds and removes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let old = CodePoints::from_string("あいう");
+    /// let new = CodePoints::from_string("いうえ");
+    /// let cmp = old.compare(&new);
+    /// assert_eq!(cmp.only_left, CodePoints::from_string("あ"));
+    /// assert_eq!(cmp.only_right, CodePoints::from_string("え"));
+    /// assert_eq!(cmp.both, CodePoints::from_string("いう"));
+    /// ```
+    pub fn compare(&self, other: &CodePoints) -> SetComparison {
+        let (both, only_left) = self.partition_by(other);
+        SetComparison {
+            only_left,
+            only_right: other.difference(self),
+            both,
+        }
+    }
+
+    /// Returns what changes from `self` (the old set) to `other` (the new
+    /// one), as sorted ranges with a line-per-range [`Display`](fmt::Display)
+    /// for change reviews.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let old = CodePoints::from_string("あいう～");
+    /// let new = CodePoints::from_string("あいうえぉおかー");
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.added(), &[(0x3048, 0x304B), (0x30FC, 0x30FC)]);
+    /// assert_eq!(diff.removed(), &[(0xFF5E, 0xFF5E)]);
+    /// assert_eq!(
+    ///     diff.to_string(),
+    ///     "+ U+3048..U+304B 'え'..'か' (4 chars)\n\
+    ///      + U+30FC 'ー' (1 char)\n\
+    ///      - U+FF5E '～' (1 char)\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &CodePoints) -> SetDiff {
+        SetDiff {
+            added: sorted_ranges(other.difference(self).iter().copied()),
+            removed: sorted_ranges(self.difference(other).iter().copied()),
+        }
+    }
+
+    /// Returns `self` minus every character of `chars`.
+    ///
+    /// Shorthand for `self.difference(&CodePoints::from_string(chars))`, the
+    /// usual way to ban a handful of characters from a standard set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let kana = CodePoints::from_string("あいうゐゑ");
+    /// let modern = kana.without("ゐゑ");
+    /// assert!(modern.contains("あいう"));
+    /// assert_eq!(modern.first_excluded_with_position("あゐ"), Some((0x3090, 1)));
+    /// ```
+    #[must_use]
+    pub fn without(&self, chars: &str) -> CodePoints {
+        let mut codepoints = self.codepoints.clone();
+        for c in chars.chars() {
+            codepoints.remove(&(c as u32));
+        }
+        CodePoints { codepoints }
+    }
+
+    /// Returns `self` minus every code point in `cps`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("abc").without_codepoints(&[0x62]);
+    /// assert_eq!(cp, CodePoints::from_string("ac"));
+    /// ```
+    #[must_use]
+    pub fn without_codepoints(&self, cps: &[u32]) -> CodePoints {
+        let mut codepoints = self.codepoints.clone();
+        for cp in cps {
+            codepoints.remove(cp);
+        }
+        CodePoints { codepoints }
+    }
+
+    /// Returns `self` plus every character of `chars`.
+    ///
+    /// Shorthand for `self.union(&CodePoints::from_string(chars))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable().with("・ー");
+    /// assert!(cp.contains("A・B"));
+    /// ```
+    #[must_use]
+    pub fn with(&self, chars: &str) -> CodePoints {
+        let mut codepoints = self.codepoints.clone();
+        codepoints.extend(chars.chars().map(|c| c as u32));
+        CodePoints { codepoints }
+    }
+
+    /// Returns `self` minus the [registry][crate::registry] set named `name`,
+    /// or `None` if no set is registered under that name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("a\nあ");
+    /// let d = cp.difference_from_named("ascii_all").unwrap();
+    /// assert!(d.contains("あ"));
+    /// assert_eq!(d.len(), 1);
+    /// assert!(cp.difference_from_named("no_such_set").is_none());
+    /// ```
+    pub fn difference_from_named(&self, name: &str) -> Option<CodePoints> {
+        crate::registry::get(name).map(|other| self.difference(other))
+    }
+
+    /// Returns `true` if every code point in `self` is also in `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let small = CodePoints::new(vec![0x3042]);                // あ
+    /// let big   = CodePoints::new(vec![0x3042, 0x3044]);        // あ, い
+    /// assert!(small.is_subset_of(&big));
+    /// assert!(!big.is_subset_of(&small));
+    /// ```
+    pub fn is_subset_of(&self, other: &CodePoints) -> bool {
+        self.codepoints.is_subset(&other.codepoints)
+    }
+
+    /// Returns `true` if every code point in `other` is also in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let big   = CodePoints::new(vec![0x3042, 0x3044]);        // あ, い
+    /// let small = CodePoints::new(vec![0x3042]);                // あ
+    /// assert!(big.is_superset_of(&small));
+    /// ```
+    pub fn is_superset_of(&self, other: &CodePoints) -> bool {
+        self.codepoints.is_superset(&other.codepoints)
+    }
+
+    /// Returns every pair of `sets` that overlap, as `(i, j, intersection)`
+    /// with `i < j` and a non-empty intersection.
+    ///
+    /// Pairs are ordered by `i`, then `j`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let sets = [
+    ///     CodePoints::from_string("あい"),
+    ///     CodePoints::from_string("う"),
+    ///     CodePoints::from_string("いう"),
+    /// ];
+    /// let overlaps = CodePoints::overlapping_sets(&sets);
+    /// assert_eq!(overlaps.len(), 2);
+    /// assert_eq!(overlaps[0].0, 0);
+    /// assert_eq!(overlaps[0].1, 2);
+    /// assert!(overlaps[0].2.contains("い"));
+    /// ```
+    pub fn overlapping_sets(sets: &[CodePoints]) -> Vec<(usize, usize, CodePoints)> {
+        let mut overlaps = Vec::new();
+        for (i, a) in sets.iter().enumerate() {
+            for (j, b) in sets.iter().enumerate().skip(i + 1) {
+                let common = a.intersection(b);
+                if !common.is_empty() {
+                    overlaps.push((i, j, common));
+                }
+            }
+        }
+        overlaps
+    }
+
+    /// Returns `true` if `sets` are pairwise disjoint.
+    ///
+    /// Equivalent to `overlapping_sets(sets).is_empty()` but stops at the
+    /// first shared code point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let sets = [CodePoints::from_string("あ"), CodePoints::from_string("ア")];
+    /// assert!(CodePoints::disjoint_partition(&sets));
+    /// ```
+    pub fn disjoint_partition(sets: &[CodePoints]) -> bool {
+        sets.iter().enumerate().all(|(i, a)| {
+            sets.iter()
+                .skip(i + 1)
+                .all(|b| a.codepoints.is_disjoint(&b.codepoints))
+        })
+    }
+}
+
+/// Three-way comparison of two sets, returned by [`CodePoints::compare`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetComparison {
+    /// Code points only in the left-hand set (`self`).
+    pub only_left: CodePoints,
+    /// Code points only in the right-hand set (`other`).
+    pub only_right: CodePoints,
+    /// Code points in both sets.
+    pub both: CodePoints,
+}
+
+/// Changes between two sets, returned by [`CodePoints::diff`].
+///
+/// `Display` writes one line per range in code-point order, `+` for added
+/// and `-` for removed code points, e.g. `+ U+30FC 'ー' (1 char)`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SetDiff {
+    added: Vec<(u32, u32)>,
+    removed: Vec<(u32, u32)>,
+}
+
+impl SetDiff {
+    /// Code points in the new set only, as sorted inclusive ranges.
+    pub fn added(&self) -> &[(u32, u32)] {
+        &self.added
+    }
+
+    /// Code points in the old set only, as sorted inclusive ranges.
+    pub fn removed(&self) -> &[(u32, u32)] {
+        &self.removed
+    }
+
+    /// Returns `true` if the two sets are equal.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Writes ` 'c'` (or ` 'a'..'b'` for a range), escaping controls;
+/// nothing for non-scalar values.
+fn write_quoted(f: &mut fmt::Formatter<'_>, lo: u32, hi: u32) -> fmt::Result {
+    match (char::from_u32(lo), char::from_u32(hi)) {
+        (Some(lo), Some(hi)) if lo != hi => {
+            write!(f, " '{}'..'{}'", lo.escape_debug(), hi.escape_debug())
+        }
+        (Some(c), _) => write!(f, " '{}'", c.escape_debug()),
+        (None, _) => Ok(()),
+    }
+}
+
+impl fmt::Display for SetDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut lines: Vec<(u32, u32, char)> = self
+            .added
+            .iter()
+            .map(|&(lo, hi)| (lo, hi, '+'))
+            .chain(self.removed.iter().map(|&(lo, hi)| (lo, hi, '-')))
+            .collect();
+        lines.sort_unstable();
+        for (lo, hi, sign) in lines {
+            if lo == hi {
+                write!(f, "{} U+{:04X}", sign, lo)?;
+                write_quoted(f, lo, hi)?;
+                writeln!(f, " (1 char)")?;
+            } else {
+                write!(f, "{} U+{:04X}..U+{:04X}", sign, lo, hi)?;
+                write_quoted(f, lo, hi)?;
+                writeln!(f, " ({} chars)", hi - lo + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sorts code points and collapses runs of consecutive values into
+/// inclusive ranges.
+pub(crate) fn sorted_ranges(codepoints: impl Iterator<Item = u32>) -> Vec<(u32, u32)> {
+    let mut sorted: Vec<u32> = codepoints.collect();
+    sorted.sort_unstable();
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for cp in sorted {
+        match ranges.last_mut() {
+            Some((_, hi)) if *hi + 1 == cp => *hi = cp,
+            _ => ranges.push((cp, cp)),
+        }
+    }
+    ranges
+}
+
+// ── size / iteration ──────────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Returns the number of code points in this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]);
+    /// assert_eq!(cp.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.codepoints.len()
+    }
+
+    /// Returns `true` if the set contains no code points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// assert!(CodePoints::new(vec![]).is_empty());
+    /// assert!(!CodePoints::new(vec![0x41]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.codepoints.is_empty()
+    }
+
+    /// Returns an iterator over the code points in this set.
+    ///
+    /// > **Note:** iteration order is **not** guaranteed, except for
+    /// > [ordered sets](Self::new_ordered), which iterate in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0x3044]);
+    /// assert_eq!(cp.iter().count(), 2);
+    ///
+    /// let ordered = CodePoints::new_ordered(vec![0x3044, 0x41, 0x3042]);
+    /// assert_eq!(ordered.iter().copied().collect::<Vec<_>>(), vec![0x41, 0x3042, 0x3044]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        self.codepoints.iter()
+    }
+
+    /// Returns an iterator over the members of this set as `char`s.
+    ///
+    /// Values that are not Unicode scalar values (surrogates, or anything
+    /// above U+10FFFF) can only enter a set through [`Self::new`] or
+    /// [`Self::from_slice`]; they are silently skipped.  Use
+    /// [`Self::try_chars`] to see them.
+    ///
+    /// > **Note:** iteration order is **not** guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x3042, 0xD800]); // あ, lone surrogate
+    /// assert_eq!(cp.chars().collect::<Vec<_>>(), vec!['あ']);
+    /// ```
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.codepoints.iter().filter_map(|&cp| char::from_u32(cp))
+    }
+
+    /// Returns an iterator over the members of this set, yielding `Err(cp)`
+    /// for values that are not Unicode scalar values.
+    ///
+    /// > **Note:** iteration order is **not** guaranteed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0xD800]);
+    /// assert_eq!(cp.try_chars().collect::<Vec<_>>(), vec![Err(0xD800)]);
+    /// ```
+    pub fn try_chars(&self) -> impl Iterator<Item = Result<char, u32>> + '_ {
+        self.codepoints
+            .iter()
+            .map(|&cp| char::from_u32(cp).ok_or(cp))
+    }
+
+    /// Concatenates every member of this set into a string, in ascending
+    /// code-point order.
+    ///
+    /// Invalid scalar values are skipped as in [`Self::chars`].  Handy for
+    /// generating documentation tables or a literal character class.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("いあう");
+    /// assert_eq!(cp.to_string_of_all(), "あいう");
+    /// ```
+    pub fn to_string_of_all(&self) -> String {
+        let mut chars: Vec<char> = self.chars().collect();
+        chars.sort_unstable();
+        chars.into_iter().collect()
+    }
+
+    /// Returns how many code points of this set fall within `start..=end`;
+    /// the same as [`count_in`](Self::count_in)`(start..=end)`.
+    ///
+    /// No intermediate set is built.  The cost is `O(log n)` for the ordered
+    /// backends, which binary-search the bounds; a [`Backend::HashSet`] costs
+    /// `O(min(end - start, len))`, probing each value of a narrow range and
+    /// scanning the set once for a wide one.  An empty range (`start > end`)
+    /// yields `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x41, 0x42, 0x3042]); // A, B, あ
+    /// assert_eq!(cp.count_codepoints_in_range(0x00, 0x7F), 2);
+    /// assert_eq!(cp.count_codepoints_in_range(0x3040, 0x309F), 1);
+    /// ```
+    pub fn count_codepoints_in_range(&self, start: u32, end: u32) -> usize {
+        self.count_in(start..=end)
+    }
+
+    /// Returns `true` if at least one code point of this set falls within
+    /// `start..=end`.
+    ///
+    /// This is the short-circuiting counterpart of
+    /// [`Self::count_codepoints_in_range`], with the same cost bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::new(vec![0x41, 0x3042]); // A, あ
+    /// assert!(cp.has_any_in_range(0x3040, 0x309F));
+    /// assert!(!cp.has_any_in_range(0x30A0, 0x30FF));
+    /// ```
+    pub fn has_any_in_range(&self, start: u32, end: u32) -> bool {
+        start <= end && self.codepoints.has_any_in(start, end)
+    }
+
+    /// Returns the members of this set that fall within `range`, with the
+    /// same backend.
+    ///
+    /// Any [`RangeBounds`] works, including open-ended ones such as
+    /// `..=0xFFFF` (the BMP) or `0x10000..`.  The `SortedVec` and `Bitmap`
+    /// backends binary-search the bounds and `BTreeSet` walks only the
+    /// range; a `HashSet` is scanned once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("aあ𠮷");
+    /// assert_eq!(cp.restrict_to(..=0xFFFF), CodePoints::from_string("aあ"));
+    /// assert_eq!(cp.restrict_to(0x80..0x10000), CodePoints::from_string("あ"));
+    /// assert!(cp.restrict_to(0x10FFFF..).is_empty());
+    /// ```
+    #[must_use]
+    pub fn restrict_to(&self, range: impl RangeBounds<u32>) -> CodePoints {
+        let codepoints = match inclusive_bounds(&range) {
+            Some((lo, hi)) => self.codepoints.restrict(lo, hi),
+            None => self.codepoints.collect_like(None),
+        };
+        Self { codepoints }
+    }
+
+    /// Returns how many members of this set fall within `range`.
+    ///
+    /// Like [`restrict_to`](Self::restrict_to) without building the set;
+    /// `O(log n)` for the ordered backends.  [`count_codepoints_in_range`]
+    /// is the inclusive `start, end` form.
+    ///
+    /// [`count_codepoints_in_range`]: Self::count_codepoints_in_range
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("aあ𠮷");
+    /// assert_eq!(cp.count_in(..0x10000), 2);
+    /// assert_eq!(cp.count_in(..), cp.len());
+    /// ```
+    pub fn count_in(&self, range: impl RangeBounds<u32>) -> usize {
+        inclusive_bounds(&range).map_or(0, |(lo, hi)| self.codepoints.count_in(lo, hi))
+    }
+
+    /// Returns the lowest Unicode scalar value in `range` that is **not** in
+    /// this set, or `None` if the set covers the whole range.
+    ///
+    /// Surrogates are skipped.  Handy in tests and examples that need "some
+    /// character outside the set" without hard-coding one that a data
+    /// update could make valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あいう");
+    /// let outside = cp.find_excluded_in(0x3042..=0x3093).unwrap();
+    /// assert_eq!(outside, 'ぃ');
+    /// assert!(!cp.contains(&outside.to_string()));
+    /// assert_eq!(cp.find_excluded_in(0x3042..=0x3042), None);
+    /// ```
+    pub fn find_excluded_in(&self, range: RangeInclusive<u32>) -> Option<char> {
+        (*range.start()..=(*range.end()).min(char::MAX as u32))
+            .filter_map(char::from_u32)
+            .find(|&c| !self.codepoints.contains(&(c as u32)))
+    }
+
+    /// Returns the lowest Unicode scalar value in `range` that is in this
+    /// set, or `None` if there is none.
+    ///
+    /// The dual of [`Self::find_excluded_in`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("Aあい");
+    /// assert_eq!(cp.find_included_in(0x3000..=0x30FF), Some('あ'));
+    /// assert_eq!(cp.find_included_in(0..=0x10FFFF), Some('A'));
+    /// assert_eq!(cp.find_included_in(0x30A0..=0x30FF), None);
+    /// ```
+    pub fn find_included_in(&self, range: RangeInclusive<u32>) -> Option<char> {
+        let (start, end) = (*range.start(), (*range.end()).min(char::MAX as u32));
+        if start > end {
+            return None;
+        }
+        if ((end - start) as usize) < self.codepoints.len() {
+            (start..=end)
+                .filter_map(char::from_u32)
+                .find(|&c| self.codepoints.contains(&(c as u32)))
+        } else {
+            self.codepoints
+                .iter()
+                .filter(|cp| (start..=end).contains(cp))
+                .filter_map(|&cp| char::from_u32(cp))
+                .min()
+        }
+    }
+}
+
+// ── memory introspection ──────────────────────────────────────────────────────
+
+/// How a [`CodePoints`] stores its members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Representation {
+    /// A `HashSet<u32>`; the default.  `O(1)` lookups, unspecified
+    /// iteration order.
+    HashSet,
+    /// A `BTreeSet<u32>`, used by ordered sets (see
+    /// [`CodePoints::new_ordered`]).
+    BTreeSet,
+    /// A sorted `Vec<u32>`: `O(log n)` lookups by binary search, the
+    /// smallest footprint, `O(n)` inserts.
+    SortedVec,
+    /// One bit per code point up to the largest member, alongside the
+    /// sorted members: `O(1)` lookups without hashing, at a size set by the
+    /// largest code point rather than the member count.
+    Bitmap,
+    /// A sorted table file mapped read-only (see
+    /// [`CodePoints::map_sorted_file`]): `O(log n)` lookups, no heap
+    /// copy.  Only produced by loading a file; selecting it with
+    /// [`CodePoints::with_backend`] stores a sorted `Vec` instead.
+    #[cfg(feature = "mmap")]
+    Mapped,
+}
+
+/// The storage backend selected with [`CodePoints::with_backend`] and
+/// [`CodePoints::convert_backend`].
+pub type Backend = Representation;
+
+/// Storage statistics returned by [`CodePoints::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodePointsStats {
+    /// Number of code points in the set.
+    pub len: usize,
+    /// Number of code points the set can hold without reallocating.
+    pub capacity: usize,
+    /// The storage in use.
+    pub representation: Representation,
+}
+
+impl CodePoints {
+    /// Shrinks the storage as much as possible while keeping every member.
+    ///
+    /// Useful for long-lived sets built by composing other sets.
+    pub fn shrink_to_fit(&mut self) {
+        self.codepoints.shrink_to_fit();
+    }
+
+    /// Returns an estimate of the memory used by this set, in bytes.
+    ///
+    /// The estimate is the size of the struct plus, for every slot of
+    /// capacity, one `u32` and one byte of hash-table control data.  Actual
+    /// usage may be somewhat higher because the table rounds its bucket
+    /// count up to a power of two.  For [ordered sets](Self::new_ordered)
+    /// the per-member cost is one `u32`, not counting tree-node overhead;
+    /// [`Backend::SortedVec`] costs one `u32` per slot and
+    /// [`Backend::Bitmap`] adds one bit per code point below the largest
+    /// member.  Mapped file pages (`mmap` feature) are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let mut cp = CodePoints::new(vec![0x41; 1000]); // 1 member, room for 1000
+    /// let before = cp.memory_usage();
+    /// cp.shrink_to_fit();
+    /// assert!(cp.memory_usage() < before);
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        let (per_slot, extra) = match &self.codepoints {
+            Storage::Hash(_) => (std::mem::size_of::<u32>() + 1, 0),
+            Storage::Ordered(_) | Storage::Sorted(_) => (std::mem::size_of::<u32>(), 0),
+            Storage::Bitmap(bitmap) => (
+                std::mem::size_of::<u32>(),
+                bitmap.words.capacity() * std::mem::size_of::<u64>(),
+            ),
+            #[cfg(feature = "mmap")]
+            Storage::Mapped(_) => (0, 0),
+        };
+        std::mem::size_of::<Self>() + self.codepoints.capacity() * per_slot + extra
+    }
+
+    /// Returns the length, capacity and storage representation of this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::codepoints::Representation;
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let stats = CodePoints::ascii_printable().stats();
+    /// assert_eq!(stats.len, 95);
+    /// assert!(stats.capacity >= 95);
+    /// assert_eq!(stats.representation, Representation::HashSet);
+    /// ```
+    pub fn stats(&self) -> CodePointsStats {
+        CodePointsStats {
+            len: self.codepoints.len(),
+            capacity: self.codepoints.capacity(),
+            representation: self.codepoints.representation(),
+        }
+    }
+}
+
+// ── Unicode block analysis ────────────────────────────────────────────────────
+
+/// Key used by [`CodePoints::group_by_unicode_block`] for code points in
+/// private-use or unassigned blocks.
+pub const OTHER_BLOCK: &str = "Other";
+
+/// Returns the name of the Unicode block containing `cp`, or `None` for code
+/// points outside every block.
+fn unicode_block(cp: u32) -> Option<&'static str> {
+    let id = UnicodeBlockId::of(cp);
+    (id != UnicodeBlockId::OUTSIDE).then(|| id.name())
+}
+
+impl CodePoints {
+    /// Partitions this set by Unicode block (Unicode 14.0 `Blocks.txt`).
+    ///
+    /// Keys are block names such as `"Hiragana"` or
+    /// `"CJK Unified Ideographs"`.  Code points in private-use blocks or
+    /// outside any block are grouped under [`OTHER_BLOCK`] (`"Other"`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あいア漢\u{E000}");
+    /// let blocks = cp.group_by_unicode_block();
+    /// assert_eq!(blocks["Hiragana"].len(), 2);
+    /// assert_eq!(blocks["Katakana"].len(), 1);
+    /// assert!(blocks["CJK Unified Ideographs"].contains("漢"));
+    /// assert!(blocks["Other"].contains("\u{E000}"));
+    /// ```
+    pub fn group_by_unicode_block(&self) -> HashMap<&'static str, CodePoints> {
+        let mut groups: HashMap<&'static str, CodePoints> = HashMap::new();
+        for &cp in &self.codepoints {
+            let name = match unicode_block(cp) {
+                Some(name) if !name.contains("Private Use") => name,
+                _ => OTHER_BLOCK,
+            };
+            groups
+                .entry(name)
+                .or_insert_with(|| CodePoints::new(vec![]))
+                .codepoints
+                .insert(cp);
+        }
+        groups
+    }
+
+    /// Counts the members of this set in each Unicode block, in code-point
+    /// order of the blocks.
+    ///
+    /// Unlike [`group_by_unicode_block`](Self::group_by_unicode_block),
+    /// private-use blocks are counted under their own ids; only members
+    /// outside every block fall under [`UnicodeBlockId::OUTSIDE`], which
+    /// sorts last.  The counts add up to [`len`](Self::len).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{Block, CodePoints, UnicodeBlockId};
+    ///
+    /// let cp = CodePoints::from_string("あいう漢");
+    /// let spans: Vec<(&str, usize)> = cp
+    ///     .block_spans()
+    ///     .into_iter()
+    ///     .map(|(id, n)| (id.name(), n))
+    ///     .collect();
+    /// assert_eq!(spans, [("Hiragana", 3), ("CJK Unified Ideographs", 1)]);
+    /// assert_eq!(cp.block_spans()[0].0, UnicodeBlockId::from(Block::Hiragana));
+    /// ```
+    pub fn block_spans(&self) -> Vec<(UnicodeBlockId, usize)> {
+        let mut counts: BTreeMap<UnicodeBlockId, usize> = BTreeMap::new();
+        for &cp in &self.codepoints {
+            *counts.entry(UnicodeBlockId::of(cp)).or_insert(0) += 1;
+        }
+        counts.into_iter().collect()
+    }
+
+    /// Returns `true` if every member lies in the same Unicode block.
+    ///
+    /// The empty set spans no block and returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// assert!(CodePoints::from_string("あいう").is_single_block());
+    /// assert!(!CodePoints::from_string("あいう亜").is_single_block());
+    /// assert!(!CodePoints::new(vec![]).is_single_block());
+    /// ```
+    pub fn is_single_block(&self) -> bool {
+        let mut members = self.codepoints.iter();
+        match members.next() {
+            Some(&first) => {
+                let block = UnicodeBlockId::of(first);
+                members.all(|&cp| UnicodeBlockId::of(cp) == block)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the members that lie in blocks holding fewer than
+    /// `threshold` of this set's members.
+    ///
+    /// A handful of stray code points next to a large, coherent block is
+    /// usually a typo in a code-point literal; this picks them out for a
+    /// human to review.  A `threshold` of 0 or 1 never reports anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let mut kana: Vec<u32> = (0x3041..=0x3096).collect();
+    /// kana.push(0x4E9C); // 亜: meant to be U+3042?
+    /// let cp = CodePoints::new(kana);
+    /// assert_eq!(cp.outliers(3), CodePoints::from_string("亜"));
+    /// ```
+    #[must_use]
+    pub fn outliers(&self, threshold: usize) -> CodePoints {
+        let sparse: HashSet<UnicodeBlockId> = self
+            .block_spans()
+            .into_iter()
+            .filter(|&(_, n)| n < threshold)
+            .map(|(id, _)| id)
+            .collect();
+        if sparse.is_empty() {
+            return CodePoints::new(vec![]);
+        }
+        Self {
+            codepoints: self.codepoints.collect_like(
+                self.iter()
+                    .copied()
+                    .filter(|&cp| sparse.contains(&UnicodeBlockId::of(cp))),
+            ),
+        }
+    }
+}
+
+/// Identifies one block of the Unicode 14.0 block table, as reported by
+/// [`CodePoints::block_spans`].
+///
+/// Ids order like the blocks' code points, with [`OUTSIDE`](Self::OUTSIDE)
+/// last.  Convert a [`Block`] with `UnicodeBlockId::from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UnicodeBlockId(usize);
+
+impl UnicodeBlockId {
+    /// Code points outside every block: unassigned gaps and values above
+    /// U+10FFFF.
+    pub const OUTSIDE: UnicodeBlockId = UnicodeBlockId(unicode_blocks::UNICODE_BLOCKS.len());
+
+    /// Returns the block containing `cp`, or [`OUTSIDE`](Self::OUTSIDE).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::UnicodeBlockId;
+    ///
+    /// assert_eq!(UnicodeBlockId::of(0x3042).name(), "Hiragana");
+    /// assert_eq!(UnicodeBlockId::of(0x2FE0), UnicodeBlockId::OUTSIDE);
+    /// ```
+    pub fn of(cp: u32) -> UnicodeBlockId {
+        let blocks = unicode_blocks::UNICODE_BLOCKS;
+        let i = blocks.partition_point(|&(_, last, _)| last < cp);
+        match blocks.get(i) {
+            Some(&(first, _, _)) if first <= cp => UnicodeBlockId(i),
+            _ => Self::OUTSIDE,
+        }
+    }
+
+    /// Returns the block name from `Blocks.txt`, or [`OTHER_BLOCK`] for
+    /// [`OUTSIDE`](Self::OUTSIDE).
+    pub fn name(self) -> &'static str {
+        unicode_blocks::UNICODE_BLOCKS
+            .get(self.0)
+            .map_or(OTHER_BLOCK, |&(_, _, name)| name)
+    }
+
+    /// Returns the first and last code point of the block (inclusive), or
+    /// `None` for [`OUTSIDE`](Self::OUTSIDE).
+    pub fn range(self) -> Option<(u32, u32)> {
+        unicode_blocks::UNICODE_BLOCKS
+            .get(self.0)
+            .map(|&(first, last, _)| (first, last))
+    }
+}
+
+impl From<Block> for UnicodeBlockId {
+    fn from(block: Block) -> Self {
+        UnicodeBlockId::of(block.range().0)
+    }
+}
+
+impl fmt::Display for UnicodeBlockId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Japan-relevant Unicode blocks accepted by
+/// [`CodePoints::from_unicode_block`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Block {
+    /// U+3000–U+303F
+    CjkSymbolsAndPunctuation,
+    /// U+3040–U+309F
+    Hiragana,
+    /// U+30A0–U+30FF
+    Katakana,
+    /// U+31F0–U+31FF
+    KatakanaPhoneticExtensions,
+    /// U+3400–U+4DBF
+    CjkUnifiedIdeographsExtensionA,
+    /// U+4E00–U+9FFF
+    CjkUnifiedIdeographs,
+    /// U+FF00–U+FFEF
+    HalfwidthAndFullwidthForms,
+    /// U+20000–U+2A6DF
+    CjkUnifiedIdeographsExtensionB,
+}
+
+impl Block {
+    /// Every variant, in code-point order.
+    pub const ALL: [Block; 8] = [
+        Block::CjkSymbolsAndPunctuation,
+        Block::Hiragana,
+        Block::Katakana,
+        Block::KatakanaPhoneticExtensions,
+        Block::CjkUnifiedIdeographsExtensionA,
+        Block::CjkUnifiedIdeographs,
+        Block::HalfwidthAndFullwidthForms,
+        Block::CjkUnifiedIdeographsExtensionB,
+    ];
+
+    /// Returns the first and last code point of the block (inclusive).
+    pub const fn range(self) -> (u32, u32) {
+        match self {
+            Block::CjkSymbolsAndPunctuation => (0x3000, 0x303F),
+            Block::Hiragana => (0x3040, 0x309F),
+            Block::Katakana => (0x30A0, 0x30FF),
+            Block::KatakanaPhoneticExtensions => (0x31F0, 0x31FF),
+            Block::CjkUnifiedIdeographsExtensionA => (0x3400, 0x4DBF),
+            Block::CjkUnifiedIdeographs => (0x4E00, 0x9FFF),
+            Block::HalfwidthAndFullwidthForms => (0xFF00, 0xFFEF),
+            Block::CjkUnifiedIdeographsExtensionB => (0x20000, 0x2A6DF),
+        }
+    }
+
+    /// Returns the block name as used in `Blocks.txt` and as a key of
+    /// [`CodePoints::group_by_unicode_block`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            Block::CjkSymbolsAndPunctuation => "CJK Symbols and Punctuation",
+            Block::Hiragana => "Hiragana",
+            Block::Katakana => "Katakana",
+            Block::KatakanaPhoneticExtensions => "Katakana Phonetic Extensions",
+            Block::CjkUnifiedIdeographsExtensionA => "CJK Unified Ideographs Extension A",
+            Block::CjkUnifiedIdeographs => "CJK Unified Ideographs",
+            Block::HalfwidthAndFullwidthForms => "Halfwidth and Fullwidth Forms",
+            Block::CjkUnifiedIdeographsExtensionB => "CJK Unified Ideographs Extension B",
+        }
+    }
+}
+
+impl CodePoints {
+    /// Creates a set holding every code point of a Unicode block, including
+    /// code points the block leaves unassigned.
+    ///
+    /// Handy for comparing a JIS set against the Unicode framing of the same
+    /// script.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{Block, CodePoints};
+    ///
+    /// let block = CodePoints::from_unicode_block(Block::Hiragana);
+    /// assert_eq!(block.len(), 96);
+    ///
+    /// // Characters outside the main CJK block.
+    /// let kanji = CodePoints::from_string("漢㐂");
+    /// let outside = kanji.difference(&CodePoints::from_unicode_block(Block::CjkUnifiedIdeographs));
+    /// assert_eq!(outside, CodePoints::from_string("㐂"));
+    /// ```
+    pub fn from_unicode_block(block: Block) -> Self {
+        let (first, last) = block.range();
+        Self {
+            codepoints: (first..=last).collect(),
+        }
+    }
+}
+
+impl CodePoints {
+    /// Returns the members of this set that lie in `block`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{Block, CodePoints};
+    ///
+    /// let cp = CodePoints::from_string("あいア漢");
+    /// assert_eq!(cp.subset_matching_block(Block::Hiragana), CodePoints::from_string("あい"));
+    /// ```
+    #[must_use]
+    pub fn subset_matching_block(&self, block: Block) -> CodePoints {
+        let (first, last) = block.range();
+        Self {
+            codepoints: self.codepoints.collect_like(
+                self.iter()
+                    .copied()
+                    .filter(|cp| (first..=last).contains(cp)),
+            ),
+        }
+    }
+}
+
+// ── character classes ─────────────────────────────────────────────────────────
+
+/// Coarse class of a character, for summarizing what a set contains.
+///
+/// Classes follow Unicode blocks rather than JIS rows, so they are
+/// available without any character-set feature:
+///
+/// | Class | Code points |
+/// |---|---|
+/// | `Hiragana` | Hiragana block (U+3040–U+309F) |
+/// | `Katakana` | Katakana and Katakana Phonetic Extensions blocks, halfwidth katakana |
+/// | `Kanji` | CJK Unified Ideographs (through Extension G) and Compatibility Ideographs |
+/// | `Latin` | ASCII and fullwidth letters |
+/// | `Digit` | ASCII and fullwidth digits |
+/// | `Greek` | Greek and Coptic block |
+/// | `Cyrillic` | Cyrillic block |
+/// | `Symbol` | Other printable ASCII and Latin-1, U+2000–U+2BFF, CJK Symbols and Punctuation, other fullwidth forms |
+/// | `Uncategorized` | Everything else (controls, private use, emoji …) |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum CharClass {
+    /// Hiragana, including the sound marks ゛゜ and iteration marks ゝゞ.
+    Hiragana,
+    /// Full- and halfwidth katakana, including ー.
+    Katakana,
+    /// CJK ideographs.
+    Kanji,
+    /// Latin letters.
+    Latin,
+    /// Decimal digits.
+    Digit,
+    /// Greek letters.
+    Greek,
+    /// Cyrillic letters.
+    Cyrillic,
+    /// Punctuation, spaces and symbols.
+    Symbol,
+    /// Anything not covered by the other classes.
+    Uncategorized,
+}
+
+impl CharClass {
+    /// Every variant, in the order used by [`CodePoints::decompose`].
+    pub const ALL: [CharClass; 9] = [
+        CharClass::Hiragana,
+        CharClass::Katakana,
+        CharClass::Kanji,
+        CharClass::Latin,
+        CharClass::Digit,
+        CharClass::Greek,
+        CharClass::Cyrillic,
+        CharClass::Symbol,
+        CharClass::Uncategorized,
+    ];
+
+    /// Returns the class of code point `cp`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CharClass;
+    ///
+    /// assert_eq!(CharClass::of('ｱ' as u32), CharClass::Katakana);
+    /// assert_eq!(CharClass::of('９' as u32), CharClass::Digit);
+    /// assert_eq!(CharClass::of('、' as u32), CharClass::Symbol);
+    /// assert_eq!(CharClass::of(0xE000), CharClass::Uncategorized);
+    /// ```
+    pub fn of(cp: u32) -> CharClass {
+        match cp {
+            0x30..=0x39 | 0xFF10..=0xFF19 => CharClass::Digit,
+            0x41..=0x5A | 0x61..=0x7A | 0xFF21..=0xFF3A | 0xFF41..=0xFF5A => CharClass::Latin,
+            0x20..=0x7E | 0xA0..=0xFF | 0x2000..=0x2BFF | 0x3000..=0x303F => CharClass::Symbol,
+            0x3040..=0x309F => CharClass::Hiragana,
+            0x30A0..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => CharClass::Katakana,
+            0xFF01..=0xFF65 | 0xFFE0..=0xFFEE => CharClass::Symbol,
+            0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x20000..=0x3134F => {
+                CharClass::Kanji
+            }
+            0x0370..=0x03FF => CharClass::Greek,
+            0x0400..=0x04FF => CharClass::Cyrillic,
+            _ => CharClass::Uncategorized,
+        }
+    }
+
+    /// Returns the stable lowercase name of the class, e.g. `"hiragana"`;
+    /// [`str::parse`] accepts it back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CharClass;
+    ///
+    /// assert_eq!(CharClass::Kanji.as_str(), "kanji");
+    /// assert_eq!("kanji".parse(), Ok(CharClass::Kanji));
+    /// ```
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            CharClass::Hiragana => "hiragana",
+            CharClass::Katakana => "katakana",
+            CharClass::Kanji => "kanji",
+            CharClass::Latin => "latin",
+            CharClass::Digit => "digit",
+            CharClass::Greek => "greek",
+            CharClass::Cyrillic => "cyrillic",
+            CharClass::Symbol => "symbol",
+            CharClass::Uncategorized => "uncategorized",
+        }
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for CharClass {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CharClass::ALL
+            .into_iter()
+            .find(|class| class.as_str() == s)
+            .ok_or_else(|| ParseNameError::new("character class", s))
+    }
+}
+
+/// Error returned when parsing a name that no variant has, e.g. with
+/// `"..".parse::<CharClass>()`.
+///
+/// The names are those returned by the `as_str` methods of [`CharClass`],
+/// [`ValidationErrorKind`](crate::ValidationErrorKind) and the other
+/// name-carrying enums.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNameError {
+    kind: &'static str,
+    name: String,
+}
+
+impl ParseNameError {
+    pub(crate) fn new(kind: &'static str, name: &str) -> Self {
+        Self {
+            kind,
+            name: name.to_string(),
+        }
+    }
+
+    /// Returns the name that was not recognized.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} `{}`", self.kind, self.name)
+    }
+}
+
+impl std::error::Error for ParseNameError {}
+
+impl CodePoints {
+    /// Counts the members of each [`CharClass`], in [`CharClass::ALL`]
+    /// order; classes with no members are omitted.
+    ///
+    /// Together with [`subset_of_class`](Self::subset_of_class) this lets a
+    /// configuration be summarized, or two configurations compared class by
+    /// class.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あいアイ漢、\u{E000}");
+    /// let summary: Vec<String> = cp
+    ///     .decompose()
+    ///     .iter()
+    ///     .map(|(class, n)| format!("{} {}", n, class))
+    ///     .collect();
+    /// assert_eq!(
+    ///     summary.join(", "),
+    ///     "2 hiragana, 2 katakana, 1 kanji, 1 symbol, 1 uncategorized"
+    /// );
+    /// ```
+    pub fn decompose(&self) -> Vec<(CharClass, usize)> {
+        let mut counts = [0usize; CharClass::ALL.len()];
+        for &cp in self.iter() {
+            if let Some(count) = counts.get_mut(CharClass::of(cp) as usize) {
+                *count += 1;
+            }
+        }
+        CharClass::ALL
+            .into_iter()
+            .zip(counts)
+            .filter(|&(_, n)| n > 0)
+            .collect()
+    }
+
+    /// Returns the members of this set of class `class`.
+    #[must_use]
+    pub fn subset_of_class(&self, class: CharClass) -> CodePoints {
+        Self {
+            codepoints: self.codepoints.collect_like(
+                self.iter()
+                    .copied()
+                    .filter(|&cp| CharClass::of(cp) == class),
+            ),
+        }
+    }
+
+    /// Returns up to `max` members of class [`CharClass::Uncategorized`] in
+    /// ascending order, for listing alongside [`decompose`](Self::decompose).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あ\u{E002}\u{E000}\u{E001}");
+    /// assert_eq!(cp.uncategorized(2), vec![0xE000, 0xE001]);
+    /// ```
+    pub fn uncategorized(&self, max: usize) -> Vec<u32> {
+        let mut members: Vec<u32> = self
+            .iter()
+            .copied()
+            .filter(|&cp| CharClass::of(cp) == CharClass::Uncategorized)
+            .collect();
+        members.sort_unstable();
+        members.truncate(max);
+        members
+    }
+}
+
+// ── compact serialization ─────────────────────────────────────────────────────
+
+/// Magic number at the start of [`CodePoints::serialize_compact`] output.
+const COMPACT_MAGIC: [u8; 4] = *b"JCP1";
+
+/// Size of the magic number plus the code-point count.
+const COMPACT_HEADER_LEN: usize = 8;
+
+/// Error returned by [`CodePoints::deserialize_compact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializeError {
+    /// The data does not start with the expected magic number.
+    InvalidMagic,
+    /// The data length does not match the header.
+    LengthMismatch {
+        /// Length in bytes implied by the header.
+        expected: usize,
+        /// Actual length of the data in bytes.
+        actual: usize,
+    },
+    /// A stored value is not a valid Unicode scalar value.
+    InvalidCodePoint(u32),
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeserializeError::InvalidMagic => f.write_str("invalid magic number"),
+            DeserializeError::LengthMismatch { expected, actual } => write!(
+                f,
+                "expected {} bytes of compact data, found {}",
+                expected, actual
+            ),
+            DeserializeError::InvalidCodePoint(cp) => {
+                write!(f, "invalid code point U+{:04X}", cp)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl CodePoints {
+    /// Serializes this set into a compact binary form that does not require
+    /// `serde`.
+    ///
+    /// The layout is a 4-byte magic number (`JCP1`), the number of code points
+    /// as a little-endian `u32`, then every code point in ascending order as a
+    /// little-endian `u32`.  The output is deterministic, so equal sets always
+    /// serialize to identical bytes.
+    ///
+    /// Only sets of Unicode scalar values round-trip.  Surrogates and values
+    /// above U+10FFFF, which [`Self::new`] accepts, are written unchanged, and
+    /// [`Self::deserialize_compact`] rejects them with
+    /// [`DeserializeError::InvalidCodePoint`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あい");
+    /// let bytes = cp.serialize_compact();
+    /// assert_eq!(bytes.len(), 8 + 2 * 4);
+    /// assert_eq!(CodePoints::deserialize_compact(&bytes).unwrap(), cp);
+    /// ```
+    pub fn serialize_compact(&self) -> Vec<u8> {
+        let mut sorted: Vec<u32> = self.codepoints.iter().copied().collect();
+        sorted.sort_unstable();
+
+        let mut out = Vec::with_capacity(COMPACT_HEADER_LEN + sorted.len() * 4);
+        out.extend_from_slice(&COMPACT_MAGIC);
+        out.extend_from_slice(&(sorted.len() as u32).to_le_bytes());
+        for cp in sorted {
+            out.extend_from_slice(&cp.to_le_bytes());
+        }
+        out
+    }
+
+    /// Reconstructs a set from the output of [`Self::serialize_compact`].
+    ///
+    /// The magic number and total length are checked before any code point
+    /// is read.
+    ///
+    /// # Errors
+    ///
+    /// * [`DeserializeError::InvalidMagic`] if the data has the wrong header.
+    /// * [`DeserializeError::LengthMismatch`] if the data is truncated or has
+    ///   trailing bytes.
+    /// * [`DeserializeError::InvalidCodePoint`] if a stored value is not a
+    ///   Unicode scalar value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::codepoints::DeserializeError;
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let bytes = CodePoints::from_string("あ").serialize_compact();
+    /// assert!(matches!(
+    ///     CodePoints::deserialize_compact(&bytes[..10]),
+    ///     Err(DeserializeError::LengthMismatch { expected: 12, actual: 10 })
+    /// ));
+    /// ```
+    pub fn deserialize_compact(data: &[u8]) -> Result<Self, DeserializeError> {
+        let (header, body) = data.split_at(data.len().min(COMPACT_HEADER_LEN));
+        let Ok(header) = <[u8; COMPACT_HEADER_LEN]>::try_from(header) else {
+            let magic_prefix = COMPACT_MAGIC.iter().zip(data).all(|(m, b)| m == b);
+            return Err(if magic_prefix {
+                DeserializeError::LengthMismatch {
+                    expected: COMPACT_HEADER_LEN,
+                    actual: data.len(),
+                }
+            } else {
+                DeserializeError::InvalidMagic
+            });
+        };
+        let [m0, m1, m2, m3, c0, c1, c2, c3] = header;
+        if [m0, m1, m2, m3] != COMPACT_MAGIC {
+            return Err(DeserializeError::InvalidMagic);
+        }
+        let count = u32::from_le_bytes([c0, c1, c2, c3]) as usize;
+        let expected = count
+            .checked_mul(4)
+            .and_then(|n| n.checked_add(COMPACT_HEADER_LEN))
+            .unwrap_or(usize::MAX);
+        if data.len() != expected {
+            return Err(DeserializeError::LengthMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        let mut codepoints = HashSet::with_capacity(count);
+        for chunk in body.chunks_exact(4) {
+            if let [b0, b1, b2, b3] = *chunk {
+                let cp = u32::from_le_bytes([b0, b1, b2, b3]);
+                if char::from_u32(cp).is_none() {
+                    return Err(DeserializeError::InvalidCodePoint(cp));
+                }
+                codepoints.insert(cp);
+            }
+        }
+        Ok(Self {
+            codepoints: codepoints.into(),
+        })
+    }
+}
+
+// ── ASCII factory methods ─────────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Creates a new set containing all ASCII **control** characters
+    /// (U+0000–U+001F and U+007F).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_control();
+    /// assert!(cp.contains("\n\r\t"));
+    /// assert!(!cp.contains("a"));
+    /// ```
+    pub fn ascii_control() -> Self {
+        Self::from_slice(ascii::CONTROL_CHARS)
+    }
+
+    /// Returns a cached static reference to the ASCII control character set.
+    ///
+    /// Equivalent to [`Self::ascii_control`] but allocated only once via
+    /// [`OnceLock`].
+    pub fn ascii_control_cached() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(Self::ascii_control)
+    }
+
+    /// Creates a new set containing all ASCII **printable** characters
+    /// (U+0020–U+007E).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// assert!(cp.contains("Hello 123!"));
+    /// assert!(!cp.contains("あ"));
+    /// ```
+    pub fn ascii_printable() -> Self {
+        Self::from_slice(ascii::PRINTABLE_CHARS)
+    }
+
+    /// Returns a cached static reference to the ASCII printable character set.
+    pub fn ascii_printable_cached() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(Self::ascii_printable)
+    }
+
+    /// Creates a new set containing only CR (U+000D) and LF (U+000A).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::crlf();
+    /// assert!(cp.contains("\r\n"));
+    /// assert!(!cp.contains("\t"));
+    /// ```
+    pub fn crlf() -> Self {
+        Self::from_slice(ascii::CRLF_CHARS)
+    }
+
+    /// Returns a cached static reference to the CRLF character set.
+    pub fn crlf_cached() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(Self::crlf)
+    }
+
+    /// Creates a new set containing **all** 128 ASCII characters
+    /// (control + printable).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_all();
+    /// assert!(cp.contains("Hello\n"));
+    /// assert!(!cp.contains("あ"));
+    /// ```
+    pub fn ascii_all() -> Self {
+        Self::from_slice(&ascii::ALL_ASCII)
+    }
+
+    /// Returns a cached static reference to the full ASCII character set.
+    pub fn ascii_all_cached() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(Self::ascii_all)
+    }
+}
+
+// ── Unicode factory methods ───────────────────────────────────────────────────
+
+impl CodePoints {
+    /// Creates a new set containing the common **zero-width** characters:
+    /// ZWSP (U+200B), ZWNJ (U+200C), ZWJ (U+200D), WORD JOINER (U+2060) and
+    /// BOM (U+FEFF).
+    ///
+    /// These characters are invisible but frequently pasted into form input.
+    /// The set is intended as an *ignore* list for
+    /// [`validate_with_options`][crate::validation::validate_with_options].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::zero_width();
+    /// assert!(cp.contains("\u{FEFF}\u{200B}"));
+    /// assert!(!cp.contains(" "));
+    /// ```
+    pub fn zero_width() -> Self {
+        Self::from_slice(unicode::ZERO_WIDTH_CHARS)
+    }
+
+    /// Returns a cached static reference to the zero-width character set.
+    pub fn zero_width_cached() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(Self::zero_width)
+    }
+}
+
+impl CodePoints {
+    /// Returns the position of `c` in JIS X 0208 kuten order,
+    /// `(ku - 1) * 94 + (ten - 1)`, or `None` if `c` is not a JIS X 0208
+    /// character.
+    ///
+    /// Sorting by this key lists any subset row by row, as the printed code
+    /// tables do: symbols, Latin letters, kana, Greek, Cyrillic, box
+    /// drawing, then the kanji by reading (Level 1) and by radical
+    /// (Level 2).  NEC row 13 and other vendor extensions have no key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// assert_eq!(CodePoints::sort_key_kuten('　'), Some(0)); // 1-1
+    /// assert_eq!(CodePoints::sort_key_kuten('亜'), Some(15 * 94)); // 16-1
+    /// assert_eq!(CodePoints::sort_key_kuten('①'), None);
+    ///
+    /// let mut chars: Vec<char> = CodePoints::from_string("亜Ａあ、弌").chars().collect();
+    /// chars.sort_by_key(|&c| CodePoints::sort_key_kuten(c));
+    /// assert_eq!(chars, ['、', 'Ａ', 'あ', '亜', '弌']);
+    /// ```
+    pub fn sort_key_kuten(c: char) -> Option<u16> {
+        let (ku, ten) = jisx0208::kuten_of(c as u32)?;
+        Some((u16::from(ku) - 1) * 94 + u16::from(ten) - 1)
+    }
+}
+
+// ── trait implementations ────────────────────────────────────────────────────
+
+impl fmt::Display for CodePoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CodePoints({} items)", self.codepoints.len())
+    }
+}
+
+impl From<Vec<u32>> for CodePoints {
+    fn from(codepoints: Vec<u32>) -> Self {
+        Self::new(codepoints)
+    }
+}
+
+impl From<&str> for CodePoints {
+    fn from(s: &str) -> Self {
+        Self::from_string(s)
+    }
+}
+
+impl From<RangeInclusive<char>> for CodePoints {
+    fn from(range: RangeInclusive<char>) -> Self {
+        Self::from_char_range(range)
+    }
+}
+
+impl std::hash::Hash for CodePoints {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Sort for deterministic hashing regardless of HashSet iteration order.
+        let mut sorted: Vec<&u32> = self.codepoints.iter().collect();
+        sorted.sort_unstable();
+        sorted.hash(state);
+    }
+}
+
+// ── character sets ────────────────────────────────────────────────────────────
+
+/// A named set of characters, implemented by every character-set type such
+/// as `jisx0208::Hiragana` and by [`LazyCodePoints`].
+///
+/// Code that accepts any set can take `&dyn CharacterSet` and pass
+/// [`Self::codepoints`] on to [`contains_all_in_any`] or
+/// [`Validator::allow`](crate::validator::Validator::allow).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "codepoints-jisx0208")]
+/// # {
+/// use japanese_codepoints::jisx0208::{Hiragana, Katakana};
+/// use japanese_codepoints::CharacterSet;
+///
+/// fn rejected_by(sets: &[&dyn CharacterSet], text: &str) -> Vec<&'static str> {
+///     sets.iter()
+///         .filter(|set| set.validate(text).is_err())
+///         .map(|set| set.name())
+///         .collect()
+/// }
+///
+/// let sets: [&dyn CharacterSet; 2] = [Hiragana::cached(), Katakana::cached()];
+/// assert_eq!(rejected_by(&sets, "あい"), ["JIS X 0208 Katakana"]);
+/// # }
+/// ```
+pub trait CharacterSet {
+    /// Returns the human-readable name of the set, e.g. for error messages.
+    fn name(&self) -> &'static str;
+
+    /// Returns the underlying [`CodePoints`].
+    fn codepoints(&self) -> &CodePoints;
+
+    /// Returns `true` if every character in `text` belongs to the set.
+    fn contains(&self, text: &str) -> bool {
+        self.codepoints().contains(text)
+    }
+
+    /// Validates that every character in `text` belongs to the set,
+    /// reporting failures under [`Self::name`].
+    fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
+        self.codepoints().validate_as(text, Some(self.name()))
+    }
+}
+
+/// Implements [`CharacterSet`], `Display` (name and count) and a concise
+/// `Debug` (name, count and code-point range) for a character-set type
+/// with inherent `name` and `codepoints` methods.
+#[cfg(any(
+    feature = "codepoints-jisx0201",
+    feature = "codepoints-jisx0208",
+    feature = "codepoints-jisx0208kanji",
+    feature = "codepoints-jisx0213kanji"
+))]
+macro_rules! impl_character_set {
+    ($name:ident) => {
+        impl crate::CharacterSet for $name {
+            fn name(&self) -> &'static str {
+                $name::name(self)
+            }
+
+            fn codepoints(&self) -> &crate::CodePoints {
+                $name::codepoints(self)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    f,
+                    "{} ({} characters)",
+                    self.name(),
+                    self.codepoints().len()
+                )
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                crate::codepoints::debug_charset(f, stringify!($name), self)
+            }
+        }
+    };
+}
+
+#[cfg(any(
+    feature = "codepoints-jisx0201",
+    feature = "codepoints-jisx0208",
+    feature = "codepoints-jisx0208kanji",
+    feature = "codepoints-jisx0213kanji"
+))]
+pub(crate) use impl_character_set;
+
+/// Writes the concise `Debug` form shared by the character-set types: name,
+/// count and code-point range instead of every member.
+#[cfg(any(
+    feature = "codepoints-jisx0201",
+    feature = "codepoints-jisx0208",
+    feature = "codepoints-jisx0208kanji",
+    feature = "codepoints-jisx0213kanji"
+))]
+pub(crate) fn debug_charset(
+    f: &mut fmt::Formatter<'_>,
+    type_name: &str,
+    set: &dyn CharacterSet,
+) -> fmt::Result {
+    let codepoints = set.codepoints();
+    let mut s = f.debug_struct(type_name);
+    s.field("name", &set.name())
+        .field("count", &codepoints.len());
+    if let (Some(min), Some(max)) = (
+        codepoints.codepoints.iter().min(),
+        codepoints.codepoints.iter().max(),
+    ) {
+        s.field("min", &format_args!("U+{:04X}", min))
+            .field("max", &format_args!("U+{:04X}", max));
+    }
+    s.finish()
+}
+
+// ── lazily initialized statics ────────────────────────────────────────────────
+
+/// A [`CodePoints`] set that is built on first use, for application-defined
+/// composite sets stored in a `static`.
+///
+/// This is the same [`OnceLock`] pattern the built-in `*_cached()` methods use,
+/// packaged so downstream code does not have to repeat it.  Initialization
+/// runs exactly once even if several threads access the set concurrently.
+///
+/// `LazyCodePoints` dereferences to [`CodePoints`], so it can be passed
+/// anywhere a `&CodePoints` is expected, including [`contains_all_in_any`]
+/// and [`Validator::allow`](crate::validator::Validator::allow).
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{contains_all_in_any, CodePoints, LazyCodePoints};
+///
+/// fn address() -> CodePoints {
+///     CodePoints::ascii_printable().union(&CodePoints::from_string("丁目番地号"))
+/// }
+///
+/// static ADDRESS: LazyCodePoints = LazyCodePoints::new(address);
+///
+/// assert!(ADDRESS.contains("1丁目2番地"));
+/// assert!(contains_all_in_any("3号", &[&ADDRESS]));
+/// ```
+pub struct LazyCodePoints {
+    cell: OnceLock<CodePoints>,
+    init: fn() -> CodePoints,
+    name: &'static str,
+}
+
+impl LazyCodePoints {
+    /// Creates a set that will be built by `init` on first access.
+    ///
+    /// This is a `const fn`, so it can initialize a `static`.  Its
+    /// [`CharacterSet::name`] is `"unnamed"`; see [`Self::named`].
+    pub const fn new(init: fn() -> CodePoints) -> Self {
+        Self::named("unnamed", init)
+    }
+
+    /// [`Self::new`], with the name reported by [`CharacterSet::name`] and
+    /// [`CharacterSet::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{CharacterSet, CodePoints, LazyCodePoints};
+    ///
+    /// static DIGITS: LazyCodePoints =
+    ///     LazyCodePoints::named("digits", || CodePoints::from_string("0123456789"));
+    ///
+    /// assert_eq!(DIGITS.name(), "digits");
+    /// assert!(CharacterSet::validate(&DIGITS, "12a").is_err());
+    /// ```
+    pub const fn named(name: &'static str, init: fn() -> CodePoints) -> Self {
+        Self {
+            cell: OnceLock::new(),
+            init,
+            name,
+        }
+    }
+
+    /// Returns the set, building it first if necessary.
+    pub fn get(&self) -> &CodePoints {
+        self.cell.get_or_init(self.init)
+    }
+
+    /// Returns `true` if the set has already been built.
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+}
+
+impl std::ops::Deref for LazyCodePoints {
+    type Target = CodePoints;
+
+    fn deref(&self) -> &CodePoints {
+        self.get()
+    }
+}
+
+impl AsRef<CodePoints> for LazyCodePoints {
+    fn as_ref(&self) -> &CodePoints {
+        self.get()
+    }
+}
+
+impl CharacterSet for LazyCodePoints {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn codepoints(&self) -> &CodePoints {
+        self.get()
+    }
+}
+
+impl fmt::Debug for LazyCodePoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cell.get() {
+            Some(cp) => f.debug_tuple("LazyCodePoints").field(cp).finish(),
+            None => f.write_str("LazyCodePoints(<uninitialized>)"),
+        }
+    }
+}
+
+// ── multi-set membership ──────────────────────────────────────────────────────
+
+/// Returns `true` if **every** character in `text` belongs to **at least one**
+/// of the provided character sets.
+///
+/// This is the idiomatic way to check text that may contain characters from
+/// multiple scripts — for example Japanese hiragana mixed with ASCII
+/// punctuation.
+///
+/// # Edge cases
+///
+/// * An empty `text` returns `true` (vacuously).
+/// * An empty `sets` slice returns `false` for any input (including empty).
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{CodePoints, contains_all_in_any};
+///
+/// let hiragana = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+/// let katakana = CodePoints::new(vec![0x30A2, 0x30A4]); // ア, イ
+///
+/// // Each character is valid in at least one set
+/// assert!(contains_all_in_any("あア", &[&hiragana, &katakana]));
+///
+/// // 'x' is not in either set
+/// assert!(!contains_all_in_any("あx", &[&hiragana, &katakana]));
+/// ```
+pub fn contains_all_in_any(text: &str, sets: &[&CodePoints]) -> bool {
+    !sets.is_empty() && CodePoints::iter_attributed(text, sets).all(|set| set.is_some())
+}
+
+impl CodePoints {
+    /// Returns, for each character of `s`, the index in `sets` of the first
+    /// set that contains it, or `None` if no set does.
+    ///
+    /// Earlier sets win where sets overlap, so order `sets` by priority.
+    /// With an empty `sets` slice every entry is `None`.  For a lazy
+    /// version see [`Self::iter_attributed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let hiragana = CodePoints::from_string("あい");
+    /// let kana = CodePoints::from_string("あア");
+    ///
+    /// let attribution = CodePoints::attribute_chars("あアx", &[&hiragana, &kana]);
+    /// assert_eq!(attribution, [Some(0), Some(1), None]);
+    /// ```
+    pub fn attribute_chars(s: &str, sets: &[&CodePoints]) -> Vec<Option<usize>> {
+        Self::iter_attributed(s, sets).collect()
+    }
+
+    /// Returns a lazy iterator over the characters of `s` yielding the
+    /// index of the first set in `sets` that contains each one, as
+    /// [`Self::attribute_chars`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let digits = CodePoints::from_string("0123456789");
+    /// let ascii = CodePoints::ascii_printable();
+    ///
+    /// let spans: Vec<_> = "a1"
+    ///     .chars()
+    ///     .zip(CodePoints::iter_attributed("a1", &[&digits, &ascii]))
+    ///     .collect();
+    /// assert_eq!(spans, [('a', Some(1)), ('1', Some(0))]);
+    /// ```
+    pub fn iter_attributed<'a>(s: &'a str, sets: &'a [&'a CodePoints]) -> AttributedIter<'a> {
+        AttributedIter {
+            sets,
+            chars: s.chars(),
+        }
+    }
+}
+
+/// Lazy iterator returned by [`CodePoints::iter_attributed`].
+///
+/// Yields, per character, the index of the first set containing it.
+#[derive(Clone, Debug)]
+pub struct AttributedIter<'a> {
+    sets: &'a [&'a CodePoints],
+    chars: std::str::Chars<'a>,
+}
+
+impl Iterator for AttributedIter<'_> {
+    type Item = Option<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.chars.next()?;
+        Some(self.sets.iter().position(|set| set.contains_char(c)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl std::iter::FusedIterator for AttributedIter<'_> {}
+
+/// The union of several sets, precomputed so that [`contains_all_in_any`]
+/// checks become a single lookup per character.
+///
+/// Build one with [`new`](Self::new), or let [`cached_for`](Self::cached_for)
+/// memoize it for a combination of `'static` sets that is used repeatedly.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{contains_all_in_any, CodePoints, CodePointsGroup};
+///
+/// let hiragana = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+/// let katakana = CodePoints::new(vec![0x30A2, 0x30A4]); // ア, イ
+/// let group = CodePointsGroup::new(&[&hiragana, &katakana]);
+///
+/// assert!(group.contains("あア"));
+/// assert_eq!(group.contains("あx"), contains_all_in_any("あx", &[&hiragana, &katakana]));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodePointsGroup {
+    union: CodePoints,
+    set_count: usize,
+}
+
+impl CodePointsGroup {
+    /// Precomputes the union of `sets`.
+    pub fn new(sets: &[&CodePoints]) -> Self {
+        let mut union = CodePoints::new(vec![]);
+        for set in sets {
+            union.codepoints.extend(&set.codepoints);
+        }
+        Self {
+            union,
+            set_count: sets.len(),
+        }
+    }
+
+    /// Returns the memoized group for `sets`, building it on first use.
+    ///
+    /// Groups are keyed by the addresses of the sets, ignoring order and
+    /// repeats, so any slice naming the same `'static` sets (a different
+    /// slice, a permutation) shares one group.  The registry is global and
+    /// thread-safe.
+    ///
+    /// # Memory
+    ///
+    /// Groups are leaked and never evicted: memory grows with the number of
+    /// **distinct combinations** seen, each costing about one set the size
+    /// of its union.  This suits a fixed set of combinations composed at run
+    /// time; for combinations built from user input, use [`Self::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::{CodePoints, CodePointsGroup};
+    ///
+    /// let ascii = CodePoints::ascii_printable_cached();
+    /// let kana: &'static CodePoints = Box::leak(Box::new(CodePoints::from_char_range('ぁ'..='ん')));
+    ///
+    /// let group = CodePointsGroup::cached_for(&[ascii, kana]);
+    /// assert!(group.contains("abc かな"));
+    /// assert!(std::ptr::eq(group, CodePointsGroup::cached_for(&[kana, ascii])));
+    /// ```
+    pub fn cached_for(sets: &[&'static CodePoints]) -> &'static CodePointsGroup {
+        static GROUPS: OnceLock<RwLock<HashMap<Vec<usize>, &'static CodePointsGroup>>> =
+            OnceLock::new();
+        let groups = GROUPS.get_or_init(Default::default);
+
+        // 'static sets never move or get freed, so their addresses are
+        // stable identities.
+        let mut key: Vec<usize> = sets
+            .iter()
+            .map(|&set| set as *const CodePoints as usize)
+            .collect();
+        key.sort_unstable();
+        key.dedup();
+
+        if let Some(&group) = groups.read().unwrap_or_else(|e| e.into_inner()).get(&key) {
+            return group;
+        }
+        let group = CodePointsGroup::new(sets);
+        let mut groups = groups.write().unwrap_or_else(|e| e.into_inner());
+        groups
+            .entry(key)
+            .or_insert_with(|| Box::leak(Box::new(group)))
+    }
+
+    /// Returns `true` if every character of `text` is in at least one of the
+    /// sets; same result as [`contains_all_in_any`].
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.set_count > 0 && self.union.contains(text)
+    }
+
+    /// Returns `true` if `c` is in at least one of the sets.
+    pub fn contains_char(&self, c: char) -> bool {
+        self.union.contains_char(c)
+    }
+
+    /// Returns the union of the sets.
+    pub fn union(&self) -> &CodePoints {
+        &self.union
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── construction ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_new_deduplicates() {
+        let cp = CodePoints::new(vec![0x3042, 0x3042, 0x3044]);
+        assert_eq!(cp.len(), 2);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let cp = CodePoints::from_slice(&[0x3042, 0x3044]);
+        assert!(cp.contains("あい"));
+        assert_eq!(cp.len(), 2);
+    }
+
+    #[test]
+    fn test_from_string() {
+        let cp = CodePoints::from_string("あいあ");
+        assert_eq!(cp.len(), 2);
+        assert!(cp.contains("あい"));
+    }
+
+    #[test]
+    fn test_from_string_filtered() {
+        let controls = CodePoints::ascii_control();
+        let cp = CodePoints::from_string_filtered("a\tあ\0a", &controls);
+        assert_eq!(cp, CodePoints::from_string("aあ"));
+        assert!(CodePoints::from_string_filtered("\r\n", &controls).is_empty());
+        assert_eq!(
+            CodePoints::from_string_filtered("あい", &CodePoints::new(vec![])),
+            CodePoints::from_string("あい")
+        );
+    }
+
+    #[test]
+    fn test_from_strings() {
+        let samples = vec![
+            String::from("あいう"),
+            String::from("うえお"),
+            String::from("お𠮷\t"),
+        ];
+        let cp = CodePoints::from_strings(&samples);
+        assert_eq!(cp.len(), 7);
+        assert_eq!(cp, CodePoints::from_string(&samples.concat()));
+        assert_eq!(
+            cp.difference(&CodePoints::ascii_control()),
+            CodePoints::from_string("あいうえお𠮷")
+        );
+        assert!(CodePoints::from_strings(Vec::<&str>::new()).is_empty());
+        assert!(CodePoints::from_strings(["", ""]).is_empty());
+    }
+
+    #[test]
+    fn test_empty() {
+        let cp = CodePoints::new(vec![]);
+        assert!(cp.is_empty());
+        assert!(cp.contains("")); // empty string is always valid
+        assert!(!cp.contains("a")); // any character fails
+    }
+
+    #[test]
+    fn test_ordered_iteration_is_ascending() {
+        let values: Vec<u32> = (0..2000u32).map(|i| (i * 7919) % 0x3000).collect();
+        let ordered = CodePoints::new_ordered(values.clone());
+        let got: Vec<u32> = ordered.iter().copied().collect();
+        let mut expected = values;
+        expected.sort_unstable();
+        expected.dedup();
+        assert_eq!(got, expected);
+        assert_eq!(ordered.iter().len(), expected.len());
+
+        let chars: Vec<char> = CodePoints::ascii_printable().to_ordered().chars().collect();
+        assert!(chars.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(chars[0], ' ');
+    }
+
+    #[test]
+    fn test_ordered_behaves_like_hashed() {
+        let hashed = CodePoints::from_string("あいうabc");
+        let ordered = hashed.to_ordered();
+        assert!(ordered.is_ordered() && !hashed.is_ordered());
+        assert_eq!(ordered, hashed);
+        assert_eq!(ordered.len(), hashed.len());
+        assert!(ordered.contains("あa"));
+        assert_eq!(ordered.validate("aえ"), hashed.validate("aえ"));
+        assert_eq!(ordered.all_excluded("xあy"), hashed.all_excluded("xあy"));
+        assert_eq!(ordered.serialize_compact(), hashed.serialize_compact());
+        assert_eq!(ordered.stats().representation, Representation::BTreeSet);
+        assert_eq!(hashed.stats().representation, Representation::HashSet);
+
+        use std::hash::{BuildHasher, RandomState};
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(&ordered), state.hash_one(&hashed));
+
+        let mut shrunk = ordered.clone();
+        shrunk.shrink_to_fit();
+        assert_eq!(shrunk, ordered);
+    }
+
+    #[test]
+    fn test_mixed_backend_set_operations_follow_lhs() {
+        let ordered = CodePoints::new_ordered(vec![1, 2, 3]);
+        let hashed = CodePoints::new(vec![3, 4]);
+        let ops: [fn(&CodePoints, &CodePoints) -> CodePoints; 4] = [
+            CodePoints::union,
+            CodePoints::intersection,
+            CodePoints::difference,
+            CodePoints::symmetric_difference,
+        ];
+        for op in ops {
+            let left = op(&ordered, &hashed);
+            let right = op(&hashed.to_ordered(), &ordered);
+            assert!(left.is_ordered());
+            assert!(!op(&hashed, &ordered).is_ordered());
+            assert_eq!(op(&ordered.to_ordered(), &hashed), left);
+            assert!(right.is_ordered());
+        }
+        assert_eq!(
+            ordered
+                .symmetric_difference(&hashed)
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+        assert!(ordered.without("\u{1}").is_ordered());
+        assert!(ordered.with("a").is_ordered());
+        assert!(CodePoints::new_ordered(vec![3]).is_subset_of(&hashed));
+        assert!(CodePoints::disjoint_partition(&[
+            CodePoints::new_ordered(vec![1]),
+            CodePoints::new(vec![2]),
+        ]));
+    }
+
+    const BACKENDS: [Backend; 4] = [
+        Backend::HashSet,
+        Backend::BTreeSet,
+        Backend::SortedVec,
+        Backend::Bitmap,
+    ];
+
+    #[test]
+    fn test_backends_behave_identically() {
+        let members: Vec<u32> = "あいうabc𠮷\u{0}".chars().map(|c| c as u32).collect();
+        let reference = CodePoints::new(members.clone());
+        let other = CodePoints::from_string("いえb");
+        for backend in BACKENDS {
+            let cp = CodePoints::with_backend(members.clone(), backend);
+            assert_eq!(cp.backend(), backend);
+            assert_eq!(cp.stats().representation, backend);
+            assert_eq!(cp.is_ordered(), backend != Backend::HashSet);
+            assert_eq!(cp, reference, "{backend:?}");
+            assert_eq!(cp.len(), reference.len());
+            assert!(cp.contains("あa𠮷\u{0}"));
+            assert!(!cp.contains_char('え') && !cp.contains_char('\u{10FFFF}'));
+            assert_eq!(
+                cp.first_excluded("abえc"),
+                reference.first_excluded("abえc")
+            );
+            assert_eq!(cp.all_excluded("xあyえ"), reference.all_excluded("xあyえ"));
+            assert_eq!(cp.validate("aえ"), reference.validate("aえ"));
+            assert_eq!(cp.serialize_compact(), reference.serialize_compact());
+
+            let union = cp.union(&other);
+            assert_eq!(union.backend(), backend);
+            assert_eq!(union, reference.union(&other));
+            assert_eq!(cp.intersection(&other), reference.intersection(&other));
+            assert_eq!(cp.difference(&other), reference.difference(&other));
+            assert_eq!(
+                cp.symmetric_difference(&other),
+                reference.symmetric_difference(&other)
+            );
+            assert!(cp.intersection(&other).is_subset_of(&cp));
+
+            let mut edited = cp.without("あ𠮷").with("ん");
+            assert_eq!(edited, reference.without("あ𠮷").with("ん"));
+            assert_eq!(edited.insert_char_range('x'..='z'), 3);
+            assert!(edited.contains("んxyz") && !edited.contains("あ"));
+            edited.shrink_to_fit();
+            assert_eq!(edited.backend(), backend);
+            assert!(edited.memory_usage() >= std::mem::size_of::<CodePoints>());
+
+            if backend != Backend::HashSet {
+                let mut sorted = members.clone();
+                sorted.sort_unstable();
+                assert_eq!(cp.iter().copied().collect::<Vec<_>>(), sorted);
+            }
+            assert_eq!(cp.iter().len(), reference.len());
+
+            let empty = CodePoints::with_backend(vec![], backend);
+            assert!(empty.is_empty() && empty.contains("") && !empty.contains("a"));
+        }
+    }
+
+    #[test]
+    fn test_convert_backend_round_trips() {
+        let mut cp = CodePoints::from_string("かなカナ");
+        let original = cp.clone();
+        for backend in BACKENDS.into_iter().chain(BACKENDS.into_iter().rev()) {
+            cp.convert_backend(backend);
+            assert_eq!(cp.backend(), backend);
+            assert_eq!(cp, original);
+        }
+        let mut bitmap = CodePoints::with_backend(vec![0x10000, 1], Backend::Bitmap);
+        let before = bitmap.memory_usage();
+        assert!(
+            before > CodePoints::with_backend(vec![0x10000, 1], Backend::SortedVec).memory_usage()
+        );
+        bitmap = bitmap.without("\u{10000}");
+        bitmap.shrink_to_fit();
+        assert!(bitmap.memory_usage() < before);
+    }
+
+    // ── membership ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_contains_basic() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert!(cp.contains("あ"));
+        assert!(cp.contains("あい"));
+        assert!(!cp.contains("う"));
+        assert!(!cp.contains("あいう"));
+        assert!(cp.contains(""));
+    }
+
+    #[test]
+    fn test_contains_char() {
+        let cp = CodePoints::new(vec![0x3042]); // あ
+        assert!(cp.contains_char('あ'));
+        assert!(!cp.contains_char('い'));
+    }
+
+    #[test]
+    fn test_contains_surrogate_pairs() {
+        // U+2000B is outside the BMP; Rust represents it as a single char.
+        let cp = CodePoints::new(vec![0x2000B, 0x3042, 0x3044]);
+        assert!(cp.contains("𠀋あい"));
+        assert!(!cp.contains("𠀋あいか")); // か not in set
+    }
+
+    #[test]
+    fn test_contains_mixed_characters() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044, 0x3046, 0x3048, 0x304A, 0x2000B]);
+        assert!(cp.contains("𠀋あいうあ"));
+        assert!(!cp.contains("𠀋あいうか")); // か not in set
+    }
+
+    #[test]
+    fn test_first_excluded_char_astral() {
+        let cp = CodePoints::new(vec![0x3042, 0x20BB7]); // あ, 𠮷
+        assert_eq!(cp.first_excluded_char("あ𠮷"), None);
+        assert_eq!(cp.first_excluded_char("𠮷あ🎌"), Some('🎌'));
+        assert_eq!(
+            cp.first_excluded_detail("あ𠀋"),
+            Some(ExcludedChar {
+                ch: '𠀋',
+                code_point: 0x2000B,
+                position: 1,
+                byte_index: 3
+            })
+        );
+        let err = cp.validate("𠮷😀").unwrap_err();
+        assert_eq!((err.code_point, err.position), (0x1F600, 1));
+        assert!(err.message.contains("'😀' (U+1F600)"));
+    }
+
+    #[test]
+    fn test_contains_short_circuits() {
+        let cp = CodePoints::new(vec![0x61]);
+        // Pulling past the first violation would panic.
+        let chars = "ab".chars().chain(std::iter::from_fn(|| -> Option<char> {
+            panic!("contains scanned past the first violation")
+        }));
+        assert!(!cp.contains_chars(chars));
+    }
+
+    #[test]
+    fn test_contains_within() {
+        let cp = CodePoints::new(vec![0x61, 0x62]);
+        assert_eq!(cp.contains_within("", 0), ContainsOutcome::AllValid);
+        assert_eq!(cp.contains_within("a", 0), ContainsOutcome::TruncatedValid);
+        assert_eq!(cp.contains_within("ab", 2), ContainsOutcome::AllValid);
+        assert_eq!(cp.contains_within("ab", 10), ContainsOutcome::AllValid);
+        assert_eq!(
+            cp.contains_within("abab", 2),
+            ContainsOutcome::TruncatedValid
+        );
+        assert_eq!(
+            cp.contains_within("aあb", 2),
+            ContainsOutcome::InvalidAt(0x3042, 1)
+        );
+        // A violation beyond the limit is not seen.
+        assert_eq!(
+            cp.contains_within("abc", 2),
+            ContainsOutcome::TruncatedValid
+        );
+        assert_eq!(
+            cp.contains_within("abc", 3),
+            ContainsOutcome::InvalidAt(0x63, 2)
+        );
+
+        let long = format!("a{}", "b".repeat(1_000_000));
+        assert_eq!(
+            cp.contains_within(&long, 100),
+            ContainsOutcome::TruncatedValid
+        );
+    }
+
+    // ── exclusion queries ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_first_excluded() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert_eq!(cp.first_excluded("あい"), None);
+        assert_eq!(cp.first_excluded("あいう"), Some(0x3046)); // う
+    }
+
+    #[test]
+    fn test_first_excluded_empty() {
+        let cp = CodePoints::new(vec![0x3042]);
+        assert_eq!(cp.first_excluded(""), None);
+    }
+
+    #[test]
+    fn test_first_excluded_with_position() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert_eq!(cp.first_excluded_with_position("あいう"), Some((0x3046, 2)));
+        assert_eq!(cp.first_excluded_with_position("あい"), None);
+    }
+
+    #[test]
+    fn test_first_excluded_surrogate() {
+        // あ, い, う
+        let cp = CodePoints::new(vec![0x3042, 0x3044, 0x3046]);
+        // 𠀋 (U+2000B) is the first excluded character
+        assert_eq!(cp.first_excluded("𠀋あいう"), Some(0x2000B));
+    }
+
+    #[test]
+    fn test_all_excluded_order() {
+        // あ, い
+        let cp = CodePoints::new(vec![0x3042, 0x3044]);
+        // う appears before え; duplicate う is skipped
+        assert_eq!(cp.all_excluded("あいうえ"), vec![0x3046, 0x3048]);
+    }
+
+    #[test]
+    fn test_all_excluded_empty() {
+        let cp = CodePoints::new(vec![0x3042]);
+        assert_eq!(cp.all_excluded(""), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_all_excluded_surrogate() {
+        // あ, い
+        let cp = CodePoints::new(vec![0x3042, 0x3044]);
+        // 𠀋 (U+2000B) then き (U+304D)
+        let result = cp.all_excluded("あ𠀋いき");
+        assert_eq!(result, vec![0x2000B, 0x304D]);
+    }
+
+    #[test]
+    fn test_all_excluded_multiple_surrogates() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044, 0x3046]); // あ, い, う
+        let result = cp.all_excluded("𠀋あいうきかくか𠂟");
+        // 𠀋, き, か, く, 𠂟  (か deduplicated)
+        assert_eq!(result, vec![0x2000B, 0x304D, 0x304B, 0x304F, 0x2009F]);
+    }
+
+    #[test]
+    fn test_excluded_options() {
+        let cp = CodePoints::new(vec![0x3042]); // あ
+        let text = "えあうえいう";
+        let found = |dedup, order, limit| -> Vec<(char, usize)> {
+            let options = ExcludedOptions {
+                dedup,
+                order,
+                limit,
+            };
+            cp.excluded(text, options)
+                .iter()
+                .map(|e| (e.ch, e.position))
+                .collect()
+        };
+        use Order::{CodePoint, Occurrence};
+
+        assert_eq!(
+            found(true, Occurrence, None),
+            vec![('え', 0), ('う', 2), ('い', 4)]
+        );
+        assert_eq!(
+            found(false, Occurrence, None),
+            vec![('え', 0), ('う', 2), ('え', 3), ('い', 4), ('う', 5)]
+        );
+        assert_eq!(
+            found(true, CodePoint, None),
+            vec![('い', 4), ('う', 2), ('え', 0)]
+        );
+        assert_eq!(
+            found(false, CodePoint, None),
+            vec![('い', 4), ('う', 2), ('う', 5), ('え', 0), ('え', 3)]
+        );
+
+        // The limit counts reported characters, after dedup and ordering.
+        assert_eq!(found(true, Occurrence, Some(2)), vec![('え', 0), ('う', 2)]);
+        assert_eq!(
+            found(false, Occurrence, Some(2)),
+            vec![('え', 0), ('う', 2)]
+        );
+        assert_eq!(
+            found(true, Occurrence, Some(3)),
+            vec![('え', 0), ('う', 2), ('い', 4)]
+        );
+        assert_eq!(
+            found(false, Occurrence, Some(3)),
+            vec![('え', 0), ('う', 2), ('え', 3)]
+        );
+        assert_eq!(found(true, CodePoint, Some(2)), vec![('い', 4), ('う', 2)]);
+        assert_eq!(
+            found(false, CodePoint, Some(3)),
+            vec![('い', 4), ('う', 2), ('う', 5)]
+        );
+        assert_eq!(found(true, Occurrence, Some(0)), vec![]);
+        assert_eq!(found(false, CodePoint, Some(10)).len(), 5);
+
+        assert!(cp.excluded("", ExcludedOptions::default()).is_empty());
+        assert!(cp.excluded("ああ", ExcludedOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_excluded_default_matches_all_excluded() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044, 0x3046]); // あ, い, う
+        let text = "𠀋あいうきかくか𠂟";
+        let excluded: Vec<u32> = cp
+            .excluded(text, ExcludedOptions::default())
+            .iter()
+            .map(|e| e.code_point)
+            .collect();
+        assert_eq!(excluded, cp.all_excluded(text));
+        assert_eq!(cp.first_excluded(text), excluded.first().copied());
+    }
+
+    // ── inclusion queries ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_any_included() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert!(cp.any_included("xあ"));
+        assert!(!cp.any_included("xyz"));
+        assert!(!cp.any_included(""));
+    }
+
+    #[test]
+    fn test_first_included() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert_eq!(cp.first_included("うえ"), None);
+        assert_eq!(cp.first_included("うえい"), Some(0x3044)); // い
+    }
+
+    #[test]
+    fn test_first_included_empty() {
+        let cp = CodePoints::new(vec![0x3042]);
+        assert_eq!(cp.first_included(""), None);
+    }
+
+    #[test]
+    fn test_first_included_with_position() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert_eq!(cp.first_included_with_position("うえい"), Some((0x3044, 2)));
+        assert_eq!(cp.first_included_with_position("うえ"), None);
+    }
+
+    #[test]
+    fn test_first_included_surrogate() {
+        let cp = CodePoints::new(vec![0x2000B]); // 𠀋
+        assert_eq!(cp.first_included_with_position("あ𠀋"), Some((0x2000B, 1)));
+    }
+
+    #[test]
+    fn test_all_included_with_positions() {
+        let cp = CodePoints::new(vec![0x3042, 0x2000B]); // あ, 𠀋
+        assert_eq!(
+            cp.all_included_with_positions("あい𠀋あ"),
+            vec![(0x3042, 0), (0x2000B, 2), (0x3042, 3)]
+        );
+        assert_eq!(cp.all_included_with_positions(""), Vec::new());
+    }
+
+    #[test]
+    fn test_ascii_control_as_deny_list() {
+        let forbidden = CodePoints::ascii_control();
+        assert!(!forbidden.any_included("こんにちは world"));
+        assert_eq!(
+            forbidden.first_included_with_position("ok\0"),
+            Some((0x00, 2))
+        );
+    }
+
+    #[test]
+    fn test_iter_excluded_chars_take() {
+        let cp = CodePoints::new(vec![0x3042]); // あ
+                                                // 10 excluded characters interleaved with allowed ones
+        let text = "abあcdeあfghij";
+        assert_eq!(cp.iter_excluded_chars(text).count(), 10);
+
+        let first: Vec<_> = cp.iter_excluded_chars(text).take(3).collect();
+        assert_eq!(first, vec![('a', 0), ('b', 1), ('c', 3)]);
+    }
+
+    #[test]
+    fn test_iter_excluded_chars_keeps_duplicates() {
+        let cp = CodePoints::new(vec![0x3042]); // あ
+        let excluded: Vec<_> = cp.iter_excluded_chars("𠀋あ𠀋").collect();
+        assert_eq!(excluded, vec![('𠀋', 0), ('𠀋', 2)]);
+        assert_eq!(cp.iter_excluded_chars("").next(), None);
+    }
+
+    #[test]
+    fn test_iter_valid_chars() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        let valid: Vec<_> = cp.iter_valid_chars("xあyいあ").collect();
+        assert_eq!(valid, vec![('あ', 1), ('い', 3), ('あ', 4)]);
+        assert_eq!(cp.iter_valid_chars("xyz").next(), None);
+    }
+
+    #[test]
+    fn test_excluded_histogram() {
+        let cp = CodePoints::new(vec![0x3042]); // あ
+        assert_eq!(
+            cp.excluded_histogram("うあいういう"),
+            vec![(0x3046, 3), (0x3044, 2)]
+        );
+        // Ties are ordered by code point.
+        assert_eq!(
+            cp.excluded_histogram("えう"),
+            vec![(0x3046, 1), (0x3048, 1)]
+        );
+        assert!(cp.excluded_histogram("ああ").is_empty());
+    }
+
+    #[test]
+    fn test_excluded_histogram_feed_and_merge() {
+        let cp = CodePoints::ascii_printable();
+        let text = "a\tb\nc\td\u{3000}";
+        let mut whole = ExcludedHistogram::new(&cp);
+        whole.feed(text);
+
+        let mut first = ExcludedHistogram::new(&cp);
+        first.feed("a\tb\n");
+        let mut second = ExcludedHistogram::new(&cp);
+        second.feed("c\td\u{3000}");
+        first.merge(second);
+
+        assert_eq!(first.len(), 3);
+        assert_eq!(first.total(), 4);
+        assert_eq!(first.count(0x09), 2);
+        assert_eq!(first.count(0x41), 0);
+        assert_eq!(first.into_sorted_vec(), whole.into_sorted_vec());
+        assert!(ExcludedHistogram::new(&cp).is_empty());
+    }
+
+    // ── UTF-8 byte input ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_first_excluded_utf8_matches_str() {
+        let cp = CodePoints::from_string("aあ😀");
+        for text in ["", "aあ😀", "aあb", "😀x", "ああい"] {
+            let expected = text
+                .char_indices()
+                .find(|&(_, c)| !cp.contains_char(c))
+                .map(|(i, c)| (c as u32, i));
+            assert_eq!(cp.first_excluded_utf8(text.as_bytes()), Ok(expected));
+            assert_eq!(cp.contains_utf8(text.as_bytes()), Ok(cp.contains(text)));
+        }
+    }
+
+    #[test]
+    fn test_utf8_astral() {
+        let cp = CodePoints::new(vec![0x20BB7]); // 𠮷
+        assert_eq!(cp.contains_utf8(&[0xF0, 0xA0, 0xAE, 0xB7]), Ok(true));
+        assert_eq!(
+            CodePoints::new(vec![]).first_excluded_utf8("a𠮷".as_bytes()),
+            Ok(Some((0x61, 0)))
+        );
+        assert_eq!(
+            CodePoints::from_string("a").first_excluded_utf8("a𠮷".as_bytes()),
+            Ok(Some((0x20BB7, 1)))
+        );
+    }
+
+    #[test]
+    fn test_utf8_truncated() {
+        let cp = CodePoints::from_string("aあ");
+        for bytes in [&b"a\xE3"[..], b"a\xE3\x81", b"a\xF0\xA0\xAE"] {
+            let err = cp.contains_utf8(bytes).unwrap_err();
+            assert_eq!(
+                err,
+                Utf8ErrorAt {
+                    byte_offset: 1,
+                    incomplete: true
+                }
+            );
+        }
+        // A truncated sequence followed by more input is invalid, not incomplete.
+        let err = cp.contains_utf8(b"\xE3\x81a").unwrap_err();
+        assert_eq!(
+            err,
+            Utf8ErrorAt {
+                byte_offset: 0,
+                incomplete: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_utf8_rejects_invalid_sequences() {
+        // Decoding fails before membership is consulted.
+        let cp = CodePoints::new(vec![]);
+        for bytes in [
+            &b"\xC0\x80"[..],    // overlong NUL
+            b"\xC1\xBF",         // overlong U+007F
+            b"\xE0\x80\xAF",     // overlong '/'
+            b"\xF0\x82\x82\xAC", // overlong U+20AC
+            b"\xED\xA0\x80",     // surrogate U+D800
+            b"\xF4\x90\x80\x80", // U+110000
+            b"\x80",             // lone continuation byte
+            b"\xFF",
+        ] {
+            assert!(std::str::from_utf8(bytes).is_err());
+            let err = cp.first_excluded_utf8(bytes).unwrap_err();
+            assert_eq!(err.byte_offset, 0);
+            assert!(!err.incomplete);
+        }
+    }
+
+    #[test]
+    fn test_utf8_excluded_before_invalid() {
+        let cp = CodePoints::from_string("a");
+        assert_eq!(cp.contains_utf8(b"ab\xFF"), Ok(false));
+        assert_eq!(
+            cp.first_excluded_utf8(b"a\xFFb").unwrap_err().to_string(),
+            "invalid UTF-8 sequence at byte 1"
+        );
+    }
+
+    // ── ASCII case-insensitive matching ───────────────────────────────────
+
+    #[test]
+    fn test_ascii_case_insensitive() {
+        let upper = CodePoints::from_string("ABCXYZ");
+        assert!(upper.contains_ascii_case_insensitive("abc"));
+        assert!(upper.contains_ascii_case_insensitive("AbCxYz"));
+        assert!(!upper.contains_ascii_case_insensitive("ａ")); // fullwidth a
+        assert!(!upper.contains_ascii_case_insensitive("abd"));
+        assert_eq!(
+            upper.first_excluded_ascii_case_insensitive("xyzａ"),
+            Some(('ａ' as u32, 3))
+        );
+
+        let lower = CodePoints::from_string("abc");
+        assert!(lower.contains_ascii_case_insensitive("ABC"));
+        assert!(CodePoints::new(vec![]).contains_ascii_case_insensitive(""));
+    }
+
+    #[test]
+    fn test_fold_ascii_case() {
+        let cp = CodePoints::from_string("AZ-あＡ");
+        let folded = cp.fold_ascii_case();
+        assert_eq!(folded.len(), cp.len() + 2);
+        assert!(folded.contains("aAzZ-あＡ"));
+        assert!(!folded.contains("ａ"));
+        for s in ["az", "AZ", "aZ-", "b", "ａ"] {
+            assert_eq!(folded.contains(s), cp.contains_ascii_case_insensitive(s));
+        }
+    }
+
+    #[test]
+    fn test_fold_fullwidth_case() {
+        let cp = CodePoints::from_string("ＡＺ-あa");
+        let folded = cp.fold_fullwidth_case();
+        assert_eq!(folded.len(), cp.len() + 2);
+        assert!(folded.contains("ａＡｚＺ-あa"));
+        assert!(!folded.contains("A"));
+    }
+
+    // ── per-character masks ───────────────────────────────────────────────
+
+    #[test]
+    fn test_similarity() {
+        let cp = CodePoints::from_string("あいう");
+        assert_eq!(cp.similarity("あいう"), 1.0);
+        assert_eq!(cp.similarity("xyz"), 0.0);
+        assert_eq!(cp.similarity("あxyz"), 0.25);
+        assert_eq!(cp.similarity(""), 1.0);
+        // Counted per character, not per byte or per distinct character.
+        assert_eq!(cp.similarity("ああab"), 0.5);
+    }
+
+    #[test]
+    fn test_weighted_similarity() {
+        let cp = CodePoints::from_string("あい");
+        let double_kanji = |c: char| if c == '漢' { 2.0 } else { 1.0 };
+        assert_eq!(cp.weighted_similarity("あ漢", &double_kanji), 1.0 / 3.0);
+        assert_eq!(cp.weighted_similarity("", &double_kanji), 1.0);
+
+        // Unusable weights count as zero.
+        for bad in [-1.0, f64::NAN, f64::INFINITY] {
+            let weights = move |c: char| if c == 'x' { bad } else { 1.0 };
+            assert_eq!(cp.weighted_similarity("あxい", &weights), 1.0);
+            assert_eq!(cp.weighted_similarity("x", &weights), 1.0);
+        }
+        assert_eq!(cp.weighted_similarity("xyz", &|_| 0.0), 1.0);
+        assert_eq!(
+            cp.weighted_similarity("あxい", &|_| 1.0),
+            cp.similarity("あxい")
+        );
+    }
+
+    #[test]
+    fn test_mask() {
+        let cp = CodePoints::from_string("a𠮷");
+        assert_eq!(cp.mask("a𠮷b"), vec![true, true, false]);
+        assert!(cp.mask("").is_empty());
+
+        let mut buf = vec![false; 10];
+        cp.mask_into("ba", &mut buf);
+        assert_eq!(buf, vec![false, true]);
+    }
+
+    #[test]
+    fn test_mask_bytes() {
+        let cp = CodePoints::from_string("a𠮷");
+        let text = "a𠮷é";
+        let mask = cp.mask_bytes(text);
+        assert_eq!(mask.len(), text.len());
+        assert_eq!(mask, vec![true, true, true, true, true, false, false]);
+
+        // Byte mask agrees with the char mask at every char boundary.
+        let chars = cp.mask(text);
+        for (i, (offset, _)) in text.char_indices().enumerate() {
+            assert_eq!(mask[offset], chars[i]);
+        }
+    }
+
+    // ── validation ────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_validate_ok() {
+        let cp = CodePoints::ascii_printable();
+        assert!(cp.validate("Hello World!").is_ok());
+    }
+
+    #[test]
+    fn test_validate_err() {
+        let cp = CodePoints::ascii_printable();
+        let err = cp.validate("hello\0world").unwrap_err();
+        assert_eq!(err.code_point, 0);
+        assert_eq!(err.position, 5);
+    }
+
+    // ── batch validation ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_batch_validation() {
+        let cp = CodePoints::ascii_printable();
+        let mut rows: Vec<String> = (0..1000).map(|i| format!("row {}", i)).collect();
+        assert!(cp.contains_all(rows.iter().map(String::as_str)));
+        assert_eq!(cp.first_failing_item(rows.iter().map(String::as_str)), None);
+
+        rows[637] = "row 6\u{3000}37".to_string();
+        let items = || rows.iter().map(String::as_str);
+        assert!(!cp.contains_all(items()));
+        let (index, excluded) = cp.first_failing_item(items()).unwrap();
+        assert_eq!(index, 637);
+        assert_eq!((excluded.ch, excluded.position), ('\u{3000}', 5));
+
+        let results = cp.check_all(items());
+        assert_eq!(results.len(), 1000);
+        assert_eq!(results.iter().filter(|r| r.is_err()).count(), 1);
+        let report = results[637].as_ref().unwrap_err();
+        assert_eq!(report.first().byte_index, 5);
+
+        let empty: [&str; 0] = [];
+        assert!(cp.contains_all(empty));
+        assert!(cp.check_all(empty).is_empty());
+    }
+
+    // ── set operations ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_union() {
+        let a = CodePoints::new(vec![0x3042, 0x3044]);
+        let b = CodePoints::new(vec![0x3044, 0x3046]);
+        let u = a.union(&b);
+        assert_eq!(u.len(), 3);
+        assert!(u.contains("あいう"));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = CodePoints::new(vec![0x3042, 0x3044]);
+        let b = CodePoints::new(vec![0x3044, 0x3046]);
+        let i = a.intersection(&b);
+        assert_eq!(i.len(), 1);
+        assert!(i.contains("い"));
+        assert!(!i.contains("あ"));
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = CodePoints::new(vec![0x3042, 0x3044]);
+        let b = CodePoints::new(vec![0x3044, 0x3046]);
+        let d = a.difference(&b);
+        assert_eq!(d.len(), 1);
+        assert!(d.contains("あ"));
+        assert!(!d.contains("い"));
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let a = CodePoints::new(vec![0x3042, 0x3044]);
+        let b = CodePoints::new(vec![0x3044, 0x3046]);
+        let s = a.symmetric_difference(&b);
+        assert_eq!(s.len(), 2);
+        assert!(s.contains("あ"));
+        assert!(s.contains("う"));
+        assert!(!s.contains("い"));
+    }
+
+    #[test]
+    fn test_delta() {
+        let a = CodePoints::new(vec![1, 2, 3]);
+        let b = CodePoints::new(vec![2, 3, 4, 5]);
+        let (added, removed) = a.delta(&b);
+        assert_eq!(added, CodePoints::new(vec![4, 5]));
+        assert_eq!(removed, CodePoints::new(vec![1]));
+        assert_eq!(added.union(&removed), a.symmetric_difference(&b));
+
+        let (added, removed) = a.delta(&a);
+        assert!(added.is_empty() && removed.is_empty());
+    }
+
+    #[test]
+    fn test_with_without() {
+        let base = CodePoints::from_string("abcあい");
+        let banned = base.without("bい");
+        assert_eq!(banned, base.difference(&CodePoints::from_string("bい")));
+        assert_eq!(
+            banned.first_excluded_with_position("aあい"),
+            Some((0x3044, 2))
+        );
+        assert_eq!(banned.first_excluded_with_position("cbあ"), Some((0x62, 1)));
+        assert_eq!(base.first_excluded_with_position("aあい"), None);
+
+        assert_eq!(base.without_codepoints(&[0x62, 0x3044]), banned);
+        assert_eq!(base.without("xyz"), base);
+        assert_eq!(base.without(""), base);
+
+        let widened = banned.with("b");
+        assert_eq!(
+            widened.first_excluded_with_position("cbあい"),
+            Some((0x3044, 3))
+        );
+        assert_eq!(banned.with("bい"), base);
+    }
+
+    #[test]
+    fn test_partition_by_and_compare() {
+        let a = CodePoints::new(vec![1, 2, 3, 5, 8]);
+        let b = CodePoints::new(vec![2, 3, 4, 8, 9]);
+        let (both, only) = a.partition_by(&b);
+        assert_eq!(both, a.intersection(&b));
+        assert_eq!(only, a.difference(&b));
+
+        let cmp = a.compare(&b);
+        assert_eq!(cmp.both, a.intersection(&b));
+        assert_eq!(cmp.only_left, a.difference(&b));
+        assert_eq!(cmp.only_right, b.difference(&a));
+        assert_eq!(b.compare(&a).only_left, cmp.only_right);
+
+        let (both, only) = a.partition_by(&CodePoints::new(vec![]));
+        assert!(both.is_empty());
+        assert_eq!(only, a);
+        let (both, _) = a.to_ordered().partition_by(&b);
+        assert!(both.is_ordered());
+    }
+
+    #[test]
+    fn test_diff_golden() {
+        let old = CodePoints::from_string("あいう～\n");
+        let new = CodePoints::from_string("あいうえぉおかー\t")
+            .union(&CodePoints::new(vec![0xD800, 0xD801]));
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.to_string(),
+            "\
++ U+0009 '\\t' (1 char)
+- U+000A '\\n' (1 char)
++ U+3048..U+304B 'え'..'か' (4 chars)
++ U+30FC 'ー' (1 char)
++ U+D800..U+D801 (2 chars)
+- U+FF5E '～' (1 char)
+"
+        );
+        assert_eq!(new.diff(&old).added(), diff.removed());
+
+        let same = old.diff(&old.to_ordered());
+        assert!(same.is_empty());
+        assert_eq!(same.to_string(), "");
+    }
+
+    #[test]
+    fn test_diff_collapses_large_ranges() {
+        let old = CodePoints::new(vec![]);
+        let new = CodePoints::from_unicode_block(Block::CjkUnifiedIdeographs);
+        let diff = old.diff(&new);
+        assert_eq!(diff.added(), &[(0x4E00, 0x9FFF)]);
+        assert_eq!(
+            diff.to_string(),
+            "+ U+4E00..U+9FFF '一'..'\u{9FFF}' (20992 chars)\n"
+        );
+    }
+
+    #[test]
+    fn test_subset_superset() {
+        let small = CodePoints::new(vec![0x3042]);
+        let big = CodePoints::new(vec![0x3042, 0x3044]);
+        assert!(small.is_subset_of(&big));
+        assert!(big.is_superset_of(&small));
+        assert!(!big.is_subset_of(&small));
+        assert!(!small.is_superset_of(&big));
+    }
+
+    #[test]
+    fn test_overlapping_sets() {
+        let sets = [
+            CodePoints::new(vec![1, 2, 3]),
+            CodePoints::new(vec![4, 5]),
+            CodePoints::new(vec![3, 4, 6]),
+            CodePoints::new(vec![7]),
+        ];
+        let overlaps = CodePoints::overlapping_sets(&sets);
+        assert_eq!(overlaps.len(), 2);
+        assert_eq!((overlaps[0].0, overlaps[0].1), (0, 2));
+        assert_eq!(overlaps[0].2, CodePoints::new(vec![3]));
+        assert_eq!((overlaps[1].0, overlaps[1].1), (1, 2));
+        assert_eq!(overlaps[1].2, CodePoints::new(vec![4]));
+        assert!(!CodePoints::disjoint_partition(&sets));
+
+        assert!(CodePoints::disjoint_partition(&[
+            sets[0].clone(),
+            sets[1].clone(),
+            sets[3].clone()
+        ]));
+        assert!(CodePoints::overlapping_sets(&[]).is_empty());
+        assert!(CodePoints::disjoint_partition(&[]));
+    }
+
+    #[test]
+    fn test_set_ops_with_empty() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]);
+        let empty = CodePoints::new(vec![]);
+
+        assert!(cp.intersection(&empty).is_empty());
+        assert_eq!(cp.union(&empty).len(), 2);
+        assert_eq!(cp.difference(&empty).len(), 2);
+        assert!(empty.difference(&cp).is_empty());
+    }
+
+    // ── char iteration ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_chars_skips_invalid_scalars() {
+        // new() does not validate, so a surrogate and an out-of-range value
+        // can be smuggled in.
+        let cp = CodePoints::new(vec![0x3042, 0xDC00, 0x110000, 0x41]);
+        let mut chars: Vec<char> = cp.chars().collect();
+        chars.sort_unstable();
+        assert_eq!(chars, vec!['A', 'あ']);
+
+        let mut invalid: Vec<u32> = cp.try_chars().filter_map(Result::err).collect();
+        invalid.sort_unstable();
+        assert_eq!(invalid, vec![0xDC00, 0x110000]);
+        assert_eq!(cp.try_chars().filter(Result::is_ok).count(), 2);
+
+        assert_eq!(cp.to_string_of_all(), "Aあ");
+    }
+
+    #[test]
+    fn test_to_string_of_all() {
+        assert_eq!(CodePoints::new(vec![]).to_string_of_all(), "");
+        assert_eq!(CodePoints::crlf().to_string_of_all(), "\n\r");
+        let cp = CodePoints::from_string("𠀋ンあA");
+        assert_eq!(cp.to_string_of_all(), "Aあン𠀋");
+        assert_eq!(CodePoints::from_string(&cp.to_string_of_all()), cp);
+    }
+
+    // ── range queries ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_char_ranges_skip_surrogates() {
+        let cp = CodePoints::from_char_range('\u{D7FB}'..='\u{E000}');
+        assert_eq!(cp.len(), 6);
+        assert!(cp.contains("\u{D7FB}\u{D7FF}\u{E000}"));
+        assert!(!cp.has_any_in_range(0xD800, 0xDFFF));
+        assert_eq!(CodePoints::from('\u{D7FB}'..='\u{E000}'), cp);
+
+        let mut ordered = CodePoints::from_string("あ").to_ordered();
+        assert_eq!(ordered.insert_char_range('\u{D7FF}'..='\u{E001}'), 3);
+        assert_eq!(ordered.insert_char_range('\u{E000}'..='\u{E001}'), 0);
+        assert!(ordered.is_ordered());
+        assert_eq!(ordered.len(), 4);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = CodePoints::from_char_range('b'..='a');
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_iter_excluded_is_lazy() {
+        let cp = CodePoints::from_string("a");
+        let text = "xaxyaz";
+
+        let mut iter = cp.iter_excluded(text);
+        assert_eq!(iter.next().map(|e| e.position), Some(0));
+        assert_eq!(iter.next().map(|e| e.position), Some(2));
+        // Nothing past the second violation has been scanned.
+        assert_eq!(iter.inner.chars.as_str(), "yaz");
+
+        let mut unique = cp.iter_excluded_unique(text);
+        assert_eq!(unique.next().map(|e| e.ch), Some('x'));
+        assert_eq!(unique.next().map(|e| e.ch), Some('y'));
+        assert_eq!(unique.inner.chars.as_str(), "az");
+        assert_eq!(unique.seen.len(), 2);
+    }
+
+    #[test]
+    fn test_iter_excluded_matches_eager() {
+        let cp = CodePoints::from_string("あい");
+        for text in ["", "あい", "うあうえい𠮷う", "xyzzy"] {
+            let lazy: Vec<(char, usize)> =
+                cp.iter_excluded(text).map(|e| (e.ch, e.position)).collect();
+            let eager: Vec<(char, usize)> = cp.iter_excluded_chars(text).collect();
+            assert_eq!(lazy, eager);
+
+            let unique: Vec<u32> = cp
+                .iter_excluded_unique(text)
+                .map(|e| e.code_point)
+                .collect();
+            assert_eq!(unique, cp.all_excluded(text));
+            assert_eq!(
+                cp.iter_excluded(text).next(),
+                cp.first_excluded_detail(text)
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_codepoints_in_range() {
+        let cp = CodePoints::new(vec![0x41, 0x42, 0x3042, 0x3044, 0x2000B]);
+        assert_eq!(cp.count_codepoints_in_range(0x41, 0x41), 1);
+        assert_eq!(cp.count_codepoints_in_range(0x00, 0x7F), 2);
+        assert_eq!(cp.count_codepoints_in_range(0x3040, 0x309F), 2);
+        assert_eq!(cp.count_codepoints_in_range(0, u32::MAX), 5);
+        assert_eq!(cp.count_codepoints_in_range(0x7F, 0x00), 0); // empty range
+    }
+
+    #[test]
+    fn test_has_any_in_range() {
+        let cp = CodePoints::new(vec![0x41, 0x3042]);
+        assert!(cp.has_any_in_range(0x41, 0x41));
+        assert!(cp.has_any_in_range(0, u32::MAX));
+        assert!(!cp.has_any_in_range(0x30A0, 0x30FF));
+        assert!(!cp.has_any_in_range(0x3042, 0x3041)); // empty range
+        assert!(!CodePoints::new(vec![]).has_any_in_range(0, u32::MAX));
+    }
+
+    #[test]
+    fn test_range_queries_on_large_hash_set() {
+        // Every third code point up to U+2FFFF, in the default HashSet.
+        let members: Vec<u32> = (0..0x30000).step_by(3).collect();
+        let cp = CodePoints::new(members.clone());
+        assert_eq!(cp.backend(), Backend::HashSet);
+        let naive = |lo: u32, hi: u32| members.iter().filter(|&&m| lo <= m && m <= hi).count();
+
+        // Narrow ranges probe, wide ranges scan; both must agree.
+        for (lo, hi) in [
+            (0, 0),
+            (1, 2),
+            (0x3040, 0x309F),
+            (0, 0x2FFFF),
+            (0, u32::MAX),
+        ] {
+            assert_eq!(cp.count_codepoints_in_range(lo, hi), naive(lo, hi));
+            assert_eq!(cp.has_any_in_range(lo, hi), naive(lo, hi) > 0);
+        }
+        assert!(!cp.has_any_in_range(0x30000, u32::MAX));
+        assert_eq!(cp.count_codepoints_in_range(0x30000, u32::MAX), 0);
+    }
+
+    #[test]
+    fn test_find_excluded_and_included_in() {
+        let empty = CodePoints::new(vec![]);
+        assert_eq!(empty.find_excluded_in(0xD800..=0xE000), Some('\u{E000}'));
+        assert_eq!(empty.find_excluded_in(0xD800..=0xDFFF), None);
+        assert_eq!(empty.find_included_in(0..=u32::MAX), None);
+
+        let around = CodePoints::new((0xD7F0..=0xE010).collect());
+        assert_eq!(around.find_excluded_in(0xD7F0..=0xE010), None);
+        assert_eq!(around.find_included_in(0xD800..=u32::MAX), Some('\u{E000}'));
+        assert_eq!(around.find_included_in(0xD800..=0xDFFF), None);
+
+        // ranges past U+10FFFF stop at char::MAX
+        let last = CodePoints::new(vec![0x10FFFF]);
+        assert_eq!(last.find_excluded_in(0x10FFFF..=u32::MAX), None);
+        assert_eq!(empty.find_excluded_in(0x10FFFF..=u32::MAX), Some(char::MAX));
+
+        // small range probes, large range scans the set; both agree
+        let cp = CodePoints::new(vec![0x41, 0x3042, 0xD800, 0x20BB7]);
+        assert_eq!(cp.find_included_in(0x3042..=0x3043), Some('あ'));
+        assert_eq!(cp.find_included_in(0x42..=0x10FFFF), Some('あ'));
+        assert_eq!(cp.find_included_in(0xD000..=0xE000), None);
+        assert_eq!(
+            cp.to_ordered().find_included_in(0x3043..=u32::MAX),
+            Some('𠮷')
+        );
+        assert_eq!(cp.find_excluded_in(0x41..=0x41), None);
+        assert_eq!(cp.find_excluded_in(0x41..=0x42), Some('B'));
+    }
+
+    // ── memory introspection ──────────────────────────────────────────────
+
+    #[test]
+    fn test_shrink_to_fit_reduces_memory_usage() {
+        let mut cp = CodePoints::new(vec![0x3042; 4096]);
+        let before = cp.stats();
+        assert_eq!(before.len, 1);
+        assert!(before.capacity >= 4096);
+
+        let usage = cp.memory_usage();
+        cp.shrink_to_fit();
+        assert!(cp.memory_usage() < usage);
+        assert!(cp.stats().capacity < before.capacity);
+        assert!(cp.contains("あ"));
+        assert_eq!(cp.len(), 1);
+    }
+
+    #[test]
+    fn test_memory_usage_lower_bound() {
+        let cp = CodePoints::new(vec![]);
+        assert!(cp.memory_usage() >= std::mem::size_of::<CodePoints>());
+        let cp = CodePoints::ascii_all();
+        assert!(cp.memory_usage() >= cp.len() * std::mem::size_of::<u32>());
+    }
+
+    // ── Unicode blocks ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_unicode_block_lookup() {
+        assert_eq!(unicode_block(0x41), Some("Basic Latin"));
+        assert_eq!(unicode_block(0x3040), Some("Hiragana"));
+        assert_eq!(unicode_block(0x309F), Some("Hiragana"));
+        assert_eq!(
+            unicode_block(0x20B9F),
+            Some("CJK Unified Ideographs Extension B")
+        );
+        assert_eq!(unicode_block(0x086F), Some("Syriac Supplement"));
+        assert_eq!(unicode_block(0x2FE0), None); // unassigned gap
+        assert_eq!(unicode_block(0x110000), None);
+    }
+
+    #[test]
+    fn test_group_by_unicode_block() {
+        let cp = CodePoints::new(vec![0x41, 0x3042, 0x30A2, 0x2FE0, 0xE000, 0x10FFFD]);
+        let groups = cp.group_by_unicode_block();
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups["Basic Latin"], CodePoints::new(vec![0x41]));
+        assert_eq!(groups["Hiragana"], CodePoints::new(vec![0x3042]));
+        assert_eq!(groups["Katakana"], CodePoints::new(vec![0x30A2]));
+        assert_eq!(
+            groups[OTHER_BLOCK],
+            CodePoints::new(vec![0x2FE0, 0xE000, 0x10FFFD])
+        );
+        assert!(CodePoints::new(vec![]).group_by_unicode_block().is_empty());
+    }
+
+    #[test]
+    fn test_restrict_to_and_count_in() {
+        use std::ops::Bound;
+
+        let members = vec![0x00, 0x41, 0x3042, 0xFFFF, 0x10000, 0x20B9F, u32::MAX];
+        for backend in BACKENDS {
+            let cp = CodePoints::with_backend(members.clone(), backend);
+
+            // Full containment.
+            assert_eq!(cp.restrict_to(..), cp);
+            assert_eq!(cp.restrict_to(0..=u32::MAX), cp);
+            assert_eq!(cp.count_in(..), cp.len());
+
+            // Partial windows, every bound kind.
+            let bmp = cp.restrict_to(..=0xFFFF);
+            assert_eq!(bmp, CodePoints::new(vec![0x00, 0x41, 0x3042, 0xFFFF]));
+            assert_eq!(bmp.backend(), backend);
+            assert_eq!(cp.count_in(..0x10000), 4);
+            assert_eq!(cp.count_in(0x10000..), 3);
+            assert_eq!(cp.count_in(0x41..=0x41), 1);
+            assert_eq!(
+                cp.restrict_to((Bound::Excluded(0x41), Bound::Excluded(0x10000))),
+                CodePoints::new(vec![0x3042, 0xFFFF])
+            );
+
+            // Empty intersections and empty ranges.
+            assert!(cp.restrict_to(0x42..0x3042).is_empty());
+            assert_eq!(cp.count_in(0x30000..0x110000), 0);
+            #[allow(clippy::reversed_empty_ranges)]
+            let reversed = 0x3042..=0x41;
+            assert!(cp.restrict_to(reversed).is_empty());
+            assert_eq!(cp.count_in(0x41..0x41), 0);
+            assert_eq!(cp.count_in(..0), 0);
+            assert_eq!(
+                cp.count_in((Bound::Excluded(u32::MAX), Bound::Unbounded)),
+                0
+            );
+            assert!(CodePoints::with_backend(vec![], backend)
+                .restrict_to(..)
+                .is_empty());
+        }
+    }
+
+    /// `count_in` and `count_codepoints_in_range` on `set`, over ranges that
+    /// hit, miss and straddle `members`.
+    fn assert_range_counts_agree(set: &CodePoints, members: &[u32]) {
+        let bounds = [0, 0x41, 0x42, 0x3042, 0xFFFF, 0x10000, 0x20B9F, u32::MAX];
+        for &lo in &bounds {
+            for &hi in &bounds {
+                let naive = members.iter().filter(|&&m| lo <= m && m <= hi).count();
+                assert_eq!(set.count_in(lo..=hi), naive, "{lo:#x}..={hi:#x}");
+                assert_eq!(set.count_codepoints_in_range(lo, hi), naive);
+                assert_eq!(set.has_any_in_range(lo, hi), naive > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_in_matches_count_codepoints_in_range() {
+        let members = vec![0x00, 0x41, 0x3042, 0xFFFF, 0x10000, 0x20B9F];
+        for backend in BACKENDS {
+            let cp = CodePoints::with_backend(members.clone(), backend);
+            assert_range_counts_agree(&cp, &members);
+        }
+
+        #[cfg(feature = "mmap")]
+        {
+            let path = temp_table("range-counts");
+            CodePoints::new(members.clone())
+                .write_sorted_file(&path)
+                .unwrap();
+            // SAFETY: only this test touches the file, and not while mapped.
+            let mapped = unsafe { CodePoints::map_sorted_file(&path) }.unwrap();
+            assert_range_counts_agree(&mapped, &members);
+            drop(mapped);
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_block_spans_flag_polluted_hiragana() {
+        let clean: Vec<u32> = (0x3041..=0x3096).collect();
+        // Three ideographs that look like typos for hiragana literals.
+        let mut polluted = clean.clone();
+        polluted.extend([0x4E9C, 0x5516, 0x5A03]); // 亜 唖 娃
+
+        for backend in BACKENDS {
+            let clean = CodePoints::with_backend(clean.clone(), backend);
+            assert!(clean.is_single_block());
+            assert_eq!(
+                clean.block_spans(),
+                vec![(UnicodeBlockId::from(Block::Hiragana), 86)]
+            );
+            assert!(clean.outliers(10).is_empty());
+
+            let cp = CodePoints::with_backend(polluted.clone(), backend);
+            assert!(!cp.is_single_block());
+            assert_eq!(
+                cp.block_spans(),
+                vec![
+                    (UnicodeBlockId::from(Block::Hiragana), 86),
+                    (UnicodeBlockId::from(Block::CjkUnifiedIdeographs), 3),
+                ]
+            );
+            assert_eq!(cp.outliers(4), CodePoints::from_string("亜唖娃"));
+            assert!(cp.outliers(3).is_empty());
+            assert!(cp.outliers(0).is_empty());
+            assert_eq!(cp.outliers(usize::MAX), cp);
+        }
+    }
+
+    #[test]
+    fn test_block_spans_outside_and_private_use() {
+        let cp = CodePoints::new(vec![0x2FE0, 0xE000, 0xE001, 0x110000, u32::MAX]);
+        let spans = cp.block_spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].0.name(), "Private Use Area");
+        assert_eq!(spans[0].0.range(), Some((0xE000, 0xF8FF)));
+        assert_eq!(spans[1], (UnicodeBlockId::OUTSIDE, 3));
+        assert_eq!(UnicodeBlockId::OUTSIDE.name(), OTHER_BLOCK);
+        assert_eq!(UnicodeBlockId::OUTSIDE.range(), None);
+        assert_eq!(spans.iter().map(|&(_, n)| n).sum::<usize>(), cp.len());
+        assert!(CodePoints::new(vec![]).block_spans().is_empty());
+        for block in Block::ALL {
+            assert_eq!(UnicodeBlockId::from(block).name(), block.name());
+        }
+    }
+
+    #[test]
+    fn test_from_unicode_block_sizes() {
+        let sizes: Vec<usize> = Block::ALL
+            .iter()
+            .map(|&b| CodePoints::from_unicode_block(b).len())
+            .collect();
+        assert_eq!(sizes, vec![64, 96, 96, 16, 6592, 20992, 240, 42720]);
+    }
+
+    #[test]
+    fn test_block_matches_blocks_table() {
+        for block in Block::ALL {
+            let (first, last) = block.range();
+            assert!(unicode_blocks::UNICODE_BLOCKS.contains(&(first, last, block.name())));
+
+            let groups = CodePoints::from_unicode_block(block).group_by_unicode_block();
+            assert_eq!(groups.len(), 1);
+            assert!(groups.contains_key(block.name()));
+        }
+    }
+
+    // ── character classes ───────────────────────────────────────────────
+
+    #[test]
+    fn test_char_class_names() {
+        for class in CharClass::ALL {
+            assert_eq!(class.as_str().parse::<CharClass>(), Ok(class));
+            assert_eq!(class.to_string(), class.as_str());
+        }
+        let err = "Kanji".parse::<CharClass>().unwrap_err();
+        assert_eq!(err.name(), "Kanji");
+        assert_eq!(err.to_string(), "unknown character class `Kanji`");
+    }
+
+    #[test]
+    fn test_decompose() {
+        let mut set = CodePoints::from_string("あいうゝ゛アイウエーｱｲ亜愛𠮷ABCｚ123０ΑЖ、。！ ±");
+        set = set.union(&CodePoints::new(vec![0x0A, 0xE000, 0x1F600]));
+        assert_eq!(
+            set.decompose(),
+            vec![
+                (CharClass::Hiragana, 5),
+                (CharClass::Katakana, 7),
+                (CharClass::Kanji, 3),
+                (CharClass::Latin, 4),
+                (CharClass::Digit, 4),
+                (CharClass::Greek, 1),
+                (CharClass::Cyrillic, 1),
+                (CharClass::Symbol, 5),
+                (CharClass::Uncategorized, 3),
+            ]
+        );
+        let total: usize = set.decompose().iter().map(|&(_, n)| n).sum();
+        assert_eq!(total, set.len());
+
+        assert_eq!(
+            set.subset_of_class(CharClass::Kanji),
+            CodePoints::from_string("亜愛𠮷")
+        );
+        assert!(set.subset_of_class(CharClass::Katakana).contains("ーｱ"));
+        assert_eq!(set.uncategorized(10), vec![0x0A, 0xE000, 0x1F600]);
+        assert_eq!(set.uncategorized(1), vec![0x0A]);
+        assert!(CodePoints::new(vec![]).decompose().is_empty());
+    }
+
+    #[test]
+    fn test_class_subsets_keep_backend() {
+        let ordered = CodePoints::new_ordered(vec![0x3042, 0x30A2, 0x4E9C]);
+        assert!(ordered.subset_of_class(CharClass::Hiragana).is_ordered());
+        assert!(ordered.subset_matching_block(Block::Katakana).is_ordered());
+        assert_eq!(
+            ordered.subset_matching_block(Block::Katakana),
+            CodePoints::from_string("ア")
+        );
+    }
+
+    // ── compact serialization ─────────────────────────────────────────────
+
+    #[test]
+    fn test_compact_round_trip() {
+        let cp = CodePoints::from_string("あい𠀋A");
+        let bytes = cp.serialize_compact();
+        assert_eq!(&bytes[..4], b"JCP1");
+        assert_eq!(&bytes[4..8], &4u32.to_le_bytes());
+        assert_eq!(&bytes[8..12], &0x41u32.to_le_bytes()); // sorted
+        assert_eq!(CodePoints::deserialize_compact(&bytes).unwrap(), cp);
+
+        let empty = CodePoints::new(vec![]);
+        let bytes = empty.serialize_compact();
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(CodePoints::deserialize_compact(&bytes).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_compact_non_scalar_values() {
+        for cp in [0xD800, 0x110000] {
+            let bytes = CodePoints::new(vec![0x41, cp]).serialize_compact();
+            assert_eq!(
+                CodePoints::deserialize_compact(&bytes),
+                Err(DeserializeError::InvalidCodePoint(cp))
+            );
+        }
+    }
+
+    #[test]
+    fn test_compact_deterministic() {
+        let a = CodePoints::new(vec![3, 1, 2]);
+        let b = CodePoints::new(vec![2, 3, 1]);
+        assert_eq!(a.serialize_compact(), b.serialize_compact());
+    }
+
+    #[test]
+    fn test_deserialize_compact_errors() {
+        let bytes = CodePoints::from_string("あい").serialize_compact();
+
+        for len in 0..bytes.len() {
+            assert!(
+                matches!(
+                    CodePoints::deserialize_compact(&bytes[..len]),
+                    Err(DeserializeError::LengthMismatch { .. })
+                ),
+                "len {}",
+                len
+            );
+        }
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            CodePoints::deserialize_compact(&trailing),
+            Err(DeserializeError::LengthMismatch {
+                expected: 16,
+                actual: 17
+            })
+        );
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert_eq!(
+            CodePoints::deserialize_compact(&bad_magic),
+            Err(DeserializeError::InvalidMagic)
+        );
+        assert_eq!(
+            CodePoints::deserialize_compact(b"XY"),
+            Err(DeserializeError::InvalidMagic)
+        );
+
+        let mut surrogate = bytes;
+        surrogate[8..12].copy_from_slice(&0xD800u32.to_le_bytes());
+        assert_eq!(
+            CodePoints::deserialize_compact(&surrogate),
+            Err(DeserializeError::InvalidCodePoint(0xD800))
+        );
+    }
+
+    // ── sorted table files ────────────────────────────────────────────────
+
+    /// A path in the temp directory unique to this process and test.
+    #[cfg(feature = "mmap")]
+    pub(super) fn temp_table(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("jcp-{}-{}.u32", std::process::id(), name))
+    }
+
+    // ── ASCII factories ───────────────────────────────────────────────────
+
+    #[test]
+    fn test_ascii_control() {
+        let cp = CodePoints::ascii_control();
+        assert!(cp.contains("\n\r\t"));
+        assert!(!cp.contains("a"));
+        assert!(!cp.contains("あ"));
+    }
+
+    #[test]
+    fn test_ascii_printable() {
+        let cp = CodePoints::ascii_printable();
+        assert!(cp.contains("Hello 123!@#~"));
+        assert!(!cp.contains("\n"));
+        assert!(!cp.contains("あ"));
+        // JIS X 0201 special chars NOT in plain ASCII printable
+        assert!(!cp.contains("Hello‾")); // Overline
+        assert!(!cp.contains("¥100")); // Yen symbol
+    }
+
+    #[test]
+    fn test_crlf() {
+        let cp = CodePoints::crlf();
+        assert!(cp.contains("\r\n"));
+        assert!(!cp.contains("\t"));
+        assert!(!cp.contains("a"));
+    }
+
+    #[test]
+    fn test_ascii_all() {
+        let cp = CodePoints::ascii_all();
+        assert!(cp.contains("Hello\n\r\t"));
+        assert!(!cp.contains("あ"));
+    }
+
+    #[test]
+    fn test_ascii_cached_identity() {
+        // Each cached() call must return the exact same pointer.
+        assert!(std::ptr::eq(
+            CodePoints::ascii_control_cached(),
+            CodePoints::ascii_control_cached()
+        ));
+        assert!(std::ptr::eq(
+            CodePoints::ascii_printable_cached(),
+            CodePoints::ascii_printable_cached()
+        ));
+        assert!(std::ptr::eq(
+            CodePoints::crlf_cached(),
+            CodePoints::crlf_cached()
+        ));
+        assert!(std::ptr::eq(
+            CodePoints::ascii_all_cached(),
+            CodePoints::ascii_all_cached()
+        ));
+    }
+
+    #[test]
+    fn test_ascii_cached_equals_uncached() {
+        assert_eq!(
+            *CodePoints::ascii_control_cached(),
+            CodePoints::ascii_control()
+        );
+        assert_eq!(
+            *CodePoints::ascii_printable_cached(),
+            CodePoints::ascii_printable()
+        );
+        assert_eq!(*CodePoints::crlf_cached(), CodePoints::crlf());
+        assert_eq!(*CodePoints::ascii_all_cached(), CodePoints::ascii_all());
+    }
+
+    // ── Unicode factories ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_zero_width() {
+        let cp = CodePoints::zero_width();
+        assert_eq!(cp.len(), 5);
+        assert!(cp.contains("\u{200B}\u{200C}\u{200D}\u{2060}\u{FEFF}"));
+        assert!(!cp.contains(" "));
+        assert_eq!(*CodePoints::zero_width_cached(), cp);
+    }
+
+    #[test]
+    fn test_sort_key_kuten() {
+        let key = |c| CodePoints::sort_key_kuten(c);
+        let at = |ku: u16, ten: u16| Some((ku - 1) * 94 + ten - 1);
+        assert_eq!(key('　'), at(1, 1));
+        assert_eq!(key('◯'), at(2, 94));
+        assert_eq!(key('０'), at(3, 16));
+        assert_eq!(key('ｚ'), at(3, 90));
+        assert_eq!(key('ん'), at(4, 83));
+        assert_eq!(key('Ё'), at(7, 7));
+        assert_eq!(key('╂'), at(8, 32));
+        assert_eq!(key('腕'), at(47, 51));
+        assert_eq!(key('弌'), at(48, 1));
+        assert_eq!(key('熙'), at(84, 6));
+        for c in ['A', 'ｱ', '①', '髙', '𠮷'] {
+            assert_eq!(key(c), None, "{c}");
+        }
+
+        // Unicode order and kuten order differ for Ё (U+0401) and Å (U+212B).
+        let mut chars: Vec<char> = CodePoints::from_string("ЖЁЕ\u{212B}‰").chars().collect();
+        chars.sort_by_key(|&c| key(c));
+        assert_eq!(chars, ['\u{212B}', '‰', 'Е', 'Ё', 'Ж']);
+    }
+
+    // ── trait impls ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_display() {
+        let cp = CodePoints::new(vec![0x3042, 0x3044]);
+        assert_eq!(cp.to_string(), "CodePoints(2 items)");
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let cp: CodePoints = vec![0x3042u32].into();
+        assert!(cp.contains("あ"));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let cp: CodePoints = "あい".into();
+        assert_eq!(cp.len(), 2);
+    }
+
+    #[test]
+    fn test_hash_consistency() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        // Two sets with same elements but potentially different insertion order.
+        let a = CodePoints::new(vec![0x3042, 0x3044]);
+        let b = CodePoints::new(vec![0x3044, 0x3042]);
+
+        let mut h1 = DefaultHasher::new();
+        let mut h2 = DefaultHasher::new();
+        a.hash(&mut h1);
+        b.hash(&mut h2);
+
+        assert_eq!(a, b);
+        assert_eq!(h1.finish(), h2.finish());
+    }
+
+    // ── LazyCodePoints ────────────────────────────────────────────────────
+
+    #[test]
+    fn test_lazy_codepoints_deref() {
+        static LAZY: LazyCodePoints = LazyCodePoints::new(|| CodePoints::from_string("あい"));
+        assert!(format!("{:?}", LazyCodePoints::new(CodePoints::crlf)).contains("uninitialized"));
+        assert!(LAZY.contains("あい"));
+        assert!(LAZY.is_initialized());
+        assert_eq!(LAZY.len(), 2);
+        assert!(contains_all_in_any(
+            "あx",
+            &[&LAZY, CodePoints::ascii_printable_cached()]
+        ));
+        assert!(std::ptr::eq(LAZY.get(), &*LAZY));
+    }
+
+    #[test]
+    fn test_lazy_codepoints_character_set() {
+        static KANA: LazyCodePoints =
+            LazyCodePoints::named("kana", || CodePoints::from_string("あア"));
+        let sets: [&dyn CharacterSet; 2] = [&KANA, &LazyCodePoints::new(CodePoints::crlf)];
+        assert_eq!(sets.map(|set| set.name()), ["kana", "unnamed"]);
+        assert!(CharacterSet::contains(&KANA, "アあ"));
+        assert_eq!(
+            CharacterSet::validate(&KANA, "あx").unwrap_err().position,
+            1
+        );
+        assert!(contains_all_in_any(
+            "あ\r",
+            &[sets[0].codepoints(), sets[1].codepoints()]
+        ));
+    }
+
+    #[test]
+    fn test_lazy_codepoints_single_initialization() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Barrier;
+
+        static INIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+        static LAZY: LazyCodePoints = LazyCodePoints::new(|| {
+            INIT_COUNT.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            CodePoints::ascii_printable()
+        });
+
+        let barrier = Barrier::new(8);
+        let sets: Vec<usize> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    s.spawn(|| {
+                        barrier.wait();
+                        LAZY.get() as *const CodePoints as usize
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(INIT_COUNT.load(Ordering::SeqCst), 1);
+        assert!(sets.windows(2).all(|w| w[0] == w[1]));
+    }
+
+    // ── contains_all_in_any ───────────────────────────────────────────────
+
+    #[test]
+    fn test_contains_all_in_any_basic() {
+        let hira = CodePoints::new(vec![0x3042, 0x3044, 0x3046]); // あ, い, う
+        let kata = CodePoints::new(vec![0x30A2, 0x30A4, 0x30A6]); // ア, イ, ウ
+
+        assert!(contains_all_in_any("あア", &[&hira, &kata]));
+        assert!(contains_all_in_any("あいう", &[&hira]));
+        assert!(contains_all_in_any("アイウ", &[&kata]));
+        assert!(!contains_all_in_any("xyz", &[&hira, &kata]));
+        assert!(!contains_all_in_any("あアx", &[&hira, &kata])); // x not in either
+    }
+
+    #[test]
+    fn test_contains_all_in_any_empty_text() {
+        let cp = CodePoints::new(vec![0x3042]);
+        // Empty text with non-empty sets → vacuously true
+        assert!(contains_all_in_any("", &[&cp]));
+    }
+
+    #[test]
+    fn test_contains_all_in_any_empty_sets() {
+        // Empty sets → always false
+        assert!(!contains_all_in_any("a", &[]));
+        assert!(!contains_all_in_any("", &[]));
+    }
+
+    #[test]
+    fn test_contains_all_in_any_three_sets() {
+        let hira = CodePoints::new(vec![0x3042]); // あ
+        let kata = CodePoints::new(vec![0x30A2]); // ア
+        let ascii = CodePoints::ascii_printable();
+
+        // Each char in a different set
+        assert!(contains_all_in_any("あアA", &[&hira, &kata, &ascii]));
+        // π (U+03C0) not in any
+        assert!(!contains_all_in_any("あアAπ", &[&hira, &kata, &ascii]));
+        // "Hello" is entirely in ascii
+        assert!(contains_all_in_any("Hello", &[&hira, &kata, &ascii]));
+    }
+
+    #[test]
+    fn test_contains_all_in_any_overlap() {
+        // Character present in multiple sets — should still pass.
+        let cp1 = CodePoints::new(vec![0x3042, 0x3046]); // あ, う
+        let cp2 = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        assert!(contains_all_in_any("あいう", &[&cp1, &cp2]));
+    }
+
+    #[test]
+    fn test_attribute_chars() {
+        let cp1 = CodePoints::new(vec![0x3042, 0x3046]); // あ, う
+        let cp2 = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+
+        // あ is in both sets; the first one listed wins.
+        assert_eq!(
+            CodePoints::attribute_chars("あいうx", &[&cp1, &cp2]),
+            [Some(0), Some(1), Some(0), None]
+        );
+        assert_eq!(
+            CodePoints::attribute_chars("あいうx", &[&cp2, &cp1]),
+            [Some(0), Some(0), Some(1), None]
+        );
+        assert_eq!(CodePoints::attribute_chars("あx", &[]), [None, None]);
+        assert!(CodePoints::attribute_chars("", &[&cp1]).is_empty());
+
+        let sets = [&cp1];
+        let iter = CodePoints::iter_attributed("あい", &sets);
+        assert_eq!(iter.size_hint().1, Some(6));
+        assert_eq!(iter.collect::<Vec<_>>(), [Some(0), None]);
+    }
+
+    #[test]
+    fn test_group_matches_contains_all_in_any() {
+        let cp1 = CodePoints::new(vec![0x3042, 0x3046]); // あ, う
+        let cp2 = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        let group = CodePointsGroup::new(&[&cp1, &cp2]);
+        for text in ["", "あいう", "あえ", "x"] {
+            assert_eq!(
+                group.contains(text),
+                contains_all_in_any(text, &[&cp1, &cp2])
+            );
+        }
+        assert_eq!(group.union().len(), 3);
+        let empty = CodePointsGroup::new(&[]);
+        assert_eq!(empty.contains(""), contains_all_in_any("", &[]));
+    }
+
+    #[test]
+    fn test_group_cached_for_concurrent() {
+        let kana: &'static CodePoints = Box::leak(Box::new(CodePoints::from_string("かな")));
+        let ascii = CodePoints::ascii_printable_cached();
+        let sets: &'static [&'static CodePoints] = Box::leak(Box::new([ascii, kana]));
+
+        let groups: Vec<usize> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut first = None;
+                        for _ in 0..200 {
+                            let group = CodePointsGroup::cached_for(sets);
+                            assert!(group.contains("abc かな") && !group.contains("カ"));
+                            let addr = group as *const CodePointsGroup as usize;
+                            assert_eq!(*first.get_or_insert(addr), addr);
+                        }
+                        first.unwrap()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(groups.windows(2).all(|w| w[0] == w[1]));
+
+        let group = CodePointsGroup::cached_for(sets);
+        assert!(std::ptr::eq(
+            group,
+            CodePointsGroup::cached_for(&[kana, ascii, kana])
+        ));
+        assert!(!std::ptr::eq(group, CodePointsGroup::cached_for(&[kana])));
+    }
+}
@@ -0,0 +1,769 @@
+//! Spec strings (`"U+3041..U+3096, U+30FC"`) and ICU UnicodeSet patterns
+//! (`[ぁ-んー]`), parsed into and formatted from [`CodePoints`].
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::{sorted_ranges, CodePoints};
+
+// ── spec strings ──────────────────────────────────────────────────────────────
+
+/// Error returned when parsing a [`CodePoints`] spec string with
+/// [`str::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSpecError {
+    token: String,
+    reason: &'static str,
+}
+
+impl ParseSpecError {
+    fn new(token: &str, reason: &'static str) -> Self {
+        Self {
+            token: token.to_string(),
+            reason,
+        }
+    }
+
+    /// Returns the item of the spec string that could not be parsed.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl fmt::Display for ParseSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid code point spec `{}`: {}",
+            self.token, self.reason
+        )
+    }
+}
+
+impl std::error::Error for ParseSpecError {}
+
+/// Parses one spec value: `U+XXXX`, `0xXXXX` or a decimal number.
+fn parse_spec_value(item: &str, value: &str) -> Result<u32, ParseSpecError> {
+    let parsed = if let Some(hex) = value
+        .strip_prefix("U+")
+        .or_else(|| value.strip_prefix("u+"))
+        .or_else(|| value.strip_prefix("0x"))
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        u32::from_str_radix(hex, 16)
+    } else {
+        value.parse::<u32>()
+    };
+    let cp = parsed
+        .map_err(|_| ParseSpecError::new(item, "expected U+XXXX, 0xXXXX or a decimal number"))?;
+    if char::from_u32(cp).is_none() {
+        return Err(ParseSpecError::new(item, "not a Unicode scalar value"));
+    }
+    Ok(cp)
+}
+
+/// Splits a spec string into items.
+///
+/// Items are separated by commas and whitespace, except that whitespace
+/// around a range separator (`U+3041 .. U+3096`) stays inside the item.
+fn spec_items(s: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    for group in s.split(',') {
+        let mut current: Option<(usize, usize)> = None;
+        for token in group.split_whitespace() {
+            let start = token.as_ptr() as usize - group.as_ptr() as usize;
+            let end = start + token.len();
+            let item = current.and_then(|(from, to)| group.get(from..to));
+            let joins = item.is_some_and(|item| item.ends_with("..") || item.ends_with('-'))
+                || token.starts_with("..")
+                || token.starts_with('-');
+            match current {
+                Some((from, _)) if joins => current = Some((from, end)),
+                _ => {
+                    items.extend(item);
+                    current = Some((start, end));
+                }
+            }
+        }
+        items.extend(current.and_then(|(from, to)| group.get(from..to)));
+    }
+    items
+}
+
+impl std::str::FromStr for CodePoints {
+    type Err = ParseSpecError;
+
+    /// Parses a list of numeric code points and ranges.
+    ///
+    /// Items are separated by commas and/or whitespace.  Each item is a
+    /// value (`U+3042`, `0x3042` or `12354`) or an inclusive range of two
+    /// values joined by `..` or `-` (`U+3041..U+3096`), optionally with
+    /// whitespace around the separator (`U+3041 .. U+3096`).  Surrogates
+    /// inside a range are skipped.  An empty string yields an empty set.
+    ///
+    /// See [`CodePoints::to_spec_string`] for the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp: CodePoints = "U+3041..U+3096, U+30FC 0x3000".parse().unwrap();
+    /// assert_eq!(cp.len(), 0x56 + 2);
+    /// assert!(cp.contains("あー\u{3000}"));
+    ///
+    /// let err = "U+3041, U+ZZZZ".parse::<CodePoints>().unwrap_err();
+    /// assert_eq!(err.token(), "U+ZZZZ");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut codepoints = HashSet::new();
+        for item in spec_items(s) {
+            let range = item.split_once("..").or_else(|| item.split_once('-'));
+            match range {
+                Some((start, end)) => {
+                    let start = parse_spec_value(item, start.trim())?;
+                    let end = parse_spec_value(item, end.trim())?;
+                    if start > end {
+                        return Err(ParseSpecError::new(item, "range start is after its end"));
+                    }
+                    codepoints.extend((start..=end).filter(|&cp| char::from_u32(cp).is_some()));
+                }
+                None => {
+                    codepoints.insert(parse_spec_value(item, item)?);
+                }
+            }
+        }
+        Ok(Self {
+            codepoints: codepoints.into(),
+        })
+    }
+}
+
+impl CodePoints {
+    /// Formats this set as a spec string that [`str::parse`] accepts.
+    ///
+    /// Code points are listed in ascending order as `U+XXXX`, separated by
+    /// `", "`.  Runs of three or more consecutive code points are written as
+    /// a `U+XXXX..U+YYYY` range, so large contiguous sets stay short enough
+    /// to log.
+    ///
+    /// Only sets of Unicode scalar values round-trip.  Surrogates and values
+    /// above U+10FFFF, which [`Self::new`] accepts, are written unchanged.
+    /// Parsing rejects them as a value or range end and skips surrogates
+    /// inside a range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_string("あぃいぅ ー");
+    /// assert_eq!(cp.to_spec_string(), "U+0020, U+3042..U+3045, U+30FC");
+    /// assert_eq!(cp.to_spec_string().parse::<CodePoints>().unwrap(), cp);
+    /// ```
+    pub fn to_spec_string(&self) -> String {
+        let mut items = Vec::new();
+        for (lo, hi) in sorted_ranges(self.codepoints.iter().copied()) {
+            if hi - lo >= 2 {
+                items.push(format!("U+{:04X}..U+{:04X}", lo, hi));
+            } else {
+                items.extend((lo..=hi).map(|cp| format!("U+{:04X}", cp)));
+            }
+        }
+        items.join(", ")
+    }
+}
+
+// ── UnicodeSet patterns ───────────────────────────────────────────────────────
+
+/// Error returned by [`CodePoints::from_unicode_set_pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUnicodeSetError {
+    position: usize,
+    reason: &'static str,
+}
+
+impl ParseUnicodeSetError {
+    fn new(position: usize, reason: &'static str) -> Self {
+        Self { position, reason }
+    }
+
+    /// Returns the zero-based character index in the pattern at which
+    /// parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a short description of the problem.
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for ParseUnicodeSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid UnicodeSet pattern at position {}: {}",
+            self.position, self.reason
+        )
+    }
+}
+
+impl std::error::Error for ParseUnicodeSetError {}
+
+/// Pattern_White_Space, which UnicodeSet patterns ignore unless escaped.
+fn is_pattern_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\t'..='\r' | ' ' | '\u{85}' | '\u{200E}' | '\u{200F}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// Deepest nesting of `[` accepted by [`CodePoints::from_unicode_set_pattern`].
+const MAX_UNICODE_SET_DEPTH: usize = 64;
+
+/// Recursive-descent parser for the supported UnicodeSet subset.
+struct UnicodeSetParser {
+    chars: Vec<char>,
+    pos: usize,
+    /// Number of sets currently open.
+    depth: usize,
+}
+
+impl UnicodeSetParser {
+    fn peek(&mut self) -> Option<char> {
+        while self
+            .chars
+            .get(self.pos)
+            .is_some_and(|&c| is_pattern_white_space(c))
+        {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    fn error<T>(&self, reason: &'static str) -> Result<T, ParseUnicodeSetError> {
+        Err(ParseUnicodeSetError::new(self.pos, reason))
+    }
+
+    /// Parses `[ ... ]` starting at the opening bracket.
+    fn set(&mut self) -> Result<HashSet<u32>, ParseUnicodeSetError> {
+        if self.depth == MAX_UNICODE_SET_DEPTH {
+            return self.error("sets are nested too deeply");
+        }
+        self.depth += 1;
+        let set = self.set_body();
+        self.depth -= 1;
+        set
+    }
+
+    /// Parses the rest of a set whose opening bracket is at `self.pos`.
+    fn set_body(&mut self) -> Result<HashSet<u32>, ParseUnicodeSetError> {
+        let open = self.pos;
+        self.pos += 1;
+        match self.chars.get(self.pos) {
+            Some('^') => return self.error("complemented sets are not supported"),
+            Some(':') => return self.error("property references are not supported"),
+            _ => {}
+        }
+
+        let mut set = HashSet::new();
+        let mut operator: Option<char> = None;
+        let mut range_start: Option<u32> = None;
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(ParseUnicodeSetError::new(open, "unclosed '['"));
+            };
+            match c {
+                ']' => {
+                    self.pos += 1;
+                    return Ok(set);
+                }
+                '[' => {
+                    let nested = self.set()?;
+                    match operator.take() {
+                        Some('-') => set.retain(|cp| !nested.contains(cp)),
+                        Some(_) => set.retain(|cp| nested.contains(cp)),
+                        None => set.extend(nested),
+                    }
+                    range_start = None;
+                }
+                '-' | '&' => {
+                    let at = self.pos;
+                    self.pos += 1;
+                    let next = self.peek();
+                    if next == Some('[') && at > open + 1 {
+                        operator = Some(c);
+                    } else if c == '&' {
+                        return Err(ParseUnicodeSetError::new(at, "'&' must join two sets"));
+                    } else if let (Some(start), Some(n)) = (range_start.take(), next) {
+                        if n == ']' {
+                            set.insert('-' as u32);
+                            continue;
+                        }
+                        let end_at = self.pos;
+                        let end = self.literal()?;
+                        if end < start {
+                            return Err(ParseUnicodeSetError::new(
+                                end_at,
+                                "range start is after its end",
+                            ));
+                        }
+                        set.extend((start..=end).filter(|&cp| char::from_u32(cp).is_some()));
+                    } else if at == open + 1 || next == Some(']') {
+                        set.insert('-' as u32);
+                    } else {
+                        return Err(ParseUnicodeSetError::new(at, "'-' must follow a character"));
+                    }
+                }
+                _ => {
+                    let cp = self.literal()?;
+                    set.insert(cp);
+                    range_start = Some(cp);
+                }
+            }
+        }
+    }
+
+    /// Parses one literal character or escape.
+    fn literal(&mut self) -> Result<u32, ParseUnicodeSetError> {
+        let at = self.pos;
+        let Some(c) = self.peek() else {
+            return self.error("unexpected end of pattern");
+        };
+        self.pos += 1;
+        match c {
+            '\\' => self.escape(at),
+            '[' | ']' | '-' | '&' => Err(ParseUnicodeSetError::new(at, "expected a character")),
+            '{' => Err(ParseUnicodeSetError::new(
+                at,
+                "multi-character strings are not supported",
+            )),
+            '$' => Err(ParseUnicodeSetError::new(
+                at,
+                "variables and anchors are not supported",
+            )),
+            _ => Ok(c as u32),
+        }
+    }
+
+    /// Parses the escape whose backslash is at `at`; `self.pos` is just
+    /// after the backslash.
+    fn escape(&mut self, at: usize) -> Result<u32, ParseUnicodeSetError> {
+        let Some(&c) = self.chars.get(self.pos) else {
+            return Err(ParseUnicodeSetError::new(at, "dangling '\\'"));
+        };
+        self.pos += 1;
+        let cp = match c {
+            'u' => self.hex(at, 4, 4)?,
+            'U' => self.hex(at, 8, 8)?,
+            'x' if self.chars.get(self.pos) == Some(&'{') => {
+                self.pos += 1;
+                let cp = self.hex(at, 1, 6)?;
+                if self.chars.get(self.pos) != Some(&'}') {
+                    return Err(ParseUnicodeSetError::new(at, "unclosed '\\x{'"));
+                }
+                self.pos += 1;
+                cp
+            }
+            'x' => self.hex(at, 2, 2)?,
+            'p' | 'P' => {
+                return Err(ParseUnicodeSetError::new(
+                    at,
+                    "property references are not supported",
+                ))
+            }
+            'N' => {
+                return Err(ParseUnicodeSetError::new(
+                    at,
+                    "named characters are not supported",
+                ))
+            }
+            'a' => 0x07,
+            'b' => 0x08,
+            'e' => 0x1B,
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            _ => c as u32,
+        };
+        if char::from_u32(cp).is_none() {
+            return Err(ParseUnicodeSetError::new(at, "not a Unicode scalar value"));
+        }
+        Ok(cp)
+    }
+
+    /// Reads `min..=max` hex digits as a code point.
+    fn hex(&mut self, at: usize, min: usize, max: usize) -> Result<u32, ParseUnicodeSetError> {
+        let digits: String = self
+            .chars
+            .iter()
+            .skip(self.pos)
+            .take(max)
+            .take_while(|c| c.is_ascii_hexdigit())
+            .collect();
+        if digits.len() < min {
+            return Err(ParseUnicodeSetError::new(at, "malformed hex escape"));
+        }
+        self.pos += digits.len();
+        u32::from_str_radix(&digits, 16)
+            .map_err(|_| ParseUnicodeSetError::new(at, "malformed hex escape"))
+    }
+}
+
+/// Writes `cp` as a pattern literal, escaping syntax characters and
+/// anything invisible.
+fn write_pattern_char(out: &mut String, cp: u32) {
+    match char::from_u32(cp) {
+        Some(c @ ('[' | ']' | '-' | '&' | '^' | '\\' | '{' | '}' | '$' | ':')) => {
+            out.push('\\');
+            out.push(c);
+        }
+        Some(c)
+            if !(c.is_control()
+                || c.is_whitespace()
+                || is_pattern_white_space(c)
+                || matches!(cp, 0x200B..=0x200F | 0x202A..=0x202E | 0x2060..=0x2064 | 0xFEFF)) =>
+        {
+            out.push(c);
+        }
+        _ if cp > 0xFFFF => out.push_str(&format!("\\U{:08X}", cp)),
+        _ => out.push_str(&format!("\\u{:04X}", cp)),
+    }
+}
+
+impl CodePoints {
+    /// Formats this set in ICU UnicodeSet notation, e.g. `[ぁ-んー]`.
+    ///
+    /// Code points are listed in ascending order, with runs of two or more
+    /// written as ranges.  Syntax characters are backslash-escaped and
+    /// invisible ones (controls, whitespace, format characters) written as
+    /// `\uXXXX` or `\UXXXXXXXX`, so the pattern can be pasted into
+    /// ICU-based tooling and read back with
+    /// [`from_unicode_set_pattern`](Self::from_unicode_set_pattern).
+    /// Surrogates are written as `\uXXXX` too, but are rejected when read
+    /// back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::from_char_range('ぁ'..='ん').union(&CodePoints::from_string("ー- "));
+    /// assert_eq!(cp.to_unicode_set_pattern(), r"[\u0020\-ぁ-んー]");
+    /// assert_eq!(CodePoints::from_unicode_set_pattern(&cp.to_unicode_set_pattern()), Ok(cp));
+    /// ```
+    pub fn to_unicode_set_pattern(&self) -> String {
+        let mut out = String::from("[");
+        for (lo, hi) in sorted_ranges(self.codepoints.iter().copied()) {
+            write_pattern_char(&mut out, lo);
+            if hi > lo {
+                out.push('-');
+                write_pattern_char(&mut out, hi);
+            }
+        }
+        out.push(']');
+        out
+    }
+
+    /// Parses an ICU UnicodeSet pattern.
+    ///
+    /// Supported syntax:
+    ///
+    /// * literals and ranges: `[あいう]`, `[ぁ-ん]`;
+    /// * escapes: `\uXXXX`, `\UXXXXXXXX`, `\x{X…}`, `\xXX`, `\t` and the
+    ///   other C escapes, and `\` before any other character for that
+    ///   character (`\-`, `\[`);
+    /// * nested sets, combined by union (`[[ぁ-ん][ァ-ン]]`), difference
+    ///   (`[[ぁ-ん]-[ゃゅょ]]`) or intersection (`[[ぁ-ん]&[あ-お]]`),
+    ///   left to right, at most 64 levels deep;
+    /// * unescaped whitespace is ignored, as in ICU; a `-` at the start or
+    ///   end of a set is literal.
+    ///
+    /// Property references (`[:Hiragana:]`, `\p{…}`), complements (`[^…]`),
+    /// strings (`{…}`) and variables are rejected with an error naming the
+    /// construct.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseUnicodeSetError`] carrying the character index of
+    /// the problem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let kana = CodePoints::from_unicode_set_pattern("[[ぁ-ん]-[ぁぃぅぇぉ] ー]").unwrap();
+    /// assert!(kana.contains("あいーん"));
+    /// assert!(!kana.contains("ぁ"));
+    ///
+    /// let err = CodePoints::from_unicode_set_pattern("[[:Hiragana:]&[あ-ん]]").unwrap_err();
+    /// assert_eq!(err.position(), 2);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid UnicodeSet pattern at position 2: property references are not supported"
+    /// );
+    /// ```
+    pub fn from_unicode_set_pattern(pattern: &str) -> Result<CodePoints, ParseUnicodeSetError> {
+        let mut parser = UnicodeSetParser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+            depth: 0,
+        };
+        if parser.peek() != Some('[') {
+            return parser.error("expected '['");
+        }
+        let set = parser.set()?;
+        if parser.peek().is_some() {
+            return parser.error("unexpected input after the set");
+        }
+        Ok(Self {
+            codepoints: set.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── spec strings ──────────────────────────────────────────────────────
+
+    #[test]
+    fn test_from_str_items() {
+        let cp: CodePoints = "U+3042, 0x3044 12358\tu+3048,,0X304A".parse().unwrap();
+        assert!(cp.contains("あいうえお"));
+        assert_eq!(cp.len(), 5);
+
+        let empty: CodePoints = " , ".parse().unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_from_str_ranges() {
+        let a: CodePoints = "U+3041..U+3096".parse().unwrap();
+        let b: CodePoints = "0x3041-0x3096".parse().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 0x56);
+        assert_eq!("65..65".parse::<CodePoints>().unwrap().len(), 1);
+
+        // Surrogates are skipped rather than rejected inside a range.
+        let around: CodePoints = "U+D7FF..U+E000".parse().unwrap();
+        assert_eq!(around, CodePoints::new(vec![0xD7FF, 0xE000]));
+    }
+
+    #[test]
+    fn test_from_str_spaced_ranges() {
+        let expected: CodePoints = "U+3041..U+3096".parse().unwrap();
+        for spec in [
+            "U+3041 .. U+3096",
+            "U+3041 - U+3096",
+            "U+3041 ..U+3096",
+            "U+3041..\tU+3096",
+            "U+3041 -U+3096",
+            "U+3041- U+3096",
+        ] {
+            assert_eq!(spec.parse::<CodePoints>().unwrap(), expected, "{spec}");
+        }
+
+        let cp: CodePoints = "U+30FC, U+3041 .. U+3043 0x41 - 0x42 U+3000"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            cp,
+            CodePoints::new(vec![0x30FC, 0x3041, 0x3042, 0x3043, 0x41, 0x42, 0x3000])
+        );
+
+        // A comma still ends an item, so a separator cannot cross it.
+        assert_eq!(
+            "U+3041 .., U+3096"
+                .parse::<CodePoints>()
+                .unwrap_err()
+                .token(),
+            "U+3041 .."
+        );
+        let err = "U+3096 .. U+3041".parse::<CodePoints>().unwrap_err();
+        assert_eq!(err.token(), "U+3096 .. U+3041");
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let err = "U+3041 U+30G0".parse::<CodePoints>().unwrap_err();
+        assert_eq!(err.token(), "U+30G0");
+        assert!(err.to_string().contains("U+30G0"));
+
+        let err = "U+3096..U+3041".parse::<CodePoints>().unwrap_err();
+        assert_eq!(err.token(), "U+3096..U+3041");
+        assert!(err.to_string().contains("after its end"));
+
+        assert_eq!(
+            "U+D800".parse::<CodePoints>().unwrap_err().token(),
+            "U+D800"
+        );
+        assert_eq!(
+            "U+110000".parse::<CodePoints>().unwrap_err().token(),
+            "U+110000"
+        );
+        assert_eq!("..5".parse::<CodePoints>().unwrap_err().token(), "..5");
+        assert_eq!("-5".parse::<CodePoints>().unwrap_err().token(), "-5");
+    }
+
+    #[test]
+    fn test_to_spec_string_round_trip() {
+        assert_eq!(CodePoints::new(vec![]).to_spec_string(), "");
+        assert_eq!(
+            CodePoints::new(vec![0x41, 0x42, 0x44, 0x45, 0x46]).to_spec_string(),
+            "U+0041, U+0042, U+0044..U+0046"
+        );
+        assert_eq!(CodePoints::new(vec![0x20B9F]).to_spec_string(), "U+20B9F");
+
+        for cp in [
+            CodePoints::ascii_all(),
+            CodePoints::zero_width(),
+            CodePoints::from_string("あ𠀋A"),
+        ] {
+            assert_eq!(cp.to_spec_string().parse::<CodePoints>().unwrap(), cp);
+        }
+    }
+
+    #[test]
+    fn test_to_spec_string_non_scalar_values() {
+        let surrogate = CodePoints::new(vec![0x41, 0xD800]);
+        assert_eq!(surrogate.to_spec_string(), "U+0041, U+D800");
+        assert_eq!(
+            surrogate
+                .to_spec_string()
+                .parse::<CodePoints>()
+                .unwrap_err()
+                .token(),
+            "U+D800"
+        );
+
+        let range = CodePoints::new(vec![0xD7FE, 0xD7FF, 0xD800]);
+        assert_eq!(range.to_spec_string(), "U+D7FE..U+D800");
+        assert!(range.to_spec_string().parse::<CodePoints>().is_err());
+
+        let above = CodePoints::new(vec![0x110000]);
+        assert!(above.to_spec_string().parse::<CodePoints>().is_err());
+    }
+
+    // ── UnicodeSet patterns ───────────────────────────────────────────────
+
+    #[test]
+    fn test_unicode_set_pattern_round_trip() {
+        let sets = [
+            CodePoints::new(vec![]),
+            CodePoints::from_string("あいうー"),
+            CodePoints::from_string("[]-&^\\{}$: \t\n\u{3000}\u{200B}a"),
+            CodePoints::from_char_range('\u{20000}'..='\u{2A6DF}').union(&"ｱ".into()),
+            CodePoints::ascii_printable().union(&CodePoints::ascii_control()),
+        ];
+        for cp in sets {
+            let pattern = cp.to_unicode_set_pattern();
+            assert_eq!(
+                CodePoints::from_unicode_set_pattern(&pattern),
+                Ok(cp),
+                "{}",
+                pattern
+            );
+        }
+        assert_eq!(
+            CodePoints::from_string("\t a-").to_unicode_set_pattern(),
+            r"[\u0009\u0020\-a]"
+        );
+        assert_eq!(
+            CodePoints::from_string("𠮷\u{FEFF}").to_unicode_set_pattern(),
+            r"[\uFEFF𠮷]"
+        );
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    #[test]
+    fn test_unicode_set_pattern_round_trip_jis() {
+        let jis = crate::JisX0208::cached().codepoints();
+        let pattern = jis.to_unicode_set_pattern();
+        assert_eq!(
+            &CodePoints::from_unicode_set_pattern(&pattern).unwrap(),
+            jis
+        );
+    }
+
+    #[test]
+    fn test_unicode_set_pattern_syntax() {
+        let parse = |p: &str| CodePoints::from_unicode_set_pattern(p).unwrap();
+        assert_eq!(
+            parse("[あ-え ー]"),
+            CodePoints::from_string("あぃいぅうぇえー")
+        );
+        assert_eq!(parse(" [ ] "), CodePoints::new(vec![]));
+        assert_eq!(parse("[-a-]"), CodePoints::from_string("-a"));
+        assert_eq!(parse("[[a]-]"), CodePoints::from_string("-a"));
+        assert_eq!(
+            parse(r"[あ\U0002000B\x{3044}\x41\t\-\[\ ]"),
+            CodePoints::from_string("あ𠀋いA\t-[ ")
+        );
+        assert_eq!(parse("[[あい][う]]"), CodePoints::from_string("あいう"));
+        assert_eq!(
+            parse("[[あ-お]-[いう]]"),
+            CodePoints::from_string("あぃぅぇえぉお")
+        );
+        assert_eq!(parse("[[あ-お]&[いうx]]"), CodePoints::from_string("いう"));
+        // Operators apply left to right to everything before them.
+        assert_eq!(parse("[a[b]-[a]c]"), CodePoints::from_string("bc"));
+        assert_eq!(parse("[\u{D7FF}-\u{E000}]").len(), 2);
+    }
+
+    #[test]
+    fn test_unicode_set_pattern_errors() {
+        let err = |p: &str| {
+            let e = CodePoints::from_unicode_set_pattern(p).unwrap_err();
+            (e.position(), e.reason())
+        };
+        assert_eq!(err(""), (0, "expected '['"));
+        assert_eq!(err("あ"), (0, "expected '['"));
+        assert_eq!(err("[あ"), (0, "unclosed '['"));
+        assert_eq!(err("[[あ]"), (0, "unclosed '['"));
+        assert_eq!(err("[あ]]"), (3, "unexpected input after the set"));
+        assert_eq!(err("[お-あ]"), (3, "range start is after its end"));
+        assert_eq!(err("[^あ]"), (1, "complemented sets are not supported"));
+        assert_eq!(
+            err("[a[:Hiragana:]]"),
+            (3, "property references are not supported")
+        );
+        assert_eq!(
+            err(r"[a\p{Hiragana}]"),
+            (2, "property references are not supported")
+        );
+        assert_eq!(
+            err(r"[\N{SPACE}]"),
+            (1, "named characters are not supported")
+        );
+        assert_eq!(
+            err("[{ab}]"),
+            (1, "multi-character strings are not supported")
+        );
+        assert_eq!(err("[a$]"), (2, "variables and anchors are not supported"));
+        assert_eq!(err(r"[\u30]"), (1, "malformed hex escape"));
+        assert_eq!(err(r"[\x{110000}]"), (1, "not a Unicode scalar value"));
+        assert_eq!(err(r"[\uD800]"), (1, "not a Unicode scalar value"));
+        assert_eq!(err(r"[\x{41]"), (1, "unclosed '\\x{'"));
+        assert_eq!(err("[a\\"), (2, "dangling '\\'"));
+        assert_eq!(err("[[a]-b]"), (4, "'-' must follow a character"));
+        assert_eq!(err("[a&b]"), (2, "'&' must join two sets"));
+        assert_eq!(err("[[a]&b]"), (4, "'&' must join two sets"));
+        assert!(err("[a-b-c]").1.contains("'-'"));
+
+        // Nesting is capped rather than overflowing the stack.
+        let nested = |depth: usize| "[".repeat(depth) + "a" + &"]".repeat(depth);
+        assert!(CodePoints::from_unicode_set_pattern(&nested(64))
+            .unwrap()
+            .contains("a"));
+        assert_eq!(err(&nested(65)), (64, "sets are nested too deeply"));
+        let huge = "[".repeat(200_000) + &"]".repeat(200_000);
+        assert_eq!(err(&huge), (64, "sets are nested too deeply"));
+    }
+}
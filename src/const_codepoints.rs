@@ -0,0 +1,663 @@
+//! Character sets usable in `const` contexts.
+//!
+//! [`CodePoints`] is built at run time, so it cannot back compile-time
+//! checks.  [`ConstCodePoints`] wraps a sorted `&'static [u32]` instead and
+//! answers membership with a `const fn` binary search, so policies can be
+//! asserted when the program is compiled:
+//!
+//! ```rust
+//! use japanese_codepoints::const_codepoints::{ConstCodePoints, ASCII_PRINTABLE};
+//!
+//! const _: () = assert!(ASCII_PRINTABLE.contains(b'A' as u32));
+//! const _: () = assert!(!ASCII_PRINTABLE.contains_str("tab\there"));
+//!
+//! const ALLOWED: ConstCodePoints = ConstCodePoints::new(&[0x3042, 0x3044]); // あ い
+//! const _: () = assert!(ALLOWED.contains_str("あいあ"));
+//! ```
+//!
+//! [`RangeSet`] stores a few inclusive ranges inline instead, for hot paths
+//! such as per-keystroke filtering where the allowed set is contiguous:
+//!
+//! ```rust
+//! use japanese_codepoints::const_codepoints::RangeSet;
+//!
+//! const DIGITS_AND_KANA: RangeSet<2> = RangeSet::new([(0x30, 0x39), (0x3041, 0x3093)]);
+//! assert!(DIGITS_AND_KANA.contains_str("3かい"));
+//! ```
+//!
+//! The built-in sets below mirror the character-set types and are gated by
+//! the same features.  Tables that are not in code-point order are sorted
+//! at compile time with [`sorted`].
+//!
+//! # Limits
+//!
+//! * Only membership is `const`; set operations need
+//!   [`to_codepoints`](ConstCodePoints::to_codepoints).
+//! * [`ConstCodePoints::new`] requires strictly ascending code points and
+//!   fails to compile otherwise; sort unsorted tables with [`sorted`].
+//! * `sorted` needs the length as a const parameter, and its result must be
+//!   bound to a `const` or `static` item before it is borrowed for `new`.
+
+use crate::CodePoints;
+
+/// A sorted, `'static` code-point set with `const` membership tests.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::const_codepoints::{sorted, ConstCodePoints};
+///
+/// const RAW: &[u32] = &[0x30A2, 0x3042, 0x30A4]; // ア あ イ
+/// const SORTED: [u32; 3] = sorted::<3>(RAW);
+/// const SET: ConstCodePoints = ConstCodePoints::new(&SORTED);
+///
+/// const _: () = assert!(SET.contains('ア' as u32));
+/// assert_eq!(SET.len(), 3);
+/// assert!(SET.to_codepoints().contains("あア"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstCodePoints {
+    codepoints: &'static [u32],
+}
+
+impl ConstCodePoints {
+    /// Wraps `codepoints`, which must be strictly ascending.
+    ///
+    /// # Panics
+    ///
+    /// Panics — a compile error in `const` items — if `codepoints` is not
+    /// strictly ascending.
+    #[allow(clippy::indexing_slicing)] // `get` is not const; indices are loop-bounded
+    pub const fn new(codepoints: &'static [u32]) -> Self {
+        let mut i = 1;
+        while i < codepoints.len() {
+            assert!(
+                codepoints[i - 1] < codepoints[i],
+                "ConstCodePoints requires strictly ascending code points"
+            );
+            i += 1;
+        }
+        Self { codepoints }
+    }
+
+    /// Returns `true` if `cp` is in the set.
+    #[allow(clippy::indexing_slicing)] // `get` is not const; indices are loop-bounded
+    pub const fn contains(&self, cp: u32) -> bool {
+        let (mut lo, mut hi) = (0, self.codepoints.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.codepoints[mid] == cp {
+                return true;
+            } else if self.codepoints[mid] < cp {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        false
+    }
+
+    /// Returns `true` if `c` is in the set.
+    pub const fn contains_char(&self, c: char) -> bool {
+        self.contains(c as u32)
+    }
+
+    /// Returns `true` if every character of `s` is in the set (so `true` for
+    /// `""`, as with [`CodePoints::contains`]).
+    pub const fn contains_str(&self, s: &str) -> bool {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let (cp, len) = decode_utf8(bytes, i);
+            if !self.contains(cp) {
+                return false;
+            }
+            i += len;
+        }
+        true
+    }
+
+    /// Returns the number of code points in the set.
+    pub const fn len(&self) -> usize {
+        self.codepoints.len()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.codepoints.is_empty()
+    }
+
+    /// Returns the code points in ascending order.
+    pub const fn as_slice(&self) -> &'static [u32] {
+        self.codepoints
+    }
+
+    /// Builds the equivalent run-time [`CodePoints`].
+    #[must_use]
+    pub fn to_codepoints(&self) -> CodePoints {
+        CodePoints::from_slice(self.codepoints)
+    }
+}
+
+/// Decodes the character starting at byte `i` of valid UTF-8 `bytes`,
+/// returning its code point and length in bytes.
+#[allow(clippy::indexing_slicing)] // `get` is not const; `bytes` is valid UTF-8
+const fn decode_utf8(bytes: &[u8], i: usize) -> (u32, usize) {
+    // The lead byte gives the length.
+    let b = bytes[i] as u32;
+    let (mut cp, len) = match b {
+        0x00..=0x7F => (b, 1),
+        0xC0..=0xDF => (b & 0x1F, 2),
+        0xE0..=0xEF => (b & 0x0F, 3),
+        _ => (b & 0x07, 4),
+    };
+    let mut j = 1;
+    while j < len {
+        cp = (cp << 6) | (bytes[i + j] as u32 & 0x3F);
+        j += 1;
+    }
+    (cp, len)
+}
+
+// ── inline range sets ─────────────────────────────────────────────────────────
+
+/// A set of `N` inclusive code-point ranges stored inline, with `const`
+/// membership tests.
+///
+/// A lookup compares against every range without branching on the result,
+/// so for the one or two ranges of a script such as hiragana it costs a
+/// couple of subtractions — no hashing and no pointer chasing.  Use it for
+/// hot single-purpose checks; [`CodePoints`] converts to it with
+/// [`TryFrom`] and back with [`From`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::const_codepoints::RangeSet;
+/// use japanese_codepoints::CodePoints;
+///
+/// const KANA: RangeSet<2> = RangeSet::new([(0x3041, 0x3093), (0x30A1, 0x30F6)]);
+/// const _: () = assert!(KANA.contains_char('ア'));
+/// assert_eq!(KANA.len(), 83 + 86);
+///
+/// let set = CodePoints::from(KANA);
+/// assert_eq!(RangeSet::<2>::try_from(&set), Ok(KANA));
+/// assert!(RangeSet::<1>::try_from(&set).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeSet<const N: usize> {
+    ranges: [(u32, u32); N],
+}
+
+impl<const N: usize> RangeSet<N> {
+    /// Wraps `ranges`, inclusive `(first, last)` pairs in ascending order
+    /// that neither overlap nor touch.
+    ///
+    /// # Panics
+    ///
+    /// Panics — a compile error in `const` items — if a range is reversed,
+    /// or if a range does not start at least two code points after the end
+    /// of the previous one (touching ranges must be merged).
+    #[allow(clippy::indexing_slicing)] // `get` is not const; indices are loop-bounded
+    pub const fn new(ranges: [(u32, u32); N]) -> Self {
+        let mut i = 0;
+        while i < N {
+            assert!(
+                ranges[i].0 <= ranges[i].1,
+                "RangeSet requires first <= last in every range"
+            );
+            assert!(
+                i == 0 || ranges[i - 1].1 < ranges[i].0.saturating_sub(1),
+                "RangeSet requires ascending, separated ranges"
+            );
+            i += 1;
+        }
+        Self { ranges }
+    }
+
+    /// Returns `true` if `cp` is in the set.
+    #[allow(clippy::indexing_slicing)] // `get` is not const; indices are loop-bounded
+    pub const fn contains(&self, cp: u32) -> bool {
+        let mut found = false;
+        let mut i = 0;
+        while i < N {
+            let (first, last) = self.ranges[i];
+            found |= cp.wrapping_sub(first) <= last - first;
+            i += 1;
+        }
+        found
+    }
+
+    /// Returns `true` if `c` is in the set.
+    pub const fn contains_char(&self, c: char) -> bool {
+        self.contains(c as u32)
+    }
+
+    /// Returns `true` if every character of `s` is in the set (so `true` for
+    /// `""`, as with [`CodePoints::contains`]).
+    pub const fn contains_str(&self, s: &str) -> bool {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let (cp, len) = decode_utf8(bytes, i);
+            if !self.contains(cp) {
+                return false;
+            }
+            i += len;
+        }
+        true
+    }
+
+    /// Returns the number of code points in the set.
+    #[allow(clippy::indexing_slicing)] // `get` is not const; indices are loop-bounded
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < N {
+            len += (self.ranges[i].1 - self.ranges[i].0) as usize + 1;
+            i += 1;
+        }
+        len
+    }
+
+    /// Returns `true` if the set is empty, i.e. `N` is 0.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the ranges in ascending order.
+    pub const fn ranges(&self) -> &[(u32, u32); N] {
+        &self.ranges
+    }
+}
+
+impl<const N: usize> From<RangeSet<N>> for CodePoints {
+    fn from(set: RangeSet<N>) -> Self {
+        CodePoints::new(
+            set.ranges
+                .iter()
+                .flat_map(|&(first, last)| first..=last)
+                .collect(),
+        )
+    }
+}
+
+impl<const N: usize> TryFrom<&CodePoints> for RangeSet<N> {
+    type Error = RangeCountError;
+
+    /// Collapses `set` into its maximal runs of consecutive code points,
+    /// failing unless there are exactly `N`.
+    fn try_from(set: &CodePoints) -> Result<Self, Self::Error> {
+        let ranges = crate::codepoints::sorted_ranges(set.iter().copied());
+        let found = ranges.len();
+        <[(u32, u32); N]>::try_from(ranges)
+            .map(|ranges| Self { ranges })
+            .map_err(|_| RangeCountError { expected: N, found })
+    }
+}
+
+/// Error returned when converting a [`CodePoints`] into a [`RangeSet`] whose
+/// range count does not match the set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeCountError {
+    /// The `N` of the target `RangeSet`.
+    pub expected: usize,
+    /// The number of runs of consecutive code points in the set.
+    pub found: usize,
+}
+
+impl std::fmt::Display for RangeCountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "set has {} ranges of consecutive code points, expected {}",
+            self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for RangeCountError {}
+
+/// Returns `table` sorted in ascending order, at compile time.
+///
+/// Uses heapsort, so large tables (the 10 050 JIS X 0213 kanji) stay within
+/// the compiler's evaluation budget.
+///
+/// # Panics
+///
+/// Panics if `table.len() != N`.
+#[allow(clippy::indexing_slicing)] // `get` is not const; indices are bounded by `N`
+pub const fn sorted<const N: usize>(table: &[u32]) -> [u32; N] {
+    assert!(table.len() == N);
+    let mut out = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = table[i];
+        i += 1;
+    }
+
+    let mut start = N / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down(&mut out, start, N);
+    }
+    let mut end = N;
+    while end > 1 {
+        end -= 1;
+        let top = out[0];
+        out[0] = out[end];
+        out[end] = top;
+        sift_down(&mut out, 0, end);
+    }
+    out
+}
+
+#[allow(clippy::indexing_slicing)] // `get` is not const; indices are bounded by `end`
+const fn sift_down(heap: &mut [u32], mut root: usize, end: usize) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && heap[child] < heap[child + 1] {
+            child += 1;
+        }
+        if heap[root] >= heap[child] {
+            return;
+        }
+        let tmp = heap[root];
+        heap[root] = heap[child];
+        heap[child] = tmp;
+        root = child;
+    }
+}
+
+// ── built-in sets ─────────────────────────────────────────────────────────────
+
+/// ASCII control characters (U+0000–U+001F, U+007F).
+pub const ASCII_CONTROL: ConstCodePoints = ConstCodePoints::new(crate::data::ascii::CONTROL_CHARS);
+
+/// Printable ASCII (U+0020–U+007E).
+pub const ASCII_PRINTABLE: ConstCodePoints =
+    ConstCodePoints::new(crate::data::ascii::PRINTABLE_CHARS);
+
+#[cfg(feature = "codepoints-jisx0201")]
+static JISX0201_LATIN_LETTERS_SORTED: [u32; crate::data::jisx0201::LATIN_LETTERS_COUNT] =
+    sorted(crate::data::jisx0201::LATIN_LETTERS);
+
+/// JIS X 0201 Latin letters (`codepoints-jisx0201` feature).
+#[cfg(feature = "codepoints-jisx0201")]
+pub const JISX0201_LATIN_LETTERS: ConstCodePoints =
+    ConstCodePoints::new(&JISX0201_LATIN_LETTERS_SORTED);
+
+/// JIS X 0201 halfwidth katakana (`codepoints-jisx0201` feature).
+#[cfg(feature = "codepoints-jisx0201")]
+pub const JISX0201_KATAKANA: ConstCodePoints =
+    ConstCodePoints::new(crate::data::jisx0201::KATAKANA);
+
+/// JIS X 0208 hiragana (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_HIRAGANA: ConstCodePoints =
+    ConstCodePoints::new(crate::data::jisx0208::HIRAGANA);
+
+/// JIS X 0208 katakana (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_KATAKANA: ConstCodePoints =
+    ConstCodePoints::new(crate::data::jisx0208::KATAKANA);
+
+/// JIS X 0208 Latin letters (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_LATIN_LETTERS: ConstCodePoints =
+    ConstCodePoints::new(crate::data::jisx0208::LATIN_LETTERS);
+
+/// JIS X 0208 Greek letters (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_GREEK_LETTERS: ConstCodePoints =
+    ConstCodePoints::new(crate::data::jisx0208::GREEK_LETTERS);
+
+#[cfg(feature = "codepoints-jisx0208")]
+static JISX0208_CYRILLIC_LETTERS_SORTED: [u32; crate::data::jisx0208::CYRILLIC_LETTERS_COUNT] =
+    sorted(crate::data::jisx0208::CYRILLIC_LETTERS);
+
+/// JIS X 0208 Cyrillic letters (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_CYRILLIC_LETTERS: ConstCodePoints =
+    ConstCodePoints::new(&JISX0208_CYRILLIC_LETTERS_SORTED);
+
+#[cfg(feature = "codepoints-jisx0208")]
+static JISX0208_SPECIAL_CHARS_SORTED: [u32; crate::data::jisx0208::SPECIAL_CHARS_COUNT] =
+    sorted(crate::data::jisx0208::SPECIAL_CHARS);
+
+/// JIS X 0208 special characters (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_SPECIAL_CHARS: ConstCodePoints =
+    ConstCodePoints::new(&JISX0208_SPECIAL_CHARS_SORTED);
+
+#[cfg(feature = "codepoints-jisx0208")]
+static JISX0208_BOX_DRAWING_CHARS_SORTED: [u32; crate::data::jisx0208::BOX_DRAWING_CHARS_COUNT] =
+    sorted(crate::data::jisx0208::BOX_DRAWING_CHARS);
+
+/// JIS X 0208 box-drawing characters (`codepoints-jisx0208` feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_BOX_DRAWING_CHARS: ConstCodePoints =
+    ConstCodePoints::new(&JISX0208_BOX_DRAWING_CHARS_SORTED);
+
+#[cfg(feature = "codepoints-jisx0208kanji")]
+static JISX0208_KANJI_SORTED: [u32; crate::data::jisx0208kanji::JISX0208_KANJI_COUNT] =
+    sorted(crate::data::jisx0208kanji::JISX0208_CHARS);
+
+/// JIS X 0208 Level 1 and 2 kanji (`codepoints-jisx0208kanji` feature).
+#[cfg(feature = "codepoints-jisx0208kanji")]
+pub const JISX0208_KANJI: ConstCodePoints = ConstCodePoints::new(&JISX0208_KANJI_SORTED);
+
+#[cfg(feature = "codepoints-jisx0213kanji")]
+static JISX0213_KANJI_SORTED: [u32; crate::data::jisx0213kanji::JISX0213_KANJI_COUNT] =
+    sorted(crate::data::jisx0213kanji::JISX0213_KANJI);
+
+/// JIS X 0213 Level 1–4 kanji (`codepoints-jisx0213kanji` feature).
+#[cfg(feature = "codepoints-jisx0213kanji")]
+pub const JISX0213_KANJI: ConstCodePoints = ConstCodePoints::new(&JISX0213_KANJI_SORTED);
+
+/// JIS X 0201 halfwidth katakana, U+FF61–U+FF9F, as one range
+/// (`codepoints-jisx0201` feature).
+#[cfg(feature = "codepoints-jisx0201")]
+pub const JISX0201_KATAKANA_RANGES: RangeSet<1> = RangeSet::new([(0xFF61, 0xFF9F)]);
+
+/// JIS X 0208 hiragana, U+3041–U+3093, as one range (`codepoints-jisx0208`
+/// feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_HIRAGANA_RANGES: RangeSet<1> = RangeSet::new([(0x3041, 0x3093)]);
+
+/// JIS X 0208 katakana, U+30A1–U+30F6, as one range (`codepoints-jisx0208`
+/// feature).
+#[cfg(feature = "codepoints-jisx0208")]
+pub const JISX0208_KATAKANA_RANGES: RangeSet<1> = RangeSet::new([(0x30A1, 0x30F6)]);
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const _: () = assert!(ASCII_PRINTABLE.contains(0x20));
+    const _: () = assert!(!ASCII_PRINTABLE.contains(0x7F));
+    const _: () = assert!(ASCII_CONTROL.contains_char('\n'));
+    const _: () = assert!(ASCII_PRINTABLE.contains_str(""));
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    const _: () = assert!(JISX0208_HIRAGANA.contains(0x3042));
+    #[cfg(feature = "codepoints-jisx0208")]
+    const _: () = assert!(JISX0208_SPECIAL_CHARS.contains_str("、。ー〜"));
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    const _: () = assert!(JISX0213_KANJI.contains_str("𠮟叱"));
+
+    #[test]
+    fn test_sorted() {
+        const SORTED: [u32; 6] = sorted::<6>(&[5, 3, 9, 1, 7, 2]);
+        assert_eq!(SORTED, [1, 2, 3, 5, 7, 9]);
+        assert_eq!(sorted::<0>(&[]), [0u32; 0]);
+        assert_eq!(sorted::<1>(&[4]), [4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly ascending")]
+    fn test_new_rejects_unsorted() {
+        ConstCodePoints::new(&[2, 1]);
+    }
+
+    #[test]
+    fn test_contains_str_decodes_utf8() {
+        const SET: ConstCodePoints = ConstCodePoints::new(&[0x41, 0xE9, 0x3042, 0x20BB7]);
+        assert!(SET.contains_str("Aéあ𠮷"));
+        assert!(!SET.contains_str("Aéあ𠮷B"));
+        assert!(!SET.contains_str("い"));
+        assert!(ConstCodePoints::new(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_range_set() {
+        const SET: RangeSet<3> =
+            RangeSet::new([(0x41, 0x43), (0x3042, 0x3042), (0x20000, 0x2A6DF)]);
+        const _: () = assert!(SET.contains_str("ABCあ𠀀"));
+        for cp in [0x40, 0x44, 0x3041, 0x3043, 0x1FFFF, 0x2A6E0, 0, u32::MAX] {
+            assert!(!SET.contains(cp), "U+{:04X}", cp);
+        }
+        assert_eq!(SET.len(), 3 + 1 + 0xA6E0);
+        assert!(!SET.contains_str("ABD"));
+
+        let runtime = CodePoints::from(SET);
+        assert_eq!(runtime.len(), SET.len());
+        assert_eq!(RangeSet::<3>::try_from(&runtime), Ok(SET));
+        let err = RangeSet::<2>::try_from(&runtime).unwrap_err();
+        assert_eq!(
+            err,
+            RangeCountError {
+                expected: 2,
+                found: 3
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "set has 3 ranges of consecutive code points, expected 2"
+        );
+
+        const EMPTY: RangeSet<0> = RangeSet::new([]);
+        assert!(EMPTY.is_empty() && !EMPTY.contains(0) && EMPTY.contains_str(""));
+        assert_eq!(RangeSet::<0>::try_from(&CodePoints::new(vec![])), Ok(EMPTY));
+        assert!(RangeSet::new([(0, u32::MAX)]).contains(u32::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "separated ranges")]
+    fn test_range_set_rejects_touching_ranges() {
+        RangeSet::new([(0x41, 0x42), (0x43, 0x44)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "first <= last")]
+    fn test_range_set_rejects_reversed_range() {
+        RangeSet::new([(0x42, 0x41)]);
+    }
+
+    #[test]
+    fn test_builtin_range_sets_match_runtime_sets() {
+        #[allow(unused_mut)] // only extended with the character-set features
+        let mut pairs: Vec<(RangeSet<1>, CodePoints)> = Vec::new();
+        #[cfg(feature = "codepoints-jisx0201")]
+        pairs.push((
+            JISX0201_KATAKANA_RANGES,
+            crate::JisX0201Katakana::cached().codepoints().clone(),
+        ));
+        #[cfg(feature = "codepoints-jisx0208")]
+        pairs.extend([
+            (
+                JISX0208_HIRAGANA_RANGES,
+                crate::Hiragana::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_KATAKANA_RANGES,
+                crate::Katakana::cached().codepoints().clone(),
+            ),
+        ]);
+
+        for (constant, runtime) in pairs {
+            assert_eq!(CodePoints::from(constant), runtime);
+            assert_eq!(RangeSet::try_from(&runtime), Ok(constant));
+            for cp in 0..=0x10000 {
+                assert_eq!(
+                    constant.contains(cp),
+                    runtime.iter().any(|&m| m == cp),
+                    "U+{:04X}",
+                    cp
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_builtins_match_runtime_sets() {
+        #[allow(unused_mut)] // only extended with the character-set features
+        let mut pairs: Vec<(ConstCodePoints, CodePoints)> = vec![
+            (ASCII_CONTROL, CodePoints::ascii_control()),
+            (ASCII_PRINTABLE, CodePoints::ascii_printable()),
+        ];
+        #[cfg(feature = "codepoints-jisx0201")]
+        pairs.extend([
+            (
+                JISX0201_LATIN_LETTERS,
+                crate::JisX0201LatinLetters::cached().codepoints().clone(),
+            ),
+            (
+                JISX0201_KATAKANA,
+                crate::JisX0201Katakana::cached().codepoints().clone(),
+            ),
+        ]);
+        #[cfg(feature = "codepoints-jisx0208")]
+        pairs.extend([
+            (
+                JISX0208_HIRAGANA,
+                crate::Hiragana::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_KATAKANA,
+                crate::Katakana::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_LATIN_LETTERS,
+                crate::LatinLetters::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_GREEK_LETTERS,
+                crate::GreekLetters::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_CYRILLIC_LETTERS,
+                crate::CyrillicLetters::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_SPECIAL_CHARS,
+                crate::SpecialChars::cached().codepoints().clone(),
+            ),
+            (
+                JISX0208_BOX_DRAWING_CHARS,
+                crate::BoxDrawingChars::cached().codepoints().clone(),
+            ),
+        ]);
+        #[cfg(feature = "codepoints-jisx0208kanji")]
+        pairs.push((
+            JISX0208_KANJI,
+            crate::JisX0208Kanji::cached().codepoints().clone(),
+        ));
+        #[cfg(feature = "codepoints-jisx0213kanji")]
+        pairs.push((
+            JISX0213_KANJI,
+            crate::JisX0213Kanji::cached().codepoints().clone(),
+        ));
+
+        for (constant, runtime) in pairs {
+            assert_eq!(constant.to_codepoints(), runtime);
+            assert!(constant.as_slice().windows(2).all(|w| w[0] < w[1]));
+            assert!(runtime.iter().all(|&cp| constant.contains(cp)));
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Simple text conversions.
+//!
+//! [`transliterate_to_ascii`] turns standard kana into Hepburn romaji, which
+//! is useful for log output and search keys.  It is deliberately basic: kanji
+//! and other scripts are not transliterated.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::conversion::transliterate_to_ascii;
+//!
+//! assert_eq!(transliterate_to_ascii("こんにちは"), "konnichiha");
+//! assert_eq!(transliterate_to_ascii("トウキョウ"), "toukyou");
+//! ```
+//!
+//! [`halfwidth_to_fullwidth_katakana`] turns JIS X 0201 halfwidth katakana
+//! into its JIS X 0208 fullwidth form, composing sound marks (`ｶﾞ` → `ガ`).
+//!
+//! [`to_fullwidth_upper`] and [`to_fullwidth_lower`] change the case of
+//! fullwidth Latin letters only.
+//!
+//! [`normalize_vertical_forms`] replaces vertical presentation forms and
+//! small form variants with their horizontal equivalents (`︒` → `。`);
+//! [`to_vertical_forms`] goes the other way.
+
+use std::fmt;
+
+use crate::data::jisx0201::{KATAKANA_COMPOSE, KATAKANA_FULLWIDTH};
+use crate::data::romaji_map::{
+    KATAKANA_OFFSET, PUNCTUATION_MAP, ROMAJI_DIGRAPHS, ROMAJI_MAP, SOKUON, SYLLABIC_N,
+};
+use crate::data::vertical::{SMALL_FORM_VARIANTS, VERTICAL_FORMS};
+
+/// Transliterates hiragana and katakana in `s` to Hepburn romaji.
+///
+/// * ASCII characters pass through unchanged.
+/// * Yōon digraphs are combined (`きゃ` → `kya`).
+/// * `っ`/`ッ` doubles the following consonant (`がっこう` → `gakkou`,
+///   `まっちゃ` → `matcha`).
+/// * `ん`/`ン` becomes `n'` before a vowel or `y` (`きんえん` → `kin'en`,
+///   `ほんや` → `hon'ya`) and `n` otherwise.
+/// * A few punctuation marks are mapped (`、` → `,`, `。` → `.`, `ー` → `-`).
+/// * Every other character, including kanji, becomes `?`.
+///
+/// Long vowels are not marked: `とうきょう` becomes `toukyou`.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::conversion::transliterate_to_ascii;
+///
+/// assert_eq!(transliterate_to_ascii("しゃしん"), "shashin");
+/// assert_eq!(transliterate_to_ascii("ID: ゲスト"), "ID: gesuto");
+/// assert_eq!(transliterate_to_ascii("東京"), "??");
+/// ```
+pub fn transliterate_to_ascii(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len());
+    let mut rest = chars.as_slice();
+    while let [c, tail @ ..] = rest {
+        let c = *c;
+        if c.is_ascii() {
+            out.push(c);
+            rest = tail;
+            continue;
+        }
+        let cp = to_hiragana(c as u32);
+        if cp == SOKUON {
+            match syllable(tail) {
+                Some((romaji, _)) if !starts_with_vowel(romaji) => {
+                    out.push(if romaji.starts_with("ch") {
+                        't'
+                    } else {
+                        first(romaji)
+                    });
+                }
+                _ => out.push('?'),
+            }
+            rest = tail;
+        } else if let Some((romaji, consumed)) = syllable(rest) {
+            out.push_str(romaji);
+            if cp == SYLLABIC_N {
+                if let Some((next, _)) = syllable(tail) {
+                    if starts_with_vowel(next) || next.starts_with('y') {
+                        out.push('\'');
+                    }
+                }
+            }
+            rest = rest.get(consumed..).unwrap_or_default();
+        } else if let Some(p) = lookup(PUNCTUATION_MAP, c as u32) {
+            out.push_str(p);
+            rest = tail;
+        } else {
+            out.push('?');
+            rest = tail;
+        }
+    }
+    out
+}
+
+/// Maps katakana U+30A1–U+30F6 onto hiragana; other code points are
+/// returned unchanged.
+fn to_hiragana(cp: u32) -> u32 {
+    if (0x30A1..=0x30F6).contains(&cp) {
+        cp - KATAKANA_OFFSET
+    } else {
+        cp
+    }
+}
+
+/// Returns the romaji of the syllable at the start of `chars` and the number
+/// of characters it spans (1, or 2 for a digraph).
+fn syllable(chars: &[char]) -> Option<(&'static str, usize)> {
+    let first = to_hiragana(*chars.first()? as u32);
+    if let Some(&second) = chars.get(1) {
+        let second = to_hiragana(second as u32);
+        let digraph = ROMAJI_DIGRAPHS
+            .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+            .ok()
+            .and_then(|i| ROMAJI_DIGRAPHS.get(i));
+        if let Some(&(_, _, romaji)) = digraph {
+            return Some((romaji, 2));
+        }
+    }
+    lookup(ROMAJI_MAP, first).map(|romaji| (romaji, 1))
+}
+
+fn lookup(table: &[(u32, &'static str)], cp: u32) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&cp, |&(k, _)| k)
+        .ok()
+        .and_then(|i| table.get(i))
+        .map(|&(_, romaji)| romaji)
+}
+
+fn starts_with_vowel(romaji: &str) -> bool {
+    matches!(first(romaji), 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+fn first(romaji: &str) -> char {
+    romaji.chars().next().unwrap_or('?')
+}
+
+// ── halfwidth → fullwidth katakana ────────────────────────────────────────────
+
+const HALFWIDTH_FIRST: u32 = 0xFF61;
+
+/// A character with no acceptable fullwidth image, reported by
+/// `jisx0201::Katakana::convertible_to_fullwidth`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionError {
+    /// The offending code point of the **input**.
+    pub code_point: u32,
+    /// Zero-based character index of the offending code point in the input.
+    pub position: usize,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = char::from_u32(self.code_point).unwrap_or('\u{FFFD}');
+        write!(
+            f,
+            "character '{}' (U+{:04X}) at position {} has no fullwidth katakana equivalent",
+            ch, self.code_point, self.position
+        )
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+/// Converts JIS X 0201 halfwidth katakana (U+FF61–U+FF9F) in `s` to fullwidth.
+///
+/// * A voiced or semi-voiced sound mark is composed with the preceding kana
+///   where a precomposed form exists (`ｶﾞ` → `ガ`, `ﾊﾟ` → `パ`, `ｳﾞ` → `ヴ`).
+/// * A mark that cannot be composed becomes the spacing mark `゛`/`゜`.
+/// * Halfwidth punctuation becomes its fullwidth form (`｡` → `。`).
+/// * Every other character is passed through unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::conversion::halfwidth_to_fullwidth_katakana;
+///
+/// assert_eq!(halfwidth_to_fullwidth_katakana("ｶﾞｯｺｳ"), "ガッコウ");
+/// assert_eq!(halfwidth_to_fullwidth_katakana("｢ﾊﾟﾝ｣ 100"), "「パン」 100");
+/// ```
+pub fn halfwidth_to_fullwidth_katakana(s: &str) -> String {
+    halfwidth_katakana_units(s).map(|(c, _, _)| c).collect()
+}
+
+/// Yields `(converted, position, source)` for each output character, where
+/// `position` and `source` identify the input character it came from (the
+/// base kana for a composed pair).
+pub(crate) fn halfwidth_katakana_units(s: &str) -> impl Iterator<Item = (char, usize, char)> + '_ {
+    let mut chars = s.chars().enumerate().peekable();
+    std::iter::from_fn(move || {
+        let (position, c) = chars.next()?;
+        let Some(full) = fullwidth(c as u32) else {
+            return Some((c, position, c));
+        };
+        let composed = chars
+            .peek()
+            .and_then(|&(_, mark)| compose(c as u32, mark as u32));
+        let out = match composed {
+            Some(composed) => {
+                chars.next();
+                composed
+            }
+            None => full,
+        };
+        Some((char::from_u32(out).unwrap_or('\u{FFFD}'), position, c))
+    })
+}
+
+fn fullwidth(cp: u32) -> Option<u32> {
+    let index = cp.checked_sub(HALFWIDTH_FIRST)? as usize;
+    KATAKANA_FULLWIDTH.get(index).copied()
+}
+
+/// Returns the precomposed form of halfwidth `base` followed by halfwidth
+/// `mark`, if there is one.
+fn compose(base: u32, mark: u32) -> Option<u32> {
+    KATAKANA_COMPOSE
+        .iter()
+        .find(|&&(b, m, _)| b == base && m == mark)
+        .map(|&(_, _, composed)| composed)
+}
+
+// ── fullwidth Latin case ──────────────────────────────────────────────────────
+
+const FULLWIDTH_UPPER: std::ops::RangeInclusive<char> = 'Ａ'..='Ｚ';
+const FULLWIDTH_LOWER: std::ops::RangeInclusive<char> = 'ａ'..='ｚ';
+const FULLWIDTH_CASE_OFFSET: u32 = 'ａ' as u32 - 'Ａ' as u32;
+
+/// Returns the opposite case of a fullwidth Latin letter, or `None` for any
+/// other character.
+pub(crate) fn fullwidth_swap_case(c: char) -> Option<char> {
+    if FULLWIDTH_UPPER.contains(&c) {
+        char::from_u32(c as u32 + FULLWIDTH_CASE_OFFSET)
+    } else if FULLWIDTH_LOWER.contains(&c) {
+        char::from_u32(c as u32 - FULLWIDTH_CASE_OFFSET)
+    } else {
+        None
+    }
+}
+
+/// Converts fullwidth lowercase Latin letters (`ａ`–`ｚ`) in `s` to uppercase.
+///
+/// Every other character — ASCII letters, fullwidth digits, kana — is left
+/// untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::conversion::to_fullwidth_upper;
+///
+/// assert_eq!(to_fullwidth_upper("ｔｏｋｙｏ１abc"), "ＴＯＫＹＯ１abc");
+/// ```
+pub fn to_fullwidth_upper(s: &str) -> String {
+    s.chars()
+        .map(|c| match fullwidth_swap_case(c) {
+            Some(upper) if FULLWIDTH_LOWER.contains(&c) => upper,
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts fullwidth uppercase Latin letters (`Ａ`–`Ｚ`) in `s` to lowercase.
+///
+/// Every other character is left untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::conversion::to_fullwidth_lower;
+///
+/// assert_eq!(to_fullwidth_lower("ＴＯＫＹＯ１ABC"), "ｔｏｋｙｏ１ABC");
+/// ```
+pub fn to_fullwidth_lower(s: &str) -> String {
+    s.chars()
+        .map(|c| match fullwidth_swap_case(c) {
+            Some(lower) if FULLWIDTH_UPPER.contains(&c) => lower,
+            _ => c,
+        })
+        .collect()
+}
+
+// ── vertical forms ────────────────────────────────────────────────────────────
+
+fn vertical_lookup(table: &[(u32, u32)], c: char) -> Option<char> {
+    table
+        .binary_search_by_key(&(c as u32), |&(form, _)| form)
+        .ok()
+        .and_then(|i| table.get(i))
+        .and_then(|&(_, upright)| char::from_u32(upright))
+}
+
+/// Replaces vertical presentation forms and small form variants in `s` with
+/// the horizontal characters they are variants of.
+///
+/// The targets are the Unicode compatibility decompositions, so `︒` becomes
+/// `。` and `﹁` becomes `「`, while `︵` becomes the ASCII `(` rather than
+/// `（`.  Every other character is left untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::conversion::normalize_vertical_forms;
+///
+/// assert_eq!(normalize_vertical_forms("﹁はい︒﹂"), "「はい。」");
+/// assert_eq!(normalize_vertical_forms("︵注﹚"), "(注)");
+/// ```
+pub fn normalize_vertical_forms(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            vertical_lookup(VERTICAL_FORMS, c)
+                .or_else(|| vertical_lookup(SMALL_FORM_VARIANTS, c))
+                .unwrap_or(c)
+        })
+        .collect()
+}
+
+/// Replaces characters in `s` that have a vertical presentation form with
+/// that form; the inverse of [`normalize_vertical_forms`] for vertical forms.
+///
+/// `_` has two vertical forms and maps to `︳`.  Small form variants are
+/// never produced.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::conversion::to_vertical_forms;
+///
+/// assert_eq!(to_vertical_forms("「はい。」"), "﹁はい︒﹂");
+/// ```
+pub fn to_vertical_forms(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            VERTICAL_FORMS
+                .iter()
+                .find(|&&(_, horizontal)| horizontal == c as u32)
+                .and_then(|&(form, _)| char::from_u32(form))
+                .unwrap_or(c)
+        })
+        .collect()
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_hiragana() {
+        let kana = [
+            "あいうえお",
+            "かきくけこ",
+            "さしすせそ",
+            "たちつてと",
+            "なにぬねの",
+            "はひふへほ",
+            "まみむめも",
+            "やゆよ",
+            "らりるれろ",
+            "わをん",
+        ];
+        let romaji = [
+            "a i u e o",
+            "ka ki ku ke ko",
+            "sa shi su se so",
+            "ta chi tsu te to",
+            "na ni nu ne no",
+            "ha hi fu he ho",
+            "ma mi mu me mo",
+            "ya yu yo",
+            "ra ri ru re ro",
+            "wa o n",
+        ];
+        let mut total = 0;
+        for (k, r) in kana.iter().zip(romaji.iter()) {
+            for (c, expected) in k.chars().zip(r.split(' ')) {
+                assert_eq!(transliterate_to_ascii(&c.to_string()), expected, "{}", c);
+                total += 1;
+            }
+        }
+        assert_eq!(total, 46);
+    }
+
+    #[test]
+    fn test_voiced() {
+        assert_eq!(transliterate_to_ascii("がぎぐげご"), "gagigugego");
+        assert_eq!(transliterate_to_ascii("ざじずぜぞ"), "zajizuzezo");
+        assert_eq!(transliterate_to_ascii("だぢづでど"), "dajizudedo");
+        assert_eq!(transliterate_to_ascii("ばびぶべぼ"), "babibubebo");
+        assert_eq!(transliterate_to_ascii("ぱぴぷぺぽ"), "papipupepo");
+        assert_eq!(transliterate_to_ascii("ゔ"), "vu");
+    }
+
+    #[test]
+    fn test_katakana() {
+        assert_eq!(transliterate_to_ascii("アイウエオ"), "aiueo");
+        assert_eq!(transliterate_to_ascii("ガッコウ"), "gakkou");
+        assert_eq!(transliterate_to_ascii("ヴ"), "vu");
+        assert_eq!(transliterate_to_ascii("コーヒー"), "ko-hi-");
+    }
+
+    #[test]
+    fn test_syllabic_n() {
+        assert_eq!(transliterate_to_ascii("ほん"), "hon");
+        assert_eq!(transliterate_to_ascii("しんぶん"), "shinbun");
+        // Before a vowel or y, an apostrophe disambiguates (kin'en vs kinen).
+        assert_eq!(transliterate_to_ascii("きんえん"), "kin'en");
+        assert_eq!(transliterate_to_ascii("きねん"), "kinen");
+        assert_eq!(transliterate_to_ascii("ほんや"), "hon'ya");
+        assert_eq!(transliterate_to_ascii("こんにゃく"), "konnyaku");
+    }
+
+    #[test]
+    fn test_digraphs_and_sokuon() {
+        assert_eq!(transliterate_to_ascii("きょう"), "kyou");
+        assert_eq!(transliterate_to_ascii("ちゃ"), "cha");
+        assert_eq!(transliterate_to_ascii("じゅ"), "ju");
+        assert_eq!(transliterate_to_ascii("まっちゃ"), "matcha");
+        assert_eq!(transliterate_to_ascii("きって"), "kitte");
+        assert_eq!(transliterate_to_ascii("あっ"), "a?"); // nothing to double
+    }
+
+    #[test]
+    fn test_passthrough_and_unknown() {
+        assert_eq!(transliterate_to_ascii(""), "");
+        assert_eq!(transliterate_to_ascii("abc 123!"), "abc 123!");
+        assert_eq!(transliterate_to_ascii("漢字"), "??");
+        assert_eq!(transliterate_to_ascii("ｱ"), "?"); // halfwidth katakana
+        assert_eq!(transliterate_to_ascii("はい、そう。"), "hai,sou.");
+    }
+
+    #[test]
+    fn test_halfwidth_to_fullwidth_katakana() {
+        assert_eq!(halfwidth_to_fullwidth_katakana("ｱｲｳｴｵ"), "アイウエオ");
+        assert_eq!(halfwidth_to_fullwidth_katakana("ｶﾞｷﾞｸﾞｹﾞｺﾞ"), "ガギグゲゴ");
+        assert_eq!(halfwidth_to_fullwidth_katakana("ﾂﾞﾄﾞ"), "ヅド");
+        assert_eq!(halfwidth_to_fullwidth_katakana("ﾊﾞﾋﾟﾌﾞﾍﾟﾎﾞ"), "バピブペボ");
+        assert_eq!(halfwidth_to_fullwidth_katakana("ｳﾞｧｲｵﾘﾝ"), "ヴァイオリン");
+        assert_eq!(
+            halfwidth_to_fullwidth_katakana("ｧｨｩｪｫｬｭｮｯｰ"),
+            "ァィゥェォャュョッー"
+        );
+        assert_eq!(halfwidth_to_fullwidth_katakana("｡｢｣､･"), "。「」、・");
+        assert_eq!(halfwidth_to_fullwidth_katakana("ﾞｱﾟ"), "゛ア゜");
+        assert_eq!(halfwidth_to_fullwidth_katakana("abcアあ"), "abcアあ");
+    }
+
+    #[test]
+    fn test_katakana_tables_match_conversion() {
+        use crate::data::jisx0201::{KATAKANA_COMPOSE, KATAKANA_TO_FULLWIDTH};
+        use crate::data::jisx0208::{KATAKANA, KATAKANA_TO_HALFWIDTH};
+
+        let text =
+            |cps: &[u32]| -> String { cps.iter().filter_map(|&cp| char::from_u32(cp)).collect() };
+
+        for &(half, full) in KATAKANA_TO_FULLWIDTH {
+            assert_eq!(
+                halfwidth_to_fullwidth_katakana(&text(&[half])),
+                text(&[full])
+            );
+        }
+        for &(base, mark, composed) in KATAKANA_COMPOSE {
+            assert_eq!(
+                halfwidth_to_fullwidth_katakana(&text(&[base, mark])),
+                text(&[composed])
+            );
+        }
+
+        // Every fullwidth entry round-trips through the halfwidth form.
+        for &(full, half) in KATAKANA_TO_HALFWIDTH {
+            assert_eq!(halfwidth_to_fullwidth_katakana(&text(half)), text(&[full]));
+        }
+        assert!(KATAKANA_TO_HALFWIDTH.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let omitted: String = KATAKANA
+            .iter()
+            .filter(|&&cp| !KATAKANA_TO_HALFWIDTH.iter().any(|&(full, _)| full == cp))
+            .filter_map(|&cp| char::from_u32(cp))
+            .collect();
+        assert_eq!(omitted, "ヮヰヱヵヶ");
+    }
+
+    #[test]
+    fn test_fullwidth_case() {
+        assert_eq!(to_fullwidth_upper("ａｂｃｘｙｚ"), "ＡＢＣＸＹＺ");
+        assert_eq!(to_fullwidth_lower("ＡＢＣＸＹＺ"), "ａｂｃｘｙｚ");
+        assert_eq!(to_fullwidth_upper("ＡａＺｚ"), "ＡＡＺＺ");
+        assert_eq!(to_fullwidth_lower("ＡａＺｚ"), "ａａｚｚ");
+
+        // Digits, kana, ASCII and neighbouring symbols are untouched.
+        let other = "０１２９あアｱabcXYZ＠［｀｛";
+        assert_eq!(to_fullwidth_upper(other), other);
+        assert_eq!(to_fullwidth_lower(other), other);
+    }
+
+    #[test]
+    fn test_normalize_vertical_forms() {
+        let vertical = "﹁こんにちは︑世界︒﹂";
+        let strict = crate::CodePoints::from_string("「」、。こんにちは世界");
+        assert!(!strict.contains(vertical));
+        let normalized = normalize_vertical_forms(vertical);
+        assert_eq!(normalized, "「こんにちは、世界。」");
+        assert!(strict.contains(&normalized));
+
+        assert_eq!(normalize_vertical_forms("﹝﹐﹑﹒﹫"), "〔,、.@");
+        assert_eq!(normalize_vertical_forms("abc﹅"), "abc﹅");
+    }
+
+    #[test]
+    fn test_vertical_forms_round_trip() {
+        for &(form, horizontal) in VERTICAL_FORMS {
+            let form = char::from_u32(form).unwrap().to_string();
+            let horizontal = char::from_u32(horizontal).unwrap().to_string();
+            assert_eq!(normalize_vertical_forms(&form), horizontal);
+            if form != "︴" {
+                assert_eq!(to_vertical_forms(&horizontal), form);
+            }
+        }
+        assert_eq!(to_vertical_forms("_"), "︳");
+        let text = "﹁はい︑そうです︒﹂";
+        assert_eq!(to_vertical_forms(&normalize_vertical_forms(text)), text);
+    }
+}
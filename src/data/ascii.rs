@@ -39,6 +39,10 @@ pub const CONTROL_CHARS: &[u32] = &[
     0x007F, // DELETE
 ];
 
+/// Number of entries in [`CONTROL_CHARS`].
+pub const CONTROL_COUNT: usize = 33;
+const _: () = assert!(CONTROL_CHARS.len() == CONTROL_COUNT);
+
 /// ASCII printable characters (0x0020-0x007E)
 pub const PRINTABLE_CHARS: &[u32] = &[
     0x0020, // SPACE
@@ -138,8 +142,34 @@ pub const PRINTABLE_CHARS: &[u32] = &[
     0x007E, // ~
 ];
 
+/// Number of entries in [`PRINTABLE_CHARS`].
+pub const PRINTABLE_COUNT: usize = 95;
+const _: () = assert!(PRINTABLE_CHARS.len() == PRINTABLE_COUNT);
+
 /// CRLF characters
 pub const CRLF_CHARS: &[u32] = &[
     0x000A, // LINE FEED
     0x000D, // CARRIAGE RETURN
 ];
+
+/// Number of entries in [`CRLF_CHARS`].
+pub const CRLF_COUNT: usize = 2;
+const _: () = assert!(CRLF_CHARS.len() == CRLF_COUNT);
+
+// ── merged table ──────────────────────────────────────────────────────────────
+
+const ALL_PARTS: &[&[u32]] = &[CONTROL_CHARS, PRINTABLE_CHARS];
+
+/// Number of entries in [`ALL_ASCII`].
+pub const ALL_ASCII_COUNT: usize = super::total_len(ALL_PARTS);
+const _: () = assert!(ALL_ASCII_COUNT == 128);
+
+/// All 128 ASCII code points (U+0000–U+007F), strictly ascending.
+///
+/// Exactly the union of [`CONTROL_CHARS`] and [`PRINTABLE_CHARS`], merged
+/// and sorted at compile time; compilation fails if the two tables overlap.
+/// [`CRLF_CHARS`] is a subset of [`CONTROL_CHARS`] and adds nothing.
+pub const ALL_ASCII: [u32; ALL_ASCII_COUNT] = super::sorted_union(ALL_PARTS);
+
+/// [`ALL_ASCII`] as `char`s, converted at compile time.
+pub const ALL_ASCII_CHARS: &[char] = &super::to_chars::<ALL_ASCII_COUNT>(&ALL_ASCII);
@@ -0,0 +1,341 @@
+//! Consistency checks over the bundled tables
+//!
+//! Most invariants are also enforced at compile time (`*_COUNT` asserts,
+//! `to_chars`, the overlap check of `ALL_JISX0208`); [`verify`] re-checks
+//! them at run time and reports every violation instead of stopping at the
+//! first, so patched data can be audited as a whole.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use super::{ascii, enclosed, jisx0201, jisx0208, jisx0208kanji, jisx0213kana, jisx0213kanji};
+use super::{ibm, kinsoku, nec, presets, shift_jis};
+
+/// A violation of the invariants of the bundled tables, reported by
+/// [`verify`].
+///
+/// Tables are named by their path below `data`, e.g. `"jisx0208::HIRAGANA"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataInconsistency {
+    /// A table lists the same code point more than once.
+    Duplicate {
+        /// The table.
+        table: &'static str,
+        /// The repeated code point.
+        code_point: u32,
+    },
+    /// A table contains a surrogate or a value above U+10FFFF.
+    InvalidCodePoint {
+        /// The table.
+        table: &'static str,
+        /// The offending value.
+        code_point: u32,
+    },
+    /// Two tables that must be disjoint share a code point.
+    Overlap {
+        /// The table listed first.
+        first: &'static str,
+        /// The table listed second.
+        second: &'static str,
+        /// The shared code point.
+        code_point: u32,
+    },
+    /// A table that must be a subset of another has a code point the other
+    /// lacks.
+    NotSubset {
+        /// The table expected to be the subset.
+        subset: &'static str,
+        /// The table expected to contain it.
+        superset: &'static str,
+        /// The code point missing from `superset`.
+        code_point: u32,
+    },
+}
+
+impl fmt::Display for DataInconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DataInconsistency::Duplicate { table, code_point } => {
+                write!(f, "{} lists U+{:04X} more than once", table, code_point)
+            }
+            DataInconsistency::InvalidCodePoint { table, code_point } => {
+                write!(
+                    f,
+                    "{} contains invalid code point 0x{:X}",
+                    table, code_point
+                )
+            }
+            DataInconsistency::Overlap {
+                first,
+                second,
+                code_point,
+            } => write!(
+                f,
+                "{} and {} both contain U+{:04X}",
+                first, second, code_point
+            ),
+            DataInconsistency::NotSubset {
+                subset,
+                superset,
+                code_point,
+            } => write!(
+                f,
+                "U+{:04X} is in {} but not in {}",
+                code_point, subset, superset
+            ),
+        }
+    }
+}
+
+type Table = (&'static str, &'static [u32]);
+
+/// Every code-point table, for the duplicate and validity checks.
+const TABLES: &[Table] = &[
+    ("ascii::CONTROL_CHARS", ascii::CONTROL_CHARS),
+    ("ascii::PRINTABLE_CHARS", ascii::PRINTABLE_CHARS),
+    ("ascii::CRLF_CHARS", ascii::CRLF_CHARS),
+    ("jisx0201::LATIN_LETTERS", jisx0201::LATIN_LETTERS),
+    ("jisx0201::KATAKANA", jisx0201::KATAKANA),
+    ("jisx0208::SPECIAL_CHARS", jisx0208::SPECIAL_CHARS),
+    ("jisx0208::LATIN_LETTERS", jisx0208::LATIN_LETTERS),
+    ("jisx0208::HIRAGANA", jisx0208::HIRAGANA),
+    ("jisx0208::KATAKANA", jisx0208::KATAKANA),
+    ("jisx0208::GREEK_LETTERS", jisx0208::GREEK_LETTERS),
+    ("jisx0208::CYRILLIC_LETTERS", jisx0208::CYRILLIC_LETTERS),
+    ("jisx0208::BOX_DRAWING_CHARS", jisx0208::BOX_DRAWING_CHARS),
+    (
+        "jisx0208kanji::JISX0208_CHARS",
+        jisx0208kanji::JISX0208_CHARS,
+    ),
+    (
+        "jisx0213kanji::JISX0213_KANJI",
+        jisx0213kanji::JISX0213_KANJI,
+    ),
+    (
+        "jisx0213kana::HIRAGANA_ADDITIONS",
+        jisx0213kana::HIRAGANA_ADDITIONS,
+    ),
+    (
+        "jisx0213kana::KATAKANA_ADDITIONS",
+        jisx0213kana::KATAKANA_ADDITIONS,
+    ),
+    (
+        "jisx0213kana::PHONETIC_EXTENSIONS",
+        jisx0213kana::PHONETIC_EXTENSIONS,
+    ),
+    ("enclosed::JISX0213_ENCLOSED", enclosed::JISX0213_ENCLOSED),
+    (
+        "kinsoku::LINE_START_PROHIBITED",
+        kinsoku::LINE_START_PROHIBITED,
+    ),
+    ("kinsoku::LINE_END_PROHIBITED", kinsoku::LINE_END_PROHIBITED),
+    ("nec::NEC_ROW13", nec::NEC_ROW13),
+    ("ibm::IBM_EXTENSIONS", ibm::IBM_EXTENSIONS),
+    ("shift_jis::TRAIL_BYTE_5C", shift_jis::TRAIL_BYTE_5C),
+    ("shift_jis::JIS_MAPPINGS", shift_jis::JIS_MAPPINGS),
+    ("shift_jis::CP932_MAPPINGS", shift_jis::CP932_MAPPINGS),
+    (
+        "presets::CORPORATE_NAME_KANA_SYMBOLS",
+        presets::CORPORATE_NAME_KANA_SYMBOLS,
+    ),
+    ("presets::JOYO_KANJI", presets::JOYO_KANJI),
+    ("presets::JINMEIYO_KANJI", presets::JINMEIYO_KANJI),
+];
+
+/// Checks the bundled tables and returns every inconsistency found.
+///
+/// The checks are:
+///
+/// * no table contains a duplicate, a surrogate or a value above U+10FFFF;
+/// * the seven JIS X 0208 non-kanji tables are pairwise disjoint, as are
+///   the JIS X 0213 kana additions and the JIS X 0208 kana they extend;
+/// * every JIS X 0208 kanji is also a JIS X 0213 kanji;
+/// * the jōyō and jinmeiyō kanji are disjoint and all JIS X 0213 kanji.
+///
+/// Violations are reported in table order.  The crate's own test suite
+/// runs this; applications that patch the data can call it to keep an
+/// executable record of the audit.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(japanese_codepoints::data::verify(), Ok(()));
+/// ```
+pub fn verify() -> Result<(), Vec<DataInconsistency>> {
+    let mut found = Vec::new();
+    for &table in TABLES {
+        check_table(table, &mut found);
+    }
+    check_disjoint(
+        &[
+            ("jisx0208::SPECIAL_CHARS", jisx0208::SPECIAL_CHARS),
+            ("jisx0208::LATIN_LETTERS", jisx0208::LATIN_LETTERS),
+            ("jisx0208::HIRAGANA", jisx0208::HIRAGANA),
+            ("jisx0208::KATAKANA", jisx0208::KATAKANA),
+            ("jisx0208::GREEK_LETTERS", jisx0208::GREEK_LETTERS),
+            ("jisx0208::CYRILLIC_LETTERS", jisx0208::CYRILLIC_LETTERS),
+            ("jisx0208::BOX_DRAWING_CHARS", jisx0208::BOX_DRAWING_CHARS),
+        ],
+        &mut found,
+    );
+    check_disjoint(
+        &[
+            ("jisx0208::HIRAGANA", jisx0208::HIRAGANA),
+            (
+                "jisx0213kana::HIRAGANA_ADDITIONS",
+                jisx0213kana::HIRAGANA_ADDITIONS,
+            ),
+        ],
+        &mut found,
+    );
+    check_disjoint(
+        &[
+            ("jisx0208::KATAKANA", jisx0208::KATAKANA),
+            (
+                "jisx0213kana::KATAKANA_ADDITIONS",
+                jisx0213kana::KATAKANA_ADDITIONS,
+            ),
+        ],
+        &mut found,
+    );
+    check_subset(
+        (
+            "jisx0208kanji::JISX0208_CHARS",
+            jisx0208kanji::JISX0208_CHARS,
+        ),
+        (
+            "jisx0213kanji::JISX0213_KANJI",
+            jisx0213kanji::JISX0213_KANJI,
+        ),
+        &mut found,
+    );
+    check_disjoint(
+        &[
+            ("presets::JOYO_KANJI", presets::JOYO_KANJI),
+            ("presets::JINMEIYO_KANJI", presets::JINMEIYO_KANJI),
+        ],
+        &mut found,
+    );
+    for names in [
+        ("presets::JOYO_KANJI", presets::JOYO_KANJI),
+        ("presets::JINMEIYO_KANJI", presets::JINMEIYO_KANJI),
+    ] {
+        check_subset(
+            names,
+            (
+                "jisx0213kanji::JISX0213_KANJI",
+                jisx0213kanji::JISX0213_KANJI,
+            ),
+            &mut found,
+        );
+    }
+
+    if found.is_empty() {
+        Ok(())
+    } else {
+        Err(found)
+    }
+}
+
+fn check_table((table, values): Table, found: &mut Vec<DataInconsistency>) {
+    let mut seen = HashSet::with_capacity(values.len());
+    for &code_point in values {
+        if char::from_u32(code_point).is_none() {
+            found.push(DataInconsistency::InvalidCodePoint { table, code_point });
+        }
+        if !seen.insert(code_point) {
+            found.push(DataInconsistency::Duplicate { table, code_point });
+        }
+    }
+}
+
+fn check_disjoint(tables: &[Table], found: &mut Vec<DataInconsistency>) {
+    for (i, &(first, a)) in tables.iter().enumerate() {
+        let a: HashSet<u32> = a.iter().copied().collect();
+        for &(second, b) in tables.iter().skip(i + 1) {
+            for &code_point in b {
+                if a.contains(&code_point) {
+                    found.push(DataInconsistency::Overlap {
+                        first,
+                        second,
+                        code_point,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn check_subset((subset, sub): Table, (superset, sup): Table, found: &mut Vec<DataInconsistency>) {
+    let sup: HashSet<u32> = sup.iter().copied().collect();
+    for &code_point in sub {
+        if !sup.contains(&code_point) {
+            found.push(DataInconsistency::NotSubset {
+                subset,
+                superset,
+                code_point,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_data_is_consistent() {
+        if let Err(found) = verify() {
+            let lines: Vec<String> = found.iter().map(|e| e.to_string()).collect();
+            panic!("data inconsistencies:\n{}", lines.join("\n"));
+        }
+    }
+
+    #[test]
+    fn test_detects_violations() {
+        let mut found = Vec::new();
+        check_table(("a", &[0x41, 0xD800, 0x41, 0x110000]), &mut found);
+        check_disjoint(&[("a", &[1, 2]), ("b", &[3]), ("c", &[2, 3])], &mut found);
+        check_subset(("a", &[1, 2, 5]), ("b", &[2]), &mut found);
+        assert_eq!(
+            found,
+            vec![
+                DataInconsistency::InvalidCodePoint {
+                    table: "a",
+                    code_point: 0xD800
+                },
+                DataInconsistency::Duplicate {
+                    table: "a",
+                    code_point: 0x41
+                },
+                DataInconsistency::InvalidCodePoint {
+                    table: "a",
+                    code_point: 0x110000
+                },
+                DataInconsistency::Overlap {
+                    first: "a",
+                    second: "c",
+                    code_point: 2
+                },
+                DataInconsistency::Overlap {
+                    first: "b",
+                    second: "c",
+                    code_point: 3
+                },
+                DataInconsistency::NotSubset {
+                    subset: "a",
+                    superset: "b",
+                    code_point: 1
+                },
+                DataInconsistency::NotSubset {
+                    subset: "a",
+                    superset: "b",
+                    code_point: 5
+                },
+            ]
+        );
+        assert_eq!(found[3].to_string(), "a and c both contain U+0002");
+        assert_eq!(found[0].to_string(), "a contains invalid code point 0xD800");
+    }
+}
@@ -0,0 +1,59 @@
+//! JIS X 0213 → JIS X 0208 kanji fallbacks
+//!
+//! Kanji added by JIS X 0213 are often variants of a JIS X 0208 kanji, and
+//! systems limited to JIS X 0208 conventionally substitute that kanji
+//! (`𠮟` → `叱`, `頰` → `頬`).  The table lists only such well-established
+//! pairs; a JIS X 0213 kanji without an entry has no accepted substitute.
+
+/// `(JIS X 0213 kanji, JIS X 0208 substitute)` pairs
+///
+/// Sorted by JIS X 0213 kanji.  Every source is a JIS X 0213 kanji outside
+/// JIS X 0208, and every substitute is a JIS X 0208 kanji.
+pub const JISX0213_TO_JISX0208: &[(u32, u32)] = &[
+    (0x4FF1, 0x5036),  // 俱 → 倶
+    (0x5002, 0x4F75),  // 倂 → 併
+    (0x525D, 0x5265),  // 剝 → 剥
+    (0x537D, 0x5373),  // 卽 → 即
+    (0x541E, 0x5451),  // 吞 → 呑
+    (0x5653, 0x5618),  // 噓 → 嘘
+    (0x56CA, 0x56A2),  // 囊 → 嚢
+    (0x5861, 0x586B),  // 塡 → 填
+    (0x59F8, 0x598D),  // 姸 → 妍
+    (0x5C5B, 0x5C4F),  // 屛 → 屏
+    (0x5C62, 0x5C61),  // 屢 → 屡
+    (0x5DE2, 0x5DE3),  // 巢 → 巣
+    (0x5E77, 0x5E76),  // 幷 → 并
+    (0x5FB7, 0x5FB3),  // 德 → 徳
+    (0x63ED, 0x63B2),  // 揭 → 掲
+    (0x6414, 0x63BB),  // 搔 → 掻
+    (0x6451, 0x63B4),  // 摑 → 掴
+    (0x6801, 0x67F3),  // 栁 → 柳
+    (0x6A6B, 0x6A2A),  // 橫 → 横
+    (0x6DDA, 0x6D99),  // 淚 → 涙
+    (0x6F51, 0x6E8C),  // 潑 → 溌
+    (0x7006, 0x6D9C),  // 瀆 → 涜
+    (0x7501, 0x74F6),  // 甁 → 瓶
+    (0x7626, 0x75E9),  // 瘦 → 痩
+    (0x79B1, 0x7977),  // 禱 → 祷
+    (0x7DA0, 0x7DD1),  // 綠 → 緑
+    (0x7E6B, 0x7E4B),  // 繫 → 繋
+    (0x8523, 0x848B),  // 蔣 → 蒋
+    (0x87EC, 0x8749),  // 蟬 → 蝉
+    (0x881F, 0x874B),  // 蠟 → 蝋
+    (0x91AC, 0x91A4),  // 醬 → 醤
+    (0x934A, 0x932C),  // 鍊 → 錬
+    (0x9830, 0x982C),  // 頰 → 頬
+    (0x985A, 0x985B),  // 顚 → 顛
+    (0x9A52, 0x9A28),  // 驒 → 騨
+    (0x9DD7, 0x9D0E),  // 鷗 → 鴎
+    (0x9E7C, 0x9E78),  // 鹼 → 鹸
+    (0x9EB4, 0x9EB9),  // 麴 → 麹
+    (0x9EC3, 0x9EC4),  // 黃 → 黄
+    (0xFA11, 0x5D0E),  // 﨑 → 崎
+    (0x20B9F, 0x53F1), // 𠮟 → 叱
+    (0x2123D, 0x571F), // 𡈽 → 土
+];
+
+/// Number of entries in [`JISX0213_TO_JISX0208`].
+pub const JISX0213_TO_JISX0208_COUNT: usize = 42;
+const _: () = assert!(JISX0213_TO_JISX0208.len() == JISX0213_TO_JISX0208_COUNT);
@@ -0,0 +1,169 @@
+//! Enclosed (circled and parenthesized) characters
+//!
+//! Circled digits, circled katakana and abbreviations such as ㈱ are common
+//! in Japanese business text.  JIS X 0213 standardizes only some of them;
+//! the rest exist in Unicode (and vendor encodings such as CP932) alone.
+
+/// Enclosed characters defined by JIS X 0213:2004 (plane 1), in code-point
+/// order
+pub const JISX0213_ENCLOSED: &[u32] = &[
+    0x2460, // ① CIRCLED DIGIT ONE
+    0x2461, // ② CIRCLED DIGIT TWO
+    0x2462, // ③ CIRCLED DIGIT THREE
+    0x2463, // ④ CIRCLED DIGIT FOUR
+    0x2464, // ⑤ CIRCLED DIGIT FIVE
+    0x2465, // ⑥ CIRCLED DIGIT SIX
+    0x2466, // ⑦ CIRCLED DIGIT SEVEN
+    0x2467, // ⑧ CIRCLED DIGIT EIGHT
+    0x2468, // ⑨ CIRCLED DIGIT NINE
+    0x2469, // ⑩ CIRCLED NUMBER TEN
+    0x246A, // ⑪ CIRCLED NUMBER ELEVEN
+    0x246B, // ⑫ CIRCLED NUMBER TWELVE
+    0x246C, // ⑬ CIRCLED NUMBER THIRTEEN
+    0x246D, // ⑭ CIRCLED NUMBER FOURTEEN
+    0x246E, // ⑮ CIRCLED NUMBER FIFTEEN
+    0x246F, // ⑯ CIRCLED NUMBER SIXTEEN
+    0x2470, // ⑰ CIRCLED NUMBER SEVENTEEN
+    0x2471, // ⑱ CIRCLED NUMBER EIGHTEEN
+    0x2472, // ⑲ CIRCLED NUMBER NINETEEN
+    0x2473, // ⑳ CIRCLED NUMBER TWENTY
+    0x24D0, // ⓐ CIRCLED LATIN SMALL LETTER A
+    0x24D1, // ⓑ CIRCLED LATIN SMALL LETTER B
+    0x24D2, // ⓒ CIRCLED LATIN SMALL LETTER C
+    0x24D3, // ⓓ CIRCLED LATIN SMALL LETTER D
+    0x24D4, // ⓔ CIRCLED LATIN SMALL LETTER E
+    0x24D5, // ⓕ CIRCLED LATIN SMALL LETTER F
+    0x24D6, // ⓖ CIRCLED LATIN SMALL LETTER G
+    0x24D7, // ⓗ CIRCLED LATIN SMALL LETTER H
+    0x24D8, // ⓘ CIRCLED LATIN SMALL LETTER I
+    0x24D9, // ⓙ CIRCLED LATIN SMALL LETTER J
+    0x24DA, // ⓚ CIRCLED LATIN SMALL LETTER K
+    0x24DB, // ⓛ CIRCLED LATIN SMALL LETTER L
+    0x24DC, // ⓜ CIRCLED LATIN SMALL LETTER M
+    0x24DD, // ⓝ CIRCLED LATIN SMALL LETTER N
+    0x24DE, // ⓞ CIRCLED LATIN SMALL LETTER O
+    0x24DF, // ⓟ CIRCLED LATIN SMALL LETTER P
+    0x24E0, // ⓠ CIRCLED LATIN SMALL LETTER Q
+    0x24E1, // ⓡ CIRCLED LATIN SMALL LETTER R
+    0x24E2, // ⓢ CIRCLED LATIN SMALL LETTER S
+    0x24E3, // ⓣ CIRCLED LATIN SMALL LETTER T
+    0x24E4, // ⓤ CIRCLED LATIN SMALL LETTER U
+    0x24E5, // ⓥ CIRCLED LATIN SMALL LETTER V
+    0x24E6, // ⓦ CIRCLED LATIN SMALL LETTER W
+    0x24E7, // ⓧ CIRCLED LATIN SMALL LETTER X
+    0x24E8, // ⓨ CIRCLED LATIN SMALL LETTER Y
+    0x24E9, // ⓩ CIRCLED LATIN SMALL LETTER Z
+    0x24EB, // ⓫ NEGATIVE CIRCLED NUMBER ELEVEN
+    0x24EC, // ⓬ NEGATIVE CIRCLED NUMBER TWELVE
+    0x24ED, // ⓭ NEGATIVE CIRCLED NUMBER THIRTEEN
+    0x24EE, // ⓮ NEGATIVE CIRCLED NUMBER FOURTEEN
+    0x24EF, // ⓯ NEGATIVE CIRCLED NUMBER FIFTEEN
+    0x24F0, // ⓰ NEGATIVE CIRCLED NUMBER SIXTEEN
+    0x24F1, // ⓱ NEGATIVE CIRCLED NUMBER SEVENTEEN
+    0x24F2, // ⓲ NEGATIVE CIRCLED NUMBER EIGHTEEN
+    0x24F3, // ⓳ NEGATIVE CIRCLED NUMBER NINETEEN
+    0x24F4, // ⓴ NEGATIVE CIRCLED NUMBER TWENTY
+    0x24F5, // ⓵ DOUBLE CIRCLED DIGIT ONE
+    0x24F6, // ⓶ DOUBLE CIRCLED DIGIT TWO
+    0x24F7, // ⓷ DOUBLE CIRCLED DIGIT THREE
+    0x24F8, // ⓸ DOUBLE CIRCLED DIGIT FOUR
+    0x24F9, // ⓹ DOUBLE CIRCLED DIGIT FIVE
+    0x24FA, // ⓺ DOUBLE CIRCLED DIGIT SIX
+    0x24FB, // ⓻ DOUBLE CIRCLED DIGIT SEVEN
+    0x24FC, // ⓼ DOUBLE CIRCLED DIGIT EIGHT
+    0x24FD, // ⓽ DOUBLE CIRCLED DIGIT NINE
+    0x24FE, // ⓾ DOUBLE CIRCLED NUMBER TEN
+    0x2776, // ❶ DINGBAT NEGATIVE CIRCLED DIGIT ONE
+    0x2777, // ❷ DINGBAT NEGATIVE CIRCLED DIGIT TWO
+    0x2778, // ❸ DINGBAT NEGATIVE CIRCLED DIGIT THREE
+    0x2779, // ❹ DINGBAT NEGATIVE CIRCLED DIGIT FOUR
+    0x277A, // ❺ DINGBAT NEGATIVE CIRCLED DIGIT FIVE
+    0x277B, // ❻ DINGBAT NEGATIVE CIRCLED DIGIT SIX
+    0x277C, // ❼ DINGBAT NEGATIVE CIRCLED DIGIT SEVEN
+    0x277D, // ❽ DINGBAT NEGATIVE CIRCLED DIGIT EIGHT
+    0x277E, // ❾ DINGBAT NEGATIVE CIRCLED DIGIT NINE
+    0x277F, // ❿ DINGBAT NEGATIVE CIRCLED NUMBER TEN
+    0x3231, // ㈱ PARENTHESIZED IDEOGRAPH STOCK
+    0x3232, // ㈲ PARENTHESIZED IDEOGRAPH HAVE
+    0x3239, // ㈹ PARENTHESIZED IDEOGRAPH REPRESENT
+    0x3251, // ㉑ CIRCLED NUMBER TWENTY ONE
+    0x3252, // ㉒ CIRCLED NUMBER TWENTY TWO
+    0x3253, // ㉓ CIRCLED NUMBER TWENTY THREE
+    0x3254, // ㉔ CIRCLED NUMBER TWENTY FOUR
+    0x3255, // ㉕ CIRCLED NUMBER TWENTY FIVE
+    0x3256, // ㉖ CIRCLED NUMBER TWENTY SIX
+    0x3257, // ㉗ CIRCLED NUMBER TWENTY SEVEN
+    0x3258, // ㉘ CIRCLED NUMBER TWENTY EIGHT
+    0x3259, // ㉙ CIRCLED NUMBER TWENTY NINE
+    0x325A, // ㉚ CIRCLED NUMBER THIRTY
+    0x325B, // ㉛ CIRCLED NUMBER THIRTY ONE
+    0x325C, // ㉜ CIRCLED NUMBER THIRTY TWO
+    0x325D, // ㉝ CIRCLED NUMBER THIRTY THREE
+    0x325E, // ㉞ CIRCLED NUMBER THIRTY FOUR
+    0x325F, // ㉟ CIRCLED NUMBER THIRTY FIVE
+    0x32A4, // ㊤ CIRCLED IDEOGRAPH HIGH
+    0x32A5, // ㊥ CIRCLED IDEOGRAPH CENTRE
+    0x32A6, // ㊦ CIRCLED IDEOGRAPH LOW
+    0x32A7, // ㊧ CIRCLED IDEOGRAPH LEFT
+    0x32A8, // ㊨ CIRCLED IDEOGRAPH RIGHT
+    0x32B1, // ㊱ CIRCLED NUMBER THIRTY SIX
+    0x32B2, // ㊲ CIRCLED NUMBER THIRTY SEVEN
+    0x32B3, // ㊳ CIRCLED NUMBER THIRTY EIGHT
+    0x32B4, // ㊴ CIRCLED NUMBER THIRTY NINE
+    0x32B5, // ㊵ CIRCLED NUMBER FORTY
+    0x32B6, // ㊶ CIRCLED NUMBER FORTY ONE
+    0x32B7, // ㊷ CIRCLED NUMBER FORTY TWO
+    0x32B8, // ㊸ CIRCLED NUMBER FORTY THREE
+    0x32B9, // ㊹ CIRCLED NUMBER FORTY FOUR
+    0x32BA, // ㊺ CIRCLED NUMBER FORTY FIVE
+    0x32BB, // ㊻ CIRCLED NUMBER FORTY SIX
+    0x32BC, // ㊼ CIRCLED NUMBER FORTY SEVEN
+    0x32BD, // ㊽ CIRCLED NUMBER FORTY EIGHT
+    0x32BE, // ㊾ CIRCLED NUMBER FORTY NINE
+    0x32BF, // ㊿ CIRCLED NUMBER FIFTY
+    0x32D0, // ㋐ CIRCLED KATAKANA A
+    0x32D1, // ㋑ CIRCLED KATAKANA I
+    0x32D2, // ㋒ CIRCLED KATAKANA U
+    0x32D3, // ㋓ CIRCLED KATAKANA E
+    0x32D4, // ㋔ CIRCLED KATAKANA O
+    0x32D5, // ㋕ CIRCLED KATAKANA KA
+    0x32D6, // ㋖ CIRCLED KATAKANA KI
+    0x32D7, // ㋗ CIRCLED KATAKANA KU
+    0x32D8, // ㋘ CIRCLED KATAKANA KE
+    0x32D9, // ㋙ CIRCLED KATAKANA KO
+    0x32DA, // ㋚ CIRCLED KATAKANA SA
+    0x32DB, // ㋛ CIRCLED KATAKANA SI
+    0x32DC, // ㋜ CIRCLED KATAKANA SU
+    0x32DD, // ㋝ CIRCLED KATAKANA SE
+    0x32DE, // ㋞ CIRCLED KATAKANA SO
+    0x32DF, // ㋟ CIRCLED KATAKANA TA
+    0x32E0, // ㋠ CIRCLED KATAKANA TI
+    0x32E1, // ㋡ CIRCLED KATAKANA TU
+    0x32E2, // ㋢ CIRCLED KATAKANA TE
+    0x32E3, // ㋣ CIRCLED KATAKANA TO
+    0x32E5, // ㋥ CIRCLED KATAKANA NI
+    0x32E9, // ㋩ CIRCLED KATAKANA HA
+    0x32EC, // ㋬ CIRCLED KATAKANA HE
+    0x32ED, // ㋭ CIRCLED KATAKANA HO
+    0x32FA, // ㋺ CIRCLED KATAKANA RO
+];
+
+/// Number of entries in [`JISX0213_ENCLOSED`].
+pub const JISX0213_ENCLOSED_COUNT: usize = 139;
+const _: () = assert!(JISX0213_ENCLOSED.len() == JISX0213_ENCLOSED_COUNT);
+
+/// [`JISX0213_ENCLOSED`] as `char`s, converted at compile time.
+pub const JISX0213_ENCLOSED_CHARS: &[char] =
+    &super::to_chars::<JISX0213_ENCLOSED_COUNT>(JISX0213_ENCLOSED);
+
+/// Assigned code points of the Enclosed Alphanumerics and Enclosed CJK
+/// Letters and Months blocks, plus the dingbat negative circled digits ❶–❿
+/// so that the ranges cover [`JISX0213_ENCLOSED`], as inclusive ranges
+///
+/// Sorted and non-overlapping.  U+321F is unassigned.
+pub const EXTENDED_RANGES: &[(u32, u32)] = &[
+    (0x2460, 0x24FF), // Enclosed Alphanumerics
+    (0x2776, 0x277F), // ❶..❿
+    (0x3200, 0x321E), // Enclosed CJK Letters and Months
+    (0x3220, 0x32FF),
+];
@@ -0,0 +1,408 @@
+//! IBM extensions (CP932 rows 115–119)
+//!
+//! IBM's DBCS code pages carried 360 kanji and a few symbols missing from
+//! JIS X 0208, most of them variant forms used in personal names (髙, 﨑).
+//! CP932 (Windows-31J) placed them in rows 115–119 (lead bytes 0xFA–0xFC)
+//! and again, as the "NEC-selected IBM extensions", in rows 89–92.  The
+//! Roman numerals, `㈱`, `№`, `℡` and `∵` duplicate characters of JIS X 0208
+//! or NEC row 13.
+
+/// IBM extension characters, in kuten order, with the code points CP932
+/// maps them to
+pub const IBM_EXTENSIONS: &[u32] = &[
+    0x2170, // ⅰ (115-01, 0xFA40) SMALL ROMAN NUMERAL ONE
+    0x2171, // ⅱ (115-02, 0xFA41) SMALL ROMAN NUMERAL TWO
+    0x2172, // ⅲ (115-03, 0xFA42) SMALL ROMAN NUMERAL THREE
+    0x2173, // ⅳ (115-04, 0xFA43) SMALL ROMAN NUMERAL FOUR
+    0x2174, // ⅴ (115-05, 0xFA44) SMALL ROMAN NUMERAL FIVE
+    0x2175, // ⅵ (115-06, 0xFA45) SMALL ROMAN NUMERAL SIX
+    0x2176, // ⅶ (115-07, 0xFA46) SMALL ROMAN NUMERAL SEVEN
+    0x2177, // ⅷ (115-08, 0xFA47) SMALL ROMAN NUMERAL EIGHT
+    0x2178, // ⅸ (115-09, 0xFA48) SMALL ROMAN NUMERAL NINE
+    0x2179, // ⅹ (115-10, 0xFA49) SMALL ROMAN NUMERAL TEN
+    0x2160, // Ⅰ (115-11, 0xFA4A) ROMAN NUMERAL ONE
+    0x2161, // Ⅱ (115-12, 0xFA4B) ROMAN NUMERAL TWO
+    0x2162, // Ⅲ (115-13, 0xFA4C) ROMAN NUMERAL THREE
+    0x2163, // Ⅳ (115-14, 0xFA4D) ROMAN NUMERAL FOUR
+    0x2164, // Ⅴ (115-15, 0xFA4E) ROMAN NUMERAL FIVE
+    0x2165, // Ⅵ (115-16, 0xFA4F) ROMAN NUMERAL SIX
+    0x2166, // Ⅶ (115-17, 0xFA50) ROMAN NUMERAL SEVEN
+    0x2167, // Ⅷ (115-18, 0xFA51) ROMAN NUMERAL EIGHT
+    0x2168, // Ⅸ (115-19, 0xFA52) ROMAN NUMERAL NINE
+    0x2169, // Ⅹ (115-20, 0xFA53) ROMAN NUMERAL TEN
+    0xFFE2, // ￢ (115-21, 0xFA54) FULLWIDTH NOT SIGN
+    0xFFE4, // ￤ (115-22, 0xFA55) FULLWIDTH BROKEN BAR
+    0xFF07, // ＇ (115-23, 0xFA56) FULLWIDTH APOSTROPHE
+    0xFF02, // ＂ (115-24, 0xFA57) FULLWIDTH QUOTATION MARK
+    0x3231, // ㈱ (115-25, 0xFA58) PARENTHESIZED IDEOGRAPH STOCK
+    0x2116, // № (115-26, 0xFA59) NUMERO SIGN
+    0x2121, // ℡ (115-27, 0xFA5A) TELEPHONE SIGN
+    0x2235, // ∵ (115-28, 0xFA5B) BECAUSE
+    0x7E8A, // 纊 (115-29, 0xFA5C) CJK UNIFIED IDEOGRAPH-7E8A
+    0x891C, // 褜 (115-30, 0xFA5D) CJK UNIFIED IDEOGRAPH-891C
+    0x9348, // 鍈 (115-31, 0xFA5E) CJK UNIFIED IDEOGRAPH-9348
+    0x9288, // 銈 (115-32, 0xFA5F) CJK UNIFIED IDEOGRAPH-9288
+    0x84DC, // 蓜 (115-33, 0xFA60) CJK UNIFIED IDEOGRAPH-84DC
+    0x4FC9, // 俉 (115-34, 0xFA61) CJK UNIFIED IDEOGRAPH-4FC9
+    0x70BB, // 炻 (115-35, 0xFA62) CJK UNIFIED IDEOGRAPH-70BB
+    0x6631, // 昱 (115-36, 0xFA63) CJK UNIFIED IDEOGRAPH-6631
+    0x68C8, // 棈 (115-37, 0xFA64) CJK UNIFIED IDEOGRAPH-68C8
+    0x92F9, // 鋹 (115-38, 0xFA65) CJK UNIFIED IDEOGRAPH-92F9
+    0x66FB, // 曻 (115-39, 0xFA66) CJK UNIFIED IDEOGRAPH-66FB
+    0x5F45, // 彅 (115-40, 0xFA67) CJK UNIFIED IDEOGRAPH-5F45
+    0x4E28, // 丨 (115-41, 0xFA68) CJK UNIFIED IDEOGRAPH-4E28
+    0x4EE1, // 仡 (115-42, 0xFA69) CJK UNIFIED IDEOGRAPH-4EE1
+    0x4EFC, // 仼 (115-43, 0xFA6A) CJK UNIFIED IDEOGRAPH-4EFC
+    0x4F00, // 伀 (115-44, 0xFA6B) CJK UNIFIED IDEOGRAPH-4F00
+    0x4F03, // 伃 (115-45, 0xFA6C) CJK UNIFIED IDEOGRAPH-4F03
+    0x4F39, // 伹 (115-46, 0xFA6D) CJK UNIFIED IDEOGRAPH-4F39
+    0x4F56, // 佖 (115-47, 0xFA6E) CJK UNIFIED IDEOGRAPH-4F56
+    0x4F92, // 侒 (115-48, 0xFA6F) CJK UNIFIED IDEOGRAPH-4F92
+    0x4F8A, // 侊 (115-49, 0xFA70) CJK UNIFIED IDEOGRAPH-4F8A
+    0x4F9A, // 侚 (115-50, 0xFA71) CJK UNIFIED IDEOGRAPH-4F9A
+    0x4F94, // 侔 (115-51, 0xFA72) CJK UNIFIED IDEOGRAPH-4F94
+    0x4FCD, // 俍 (115-52, 0xFA73) CJK UNIFIED IDEOGRAPH-4FCD
+    0x5040, // 偀 (115-53, 0xFA74) CJK UNIFIED IDEOGRAPH-5040
+    0x5022, // 倢 (115-54, 0xFA75) CJK UNIFIED IDEOGRAPH-5022
+    0x4FFF, // 俿 (115-55, 0xFA76) CJK UNIFIED IDEOGRAPH-4FFF
+    0x501E, // 倞 (115-56, 0xFA77) CJK UNIFIED IDEOGRAPH-501E
+    0x5046, // 偆 (115-57, 0xFA78) CJK UNIFIED IDEOGRAPH-5046
+    0x5070, // 偰 (115-58, 0xFA79) CJK UNIFIED IDEOGRAPH-5070
+    0x5042, // 偂 (115-59, 0xFA7A) CJK UNIFIED IDEOGRAPH-5042
+    0x5094, // 傔 (115-60, 0xFA7B) CJK UNIFIED IDEOGRAPH-5094
+    0x50F4, // 僴 (115-61, 0xFA7C) CJK UNIFIED IDEOGRAPH-50F4
+    0x50D8, // 僘 (115-62, 0xFA7D) CJK UNIFIED IDEOGRAPH-50D8
+    0x514A, // 兊 (115-63, 0xFA7E) CJK UNIFIED IDEOGRAPH-514A
+    0x5164, // 兤 (115-64, 0xFA80) CJK UNIFIED IDEOGRAPH-5164
+    0x519D, // 冝 (115-65, 0xFA81) CJK UNIFIED IDEOGRAPH-519D
+    0x51BE, // 冾 (115-66, 0xFA82) CJK UNIFIED IDEOGRAPH-51BE
+    0x51EC, // 凬 (115-67, 0xFA83) CJK UNIFIED IDEOGRAPH-51EC
+    0x5215, // 刕 (115-68, 0xFA84) CJK UNIFIED IDEOGRAPH-5215
+    0x529C, // 劜 (115-69, 0xFA85) CJK UNIFIED IDEOGRAPH-529C
+    0x52A6, // 劦 (115-70, 0xFA86) CJK UNIFIED IDEOGRAPH-52A6
+    0x52C0, // 勀 (115-71, 0xFA87) CJK UNIFIED IDEOGRAPH-52C0
+    0x52DB, // 勛 (115-72, 0xFA88) CJK UNIFIED IDEOGRAPH-52DB
+    0x5300, // 匀 (115-73, 0xFA89) CJK UNIFIED IDEOGRAPH-5300
+    0x5307, // 匇 (115-74, 0xFA8A) CJK UNIFIED IDEOGRAPH-5307
+    0x5324, // 匤 (115-75, 0xFA8B) CJK UNIFIED IDEOGRAPH-5324
+    0x5372, // 卲 (115-76, 0xFA8C) CJK UNIFIED IDEOGRAPH-5372
+    0x5393, // 厓 (115-77, 0xFA8D) CJK UNIFIED IDEOGRAPH-5393
+    0x53B2, // 厲 (115-78, 0xFA8E) CJK UNIFIED IDEOGRAPH-53B2
+    0x53DD, // 叝 (115-79, 0xFA8F) CJK UNIFIED IDEOGRAPH-53DD
+    0xFA0E, // 﨎 (115-80, 0xFA90) CJK COMPATIBILITY IDEOGRAPH-FA0E
+    0x549C, // 咜 (115-81, 0xFA91) CJK UNIFIED IDEOGRAPH-549C
+    0x548A, // 咊 (115-82, 0xFA92) CJK UNIFIED IDEOGRAPH-548A
+    0x54A9, // 咩 (115-83, 0xFA93) CJK UNIFIED IDEOGRAPH-54A9
+    0x54FF, // 哿 (115-84, 0xFA94) CJK UNIFIED IDEOGRAPH-54FF
+    0x5586, // 喆 (115-85, 0xFA95) CJK UNIFIED IDEOGRAPH-5586
+    0x5759, // 坙 (115-86, 0xFA96) CJK UNIFIED IDEOGRAPH-5759
+    0x5765, // 坥 (115-87, 0xFA97) CJK UNIFIED IDEOGRAPH-5765
+    0x57AC, // 垬 (115-88, 0xFA98) CJK UNIFIED IDEOGRAPH-57AC
+    0x57C8, // 埈 (115-89, 0xFA99) CJK UNIFIED IDEOGRAPH-57C8
+    0x57C7, // 埇 (115-90, 0xFA9A) CJK UNIFIED IDEOGRAPH-57C7
+    0xFA0F, // 﨏 (115-91, 0xFA9B) CJK COMPATIBILITY IDEOGRAPH-FA0F
+    0xFA10, // 塚 (115-92, 0xFA9C) CJK COMPATIBILITY IDEOGRAPH-FA10
+    0x589E, // 增 (115-93, 0xFA9D) CJK UNIFIED IDEOGRAPH-589E
+    0x58B2, // 墲 (115-94, 0xFA9E) CJK UNIFIED IDEOGRAPH-58B2
+    0x590B, // 夋 (116-01, 0xFA9F) CJK UNIFIED IDEOGRAPH-590B
+    0x5953, // 奓 (116-02, 0xFAA0) CJK UNIFIED IDEOGRAPH-5953
+    0x595B, // 奛 (116-03, 0xFAA1) CJK UNIFIED IDEOGRAPH-595B
+    0x595D, // 奝 (116-04, 0xFAA2) CJK UNIFIED IDEOGRAPH-595D
+    0x5963, // 奣 (116-05, 0xFAA3) CJK UNIFIED IDEOGRAPH-5963
+    0x59A4, // 妤 (116-06, 0xFAA4) CJK UNIFIED IDEOGRAPH-59A4
+    0x59BA, // 妺 (116-07, 0xFAA5) CJK UNIFIED IDEOGRAPH-59BA
+    0x5B56, // 孖 (116-08, 0xFAA6) CJK UNIFIED IDEOGRAPH-5B56
+    0x5BC0, // 寀 (116-09, 0xFAA7) CJK UNIFIED IDEOGRAPH-5BC0
+    0x752F, // 甯 (116-10, 0xFAA8) CJK UNIFIED IDEOGRAPH-752F
+    0x5BD8, // 寘 (116-11, 0xFAA9) CJK UNIFIED IDEOGRAPH-5BD8
+    0x5BEC, // 寬 (116-12, 0xFAAA) CJK UNIFIED IDEOGRAPH-5BEC
+    0x5C1E, // 尞 (116-13, 0xFAAB) CJK UNIFIED IDEOGRAPH-5C1E
+    0x5CA6, // 岦 (116-14, 0xFAAC) CJK UNIFIED IDEOGRAPH-5CA6
+    0x5CBA, // 岺 (116-15, 0xFAAD) CJK UNIFIED IDEOGRAPH-5CBA
+    0x5CF5, // 峵 (116-16, 0xFAAE) CJK UNIFIED IDEOGRAPH-5CF5
+    0x5D27, // 崧 (116-17, 0xFAAF) CJK UNIFIED IDEOGRAPH-5D27
+    0x5D53, // 嵓 (116-18, 0xFAB0) CJK UNIFIED IDEOGRAPH-5D53
+    0xFA11, // 﨑 (116-19, 0xFAB1) CJK COMPATIBILITY IDEOGRAPH-FA11
+    0x5D42, // 嵂 (116-20, 0xFAB2) CJK UNIFIED IDEOGRAPH-5D42
+    0x5D6D, // 嵭 (116-21, 0xFAB3) CJK UNIFIED IDEOGRAPH-5D6D
+    0x5DB8, // 嶸 (116-22, 0xFAB4) CJK UNIFIED IDEOGRAPH-5DB8
+    0x5DB9, // 嶹 (116-23, 0xFAB5) CJK UNIFIED IDEOGRAPH-5DB9
+    0x5DD0, // 巐 (116-24, 0xFAB6) CJK UNIFIED IDEOGRAPH-5DD0
+    0x5F21, // 弡 (116-25, 0xFAB7) CJK UNIFIED IDEOGRAPH-5F21
+    0x5F34, // 弴 (116-26, 0xFAB8) CJK UNIFIED IDEOGRAPH-5F34
+    0x5F67, // 彧 (116-27, 0xFAB9) CJK UNIFIED IDEOGRAPH-5F67
+    0x5FB7, // 德 (116-28, 0xFABA) CJK UNIFIED IDEOGRAPH-5FB7
+    0x5FDE, // 忞 (116-29, 0xFABB) CJK UNIFIED IDEOGRAPH-5FDE
+    0x605D, // 恝 (116-30, 0xFABC) CJK UNIFIED IDEOGRAPH-605D
+    0x6085, // 悅 (116-31, 0xFABD) CJK UNIFIED IDEOGRAPH-6085
+    0x608A, // 悊 (116-32, 0xFABE) CJK UNIFIED IDEOGRAPH-608A
+    0x60DE, // 惞 (116-33, 0xFABF) CJK UNIFIED IDEOGRAPH-60DE
+    0x60D5, // 惕 (116-34, 0xFAC0) CJK UNIFIED IDEOGRAPH-60D5
+    0x6120, // 愠 (116-35, 0xFAC1) CJK UNIFIED IDEOGRAPH-6120
+    0x60F2, // 惲 (116-36, 0xFAC2) CJK UNIFIED IDEOGRAPH-60F2
+    0x6111, // 愑 (116-37, 0xFAC3) CJK UNIFIED IDEOGRAPH-6111
+    0x6137, // 愷 (116-38, 0xFAC4) CJK UNIFIED IDEOGRAPH-6137
+    0x6130, // 愰 (116-39, 0xFAC5) CJK UNIFIED IDEOGRAPH-6130
+    0x6198, // 憘 (116-40, 0xFAC6) CJK UNIFIED IDEOGRAPH-6198
+    0x6213, // 戓 (116-41, 0xFAC7) CJK UNIFIED IDEOGRAPH-6213
+    0x62A6, // 抦 (116-42, 0xFAC8) CJK UNIFIED IDEOGRAPH-62A6
+    0x63F5, // 揵 (116-43, 0xFAC9) CJK UNIFIED IDEOGRAPH-63F5
+    0x6460, // 摠 (116-44, 0xFACA) CJK UNIFIED IDEOGRAPH-6460
+    0x649D, // 撝 (116-45, 0xFACB) CJK UNIFIED IDEOGRAPH-649D
+    0x64CE, // 擎 (116-46, 0xFACC) CJK UNIFIED IDEOGRAPH-64CE
+    0x654E, // 敎 (116-47, 0xFACD) CJK UNIFIED IDEOGRAPH-654E
+    0x6600, // 昀 (116-48, 0xFACE) CJK UNIFIED IDEOGRAPH-6600
+    0x6615, // 昕 (116-49, 0xFACF) CJK UNIFIED IDEOGRAPH-6615
+    0x663B, // 昻 (116-50, 0xFAD0) CJK UNIFIED IDEOGRAPH-663B
+    0x6609, // 昉 (116-51, 0xFAD1) CJK UNIFIED IDEOGRAPH-6609
+    0x662E, // 昮 (116-52, 0xFAD2) CJK UNIFIED IDEOGRAPH-662E
+    0x661E, // 昞 (116-53, 0xFAD3) CJK UNIFIED IDEOGRAPH-661E
+    0x6624, // 昤 (116-54, 0xFAD4) CJK UNIFIED IDEOGRAPH-6624
+    0x6665, // 晥 (116-55, 0xFAD5) CJK UNIFIED IDEOGRAPH-6665
+    0x6657, // 晗 (116-56, 0xFAD6) CJK UNIFIED IDEOGRAPH-6657
+    0x6659, // 晙 (116-57, 0xFAD7) CJK UNIFIED IDEOGRAPH-6659
+    0xFA12, // 晴 (116-58, 0xFAD8) CJK COMPATIBILITY IDEOGRAPH-FA12
+    0x6673, // 晳 (116-59, 0xFAD9) CJK UNIFIED IDEOGRAPH-6673
+    0x6699, // 暙 (116-60, 0xFADA) CJK UNIFIED IDEOGRAPH-6699
+    0x66A0, // 暠 (116-61, 0xFADB) CJK UNIFIED IDEOGRAPH-66A0
+    0x66B2, // 暲 (116-62, 0xFADC) CJK UNIFIED IDEOGRAPH-66B2
+    0x66BF, // 暿 (116-63, 0xFADD) CJK UNIFIED IDEOGRAPH-66BF
+    0x66FA, // 曺 (116-64, 0xFADE) CJK UNIFIED IDEOGRAPH-66FA
+    0x670E, // 朎 (116-65, 0xFADF) CJK UNIFIED IDEOGRAPH-670E
+    0xF929, // 朗 (116-66, 0xFAE0) CJK COMPATIBILITY IDEOGRAPH-F929
+    0x6766, // 杦 (116-67, 0xFAE1) CJK UNIFIED IDEOGRAPH-6766
+    0x67BB, // 枻 (116-68, 0xFAE2) CJK UNIFIED IDEOGRAPH-67BB
+    0x6852, // 桒 (116-69, 0xFAE3) CJK UNIFIED IDEOGRAPH-6852
+    0x67C0, // 柀 (116-70, 0xFAE4) CJK UNIFIED IDEOGRAPH-67C0
+    0x6801, // 栁 (116-71, 0xFAE5) CJK UNIFIED IDEOGRAPH-6801
+    0x6844, // 桄 (116-72, 0xFAE6) CJK UNIFIED IDEOGRAPH-6844
+    0x68CF, // 棏 (116-73, 0xFAE7) CJK UNIFIED IDEOGRAPH-68CF
+    0xFA13, // 﨓 (116-74, 0xFAE8) CJK COMPATIBILITY IDEOGRAPH-FA13
+    0x6968, // 楨 (116-75, 0xFAE9) CJK UNIFIED IDEOGRAPH-6968
+    0xFA14, // 﨔 (116-76, 0xFAEA) CJK COMPATIBILITY IDEOGRAPH-FA14
+    0x6998, // 榘 (116-77, 0xFAEB) CJK UNIFIED IDEOGRAPH-6998
+    0x69E2, // 槢 (116-78, 0xFAEC) CJK UNIFIED IDEOGRAPH-69E2
+    0x6A30, // 樰 (116-79, 0xFAED) CJK UNIFIED IDEOGRAPH-6A30
+    0x6A6B, // 橫 (116-80, 0xFAEE) CJK UNIFIED IDEOGRAPH-6A6B
+    0x6A46, // 橆 (116-81, 0xFAEF) CJK UNIFIED IDEOGRAPH-6A46
+    0x6A73, // 橳 (116-82, 0xFAF0) CJK UNIFIED IDEOGRAPH-6A73
+    0x6A7E, // 橾 (116-83, 0xFAF1) CJK UNIFIED IDEOGRAPH-6A7E
+    0x6AE2, // 櫢 (116-84, 0xFAF2) CJK UNIFIED IDEOGRAPH-6AE2
+    0x6AE4, // 櫤 (116-85, 0xFAF3) CJK UNIFIED IDEOGRAPH-6AE4
+    0x6BD6, // 毖 (116-86, 0xFAF4) CJK UNIFIED IDEOGRAPH-6BD6
+    0x6C3F, // 氿 (116-87, 0xFAF5) CJK UNIFIED IDEOGRAPH-6C3F
+    0x6C5C, // 汜 (116-88, 0xFAF6) CJK UNIFIED IDEOGRAPH-6C5C
+    0x6C86, // 沆 (116-89, 0xFAF7) CJK UNIFIED IDEOGRAPH-6C86
+    0x6C6F, // 汯 (116-90, 0xFAF8) CJK UNIFIED IDEOGRAPH-6C6F
+    0x6CDA, // 泚 (116-91, 0xFAF9) CJK UNIFIED IDEOGRAPH-6CDA
+    0x6D04, // 洄 (116-92, 0xFAFA) CJK UNIFIED IDEOGRAPH-6D04
+    0x6D87, // 涇 (116-93, 0xFAFB) CJK UNIFIED IDEOGRAPH-6D87
+    0x6D6F, // 浯 (116-94, 0xFAFC) CJK UNIFIED IDEOGRAPH-6D6F
+    0x6D96, // 涖 (117-01, 0xFB40) CJK UNIFIED IDEOGRAPH-6D96
+    0x6DAC, // 涬 (117-02, 0xFB41) CJK UNIFIED IDEOGRAPH-6DAC
+    0x6DCF, // 淏 (117-03, 0xFB42) CJK UNIFIED IDEOGRAPH-6DCF
+    0x6DF8, // 淸 (117-04, 0xFB43) CJK UNIFIED IDEOGRAPH-6DF8
+    0x6DF2, // 淲 (117-05, 0xFB44) CJK UNIFIED IDEOGRAPH-6DF2
+    0x6DFC, // 淼 (117-06, 0xFB45) CJK UNIFIED IDEOGRAPH-6DFC
+    0x6E39, // 渹 (117-07, 0xFB46) CJK UNIFIED IDEOGRAPH-6E39
+    0x6E5C, // 湜 (117-08, 0xFB47) CJK UNIFIED IDEOGRAPH-6E5C
+    0x6E27, // 渧 (117-09, 0xFB48) CJK UNIFIED IDEOGRAPH-6E27
+    0x6E3C, // 渼 (117-10, 0xFB49) CJK UNIFIED IDEOGRAPH-6E3C
+    0x6EBF, // 溿 (117-11, 0xFB4A) CJK UNIFIED IDEOGRAPH-6EBF
+    0x6F88, // 澈 (117-12, 0xFB4B) CJK UNIFIED IDEOGRAPH-6F88
+    0x6FB5, // 澵 (117-13, 0xFB4C) CJK UNIFIED IDEOGRAPH-6FB5
+    0x6FF5, // 濵 (117-14, 0xFB4D) CJK UNIFIED IDEOGRAPH-6FF5
+    0x7005, // 瀅 (117-15, 0xFB4E) CJK UNIFIED IDEOGRAPH-7005
+    0x7007, // 瀇 (117-16, 0xFB4F) CJK UNIFIED IDEOGRAPH-7007
+    0x7028, // 瀨 (117-17, 0xFB50) CJK UNIFIED IDEOGRAPH-7028
+    0x7085, // 炅 (117-18, 0xFB51) CJK UNIFIED IDEOGRAPH-7085
+    0x70AB, // 炫 (117-19, 0xFB52) CJK UNIFIED IDEOGRAPH-70AB
+    0x710F, // 焏 (117-20, 0xFB53) CJK UNIFIED IDEOGRAPH-710F
+    0x7104, // 焄 (117-21, 0xFB54) CJK UNIFIED IDEOGRAPH-7104
+    0x715C, // 煜 (117-22, 0xFB55) CJK UNIFIED IDEOGRAPH-715C
+    0x7146, // 煆 (117-23, 0xFB56) CJK UNIFIED IDEOGRAPH-7146
+    0x7147, // 煇 (117-24, 0xFB57) CJK UNIFIED IDEOGRAPH-7147
+    0xFA15, // 凞 (117-25, 0xFB58) CJK COMPATIBILITY IDEOGRAPH-FA15
+    0x71C1, // 燁 (117-26, 0xFB59) CJK UNIFIED IDEOGRAPH-71C1
+    0x71FE, // 燾 (117-27, 0xFB5A) CJK UNIFIED IDEOGRAPH-71FE
+    0x72B1, // 犱 (117-28, 0xFB5B) CJK UNIFIED IDEOGRAPH-72B1
+    0x72BE, // 犾 (117-29, 0xFB5C) CJK UNIFIED IDEOGRAPH-72BE
+    0x7324, // 猤 (117-30, 0xFB5D) CJK UNIFIED IDEOGRAPH-7324
+    0xFA16, // 猪 (117-31, 0xFB5E) CJK COMPATIBILITY IDEOGRAPH-FA16
+    0x7377, // 獷 (117-32, 0xFB5F) CJK UNIFIED IDEOGRAPH-7377
+    0x73BD, // 玽 (117-33, 0xFB60) CJK UNIFIED IDEOGRAPH-73BD
+    0x73C9, // 珉 (117-34, 0xFB61) CJK UNIFIED IDEOGRAPH-73C9
+    0x73D6, // 珖 (117-35, 0xFB62) CJK UNIFIED IDEOGRAPH-73D6
+    0x73E3, // 珣 (117-36, 0xFB63) CJK UNIFIED IDEOGRAPH-73E3
+    0x73D2, // 珒 (117-37, 0xFB64) CJK UNIFIED IDEOGRAPH-73D2
+    0x7407, // 琇 (117-38, 0xFB65) CJK UNIFIED IDEOGRAPH-7407
+    0x73F5, // 珵 (117-39, 0xFB66) CJK UNIFIED IDEOGRAPH-73F5
+    0x7426, // 琦 (117-40, 0xFB67) CJK UNIFIED IDEOGRAPH-7426
+    0x742A, // 琪 (117-41, 0xFB68) CJK UNIFIED IDEOGRAPH-742A
+    0x7429, // 琩 (117-42, 0xFB69) CJK UNIFIED IDEOGRAPH-7429
+    0x742E, // 琮 (117-43, 0xFB6A) CJK UNIFIED IDEOGRAPH-742E
+    0x7462, // 瑢 (117-44, 0xFB6B) CJK UNIFIED IDEOGRAPH-7462
+    0x7489, // 璉 (117-45, 0xFB6C) CJK UNIFIED IDEOGRAPH-7489
+    0x749F, // 璟 (117-46, 0xFB6D) CJK UNIFIED IDEOGRAPH-749F
+    0x7501, // 甁 (117-47, 0xFB6E) CJK UNIFIED IDEOGRAPH-7501
+    0x756F, // 畯 (117-48, 0xFB6F) CJK UNIFIED IDEOGRAPH-756F
+    0x7682, // 皂 (117-49, 0xFB70) CJK UNIFIED IDEOGRAPH-7682
+    0x769C, // 皜 (117-50, 0xFB71) CJK UNIFIED IDEOGRAPH-769C
+    0x769E, // 皞 (117-51, 0xFB72) CJK UNIFIED IDEOGRAPH-769E
+    0x769B, // 皛 (117-52, 0xFB73) CJK UNIFIED IDEOGRAPH-769B
+    0x76A6, // 皦 (117-53, 0xFB74) CJK UNIFIED IDEOGRAPH-76A6
+    0xFA17, // 益 (117-54, 0xFB75) CJK COMPATIBILITY IDEOGRAPH-FA17
+    0x7746, // 睆 (117-55, 0xFB76) CJK UNIFIED IDEOGRAPH-7746
+    0x52AF, // 劯 (117-56, 0xFB77) CJK UNIFIED IDEOGRAPH-52AF
+    0x7821, // 砡 (117-57, 0xFB78) CJK UNIFIED IDEOGRAPH-7821
+    0x784E, // 硎 (117-58, 0xFB79) CJK UNIFIED IDEOGRAPH-784E
+    0x7864, // 硤 (117-59, 0xFB7A) CJK UNIFIED IDEOGRAPH-7864
+    0x787A, // 硺 (117-60, 0xFB7B) CJK UNIFIED IDEOGRAPH-787A
+    0x7930, // 礰 (117-61, 0xFB7C) CJK UNIFIED IDEOGRAPH-7930
+    0xFA18, // 礼 (117-62, 0xFB7D) CJK COMPATIBILITY IDEOGRAPH-FA18
+    0xFA19, // 神 (117-63, 0xFB7E) CJK COMPATIBILITY IDEOGRAPH-FA19
+    0xFA1A, // 祥 (117-64, 0xFB80) CJK COMPATIBILITY IDEOGRAPH-FA1A
+    0x7994, // 禔 (117-65, 0xFB81) CJK UNIFIED IDEOGRAPH-7994
+    0xFA1B, // 福 (117-66, 0xFB82) CJK COMPATIBILITY IDEOGRAPH-FA1B
+    0x799B, // 禛 (117-67, 0xFB83) CJK UNIFIED IDEOGRAPH-799B
+    0x7AD1, // 竑 (117-68, 0xFB84) CJK UNIFIED IDEOGRAPH-7AD1
+    0x7AE7, // 竧 (117-69, 0xFB85) CJK UNIFIED IDEOGRAPH-7AE7
+    0xFA1C, // 靖 (117-70, 0xFB86) CJK COMPATIBILITY IDEOGRAPH-FA1C
+    0x7AEB, // 竫 (117-71, 0xFB87) CJK UNIFIED IDEOGRAPH-7AEB
+    0x7B9E, // 箞 (117-72, 0xFB88) CJK UNIFIED IDEOGRAPH-7B9E
+    0xFA1D, // 精 (117-73, 0xFB89) CJK COMPATIBILITY IDEOGRAPH-FA1D
+    0x7D48, // 絈 (117-74, 0xFB8A) CJK UNIFIED IDEOGRAPH-7D48
+    0x7D5C, // 絜 (117-75, 0xFB8B) CJK UNIFIED IDEOGRAPH-7D5C
+    0x7DB7, // 綷 (117-76, 0xFB8C) CJK UNIFIED IDEOGRAPH-7DB7
+    0x7DA0, // 綠 (117-77, 0xFB8D) CJK UNIFIED IDEOGRAPH-7DA0
+    0x7DD6, // 緖 (117-78, 0xFB8E) CJK UNIFIED IDEOGRAPH-7DD6
+    0x7E52, // 繒 (117-79, 0xFB8F) CJK UNIFIED IDEOGRAPH-7E52
+    0x7F47, // 罇 (117-80, 0xFB90) CJK UNIFIED IDEOGRAPH-7F47
+    0x7FA1, // 羡 (117-81, 0xFB91) CJK UNIFIED IDEOGRAPH-7FA1
+    0xFA1E, // 羽 (117-82, 0xFB92) CJK COMPATIBILITY IDEOGRAPH-FA1E
+    0x8301, // 茁 (117-83, 0xFB93) CJK UNIFIED IDEOGRAPH-8301
+    0x8362, // 荢 (117-84, 0xFB94) CJK UNIFIED IDEOGRAPH-8362
+    0x837F, // 荿 (117-85, 0xFB95) CJK UNIFIED IDEOGRAPH-837F
+    0x83C7, // 菇 (117-86, 0xFB96) CJK UNIFIED IDEOGRAPH-83C7
+    0x83F6, // 菶 (117-87, 0xFB97) CJK UNIFIED IDEOGRAPH-83F6
+    0x8448, // 葈 (117-88, 0xFB98) CJK UNIFIED IDEOGRAPH-8448
+    0x84B4, // 蒴 (117-89, 0xFB99) CJK UNIFIED IDEOGRAPH-84B4
+    0x8553, // 蕓 (117-90, 0xFB9A) CJK UNIFIED IDEOGRAPH-8553
+    0x8559, // 蕙 (117-91, 0xFB9B) CJK UNIFIED IDEOGRAPH-8559
+    0x856B, // 蕫 (117-92, 0xFB9C) CJK UNIFIED IDEOGRAPH-856B
+    0xFA1F, // 﨟 (117-93, 0xFB9D) CJK COMPATIBILITY IDEOGRAPH-FA1F
+    0x85B0, // 薰 (117-94, 0xFB9E) CJK UNIFIED IDEOGRAPH-85B0
+    0xFA20, // 蘒 (118-01, 0xFB9F) CJK COMPATIBILITY IDEOGRAPH-FA20
+    0xFA21, // 﨡 (118-02, 0xFBA0) CJK COMPATIBILITY IDEOGRAPH-FA21
+    0x8807, // 蠇 (118-03, 0xFBA1) CJK UNIFIED IDEOGRAPH-8807
+    0x88F5, // 裵 (118-04, 0xFBA2) CJK UNIFIED IDEOGRAPH-88F5
+    0x8A12, // 訒 (118-05, 0xFBA3) CJK UNIFIED IDEOGRAPH-8A12
+    0x8A37, // 訷 (118-06, 0xFBA4) CJK UNIFIED IDEOGRAPH-8A37
+    0x8A79, // 詹 (118-07, 0xFBA5) CJK UNIFIED IDEOGRAPH-8A79
+    0x8AA7, // 誧 (118-08, 0xFBA6) CJK UNIFIED IDEOGRAPH-8AA7
+    0x8ABE, // 誾 (118-09, 0xFBA7) CJK UNIFIED IDEOGRAPH-8ABE
+    0x8ADF, // 諟 (118-10, 0xFBA8) CJK UNIFIED IDEOGRAPH-8ADF
+    0xFA22, // 諸 (118-11, 0xFBA9) CJK COMPATIBILITY IDEOGRAPH-FA22
+    0x8AF6, // 諶 (118-12, 0xFBAA) CJK UNIFIED IDEOGRAPH-8AF6
+    0x8B53, // 譓 (118-13, 0xFBAB) CJK UNIFIED IDEOGRAPH-8B53
+    0x8B7F, // 譿 (118-14, 0xFBAC) CJK UNIFIED IDEOGRAPH-8B7F
+    0x8CF0, // 賰 (118-15, 0xFBAD) CJK UNIFIED IDEOGRAPH-8CF0
+    0x8CF4, // 賴 (118-16, 0xFBAE) CJK UNIFIED IDEOGRAPH-8CF4
+    0x8D12, // 贒 (118-17, 0xFBAF) CJK UNIFIED IDEOGRAPH-8D12
+    0x8D76, // 赶 (118-18, 0xFBB0) CJK UNIFIED IDEOGRAPH-8D76
+    0xFA23, // 﨣 (118-19, 0xFBB1) CJK COMPATIBILITY IDEOGRAPH-FA23
+    0x8ECF, // 軏 (118-20, 0xFBB2) CJK UNIFIED IDEOGRAPH-8ECF
+    0xFA24, // 﨤 (118-21, 0xFBB3) CJK COMPATIBILITY IDEOGRAPH-FA24
+    0xFA25, // 逸 (118-22, 0xFBB4) CJK COMPATIBILITY IDEOGRAPH-FA25
+    0x9067, // 遧 (118-23, 0xFBB5) CJK UNIFIED IDEOGRAPH-9067
+    0x90DE, // 郞 (118-24, 0xFBB6) CJK UNIFIED IDEOGRAPH-90DE
+    0xFA26, // 都 (118-25, 0xFBB7) CJK COMPATIBILITY IDEOGRAPH-FA26
+    0x9115, // 鄕 (118-26, 0xFBB8) CJK UNIFIED IDEOGRAPH-9115
+    0x9127, // 鄧 (118-27, 0xFBB9) CJK UNIFIED IDEOGRAPH-9127
+    0x91DA, // 釚 (118-28, 0xFBBA) CJK UNIFIED IDEOGRAPH-91DA
+    0x91D7, // 釗 (118-29, 0xFBBB) CJK UNIFIED IDEOGRAPH-91D7
+    0x91DE, // 釞 (118-30, 0xFBBC) CJK UNIFIED IDEOGRAPH-91DE
+    0x91ED, // 釭 (118-31, 0xFBBD) CJK UNIFIED IDEOGRAPH-91ED
+    0x91EE, // 釮 (118-32, 0xFBBE) CJK UNIFIED IDEOGRAPH-91EE
+    0x91E4, // 釤 (118-33, 0xFBBF) CJK UNIFIED IDEOGRAPH-91E4
+    0x91E5, // 釥 (118-34, 0xFBC0) CJK UNIFIED IDEOGRAPH-91E5
+    0x9206, // 鈆 (118-35, 0xFBC1) CJK UNIFIED IDEOGRAPH-9206
+    0x9210, // 鈐 (118-36, 0xFBC2) CJK UNIFIED IDEOGRAPH-9210
+    0x920A, // 鈊 (118-37, 0xFBC3) CJK UNIFIED IDEOGRAPH-920A
+    0x923A, // 鈺 (118-38, 0xFBC4) CJK UNIFIED IDEOGRAPH-923A
+    0x9240, // 鉀 (118-39, 0xFBC5) CJK UNIFIED IDEOGRAPH-9240
+    0x923C, // 鈼 (118-40, 0xFBC6) CJK UNIFIED IDEOGRAPH-923C
+    0x924E, // 鉎 (118-41, 0xFBC7) CJK UNIFIED IDEOGRAPH-924E
+    0x9259, // 鉙 (118-42, 0xFBC8) CJK UNIFIED IDEOGRAPH-9259
+    0x9251, // 鉑 (118-43, 0xFBC9) CJK UNIFIED IDEOGRAPH-9251
+    0x9239, // 鈹 (118-44, 0xFBCA) CJK UNIFIED IDEOGRAPH-9239
+    0x9267, // 鉧 (118-45, 0xFBCB) CJK UNIFIED IDEOGRAPH-9267
+    0x92A7, // 銧 (118-46, 0xFBCC) CJK UNIFIED IDEOGRAPH-92A7
+    0x9277, // 鉷 (118-47, 0xFBCD) CJK UNIFIED IDEOGRAPH-9277
+    0x9278, // 鉸 (118-48, 0xFBCE) CJK UNIFIED IDEOGRAPH-9278
+    0x92E7, // 鋧 (118-49, 0xFBCF) CJK UNIFIED IDEOGRAPH-92E7
+    0x92D7, // 鋗 (118-50, 0xFBD0) CJK UNIFIED IDEOGRAPH-92D7
+    0x92D9, // 鋙 (118-51, 0xFBD1) CJK UNIFIED IDEOGRAPH-92D9
+    0x92D0, // 鋐 (118-52, 0xFBD2) CJK UNIFIED IDEOGRAPH-92D0
+    0xFA27, // 﨧 (118-53, 0xFBD3) CJK COMPATIBILITY IDEOGRAPH-FA27
+    0x92D5, // 鋕 (118-54, 0xFBD4) CJK UNIFIED IDEOGRAPH-92D5
+    0x92E0, // 鋠 (118-55, 0xFBD5) CJK UNIFIED IDEOGRAPH-92E0
+    0x92D3, // 鋓 (118-56, 0xFBD6) CJK UNIFIED IDEOGRAPH-92D3
+    0x9325, // 錥 (118-57, 0xFBD7) CJK UNIFIED IDEOGRAPH-9325
+    0x9321, // 錡 (118-58, 0xFBD8) CJK UNIFIED IDEOGRAPH-9321
+    0x92FB, // 鋻 (118-59, 0xFBD9) CJK UNIFIED IDEOGRAPH-92FB
+    0xFA28, // 﨨 (118-60, 0xFBDA) CJK COMPATIBILITY IDEOGRAPH-FA28
+    0x931E, // 錞 (118-61, 0xFBDB) CJK UNIFIED IDEOGRAPH-931E
+    0x92FF, // 鋿 (118-62, 0xFBDC) CJK UNIFIED IDEOGRAPH-92FF
+    0x931D, // 錝 (118-63, 0xFBDD) CJK UNIFIED IDEOGRAPH-931D
+    0x9302, // 錂 (118-64, 0xFBDE) CJK UNIFIED IDEOGRAPH-9302
+    0x9370, // 鍰 (118-65, 0xFBDF) CJK UNIFIED IDEOGRAPH-9370
+    0x9357, // 鍗 (118-66, 0xFBE0) CJK UNIFIED IDEOGRAPH-9357
+    0x93A4, // 鎤 (118-67, 0xFBE1) CJK UNIFIED IDEOGRAPH-93A4
+    0x93C6, // 鏆 (118-68, 0xFBE2) CJK UNIFIED IDEOGRAPH-93C6
+    0x93DE, // 鏞 (118-69, 0xFBE3) CJK UNIFIED IDEOGRAPH-93DE
+    0x93F8, // 鏸 (118-70, 0xFBE4) CJK UNIFIED IDEOGRAPH-93F8
+    0x9431, // 鐱 (118-71, 0xFBE5) CJK UNIFIED IDEOGRAPH-9431
+    0x9445, // 鑅 (118-72, 0xFBE6) CJK UNIFIED IDEOGRAPH-9445
+    0x9448, // 鑈 (118-73, 0xFBE7) CJK UNIFIED IDEOGRAPH-9448
+    0x9592, // 閒 (118-74, 0xFBE8) CJK UNIFIED IDEOGRAPH-9592
+    0xF9DC, // 隆 (118-75, 0xFBE9) CJK COMPATIBILITY IDEOGRAPH-F9DC
+    0xFA29, // 﨩 (118-76, 0xFBEA) CJK COMPATIBILITY IDEOGRAPH-FA29
+    0x969D, // 隝 (118-77, 0xFBEB) CJK UNIFIED IDEOGRAPH-969D
+    0x96AF, // 隯 (118-78, 0xFBEC) CJK UNIFIED IDEOGRAPH-96AF
+    0x9733, // 霳 (118-79, 0xFBED) CJK UNIFIED IDEOGRAPH-9733
+    0x973B, // 霻 (118-80, 0xFBEE) CJK UNIFIED IDEOGRAPH-973B
+    0x9743, // 靃 (118-81, 0xFBEF) CJK UNIFIED IDEOGRAPH-9743
+    0x974D, // 靍 (118-82, 0xFBF0) CJK UNIFIED IDEOGRAPH-974D
+    0x974F, // 靏 (118-83, 0xFBF1) CJK UNIFIED IDEOGRAPH-974F
+    0x9751, // 靑 (118-84, 0xFBF2) CJK UNIFIED IDEOGRAPH-9751
+    0x9755, // 靕 (118-85, 0xFBF3) CJK UNIFIED IDEOGRAPH-9755
+    0x9857, // 顗 (118-86, 0xFBF4) CJK UNIFIED IDEOGRAPH-9857
+    0x9865, // 顥 (118-87, 0xFBF5) CJK UNIFIED IDEOGRAPH-9865
+    0xFA2A, // 飯 (118-88, 0xFBF6) CJK COMPATIBILITY IDEOGRAPH-FA2A
+    0xFA2B, // 飼 (118-89, 0xFBF7) CJK COMPATIBILITY IDEOGRAPH-FA2B
+    0x9927, // 餧 (118-90, 0xFBF8) CJK UNIFIED IDEOGRAPH-9927
+    0xFA2C, // 館 (118-91, 0xFBF9) CJK COMPATIBILITY IDEOGRAPH-FA2C
+    0x999E, // 馞 (118-92, 0xFBFA) CJK UNIFIED IDEOGRAPH-999E
+    0x9A4E, // 驎 (118-93, 0xFBFB) CJK UNIFIED IDEOGRAPH-9A4E
+    0x9AD9, // 髙 (118-94, 0xFBFC) CJK UNIFIED IDEOGRAPH-9AD9
+    0x9ADC, // 髜 (119-01, 0xFC40) CJK UNIFIED IDEOGRAPH-9ADC
+    0x9B75, // 魵 (119-02, 0xFC41) CJK UNIFIED IDEOGRAPH-9B75
+    0x9B72, // 魲 (119-03, 0xFC42) CJK UNIFIED IDEOGRAPH-9B72
+    0x9B8F, // 鮏 (119-04, 0xFC43) CJK UNIFIED IDEOGRAPH-9B8F
+    0x9BB1, // 鮱 (119-05, 0xFC44) CJK UNIFIED IDEOGRAPH-9BB1
+    0x9BBB, // 鮻 (119-06, 0xFC45) CJK UNIFIED IDEOGRAPH-9BBB
+    0x9C00, // 鰀 (119-07, 0xFC46) CJK UNIFIED IDEOGRAPH-9C00
+    0x9D70, // 鵰 (119-08, 0xFC47) CJK UNIFIED IDEOGRAPH-9D70
+    0x9D6B, // 鵫 (119-09, 0xFC48) CJK UNIFIED IDEOGRAPH-9D6B
+    0xFA2D, // 鶴 (119-10, 0xFC49) CJK COMPATIBILITY IDEOGRAPH-FA2D
+    0x9E19, // 鸙 (119-11, 0xFC4A) CJK UNIFIED IDEOGRAPH-9E19
+    0x9ED1, // 黑 (119-12, 0xFC4B) CJK UNIFIED IDEOGRAPH-9ED1
+];
+
+/// Number of entries in [`IBM_EXTENSIONS`].
+pub const IBM_EXTENSIONS_COUNT: usize = 388;
+const _: () = assert!(IBM_EXTENSIONS.len() == IBM_EXTENSIONS_COUNT);
+
+/// [`IBM_EXTENSIONS`] as `char`s, converted at compile time.
+pub const IBM_EXTENSIONS_CHARS: &[char] = &super::to_chars::<IBM_EXTENSIONS_COUNT>(IBM_EXTENSIONS);
@@ -106,6 +106,13 @@ pub const LATIN_LETTERS: &[u32] = &[
     0x203E, // ‾
 ];
 
+/// Number of entries in [`LATIN_LETTERS`].
+pub const LATIN_LETTERS_COUNT: usize = 95;
+const _: () = assert!(LATIN_LETTERS.len() == LATIN_LETTERS_COUNT);
+
+/// [`LATIN_LETTERS`] as `char`s, converted at compile time.
+pub const LATIN_LETTERS_CHARS: &[char] = &super::to_chars::<LATIN_LETTERS_COUNT>(LATIN_LETTERS);
+
 /// JIS X 0201 Katakana (halfwidth kana)
 ///
 /// Includes all halfwidth katakana characters from 0xFF61 to 0xFF9F:
@@ -176,3 +183,145 @@ pub const KATAKANA: &[u32] = &[
     0xFF9E, // ﾞ
     0xFF9F, // ﾟ
 ];
+
+/// Number of entries in [`KATAKANA`].
+pub const KATAKANA_COUNT: usize = 63;
+const _: () = assert!(KATAKANA.len() == KATAKANA_COUNT);
+
+/// [`KATAKANA`] as `char`s, converted at compile time.
+pub const KATAKANA_CHARS: &[char] = &super::to_chars::<KATAKANA_COUNT>(KATAKANA);
+
+/// Fullwidth image of each entry of [`KATAKANA`], at the same index.
+///
+/// Punctuation maps to its JIS X 0208 form (｡ → 。) and the sound marks ﾞ ﾟ
+/// map to the spacing marks ゛ ゜.  Composition with a preceding kana
+/// (ｶﾞ → ガ) is handled by [`crate::conversion`].
+pub const KATAKANA_FULLWIDTH: &[u32] = &[
+    0x3002, // ｡ → 。
+    0x300C, // ｢ → 「
+    0x300D, // ｣ → 」
+    0x3001, // ､ → 、
+    0x30FB, // ･ → ・
+    0x30F2, // ｦ → ヲ
+    0x30A1, // ｧ → ァ
+    0x30A3, // ｨ → ィ
+    0x30A5, // ｩ → ゥ
+    0x30A7, // ｪ → ェ
+    0x30A9, // ｫ → ォ
+    0x30E3, // ｬ → ャ
+    0x30E5, // ｭ → ュ
+    0x30E7, // ｮ → ョ
+    0x30C3, // ｯ → ッ
+    0x30FC, // ｰ → ー
+    0x30A2, // ｱ → ア
+    0x30A4, // ｲ → イ
+    0x30A6, // ｳ → ウ
+    0x30A8, // ｴ → エ
+    0x30AA, // ｵ → オ
+    0x30AB, // ｶ → カ
+    0x30AD, // ｷ → キ
+    0x30AF, // ｸ → ク
+    0x30B1, // ｹ → ケ
+    0x30B3, // ｺ → コ
+    0x30B5, // ｻ → サ
+    0x30B7, // ｼ → シ
+    0x30B9, // ｽ → ス
+    0x30BB, // ｾ → セ
+    0x30BD, // ｿ → ソ
+    0x30BF, // ﾀ → タ
+    0x30C1, // ﾁ → チ
+    0x30C4, // ﾂ → ツ
+    0x30C6, // ﾃ → テ
+    0x30C8, // ﾄ → ト
+    0x30CA, // ﾅ → ナ
+    0x30CB, // ﾆ → ニ
+    0x30CC, // ﾇ → ヌ
+    0x30CD, // ﾈ → ネ
+    0x30CE, // ﾉ → ノ
+    0x30CF, // ﾊ → ハ
+    0x30D2, // ﾋ → ヒ
+    0x30D5, // ﾌ → フ
+    0x30D8, // ﾍ → ヘ
+    0x30DB, // ﾎ → ホ
+    0x30DE, // ﾏ → マ
+    0x30DF, // ﾐ → ミ
+    0x30E0, // ﾑ → ム
+    0x30E1, // ﾒ → メ
+    0x30E2, // ﾓ → モ
+    0x30E4, // ﾔ → ヤ
+    0x30E6, // ﾕ → ユ
+    0x30E8, // ﾖ → ヨ
+    0x30E9, // ﾗ → ラ
+    0x30EA, // ﾘ → リ
+    0x30EB, // ﾙ → ル
+    0x30EC, // ﾚ → レ
+    0x30ED, // ﾛ → ロ
+    0x30EF, // ﾜ → ワ
+    0x30F3, // ﾝ → ン
+    0x309B, // ﾞ → ゛
+    0x309C, // ﾟ → ゜
+];
+const _: () = assert!(KATAKANA_FULLWIDTH.len() == KATAKANA_COUNT);
+
+/// Each halfwidth katakana of [`KATAKANA`] paired with its fullwidth image:
+/// [`KATAKANA`] zipped with [`KATAKANA_FULLWIDTH`] at compile time, so
+/// sorted by halfwidth code point.
+///
+/// Sound marks are paired with the spacing marks ゛ ゜; for the composed
+/// forms (ｶﾞ → ガ) see [`KATAKANA_COMPOSE`].
+pub const KATAKANA_TO_FULLWIDTH: &[(u32, u32)] =
+    &super::zip_tables::<KATAKANA_COUNT>(KATAKANA, KATAKANA_FULLWIDTH);
+
+/// Halfwidth kana and sound mark pairs with a precomposed fullwidth form,
+/// as `(base, mark, composed)`: the voiced forms (ｶﾞ → ガ, ｳﾞ → ヴ), then the
+/// semi-voiced ones (ﾊﾟ → パ).
+///
+/// Any other sound mark stays a spacing mark (see [`KATAKANA_TO_FULLWIDTH`]).
+pub const KATAKANA_COMPOSE: &[(u32, u32, u32)] = &[
+    (0xFF73, 0xFF9E, 0x30F4), // ｳﾞ → ヴ
+    (0xFF76, 0xFF9E, 0x30AC), // ｶﾞ → ガ
+    (0xFF77, 0xFF9E, 0x30AE), // ｷﾞ → ギ
+    (0xFF78, 0xFF9E, 0x30B0), // ｸﾞ → グ
+    (0xFF79, 0xFF9E, 0x30B2), // ｹﾞ → ゲ
+    (0xFF7A, 0xFF9E, 0x30B4), // ｺﾞ → ゴ
+    (0xFF7B, 0xFF9E, 0x30B6), // ｻﾞ → ザ
+    (0xFF7C, 0xFF9E, 0x30B8), // ｼﾞ → ジ
+    (0xFF7D, 0xFF9E, 0x30BA), // ｽﾞ → ズ
+    (0xFF7E, 0xFF9E, 0x30BC), // ｾﾞ → ゼ
+    (0xFF7F, 0xFF9E, 0x30BE), // ｿﾞ → ゾ
+    (0xFF80, 0xFF9E, 0x30C0), // ﾀﾞ → ダ
+    (0xFF81, 0xFF9E, 0x30C2), // ﾁﾞ → ヂ
+    (0xFF82, 0xFF9E, 0x30C5), // ﾂﾞ → ヅ
+    (0xFF83, 0xFF9E, 0x30C7), // ﾃﾞ → デ
+    (0xFF84, 0xFF9E, 0x30C9), // ﾄﾞ → ド
+    (0xFF8A, 0xFF9E, 0x30D0), // ﾊﾞ → バ
+    (0xFF8B, 0xFF9E, 0x30D3), // ﾋﾞ → ビ
+    (0xFF8C, 0xFF9E, 0x30D6), // ﾌﾞ → ブ
+    (0xFF8D, 0xFF9E, 0x30D9), // ﾍﾞ → ベ
+    (0xFF8E, 0xFF9E, 0x30DC), // ﾎﾞ → ボ
+    (0xFF8A, 0xFF9F, 0x30D1), // ﾊﾟ → パ
+    (0xFF8B, 0xFF9F, 0x30D4), // ﾋﾟ → ピ
+    (0xFF8C, 0xFF9F, 0x30D7), // ﾌﾟ → プ
+    (0xFF8D, 0xFF9F, 0x30DA), // ﾍﾟ → ペ
+    (0xFF8E, 0xFF9F, 0x30DD), // ﾎﾟ → ポ
+];
+
+/// Number of entries in [`KATAKANA_COMPOSE`].
+pub const KATAKANA_COMPOSE_COUNT: usize = 26;
+const _: () = assert!(KATAKANA_COMPOSE.len() == KATAKANA_COMPOSE_COUNT);
+
+// ── merged table ──────────────────────────────────────────────────────────────
+
+const ALL_PARTS: &[&[u32]] = &[LATIN_LETTERS, KATAKANA];
+
+/// Number of entries in [`ALL_JISX0201`].
+pub const ALL_JISX0201_COUNT: usize = super::total_len(ALL_PARTS);
+
+/// All JIS X 0201 code points, strictly ascending.
+///
+/// Exactly the union of [`LATIN_LETTERS`] and [`KATAKANA`], merged and
+/// sorted at compile time; compilation fails if the two tables overlap.
+pub const ALL_JISX0201: [u32; ALL_JISX0201_COUNT] = super::sorted_union(ALL_PARTS);
+
+/// [`ALL_JISX0201`] as `char`s, converted at compile time.
+pub const ALL_JISX0201_CHARS: &[char] = &super::to_chars::<ALL_JISX0201_COUNT>(&ALL_JISX0201);
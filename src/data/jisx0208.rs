@@ -98,6 +98,13 @@ pub const HIRAGANA: &[u32] = &[
     0x3093, // ん (04-83)
 ];
 
+/// Number of entries in [`HIRAGANA`].
+pub const HIRAGANA_COUNT: usize = 83;
+const _: () = assert!(HIRAGANA.len() == HIRAGANA_COUNT);
+
+/// [`HIRAGANA`] as `char`s, converted at compile time.
+pub const HIRAGANA_CHARS: &[char] = &super::to_chars::<HIRAGANA_COUNT>(HIRAGANA);
+
 /// JIS X 0208 Katakana (カタカナ)
 ///
 /// Contains all katakana characters from 0x30A1 to 0x30F6
@@ -190,6 +197,187 @@ pub const KATAKANA: &[u32] = &[
     0x30F6, // ヶ (05-86)
 ];
 
+/// Number of entries in [`KATAKANA`].
+pub const KATAKANA_COUNT: usize = 86;
+const _: () = assert!(KATAKANA.len() == KATAKANA_COUNT);
+
+/// [`KATAKANA`] as `char`s, converted at compile time.
+pub const KATAKANA_CHARS: &[char] = &super::to_chars::<KATAKANA_COUNT>(KATAKANA);
+
+/// Fullwidth katakana and kana punctuation paired with their JIS X 0201
+/// halfwidth form, in code-point order: one halfwidth character, or a kana
+/// and a sound mark for the voiced and semi-voiced forms (ガ → ｶﾞ).
+///
+/// The inverse of [`super::jisx0201::KATAKANA_TO_FULLWIDTH`] and
+/// [`super::jisx0201::KATAKANA_COMPOSE`].  Characters without a halfwidth form are
+/// omitted: ヮ ヰ ヱ ヵ ヶ.
+pub const KATAKANA_TO_HALFWIDTH: &[(u32, &[u32])] = &[
+    (0x3001, &[0xFF64]),         // 、 → ､
+    (0x3002, &[0xFF61]),         // 。 → ｡
+    (0x300C, &[0xFF62]),         // 「 → ｢
+    (0x300D, &[0xFF63]),         // 」 → ｣
+    (0x309B, &[0xFF9E]),         // ゛ → ﾞ
+    (0x309C, &[0xFF9F]),         // ゜ → ﾟ
+    (0x30A1, &[0xFF67]),         // ァ → ｧ
+    (0x30A2, &[0xFF71]),         // ア → ｱ
+    (0x30A3, &[0xFF68]),         // ィ → ｨ
+    (0x30A4, &[0xFF72]),         // イ → ｲ
+    (0x30A5, &[0xFF69]),         // ゥ → ｩ
+    (0x30A6, &[0xFF73]),         // ウ → ｳ
+    (0x30A7, &[0xFF6A]),         // ェ → ｪ
+    (0x30A8, &[0xFF74]),         // エ → ｴ
+    (0x30A9, &[0xFF6B]),         // ォ → ｫ
+    (0x30AA, &[0xFF75]),         // オ → ｵ
+    (0x30AB, &[0xFF76]),         // カ → ｶ
+    (0x30AC, &[0xFF76, 0xFF9E]), // ガ → ｶﾞ
+    (0x30AD, &[0xFF77]),         // キ → ｷ
+    (0x30AE, &[0xFF77, 0xFF9E]), // ギ → ｷﾞ
+    (0x30AF, &[0xFF78]),         // ク → ｸ
+    (0x30B0, &[0xFF78, 0xFF9E]), // グ → ｸﾞ
+    (0x30B1, &[0xFF79]),         // ケ → ｹ
+    (0x30B2, &[0xFF79, 0xFF9E]), // ゲ → ｹﾞ
+    (0x30B3, &[0xFF7A]),         // コ → ｺ
+    (0x30B4, &[0xFF7A, 0xFF9E]), // ゴ → ｺﾞ
+    (0x30B5, &[0xFF7B]),         // サ → ｻ
+    (0x30B6, &[0xFF7B, 0xFF9E]), // ザ → ｻﾞ
+    (0x30B7, &[0xFF7C]),         // シ → ｼ
+    (0x30B8, &[0xFF7C, 0xFF9E]), // ジ → ｼﾞ
+    (0x30B9, &[0xFF7D]),         // ス → ｽ
+    (0x30BA, &[0xFF7D, 0xFF9E]), // ズ → ｽﾞ
+    (0x30BB, &[0xFF7E]),         // セ → ｾ
+    (0x30BC, &[0xFF7E, 0xFF9E]), // ゼ → ｾﾞ
+    (0x30BD, &[0xFF7F]),         // ソ → ｿ
+    (0x30BE, &[0xFF7F, 0xFF9E]), // ゾ → ｿﾞ
+    (0x30BF, &[0xFF80]),         // タ → ﾀ
+    (0x30C0, &[0xFF80, 0xFF9E]), // ダ → ﾀﾞ
+    (0x30C1, &[0xFF81]),         // チ → ﾁ
+    (0x30C2, &[0xFF81, 0xFF9E]), // ヂ → ﾁﾞ
+    (0x30C3, &[0xFF6F]),         // ッ → ｯ
+    (0x30C4, &[0xFF82]),         // ツ → ﾂ
+    (0x30C5, &[0xFF82, 0xFF9E]), // ヅ → ﾂﾞ
+    (0x30C6, &[0xFF83]),         // テ → ﾃ
+    (0x30C7, &[0xFF83, 0xFF9E]), // デ → ﾃﾞ
+    (0x30C8, &[0xFF84]),         // ト → ﾄ
+    (0x30C9, &[0xFF84, 0xFF9E]), // ド → ﾄﾞ
+    (0x30CA, &[0xFF85]),         // ナ → ﾅ
+    (0x30CB, &[0xFF86]),         // ニ → ﾆ
+    (0x30CC, &[0xFF87]),         // ヌ → ﾇ
+    (0x30CD, &[0xFF88]),         // ネ → ﾈ
+    (0x30CE, &[0xFF89]),         // ノ → ﾉ
+    (0x30CF, &[0xFF8A]),         // ハ → ﾊ
+    (0x30D0, &[0xFF8A, 0xFF9E]), // バ → ﾊﾞ
+    (0x30D1, &[0xFF8A, 0xFF9F]), // パ → ﾊﾟ
+    (0x30D2, &[0xFF8B]),         // ヒ → ﾋ
+    (0x30D3, &[0xFF8B, 0xFF9E]), // ビ → ﾋﾞ
+    (0x30D4, &[0xFF8B, 0xFF9F]), // ピ → ﾋﾟ
+    (0x30D5, &[0xFF8C]),         // フ → ﾌ
+    (0x30D6, &[0xFF8C, 0xFF9E]), // ブ → ﾌﾞ
+    (0x30D7, &[0xFF8C, 0xFF9F]), // プ → ﾌﾟ
+    (0x30D8, &[0xFF8D]),         // ヘ → ﾍ
+    (0x30D9, &[0xFF8D, 0xFF9E]), // ベ → ﾍﾞ
+    (0x30DA, &[0xFF8D, 0xFF9F]), // ペ → ﾍﾟ
+    (0x30DB, &[0xFF8E]),         // ホ → ﾎ
+    (0x30DC, &[0xFF8E, 0xFF9E]), // ボ → ﾎﾞ
+    (0x30DD, &[0xFF8E, 0xFF9F]), // ポ → ﾎﾟ
+    (0x30DE, &[0xFF8F]),         // マ → ﾏ
+    (0x30DF, &[0xFF90]),         // ミ → ﾐ
+    (0x30E0, &[0xFF91]),         // ム → ﾑ
+    (0x30E1, &[0xFF92]),         // メ → ﾒ
+    (0x30E2, &[0xFF93]),         // モ → ﾓ
+    (0x30E3, &[0xFF6C]),         // ャ → ｬ
+    (0x30E4, &[0xFF94]),         // ヤ → ﾔ
+    (0x30E5, &[0xFF6D]),         // ュ → ｭ
+    (0x30E6, &[0xFF95]),         // ユ → ﾕ
+    (0x30E7, &[0xFF6E]),         // ョ → ｮ
+    (0x30E8, &[0xFF96]),         // ヨ → ﾖ
+    (0x30E9, &[0xFF97]),         // ラ → ﾗ
+    (0x30EA, &[0xFF98]),         // リ → ﾘ
+    (0x30EB, &[0xFF99]),         // ル → ﾙ
+    (0x30EC, &[0xFF9A]),         // レ → ﾚ
+    (0x30ED, &[0xFF9B]),         // ロ → ﾛ
+    (0x30EF, &[0xFF9C]),         // ワ → ﾜ
+    (0x30F2, &[0xFF66]),         // ヲ → ｦ
+    (0x30F3, &[0xFF9D]),         // ン → ﾝ
+    (0x30F4, &[0xFF73, 0xFF9E]), // ヴ → ｳﾞ
+    (0x30FB, &[0xFF65]),         // ・ → ･
+    (0x30FC, &[0xFF70]),         // ー → ｰ
+];
+
+/// Number of entries in [`KATAKANA_TO_HALFWIDTH`].
+pub const KATAKANA_TO_HALFWIDTH_COUNT: usize = 89;
+const _: () = assert!(KATAKANA_TO_HALFWIDTH.len() == KATAKANA_TO_HALFWIDTH_COUNT);
+
+/// Kana and combining sound mark pairs with a precomposed form, as
+/// `(base, mark, composed)`: the voiced forms with U+3099 (か + ◌゙ → が),
+/// then the semi-voiced ones with U+309A (は + ◌゚ → ぱ), each in base order.
+///
+/// These are Unicode's canonical compositions.  ゔ ヷ ヸ ヹ ヺ are JIS X 0213
+/// only; for the halfwidth forms see [`super::jisx0201::KATAKANA_COMPOSE`].
+pub const KANA_COMPOSE: &[(u32, u32, u32)] = &[
+    (0x3046, 0x3099, 0x3094), // う゛ → ゔ
+    (0x304B, 0x3099, 0x304C), // か゛ → が
+    (0x304D, 0x3099, 0x304E), // き゛ → ぎ
+    (0x304F, 0x3099, 0x3050), // く゛ → ぐ
+    (0x3051, 0x3099, 0x3052), // け゛ → げ
+    (0x3053, 0x3099, 0x3054), // こ゛ → ご
+    (0x3055, 0x3099, 0x3056), // さ゛ → ざ
+    (0x3057, 0x3099, 0x3058), // し゛ → じ
+    (0x3059, 0x3099, 0x305A), // す゛ → ず
+    (0x305B, 0x3099, 0x305C), // せ゛ → ぜ
+    (0x305D, 0x3099, 0x305E), // そ゛ → ぞ
+    (0x305F, 0x3099, 0x3060), // た゛ → だ
+    (0x3061, 0x3099, 0x3062), // ち゛ → ぢ
+    (0x3064, 0x3099, 0x3065), // つ゛ → づ
+    (0x3066, 0x3099, 0x3067), // て゛ → で
+    (0x3068, 0x3099, 0x3069), // と゛ → ど
+    (0x306F, 0x3099, 0x3070), // は゛ → ば
+    (0x3072, 0x3099, 0x3073), // ひ゛ → び
+    (0x3075, 0x3099, 0x3076), // ふ゛ → ぶ
+    (0x3078, 0x3099, 0x3079), // へ゛ → べ
+    (0x307B, 0x3099, 0x307C), // ほ゛ → ぼ
+    (0x309D, 0x3099, 0x309E), // ゝ゛ → ゞ
+    (0x30A6, 0x3099, 0x30F4), // ウ゛ → ヴ
+    (0x30AB, 0x3099, 0x30AC), // カ゛ → ガ
+    (0x30AD, 0x3099, 0x30AE), // キ゛ → ギ
+    (0x30AF, 0x3099, 0x30B0), // ク゛ → グ
+    (0x30B1, 0x3099, 0x30B2), // ケ゛ → ゲ
+    (0x30B3, 0x3099, 0x30B4), // コ゛ → ゴ
+    (0x30B5, 0x3099, 0x30B6), // サ゛ → ザ
+    (0x30B7, 0x3099, 0x30B8), // シ゛ → ジ
+    (0x30B9, 0x3099, 0x30BA), // ス゛ → ズ
+    (0x30BB, 0x3099, 0x30BC), // セ゛ → ゼ
+    (0x30BD, 0x3099, 0x30BE), // ソ゛ → ゾ
+    (0x30BF, 0x3099, 0x30C0), // タ゛ → ダ
+    (0x30C1, 0x3099, 0x30C2), // チ゛ → ヂ
+    (0x30C4, 0x3099, 0x30C5), // ツ゛ → ヅ
+    (0x30C6, 0x3099, 0x30C7), // テ゛ → デ
+    (0x30C8, 0x3099, 0x30C9), // ト゛ → ド
+    (0x30CF, 0x3099, 0x30D0), // ハ゛ → バ
+    (0x30D2, 0x3099, 0x30D3), // ヒ゛ → ビ
+    (0x30D5, 0x3099, 0x30D6), // フ゛ → ブ
+    (0x30D8, 0x3099, 0x30D9), // ヘ゛ → ベ
+    (0x30DB, 0x3099, 0x30DC), // ホ゛ → ボ
+    (0x30EF, 0x3099, 0x30F7), // ワ゛ → ヷ
+    (0x30F0, 0x3099, 0x30F8), // ヰ゛ → ヸ
+    (0x30F1, 0x3099, 0x30F9), // ヱ゛ → ヹ
+    (0x30F2, 0x3099, 0x30FA), // ヲ゛ → ヺ
+    (0x30FD, 0x3099, 0x30FE), // ヽ゛ → ヾ
+    (0x306F, 0x309A, 0x3071), // は゜ → ぱ
+    (0x3072, 0x309A, 0x3074), // ひ゜ → ぴ
+    (0x3075, 0x309A, 0x3077), // ふ゜ → ぷ
+    (0x3078, 0x309A, 0x307A), // へ゜ → ぺ
+    (0x307B, 0x309A, 0x307D), // ほ゜ → ぽ
+    (0x30CF, 0x309A, 0x30D1), // ハ゜ → パ
+    (0x30D2, 0x309A, 0x30D4), // ヒ゜ → ピ
+    (0x30D5, 0x309A, 0x30D7), // フ゜ → プ
+    (0x30D8, 0x309A, 0x30DA), // ヘ゜ → ペ
+    (0x30DB, 0x309A, 0x30DD), // ホ゜ → ポ
+];
+
+/// Number of entries in [`KANA_COMPOSE`].
+pub const KANA_COMPOSE_COUNT: usize = 58;
+const _: () = assert!(KANA_COMPOSE.len() == KANA_COMPOSE_COUNT);
+
 /// JIS X 0208 Latin letters (fullwidth)
 ///
 /// Contains fullwidth Latin letters and digits
@@ -258,6 +446,13 @@ pub const LATIN_LETTERS: &[u32] = &[
     0xFF5A, // ｚ (03-90)
 ];
 
+/// Number of entries in [`LATIN_LETTERS`].
+pub const LATIN_LETTERS_COUNT: usize = 62;
+const _: () = assert!(LATIN_LETTERS.len() == LATIN_LETTERS_COUNT);
+
+/// [`LATIN_LETTERS`] as `char`s, converted at compile time.
+pub const LATIN_LETTERS_CHARS: &[char] = &super::to_chars::<LATIN_LETTERS_COUNT>(LATIN_LETTERS);
+
 /// JIS X 0208 Greek letters
 ///
 /// Contains uppercase and lowercase Greek letters
@@ -312,6 +507,13 @@ pub const GREEK_LETTERS: &[u32] = &[
     0x03C9, // ω (06-56)
 ];
 
+/// Number of entries in [`GREEK_LETTERS`].
+pub const GREEK_LETTERS_COUNT: usize = 48;
+const _: () = assert!(GREEK_LETTERS.len() == GREEK_LETTERS_COUNT);
+
+/// [`GREEK_LETTERS`] as `char`s, converted at compile time.
+pub const GREEK_LETTERS_CHARS: &[char] = &super::to_chars::<GREEK_LETTERS_COUNT>(GREEK_LETTERS);
+
 /// JIS X 0208 Cyrillic letters
 ///
 /// Contains uppercase and lowercase Cyrillic letters
@@ -384,9 +586,19 @@ pub const CYRILLIC_LETTERS: &[u32] = &[
     0x044F, // я (07-81)
 ];
 
+/// Number of entries in [`CYRILLIC_LETTERS`].
+pub const CYRILLIC_LETTERS_COUNT: usize = 66;
+const _: () = assert!(CYRILLIC_LETTERS.len() == CYRILLIC_LETTERS_COUNT);
+
+/// [`CYRILLIC_LETTERS`] as `char`s, converted at compile time.
+pub const CYRILLIC_LETTERS_CHARS: &[char] =
+    &super::to_chars::<CYRILLIC_LETTERS_COUNT>(CYRILLIC_LETTERS);
+
 /// JIS X 0208 Special characters
 ///
-/// Contains various special symbols, punctuation, and mathematical symbols
+/// Rows 1 and 2 in kuten order: punctuation, symbols, arrows and
+/// mathematical symbols.  Row 1 is full (94 cells); row 2 has 53 assigned
+/// cells.
 pub const SPECIAL_CHARS: &[u32] = &[
     0x3000, // 　 (01-01)
     0x3001, // 、 (01-02)
@@ -482,95 +694,288 @@ pub const SPECIAL_CHARS: &[u32] = &[
     0x25CF, // ● (01-92)
     0x25CE, // ◎ (01-93)
     0x25C7, // ◇ (01-94)
-    0x25C6, // ◆ (01-95)
-    0x25A1, // □ (01-96)
-    0x25A0, // ■ (01-97)
-    0x25B3, // △ (01-98)
-    0x25B2, // ▲ (01-99)
-    0x25BD, // ▽ (01-100)
-    0x25BC, // ▼ (01-101)
-    0x203B, // ※ (01-102)
-    0x3012, // 〒 (01-103)
-    0x2192, // → (01-104)
-    0x2190, // ← (01-105)
-    0x2191, // ↑ (01-106)
-    0x2193, // ↓ (01-107)
-    0x3013, // 〓 (01-108)
-    0x2208, // ∈ (01-109)
-    0x220B, // ∋ (01-110)
-    0x2286, // ⊆ (01-111)
-    0x2287, // ⊇ (01-112)
-    0x2282, // ⊂ (01-113)
-    0x2283, // ⊃ (01-114)
-    0x222A, // ∪ (01-115)
-    0x2229, // ∩ (01-116)
-    0x2227, // ∧ (01-117)
-    0x2228, // ∨ (01-118)
-    0x00AC, // ¬ (01-119)
-    0x21D2, // ⇒ (01-120)
-    0x21D4, // ⇔ (01-121)
-    0x2200, // ∀ (01-122)
-    0x2203, // ∃ (01-123)
-    0x2220, // ∠ (01-124)
-    0x22A5, // ⊥ (01-125)
-    0x2312, // ⌒ (01-126)
-    0x2202, // ∂ (01-127)
-    0x2207, // ∇ (01-128)
-    0x2261, // ≡ (01-129)
-    0x2252, // ≒ (01-130)
-    0x226A, // ≪ (01-131)
-    0x226B, // ≫ (01-132)
-    0x221A, // √ (01-133)
-    0x223D, // ∽ (01-134)
-    0x221D, // ∝ (01-135)
-    0x2235, // ∵ (01-136)
-    0x222B, // ∫ (01-137)
-    0x222C, // ∬ (01-138)
-    0x212B, // Å (01-139)
-    0x2030, // ‰ (01-140)
-    0x266F, // ♯ (01-141)
-    0x266D, // ♭ (01-142)
-    0x266A, // ♪ (01-143)
-    0x2020, // † (01-144)
-    0x2021, // ‡ (01-145)
-    0x00B6, // ¶ (01-146)
-    0x25EF, // ◯ (01-147)
+    // Row 2: cells 15–25, 34–41, 49–59, 75–81 and 90–93 are unassigned.
+    0x25C6, // ◆ (02-01)
+    0x25A1, // □ (02-02)
+    0x25A0, // ■ (02-03)
+    0x25B3, // △ (02-04)
+    0x25B2, // ▲ (02-05)
+    0x25BD, // ▽ (02-06)
+    0x25BC, // ▼ (02-07)
+    0x203B, // ※ (02-08)
+    0x3012, // 〒 (02-09)
+    0x2192, // → (02-10)
+    0x2190, // ← (02-11)
+    0x2191, // ↑ (02-12)
+    0x2193, // ↓ (02-13)
+    0x3013, // 〓 (02-14)
+    0x2208, // ∈ (02-26)
+    0x220B, // ∋ (02-27)
+    0x2286, // ⊆ (02-28)
+    0x2287, // ⊇ (02-29)
+    0x2282, // ⊂ (02-30)
+    0x2283, // ⊃ (02-31)
+    0x222A, // ∪ (02-32)
+    0x2229, // ∩ (02-33)
+    0x2227, // ∧ (02-42)
+    0x2228, // ∨ (02-43)
+    0x00AC, // ¬ (02-44)
+    0x21D2, // ⇒ (02-45)
+    0x21D4, // ⇔ (02-46)
+    0x2200, // ∀ (02-47)
+    0x2203, // ∃ (02-48)
+    0x2220, // ∠ (02-60)
+    0x22A5, // ⊥ (02-61)
+    0x2312, // ⌒ (02-62)
+    0x2202, // ∂ (02-63)
+    0x2207, // ∇ (02-64)
+    0x2261, // ≡ (02-65)
+    0x2252, // ≒ (02-66)
+    0x226A, // ≪ (02-67)
+    0x226B, // ≫ (02-68)
+    0x221A, // √ (02-69)
+    0x223D, // ∽ (02-70)
+    0x221D, // ∝ (02-71)
+    0x2235, // ∵ (02-72)
+    0x222B, // ∫ (02-73)
+    0x222C, // ∬ (02-74)
+    0x212B, // Å (02-82)
+    0x2030, // ‰ (02-83)
+    0x266F, // ♯ (02-84)
+    0x266D, // ♭ (02-85)
+    0x266A, // ♪ (02-86)
+    0x2020, // † (02-87)
+    0x2021, // ‡ (02-88)
+    0x00B6, // ¶ (02-89)
+    0x25EF, // ◯ (02-94)
 ];
 
+/// Number of entries in [`SPECIAL_CHARS`].
+pub const SPECIAL_CHARS_COUNT: usize = 147;
+const _: () = assert!(SPECIAL_CHARS.len() == SPECIAL_CHARS_COUNT);
+
+/// Number of entries of [`SPECIAL_CHARS`] in row 1 (the first entries).
+pub const SPECIAL_CHARS_ROW1_COUNT: usize = 94;
+
+/// Number of entries of [`SPECIAL_CHARS`] in row 2 (the remaining entries).
+pub const SPECIAL_CHARS_ROW2_COUNT: usize = 53;
+const _: () = assert!(SPECIAL_CHARS_ROW1_COUNT + SPECIAL_CHARS_ROW2_COUNT == SPECIAL_CHARS_COUNT);
+
+/// [`SPECIAL_CHARS`] as `char`s, converted at compile time.
+pub const SPECIAL_CHARS_AS_CHARS: &[char] = &super::to_chars::<SPECIAL_CHARS_COUNT>(SPECIAL_CHARS);
+
 /// JIS X 0208 Box drawing characters
 ///
 /// Contains various box drawing and line characters
 pub const BOX_DRAWING_CHARS: &[u32] = &[
-    0x2500, // ─ (02-01)
-    0x2502, // │ (02-02)
-    0x250C, // ┌ (02-03)
-    0x2510, // ┐ (02-04)
-    0x2518, // ┘ (02-05)
-    0x2514, // └ (02-06)
-    0x251C, // ├ (02-07)
-    0x252C, // ┬ (02-08)
-    0x2524, // ┤ (02-09)
-    0x2534, // ┴ (02-10)
-    0x253C, // ┼ (02-11)
-    0x2501, // ━ (02-12)
-    0x2503, // ┃ (02-13)
-    0x250F, // ┏ (02-14)
-    0x2513, // ┓ (02-15)
-    0x251B, // ┛ (02-16)
-    0x2517, // ┗ (02-17)
-    0x2523, // ┣ (02-18)
-    0x2533, // ┳ (02-19)
-    0x252B, // ┫ (02-20)
-    0x253B, // ┻ (02-21)
-    0x254B, // ╋ (02-22)
-    0x2520, // ┠ (02-23)
-    0x252F, // ┯ (02-24)
-    0x2528, // ┨ (02-25)
-    0x2537, // ┷ (02-26)
-    0x253F, // ┿ (02-27)
-    0x251D, // ┝ (02-28)
-    0x2530, // ┰ (02-29)
-    0x2525, // ┥ (02-30)
-    0x2538, // ┸ (02-31)
-    0x2542, // ╂ (02-32)
+    0x2500, // ─ (08-01)
+    0x2502, // │ (08-02)
+    0x250C, // ┌ (08-03)
+    0x2510, // ┐ (08-04)
+    0x2518, // ┘ (08-05)
+    0x2514, // └ (08-06)
+    0x251C, // ├ (08-07)
+    0x252C, // ┬ (08-08)
+    0x2524, // ┤ (08-09)
+    0x2534, // ┴ (08-10)
+    0x253C, // ┼ (08-11)
+    0x2501, // ━ (08-12)
+    0x2503, // ┃ (08-13)
+    0x250F, // ┏ (08-14)
+    0x2513, // ┓ (08-15)
+    0x251B, // ┛ (08-16)
+    0x2517, // ┗ (08-17)
+    0x2523, // ┣ (08-18)
+    0x2533, // ┳ (08-19)
+    0x252B, // ┫ (08-20)
+    0x253B, // ┻ (08-21)
+    0x254B, // ╋ (08-22)
+    0x2520, // ┠ (08-23)
+    0x252F, // ┯ (08-24)
+    0x2528, // ┨ (08-25)
+    0x2537, // ┷ (08-26)
+    0x253F, // ┿ (08-27)
+    0x251D, // ┝ (08-28)
+    0x2530, // ┰ (08-29)
+    0x2525, // ┥ (08-30)
+    0x2538, // ┸ (08-31)
+    0x2542, // ╂ (08-32)
+];
+
+/// Number of entries in [`BOX_DRAWING_CHARS`].
+pub const BOX_DRAWING_CHARS_COUNT: usize = 32;
+const _: () = assert!(BOX_DRAWING_CHARS.len() == BOX_DRAWING_CHARS_COUNT);
+
+/// [`BOX_DRAWING_CHARS`] as `char`s, converted at compile time.
+pub const BOX_DRAWING_CHARS_AS_CHARS: &[char] =
+    &super::to_chars::<BOX_DRAWING_CHARS_COUNT>(BOX_DRAWING_CHARS);
+
+// ── merged tables ─────────────────────────────────────────────────────────────
+
+/// Every sub-table above, in JIS row order.
+const ALL_PARTS: &[&[u32]] = &[
+    SPECIAL_CHARS,
+    LATIN_LETTERS,
+    HIRAGANA,
+    KATAKANA,
+    GREEK_LETTERS,
+    CYRILLIC_LETTERS,
+    BOX_DRAWING_CHARS,
+];
+
+/// Number of entries in [`ALL_JISX0208`].
+pub const ALL_JISX0208_COUNT: usize = super::total_len(ALL_PARTS);
+
+/// All JIS X 0208 non-kanji code points, strictly ascending.
+///
+/// Exactly the union of [`SPECIAL_CHARS`], [`LATIN_LETTERS`], [`HIRAGANA`],
+/// [`KATAKANA`], [`GREEK_LETTERS`], [`CYRILLIC_LETTERS`] and
+/// [`BOX_DRAWING_CHARS`], merged and sorted at compile time.  The sub-tables
+/// are disjoint; compilation fails if two of them share a code point, so the
+/// table never contains duplicates.
+pub const ALL_JISX0208: [u32; ALL_JISX0208_COUNT] = super::sorted_union(ALL_PARTS);
+
+/// [`ALL_JISX0208`] as `char`s, converted at compile time.
+pub const ALL_JISX0208_CHARS: &[char] = &super::to_chars::<ALL_JISX0208_COUNT>(&ALL_JISX0208);
+
+/// Kuten positions of the sub-tables, which are stored in kuten order.
+///
+/// Each `(ku, first ten, len)` run covers the next `len` entries of
+/// [`SPECIAL_CHARS`], [`LATIN_LETTERS`], [`HIRAGANA`], [`KATAKANA`],
+/// [`GREEK_LETTERS`], [`CYRILLIC_LETTERS`] and [`BOX_DRAWING_CHARS`] taken in
+/// that order.  Unassigned cells fall between runs.
+pub const KUTEN_RUNS: &[(u8, u8, usize)] = &[
+    (1, 1, 94),
+    (2, 1, 14),  // ◆ .. 〓
+    (2, 26, 8),  // ∈ .. ∩
+    (2, 42, 7),  // ∧ .. ∃
+    (2, 60, 15), // ∠ .. ∬
+    (2, 82, 8),  // Å .. ¶
+    (2, 94, 1),  // ◯
+    (3, 16, 10), // ０ .. ９
+    (3, 33, 26), // Ａ .. Ｚ
+    (3, 65, 26), // ａ .. ｚ
+    (4, 1, 83),
+    (5, 1, 86),
+    (6, 1, 24),  // Α .. Ω
+    (6, 33, 24), // α .. ω
+    (7, 1, 33),  // А .. Я
+    (7, 49, 33), // а .. я
+    (8, 1, 32),
 ];
+
+#[allow(clippy::indexing_slicing)] // compile-time only
+const _: () = {
+    let mut len = 0;
+    let mut i = 0;
+    while i < KUTEN_RUNS.len() {
+        let (_, ten, run) = KUTEN_RUNS[i];
+        assert!(ten >= 1 && ten as usize + run - 1 <= 94);
+        len += run;
+        i += 1;
+    }
+    assert!(len == ALL_JISX0208_COUNT);
+};
+
+/// Every JIS X 0208 character with its row (区) and cell (点), in kuten
+/// order: rows 1–8, then the kanji of rows 16–84.
+pub(crate) fn kuten_order() -> impl Iterator<Item = (u8, u8, u32)> {
+    let cells = KUTEN_RUNS
+        .iter()
+        .flat_map(|&(ku, ten, len)| (0..len as u8).map(move |i| (ku, ten + i)));
+    let non_kanji = cells
+        .zip(ALL_PARTS.iter().flat_map(|part| part.iter()))
+        .map(|((ku, ten), &cp)| (ku, ten, cp));
+    let kanji = super::jisx0208kanji::JISX0208_CHARS
+        .iter()
+        .enumerate()
+        .map(|(i, &cp)| {
+            let (ku, ten) = super::jisx0208kanji::kuten_at(i);
+            (ku, ten, cp)
+        });
+    non_kanji.chain(kanji)
+}
+
+/// Returns the code point at row `ku`, cell `ten`, or `None` if the cell is
+/// unassigned; the inverse of [`kuten_of`].
+#[cfg(any(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+pub(crate) fn at_kuten(ku: u8, ten: u8) -> Option<u32> {
+    use super::jisx0208kanji::{kuten_at, FIRST_ROW, JISX0208_CHARS, ROW_47_LEN};
+
+    if !(1..=94).contains(&ten) {
+        return None;
+    }
+    if ku < FIRST_ROW {
+        let mut index = 0;
+        for &(run_ku, first, len) in KUTEN_RUNS {
+            if run_ku == ku && ten >= first && usize::from(ten - first) < len {
+                let index = index + usize::from(ten - first);
+                return ALL_PARTS
+                    .iter()
+                    .flat_map(|part| part.iter())
+                    .nth(index)
+                    .copied();
+            }
+            index += len;
+        }
+        return None;
+    }
+    let rows_before = usize::from(ku - FIRST_ROW);
+    let index = if ku <= 47 {
+        rows_before * 94
+    } else {
+        (rows_before - 1) * 94 + ROW_47_LEN
+    } + usize::from(ten - 1);
+    // A cell past the end of row 47 or 84 maps to another cell, or nowhere.
+    let &cp = JISX0208_CHARS.get(index)?;
+    (kuten_at(index) == (ku, ten)).then_some(cp)
+}
+
+/// Returns the row (区) and cell (点) of `cp`, or `None` if it is not a JIS
+/// X 0208 character.
+///
+/// The one code point → kuten index of the crate, built from
+/// [`kuten_order`] on first use; the kanji lookups and
+/// `CodePoints::sort_key_kuten` share it.
+pub(crate) fn kuten_of(cp: u32) -> Option<(u8, u8)> {
+    static INDEX: std::sync::OnceLock<std::collections::HashMap<u32, (u8, u8)>> =
+        std::sync::OnceLock::new();
+    INDEX
+        .get_or_init(|| kuten_order().map(|(ku, ten, cp)| (cp, (ku, ten))).collect())
+        .get(&cp)
+        .copied()
+}
+
+/// [`ALL_PARTS`] followed by the JIS X 0208 kanji.
+#[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+const ALL_WITH_KANJI_PARTS: &[&[u32]] = &[
+    SPECIAL_CHARS,
+    LATIN_LETTERS,
+    HIRAGANA,
+    KATAKANA,
+    GREEK_LETTERS,
+    CYRILLIC_LETTERS,
+    BOX_DRAWING_CHARS,
+    super::jisx0208kanji::JISX0208_CHARS,
+];
+
+/// Number of entries in [`ALL_JISX0208_WITH_KANJI`].
+#[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+pub const ALL_JISX0208_WITH_KANJI_COUNT: usize = super::total_len(ALL_WITH_KANJI_PARTS);
+
+/// The whole JIS X 0208 repertoire, strictly ascending: [`ALL_JISX0208`]
+/// plus every kanji of
+/// [`JISX0208_CHARS`](super::jisx0208kanji::JISX0208_CHARS).
+///
+/// Merged and sorted at compile time with the same no-duplicates check as
+/// [`ALL_JISX0208`].  Only generated when both the `codepoints-jisx0208` and
+/// `codepoints-jisx0208kanji` features are enabled, so other builds do not
+/// pay for sorting the kanji table.
+#[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+pub static ALL_JISX0208_WITH_KANJI: [u32; ALL_JISX0208_WITH_KANJI_COUNT] =
+    super::sorted_union(ALL_WITH_KANJI_PARTS);
+
+/// [`ALL_JISX0208_WITH_KANJI`] as `char`s, converted at compile time.
+#[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+pub const ALL_JISX0208_WITH_KANJI_CHARS: &[char] =
+    &super::to_chars::<ALL_JISX0208_WITH_KANJI_COUNT>(&ALL_JISX0208_WITH_KANJI);
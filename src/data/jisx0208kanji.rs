@@ -6362,3 +6362,34 @@ pub const JISX0208_CHARS: &[u32] = &[
     0x51DC, // 凜 (84-05)
     0x7199, // 熙 (84-06)
 ];
+
+/// Number of entries in [`JISX0208_CHARS`].
+pub const JISX0208_KANJI_COUNT: usize = 6355;
+const _: () = assert!(JISX0208_CHARS.len() == JISX0208_KANJI_COUNT);
+
+/// [`JISX0208_CHARS`] as `char`s, converted at compile time.
+pub const JISX0208_KANJI_CHARS: &[char] = &super::to_chars::<JISX0208_KANJI_COUNT>(JISX0208_CHARS);
+
+/// Kanji added by JIS X 0208:1997 over JIS X 0208:1990: none.  The 1997
+/// edition documented the unification rules without changing the
+/// repertoire; [`JISX0208_CHARS`] is valid for both.  (凜 and 熙, 84-05 and
+/// 84-06, were added by the 1990 edition.)
+pub const JISX0208_1997_ADDITIONS: &[u32] = &[];
+
+/// First kanji row.
+pub(crate) const FIRST_ROW: u8 = 16;
+/// Row 47, the last Level 1 row, is only partially filled.
+pub(crate) const ROW_47_LEN: usize = 51;
+
+/// Returns the row (区) and cell (点) of the kanji at `index` in
+/// [`JISX0208_CHARS`].  Every row has 94 kanji except rows 47 and 84.
+pub(crate) const fn kuten_at(index: usize) -> (u8, u8) {
+    let level1_len = (47 - FIRST_ROW as usize) * 94 + ROW_47_LEN;
+    let (ku, ten) = if index < level1_len {
+        (FIRST_ROW as usize + index / 94, index % 94)
+    } else {
+        let i = index - level1_len;
+        (48 + i / 94, i % 94)
+    };
+    (ku as u8, ten as u8 + 1)
+}
@@ -0,0 +1,6375 @@
+//! Stroke counts and radicals of the JIS X 0208 kanji.
+//!
+//! Derived from the Unicode Han Database (Unihan 13.0,
+//! `Unihan_RadicalStrokeCounts.txt`, © Unicode, Inc., Unicode License):
+//!
+//! * **strokes** — the first `C` entry of `kRSAdobe_Japan1_6`, radical
+//!   strokes plus residual strokes.  Adobe-Japan1 follows Japanese glyph
+//!   forms, so 込 counts 5 and 花 counts 7.
+//! * **radical** — the `kRSKangXi` radical number, 1–214.
+
+use super::jisx0208kanji::JISX0208_KANJI_COUNT;
+
+/// `(strokes, radical)` for each entry of
+/// [`JISX0208_CHARS`](super::jisx0208kanji::JISX0208_CHARS), in the same
+/// (kuten) order.
+pub const JISX0208_KANJI_METADATA: &[(u8, u8)] = &[
+    (7, 7),    // 亜 (16-01)
+    (10, 30),  // 唖 (16-02)
+    (9, 38),   // 娃 (16-03)
+    (8, 170),  // 阿 (16-04)
+    (9, 30),   // 哀 (16-05)
+    (13, 61),  // 愛 (16-06)
+    (10, 64),  // 挨 (16-07)
+    (9, 38),   // 姶 (16-08)
+    (10, 162), // 逢 (16-09)
+    (12, 140), // 葵 (16-10)
+    (9, 140),  // 茜 (16-11)
+    (16, 115), // 穐 (16-12)
+    (11, 61),  // 悪 (16-13)
+    (12, 64),  // 握 (16-14)
+    (12, 85),  // 渥 (16-15)
+    (6, 72),   // 旭 (16-16)
+    (13, 140), // 葦 (16-17)
+    (7, 140),  // 芦 (16-18)
+    (19, 195), // 鯵 (16-19)
+    (11, 75),  // 梓 (16-20)
+    (5, 32),   // 圧 (16-21)
+    (14, 68),  // 斡 (16-22)
+    (6, 64),   // 扱 (16-23)
+    (8, 40),   // 宛 (16-24)
+    (8, 38),   // 姐 (16-25)
+    (9, 142),  // 虻 (16-26)
+    (13, 184), // 飴 (16-27)
+    (12, 120), // 絢 (16-28)
+    (14, 120), // 綾 (16-29)
+    (16, 195), // 鮎 (16-30)
+    (8, 62),   // 或 (16-31)
+    (12, 119), // 粟 (16-32)
+    (11, 145), // 袷 (16-33)
+    (6, 40),   // 安 (16-34)
+    (11, 53),  // 庵 (16-35)
+    (9, 64),   // 按 (16-36)
+    (13, 72),  // 暗 (16-37)
+    (10, 75),  // 案 (16-38)
+    (17, 169), // 闇 (16-39)
+    (15, 177), // 鞍 (16-40)
+    (7, 75),   // 杏 (16-41)
+    (5, 9),    // 以 (16-42)
+    (6, 9),    // 伊 (16-43)
+    (7, 9),    // 位 (16-44)
+    (8, 9),    // 依 (16-45)
+    (12, 9),   // 偉 (16-46)
+    (7, 31),   // 囲 (16-47)
+    (6, 37),   // 夷 (16-48)
+    (8, 38),   // 委 (16-49)
+    (9, 38),   // 威 (16-50)
+    (11, 41),  // 尉 (16-51)
+    (11, 61),  // 惟 (16-52)
+    (13, 61),  // 意 (16-53)
+    (15, 61),  // 慰 (16-54)
+    (8, 72),   // 易 (16-55)
+    (12, 75),  // 椅 (16-56)
+    (9, 86),   // 為 (16-57)
+    (9, 102),  // 畏 (16-58)
+    (11, 102), // 異 (16-59)
+    (11, 115), // 移 (16-60)
+    (14, 120), // 維 (16-61)
+    (16, 120), // 緯 (16-62)
+    (9, 130),  // 胃 (16-63)
+    (11, 140), // 萎 (16-64)
+    (6, 145),  // 衣 (16-65)
+    (16, 149), // 謂 (16-66)
+    (13, 162), // 違 (16-67)
+    (15, 162), // 遺 (16-68)
+    (7, 23),   // 医 (16-69)
+    (4, 7),    // 井 (16-70)
+    (6, 8),    // 亥 (16-71)
+    (11, 32),  // 域 (16-72)
+    (8, 130),  // 育 (16-73)
+    (9, 163),  // 郁 (16-74)
+    (17, 112), // 磯 (16-75)
+    (1, 1),    // 一 (16-76)
+    (7, 33),   // 壱 (16-77)
+    (13, 85),  // 溢 (16-78)
+    (11, 162), // 逸 (16-79)
+    (14, 115), // 稲 (16-80)
+    (9, 140),  // 茨 (16-81)
+    (6, 140),  // 芋 (16-82)
+    (21, 195), // 鰯 (16-83)
+    (4, 10),   // 允 (16-84)
+    (6, 26),   // 印 (16-85)
+    (9, 30),   // 咽 (16-86)
+    (10, 30),  // 員 (16-87)
+    (6, 31),   // 因 (16-88)
+    (9, 38),   // 姻 (16-89)
+    (4, 57),   // 引 (16-90)
+    (12, 184), // 飲 (16-91)
+    (11, 85),  // 淫 (16-92)
+    (9, 130),  // 胤 (16-93)
+    (14, 140), // 蔭 (16-94)
+    (10, 170), // 院 (17-01)
+    (11, 170), // 陰 (17-02)
+    (14, 170), // 隠 (17-03)
+    (19, 180), // 韻 (17-04)
+    (6, 30),   // 吋 (17-05)
+    (5, 30),   // 右 (17-06)
+    (6, 40),   // 宇 (17-07)
+    (10, 86),  // 烏 (17-08)
+    (6, 124),  // 羽 (17-09)
+    (6, 162),  // 迂 (17-10)
+    (8, 173),  // 雨 (17-11)
+    (5, 26),   // 卯 (17-12)
+    (18, 196), // 鵜 (17-13)
+    (16, 116), // 窺 (17-14)
+    (4, 1),    // 丑 (17-15)
+    (13, 112), // 碓 (17-16)
+    (6, 134),  // 臼 (17-17)
+    (12, 85),  // 渦 (17-18)
+    (14, 30),  // 嘘 (17-19)
+    (10, 30),  // 唄 (17-20)
+    (25, 75),  // 欝 (17-21)
+    (14, 140), // 蔚 (17-22)
+    (22, 195), // 鰻 (17-23)
+    (9, 38),   // 姥 (17-24)
+    (12, 27),  // 厩 (17-25)
+    (10, 85),  // 浦 (17-26)
+    (6, 97),   // 瓜 (17-27)
+    (12, 169), // 閏 (17-28)
+    (15, 30),  // 噂 (17-29)
+    (4, 7),    // 云 (17-30)
+    (12, 162), // 運 (17-31)
+    (12, 173), // 雲 (17-32)
+    (9, 140),  // 荏 (17-33)
+    (14, 184), // 餌 (17-34)
+    (16, 29),  // 叡 (17-35)
+    (12, 42),  // 営 (17-36)
+    (17, 38),  // 嬰 (17-37)
+    (15, 59),  // 影 (17-38)
+    (9, 72),   // 映 (17-39)
+    (6, 73),   // 曳 (17-40)
+    (9, 75),   // 栄 (17-41)
+    (5, 85),   // 永 (17-42)
+    (8, 85),   // 泳 (17-43)
+    (9, 85),   // 洩 (17-44)
+    (12, 96),  // 瑛 (17-45)
+    (9, 108),  // 盈 (17-46)
+    (16, 115), // 穎 (17-47)
+    (16, 181), // 頴 (17-48)
+    (8, 140),  // 英 (17-49)
+    (16, 144), // 衛 (17-50)
+    (12, 149), // 詠 (17-51)
+    (15, 167), // 鋭 (17-52)
+    (11, 85),  // 液 (17-53)
+    (9, 104),  // 疫 (17-54)
+    (10, 108), // 益 (17-55)
+    (14, 187), // 駅 (17-56)
+    (10, 61),  // 悦 (17-57)
+    (15, 149), // 謁 (17-58)
+    (12, 156), // 越 (17-59)
+    (15, 169), // 閲 (17-60)
+    (14, 75),  // 榎 (17-61)
+    (14, 27),  // 厭 (17-62)
+    (4, 13),   // 円 (17-63)
+    (13, 31),  // 園 (17-64)
+    (12, 32),  // 堰 (17-65)
+    (8, 37),   // 奄 (17-66)
+    (10, 40),  // 宴 (17-67)
+    (8, 54),   // 延 (17-68)
+    (9, 61),   // 怨 (17-69)
+    (11, 64),  // 掩 (17-70)
+    (12, 64),  // 援 (17-71)
+    (8, 85),   // 沿 (17-72)
+    (14, 85),  // 演 (17-73)
+    (8, 86),   // 炎 (17-74)
+    (11, 86),  // 焔 (17-75)
+    (13, 86),  // 煙 (17-76)
+    (16, 86),  // 燕 (17-77)
+    (13, 94),  // 猿 (17-78)
+    (15, 120), // 縁 (17-79)
+    (19, 139), // 艶 (17-80)
+    (8, 140),  // 苑 (17-81)
+    (16, 140), // 薗 (17-82)
+    (13, 162), // 遠 (17-83)
+    (13, 167), // 鉛 (17-84)
+    (16, 196), // 鴛 (17-85)
+    (13, 32),  // 塩 (17-86)
+    (8, 70),   // 於 (17-87)
+    (6, 85),   // 汚 (17-88)
+    (12, 100), // 甥 (17-89)
+    (5, 17),   // 凹 (17-90)
+    (5, 37),   // 央 (17-91)
+    (12, 37),  // 奥 (17-92)
+    (8, 60),   // 往 (17-93)
+    (7, 61),   // 応 (17-94)
+    (8, 64),   // 押 (18-01)
+    (8, 72),   // 旺 (18-02)
+    (15, 75),  // 横 (18-03)
+    (8, 76),   // 欧 (18-04)
+    (8, 79),   // 殴 (18-05)
+    (4, 96),   // 王 (18-06)
+    (10, 124), // 翁 (18-07)
+    (17, 145), // 襖 (18-08)
+    (16, 196), // 鴬 (18-09)
+    (15, 196), // 鴎 (18-10)
+    (11, 201), // 黄 (18-11)
+    (8, 46),   // 岡 (18-12)
+    (7, 85),   // 沖 (18-13)
+    (10, 140), // 荻 (18-14)
+    (15, 9),   // 億 (18-15)
+    (9, 44),   // 屋 (18-16)
+    (16, 61),  // 憶 (18-17)
+    (17, 130), // 臆 (18-18)
+    (11, 75),  // 桶 (18-19)
+    (7, 93),   // 牡 (18-20)
+    (1, 5),    // 乙 (18-21)
+    (10, 9),   // 俺 (18-22)
+    (9, 26),   // 卸 (18-23)
+    (10, 61),  // 恩 (18-24)
+    (12, 85),  // 温 (18-25)
+    (16, 115), // 穏 (18-26)
+    (9, 180),  // 音 (18-27)
+    (3, 1),    // 下 (18-28)
+    (4, 21),   // 化 (18-29)
+    (6, 9),    // 仮 (18-30)
+    (7, 9),    // 何 (18-31)
+    (7, 9),    // 伽 (18-32)
+    (8, 9),    // 価 (18-33)
+    (8, 9),    // 佳 (18-34)
+    (5, 19),   // 加 (18-35)
+    (5, 30),   // 可 (18-36)
+    (14, 30),  // 嘉 (18-37)
+    (10, 35),  // 夏 (18-38)
+    (13, 38),  // 嫁 (18-39)
+    (10, 40),  // 家 (18-40)
+    (14, 40),  // 寡 (18-41)
+    (9, 115),  // 科 (18-42)
+    (13, 72),  // 暇 (18-43)
+    (8, 75),   // 果 (18-44)
+    (9, 75),   // 架 (18-45)
+    (14, 76),  // 歌 (18-46)
+    (8, 85),   // 河 (18-47)
+    (4, 86),   // 火 (18-48)
+    (9, 96),   // 珂 (18-49)
+    (13, 113), // 禍 (18-50)
+    (5, 115),  // 禾 (18-51)
+    (15, 115), // 稼 (18-52)
+    (14, 118), // 箇 (18-53)
+    (7, 140),  // 花 (18-54)
+    (8, 140),  // 苛 (18-55)
+    (8, 140),  // 茄 (18-56)
+    (10, 140), // 荷 (18-57)
+    (10, 140), // 華 (18-58)
+    (11, 140), // 菓 (18-59)
+    (15, 142), // 蝦 (18-60)
+    (15, 149), // 課 (18-61)
+    (13, 30),  // 嘩 (18-62)
+    (11, 154), // 貨 (18-63)
+    (8, 162),  // 迦 (18-64)
+    (12, 162), // 過 (18-65)
+    (17, 173), // 霞 (18-66)
+    (10, 142), // 蚊 (18-67)
+    (9, 9),    // 俄 (18-68)
+    (10, 46),  // 峨 (18-69)
+    (7, 62),   // 我 (18-70)
+    (5, 92),   // 牙 (18-71)
+    (8, 102),  // 画 (18-72)
+    (9, 131),  // 臥 (18-73)
+    (8, 140),  // 芽 (18-74)
+    (13, 142), // 蛾 (18-75)
+    (12, 154), // 賀 (18-76)
+    (13, 172), // 雅 (18-77)
+    (15, 184), // 餓 (18-78)
+    (15, 187), // 駕 (18-79)
+    (4, 9),    // 介 (18-80)
+    (6, 9),    // 会 (18-81)
+    (13, 148), // 解 (18-82)
+    (6, 31),   // 回 (18-83)
+    (13, 32),  // 塊 (18-84)
+    (16, 32),  // 壊 (18-85)
+    (9, 54),   // 廻 (18-86)
+    (7, 61),   // 快 (18-87)
+    (8, 61),   // 怪 (18-88)
+    (9, 61),   // 悔 (18-89)
+    (9, 61),   // 恢 (18-90)
+    (16, 61),  // 懐 (18-91)
+    (7, 62),   // 戒 (18-92)
+    (8, 64),   // 拐 (18-93)
+    (7, 66),   // 改 (18-94)
+    (14, 194), // 魁 (19-01)
+    (10, 72),  // 晦 (19-02)
+    (11, 75),  // 械 (19-03)
+    (9, 85),   // 海 (19-04)
+    (6, 86),   // 灰 (19-05)
+    (9, 102),  // 界 (19-06)
+    (9, 106),  // 皆 (19-07)
+    (12, 120), // 絵 (19-08)
+    (7, 140),  // 芥 (19-09)
+    (19, 142), // 蟹 (19-10)
+    (12, 169), // 開 (19-11)
+    (12, 170), // 階 (19-12)
+    (7, 154),  // 貝 (19-13)
+    (12, 16),  // 凱 (19-14)
+    (8, 19),   // 劾 (19-15)
+    (5, 36),   // 外 (19-16)
+    (9, 30),   // 咳 (19-17)
+    (10, 40),  // 害 (19-18)
+    (11, 46),  // 崖 (19-19)
+    (13, 61),  // 慨 (19-20)
+    (14, 75),  // 概 (19-21)
+    (11, 85),  // 涯 (19-22)
+    (13, 112), // 碍 (19-23)
+    (13, 140), // 蓋 (19-24)
+    (12, 144), // 街 (19-25)
+    (13, 149), // 該 (19-26)
+    (18, 167), // 鎧 (19-27)
+    (16, 188), // 骸 (19-28)
+    (10, 85),  // 浬 (19-29)
+    (20, 186), // 馨 (19-30)
+    (12, 142), // 蛙 (19-31)
+    (9, 32),   // 垣 (19-32)
+    (9, 75),   // 柿 (19-33)
+    (11, 142), // 蛎 (19-34)
+    (12, 167), // 鈎 (19-35)
+    (14, 18),  // 劃 (19-36)
+    (17, 30),  // 嚇 (19-37)
+    (6, 30),   // 各 (19-38)
+    (14, 53),  // 廓 (19-39)
+    (8, 64),   // 拡 (19-40)
+    (15, 64),  // 撹 (19-41)
+    (10, 75),  // 格 (19-42)
+    (10, 75),  // 核 (19-43)
+    (11, 79),  // 殻 (19-44)
+    (16, 94),  // 獲 (19-45)
+    (15, 112), // 確 (19-46)
+    (18, 115), // 穫 (19-47)
+    (12, 147), // 覚 (19-48)
+    (7, 148),  // 角 (19-49)
+    (14, 155), // 赫 (19-50)
+    (13, 159), // 較 (19-51)
+    (11, 163), // 郭 (19-52)
+    (14, 169), // 閣 (19-53)
+    (13, 170), // 隔 (19-54)
+    (9, 177),  // 革 (19-55)
+    (8, 39),   // 学 (19-56)
+    (8, 46),   // 岳 (19-57)
+    (13, 75),  // 楽 (19-58)
+    (18, 181), // 額 (19-59)
+    (18, 181), // 顎 (19-60)
+    (11, 64),  // 掛 (19-61)
+    (11, 118), // 笠 (19-62)
+    (16, 75),  // 樫 (19-63)
+    (17, 75),  // 橿 (19-64)
+    (11, 75),  // 梶 (19-65)
+    (20, 195), // 鰍 (19-66)
+    (15, 85),  // 潟 (19-67)
+    (12, 18),  // 割 (19-68)
+    (11, 30),  // 喝 (19-69)
+    (9, 61),   // 恰 (19-70)
+    (9, 64),   // 括 (19-71)
+    (9, 85),   // 活 (19-72)
+    (11, 85),  // 渇 (19-73)
+    (13, 85),  // 滑 (19-74)
+    (11, 140), // 葛 (19-75)
+    (13, 145), // 褐 (19-76)
+    (17, 159), // 轄 (19-77)
+    (5, 1),    // 且 (19-78)
+    (23, 195), // 鰹 (19-79)
+    (5, 30),   // 叶 (19-80)
+    (11, 75),  // 椛 (19-81)
+    (14, 75),  // 樺 (19-82)
+    (14, 177), // 鞄 (19-83)
+    (10, 75),  // 株 (19-84)
+    (11, 10),  // 兜 (19-85)
+    (17, 116), // 竃 (19-86)
+    (13, 140), // 蒲 (19-87)
+    (10, 167), // 釜 (19-88)
+    (18, 167), // 鎌 (19-89)
+    (15, 30),  // 噛 (19-90)
+    (16, 196), // 鴨 (19-91)
+    (10, 75),  // 栢 (19-92)
+    (8, 140),  // 茅 (19-93)
+    (12, 140), // 萱 (19-94)
+    (12, 119), // 粥 (20-01)
+    (4, 18),   // 刈 (20-02)
+    (7, 140),  // 苅 (20-03)
+    (5, 98),   // 瓦 (20-04)
+    (11, 5),   // 乾 (20-05)
+    (8, 9),    // 侃 (20-06)
+    (9, 14),   // 冠 (20-07)
+    (12, 40),  // 寒 (20-08)
+    (5, 18),   // 刊 (20-09)
+    (11, 19),  // 勘 (20-10)
+    (13, 19),  // 勧 (20-11)
+    (9, 26),   // 巻 (20-12)
+    (12, 30),  // 喚 (20-13)
+    (12, 32),  // 堪 (20-14)
+    (9, 38),   // 姦 (20-15)
+    (7, 40),   // 完 (20-16)
+    (8, 40),   // 官 (20-17)
+    (13, 40),  // 寛 (20-18)
+    (3, 51),   // 干 (20-19)
+    (13, 51),  // 幹 (20-20)
+    (11, 61),  // 患 (20-21)
+    (13, 61),  // 感 (20-22)
+    (14, 61),  // 慣 (20-23)
+    (16, 61),  // 憾 (20-24)
+    (12, 64),  // 換 (20-25)
+    (12, 66),  // 敢 (20-26)
+    (9, 75),   // 柑 (20-27)
+    (10, 75),  // 桓 (20-28)
+    (12, 75),  // 棺 (20-29)
+    (12, 76),  // 款 (20-30)
+    (15, 76),  // 歓 (20-31)
+    (6, 85),   // 汗 (20-32)
+    (13, 85),  // 漢 (20-33)
+    (15, 85),  // 澗 (20-34)
+    (14, 85),  // 潅 (20-35)
+    (17, 96),  // 環 (20-36)
+    (5, 99),   // 甘 (20-37)
+    (15, 108), // 監 (20-38)
+    (9, 109),  // 看 (20-39)
+    (9, 118),  // 竿 (20-40)
+    (14, 118), // 管 (20-41)
+    (18, 118), // 簡 (20-42)
+    (15, 120), // 緩 (20-43)
+    (6, 121),  // 缶 (20-44)
+    (16, 124), // 翰 (20-45)
+    (7, 130),  // 肝 (20-46)
+    (21, 137), // 艦 (20-47)
+    (10, 140), // 莞 (20-48)
+    (18, 147), // 観 (20-49)
+    (15, 149), // 諌 (20-50)
+    (11, 154), // 貫 (20-51)
+    (16, 162), // 還 (20-52)
+    (23, 167), // 鑑 (20-53)
+    (12, 169), // 間 (20-54)
+    (12, 169), // 閑 (20-55)
+    (14, 169), // 関 (20-56)
+    (10, 170), // 陥 (20-57)
+    (18, 178), // 韓 (20-58)
+    (16, 184), // 館 (20-59)
+    (16, 135), // 舘 (20-60)
+    (3, 3),    // 丸 (20-61)
+    (7, 30),   // 含 (20-62)
+    (8, 46),   // 岸 (20-63)
+    (20, 46),  // 巌 (20-64)
+    (8, 96),   // 玩 (20-65)
+    (17, 104), // 癌 (20-66)
+    (11, 109), // 眼 (20-67)
+    (8, 46),   // 岩 (20-68)
+    (15, 124), // 翫 (20-69)
+    (19, 154), // 贋 (20-70)
+    (12, 172), // 雁 (20-71)
+    (13, 181), // 頑 (20-72)
+    (18, 181), // 顔 (20-73)
+    (19, 181), // 願 (20-74)
+    (6, 9),    // 企 (20-75)
+    (6, 9),    // 伎 (20-76)
+    (6, 26),   // 危 (20-77)
+    (12, 30),  // 喜 (20-78)
+    (15, 30),  // 器 (20-79)
+    (11, 32),  // 基 (20-80)
+    (8, 37),   // 奇 (20-81)
+    (15, 38),  // 嬉 (20-82)
+    (11, 40),  // 寄 (20-83)
+    (7, 46),   // 岐 (20-84)
+    (7, 50),   // 希 (20-85)
+    (12, 52),  // 幾 (20-86)
+    (7, 61),   // 忌 (20-87)
+    (12, 64),  // 揮 (20-88)
+    (6, 75),   // 机 (20-89)
+    (14, 70),  // 旗 (20-90)
+    (10, 71),  // 既 (20-91)
+    (12, 74),  // 期 (20-92)
+    (12, 75),  // 棋 (20-93)
+    (13, 75),  // 棄 (20-94)
+    (16, 75),  // 機 (21-01)
+    (10, 50),  // 帰 (21-02)
+    (15, 79),  // 毅 (21-03)
+    (6, 84),   // 気 (21-04)
+    (7, 85),   // 汽 (21-05)
+    (15, 102), // 畿 (21-06)
+    (8, 113),  // 祈 (21-07)
+    (8, 39),   // 季 (21-08)
+    (12, 115), // 稀 (21-09)
+    (9, 120),  // 紀 (21-10)
+    (16, 60),  // 徽 (21-11)
+    (11, 147), // 規 (21-12)
+    (10, 149), // 記 (21-13)
+    (12, 154), // 貴 (21-14)
+    (10, 156), // 起 (21-15)
+    (9, 159),  // 軌 (21-16)
+    (15, 159), // 輝 (21-17)
+    (10, 184), // 飢 (21-18)
+    (18, 187), // 騎 (21-19)
+    (10, 194), // 鬼 (21-20)
+    (11, 213), // 亀 (21-21)
+    (11, 9),   // 偽 (21-22)
+    (15, 9),   // 儀 (21-23)
+    (7, 38),   // 妓 (21-24)
+    (8, 40),   // 宜 (21-25)
+    (15, 62),  // 戯 (21-26)
+    (7, 64),   // 技 (21-27)
+    (17, 64),  // 擬 (21-28)
+    (12, 76),  // 欺 (21-29)
+    (17, 93),  // 犠 (21-30)
+    (14, 103), // 疑 (21-31)
+    (8, 113),  // 祇 (21-32)
+    (13, 123), // 義 (21-33)
+    (19, 142), // 蟻 (21-34)
+    (15, 149), // 誼 (21-35)
+    (20, 149), // 議 (21-36)
+    (11, 64),  // 掬 (21-37)
+    (11, 140), // 菊 (21-38)
+    (17, 177), // 鞠 (21-39)
+    (6, 30),   // 吉 (21-40)
+    (6, 30),   // 吃 (21-41)
+    (12, 30),  // 喫 (21-42)
+    (10, 75),  // 桔 (21-43)
+    (16, 75),  // 橘 (21-44)
+    (13, 149), // 詰 (21-45)
+    (10, 112), // 砧 (21-46)
+    (8, 75),   // 杵 (21-47)
+    (12, 202), // 黍 (21-48)
+    (7, 26),   // 却 (21-49)
+    (9, 40),   // 客 (21-50)
+    (11, 130), // 脚 (21-51)
+    (9, 141),  // 虐 (21-52)
+    (9, 162),  // 逆 (21-53)
+    (5, 1),    // 丘 (21-54)
+    (3, 4),    // 久 (21-55)
+    (4, 9),    // 仇 (21-56)
+    (6, 9),    // 休 (21-57)
+    (3, 29),   // 及 (21-58)
+    (6, 30),   // 吸 (21-59)
+    (10, 40),  // 宮 (21-60)
+    (3, 57),   // 弓 (21-61)
+    (9, 61),   // 急 (21-62)
+    (11, 66),  // 救 (21-63)
+    (6, 75),   // 朽 (21-64)
+    (6, 85),   // 求 (21-65)
+    (6, 85),   // 汲 (21-66)
+    (8, 85),   // 泣 (21-67)
+    (7, 86),   // 灸 (21-68)
+    (11, 96),  // 球 (21-69)
+    (7, 116),  // 究 (21-70)
+    (15, 116), // 窮 (21-71)
+    (9, 118),  // 笈 (21-72)
+    (9, 120),  // 級 (21-73)
+    (9, 120),  // 糾 (21-74)
+    (12, 120), // 給 (21-75)
+    (5, 72),   // 旧 (21-76)
+    (4, 93),   // 牛 (21-77)
+    (5, 28),   // 去 (21-78)
+    (8, 44),   // 居 (21-79)
+    (5, 48),   // 巨 (21-80)
+    (8, 64),   // 拒 (21-81)
+    (8, 64),   // 拠 (21-82)
+    (10, 64),  // 挙 (21-83)
+    (12, 85),  // 渠 (21-84)
+    (11, 141), // 虚 (21-85)
+    (11, 149), // 許 (21-86)
+    (12, 157), // 距 (21-87)
+    (16, 167), // 鋸 (21-88)
+    (14, 85),  // 漁 (21-89)
+    (17, 113), // 禦 (21-90)
+    (11, 195), // 魚 (21-91)
+    (7, 8),    // 亨 (21-92)
+    (8, 8),    // 享 (21-93)
+    (8, 8),    // 京 (21-94)
+    (8, 9),    // 供 (22-01)
+    (8, 9),    // 侠 (22-02)
+    (14, 9),   // 僑 (22-03)
+    (6, 10),   // 兇 (22-04)
+    (20, 117), // 競 (22-05)
+    (6, 12),   // 共 (22-06)
+    (4, 17),   // 凶 (22-07)
+    (8, 24),   // 協 (22-08)
+    (6, 22),   // 匡 (22-09)
+    (10, 26),  // 卿 (22-10)
+    (6, 30),   // 叫 (22-11)
+    (12, 30),  // 喬 (22-12)
+    (14, 32),  // 境 (22-13)
+    (9, 46),   // 峡 (22-14)
+    (11, 57),  // 強 (22-15)
+    (16, 57),  // 彊 (22-16)
+    (8, 61),   // 怯 (22-17)
+    (10, 61),  // 恐 (22-18)
+    (10, 61),  // 恭 (22-19)
+    (9, 64),   // 挟 (22-20)
+    (11, 66),  // 教 (22-21)
+    (16, 75),  // 橋 (22-22)
+    (8, 85),   // 況 (22-23)
+    (7, 94),   // 狂 (22-24)
+    (9, 94),   // 狭 (22-25)
+    (17, 111), // 矯 (22-26)
+    (10, 130), // 胸 (22-27)
+    (10, 130), // 脅 (22-28)
+    (16, 134), // 興 (22-29)
+    (15, 140), // 蕎 (22-30)
+    (11, 163), // 郷 (22-31)
+    (19, 167), // 鏡 (22-32)
+    (20, 180), // 響 (22-33)
+    (20, 184), // 饗 (22-34)
+    (22, 187), // 驚 (22-35)
+    (6, 9),    // 仰 (22-36)
+    (16, 15),  // 凝 (22-37)
+    (8, 10),   // 尭 (22-38)
+    (12, 72),  // 暁 (22-39)
+    (13, 75),  // 業 (22-40)
+    (7, 44),   // 局 (22-41)
+    (6, 73),   // 曲 (22-42)
+    (12, 75),  // 極 (22-43)
+    (5, 96),   // 玉 (22-44)
+    (10, 75),  // 桐 (22-45)
+    (9, 119),  // 粁 (22-46)
+    (12, 9),   // 僅 (22-47)
+    (12, 19),  // 勤 (22-48)
+    (7, 32),   // 均 (22-49)
+    (3, 50),   // 巾 (22-50)
+    (16, 167), // 錦 (22-51)
+    (4, 69),   // 斤 (22-52)
+    (8, 76),   // 欣 (22-53)
+    (12, 76),  // 欽 (22-54)
+    (12, 96),  // 琴 (22-55)
+    (13, 113), // 禁 (22-56)
+    (13, 114), // 禽 (22-57)
+    (12, 118), // 筋 (22-58)
+    (15, 120), // 緊 (22-59)
+    (7, 140),  // 芹 (22-60)
+    (11, 140), // 菌 (22-61)
+    (9, 145),  // 衿 (22-62)
+    (18, 145), // 襟 (22-63)
+    (17, 149), // 謹 (22-64)
+    (7, 162),  // 近 (22-65)
+    (8, 167),  // 金 (22-66)
+    (7, 30),   // 吟 (22-67)
+    (14, 167), // 銀 (22-68)
+    (2, 5),    // 九 (22-69)
+    (10, 9),   // 倶 (22-70)
+    (5, 30),   // 句 (22-71)
+    (4, 23),   // 区 (22-72)
+    (8, 94),   // 狗 (22-73)
+    (7, 96),   // 玖 (22-74)
+    (10, 111), // 矩 (22-75)
+    (8, 140),  // 苦 (22-76)
+    (11, 158), // 躯 (22-77)
+    (14, 187), // 駆 (22-78)
+    (15, 187), // 駈 (22-79)
+    (15, 187), // 駒 (22-80)
+    (8, 12),   // 具 (22-81)
+    (13, 61),  // 愚 (22-82)
+    (13, 141), // 虞 (22-83)
+    (12, 30),  // 喰 (22-84)
+    (8, 116),  // 空 (22-85)
+    (11, 9),   // 偶 (22-86)
+    (12, 40),  // 寓 (22-87)
+    (12, 162), // 遇 (22-88)
+    (12, 170), // 隅 (22-89)
+    (7, 2),    // 串 (22-90)
+    (17, 75),  // 櫛 (22-91)
+    (11, 167), // 釧 (22-92)
+    (10, 44),  // 屑 (22-93)
+    (8, 44),   // 屈 (22-94)
+    (11, 64),  // 掘 (23-01)
+    (13, 116), // 窟 (23-02)
+    (8, 85),   // 沓 (23-03)
+    (13, 177), // 靴 (23-04)
+    (22, 159), // 轡 (23-05)
+    (14, 116), // 窪 (23-06)
+    (14, 86),  // 熊 (23-07)
+    (12, 170), // 隈 (23-08)
+    (9, 119),  // 粂 (23-09)
+    (10, 75),  // 栗 (23-10)
+    (19, 120), // 繰 (23-11)
+    (10, 75),  // 桑 (23-12)
+    (17, 167), // 鍬 (23-13)
+    (15, 86),  // 勲 (23-14)
+    (7, 30),   // 君 (23-15)
+    (16, 140), // 薫 (23-16)
+    (10, 149), // 訓 (23-17)
+    (13, 123), // 群 (23-18)
+    (9, 159),  // 軍 (23-19)
+    (10, 163), // 郡 (23-20)
+    (8, 25),   // 卦 (23-21)
+    (11, 145), // 袈 (23-22)
+    (7, 113),  // 祁 (23-23)
+    (9, 9),    // 係 (23-24)
+    (13, 9),   // 傾 (23-25)
+    (6, 18),   // 刑 (23-26)
+    (5, 10),   // 兄 (23-27)
+    (11, 30),  // 啓 (23-28)
+    (6, 32),   // 圭 (23-29)
+    (10, 96),  // 珪 (23-30)
+    (9, 32),   // 型 (23-31)
+    (9, 37),   // 契 (23-32)
+    (7, 59),   // 形 (23-33)
+    (8, 60),   // 径 (23-34)
+    (10, 61),  // 恵 (23-35)
+    (15, 61),  // 慶 (23-36)
+    (15, 61),  // 慧 (23-37)
+    (16, 61),  // 憩 (23-38)
+    (11, 64),  // 掲 (23-39)
+    (13, 64),  // 携 (23-40)
+    (12, 66),  // 敬 (23-41)
+    (12, 72),  // 景 (23-42)
+    (10, 75),  // 桂 (23-43)
+    (11, 85),  // 渓 (23-44)
+    (11, 102), // 畦 (23-45)
+    (15, 115), // 稽 (23-46)
+    (7, 120),  // 系 (23-47)
+    (11, 120), // 経 (23-48)
+    (13, 120), // 継 (23-49)
+    (17, 120), // 繋 (23-50)
+    (13, 122), // 罫 (23-51)
+    (8, 140),  // 茎 (23-52)
+    (9, 140),  // 荊 (23-53)
+    (11, 42),  // 蛍 (23-54)
+    (9, 149),  // 計 (23-55)
+    (13, 149), // 詣 (23-56)
+    (19, 149), // 警 (23-57)
+    (12, 159), // 軽 (23-58)
+    (14, 181), // 頚 (23-59)
+    (19, 196), // 鶏 (23-60)
+    (7, 140),  // 芸 (23-61)
+    (7, 162),  // 迎 (23-62)
+    (19, 195), // 鯨 (23-63)
+    (15, 18),  // 劇 (23-64)
+    (12, 62),  // 戟 (23-65)
+    (15, 64),  // 撃 (23-66)
+    (16, 85),  // 激 (23-67)
+    (13, 170), // 隙 (23-68)
+    (10, 75),  // 桁 (23-69)
+    (13, 9),   // 傑 (23-70)
+    (4, 76),   // 欠 (23-71)
+    (7, 85),   // 決 (23-72)
+    (15, 85),  // 潔 (23-73)
+    (5, 116),  // 穴 (23-74)
+    (12, 120), // 結 (23-75)
+    (6, 143),  // 血 (23-76)
+    (11, 149), // 訣 (23-77)
+    (4, 74),   // 月 (23-78)
+    (6, 9),    // 件 (23-79)
+    (10, 9),   // 倹 (23-80)
+    (11, 9),   // 倦 (23-81)
+    (11, 9),   // 健 (23-82)
+    (10, 12),  // 兼 (23-83)
+    (8, 18),   // 券 (23-84)
+    (10, 18),  // 剣 (23-85)
+    (12, 30),  // 喧 (23-86)
+    (12, 31),  // 圏 (23-87)
+    (12, 32),  // 堅 (23-88)
+    (13, 38),  // 嫌 (23-89)
+    (9, 54),   // 建 (23-90)
+    (16, 61),  // 憲 (23-91)
+    (20, 61),  // 懸 (23-92)
+    (10, 64),  // 拳 (23-93)
+    (12, 64),  // 捲 (23-94)
+    (12, 75),  // 検 (24-01)
+    (15, 75),  // 権 (24-02)
+    (11, 93),  // 牽 (24-03)
+    (4, 94),   // 犬 (24-04)
+    (13, 94),  // 献 (24-05)
+    (9, 112),  // 研 (24-06)
+    (12, 112), // 硯 (24-07)
+    (13, 120), // 絹 (24-08)
+    (9, 109),  // 県 (24-09)
+    (8, 130),  // 肩 (24-10)
+    (7, 147),  // 見 (24-11)
+    (17, 149), // 謙 (24-12)
+    (16, 154), // 賢 (24-13)
+    (10, 159), // 軒 (24-14)
+    (13, 162), // 遣 (24-15)
+    (17, 167), // 鍵 (24-16)
+    (11, 170), // 険 (24-17)
+    (18, 181), // 顕 (24-18)
+    (18, 187), // 験 (24-19)
+    (19, 197), // 鹸 (24-20)
+    (4, 10),   // 元 (24-21)
+    (10, 27),  // 原 (24-22)
+    (17, 27),  // 厳 (24-23)
+    (4, 52),   // 幻 (24-24)
+    (8, 57),   // 弦 (24-25)
+    (12, 85),  // 減 (24-26)
+    (13, 85),  // 源 (24-27)
+    (5, 95),   // 玄 (24-28)
+    (11, 96),  // 現 (24-29)
+    (11, 120), // 絃 (24-30)
+    (11, 137), // 舷 (24-31)
+    (7, 149),  // 言 (24-32)
+    (16, 149), // 諺 (24-33)
+    (9, 170),  // 限 (24-34)
+    (5, 4),    // 乎 (24-35)
+    (10, 9),   // 個 (24-36)
+    (5, 30),   // 古 (24-37)
+    (8, 30),   // 呼 (24-38)
+    (8, 31),   // 固 (24-39)
+    (8, 38),   // 姑 (24-40)
+    (9, 39),   // 孤 (24-41)
+    (3, 49),   // 己 (24-42)
+    (10, 53),  // 庫 (24-43)
+    (9, 57),   // 弧 (24-44)
+    (4, 63),   // 戸 (24-45)
+    (9, 66),   // 故 (24-46)
+    (9, 75),   // 枯 (24-47)
+    (12, 85),  // 湖 (24-48)
+    (9, 94),   // 狐 (24-49)
+    (15, 119), // 糊 (24-50)
+    (11, 145), // 袴 (24-51)
+    (8, 130),  // 股 (24-52)
+    (9, 130),  // 胡 (24-53)
+    (12, 140), // 菰 (24-54)
+    (8, 141),  // 虎 (24-55)
+    (13, 149), // 誇 (24-56)
+    (13, 157), // 跨 (24-57)
+    (13, 167), // 鈷 (24-58)
+    (12, 172), // 雇 (24-59)
+    (21, 181), // 顧 (24-60)
+    (13, 207), // 鼓 (24-61)
+    (4, 7),    // 五 (24-62)
+    (4, 7),    // 互 (24-63)
+    (6, 9),    // 伍 (24-64)
+    (4, 24),   // 午 (24-65)
+    (7, 30),   // 呉 (24-66)
+    (7, 30),   // 吾 (24-67)
+    (10, 38),  // 娯 (24-68)
+    (9, 60),   // 後 (24-69)
+    (12, 60),  // 御 (24-70)
+    (10, 61),  // 悟 (24-71)
+    (11, 75),  // 梧 (24-72)
+    (17, 75),  // 檎 (24-73)
+    (13, 96),  // 瑚 (24-74)
+    (13, 112), // 碁 (24-75)
+    (14, 149), // 語 (24-76)
+    (14, 149), // 誤 (24-77)
+    (20, 149), // 護 (24-78)
+    (16, 164), // 醐 (24-79)
+    (3, 5),    // 乞 (24-80)
+    (18, 195), // 鯉 (24-81)
+    (6, 8),    // 交 (24-82)
+    (8, 9),    // 佼 (24-83)
+    (9, 9),    // 侯 (24-84)
+    (10, 9),   // 候 (24-85)
+    (10, 9),   // 倖 (24-86)
+    (6, 10),   // 光 (24-87)
+    (4, 12),   // 公 (24-88)
+    (5, 19),   // 功 (24-89)
+    (8, 19),   // 効 (24-90)
+    (4, 20),   // 勾 (24-91)
+    (9, 27),   // 厚 (24-92)
+    (3, 30),   // 口 (24-93)
+    (6, 30),   // 向 (24-94)
+    (6, 30),   // 后 (25-01)
+    (12, 30),  // 喉 (25-02)
+    (7, 32),   // 坑 (25-03)
+    (9, 32),   // 垢 (25-04)
+    (6, 38),   // 好 (25-05)
+    (4, 39),   // 孔 (25-06)
+    (7, 39),   // 孝 (25-07)
+    (7, 40),   // 宏 (25-08)
+    (3, 48),   // 工 (25-09)
+    (5, 48),   // 巧 (25-10)
+    (9, 49),   // 巷 (25-11)
+    (8, 51),   // 幸 (25-12)
+    (5, 53),   // 広 (25-13)
+    (8, 53),   // 庚 (25-14)
+    (11, 53),  // 康 (25-15)
+    (5, 57),   // 弘 (25-16)
+    (9, 61),   // 恒 (25-17)
+    (12, 61),  // 慌 (25-18)
+    (7, 64),   // 抗 (25-19)
+    (8, 64),   // 拘 (25-20)
+    (11, 64),  // 控 (25-21)
+    (7, 66),   // 攻 (25-22)
+    (8, 72),   // 昂 (25-23)
+    (10, 72),  // 晃 (25-24)
+    (7, 73),   // 更 (25-25)
+    (8, 75),   // 杭 (25-26)
+    (10, 75),  // 校 (25-27)
+    (11, 75),  // 梗 (25-28)
+    (14, 75),  // 構 (25-29)
+    (6, 85),   // 江 (25-30)
+    (9, 85),   // 洪 (25-31)
+    (10, 85),  // 浩 (25-32)
+    (12, 85),  // 港 (25-33)
+    (13, 85),  // 溝 (25-34)
+    (5, 102),  // 甲 (25-35)
+    (9, 106),  // 皇 (25-36)
+    (12, 112), // 硬 (25-37)
+    (15, 115), // 稿 (25-38)
+    (17, 119), // 糠 (25-39)
+    (9, 120),  // 紅 (25-40)
+    (10, 120), // 紘 (25-41)
+    (12, 120), // 絞 (25-42)
+    (14, 120), // 綱 (25-43)
+    (10, 127), // 耕 (25-44)
+    (6, 125),  // 考 (25-45)
+    (8, 130),  // 肯 (25-46)
+    (8, 130),  // 肱 (25-47)
+    (12, 130), // 腔 (25-48)
+    (14, 130), // 膏 (25-49)
+    (10, 137), // 航 (25-50)
+    (9, 140),  // 荒 (25-51)
+    (6, 144),  // 行 (25-52)
+    (16, 144), // 衡 (25-53)
+    (17, 149), // 講 (25-54)
+    (10, 154), // 貢 (25-55)
+    (17, 154), // 購 (25-56)
+    (9, 163),  // 郊 (25-57)
+    (14, 164), // 酵 (25-58)
+    (13, 167), // 鉱 (25-59)
+    (10, 112), // 砿 (25-60)
+    (16, 167), // 鋼 (25-61)
+    (14, 169), // 閤 (25-62)
+    (10, 170), // 降 (25-63)
+    (12, 181), // 項 (25-64)
+    (9, 186),  // 香 (25-65)
+    (10, 189), // 高 (25-66)
+    (17, 196), // 鴻 (25-67)
+    (10, 18),  // 剛 (25-68)
+    (7, 19),   // 劫 (25-69)
+    (5, 30),   // 号 (25-70)
+    (6, 30),   // 合 (25-71)
+    (17, 32),  // 壕 (25-72)
+    (9, 64),   // 拷 (25-73)
+    (17, 85),  // 濠 (25-74)
+    (14, 152), // 豪 (25-75)
+    (21, 159), // 轟 (25-76)
+    (15, 199), // 麹 (25-77)
+    (7, 10),   // 克 (25-78)
+    (8, 18),   // 刻 (25-79)
+    (7, 30),   // 告 (25-80)
+    (8, 31),   // 国 (25-81)
+    (14, 115), // 穀 (25-82)
+    (14, 164), // 酷 (25-83)
+    (18, 196), // 鵠 (25-84)
+    (11, 203), // 黒 (25-85)
+    (14, 94),  // 獄 (25-86)
+    (14, 85),  // 漉 (25-87)
+    (13, 130), // 腰 (25-88)
+    (16, 98),  // 甑 (25-89)
+    (8, 61),   // 忽 (25-90)
+    (11, 61),  // 惚 (25-91)
+    (10, 188), // 骨 (25-92)
+    (8, 94),   // 狛 (25-93)
+    (5, 162),  // 込 (25-94)
+    (6, 77),   // 此 (26-01)
+    (11, 181), // 頃 (26-02)
+    (4, 9),    // 今 (26-03)
+    (7, 31),   // 困 (26-04)
+    (8, 32),   // 坤 (26-05)
+    (16, 32),  // 墾 (26-06)
+    (11, 38),  // 婚 (26-07)
+    (9, 61),   // 恨 (26-08)
+    (17, 61),  // 懇 (26-09)
+    (8, 72),   // 昏 (26-10)
+    (8, 72),   // 昆 (26-11)
+    (10, 75),  // 根 (26-12)
+    (11, 75),  // 梱 (26-13)
+    (11, 85),  // 混 (26-14)
+    (11, 104), // 痕 (26-15)
+    (11, 120), // 紺 (26-16)
+    (6, 138),  // 艮 (26-17)
+    (14, 194), // 魂 (26-18)
+    (8, 7),    // 些 (26-19)
+    (7, 9),    // 佐 (26-20)
+    (3, 29),   // 叉 (26-21)
+    (10, 30),  // 唆 (26-22)
+    (13, 46),  // 嵯 (26-23)
+    (5, 48),   // 左 (26-24)
+    (10, 48),  // 差 (26-25)
+    (9, 75),   // 査 (26-26)
+    (7, 85),   // 沙 (26-27)
+    (14, 96),  // 瑳 (26-28)
+    (9, 112),  // 砂 (26-29)
+    (12, 149), // 詐 (26-30)
+    (18, 167), // 鎖 (26-31)
+    (13, 145), // 裟 (26-32)
+    (7, 32),   // 坐 (26-33)
+    (10, 53),  // 座 (26-34)
+    (10, 64),  // 挫 (26-35)
+    (13, 9),   // 債 (26-36)
+    (13, 9),   // 催 (26-37)
+    (6, 13),   // 再 (26-38)
+    (12, 73),  // 最 (26-39)
+    (9, 30),   // 哉 (26-40)
+    (13, 32),  // 塞 (26-41)
+    (8, 38),   // 妻 (26-42)
+    (10, 40),  // 宰 (26-43)
+    (11, 59),  // 彩 (26-44)
+    (3, 64),   // 才 (26-45)
+    (11, 64),  // 採 (26-46)
+    (10, 75),  // 栽 (26-47)
+    (13, 77),  // 歳 (26-48)
+    (11, 85),  // 済 (26-49)
+    (7, 86),   // 災 (26-50)
+    (8, 165),  // 采 (26-51)
+    (12, 93),  // 犀 (26-52)
+    (9, 112),  // 砕 (26-53)
+    (11, 112), // 砦 (26-54)
+    (11, 113), // 祭 (26-55)
+    (11, 67),  // 斎 (26-56)
+    (11, 120), // 細 (26-57)
+    (11, 140), // 菜 (26-58)
+    (12, 145), // 裁 (26-59)
+    (13, 159), // 載 (26-60)
+    (14, 170), // 際 (26-61)
+    (10, 18),  // 剤 (26-62)
+    (6, 32),   // 在 (26-63)
+    (7, 75),   // 材 (26-64)
+    (13, 122), // 罪 (26-65)
+    (10, 154), // 財 (26-66)
+    (7, 15),   // 冴 (26-67)
+    (7, 32),   // 坂 (26-68)
+    (7, 170),  // 阪 (26-69)
+    (12, 32),  // 堺 (26-70)
+    (13, 75),  // 榊 (26-71)
+    (8, 130),  // 肴 (26-72)
+    (9, 30),   // 咲 (26-73)
+    (11, 46),  // 崎 (26-74)
+    (11, 32),  // 埼 (26-75)
+    (13, 112), // 碕 (26-76)
+    (24, 196), // 鷺 (26-77)
+    (7, 9),    // 作 (26-78)
+    (9, 18),   // 削 (26-79)
+    (8, 30),   // 咋 (26-80)
+    (13, 64),  // 搾 (26-81)
+    (9, 72),   // 昨 (26-82)
+    (10, 74),  // 朔 (26-83)
+    (9, 75),   // 柵 (26-84)
+    (10, 116), // 窄 (26-85)
+    (12, 118), // 策 (26-86)
+    (10, 120), // 索 (26-87)
+    (16, 167), // 錯 (26-88)
+    (10, 75),  // 桜 (26-89)
+    (17, 195), // 鮭 (26-90)
+    (11, 118), // 笹 (26-91)
+    (11, 21),  // 匙 (26-92)
+    (5, 13),   // 冊 (26-93)
+    (8, 18),   // 刷 (26-94)
+    (14, 40),  // 察 (27-01)
+    (9, 64),   // 拶 (27-02)
+    (15, 64),  // 撮 (27-03)
+    (17, 64),  // 擦 (27-04)
+    (5, 75),   // 札 (27-05)
+    (10, 79),  // 殺 (27-06)
+    (17, 140), // 薩 (27-07)
+    (14, 172), // 雑 (27-08)
+    (11, 106), // 皐 (27-09)
+    (19, 195), // 鯖 (27-10)
+    (10, 64),  // 捌 (27-11)
+    (16, 167), // 錆 (27-12)
+    (17, 195), // 鮫 (27-13)
+    (5, 108),  // 皿 (27-14)
+    (10, 72),  // 晒 (27-15)
+    (3, 1),    // 三 (27-16)
+    (12, 9),   // 傘 (27-17)
+    (8, 28),   // 参 (27-18)
+    (3, 46),   // 山 (27-19)
+    (11, 61),  // 惨 (27-20)
+    (15, 64),  // 撒 (27-21)
+    (12, 66),  // 散 (27-22)
+    (10, 75),  // 桟 (27-23)
+    (17, 86),  // 燦 (27-24)
+    (9, 96),   // 珊 (27-25)
+    (11, 100), // 産 (27-26)
+    (14, 118), // 算 (27-27)
+    (20, 120), // 纂 (27-28)
+    (10, 142), // 蚕 (27-29)
+    (22, 149), // 讃 (27-30)
+    (15, 154), // 賛 (27-31)
+    (14, 164), // 酸 (27-32)
+    (16, 184), // 餐 (27-33)
+    (11, 69),  // 斬 (27-34)
+    (15, 72),  // 暫 (27-35)
+    (10, 78),  // 残 (27-36)
+    (5, 9),    // 仕 (27-37)
+    (5, 9),    // 仔 (27-38)
+    (7, 9),    // 伺 (27-39)
+    (8, 9),    // 使 (27-40)
+    (8, 18),   // 刺 (27-41)
+    (5, 30),   // 司 (27-42)
+    (5, 30),   // 史 (27-43)
+    (13, 30),  // 嗣 (27-44)
+    (5, 31),   // 四 (27-45)
+    (3, 33),   // 士 (27-46)
+    (8, 38),   // 始 (27-47)
+    (8, 38),   // 姉 (27-48)
+    (9, 38),   // 姿 (27-49)
+    (3, 39),   // 子 (27-50)
+    (9, 44),   // 屍 (27-51)
+    (5, 50),   // 市 (27-52)
+    (10, 50),  // 師 (27-53)
+    (7, 61),   // 志 (27-54)
+    (9, 61),   // 思 (27-55)
+    (9, 64),   // 指 (27-56)
+    (4, 65),   // 支 (27-57)
+    (7, 39),   // 孜 (27-58)
+    (12, 69),  // 斯 (27-59)
+    (9, 70),   // 施 (27-60)
+    (6, 72),   // 旨 (27-61)
+    (8, 75),   // 枝 (27-62)
+    (4, 77),   // 止 (27-63)
+    (6, 78),   // 死 (27-64)
+    (4, 83),   // 氏 (27-65)
+    (13, 94),  // 獅 (27-66)
+    (8, 113),  // 祉 (27-67)
+    (7, 115),  // 私 (27-68)
+    (6, 120),  // 糸 (27-69)
+    (10, 120), // 紙 (27-70)
+    (12, 120), // 紫 (27-71)
+    (8, 130),  // 肢 (27-72)
+    (10, 130), // 脂 (27-73)
+    (6, 133),  // 至 (27-74)
+    (11, 147), // 視 (27-75)
+    (12, 149), // 詞 (27-76)
+    (13, 149), // 詩 (27-77)
+    (13, 149), // 試 (27-78)
+    (14, 149), // 誌 (27-79)
+    (16, 149), // 諮 (27-80)
+    (13, 154), // 資 (27-81)
+    (15, 154), // 賜 (27-82)
+    (14, 172), // 雌 (27-83)
+    (13, 184), // 飼 (27-84)
+    (12, 211), // 歯 (27-85)
+    (8, 6),    // 事 (27-86)
+    (7, 9),    // 似 (27-87)
+    (8, 9),    // 侍 (27-88)
+    (7, 10),   // 児 (27-89)
+    (6, 39),   // 字 (27-90)
+    (6, 41),   // 寺 (27-91)
+    (13, 61),  // 慈 (27-92)
+    (9, 64),   // 持 (27-93)
+    (10, 72),  // 時 (27-94)
+    (6, 76),   // 次 (28-01)
+    (12, 85),  // 滋 (28-02)
+    (8, 85),   // 治 (28-03)
+    (14, 89),  // 爾 (28-04)
+    (19, 96),  // 璽 (28-05)
+    (11, 104), // 痔 (28-06)
+    (14, 112), // 磁 (28-07)
+    (5, 113),  // 示 (28-08)
+    (6, 126),  // 而 (28-09)
+    (6, 128),  // 耳 (28-10)
+    (6, 132),  // 自 (28-11)
+    (13, 140), // 蒔 (28-12)
+    (13, 135), // 辞 (28-13)
+    (6, 85),   // 汐 (28-14)
+    (11, 198), // 鹿 (28-15)
+    (6, 56),   // 式 (28-16)
+    (19, 149), // 識 (28-17)
+    (16, 196), // 鴫 (28-18)
+    (8, 118),  // 竺 (28-19)
+    (12, 159), // 軸 (28-20)
+    (7, 40),   // 宍 (28-21)
+    (11, 173), // 雫 (28-22)
+    (2, 1),    // 七 (28-23)
+    (5, 30),   // 叱 (28-24)
+    (11, 32),  // 執 (28-25)
+    (5, 37),   // 失 (28-26)
+    (13, 38),  // 嫉 (28-27)
+    (9, 40),   // 室 (28-28)
+    (11, 61),  // 悉 (28-29)
+    (12, 85),  // 湿 (28-30)
+    (14, 85),  // 漆 (28-31)
+    (10, 104), // 疾 (28-32)
+    (15, 154), // 質 (28-33)
+    (8, 40),   // 実 (28-34)
+    (14, 140), // 蔀 (28-35)
+    (17, 118), // 篠 (28-36)
+    (11, 9),   // 偲 (28-37)
+    (10, 75),  // 柴 (28-38)
+    (6, 140),  // 芝 (28-39)
+    (12, 44),  // 屡 (28-40)
+    (15, 140), // 蕊 (28-41)
+    (16, 120), // 縞 (28-42)
+    (8, 135),  // 舎 (28-43)
+    (5, 14),   // 写 (28-44)
+    (10, 41),  // 射 (28-45)
+    (11, 64),  // 捨 (28-46)
+    (11, 155), // 赦 (28-47)
+    (11, 68),  // 斜 (28-48)
+    (12, 86),  // 煮 (28-49)
+    (7, 113),  // 社 (28-50)
+    (10, 120), // 紗 (28-51)
+    (8, 125),  // 者 (28-52)
+    (17, 149), // 謝 (28-53)
+    (7, 159),  // 車 (28-54)
+    (14, 162), // 遮 (28-55)
+    (11, 142), // 蛇 (28-56)
+    (8, 163),  // 邪 (28-57)
+    (10, 9),   // 借 (28-58)
+    (3, 20),   // 勺 (28-59)
+    (4, 44),   // 尺 (28-60)
+    (7, 75),   // 杓 (28-61)
+    (7, 86),   // 灼 (28-62)
+    (17, 87),  // 爵 (28-63)
+    (10, 164), // 酌 (28-64)
+    (11, 165), // 釈 (28-65)
+    (16, 167), // 錫 (28-66)
+    (8, 140),  // 若 (28-67)
+    (11, 40),  // 寂 (28-68)
+    (10, 57),  // 弱 (28-69)
+    (12, 61),  // 惹 (28-70)
+    (5, 3),    // 主 (28-71)
+    (8, 29),   // 取 (28-72)
+    (6, 40),   // 守 (28-73)
+    (4, 64),   // 手 (28-74)
+    (6, 75),   // 朱 (28-75)
+    (10, 78),  // 殊 (28-76)
+    (9, 94),   // 狩 (28-77)
+    (10, 96),  // 珠 (28-78)
+    (14, 115), // 種 (28-79)
+    (13, 130), // 腫 (28-80)
+    (15, 156), // 趣 (28-81)
+    (10, 164), // 酒 (28-82)
+    (9, 185),  // 首 (28-83)
+    (16, 9),   // 儒 (28-84)
+    (8, 29),   // 受 (28-85)
+    (8, 30),   // 呪 (28-86)
+    (7, 41),   // 寿 (28-87)
+    (11, 64),  // 授 (28-88)
+    (16, 75),  // 樹 (28-89)
+    (14, 120), // 綬 (28-90)
+    (14, 173), // 需 (28-91)
+    (5, 31),   // 囚 (28-92)
+    (5, 29),   // 収 (28-93)
+    (8, 30),   // 周 (28-94)
+    (8, 40),   // 宗 (29-01)
+    (12, 43),  // 就 (29-02)
+    (6, 47),   // 州 (29-03)
+    (10, 9),   // 修 (29-04)
+    (13, 61),  // 愁 (29-05)
+    (9, 64),   // 拾 (29-06)
+    (9, 85),   // 洲 (29-07)
+    (7, 115),  // 秀 (29-08)
+    (9, 115),  // 秋 (29-09)
+    (11, 120), // 終 (29-10)
+    (17, 120), // 繍 (29-11)
+    (11, 124), // 習 (29-12)
+    (9, 132),  // 臭 (29-13)
+    (6, 137),  // 舟 (29-14)
+    (13, 140), // 蒐 (29-15)
+    (12, 143), // 衆 (29-16)
+    (22, 145), // 襲 (29-17)
+    (23, 149), // 讐 (29-18)
+    (19, 157), // 蹴 (29-19)
+    (16, 159), // 輯 (29-20)
+    (11, 162), // 週 (29-21)
+    (9, 164),  // 酋 (29-22)
+    (13, 164), // 酬 (29-23)
+    (12, 172), // 集 (29-24)
+    (17, 164), // 醜 (29-25)
+    (4, 9),    // 什 (29-26)
+    (7, 9),    // 住 (29-27)
+    (6, 10),   // 充 (29-28)
+    (2, 24),   // 十 (29-29)
+    (10, 60),  // 従 (29-30)
+    (6, 62),   // 戎 (29-31)
+    (9, 75),   // 柔 (29-32)
+    (5, 85),   // 汁 (29-33)
+    (11, 85),  // 渋 (29-34)
+    (16, 94),  // 獣 (29-35)
+    (16, 120), // 縦 (29-36)
+    (9, 166),  // 重 (29-37)
+    (14, 167), // 銃 (29-38)
+    (8, 29),   // 叔 (29-39)
+    (6, 36),   // 夙 (29-40)
+    (11, 40),  // 宿 (29-41)
+    (11, 85),  // 淑 (29-42)
+    (9, 113),  // 祝 (29-43)
+    (17, 120), // 縮 (29-44)
+    (11, 129), // 粛 (29-45)
+    (14, 32),  // 塾 (29-46)
+    (15, 86),  // 熟 (29-47)
+    (5, 17),   // 出 (29-48)
+    (11, 144), // 術 (29-49)
+    (8, 162),  // 述 (29-50)
+    (9, 9),    // 俊 (29-51)
+    (10, 46),  // 峻 (29-52)
+    (9, 72),   // 春 (29-53)
+    (18, 109), // 瞬 (29-54)
+    (12, 117), // 竣 (29-55)
+    (13, 136), // 舜 (29-56)
+    (17, 187), // 駿 (29-57)
+    (10, 15),  // 准 (29-58)
+    (12, 60),  // 循 (29-59)
+    (6, 72),   // 旬 (29-60)
+    (13, 75),  // 楯 (29-61)
+    (10, 78),  // 殉 (29-62)
+    (11, 85),  // 淳 (29-63)
+    (13, 85),  // 準 (29-64)
+    (15, 85),  // 潤 (29-65)
+    (9, 109),  // 盾 (29-66)
+    (10, 120), // 純 (29-67)
+    (6, 47),   // 巡 (29-68)
+    (15, 162), // 遵 (29-69)
+    (15, 164), // 醇 (29-70)
+    (12, 181), // 順 (29-71)
+    (5, 34),   // 処 (29-72)
+    (7, 18),   // 初 (29-73)
+    (8, 63),   // 所 (29-74)
+    (12, 72),  // 暑 (29-75)
+    (17, 72),  // 曙 (29-76)
+    (11, 85),  // 渚 (29-77)
+    (11, 53),  // 庶 (29-78)
+    (14, 120), // 緒 (29-79)
+    (13, 122), // 署 (29-80)
+    (10, 73),  // 書 (29-81)
+    (16, 140), // 薯 (29-82)
+    (18, 140), // 藷 (29-83)
+    (15, 149), // 諸 (29-84)
+    (7, 19),   // 助 (29-85)
+    (9, 29),   // 叙 (29-86)
+    (3, 38),   // 女 (29-87)
+    (7, 53),   // 序 (29-88)
+    (10, 60),  // 徐 (29-89)
+    (10, 61),  // 恕 (29-90)
+    (15, 167), // 鋤 (29-91)
+    (10, 170), // 除 (29-92)
+    (13, 9),   // 傷 (29-93)
+    (17, 9),   // 償 (29-94)
+    (12, 19),  // 勝 (30-01)
+    (6, 22),   // 匠 (30-02)
+    (4, 24),   // 升 (30-03)
+    (5, 30),   // 召 (30-04)
+    (10, 30),  // 哨 (30-05)
+    (11, 30),  // 商 (30-06)
+    (11, 30),  // 唱 (30-07)
+    (14, 30),  // 嘗 (30-08)
+    (13, 37),  // 奨 (30-09)
+    (8, 38),   // 妾 (30-10)
+    (11, 38),  // 娼 (30-11)
+    (10, 40),  // 宵 (30-12)
+    (10, 90),  // 将 (30-13)
+    (3, 42),   // 小 (30-14)
+    (4, 42),   // 少 (30-15)
+    (8, 42),   // 尚 (30-16)
+    (6, 53),   // 庄 (30-17)
+    (7, 53),   // 床 (30-18)
+    (15, 53),  // 廠 (30-19)
+    (14, 59),  // 彰 (30-20)
+    (8, 64),   // 承 (30-21)
+    (7, 64),   // 抄 (30-22)
+    (8, 64),   // 招 (30-23)
+    (12, 64),  // 掌 (30-24)
+    (11, 64),  // 捷 (30-25)
+    (8, 72),   // 昇 (30-26)
+    (8, 72),   // 昌 (30-27)
+    (9, 72),   // 昭 (30-28)
+    (12, 72),  // 晶 (30-29)
+    (8, 75),   // 松 (30-30)
+    (11, 75),  // 梢 (30-31)
+    (15, 75),  // 樟 (30-32)
+    (16, 75),  // 樵 (30-33)
+    (8, 85),   // 沼 (30-34)
+    (10, 85),  // 消 (30-35)
+    (11, 85),  // 渉 (30-36)
+    (12, 85),  // 湘 (30-37)
+    (12, 86),  // 焼 (30-38)
+    (12, 86),  // 焦 (30-39)
+    (13, 86),  // 照 (30-40)
+    (10, 104), // 症 (30-41)
+    (9, 109),  // 省 (30-42)
+    (12, 112), // 硝 (30-43)
+    (17, 112), // 礁 (30-44)
+    (10, 113), // 祥 (30-45)
+    (10, 115), // 称 (30-46)
+    (11, 117), // 章 (30-47)
+    (10, 118), // 笑 (30-48)
+    (12, 119), // 粧 (30-49)
+    (11, 120), // 紹 (30-50)
+    (7, 130),  // 肖 (30-51)
+    (11, 140), // 菖 (30-52)
+    (13, 140), // 蒋 (30-53)
+    (15, 140), // 蕉 (30-54)
+    (15, 144), // 衝 (30-55)
+    (14, 145), // 裳 (30-56)
+    (11, 149), // 訟 (30-57)
+    (12, 149), // 証 (30-58)
+    (12, 149), // 詔 (30-59)
+    (13, 149), // 詳 (30-60)
+    (12, 152), // 象 (30-61)
+    (15, 154), // 賞 (30-62)
+    (17, 164), // 醤 (30-63)
+    (13, 167), // 鉦 (30-64)
+    (17, 167), // 鍾 (30-65)
+    (20, 167), // 鐘 (30-66)
+    (14, 170), // 障 (30-67)
+    (16, 177), // 鞘 (30-68)
+    (3, 1),    // 上 (30-69)
+    (3, 1),    // 丈 (30-70)
+    (6, 1),    // 丞 (30-71)
+    (9, 4),    // 乗 (30-72)
+    (4, 14),   // 冗 (30-73)
+    (11, 18),  // 剰 (30-74)
+    (9, 32),   // 城 (30-75)
+    (12, 32),  // 場 (30-76)
+    (16, 32),  // 壌 (30-77)
+    (16, 38),  // 嬢 (30-78)
+    (11, 50),  // 常 (30-79)
+    (11, 61),  // 情 (30-80)
+    (18, 64),  // 擾 (30-81)
+    (7, 75),   // 条 (30-82)
+    (7, 75),   // 杖 (30-83)
+    (9, 85),   // 浄 (30-84)
+    (7, 94),   // 状 (30-85)
+    (12, 102), // 畳 (30-86)
+    (18, 115), // 穣 (30-87)
+    (13, 140), // 蒸 (30-88)
+    (20, 149), // 譲 (30-89)
+    (20, 164), // 醸 (30-90)
+    (16, 167), // 錠 (30-91)
+    (15, 30),  // 嘱 (30-92)
+    (11, 32),  // 埴 (30-93)
+    (13, 184), // 飾 (30-94)
+    (9, 64),   // 拭 (31-01)
+    (12, 75),  // 植 (31-02)
+    (12, 78),  // 殖 (31-03)
+    (17, 86),  // 燭 (31-04)
+    (18, 120), // 織 (31-05)
+    (18, 128), // 職 (31-06)
+    (6, 139),  // 色 (31-07)
+    (13, 148), // 触 (31-08)
+    (9, 184),  // 食 (31-09)
+    (14, 142), // 蝕 (31-10)
+    (10, 161), // 辱 (31-11)
+    (5, 44),   // 尻 (31-12)
+    (7, 9),    // 伸 (31-13)
+    (9, 9),    // 信 (31-14)
+    (9, 9),    // 侵 (31-15)
+    (10, 30),  // 唇 (31-16)
+    (10, 38),  // 娠 (31-17)
+    (13, 40),  // 寝 (31-18)
+    (15, 40),  // 審 (31-19)
+    (4, 61),   // 心 (31-20)
+    (13, 61),  // 慎 (31-21)
+    (10, 64),  // 振 (31-22)
+    (13, 69),  // 新 (31-23)
+    (10, 72),  // 晋 (31-24)
+    (12, 75),  // 森 (31-25)
+    (14, 75),  // 榛 (31-26)
+    (10, 85),  // 浸 (31-27)
+    (11, 85),  // 深 (31-28)
+    (5, 102),  // 申 (31-29)
+    (10, 104), // 疹 (31-30)
+    (10, 109), // 真 (31-31)
+    (9, 113),  // 神 (31-32)
+    (10, 115), // 秦 (31-33)
+    (11, 120), // 紳 (31-34)
+    (7, 131),  // 臣 (31-35)
+    (7, 140),  // 芯 (31-36)
+    (16, 140), // 薪 (31-37)
+    (16, 147), // 親 (31-38)
+    (12, 149), // 診 (31-39)
+    (7, 158),  // 身 (31-40)
+    (7, 160),  // 辛 (31-41)
+    (11, 162), // 進 (31-42)
+    (10, 167), // 針 (31-43)
+    (15, 173), // 震 (31-44)
+    (2, 9),    // 人 (31-45)
+    (4, 9),    // 仁 (31-46)
+    (3, 18),   // 刃 (31-47)
+    (14, 32),  // 塵 (31-48)
+    (4, 33),   // 壬 (31-49)
+    (12, 41),  // 尋 (31-50)
+    (9, 99),   // 甚 (31-51)
+    (6, 44),   // 尽 (31-52)
+    (13, 130), // 腎 (31-53)
+    (10, 149), // 訊 (31-54)
+    (6, 162),  // 迅 (31-55)
+    (10, 170), // 陣 (31-56)
+    (12, 177), // 靭 (31-57)
+    (11, 118), // 笥 (31-58)
+    (15, 149), // 諏 (31-59)
+    (12, 181), // 須 (31-60)
+    (12, 164), // 酢 (31-61)
+    (7, 31),   // 図 (31-62)
+    (12, 27),  // 厨 (31-63)
+    (10, 162), // 逗 (31-64)
+    (7, 30),   // 吹 (31-65)
+    (8, 32),   // 垂 (31-66)
+    (9, 50),   // 帥 (31-67)
+    (11, 64),  // 推 (31-68)
+    (4, 85),   // 水 (31-69)
+    (8, 86),   // 炊 (31-70)
+    (13, 109), // 睡 (31-71)
+    (10, 119), // 粋 (31-72)
+    (14, 124), // 翠 (31-73)
+    (10, 145), // 衰 (31-74)
+    (12, 162), // 遂 (31-75)
+    (11, 164), // 酔 (31-76)
+    (16, 167), // 錐 (31-77)
+    (16, 167), // 錘 (31-78)
+    (12, 170), // 随 (31-79)
+    (13, 96),  // 瑞 (31-80)
+    (19, 188), // 髄 (31-81)
+    (11, 46),  // 崇 (31-82)
+    (13, 46),  // 嵩 (31-83)
+    (13, 66),  // 数 (31-84)
+    (8, 75),   // 枢 (31-85)
+    (17, 156), // 趨 (31-86)
+    (18, 172), // 雛 (31-87)
+    (11, 64),  // 据 (31-88)
+    (7, 75),   // 杉 (31-89)
+    (12, 75),  // 椙 (31-90)
+    (11, 140), // 菅 (31-91)
+    (14, 181), // 頗 (31-92)
+    (11, 172), // 雀 (31-93)
+    (13, 145), // 裾 (31-94)
+    (15, 85),  // 澄 (32-01)
+    (14, 64),  // 摺 (32-02)
+    (3, 41),   // 寸 (32-03)
+    (5, 1),    // 世 (32-04)
+    (19, 85),  // 瀬 (32-05)
+    (10, 102), // 畝 (32-06)
+    (9, 72),   // 是 (32-07)
+    (10, 15),  // 凄 (32-08)
+    (8, 18),   // 制 (32-09)
+    (13, 19),  // 勢 (32-10)
+    (8, 38),   // 姓 (32-11)
+    (8, 60),   // 征 (32-12)
+    (8, 61),   // 性 (32-13)
+    (6, 62),   // 成 (32-14)
+    (9, 66),   // 政 (32-15)
+    (16, 66),  // 整 (32-16)
+    (9, 72),   // 星 (32-17)
+    (12, 72),  // 晴 (32-18)
+    (12, 75),  // 棲 (32-19)
+    (10, 75),  // 栖 (32-20)
+    (5, 77),   // 正 (32-21)
+    (11, 85),  // 清 (32-22)
+    (9, 93),   // 牲 (32-23)
+    (5, 100),  // 生 (32-24)
+    (11, 108), // 盛 (32-25)
+    (14, 119), // 精 (32-26)
+    (13, 128), // 聖 (32-27)
+    (7, 33),   // 声 (32-28)
+    (14, 145), // 製 (32-29)
+    (6, 146),  // 西 (32-30)
+    (13, 149), // 誠 (32-31)
+    (14, 149), // 誓 (32-32)
+    (15, 149), // 請 (32-33)
+    (10, 162), // 逝 (32-34)
+    (16, 164), // 醒 (32-35)
+    (8, 174),  // 青 (32-36)
+    (14, 174), // 静 (32-37)
+    (8, 210),  // 斉 (32-38)
+    (12, 115), // 税 (32-39)
+    (10, 130), // 脆 (32-40)
+    (10, 172), // 隻 (32-41)
+    (10, 50),  // 席 (32-42)
+    (11, 61),  // 惜 (32-43)
+    (11, 62),  // 戚 (32-44)
+    (5, 69),   // 斥 (32-45)
+    (8, 72),   // 昔 (32-46)
+    (8, 75),   // 析 (32-47)
+    (5, 112),  // 石 (32-48)
+    (16, 115), // 積 (32-49)
+    (20, 118), // 籍 (32-50)
+    (17, 120), // 績 (32-51)
+    (10, 130), // 脊 (32-52)
+    (11, 154), // 責 (32-53)
+    (7, 155),  // 赤 (32-54)
+    (13, 157), // 跡 (32-55)
+    (18, 157), // 蹟 (32-56)
+    (14, 112), // 碩 (32-57)
+    (4, 18),   // 切 (32-58)
+    (8, 64),   // 拙 (32-59)
+    (11, 64),  // 接 (32-60)
+    (13, 64),  // 摂 (32-61)
+    (7, 64),   // 折 (32-62)
+    (11, 149), // 設 (32-63)
+    (9, 116),  // 窃 (32-64)
+    (13, 118), // 節 (32-65)
+    (14, 149), // 説 (32-66)
+    (11, 173), // 雪 (32-67)
+    (12, 120), // 絶 (32-68)
+    (6, 135),  // 舌 (32-69)
+    (15, 142), // 蝉 (32-70)
+    (5, 9),    // 仙 (32-71)
+    (6, 10),   // 先 (32-72)
+    (3, 24),   // 千 (32-73)
+    (5, 25),   // 占 (32-74)
+    (9, 40),   // 宣 (32-75)
+    (9, 41),   // 専 (32-76)
+    (6, 42),   // 尖 (32-77)
+    (3, 47),   // 川 (32-78)
+    (13, 62),  // 戦 (32-79)
+    (10, 63),  // 扇 (32-80)
+    (15, 64),  // 撰 (32-81)
+    (10, 75),  // 栓 (32-82)
+    (10, 75),  // 栴 (32-83)
+    (9, 85),   // 泉 (32-84)
+    (9, 85),   // 浅 (32-85)
+    (9, 85),   // 洗 (32-86)
+    (9, 75),   // 染 (32-87)
+    (15, 85),  // 潜 (32-88)
+    (13, 86),  // 煎 (32-89)
+    (14, 86),  // 煽 (32-90)
+    (11, 70),  // 旋 (32-91)
+    (10, 116), // 穿 (32-92)
+    (15, 118), // 箭 (32-93)
+    (15, 120), // 線 (32-94)
+    (17, 120), // 繊 (33-01)
+    (13, 123), // 羨 (33-02)
+    (13, 130), // 腺 (33-03)
+    (6, 136),  // 舛 (33-04)
+    (11, 137), // 船 (33-05)
+    (16, 140), // 薦 (33-06)
+    (13, 149), // 詮 (33-07)
+    (13, 154), // 賎 (33-08)
+    (13, 157), // 践 (33-09)
+    (15, 162), // 選 (33-10)
+    (15, 162), // 遷 (33-11)
+    (14, 167), // 銭 (33-12)
+    (14, 167), // 銑 (33-13)
+    (10, 169), // 閃 (33-14)
+    (17, 195), // 鮮 (33-15)
+    (9, 18),   // 前 (33-16)
+    (12, 30),  // 善 (33-17)
+    (14, 85),  // 漸 (33-18)
+    (12, 86),  // 然 (33-19)
+    (6, 11),   // 全 (33-20)
+    (13, 113), // 禅 (33-21)
+    (18, 120), // 繕 (33-22)
+    (16, 130), // 膳 (33-23)
+    (15, 119), // 糎 (33-24)
+    (14, 30),  // 噌 (33-25)
+    (13, 32),  // 塑 (33-26)
+    (8, 46),   // 岨 (33-27)
+    (11, 64),  // 措 (33-28)
+    (12, 73),  // 曾 (33-29)
+    (11, 72),  // 曽 (33-30)
+    (13, 75),  // 楚 (33-31)
+    (8, 94),   // 狙 (33-32)
+    (12, 103), // 疏 (33-33)
+    (12, 103), // 疎 (33-34)
+    (18, 112), // 礎 (33-35)
+    (9, 113),  // 祖 (33-36)
+    (10, 115), // 租 (33-37)
+    (11, 119), // 粗 (33-38)
+    (10, 120), // 素 (33-39)
+    (11, 120), // 組 (33-40)
+    (19, 140), // 蘇 (33-41)
+    (12, 149), // 訴 (33-42)
+    (8, 170),  // 阻 (33-43)
+    (13, 162), // 遡 (33-44)
+    (13, 208), // 鼠 (33-45)
+    (13, 9),   // 僧 (33-46)
+    (12, 18),  // 創 (33-47)
+    (4, 29),   // 双 (33-48)
+    (18, 29),  // 叢 (33-49)
+    (10, 9),   // 倉 (33-50)
+    (12, 30),  // 喪 (33-51)
+    (6, 33),   // 壮 (33-52)
+    (9, 37),   // 奏 (33-53)
+    (11, 89),  // 爽 (33-54)
+    (7, 40),   // 宋 (33-55)
+    (14, 44),  // 層 (33-56)
+    (5, 22),   // 匝 (33-57)
+    (12, 61),  // 惣 (33-58)
+    (13, 61),  // 想 (33-59)
+    (10, 64),  // 捜 (33-60)
+    (11, 64),  // 掃 (33-61)
+    (10, 64),  // 挿 (33-62)
+    (11, 64),  // 掻 (33-63)
+    (16, 64),  // 操 (33-64)
+    (6, 72),   // 早 (33-65)
+    (11, 73),  // 曹 (33-66)
+    (11, 47),  // 巣 (33-67)
+    (14, 75),  // 槍 (33-68)
+    (15, 75),  // 槽 (33-69)
+    (14, 85),  // 漕 (33-70)
+    (17, 86),  // 燥 (33-71)
+    (6, 6),    // 争 (33-72)
+    (12, 104), // 痩 (33-73)
+    (9, 109),  // 相 (33-74)
+    (11, 116), // 窓 (33-75)
+    (17, 119), // 糟 (33-76)
+    (14, 120), // 総 (33-77)
+    (14, 120), // 綜 (33-78)
+    (14, 128), // 聡 (33-79)
+    (9, 140),  // 草 (33-80)
+    (9, 140),  // 荘 (33-81)
+    (12, 140), // 葬 (33-82)
+    (13, 140), // 蒼 (33-83)
+    (19, 140), // 藻 (33-84)
+    (12, 145), // 装 (33-85)
+    (7, 156),  // 走 (33-86)
+    (9, 162),  // 送 (33-87)
+    (14, 162), // 遭 (33-88)
+    (18, 167), // 鎗 (33-89)
+    (17, 173), // 霜 (33-90)
+    (18, 187), // 騒 (33-91)
+    (14, 9),   // 像 (33-92)
+    (14, 32),  // 増 (33-93)
+    (14, 61),  // 憎 (33-94)
+    (19, 130), // 臓 (34-01)
+    (15, 140), // 蔵 (34-02)
+    (18, 154), // 贈 (34-03)
+    (10, 162), // 造 (34-04)
+    (9, 9),    // 促 (34-05)
+    (11, 9),   // 側 (34-06)
+    (9, 18),   // 則 (34-07)
+    (7, 26),   // 即 (34-08)
+    (10, 61),  // 息 (34-09)
+    (10, 64),  // 捉 (34-10)
+    (7, 75),   // 束 (34-11)
+    (12, 85),  // 測 (34-12)
+    (7, 157),  // 足 (34-13)
+    (10, 162), // 速 (34-14)
+    (9, 9),    // 俗 (34-15)
+    (12, 44),  // 属 (34-16)
+    (13, 154), // 賊 (34-17)
+    (11, 70),  // 族 (34-18)
+    (13, 120), // 続 (34-19)
+    (8, 24),   // 卒 (34-20)
+    (10, 145), // 袖 (34-21)
+    (8, 12),   // 其 (34-22)
+    (12, 64),  // 揃 (34-23)
+    (6, 39),   // 存 (34-24)
+    (10, 39),  // 孫 (34-25)
+    (12, 41),  // 尊 (34-26)
+    (13, 64),  // 損 (34-27)
+    (7, 75),   // 村 (34-28)
+    (13, 162), // 遜 (34-29)
+    (5, 9),    // 他 (34-30)
+    (6, 36),   // 多 (34-31)
+    (4, 37),   // 太 (34-32)
+    (7, 85),   // 汰 (34-33)
+    (12, 149), // 詑 (34-34)
+    (11, 30),  // 唾 (34-35)
+    (12, 32),  // 堕 (34-36)
+    (7, 38),   // 妥 (34-37)
+    (12, 61),  // 惰 (34-38)
+    (5, 64),   // 打 (34-39)
+    (9, 75),   // 柁 (34-40)
+    (11, 137), // 舵 (34-41)
+    (13, 75),  // 楕 (34-42)
+    (8, 170),  // 陀 (34-43)
+    (14, 187), // 駄 (34-44)
+    (19, 187), // 騨 (34-45)
+    (7, 9),    // 体 (34-46)
+    (11, 32),  // 堆 (34-47)
+    (7, 41),   // 対 (34-48)
+    (9, 126),  // 耐 (34-49)
+    (8, 46),   // 岱 (34-50)
+    (10, 50),  // 帯 (34-51)
+    (9, 60),   // 待 (34-52)
+    (9, 61),   // 怠 (34-53)
+    (14, 61),  // 態 (34-54)
+    (17, 62),  // 戴 (34-55)
+    (12, 73),  // 替 (34-56)
+    (10, 85),  // 泰 (34-57)
+    (13, 85),  // 滞 (34-58)
+    (9, 130),  // 胎 (34-59)
+    (13, 130), // 腿 (34-60)
+    (8, 140),  // 苔 (34-61)
+    (11, 145), // 袋 (34-62)
+    (12, 154), // 貸 (34-63)
+    (9, 162),  // 退 (34-64)
+    (11, 162), // 逮 (34-65)
+    (12, 170), // 隊 (34-66)
+    (16, 203), // 黛 (34-67)
+    (19, 195), // 鯛 (34-68)
+    (5, 9),    // 代 (34-69)
+    (5, 30),   // 台 (34-70)
+    (3, 37),   // 大 (34-71)
+    (11, 118), // 第 (34-72)
+    (16, 164), // 醍 (34-73)
+    (18, 181), // 題 (34-74)
+    (24, 196), // 鷹 (34-75)
+    (13, 85),  // 滝 (34-76)
+    (19, 85),  // 瀧 (34-77)
+    (8, 24),   // 卓 (34-78)
+    (10, 30),  // 啄 (34-79)
+    (6, 40),   // 宅 (34-80)
+    (6, 64),   // 托 (34-81)
+    (7, 64),   // 択 (34-82)
+    (8, 64),   // 拓 (34-83)
+    (7, 85),   // 沢 (34-84)
+    (17, 85),  // 濯 (34-85)
+    (11, 96),  // 琢 (34-86)
+    (10, 149), // 託 (34-87)
+    (21, 167), // 鐸 (34-88)
+    (16, 85),  // 濁 (34-89)
+    (15, 149), // 諾 (34-90)
+    (9, 140),  // 茸 (34-91)
+    (5, 16),   // 凧 (34-92)
+    (13, 142), // 蛸 (34-93)
+    (5, 30),   // 只 (34-94)
+    (5, 30),   // 叩 (35-01)
+    (7, 9),    // 但 (35-02)
+    (12, 162), // 達 (35-03)
+    (7, 161),  // 辰 (35-04)
+    (14, 37),  // 奪 (35-05)
+    (11, 130), // 脱 (35-06)
+    (12, 49),  // 巽 (35-07)
+    (14, 117), // 竪 (35-08)
+    (6, 162),  // 辿 (35-09)
+    (12, 75),  // 棚 (35-10)
+    (7, 150),  // 谷 (35-11)
+    (10, 94),  // 狸 (35-12)
+    (22, 195), // 鱈 (35-13)
+    (16, 75),  // 樽 (35-14)
+    (15, 149), // 誰 (35-15)
+    (4, 3),    // 丹 (35-16)
+    (9, 24),   // 単 (35-17)
+    (13, 30),  // 嘆 (35-18)
+    (8, 32),   // 坦 (35-19)
+    (8, 64),   // 担 (35-20)
+    (11, 64),  // 探 (35-21)
+    (5, 72),   // 旦 (35-22)
+    (14, 76),  // 歎 (35-23)
+    (11, 85),  // 淡 (35-24)
+    (12, 85),  // 湛 (35-25)
+    (9, 86),   // 炭 (35-26)
+    (12, 111), // 短 (35-27)
+    (14, 117), // 端 (35-28)
+    (15, 118), // 箪 (35-29)
+    (14, 120), // 綻 (35-30)
+    (10, 128), // 耽 (35-31)
+    (9, 130),  // 胆 (35-32)
+    (11, 142), // 蛋 (35-33)
+    (15, 149), // 誕 (35-34)
+    (17, 167), // 鍛 (35-35)
+    (6, 31),   // 団 (35-36)
+    (16, 32),  // 壇 (35-37)
+    (12, 57),  // 弾 (35-38)
+    (11, 69),  // 断 (35-39)
+    (13, 72),  // 暖 (35-40)
+    (17, 75),  // 檀 (35-41)
+    (9, 79),   // 段 (35-42)
+    (7, 102),  // 男 (35-43)
+    (15, 149), // 談 (35-44)
+    (10, 9),   // 値 (35-45)
+    (8, 111),  // 知 (35-46)
+    (6, 32),   // 地 (35-47)
+    (6, 57),   // 弛 (35-48)
+    (10, 61),  // 恥 (35-49)
+    (12, 72),  // 智 (35-50)
+    (6, 85),   // 池 (35-51)
+    (13, 104), // 痴 (35-52)
+    (13, 115), // 稚 (35-53)
+    (13, 122), // 置 (35-54)
+    (10, 133), // 致 (35-55)
+    (14, 142), // 蜘 (35-56)
+    (12, 162), // 遅 (35-57)
+    (13, 187), // 馳 (35-58)
+    (16, 118), // 築 (35-59)
+    (10, 102), // 畜 (35-60)
+    (6, 118),  // 竹 (35-61)
+    (12, 118), // 筑 (35-62)
+    (13, 140), // 蓄 (35-63)
+    (10, 162), // 逐 (35-64)
+    (10, 115), // 秩 (35-65)
+    (11, 116), // 窒 (35-66)
+    (9, 140),  // 茶 (35-67)
+    (14, 38),  // 嫡 (35-68)
+    (12, 109), // 着 (35-69)
+    (4, 2),    // 中 (35-70)
+    (6, 9),    // 仲 (35-71)
+    (8, 40),   // 宙 (35-72)
+    (8, 61),   // 忠 (35-73)
+    (8, 64),   // 抽 (35-74)
+    (9, 44),   // 昼 (35-75)
+    (9, 75),   // 柱 (35-76)
+    (8, 85),   // 注 (35-77)
+    (6, 142),  // 虫 (35-78)
+    (9, 145),  // 衷 (35-79)
+    (12, 149), // 註 (35-80)
+    (10, 164), // 酎 (35-81)
+    (15, 167), // 鋳 (35-82)
+    (15, 187), // 駐 (35-83)
+    (15, 75),  // 樗 (35-84)
+    (18, 85),  // 瀦 (35-85)
+    (11, 94),  // 猪 (35-86)
+    (8, 140),  // 苧 (35-87)
+    (11, 140), // 著 (35-88)
+    (12, 154), // 貯 (35-89)
+    (2, 1),    // 丁 (35-90)
+    (6, 10),   // 兆 (35-91)
+    (10, 15),  // 凋 (35-92)
+    (12, 30),  // 喋 (35-93)
+    (19, 40),  // 寵 (35-94)
+    (8, 50),   // 帖 (36-01)
+    (11, 50),  // 帳 (36-02)
+    (5, 53),   // 庁 (36-03)
+    (4, 57),   // 弔 (36-04)
+    (11, 57),  // 張 (36-05)
+    (11, 59),  // 彫 (36-06)
+    (14, 60),  // 徴 (36-07)
+    (18, 61),  // 懲 (36-08)
+    (9, 64),   // 挑 (36-09)
+    (14, 72),  // 暢 (36-10)
+    (12, 74),  // 朝 (36-11)
+    (15, 85),  // 潮 (36-12)
+    (13, 91),  // 牒 (36-13)
+    (7, 102),  // 町 (36-14)
+    (11, 109), // 眺 (36-15)
+    (17, 128), // 聴 (36-16)
+    (12, 130), // 脹 (36-17)
+    (13, 130), // 腸 (36-18)
+    (15, 142), // 蝶 (36-19)
+    (15, 149), // 調 (36-20)
+    (16, 149), // 諜 (36-21)
+    (12, 156), // 超 (36-22)
+    (13, 157), // 跳 (36-23)
+    (14, 167), // 銚 (36-24)
+    (8, 168),  // 長 (36-25)
+    (11, 181), // 頂 (36-26)
+    (11, 196), // 鳥 (36-27)
+    (9, 19),   // 勅 (36-28)
+    (11, 64),  // 捗 (36-29)
+    (8, 109),  // 直 (36-30)
+    (10, 74),  // 朕 (36-31)
+    (7, 85),   // 沈 (36-32)
+    (9, 96),   // 珍 (36-33)
+    (13, 154), // 賃 (36-34)
+    (18, 167), // 鎮 (36-35)
+    (11, 170), // 陳 (36-36)
+    (9, 85),   // 津 (36-37)
+    (15, 32),  // 墜 (36-38)
+    (12, 75),  // 椎 (36-39)
+    (13, 75),  // 槌 (36-40)
+    (9, 162),  // 追 (36-41)
+    (17, 167), // 鎚 (36-42)
+    (12, 104), // 痛 (36-43)
+    (10, 162), // 通 (36-44)
+    (12, 32),  // 塚 (36-45)
+    (9, 75),   // 栂 (36-46)
+    (11, 64),  // 掴 (36-47)
+    (15, 75),  // 槻 (36-48)
+    (7, 9),    // 佃 (36-49)
+    (14, 85),  // 漬 (36-50)
+    (9, 75),   // 柘 (36-51)
+    (5, 162),  // 辻 (36-52)
+    (14, 140), // 蔦 (36-53)
+    (14, 120), // 綴 (36-54)
+    (17, 167), // 鍔 (36-55)
+    (13, 75),  // 椿 (36-56)
+    (15, 85),  // 潰 (36-57)
+    (8, 32),   // 坪 (36-58)
+    (11, 33),  // 壷 (36-59)
+    (17, 38),  // 嬬 (36-60)
+    (11, 120), // 紬 (36-61)
+    (4, 87),   // 爪 (36-62)
+    (6, 30),   // 吊 (36-63)
+    (11, 167), // 釣 (36-64)
+    (21, 196), // 鶴 (36-65)
+    (9, 8),    // 亭 (36-66)
+    (7, 9),    // 低 (36-67)
+    (11, 9),   // 停 (36-68)
+    (11, 9),   // 偵 (36-69)
+    (9, 18),   // 剃 (36-70)
+    (9, 154),  // 貞 (36-71)
+    (7, 30),   // 呈 (36-72)
+    (12, 32),  // 堤 (36-73)
+    (8, 40),   // 定 (36-74)
+    (9, 50),   // 帝 (36-75)
+    (8, 53),   // 底 (36-76)
+    (10, 53),  // 庭 (36-77)
+    (7, 54),   // 廷 (36-78)
+    (7, 57),   // 弟 (36-79)
+    (10, 61),  // 悌 (36-80)
+    (8, 64),   // 抵 (36-81)
+    (10, 64),  // 挺 (36-82)
+    (12, 64),  // 提 (36-83)
+    (11, 75),  // 梯 (36-84)
+    (5, 85),   // 汀 (36-85)
+    (13, 112), // 碇 (36-86)
+    (13, 113), // 禎 (36-87)
+    (12, 115), // 程 (36-88)
+    (15, 120), // 締 (36-89)
+    (13, 137), // 艇 (36-90)
+    (9, 149),  // 訂 (36-91)
+    (16, 149), // 諦 (36-92)
+    (16, 157), // 蹄 (36-93)
+    (10, 162), // 逓 (36-94)
+    (8, 163),  // 邸 (37-01)
+    (15, 163), // 鄭 (37-02)
+    (10, 167), // 釘 (37-03)
+    (13, 206), // 鼎 (37-04)
+    (8, 85),   // 泥 (37-05)
+    (14, 64),  // 摘 (37-06)
+    (17, 64),  // 擢 (37-07)
+    (15, 66),  // 敵 (37-08)
+    (14, 85),  // 滴 (37-09)
+    (8, 106),  // 的 (37-10)
+    (11, 118), // 笛 (37-11)
+    (14, 162), // 適 (37-12)
+    (19, 167), // 鏑 (37-13)
+    (13, 85),  // 溺 (37-14)
+    (10, 30),  // 哲 (37-15)
+    (15, 60),  // 徹 (37-16)
+    (15, 64),  // 撤 (37-17)
+    (19, 159), // 轍 (37-18)
+    (8, 162),  // 迭 (37-19)
+    (13, 167), // 鉄 (37-20)
+    (8, 12),   // 典 (37-21)
+    (13, 32),  // 填 (37-22)
+    (4, 37),   // 天 (37-23)
+    (10, 44),  // 展 (37-24)
+    (8, 53),   // 店 (37-25)
+    (11, 85),  // 添 (37-26)
+    (21, 120), // 纏 (37-27)
+    (11, 99),  // 甜 (37-28)
+    (12, 154), // 貼 (37-29)
+    (11, 159), // 転 (37-30)
+    (19, 181), // 顛 (37-31)
+    (9, 86),   // 点 (37-32)
+    (6, 9),    // 伝 (37-33)
+    (13, 79),  // 殿 (37-34)
+    (16, 85),  // 澱 (37-35)
+    (5, 102),  // 田 (37-36)
+    (13, 173), // 電 (37-37)
+    (8, 10),   // 兎 (37-38)
+    (6, 30),   // 吐 (37-39)
+    (11, 32),  // 堵 (37-40)
+    (13, 32),  // 塗 (37-41)
+    (8, 38),   // 妬 (37-42)
+    (11, 44),  // 屠 (37-43)
+    (10, 60),  // 徒 (37-44)
+    (4, 68),   // 斗 (37-45)
+    (7, 75),   // 杜 (37-46)
+    (12, 85),  // 渡 (37-47)
+    (12, 105), // 登 (37-48)
+    (12, 140), // 菟 (37-49)
+    (15, 154), // 賭 (37-50)
+    (10, 162), // 途 (37-51)
+    (11, 163), // 都 (37-52)
+    (17, 167), // 鍍 (37-53)
+    (10, 112), // 砥 (37-54)
+    (10, 112), // 砺 (37-55)
+    (7, 19),   // 努 (37-56)
+    (9, 53),   // 度 (37-57)
+    (3, 32),   // 土 (37-58)
+    (5, 38),   // 奴 (37-59)
+    (9, 61),   // 怒 (37-60)
+    (10, 9),   // 倒 (37-61)
+    (10, 10),  // 党 (37-62)
+    (5, 15),   // 冬 (37-63)
+    (10, 15),  // 凍 (37-64)
+    (2, 18),   // 刀 (37-65)
+    (10, 30),  // 唐 (37-66)
+    (12, 32),  // 塔 (37-67)
+    (13, 32),  // 塘 (37-68)
+    (10, 37),  // 套 (37-69)
+    (8, 40),   // 宕 (37-70)
+    (10, 46),  // 島 (37-71)
+    (14, 46),  // 嶋 (37-72)
+    (11, 61),  // 悼 (37-73)
+    (7, 64),   // 投 (37-74)
+    (12, 64),  // 搭 (37-75)
+    (8, 75),   // 東 (37-76)
+    (10, 75),  // 桃 (37-77)
+    (11, 75),  // 梼 (37-78)
+    (12, 75),  // 棟 (37-79)
+    (11, 108), // 盗 (37-80)
+    (11, 85),  // 淘 (37-81)
+    (12, 85),  // 湯 (37-82)
+    (10, 85),  // 涛 (37-83)
+    (6, 86),   // 灯 (37-84)
+    (16, 86),  // 燈 (37-85)
+    (6, 42),   // 当 (37-86)
+    (12, 104), // 痘 (37-87)
+    (11, 113), // 祷 (37-88)
+    (12, 118), // 等 (37-89)
+    (12, 118), // 答 (37-90)
+    (12, 118), // 筒 (37-91)
+    (16, 119), // 糖 (37-92)
+    (12, 120), // 統 (37-93)
+    (8, 18),   // 到 (37-94)
+    (12, 140), // 董 (38-01)
+    (15, 140), // 蕩 (38-02)
+    (18, 140), // 藤 (38-03)
+    (10, 149), // 討 (38-04)
+    (17, 149), // 謄 (38-05)
+    (7, 151),  // 豆 (38-06)
+    (15, 157), // 踏 (38-07)
+    (9, 162),  // 逃 (38-08)
+    (10, 162), // 透 (38-09)
+    (20, 167), // 鐙 (38-10)
+    (11, 170), // 陶 (38-11)
+    (16, 181), // 頭 (38-12)
+    (20, 187), // 騰 (38-13)
+    (18, 169), // 闘 (38-14)
+    (13, 9),   // 働 (38-15)
+    (11, 19),  // 動 (38-16)
+    (6, 30),   // 同 (38-17)
+    (11, 32),  // 堂 (38-18)
+    (15, 41),  // 導 (38-19)
+    (15, 61),  // 憧 (38-20)
+    (15, 64),  // 撞 (38-21)
+    (9, 85),   // 洞 (38-22)
+    (17, 109), // 瞳 (38-23)
+    (12, 117), // 童 (38-24)
+    (10, 130), // 胴 (38-25)
+    (11, 140), // 萄 (38-26)
+    (12, 162), // 道 (38-27)
+    (14, 167), // 銅 (38-28)
+    (9, 46),   // 峠 (38-29)
+    (15, 196), // 鴇 (38-30)
+    (10, 23),  // 匿 (38-31)
+    (11, 60),  // 得 (38-32)
+    (14, 60),  // 徳 (38-33)
+    (10, 85),  // 涜 (38-34)
+    (10, 93),  // 特 (38-35)
+    (13, 109), // 督 (38-36)
+    (7, 115),  // 禿 (38-37)
+    (16, 118), // 篤 (38-38)
+    (8, 80),   // 毒 (38-39)
+    (9, 94),   // 独 (38-40)
+    (14, 149), // 読 (38-41)
+    (9, 75),   // 栃 (38-42)
+    (16, 75),  // 橡 (38-43)
+    (5, 17),   // 凸 (38-44)
+    (8, 116),  // 突 (38-45)
+    (13, 75),  // 椴 (38-46)
+    (8, 44),   // 届 (38-47)
+    (14, 196), // 鳶 (38-48)
+    (8, 140),  // 苫 (38-49)
+    (11, 40),  // 寅 (38-50)
+    (7, 164),  // 酉 (38-51)
+    (17, 85),  // 瀞 (38-52)
+    (16, 30),  // 噸 (38-53)
+    (4, 45),   // 屯 (38-54)
+    (11, 61),  // 惇 (38-55)
+    (12, 66),  // 敦 (38-56)
+    (7, 85),   // 沌 (38-57)
+    (11, 152), // 豚 (38-58)
+    (12, 162), // 遁 (38-59)
+    (13, 181), // 頓 (38-60)
+    (7, 30),   // 呑 (38-61)
+    (16, 72),  // 曇 (38-62)
+    (12, 167), // 鈍 (38-63)
+    (8, 37),   // 奈 (38-64)
+    (7, 163),  // 那 (38-65)
+    (4, 11),   // 内 (38-66)
+    (5, 4),    // 乍 (38-67)
+    (6, 16),   // 凪 (38-68)
+    (16, 140), // 薙 (38-69)
+    (16, 149), // 謎 (38-70)
+    (21, 85),  // 灘 (38-71)
+    (11, 64),  // 捺 (38-72)
+    (17, 167), // 鍋 (38-73)
+    (13, 75),  // 楢 (38-74)
+    (13, 187), // 馴 (38-75)
+    (15, 120), // 縄 (38-76)
+    (13, 102), // 畷 (38-77)
+    (9, 24),   // 南 (38-78)
+    (13, 75),  // 楠 (38-79)
+    (11, 159), // 軟 (38-80)
+    (18, 172), // 難 (38-81)
+    (6, 85),   // 汝 (38-82)
+    (2, 7),    // 二 (38-83)
+    (5, 44),   // 尼 (38-84)
+    (6, 56),   // 弐 (38-85)
+    (8, 162),  // 迩 (38-86)
+    (4, 20),   // 匂 (38-87)
+    (14, 154), // 賑 (38-88)
+    (6, 130),  // 肉 (38-89)
+    (9, 142),  // 虹 (38-90)
+    (4, 55),   // 廿 (38-91)
+    (4, 72),   // 日 (38-92)
+    (8, 5),    // 乳 (38-93)
+    (2, 11),   // 入 (38-94)
+    (6, 38),   // 如 (39-01)
+    (7, 44),   // 尿 (39-02)
+    (12, 179), // 韮 (39-03)
+    (6, 9),    // 任 (39-04)
+    (7, 38),   // 妊 (39-05)
+    (7, 61),   // 忍 (39-06)
+    (14, 149), // 認 (39-07)
+    (17, 85),  // 濡 (39-08)
+    (18, 113), // 禰 (39-09)
+    (9, 113),  // 祢 (39-10)
+    (14, 40),  // 寧 (39-11)
+    (12, 140), // 葱 (39-12)
+    (11, 94),  // 猫 (39-13)
+    (15, 86),  // 熱 (39-14)
+    (6, 51),   // 年 (39-15)
+    (8, 61),   // 念 (39-16)
+    (11, 64),  // 捻 (39-17)
+    (15, 64),  // 撚 (39-18)
+    (16, 86),  // 燃 (39-19)
+    (11, 119), // 粘 (39-20)
+    (2, 4),    // 乃 (39-21)
+    (9, 54),   // 廼 (39-22)
+    (3, 4),    // 之 (39-23)
+    (11, 32),  // 埜 (39-24)
+    (18, 30),  // 嚢 (39-25)
+    (10, 61),  // 悩 (39-26)
+    (16, 85),  // 濃 (39-27)
+    (10, 120), // 納 (39-28)
+    (10, 130), // 能 (39-29)
+    (11, 130), // 脳 (39-30)
+    (17, 130), // 膿 (39-31)
+    (13, 161), // 農 (39-32)
+    (12, 147), // 覗 (39-33)
+    (10, 142), // 蚤 (39-34)
+    (4, 49),   // 巴 (39-35)
+    (7, 64),   // 把 (39-36)
+    (15, 64),  // 播 (39-37)
+    (19, 146), // 覇 (39-38)
+    (8, 75),   // 杷 (39-39)
+    (8, 85),   // 波 (39-40)
+    (9, 85),   // 派 (39-41)
+    (12, 96),  // 琶 (39-42)
+    (10, 112), // 破 (39-43)
+    (11, 38),  // 婆 (39-44)
+    (15, 122), // 罵 (39-45)
+    (7, 140),  // 芭 (39-46)
+    (10, 187), // 馬 (39-47)
+    (10, 9),   // 俳 (39-48)
+    (12, 53),  // 廃 (39-49)
+    (8, 64),   // 拝 (39-50)
+    (11, 64),  // 排 (39-51)
+    (11, 66),  // 敗 (39-52)
+    (8, 75),   // 杯 (39-53)
+    (9, 108),  // 盃 (39-54)
+    (13, 91),  // 牌 (39-55)
+    (9, 130),  // 背 (39-56)
+    (9, 130),  // 肺 (39-57)
+    (15, 159), // 輩 (39-58)
+    (10, 164), // 配 (39-59)
+    (10, 9),   // 倍 (39-60)
+    (11, 32),  // 培 (39-61)
+    (12, 38),  // 媒 (39-62)
+    (10, 75),  // 梅 (39-63)
+    (13, 75),  // 楳 (39-64)
+    (13, 86),  // 煤 (39-65)
+    (10, 94),  // 狽 (39-66)
+    (12, 154), // 買 (39-67)
+    (7, 33),   // 売 (39-68)
+    (15, 154), // 賠 (39-69)
+    (11, 170), // 陪 (39-70)
+    (10, 162), // 這 (39-71)
+    (15, 142), // 蝿 (39-72)
+    (10, 115), // 秤 (39-73)
+    (9, 111),  // 矧 (39-74)
+    (12, 140), // 萩 (39-75)
+    (7, 9),    // 伯 (39-76)
+    (10, 18),  // 剥 (39-77)
+    (12, 24),  // 博 (39-78)
+    (8, 64),   // 拍 (39-79)
+    (9, 75),   // 柏 (39-80)
+    (8, 85),   // 泊 (39-81)
+    (5, 106),  // 白 (39-82)
+    (14, 118), // 箔 (39-83)
+    (11, 119), // 粕 (39-84)
+    (11, 137), // 舶 (39-85)
+    (16, 140), // 薄 (39-86)
+    (8, 162),  // 迫 (39-87)
+    (19, 72),  // 曝 (39-88)
+    (13, 85),  // 漠 (39-89)
+    (19, 86),  // 爆 (39-90)
+    (16, 120), // 縛 (39-91)
+    (10, 140), // 莫 (39-92)
+    (14, 187), // 駁 (39-93)
+    (7, 199),  // 麦 (39-94)
+    (8, 17),   // 函 (40-01)
+    (15, 118), // 箱 (40-02)
+    (12, 112), // 硲 (40-03)
+    (14, 118), // 箸 (40-04)
+    (14, 129), // 肇 (40-05)
+    (12, 118), // 筈 (40-06)
+    (20, 75),  // 櫨 (40-07)
+    (15, 50),  // 幡 (40-08)
+    (6, 130),  // 肌 (40-09)
+    (9, 86),   // 畑 (40-10)
+    (10, 106), // 畠 (40-11)
+    (2, 12),   // 八 (40-12)
+    (13, 167), // 鉢 (40-13)
+    (12, 85),  // 溌 (40-14)
+    (9, 105),  // 発 (40-15)
+    (16, 164), // 醗 (40-16)
+    (14, 190), // 髪 (40-17)
+    (6, 9),    // 伐 (40-18)
+    (14, 122), // 罰 (40-19)
+    (7, 64),   // 抜 (40-20)
+    (12, 118), // 筏 (40-21)
+    (14, 169), // 閥 (40-22)
+    (13, 196), // 鳩 (40-23)
+    (16, 30),  // 噺 (40-24)
+    (13, 32),  // 塙 (40-25)
+    (12, 142), // 蛤 (40-26)
+    (10, 172), // 隼 (40-27)
+    (7, 9),    // 伴 (40-28)
+    (7, 18),   // 判 (40-29)
+    (5, 24),   // 半 (40-30)
+    (4, 29),   // 反 (40-31)
+    (9, 29),   // 叛 (40-32)
+    (6, 50),   // 帆 (40-33)
+    (13, 64),  // 搬 (40-34)
+    (12, 67),  // 斑 (40-35)
+    (8, 75),   // 板 (40-36)
+    (5, 85),   // 氾 (40-37)
+    (6, 85),   // 汎 (40-38)
+    (8, 91),   // 版 (40-39)
+    (5, 94),   // 犯 (40-40)
+    (10, 96),  // 班 (40-41)
+    (10, 102), // 畔 (40-42)
+    (16, 120), // 繁 (40-43)
+    (10, 137), // 般 (40-44)
+    (18, 140), // 藩 (40-45)
+    (11, 154), // 販 (40-46)
+    (15, 118), // 範 (40-47)
+    (7, 165),  // 釆 (40-48)
+    (13, 86),  // 煩 (40-49)
+    (13, 181), // 頒 (40-50)
+    (12, 184), // 飯 (40-51)
+    (11, 64),  // 挽 (40-52)
+    (12, 72),  // 晩 (40-53)
+    (12, 102), // 番 (40-54)
+    (15, 108), // 盤 (40-55)
+    (15, 112), // 磐 (40-56)
+    (15, 140), // 蕃 (40-57)
+    (12, 142), // 蛮 (40-58)
+    (10, 22),  // 匪 (40-59)
+    (9, 24),   // 卑 (40-60)
+    (7, 30),   // 否 (40-61)
+    (6, 38),   // 妃 (40-62)
+    (7, 53),   // 庇 (40-63)
+    (8, 60),   // 彼 (40-64)
+    (12, 61),  // 悲 (40-65)
+    (12, 63),  // 扉 (40-66)
+    (7, 64),   // 批 (40-67)
+    (8, 64),   // 披 (40-68)
+    (12, 67),  // 斐 (40-69)
+    (4, 81),   // 比 (40-70)
+    (8, 85),   // 泌 (40-71)
+    (10, 104), // 疲 (40-72)
+    (5, 107),  // 皮 (40-73)
+    (14, 112), // 碑 (40-74)
+    (10, 115), // 秘 (40-75)
+    (14, 120), // 緋 (40-76)
+    (15, 122), // 罷 (40-77)
+    (8, 130),  // 肥 (40-78)
+    (10, 145), // 被 (40-79)
+    (15, 149), // 誹 (40-80)
+    (12, 154), // 費 (40-81)
+    (16, 162), // 避 (40-82)
+    (8, 175),  // 非 (40-83)
+    (9, 183),  // 飛 (40-84)
+    (14, 75),  // 樋 (40-85)
+    (19, 118), // 簸 (40-86)
+    (12, 9),   // 備 (40-87)
+    (7, 44),   // 尾 (40-88)
+    (13, 60),  // 微 (40-89)
+    (8, 75),   // 枇 (40-90)
+    (9, 81),   // 毘 (40-91)
+    (12, 96),  // 琵 (40-92)
+    (9, 109),  // 眉 (40-93)
+    (9, 123),  // 美 (40-94)
+    (14, 209), // 鼻 (41-01)
+    (9, 75),   // 柊 (41-02)
+    (14, 115), // 稗 (41-03)
+    (4, 23),   // 匹 (41-04)
+    (5, 103),  // 疋 (41-05)
+    (16, 190), // 髭 (41-06)
+    (9, 59),   // 彦 (41-07)
+    (15, 130), // 膝 (41-08)
+    (11, 140), // 菱 (41-09)
+    (7, 130),  // 肘 (41-10)
+    (12, 57),  // 弼 (41-11)
+    (5, 61),   // 必 (41-12)
+    (11, 102), // 畢 (41-13)
+    (12, 118), // 筆 (41-14)
+    (12, 162), // 逼 (41-15)
+    (10, 75),  // 桧 (41-16)
+    (10, 38),  // 姫 (41-17)
+    (12, 38),  // 媛 (41-18)
+    (10, 120), // 紐 (41-19)
+    (6, 106),  // 百 (41-20)
+    (18, 149), // 謬 (41-21)
+    (10, 9),   // 俵 (41-22)
+    (11, 59),  // 彪 (41-23)
+    (15, 75),  // 標 (41-24)
+    (5, 85),   // 氷 (41-25)
+    (14, 85),  // 漂 (41-26)
+    (17, 97),  // 瓢 (41-27)
+    (11, 113), // 票 (41-28)
+    (8, 145),  // 表 (41-29)
+    (12, 149), // 評 (41-30)
+    (10, 153), // 豹 (41-31)
+    (15, 53),  // 廟 (41-32)
+    (11, 64),  // 描 (41-33)
+    (10, 104), // 病 (41-34)
+    (9, 115),  // 秒 (41-35)
+    (8, 140),  // 苗 (41-36)
+    (16, 167), // 錨 (41-37)
+    (15, 167), // 鋲 (41-38)
+    (13, 140), // 蒜 (41-39)
+    (12, 142), // 蛭 (41-40)
+    (21, 195), // 鰭 (41-41)
+    (9, 30),   // 品 (41-42)
+    (11, 59),  // 彬 (41-43)
+    (12, 67),  // 斌 (41-44)
+    (10, 85),  // 浜 (41-45)
+    (20, 85),  // 瀕 (41-46)
+    (11, 154), // 貧 (41-47)
+    (15, 154), // 賓 (41-48)
+    (17, 181), // 頻 (41-49)
+    (10, 66),  // 敏 (41-50)
+    (11, 98),  // 瓶 (41-51)
+    (4, 1),    // 不 (41-52)
+    (5, 9),    // 付 (41-53)
+    (11, 32),  // 埠 (41-54)
+    (4, 37),   // 夫 (41-55)
+    (11, 38),  // 婦 (41-56)
+    (12, 40),  // 富 (41-57)
+    (11, 14),  // 冨 (41-58)
+    (5, 50),   // 布 (41-59)
+    (8, 53),   // 府 (41-60)
+    (8, 61),   // 怖 (41-61)
+    (7, 64),   // 扶 (41-62)
+    (15, 66),  // 敷 (41-63)
+    (8, 69),   // 斧 (41-64)
+    (12, 72),  // 普 (41-65)
+    (10, 85),  // 浮 (41-66)
+    (4, 88),   // 父 (41-67)
+    (11, 118), // 符 (41-68)
+    (14, 130), // 腐 (41-69)
+    (15, 130), // 膚 (41-70)
+    (7, 140),  // 芙 (41-71)
+    (19, 149), // 譜 (41-72)
+    (9, 154),  // 負 (41-73)
+    (15, 154), // 賦 (41-74)
+    (9, 156),  // 赴 (41-75)
+    (8, 170),  // 阜 (41-76)
+    (8, 170),  // 附 (41-77)
+    (8, 9),    // 侮 (41-78)
+    (15, 64),  // 撫 (41-79)
+    (8, 77),   // 武 (41-80)
+    (15, 136), // 舞 (41-81)
+    (12, 140), // 葡 (41-82)
+    (15, 140), // 蕪 (41-83)
+    (11, 163), // 部 (41-84)
+    (9, 41),   // 封 (41-85)
+    (13, 75),  // 楓 (41-86)
+    (9, 182),  // 風 (41-87)
+    (12, 140), // 葺 (41-88)
+    (16, 140), // 蕗 (41-89)
+    (6, 9),    // 伏 (41-90)
+    (11, 18),  // 副 (41-91)
+    (12, 60),  // 復 (41-92)
+    (12, 50),  // 幅 (41-93)
+    (8, 74),   // 服 (41-94)
+    (13, 113), // 福 (42-01)
+    (13, 130), // 腹 (42-02)
+    (14, 145), // 複 (42-03)
+    (18, 146), // 覆 (42-04)
+    (12, 85),  // 淵 (42-05)
+    (5, 57),   // 弗 (42-06)
+    (5, 64),   // 払 (42-07)
+    (8, 85),   // 沸 (42-08)
+    (4, 9),    // 仏 (42-09)
+    (8, 93),   // 物 (42-10)
+    (16, 195), // 鮒 (42-11)
+    (4, 18),   // 分 (42-12)
+    (7, 30),   // 吻 (42-13)
+    (15, 30),  // 噴 (42-14)
+    (15, 32),  // 墳 (42-15)
+    (15, 61),  // 憤 (42-16)
+    (7, 64),   // 扮 (42-17)
+    (12, 86),  // 焚 (42-18)
+    (16, 37),  // 奮 (42-19)
+    (10, 119), // 粉 (42-20)
+    (17, 119), // 糞 (42-21)
+    (10, 120), // 紛 (42-22)
+    (12, 173), // 雰 (42-23)
+    (4, 67),   // 文 (42-24)
+    (14, 128), // 聞 (42-25)
+    (5, 1),    // 丙 (42-26)
+    (8, 9),    // 併 (42-27)
+    (7, 12),   // 兵 (42-28)
+    (12, 32),  // 塀 (42-29)
+    (15, 50),  // 幣 (42-30)
+    (5, 51),   // 平 (42-31)
+    (15, 55),  // 弊 (42-32)
+    (9, 75),   // 柄 (42-33)
+    (8, 1),    // 並 (42-34)
+    (15, 140), // 蔽 (42-35)
+    (11, 169), // 閉 (42-36)
+    (10, 170), // 陛 (42-37)
+    (6, 119),  // 米 (42-38)
+    (9, 181),  // 頁 (42-39)
+    (15, 9),   // 僻 (42-40)
+    (16, 32),  // 壁 (42-41)
+    (18, 104), // 癖 (42-42)
+    (14, 112), // 碧 (42-43)
+    (7, 18),   // 別 (42-44)
+    (17, 109), // 瞥 (42-45)
+    (14, 140), // 蔑 (42-46)
+    (14, 118), // 箆 (42-47)
+    (11, 9),   // 偏 (42-48)
+    (9, 35),   // 変 (42-49)
+    (4, 91),   // 片 (42-50)
+    (15, 118), // 篇 (42-51)
+    (15, 120), // 編 (42-52)
+    (5, 162),  // 辺 (42-53)
+    (7, 162),  // 返 (42-54)
+    (12, 162), // 遍 (42-55)
+    (9, 9),    // 便 (42-56)
+    (10, 19),  // 勉 (42-57)
+    (11, 38),  // 娩 (42-58)
+    (5, 55),   // 弁 (42-59)
+    (18, 177), // 鞭 (42-60)
+    (9, 9),    // 保 (42-61)
+    (15, 135), // 舗 (42-62)
+    (15, 167), // 鋪 (42-63)
+    (10, 31),  // 圃 (42-64)
+    (10, 64),  // 捕 (42-65)
+    (8, 77),   // 歩 (42-66)
+    (7, 101),  // 甫 (42-67)
+    (12, 145), // 補 (42-68)
+    (14, 159), // 輔 (42-69)
+    (15, 115), // 穂 (42-70)
+    (12, 19),  // 募 (42-71)
+    (13, 32),  // 墓 (42-72)
+    (14, 61),  // 慕 (42-73)
+    (5, 62),   // 戊 (42-74)
+    (14, 72),  // 暮 (42-75)
+    (5, 80),   // 母 (42-76)
+    (19, 118), // 簿 (42-77)
+    (11, 140), // 菩 (42-78)
+    (10, 9),   // 倣 (42-79)
+    (10, 9),   // 俸 (42-80)
+    (5, 20),   // 包 (42-81)
+    (7, 30),   // 呆 (42-82)
+    (12, 32),  // 報 (42-83)
+    (8, 37),   // 奉 (42-84)
+    (8, 40),   // 宝 (42-85)
+    (10, 46),  // 峰 (42-86)
+    (10, 46),  // 峯 (42-87)
+    (11, 46),  // 崩 (42-88)
+    (8, 53),   // 庖 (42-89)
+    (8, 64),   // 抱 (42-90)
+    (11, 64),  // 捧 (42-91)
+    (8, 66),   // 放 (42-92)
+    (4, 70),   // 方 (42-93)
+    (8, 74),   // 朋 (42-94)
+    (8, 85),   // 法 (43-01)
+    (8, 85),   // 泡 (43-02)
+    (11, 86),  // 烹 (43-03)
+    (10, 112), // 砲 (43-04)
+    (16, 120), // 縫 (43-05)
+    (9, 130),  // 胞 (43-06)
+    (7, 140),  // 芳 (43-07)
+    (11, 140), // 萌 (43-08)
+    (13, 140), // 蓬 (43-09)
+    (13, 142), // 蜂 (43-10)
+    (15, 145), // 褒 (43-11)
+    (11, 149), // 訪 (43-12)
+    (13, 151), // 豊 (43-13)
+    (7, 163),  // 邦 (43-14)
+    (15, 167), // 鋒 (43-15)
+    (13, 184), // 飽 (43-16)
+    (14, 196), // 鳳 (43-17)
+    (19, 196), // 鵬 (43-18)
+    (4, 4),    // 乏 (43-19)
+    (3, 8),    // 亡 (43-20)
+    (12, 9),   // 傍 (43-21)
+    (10, 18),  // 剖 (43-22)
+    (7, 32),   // 坊 (43-23)
+    (7, 38),   // 妨 (43-24)
+    (12, 50),  // 帽 (43-25)
+    (7, 61),   // 忘 (43-26)
+    (6, 61),   // 忙 (43-27)
+    (8, 63),   // 房 (43-28)
+    (15, 72),  // 暴 (43-29)
+    (11, 74),  // 望 (43-30)
+    (9, 75),   // 某 (43-31)
+    (12, 75),  // 棒 (43-32)
+    (9, 13),   // 冒 (43-33)
+    (10, 120), // 紡 (43-34)
+    (8, 130),  // 肪 (43-35)
+    (16, 130), // 膨 (43-36)
+    (16, 149), // 謀 (43-37)
+    (14, 153), // 貌 (43-38)
+    (12, 154), // 貿 (43-39)
+    (14, 167), // 鉾 (43-40)
+    (7, 170),  // 防 (43-41)
+    (7, 30),   // 吠 (43-42)
+    (15, 181), // 頬 (43-43)
+    (5, 21),   // 北 (43-44)
+    (14, 9),   // 僕 (43-45)
+    (2, 25),   // 卜 (43-46)
+    (14, 32),  // 墨 (43-47)
+    (15, 64),  // 撲 (43-48)
+    (6, 75),   // 朴 (43-49)
+    (8, 93),   // 牧 (43-50)
+    (13, 109), // 睦 (43-51)
+    (16, 115), // 穆 (43-52)
+    (11, 167), // 釦 (43-53)
+    (9, 19),   // 勃 (43-54)
+    (7, 85),   // 没 (43-55)
+    (9, 78),   // 殆 (43-56)
+    (11, 32),  // 堀 (43-57)
+    (13, 50),  // 幌 (43-58)
+    (8, 37),   // 奔 (43-59)
+    (5, 75),   // 本 (43-60)
+    (18, 124), // 翻 (43-61)
+    (3, 16),   // 凡 (43-62)
+    (9, 108),  // 盆 (43-63)
+    (15, 64),  // 摩 (43-64)
+    (16, 112), // 磨 (43-65)
+    (21, 194), // 魔 (43-66)
+    (11, 200), // 麻 (43-67)
+    (10, 32),  // 埋 (43-68)
+    (8, 38),   // 妹 (43-69)
+    (9, 72),   // 昧 (43-70)
+    (8, 75),   // 枚 (43-71)
+    (6, 80),   // 毎 (43-72)
+    (10, 30),  // 哩 (43-73)
+    (14, 75),  // 槙 (43-74)
+    (13, 50),  // 幕 (43-75)
+    (14, 130), // 膜 (43-76)
+    (8, 75),   // 枕 (43-77)
+    (17, 195), // 鮪 (43-78)
+    (9, 75),   // 柾 (43-79)
+    (23, 195), // 鱒 (43-80)
+    (11, 75),  // 桝 (43-81)
+    (6, 8),    // 亦 (43-82)
+    (9, 9),    // 俣 (43-83)
+    (2, 29),   // 又 (43-84)
+    (8, 64),   // 抹 (43-85)
+    (5, 75),   // 末 (43-86)
+    (8, 85),   // 沫 (43-87)
+    (6, 162),  // 迄 (43-88)
+    (8, 9),    // 侭 (43-89)
+    (18, 120), // 繭 (43-90)
+    (18, 200), // 麿 (43-91)
+    (3, 1),    // 万 (43-92)
+    (14, 61),  // 慢 (43-93)
+    (12, 85),  // 満 (43-94)
+    (14, 85),  // 漫 (44-01)
+    (14, 140), // 蔓 (44-02)
+    (8, 30),   // 味 (44-03)
+    (5, 75),   // 未 (44-04)
+    (15, 194), // 魅 (44-05)
+    (3, 49),   // 巳 (44-06)
+    (14, 118), // 箕 (44-07)
+    (8, 46),   // 岬 (44-08)
+    (11, 40),  // 密 (44-09)
+    (14, 142), // 蜜 (44-10)
+    (12, 85),  // 湊 (44-11)
+    (13, 140), // 蓑 (44-12)
+    (13, 115), // 稔 (44-13)
+    (10, 130), // 脈 (44-14)
+    (7, 38),   // 妙 (44-15)
+    (10, 119), // 粍 (44-16)
+    (5, 83),   // 民 (44-17)
+    (10, 109), // 眠 (44-18)
+    (11, 19),  // 務 (44-19)
+    (13, 36),  // 夢 (44-20)
+    (12, 86),  // 無 (44-21)
+    (6, 93),   // 牟 (44-22)
+    (5, 110),  // 矛 (44-23)
+    (19, 173), // 霧 (44-24)
+    (19, 196), // 鵡 (44-25)
+    (12, 75),  // 椋 (44-26)
+    (12, 38),  // 婿 (44-27)
+    (10, 38),  // 娘 (44-28)
+    (10, 14),  // 冥 (44-29)
+    (6, 30),   // 名 (44-30)
+    (8, 30),   // 命 (44-31)
+    (8, 72),   // 明 (44-32)
+    (13, 108), // 盟 (44-33)
+    (9, 162),  // 迷 (44-34)
+    (14, 167), // 銘 (44-35)
+    (14, 196), // 鳴 (44-36)
+    (9, 38),   // 姪 (44-37)
+    (6, 93),   // 牝 (44-38)
+    (13, 85),  // 滅 (44-39)
+    (8, 10),   // 免 (44-40)
+    (12, 75),  // 棉 (44-41)
+    (14, 120), // 綿 (44-42)
+    (15, 120), // 緬 (44-43)
+    (9, 176),  // 面 (44-44)
+    (16, 199), // 麺 (44-45)
+    (13, 64),  // 摸 (44-46)
+    (14, 75),  // 模 (44-47)
+    (8, 140),  // 茂 (44-48)
+    (6, 38),   // 妄 (44-49)
+    (8, 39),   // 孟 (44-50)
+    (4, 82),   // 毛 (44-51)
+    (11, 94),  // 猛 (44-52)
+    (8, 109),  // 盲 (44-53)
+    (14, 120), // 網 (44-54)
+    (10, 127), // 耗 (44-55)
+    (13, 140), // 蒙 (44-56)
+    (17, 9),   // 儲 (44-57)
+    (4, 75),   // 木 (44-58)
+    (15, 203), // 黙 (44-59)
+    (5, 109),  // 目 (44-60)
+    (7, 75),   // 杢 (44-61)
+    (4, 20),   // 勿 (44-62)
+    (14, 184), // 餅 (44-63)
+    (4, 43),   // 尤 (44-64)
+    (7, 63),   // 戻 (44-65)
+    (9, 119),  // 籾 (44-66)
+    (12, 154), // 貰 (44-67)
+    (11, 30),  // 問 (44-68)
+    (12, 61),  // 悶 (44-69)
+    (10, 120), // 紋 (44-70)
+    (8, 169),  // 門 (44-71)
+    (4, 20),   // 匁 (44-72)
+    (3, 5),    // 也 (44-73)
+    (7, 15),   // 冶 (44-74)
+    (8, 36),   // 夜 (44-75)
+    (13, 88),  // 爺 (44-76)
+    (9, 128),  // 耶 (44-77)
+    (11, 166), // 野 (44-78)
+    (8, 57),   // 弥 (44-79)
+    (5, 111),  // 矢 (44-80)
+    (4, 27),   // 厄 (44-81)
+    (7, 60),   // 役 (44-82)
+    (9, 120),  // 約 (44-83)
+    (16, 140), // 薬 (44-84)
+    (11, 149), // 訳 (44-85)
+    (21, 157), // 躍 (44-86)
+    (13, 174), // 靖 (44-87)
+    (9, 75),   // 柳 (44-88)
+    (16, 140), // 薮 (44-89)
+    (21, 167), // 鑓 (44-90)
+    (12, 61),  // 愉 (44-91)
+    (13, 61),  // 愈 (44-92)
+    (8, 85),   // 油 (44-93)
+    (18, 104), // 癒 (44-94)
+    (16, 149), // 諭 (45-01)
+    (16, 159), // 輸 (45-02)
+    (11, 30),  // 唯 (45-03)
+    (7, 9),    // 佑 (45-04)
+    (17, 9),   // 優 (45-05)
+    (9, 19),   // 勇 (45-06)
+    (4, 29),   // 友 (45-07)
+    (9, 40),   // 宥 (45-08)
+    (9, 52),   // 幽 (45-09)
+    (11, 61),  // 悠 (45-10)
+    (15, 61),  // 憂 (45-11)
+    (12, 64),  // 揖 (45-12)
+    (6, 74),   // 有 (45-13)
+    (9, 75),   // 柚 (45-14)
+    (12, 85),  // 湧 (45-15)
+    (10, 85),  // 涌 (45-16)
+    (12, 94),  // 猶 (45-17)
+    (13, 94),  // 猷 (45-18)
+    (5, 102),  // 由 (45-19)
+    (9, 113),  // 祐 (45-20)
+    (12, 145), // 裕 (45-21)
+    (14, 149), // 誘 (45-22)
+    (12, 162), // 遊 (45-23)
+    (7, 163),  // 邑 (45-24)
+    (11, 163), // 郵 (45-25)
+    (12, 172), // 雄 (45-26)
+    (16, 142), // 融 (45-27)
+    (3, 36),   // 夕 (45-28)
+    (4, 6),    // 予 (45-29)
+    (7, 9),    // 余 (45-30)
+    (3, 1),    // 与 (45-31)
+    (13, 149), // 誉 (45-32)
+    (17, 159), // 輿 (45-33)
+    (13, 181), // 預 (45-34)
+    (13, 9),   // 傭 (45-35)
+    (5, 52),   // 幼 (45-36)
+    (7, 38),   // 妖 (45-37)
+    (10, 40),  // 容 (45-38)
+    (11, 53),  // 庸 (45-39)
+    (12, 64),  // 揚 (45-40)
+    (12, 64),  // 揺 (45-41)
+    (16, 64),  // 擁 (45-42)
+    (18, 72),  // 曜 (45-43)
+    (13, 75),  // 楊 (45-44)
+    (14, 75),  // 様 (45-45)
+    (9, 85),   // 洋 (45-46)
+    (13, 85),  // 溶 (45-47)
+    (14, 86),  // 熔 (45-48)
+    (5, 101),  // 用 (45-49)
+    (15, 116), // 窯 (45-50)
+    (6, 123),  // 羊 (45-51)
+    (20, 124), // 耀 (45-52)
+    (12, 140), // 葉 (45-53)
+    (13, 140), // 蓉 (45-54)
+    (9, 146),  // 要 (45-55)
+    (16, 149), // 謡 (45-56)
+    (14, 157), // 踊 (45-57)
+    (12, 162), // 遥 (45-58)
+    (12, 170), // 陽 (45-59)
+    (15, 184), // 養 (45-60)
+    (15, 61),  // 慾 (45-61)
+    (7, 64),   // 抑 (45-62)
+    (11, 76),  // 欲 (45-63)
+    (7, 85),   // 沃 (45-64)
+    (10, 85),  // 浴 (45-65)
+    (11, 124), // 翌 (45-66)
+    (17, 124), // 翼 (45-67)
+    (11, 85),  // 淀 (45-68)
+    (19, 122), // 羅 (45-69)
+    (17, 142), // 螺 (45-70)
+    (13, 145), // 裸 (45-71)
+    (7, 75),   // 来 (45-72)
+    (10, 140), // 莱 (45-73)
+    (16, 181), // 頼 (45-74)
+    (13, 173), // 雷 (45-75)
+    (9, 85),   // 洛 (45-76)
+    (12, 120), // 絡 (45-77)
+    (12, 140), // 落 (45-78)
+    (13, 164), // 酪 (45-79)
+    (7, 5),    // 乱 (45-80)
+    (7, 26),   // 卵 (45-81)
+    (12, 46),  // 嵐 (45-82)
+    (20, 75),  // 欄 (45-83)
+    (18, 85),  // 濫 (45-84)
+    (18, 140), // 藍 (45-85)
+    (19, 140), // 蘭 (45-86)
+    (17, 147), // 覧 (45-87)
+    (7, 18),   // 利 (45-88)
+    (6, 30),   // 吏 (45-89)
+    (15, 44),  // 履 (45-90)
+    (7, 75),   // 李 (45-91)
+    (11, 75),  // 梨 (45-92)
+    (11, 96),  // 理 (45-93)
+    (15, 96),  // 璃 (45-94)
+    (12, 104), // 痢 (46-01)
+    (13, 145), // 裏 (46-02)
+    (12, 145), // 裡 (46-03)
+    (7, 166),  // 里 (46-04)
+    (19, 172), // 離 (46-05)
+    (11, 170), // 陸 (46-06)
+    (9, 60),   // 律 (46-07)
+    (11, 95),  // 率 (46-08)
+    (5, 117),  // 立 (46-09)
+    (12, 140), // 葎 (46-10)
+    (11, 64),  // 掠 (46-11)
+    (11, 102), // 略 (46-12)
+    (15, 18),  // 劉 (46-13)
+    (10, 85),  // 流 (46-14)
+    (13, 85),  // 溜 (46-15)
+    (11, 96),  // 琉 (46-16)
+    (10, 102), // 留 (46-17)
+    (12, 112), // 硫 (46-18)
+    (11, 119), // 粒 (46-19)
+    (11, 170), // 隆 (46-20)
+    (10, 117), // 竜 (46-21)
+    (16, 212), // 龍 (46-22)
+    (9, 9),    // 侶 (46-23)
+    (15, 61),  // 慮 (46-24)
+    (10, 70),  // 旅 (46-25)
+    (13, 141), // 虜 (46-26)
+    (2, 6),    // 了 (46-27)
+    (9, 8),    // 亮 (46-28)
+    (14, 9),   // 僚 (46-29)
+    (6, 1),    // 両 (46-30)
+    (10, 15),  // 凌 (46-31)
+    (15, 40),  // 寮 (46-32)
+    (10, 68),  // 料 (46-33)
+    (11, 75),  // 梁 (46-34)
+    (11, 85),  // 涼 (46-35)
+    (11, 94),  // 猟 (46-36)
+    (17, 104), // 療 (46-37)
+    (17, 109), // 瞭 (46-38)
+    (13, 115), // 稜 (46-39)
+    (18, 119), // 糧 (46-40)
+    (7, 138),  // 良 (46-41)
+    (15, 149), // 諒 (46-42)
+    (15, 162), // 遼 (46-43)
+    (12, 166), // 量 (46-44)
+    (11, 170), // 陵 (46-45)
+    (14, 181), // 領 (46-46)
+    (2, 19),   // 力 (46-47)
+    (14, 120), // 緑 (46-48)
+    (10, 9),   // 倫 (46-49)
+    (9, 27),   // 厘 (46-50)
+    (8, 75),   // 林 (46-51)
+    (11, 85),  // 淋 (46-52)
+    (17, 86),  // 燐 (46-53)
+    (12, 96),  // 琳 (46-54)
+    (18, 131), // 臨 (46-55)
+    (15, 159), // 輪 (46-56)
+    (16, 170), // 隣 (46-57)
+    (24, 195), // 鱗 (46-58)
+    (24, 198), // 麟 (46-59)
+    (14, 96),  // 瑠 (46-60)
+    (12, 102), // 塁 (46-61)
+    (10, 85),  // 涙 (46-62)
+    (11, 120), // 累 (46-63)
+    (18, 181), // 類 (46-64)
+    (5, 9),    // 令 (46-65)
+    (7, 9),    // 伶 (46-66)
+    (8, 9),    // 例 (46-67)
+    (7, 15),   // 冷 (46-68)
+    (7, 19),   // 励 (46-69)
+    (17, 46),  // 嶺 (46-70)
+    (8, 61),   // 怜 (46-71)
+    (9, 96),   // 玲 (46-72)
+    (5, 113),  // 礼 (46-73)
+    (8, 140),  // 苓 (46-74)
+    (13, 167), // 鈴 (46-75)
+    (16, 171), // 隷 (46-76)
+    (13, 173), // 零 (46-77)
+    (15, 173), // 霊 (46-78)
+    (19, 198), // 麗 (46-79)
+    (17, 211), // 齢 (46-80)
+    (14, 72),  // 暦 (46-81)
+    (14, 77),  // 歴 (46-82)
+    (6, 18),   // 列 (46-83)
+    (6, 19),   // 劣 (46-84)
+    (10, 86),  // 烈 (46-85)
+    (12, 145), // 裂 (46-86)
+    (13, 53),  // 廉 (46-87)
+    (10, 61),  // 恋 (46-88)
+    (16, 61),  // 憐 (46-89)
+    (13, 85),  // 漣 (46-90)
+    (12, 86),  // 煉 (46-91)
+    (19, 118), // 簾 (46-92)
+    (14, 120), // 練 (46-93)
+    (17, 128), // 聯 (46-94)
+    (13, 140), // 蓮 (47-01)
+    (10, 162), // 連 (47-02)
+    (16, 167), // 錬 (47-03)
+    (7, 30),   // 呂 (47-04)
+    (15, 195), // 魯 (47-05)
+    (19, 75),  // 櫓 (47-06)
+    (8, 86),   // 炉 (47-07)
+    (13, 154), // 賂 (47-08)
+    (13, 157), // 路 (47-09)
+    (21, 173), // 露 (47-10)
+    (7, 19),   // 労 (47-11)
+    (11, 38),  // 婁 (47-12)
+    (12, 53),  // 廊 (47-13)
+    (7, 55),   // 弄 (47-14)
+    (10, 74),  // 朗 (47-15)
+    (13, 75),  // 楼 (47-16)
+    (13, 75),  // 榔 (47-17)
+    (10, 85),  // 浪 (47-18)
+    (14, 85),  // 漏 (47-19)
+    (7, 93),   // 牢 (47-20)
+    (10, 94),  // 狼 (47-21)
+    (16, 118), // 篭 (47-22)
+    (6, 125),  // 老 (47-23)
+    (22, 128), // 聾 (47-24)
+    (14, 142), // 蝋 (47-25)
+    (9, 163),  // 郎 (47-26)
+    (4, 12),   // 六 (47-27)
+    (19, 198), // 麓 (47-28)
+    (12, 113), // 禄 (47-29)
+    (6, 130),  // 肋 (47-30)
+    (16, 167), // 録 (47-31)
+    (15, 149), // 論 (47-32)
+    (10, 9),   // 倭 (47-33)
+    (8, 30),   // 和 (47-34)
+    (13, 149), // 話 (47-35)
+    (9, 77),   // 歪 (47-36)
+    (13, 154), // 賄 (47-37)
+    (10, 130), // 脇 (47-38)
+    (12, 61),  // 惑 (47-39)
+    (8, 75),   // 枠 (47-40)
+    (23, 196), // 鷲 (47-41)
+    (6, 7),    // 亙 (47-42)
+    (6, 7),    // 亘 (47-43)
+    (20, 195), // 鰐 (47-44)
+    (13, 149), // 詫 (47-45)
+    (17, 140), // 藁 (47-46)
+    (15, 140), // 蕨 (47-47)
+    (12, 75),  // 椀 (47-48)
+    (12, 85),  // 湾 (47-49)
+    (13, 112), // 碗 (47-50)
+    (12, 130), // 腕 (47-51)
+    (4, 56),   // 弌 (48-01)
+    (4, 1),    // 丐 (48-02)
+    (5, 1),    // 丕 (48-03)
+    (3, 2),    // 个 (48-04)
+    (5, 2),    // 丱 (48-05)
+    (1, 3),    // 丶 (48-06)
+    (5, 3),    // 丼 (48-07)
+    (1, 4),    // 丿 (48-08)
+    (2, 4),    // 乂 (48-09)
+    (8, 4),    // 乖 (48-10)
+    (10, 4),   // 乘 (48-11)
+    (13, 5),   // 亂 (48-12)
+    (1, 6),    // 亅 (48-13)
+    (16, 152), // 豫 (48-14)
+    (7, 6),    // 亊 (48-15)
+    (12, 135), // 舒 (48-16)
+    (5, 56),   // 弍 (48-17)
+    (3, 7),    // 于 (48-18)
+    (8, 7),    // 亞 (48-19)
+    (8, 7),    // 亟 (48-20)
+    (2, 8),    // 亠 (48-21)
+    (4, 8),    // 亢 (48-22)
+    (9, 8),    // 亰 (48-23)
+    (10, 8),   // 亳 (48-24)
+    (13, 8),   // 亶 (48-25)
+    (4, 9),    // 从 (48-26)
+    (4, 9),    // 仍 (48-27)
+    (4, 9),    // 仄 (48-28)
+    (4, 9),    // 仆 (48-29)
+    (4, 9),    // 仂 (48-30)
+    (5, 9),    // 仗 (48-31)
+    (5, 9),    // 仞 (48-32)
+    (5, 9),    // 仭 (48-33)
+    (5, 9),    // 仟 (48-34)
+    (6, 9),    // 价 (48-35)
+    (6, 9),    // 伉 (48-36)
+    (7, 9),    // 佚 (48-37)
+    (7, 9),    // 估 (48-38)
+    (7, 9),    // 佛 (48-39)
+    (7, 9),    // 佝 (48-40)
+    (7, 9),    // 佗 (48-41)
+    (7, 9),    // 佇 (48-42)
+    (8, 9),    // 佶 (48-43)
+    (8, 9),    // 侈 (48-44)
+    (8, 9),    // 侏 (48-45)
+    (8, 9),    // 侘 (48-46)
+    (8, 9),    // 佻 (48-47)
+    (8, 9),    // 佩 (48-48)
+    (8, 9),    // 佰 (48-49)
+    (8, 9),    // 侑 (48-50)
+    (8, 9),    // 佯 (48-51)
+    (8, 9),    // 來 (48-52)
+    (8, 9),    // 侖 (48-53)
+    (16, 9),   // 儘 (48-54)
+    (9, 9),    // 俔 (48-55)
+    (9, 9),    // 俟 (48-56)
+    (9, 9),    // 俎 (48-57)
+    (9, 9),    // 俘 (48-58)
+    (9, 9),    // 俛 (48-59)
+    (9, 9),    // 俑 (48-60)
+    (9, 9),    // 俚 (48-61)
+    (9, 9),    // 俐 (48-62)
+    (9, 9),    // 俤 (48-63)
+    (9, 9),    // 俥 (48-64)
+    (10, 9),   // 倚 (48-65)
+    (10, 9),   // 倨 (48-66)
+    (10, 9),   // 倔 (48-67)
+    (10, 9),   // 倪 (48-68)
+    (10, 9),   // 倥 (48-69)
+    (10, 9),   // 倅 (48-70)
+    (6, 9),    // 伜 (48-71)
+    (10, 9),   // 俶 (48-72)
+    (10, 9),   // 倡 (48-73)
+    (10, 9),   // 倩 (48-74)
+    (10, 9),   // 倬 (48-75)
+    (10, 9),   // 俾 (48-76)
+    (10, 9),   // 俯 (48-77)
+    (10, 9),   // 們 (48-78)
+    (10, 9),   // 倆 (48-79)
+    (11, 9),   // 偃 (48-80)
+    (11, 9),   // 假 (48-81)
+    (13, 73),  // 會 (48-82)
+    (11, 9),   // 偕 (48-83)
+    (11, 9),   // 偐 (48-84)
+    (11, 9),   // 偈 (48-85)
+    (11, 9),   // 做 (48-86)
+    (11, 9),   // 偖 (48-87)
+    (11, 9),   // 偬 (48-88)
+    (11, 9),   // 偸 (48-89)
+    (12, 9),   // 傀 (48-90)
+    (12, 9),   // 傚 (48-91)
+    (12, 9),   // 傅 (48-92)
+    (13, 9),   // 傴 (48-93)
+    (13, 9),   // 傲 (48-94)
+    (13, 9),   // 僉 (49-01)
+    (14, 9),   // 僊 (49-02)
+    (13, 9),   // 傳 (49-03)
+    (13, 9),   // 僂 (49-04)
+    (14, 9),   // 僖 (49-05)
+    (14, 9),   // 僞 (49-06)
+    (14, 9),   // 僥 (49-07)
+    (14, 9),   // 僭 (49-08)
+    (14, 9),   // 僣 (49-09)
+    (14, 9),   // 僮 (49-10)
+    (15, 9),   // 價 (49-11)
+    (15, 9),   // 僵 (49-12)
+    (15, 9),   // 儉 (49-13)
+    (14, 9),   // 儁 (49-14)
+    (15, 9),   // 儂 (49-15)
+    (17, 9),   // 儖 (49-16)
+    (16, 9),   // 儕 (49-17)
+    (16, 9),   // 儔 (49-18)
+    (15, 9),   // 儚 (49-19)
+    (17, 9),   // 儡 (49-20)
+    (21, 9),   // 儺 (49-21)
+    (21, 9),   // 儷 (49-22)
+    (22, 9),   // 儼 (49-23)
+    (22, 9),   // 儻 (49-24)
+    (2, 10),   // 儿 (49-25)
+    (3, 10),   // 兀 (49-26)
+    (8, 10),   // 兒 (49-27)
+    (7, 10),   // 兌 (49-28)
+    (8, 10),   // 兔 (49-29)
+    (14, 10),  // 兢 (49-30)
+    (22, 117), // 竸 (49-31)
+    (8, 11),   // 兩 (49-32)
+    (9, 11),   // 兪 (49-33)
+    (4, 12),   // 兮 (49-34)
+    (16, 12),  // 冀 (49-35)
+    (2, 13),   // 冂 (49-36)
+    (5, 13),   // 囘 (49-37)
+    (5, 13),   // 册 (49-38)
+    (5, 13),   // 冉 (49-39)
+    (7, 13),   // 冏 (49-40)
+    (9, 13),   // 冑 (49-41)
+    (10, 13),  // 冓 (49-42)
+    (11, 13),  // 冕 (49-43)
+    (2, 14),   // 冖 (49-44)
+    (10, 14),  // 冤 (49-45)
+    (10, 14),  // 冦 (49-46)
+    (10, 14),  // 冢 (49-47)
+    (14, 14),  // 冩 (49-48)
+    (15, 14),  // 冪 (49-49)
+    (2, 15),   // 冫 (49-50)
+    (6, 15),   // 决 (49-51)
+    (6, 15),   // 冱 (49-52)
+    (6, 15),   // 冲 (49-53)
+    (6, 15),   // 冰 (49-54)
+    (7, 15),   // 况 (49-55)
+    (8, 15),   // 冽 (49-56)
+    (10, 15),  // 凅 (49-57)
+    (10, 15),  // 凉 (49-58)
+    (15, 15),  // 凛 (49-59)
+    (2, 16),   // 几 (49-60)
+    (11, 141), // 處 (49-61)
+    (6, 16),   // 凩 (49-62)
+    (8, 16),   // 凭 (49-63)
+    (11, 16),  // 凰 (49-64)
+    (2, 17),   // 凵 (49-65)
+    (9, 17),   // 凾 (49-66)
+    (3, 18),   // 刄 (49-67)
+    (5, 18),   // 刋 (49-68)
+    (6, 18),   // 刔 (49-69)
+    (6, 18),   // 刎 (49-70)
+    (7, 18),   // 刧 (49-71)
+    (7, 18),   // 刪 (49-72)
+    (8, 18),   // 刮 (49-73)
+    (8, 18),   // 刳 (49-74)
+    (8, 18),   // 刹 (49-75)
+    (9, 18),   // 剏 (49-76)
+    (9, 18),   // 剄 (49-77)
+    (9, 18),   // 剋 (49-78)
+    (9, 18),   // 剌 (49-79)
+    (10, 18),  // 剞 (49-80)
+    (10, 18),  // 剔 (49-81)
+    (11, 18),  // 剪 (49-82)
+    (12, 18),  // 剴 (49-83)
+    (12, 18),  // 剩 (49-84)
+    (11, 18),  // 剳 (49-85)
+    (13, 18),  // 剿 (49-86)
+    (13, 18),  // 剽 (49-87)
+    (15, 18),  // 劍 (49-88)
+    (16, 18),  // 劔 (49-89)
+    (16, 18),  // 劒 (49-90)
+    (11, 18),  // 剱 (49-91)
+    (15, 18),  // 劈 (49-92)
+    (16, 18),  // 劑 (49-93)
+    (16, 160), // 辨 (49-94)
+    (16, 160), // 辧 (50-01)
+    (7, 19),   // 劬 (50-02)
+    (7, 19),   // 劭 (50-03)
+    (8, 19),   // 劼 (50-04)
+    (8, 19),   // 劵 (50-05)
+    (9, 19),   // 勁 (50-06)
+    (10, 19),  // 勍 (50-07)
+    (11, 19),  // 勗 (50-08)
+    (12, 19),  // 勞 (50-09)
+    (13, 19),  // 勣 (50-10)
+    (13, 19),  // 勦 (50-11)
+    (13, 184), // 飭 (50-12)
+    (13, 19),  // 勠 (50-13)
+    (16, 19),  // 勳 (50-14)
+    (16, 19),  // 勵 (50-15)
+    (19, 19),  // 勸 (50-16)
+    (2, 20),   // 勹 (50-17)
+    (5, 20),   // 匆 (50-18)
+    (6, 20),   // 匈 (50-19)
+    (7, 102),  // 甸 (50-20)
+    (9, 20),   // 匍 (50-21)
+    (11, 20),  // 匐 (50-22)
+    (11, 20),  // 匏 (50-23)
+    (2, 21),   // 匕 (50-24)
+    (2, 22),   // 匚 (50-25)
+    (7, 22),   // 匣 (50-26)
+    (13, 22),  // 匯 (50-27)
+    (14, 22),  // 匱 (50-28)
+    (15, 22),  // 匳 (50-29)
+    (2, 23),   // 匸 (50-30)
+    (11, 23),  // 區 (50-31)
+    (4, 24),   // 卆 (50-32)
+    (4, 24),   // 卅 (50-33)
+    (5, 1),    // 丗 (50-34)
+    (5, 24),   // 卉 (50-35)
+    (6, 24),   // 卍 (50-36)
+    (12, 15),  // 凖 (50-37)
+    (4, 25),   // 卞 (50-38)
+    (2, 26),   // 卩 (50-39)
+    (5, 26),   // 卮 (50-40)
+    (5, 36),   // 夘 (50-41)
+    (9, 26),   // 卻 (50-42)
+    (8, 26),   // 卷 (50-43)
+    (2, 27),   // 厂 (50-44)
+    (9, 27),   // 厖 (50-45)
+    (11, 27),  // 厠 (50-46)
+    (12, 27),  // 厦 (50-47)
+    (12, 27),  // 厥 (50-48)
+    (14, 27),  // 厮 (50-49)
+    (14, 27),  // 厰 (50-50)
+    (2, 28),   // 厶 (50-51)
+    (11, 28),  // 參 (50-52)
+    (17, 118), // 簒 (50-53)
+    (18, 172), // 雙 (50-54)
+    (9, 29),   // 叟 (50-55)
+    (11, 72),  // 曼 (50-56)
+    (17, 86),  // 燮 (50-57)
+    (5, 30),   // 叮 (50-58)
+    (5, 30),   // 叨 (50-59)
+    (5, 30),   // 叭 (50-60)
+    (5, 30),   // 叺 (50-61)
+    (6, 30),   // 吁 (50-62)
+    (7, 30),   // 吽 (50-63)
+    (7, 30),   // 呀 (50-64)
+    (7, 30),   // 听 (50-65)
+    (7, 30),   // 吭 (50-66)
+    (7, 30),   // 吼 (50-67)
+    (7, 30),   // 吮 (50-68)
+    (7, 30),   // 吶 (50-69)
+    (7, 30),   // 吩 (50-70)
+    (7, 30),   // 吝 (50-71)
+    (7, 30),   // 呎 (50-72)
+    (8, 30),   // 咏 (50-73)
+    (8, 30),   // 呵 (50-74)
+    (8, 30),   // 咎 (50-75)
+    (8, 30),   // 呟 (50-76)
+    (9, 30),   // 呱 (50-77)
+    (8, 30),   // 呷 (50-78)
+    (9, 30),   // 呰 (50-79)
+    (8, 30),   // 咒 (50-80)
+    (8, 30),   // 呻 (50-81)
+    (8, 30),   // 咀 (50-82)
+    (8, 30),   // 呶 (50-83)
+    (8, 30),   // 咄 (50-84)
+    (8, 30),   // 咐 (50-85)
+    (8, 30),   // 咆 (50-86)
+    (9, 30),   // 哇 (50-87)
+    (9, 30),   // 咢 (50-88)
+    (9, 30),   // 咸 (50-89)
+    (9, 30),   // 咥 (50-90)
+    (9, 30),   // 咬 (50-91)
+    (9, 30),   // 哄 (50-92)
+    (9, 30),   // 哈 (50-93)
+    (9, 30),   // 咨 (50-94)
+    (9, 30),   // 咫 (51-01)
+    (9, 30),   // 哂 (51-02)
+    (9, 30),   // 咤 (51-03)
+    (9, 30),   // 咾 (51-04)
+    (9, 30),   // 咼 (51-05)
+    (9, 30),   // 哘 (51-06)
+    (10, 30),  // 哥 (51-07)
+    (10, 30),  // 哦 (51-08)
+    (10, 30),  // 唏 (51-09)
+    (10, 30),  // 唔 (51-10)
+    (10, 30),  // 哽 (51-11)
+    (10, 30),  // 哮 (51-12)
+    (10, 30),  // 哭 (51-13)
+    (10, 30),  // 哺 (51-14)
+    (10, 30),  // 哢 (51-15)
+    (11, 30),  // 唹 (51-16)
+    (11, 30),  // 啀 (51-17)
+    (12, 30),  // 啣 (51-18)
+    (11, 30),  // 啌 (51-19)
+    (11, 30),  // 售 (51-20)
+    (11, 30),  // 啜 (51-21)
+    (11, 30),  // 啅 (51-22)
+    (11, 30),  // 啖 (51-23)
+    (11, 30),  // 啗 (51-24)
+    (11, 30),  // 唸 (51-25)
+    (10, 30),  // 唳 (51-26)
+    (11, 30),  // 啝 (51-27)
+    (12, 30),  // 喙 (51-28)
+    (12, 30),  // 喀 (51-29)
+    (9, 30),   // 咯 (51-30)
+    (12, 30),  // 喊 (51-31)
+    (12, 30),  // 喟 (51-32)
+    (12, 30),  // 啻 (51-33)
+    (12, 30),  // 啾 (51-34)
+    (12, 30),  // 喘 (51-35)
+    (12, 30),  // 喞 (51-36)
+    (12, 30),  // 單 (51-37)
+    (12, 30),  // 啼 (51-38)
+    (12, 30),  // 喃 (51-39)
+    (12, 30),  // 喩 (51-40)
+    (12, 30),  // 喇 (51-41)
+    (12, 30),  // 喨 (51-42)
+    (13, 30),  // 嗚 (51-43)
+    (13, 30),  // 嗅 (51-44)
+    (13, 30),  // 嗟 (51-45)
+    (13, 30),  // 嗄 (51-46)
+    (13, 30),  // 嗜 (51-47)
+    (13, 30),  // 嗤 (51-48)
+    (13, 30),  // 嗔 (51-49)
+    (14, 30),  // 嘔 (51-50)
+    (14, 30),  // 嗷 (51-51)
+    (14, 30),  // 嘖 (51-52)
+    (14, 30),  // 嗾 (51-53)
+    (14, 30),  // 嗽 (51-54)
+    (14, 30),  // 嘛 (51-55)
+    (14, 30),  // 嗹 (51-56)
+    (15, 30),  // 噎 (51-57)
+    (15, 30),  // 噐 (51-58)
+    (17, 86),  // 營 (51-59)
+    (16, 30),  // 嘴 (51-60)
+    (15, 30),  // 嘶 (51-61)
+    (15, 30),  // 嘲 (51-62)
+    (15, 30),  // 嘸 (51-63)
+    (16, 30),  // 噫 (51-64)
+    (16, 30),  // 噤 (51-65)
+    (16, 30),  // 嘯 (51-66)
+    (16, 30),  // 噬 (51-67)
+    (16, 30),  // 噪 (51-68)
+    (16, 30),  // 嚆 (51-69)
+    (17, 30),  // 嚀 (51-70)
+    (17, 30),  // 嚊 (51-71)
+    (18, 30),  // 嚠 (51-72)
+    (18, 30),  // 嚔 (51-73)
+    (17, 30),  // 嚏 (51-74)
+    (19, 30),  // 嚥 (51-75)
+    (19, 30),  // 嚮 (51-76)
+    (20, 30),  // 嚶 (51-77)
+    (20, 30),  // 嚴 (51-78)
+    (21, 30),  // 囂 (51-79)
+    (21, 30),  // 嚼 (51-80)
+    (21, 30),  // 囁 (51-81)
+    (21, 30),  // 囃 (51-82)
+    (21, 30),  // 囀 (51-83)
+    (21, 30),  // 囈 (51-84)
+    (22, 30),  // 囎 (51-85)
+    (24, 30),  // 囑 (51-86)
+    (24, 30),  // 囓 (51-87)
+    (3, 31),   // 囗 (51-88)
+    (7, 31),   // 囮 (51-89)
+    (8, 31),   // 囹 (51-90)
+    (9, 31),   // 圀 (51-91)
+    (9, 31),   // 囿 (51-92)
+    (10, 31),  // 圄 (51-93)
+    (11, 31),  // 圉 (51-94)
+    (11, 31),  // 圈 (52-01)
+    (11, 31),  // 國 (52-02)
+    (12, 31),  // 圍 (52-03)
+    (13, 31),  // 圓 (52-04)
+    (14, 31),  // 團 (52-05)
+    (14, 31),  // 圖 (52-06)
+    (13, 30),  // 嗇 (52-07)
+    (16, 31),  // 圜 (52-08)
+    (5, 32),   // 圦 (52-09)
+    (6, 32),   // 圷 (52-10)
+    (6, 32),   // 圸 (52-11)
+    (7, 32),   // 坎 (52-12)
+    (7, 32),   // 圻 (52-13)
+    (7, 32),   // 址 (52-14)
+    (7, 32),   // 坏 (52-15)
+    (8, 32),   // 坩 (52-16)
+    (10, 32),  // 埀 (52-17)
+    (8, 32),   // 垈 (52-18)
+    (8, 32),   // 坡 (52-19)
+    (8, 32),   // 坿 (52-20)
+    (8, 32),   // 垉 (52-21)
+    (9, 32),   // 垓 (52-22)
+    (9, 32),   // 垠 (52-23)
+    (9, 32),   // 垳 (52-24)
+    (9, 32),   // 垤 (52-25)
+    (9, 32),   // 垪 (52-26)
+    (9, 32),   // 垰 (52-27)
+    (10, 32),  // 埃 (52-28)
+    (10, 32),  // 埆 (52-29)
+    (10, 32),  // 埔 (52-30)
+    (10, 32),  // 埒 (52-31)
+    (10, 32),  // 埓 (52-32)
+    (11, 32),  // 堊 (52-33)
+    (10, 32),  // 埖 (52-34)
+    (11, 32),  // 埣 (52-35)
+    (11, 32),  // 堋 (52-36)
+    (12, 32),  // 堙 (52-37)
+    (12, 32),  // 堝 (52-38)
+    (14, 32),  // 塲 (52-39)
+    (12, 32),  // 堡 (52-40)
+    (13, 32),  // 塢 (52-41)
+    (13, 32),  // 塋 (52-42)
+    (13, 32),  // 塰 (52-43)
+    (13, 79),  // 毀 (52-44)
+    (13, 32),  // 塒 (52-45)
+    (12, 32),  // 堽 (52-46)
+    (14, 32),  // 塹 (52-47)
+    (14, 32),  // 墅 (52-48)
+    (15, 32),  // 墹 (52-49)
+    (15, 32),  // 墟 (52-50)
+    (15, 32),  // 墫 (52-51)
+    (16, 32),  // 墺 (52-52)
+    (19, 32),  // 壞 (52-53)
+    (16, 32),  // 墻 (52-54)
+    (15, 32),  // 墸 (52-55)
+    (15, 32),  // 墮 (52-56)
+    (16, 32),  // 壅 (52-57)
+    (17, 32),  // 壓 (52-58)
+    (17, 32),  // 壑 (52-59)
+    (17, 32),  // 壗 (52-60)
+    (18, 32),  // 壙 (52-61)
+    (18, 32),  // 壘 (52-62)
+    (20, 32),  // 壥 (52-63)
+    (19, 32),  // 壜 (52-64)
+    (20, 32),  // 壤 (52-65)
+    (19, 32),  // 壟 (52-66)
+    (7, 33),   // 壯 (52-67)
+    (12, 33),  // 壺 (52-68)
+    (12, 33),  // 壹 (52-69)
+    (12, 33),  // 壻 (52-70)
+    (13, 33),  // 壼 (52-71)
+    (14, 33),  // 壽 (52-72)
+    (3, 34),   // 夂 (52-73)
+    (3, 35),   // 夊 (52-74)
+    (14, 35),  // 夐 (52-75)
+    (6, 36),   // 夛 (52-76)
+    (11, 75),  // 梦 (52-77)
+    (14, 36),  // 夥 (52-78)
+    (4, 37),   // 夬 (52-79)
+    (4, 37),   // 夭 (52-80)
+    (5, 37),   // 夲 (52-81)
+    (6, 37),   // 夸 (52-82)
+    (7, 37),   // 夾 (52-83)
+    (9, 117),  // 竒 (52-84)
+    (9, 37),   // 奕 (52-85)
+    (9, 37),   // 奐 (52-86)
+    (9, 37),   // 奎 (52-87)
+    (10, 37),  // 奚 (52-88)
+    (10, 37),  // 奘 (52-89)
+    (12, 37),  // 奢 (52-90)
+    (12, 37),  // 奠 (52-91)
+    (13, 37),  // 奧 (52-92)
+    (14, 37),  // 奬 (52-93)
+    (14, 37),  // 奩 (52-94)
+    (6, 38),   // 奸 (53-01)
+    (6, 38),   // 妁 (53-02)
+    (7, 38),   // 妝 (53-03)
+    (7, 9),    // 佞 (53-04)
+    (8, 9),    // 侫 (53-05)
+    (7, 38),   // 妣 (53-06)
+    (8, 38),   // 妲 (53-07)
+    (8, 38),   // 姆 (53-08)
+    (9, 38),   // 姨 (53-09)
+    (9, 38),   // 姜 (53-10)
+    (7, 38),   // 妍 (53-11)
+    (9, 38),   // 姙 (53-12)
+    (9, 38),   // 姚 (53-13)
+    (10, 38),  // 娥 (53-14)
+    (10, 38),  // 娟 (53-15)
+    (10, 38),  // 娑 (53-16)
+    (10, 38),  // 娜 (53-17)
+    (10, 38),  // 娉 (53-18)
+    (10, 38),  // 娚 (53-19)
+    (11, 38),  // 婀 (53-20)
+    (11, 38),  // 婬 (53-21)
+    (11, 38),  // 婉 (53-22)
+    (11, 38),  // 娵 (53-23)
+    (11, 38),  // 娶 (53-24)
+    (11, 38),  // 婢 (53-25)
+    (11, 38),  // 婪 (53-26)
+    (12, 38),  // 媚 (53-27)
+    (13, 38),  // 媼 (53-28)
+    (13, 38),  // 媾 (53-29)
+    (13, 38),  // 嫋 (53-30)
+    (12, 38),  // 嫂 (53-31)
+    (13, 38),  // 媽 (53-32)
+    (14, 38),  // 嫣 (53-33)
+    (14, 38),  // 嫗 (53-34)
+    (14, 38),  // 嫦 (53-35)
+    (14, 38),  // 嫩 (53-36)
+    (14, 38),  // 嫖 (53-37)
+    (15, 38),  // 嫺 (53-38)
+    (15, 38),  // 嫻 (53-39)
+    (15, 38),  // 嬌 (53-40)
+    (15, 38),  // 嬋 (53-41)
+    (16, 38),  // 嬖 (53-42)
+    (17, 38),  // 嬲 (53-43)
+    (13, 38),  // 嫐 (53-44)
+    (17, 38),  // 嬪 (53-45)
+    (17, 38),  // 嬶 (53-46)
+    (19, 38),  // 嬾 (53-47)
+    (20, 38),  // 孃 (53-48)
+    (20, 38),  // 孅 (53-49)
+    (20, 38),  // 孀 (53-50)
+    (3, 39),   // 孑 (53-51)
+    (5, 39),   // 孕 (53-52)
+    (7, 39),   // 孚 (53-53)
+    (7, 39),   // 孛 (53-54)
+    (8, 39),   // 孥 (53-55)
+    (9, 39),   // 孩 (53-56)
+    (11, 39),  // 孰 (53-57)
+    (12, 39),  // 孳 (53-58)
+    (14, 39),  // 孵 (53-59)
+    (16, 39),  // 學 (53-60)
+    (7, 67),   // 斈 (53-61)
+    (17, 39),  // 孺 (53-62)
+    (3, 40),   // 宀 (53-63)
+    (5, 40),   // 它 (53-64)
+    (10, 40),  // 宦 (53-65)
+    (10, 40),  // 宸 (53-66)
+    (11, 40),  // 寃 (53-67)
+    (11, 40),  // 寇 (53-68)
+    (11, 40),  // 寉 (53-69)
+    (12, 40),  // 寔 (53-70)
+    (12, 40),  // 寐 (53-71)
+    (14, 40),  // 寤 (53-72)
+    (14, 40),  // 實 (53-73)
+    (14, 40),  // 寢 (53-74)
+    (13, 40),  // 寞 (53-75)
+    (14, 40),  // 寥 (53-76)
+    (15, 40),  // 寫 (53-77)
+    (16, 40),  // 寰 (53-78)
+    (20, 40),  // 寶 (53-79)
+    (19, 40),  // 寳 (53-80)
+    (10, 41),  // 尅 (53-81)
+    (11, 41),  // 將 (53-82)
+    (11, 41),  // 專 (53-83)
+    (14, 41),  // 對 (53-84)
+    (5, 42),   // 尓 (53-85)
+    (13, 42),  // 尠 (53-86)
+    (3, 43),   // 尢 (53-87)
+    (7, 43),   // 尨 (53-88)
+    (3, 44),   // 尸 (53-89)
+    (4, 44),   // 尹 (53-90)
+    (7, 44),   // 屁 (53-91)
+    (8, 44),   // 屆 (53-92)
+    (9, 44),   // 屎 (53-93)
+    (10, 44),  // 屓 (53-94)
+    (10, 44),  // 屐 (54-01)
+    (9, 44),   // 屏 (54-02)
+    (12, 39),  // 孱 (54-03)
+    (21, 44),  // 屬 (54-04)
+    (3, 45),   // 屮 (54-05)
+    (4, 5),    // 乢 (54-06)
+    (5, 46),   // 屶 (54-07)
+    (6, 46),   // 屹 (54-08)
+    (7, 46),   // 岌 (54-09)
+    (7, 46),   // 岑 (54-10)
+    (7, 46),   // 岔 (54-11)
+    (7, 46),   // 妛 (54-12)
+    (8, 46),   // 岫 (54-13)
+    (8, 46),   // 岻 (54-14)
+    (8, 46),   // 岶 (54-15)
+    (8, 46),   // 岼 (54-16)
+    (8, 46),   // 岷 (54-17)
+    (8, 46),   // 峅 (54-18)
+    (8, 46),   // 岾 (54-19)
+    (9, 46),   // 峇 (54-20)
+    (9, 46),   // 峙 (54-21)
+    (10, 46),  // 峩 (54-22)
+    (10, 46),  // 峽 (54-23)
+    (10, 46),  // 峺 (54-24)
+    (10, 46),  // 峭 (54-25)
+    (14, 46),  // 嶌 (54-26)
+    (10, 46),  // 峪 (54-27)
+    (10, 46),  // 崋 (54-28)
+    (11, 46),  // 崕 (54-29)
+    (11, 46),  // 崗 (54-30)
+    (12, 46),  // 嵜 (54-31)
+    (11, 46),  // 崟 (54-32)
+    (11, 46),  // 崛 (54-33)
+    (11, 46),  // 崑 (54-34)
+    (11, 46),  // 崔 (54-35)
+    (11, 46),  // 崢 (54-36)
+    (11, 46),  // 崚 (54-37)
+    (11, 46),  // 崙 (54-38)
+    (11, 46),  // 崘 (54-39)
+    (12, 46),  // 嵌 (54-40)
+    (12, 46),  // 嵒 (54-41)
+    (12, 46),  // 嵎 (54-42)
+    (12, 46),  // 嵋 (54-43)
+    (13, 46),  // 嵬 (54-44)
+    (13, 46),  // 嵳 (54-45)
+    (13, 46),  // 嵶 (54-46)
+    (14, 46),  // 嶇 (54-47)
+    (14, 46),  // 嶄 (54-48)
+    (14, 46),  // 嶂 (54-49)
+    (15, 46),  // 嶢 (54-50)
+    (15, 46),  // 嶝 (54-51)
+    (16, 46),  // 嶬 (54-52)
+    (16, 46),  // 嶮 (54-53)
+    (17, 46),  // 嶽 (54-54)
+    (15, 46),  // 嶐 (54-55)
+    (17, 46),  // 嶷 (54-56)
+    (16, 46),  // 嶼 (54-57)
+    (20, 46),  // 巉 (54-58)
+    (21, 46),  // 巍 (54-59)
+    (22, 46),  // 巓 (54-60)
+    (22, 46),  // 巒 (54-61)
+    (23, 46),  // 巖 (54-62)
+    (3, 47),   // 巛 (54-63)
+    (7, 48),   // 巫 (54-64)
+    (3, 49),   // 已 (54-65)
+    (7, 49),   // 巵 (54-66)
+    (7, 50),   // 帋 (54-67)
+    (8, 50),   // 帚 (54-68)
+    (8, 50),   // 帙 (54-69)
+    (8, 50),   // 帑 (54-70)
+    (8, 50),   // 帛 (54-71)
+    (11, 50),  // 帶 (54-72)
+    (11, 50),  // 帷 (54-73)
+    (12, 50),  // 幄 (54-74)
+    (12, 50),  // 幃 (54-75)
+    (12, 50),  // 幀 (54-76)
+    (13, 50),  // 幎 (54-77)
+    (14, 50),  // 幗 (54-78)
+    (14, 50),  // 幔 (54-79)
+    (15, 50),  // 幟 (54-80)
+    (15, 50),  // 幢 (54-81)
+    (15, 50),  // 幤 (54-82)
+    (12, 50),  // 幇 (54-83)
+    (6, 51),   // 幵 (54-84)
+    (6, 51),   // 并 (54-85)
+    (3, 52),   // 幺 (54-86)
+    (14, 200), // 麼 (54-87)
+    (3, 53),   // 广 (54-88)
+    (9, 53),   // 庠 (54-89)
+    (12, 53),  // 廁 (54-90)
+    (12, 53),  // 廂 (54-91)
+    (13, 53),  // 廈 (54-92)
+    (14, 53),  // 廐 (54-93)
+    (14, 53),  // 廏 (54-94)
+    (14, 53),  // 廖 (55-01)
+    (15, 53),  // 廣 (55-02)
+    (15, 53),  // 廝 (55-03)
+    (15, 53),  // 廚 (55-04)
+    (15, 53),  // 廛 (55-05)
+    (15, 53),  // 廢 (55-06)
+    (15, 53),  // 廡 (55-07)
+    (16, 53),  // 廨 (55-08)
+    (16, 53),  // 廩 (55-09)
+    (19, 53),  // 廬 (55-10)
+    (21, 53),  // 廱 (55-11)
+    (25, 53),  // 廳 (55-12)
+    (20, 53),  // 廰 (55-13)
+    (3, 54),   // 廴 (55-14)
+    (8, 54),   // 廸 (55-15)
+    (3, 55),   // 廾 (55-16)
+    (7, 55),   // 弃 (55-17)
+    (10, 55),  // 弉 (55-18)
+    (18, 58),  // 彝 (55-19)
+    (16, 58),  // 彜 (55-20)
+    (3, 56),   // 弋 (55-21)
+    (12, 56),  // 弑 (55-22)
+    (4, 57),   // 弖 (55-23)
+    (8, 57),   // 弩 (55-24)
+    (9, 57),   // 弭 (55-25)
+    (11, 57),  // 弸 (55-26)
+    (13, 57),  // 彁 (55-27)
+    (15, 57),  // 彈 (55-28)
+    (17, 57),  // 彌 (55-29)
+    (22, 57),  // 彎 (55-30)
+    (9, 57),   // 弯 (55-31)
+    (3, 58),   // 彑 (55-32)
+    (9, 58),   // 彖 (55-33)
+    (11, 58),  // 彗 (55-34)
+    (13, 58),  // 彙 (55-35)
+    (3, 59),   // 彡 (55-36)
+    (12, 59),  // 彭 (55-37)
+    (3, 60),   // 彳 (55-38)
+    (7, 60),   // 彷 (55-39)
+    (8, 60),   // 徃 (55-40)
+    (8, 60),   // 徂 (55-41)
+    (8, 60),   // 彿 (55-42)
+    (9, 60),   // 徊 (55-43)
+    (9, 60),   // 很 (55-44)
+    (10, 60),  // 徑 (55-45)
+    (9, 60),   // 徇 (55-46)
+    (11, 60),  // 從 (55-47)
+    (11, 60),  // 徙 (55-48)
+    (11, 60),  // 徘 (55-49)
+    (11, 60),  // 徠 (55-50)
+    (12, 60),  // 徨 (55-51)
+    (13, 60),  // 徭 (55-52)
+    (16, 60),  // 徼 (55-53)
+    (6, 61),   // 忖 (55-54)
+    (7, 61),   // 忻 (55-55)
+    (7, 61),   // 忤 (55-56)
+    (7, 61),   // 忸 (55-57)
+    (7, 61),   // 忱 (55-58)
+    (8, 61),   // 忝 (55-59)
+    (12, 61),  // 悳 (55-60)
+    (8, 61),   // 忿 (55-61)
+    (8, 61),   // 怡 (55-62)
+    (9, 61),   // 恠 (55-63)
+    (8, 61),   // 怙 (55-64)
+    (8, 61),   // 怐 (55-65)
+    (8, 61),   // 怩 (55-66)
+    (9, 61),   // 怎 (55-67)
+    (9, 61),   // 怱 (55-68)
+    (8, 61),   // 怛 (55-69)
+    (8, 61),   // 怕 (55-70)
+    (8, 61),   // 怫 (55-71)
+    (8, 61),   // 怦 (55-72)
+    (8, 61),   // 怏 (55-73)
+    (8, 61),   // 怺 (55-74)
+    (10, 61),  // 恚 (55-75)
+    (10, 61),  // 恁 (55-76)
+    (9, 61),   // 恪 (55-77)
+    (10, 61),  // 恷 (55-78)
+    (9, 61),   // 恟 (55-79)
+    (9, 61),   // 恊 (55-80)
+    (9, 61),   // 恆 (55-81)
+    (9, 61),   // 恍 (55-82)
+    (10, 61),  // 恣 (55-83)
+    (9, 61),   // 恃 (55-84)
+    (9, 61),   // 恤 (55-85)
+    (9, 61),   // 恂 (55-86)
+    (9, 61),   // 恬 (55-87)
+    (9, 61),   // 恫 (55-88)
+    (10, 61),  // 恙 (55-89)
+    (10, 61),  // 悁 (55-90)
+    (10, 61),  // 悍 (55-91)
+    (11, 61),  // 惧 (55-92)
+    (10, 61),  // 悃 (55-93)
+    (10, 61),  // 悚 (55-94)
+    (10, 61),  // 悄 (56-01)
+    (10, 61),  // 悛 (56-02)
+    (10, 61),  // 悖 (56-03)
+    (10, 61),  // 悗 (56-04)
+    (10, 61),  // 悒 (56-05)
+    (10, 61),  // 悧 (56-06)
+    (10, 61),  // 悋 (56-07)
+    (12, 61),  // 惡 (56-08)
+    (11, 61),  // 悸 (56-09)
+    (12, 61),  // 惠 (56-10)
+    (11, 61),  // 惓 (56-11)
+    (11, 61),  // 悴 (56-12)
+    (7, 61),   // 忰 (56-13)
+    (11, 61),  // 悽 (56-14)
+    (11, 61),  // 惆 (56-15)
+    (11, 61),  // 悵 (56-16)
+    (11, 61),  // 惘 (56-17)
+    (13, 61),  // 慍 (56-18)
+    (12, 61),  // 愕 (56-19)
+    (13, 61),  // 愆 (56-20)
+    (12, 61),  // 惶 (56-21)
+    (13, 61),  // 惷 (56-22)
+    (12, 61),  // 愀 (56-23)
+    (12, 61),  // 惴 (56-24)
+    (12, 61),  // 惺 (56-25)
+    (12, 61),  // 愃 (56-26)
+    (12, 61),  // 愡 (56-27)
+    (12, 61),  // 惻 (56-28)
+    (12, 61),  // 惱 (56-29)
+    (13, 61),  // 愍 (56-30)
+    (12, 61),  // 愎 (56-31)
+    (14, 61),  // 慇 (56-32)
+    (13, 61),  // 愾 (56-33)
+    (14, 61),  // 愨 (56-34)
+    (13, 61),  // 愧 (56-35)
+    (13, 61),  // 慊 (56-36)
+    (14, 61),  // 愿 (56-37)
+    (13, 61),  // 愼 (56-38)
+    (14, 61),  // 愬 (56-39)
+    (13, 61),  // 愴 (56-40)
+    (13, 61),  // 愽 (56-41)
+    (14, 61),  // 慂 (56-42)
+    (13, 61),  // 慄 (56-43)
+    (15, 61),  // 慳 (56-44)
+    (14, 61),  // 慷 (56-45)
+    (14, 61),  // 慘 (56-46)
+    (15, 61),  // 慙 (56-47)
+    (14, 61),  // 慚 (56-48)
+    (15, 61),  // 慫 (56-49)
+    (14, 61),  // 慴 (56-50)
+    (14, 61),  // 慯 (56-51)
+    (14, 61),  // 慥 (56-52)
+    (14, 61),  // 慱 (56-53)
+    (14, 61),  // 慟 (56-54)
+    (14, 61),  // 慝 (56-55)
+    (14, 61),  // 慓 (56-56)
+    (14, 61),  // 慵 (56-57)
+    (16, 61),  // 憙 (56-58)
+    (16, 61),  // 憖 (56-59)
+    (15, 61),  // 憇 (56-60)
+    (15, 61),  // 憬 (56-61)
+    (15, 61),  // 憔 (56-62)
+    (15, 61),  // 憚 (56-63)
+    (16, 61),  // 憊 (56-64)
+    (16, 61),  // 憑 (56-65)
+    (15, 61),  // 憫 (56-66)
+    (15, 61),  // 憮 (56-67)
+    (16, 61),  // 懌 (56-68)
+    (16, 61),  // 懊 (56-69)
+    (17, 61),  // 應 (56-70)
+    (19, 61),  // 懷 (56-71)
+    (16, 61),  // 懈 (56-72)
+    (17, 61),  // 懃 (56-73)
+    (16, 61),  // 懆 (56-74)
+    (16, 61),  // 憺 (56-75)
+    (17, 61),  // 懋 (56-76)
+    (16, 122), // 罹 (56-77)
+    (16, 61),  // 懍 (56-78)
+    (17, 61),  // 懦 (56-79)
+    (18, 61),  // 懣 (56-80)
+    (19, 61),  // 懶 (56-81)
+    (20, 61),  // 懺 (56-82)
+    (18, 61),  // 懴 (56-83)
+    (22, 61),  // 懿 (56-84)
+    (20, 61),  // 懽 (56-85)
+    (21, 61),  // 懼 (56-86)
+    (21, 61),  // 懾 (56-87)
+    (23, 61),  // 戀 (56-88)
+    (4, 62),   // 戈 (56-89)
+    (5, 62),   // 戉 (56-90)
+    (6, 62),   // 戍 (56-91)
+    (6, 62),   // 戌 (56-92)
+    (8, 62),   // 戔 (56-93)
+    (11, 62),  // 戛 (56-94)
+    (12, 62),  // 戞 (57-01)
+    (13, 62),  // 戡 (57-02)
+    (14, 62),  // 截 (57-03)
+    (15, 62),  // 戮 (57-04)
+    (16, 62),  // 戰 (57-05)
+    (17, 62),  // 戲 (57-06)
+    (18, 62),  // 戳 (57-07)
+    (9, 63),   // 扁 (57-08)
+    (4, 64),   // 扎 (57-09)
+    (6, 64),   // 扞 (57-10)
+    (6, 64),   // 扣 (57-11)
+    (6, 64),   // 扛 (57-12)
+    (6, 64),   // 扠 (57-13)
+    (6, 64),   // 扨 (57-14)
+    (7, 64),   // 扼 (57-15)
+    (7, 64),   // 抂 (57-16)
+    (7, 64),   // 抉 (57-17)
+    (7, 64),   // 找 (57-18)
+    (7, 64),   // 抒 (57-19)
+    (7, 64),   // 抓 (57-20)
+    (7, 64),   // 抖 (57-21)
+    (8, 64),   // 拔 (57-22)
+    (7, 64),   // 抃 (57-23)
+    (7, 64),   // 抔 (57-24)
+    (8, 64),   // 拗 (57-25)
+    (8, 64),   // 拑 (57-26)
+    (8, 64),   // 抻 (57-27)
+    (9, 64),   // 拏 (57-28)
+    (10, 64),  // 拿 (57-29)
+    (8, 64),   // 拆 (57-30)
+    (16, 64),  // 擔 (57-31)
+    (8, 64),   // 拈 (57-32)
+    (9, 64),   // 拜 (57-33)
+    (8, 64),   // 拌 (57-34)
+    (8, 64),   // 拊 (57-35)
+    (8, 64),   // 拂 (57-36)
+    (8, 64),   // 拇 (57-37)
+    (7, 64),   // 抛 (57-38)
+    (8, 64),   // 拉 (57-39)
+    (9, 64),   // 挌 (57-40)
+    (9, 64),   // 拮 (57-41)
+    (9, 64),   // 拱 (57-42)
+    (9, 64),   // 挧 (57-43)
+    (9, 64),   // 挂 (57-44)
+    (10, 64),  // 挈 (57-45)
+    (9, 64),   // 拯 (57-46)
+    (9, 64),   // 拵 (57-47)
+    (10, 64),  // 捐 (57-48)
+    (10, 64),  // 挾 (57-49)
+    (10, 64),  // 捍 (57-50)
+    (12, 64),  // 搜 (57-51)
+    (10, 64),  // 捏 (57-52)
+    (11, 64),  // 掖 (57-53)
+    (11, 64),  // 掎 (57-54)
+    (11, 64),  // 掀 (57-55)
+    (11, 64),  // 掫 (57-56)
+    (11, 64),  // 捶 (57-57)
+    (12, 64),  // 掣 (57-58)
+    (11, 64),  // 掏 (57-59)
+    (11, 64),  // 掉 (57-60)
+    (11, 64),  // 掟 (57-61)
+    (11, 64),  // 掵 (57-62)
+    (11, 64),  // 捫 (57-63)
+    (10, 64),  // 捩 (57-64)
+    (12, 64),  // 掾 (57-65)
+    (12, 64),  // 揩 (57-66)
+    (12, 64),  // 揀 (57-67)
+    (12, 64),  // 揆 (57-68)
+    (12, 64),  // 揣 (57-69)
+    (12, 64),  // 揉 (57-70)
+    (12, 64),  // 插 (57-71)
+    (12, 64),  // 揶 (57-72)
+    (12, 64),  // 揄 (57-73)
+    (13, 64),  // 搖 (57-74)
+    (14, 64),  // 搴 (57-75)
+    (13, 64),  // 搆 (57-76)
+    (13, 64),  // 搓 (57-77)
+    (13, 64),  // 搦 (57-78)
+    (13, 64),  // 搶 (57-79)
+    (21, 64),  // 攝 (57-80)
+    (13, 64),  // 搗 (57-81)
+    (13, 64),  // 搨 (57-82)
+    (13, 64),  // 搏 (57-83)
+    (14, 64),  // 摧 (57-84)
+    (15, 64),  // 摯 (57-85)
+    (14, 64),  // 摶 (57-86)
+    (14, 64),  // 摎 (57-87)
+    (23, 64),  // 攪 (57-88)
+    (15, 64),  // 撕 (57-89)
+    (15, 64),  // 撓 (57-90)
+    (15, 64),  // 撥 (57-91)
+    (15, 64),  // 撩 (57-92)
+    (15, 64),  // 撈 (57-93)
+    (16, 64),  // 撼 (57-94)
+    (16, 64),  // 據 (58-01)
+    (16, 64),  // 擒 (58-02)
+    (16, 64),  // 擅 (58-03)
+    (16, 64),  // 擇 (58-04)
+    (16, 64),  // 撻 (58-05)
+    (17, 64),  // 擘 (58-06)
+    (16, 64),  // 擂 (58-07)
+    (17, 64),  // 擱 (58-08)
+    (17, 64),  // 擧 (58-09)
+    (16, 134), // 舉 (58-10)
+    (17, 64),  // 擠 (58-11)
+    (17, 64),  // 擡 (58-12)
+    (8, 64),   // 抬 (58-13)
+    (17, 64),  // 擣 (58-14)
+    (17, 64),  // 擯 (58-15)
+    (25, 64),  // 攬 (58-16)
+    (18, 64),  // 擶 (58-17)
+    (18, 64),  // 擴 (58-18)
+    (18, 64),  // 擲 (58-19)
+    (18, 64),  // 擺 (58-20)
+    (19, 64),  // 攀 (58-21)
+    (18, 64),  // 擽 (58-22)
+    (20, 64),  // 攘 (58-23)
+    (21, 64),  // 攜 (58-24)
+    (18, 64),  // 攅 (58-25)
+    (22, 64),  // 攤 (58-26)
+    (23, 64),  // 攣 (58-27)
+    (23, 64),  // 攫 (58-28)
+    (4, 66),   // 攴 (58-29)
+    (4, 66),   // 攵 (58-30)
+    (6, 66),   // 攷 (58-31)
+    (6, 66),   // 收 (58-32)
+    (7, 66),   // 攸 (58-33)
+    (9, 102),  // 畋 (58-34)
+    (10, 66),  // 效 (58-35)
+    (11, 66),  // 敖 (58-36)
+    (11, 66),  // 敕 (58-37)
+    (11, 66),  // 敍 (58-38)
+    (11, 66),  // 敘 (58-39)
+    (12, 66),  // 敞 (58-40)
+    (12, 66),  // 敝 (58-41)
+    (14, 66),  // 敲 (58-42)
+    (15, 66),  // 數 (58-43)
+    (17, 66),  // 斂 (58-44)
+    (18, 66),  // 斃 (58-45)
+    (23, 149), // 變 (58-46)
+    (11, 68),  // 斛 (58-47)
+    (13, 68),  // 斟 (58-48)
+    (9, 69),   // 斫 (58-49)
+    (18, 69),  // 斷 (58-50)
+    (10, 70),  // 旃 (58-51)
+    (10, 70),  // 旆 (58-52)
+    (10, 70),  // 旁 (58-53)
+    (10, 70),  // 旄 (58-54)
+    (11, 70),  // 旌 (58-55)
+    (13, 70),  // 旒 (58-56)
+    (18, 70),  // 旛 (58-57)
+    (16, 70),  // 旙 (58-58)
+    (4, 71),   // 无 (58-59)
+    (4, 71),   // 旡 (58-60)
+    (7, 72),   // 旱 (58-61)
+    (8, 75),   // 杲 (58-62)
+    (8, 72),   // 昊 (58-63)
+    (8, 72),   // 昃 (58-64)
+    (8, 72),   // 旻 (58-65)
+    (8, 75),   // 杳 (58-66)
+    (9, 72),   // 昵 (58-67)
+    (9, 72),   // 昶 (58-68)
+    (9, 72),   // 昴 (58-69)
+    (9, 72),   // 昜 (58-70)
+    (10, 72),  // 晏 (58-71)
+    (10, 72),  // 晄 (58-72)
+    (10, 72),  // 晉 (58-73)
+    (10, 72),  // 晁 (58-74)
+    (11, 72),  // 晞 (58-75)
+    (11, 72),  // 晝 (58-76)
+    (11, 72),  // 晤 (58-77)
+    (11, 72),  // 晧 (58-78)
+    (11, 72),  // 晨 (58-79)
+    (10, 72),  // 晟 (58-80)
+    (11, 72),  // 晢 (58-81)
+    (12, 72),  // 晰 (58-82)
+    (12, 72),  // 暃 (58-83)
+    (13, 72),  // 暈 (58-84)
+    (12, 72),  // 暎 (58-85)
+    (13, 72),  // 暉 (58-86)
+    (13, 72),  // 暄 (58-87)
+    (13, 72),  // 暘 (58-88)
+    (14, 72),  // 暝 (58-89)
+    (16, 72),  // 曁 (58-90)
+    (16, 72),  // 暹 (58-91)
+    (16, 72),  // 曉 (58-92)
+    (16, 72),  // 暾 (58-93)
+    (16, 72),  // 暼 (58-94)
+    (14, 72),  // 曄 (59-01)
+    (16, 72),  // 暸 (59-02)
+    (17, 72),  // 曖 (59-03)
+    (17, 72),  // 曚 (59-04)
+    (19, 72),  // 曠 (59-05)
+    (9, 72),   // 昿 (59-06)
+    (20, 72),  // 曦 (59-07)
+    (21, 72),  // 曩 (59-08)
+    (4, 73),   // 曰 (59-09)
+    (7, 73),   // 曵 (59-10)
+    (9, 73),   // 曷 (59-11)
+    (9, 74),   // 朏 (59-12)
+    (11, 74),  // 朖 (59-13)
+    (12, 74),  // 朞 (59-14)
+    (17, 74),  // 朦 (59-15)
+    (20, 74),  // 朧 (59-16)
+    (21, 173), // 霸 (59-17)
+    (5, 75),   // 朮 (59-18)
+    (6, 75),   // 朿 (59-19)
+    (6, 75),   // 朶 (59-20)
+    (6, 75),   // 杁 (59-21)
+    (6, 75),   // 朸 (59-22)
+    (6, 75),   // 朷 (59-23)
+    (7, 75),   // 杆 (59-24)
+    (7, 75),   // 杞 (59-25)
+    (7, 75),   // 杠 (59-26)
+    (7, 75),   // 杙 (59-27)
+    (7, 75),   // 杣 (59-28)
+    (7, 75),   // 杤 (59-29)
+    (8, 75),   // 枉 (59-30)
+    (8, 75),   // 杰 (59-31)
+    (8, 75),   // 枩 (59-32)
+    (8, 75),   // 杼 (59-33)
+    (8, 75),   // 杪 (59-34)
+    (8, 75),   // 枌 (59-35)
+    (8, 75),   // 枋 (59-36)
+    (8, 75),   // 枦 (59-37)
+    (8, 75),   // 枡 (59-38)
+    (8, 75),   // 枅 (59-39)
+    (9, 75),   // 枷 (59-40)
+    (9, 75),   // 柯 (59-41)
+    (9, 75),   // 枴 (59-42)
+    (9, 75),   // 柬 (59-43)
+    (9, 75),   // 枳 (59-44)
+    (9, 75),   // 柩 (59-45)
+    (9, 75),   // 枸 (59-46)
+    (9, 75),   // 柤 (59-47)
+    (9, 75),   // 柞 (59-48)
+    (9, 75),   // 柝 (59-49)
+    (9, 75),   // 柢 (59-50)
+    (9, 75),   // 柮 (59-51)
+    (9, 75),   // 枹 (59-52)
+    (9, 75),   // 柎 (59-53)
+    (9, 75),   // 柆 (59-54)
+    (10, 75),  // 柧 (59-55)
+    (17, 75),  // 檜 (59-56)
+    (10, 75),  // 栞 (59-57)
+    (10, 75),  // 框 (59-58)
+    (10, 75),  // 栩 (59-59)
+    (11, 75),  // 桀 (59-60)
+    (10, 75),  // 桍 (59-61)
+    (10, 75),  // 栲 (59-62)
+    (10, 75),  // 桎 (59-63)
+    (11, 75),  // 梳 (59-64)
+    (10, 75),  // 栫 (59-65)
+    (10, 75),  // 桙 (59-66)
+    (10, 75),  // 档 (59-67)
+    (11, 75),  // 桷 (59-68)
+    (11, 75),  // 桿 (59-69)
+    (11, 75),  // 梟 (59-70)
+    (11, 75),  // 梏 (59-71)
+    (11, 75),  // 梭 (59-72)
+    (11, 75),  // 梔 (59-73)
+    (11, 75),  // 條 (59-74)
+    (11, 75),  // 梛 (59-75)
+    (11, 75),  // 梃 (59-76)
+    (18, 75),  // 檮 (59-77)
+    (11, 75),  // 梹 (59-78)
+    (11, 75),  // 桴 (59-79)
+    (11, 75),  // 梵 (59-80)
+    (11, 75),  // 梠 (59-81)
+    (11, 75),  // 梺 (59-82)
+    (12, 75),  // 椏 (59-83)
+    (11, 75),  // 梍 (59-84)
+    (11, 75),  // 桾 (59-85)
+    (12, 75),  // 椁 (59-86)
+    (12, 75),  // 棊 (59-87)
+    (12, 75),  // 椈 (59-88)
+    (12, 75),  // 棘 (59-89)
+    (12, 75),  // 椢 (59-90)
+    (12, 75),  // 椦 (59-91)
+    (12, 75),  // 棡 (59-92)
+    (12, 75),  // 椌 (59-93)
+    (12, 75),  // 棍 (59-94)
+    (12, 75),  // 棔 (60-01)
+    (12, 75),  // 棧 (60-02)
+    (12, 75),  // 棕 (60-03)
+    (13, 75),  // 椶 (60-04)
+    (12, 75),  // 椒 (60-05)
+    (12, 75),  // 椄 (60-06)
+    (12, 75),  // 棗 (60-07)
+    (12, 75),  // 棣 (60-08)
+    (12, 75),  // 椥 (60-09)
+    (12, 75),  // 棹 (60-10)
+    (12, 75),  // 棠 (60-11)
+    (12, 75),  // 棯 (60-12)
+    (12, 75),  // 椨 (60-13)
+    (12, 75),  // 椪 (60-14)
+    (12, 75),  // 椚 (60-15)
+    (12, 75),  // 椣 (60-16)
+    (12, 75),  // 椡 (60-17)
+    (12, 75),  // 棆 (60-18)
+    (13, 75),  // 楹 (60-19)
+    (13, 75),  // 楷 (60-20)
+    (13, 75),  // 楜 (60-21)
+    (13, 75),  // 楸 (60-22)
+    (13, 75),  // 楫 (60-23)
+    (13, 75),  // 楔 (60-24)
+    (13, 75),  // 楾 (60-25)
+    (13, 75),  // 楮 (60-26)
+    (13, 75),  // 椹 (60-27)
+    (13, 75),  // 楴 (60-28)
+    (13, 75),  // 椽 (60-29)
+    (13, 75),  // 楙 (60-30)
+    (13, 75),  // 椰 (60-31)
+    (13, 75),  // 楡 (60-32)
+    (13, 75),  // 楞 (60-33)
+    (13, 75),  // 楝 (60-34)
+    (13, 75),  // 榁 (60-35)
+    (13, 75),  // 楪 (60-36)
+    (14, 75),  // 榲 (60-37)
+    (14, 75),  // 榮 (60-38)
+    (14, 75),  // 槐 (60-39)
+    (14, 75),  // 榿 (60-40)
+    (14, 75),  // 槁 (60-41)
+    (14, 75),  // 槓 (60-42)
+    (14, 75),  // 榾 (60-43)
+    (14, 75),  // 槎 (60-44)
+    (14, 40),  // 寨 (60-45)
+    (14, 75),  // 槊 (60-46)
+    (14, 75),  // 槝 (60-47)
+    (14, 75),  // 榻 (60-48)
+    (14, 75),  // 槃 (60-49)
+    (14, 75),  // 榧 (60-50)
+    (14, 75),  // 樮 (60-51)
+    (14, 75),  // 榑 (60-52)
+    (14, 75),  // 榠 (60-53)
+    (14, 75),  // 榜 (60-54)
+    (14, 75),  // 榕 (60-55)
+    (14, 75),  // 榴 (60-56)
+    (14, 75),  // 槞 (60-57)
+    (15, 75),  // 槨 (60-58)
+    (15, 75),  // 樂 (60-59)
+    (15, 75),  // 樛 (60-60)
+    (15, 75),  // 槿 (60-61)
+    (21, 75),  // 權 (60-62)
+    (15, 75),  // 槹 (60-63)
+    (15, 75),  // 槲 (60-64)
+    (15, 75),  // 槧 (60-65)
+    (15, 75),  // 樅 (60-66)
+    (14, 75),  // 榱 (60-67)
+    (15, 75),  // 樞 (60-68)
+    (15, 75),  // 槭 (60-69)
+    (15, 75),  // 樔 (60-70)
+    (15, 75),  // 槫 (60-71)
+    (15, 75),  // 樊 (60-72)
+    (15, 75),  // 樒 (60-73)
+    (18, 75),  // 櫁 (60-74)
+    (15, 75),  // 樣 (60-75)
+    (15, 75),  // 樓 (60-76)
+    (16, 75),  // 橄 (60-77)
+    (15, 75),  // 樌 (60-78)
+    (16, 75),  // 橲 (60-79)
+    (16, 75),  // 樶 (60-80)
+    (16, 75),  // 橸 (60-81)
+    (16, 75),  // 橇 (60-82)
+    (16, 75),  // 橢 (60-83)
+    (16, 75),  // 橙 (60-84)
+    (16, 75),  // 橦 (60-85)
+    (16, 75),  // 橈 (60-86)
+    (16, 75),  // 樸 (60-87)
+    (15, 75),  // 樢 (60-88)
+    (17, 75),  // 檐 (60-89)
+    (17, 75),  // 檍 (60-90)
+    (16, 75),  // 檠 (60-91)
+    (17, 75),  // 檄 (60-92)
+    (17, 75),  // 檢 (60-93)
+    (17, 75),  // 檣 (60-94)
+    (17, 75),  // 檗 (61-01)
+    (20, 140), // 蘗 (61-02)
+    (19, 75),  // 檻 (61-03)
+    (18, 75),  // 櫃 (61-04)
+    (18, 75),  // 櫂 (61-05)
+    (18, 75),  // 檸 (61-06)
+    (18, 75),  // 檳 (61-07)
+    (17, 75),  // 檬 (61-08)
+    (19, 75),  // 櫞 (61-09)
+    (19, 75),  // 櫑 (61-10)
+    (19, 75),  // 櫟 (61-11)
+    (17, 75),  // 檪 (61-12)
+    (19, 75),  // 櫚 (61-13)
+    (20, 75),  // 櫪 (61-14)
+    (21, 75),  // 櫻 (61-15)
+    (21, 75),  // 欅 (61-16)
+    (20, 140), // 蘖 (61-17)
+    (21, 75),  // 櫺 (61-18)
+    (23, 75),  // 欒 (61-19)
+    (26, 75),  // 欖 (61-20)
+    (29, 192), // 鬱 (61-21)
+    (28, 75),  // 欟 (61-22)
+    (11, 76),  // 欸 (61-23)
+    (11, 76),  // 欷 (61-24)
+    (12, 108), // 盜 (61-25)
+    (12, 76),  // 欹 (61-26)
+    (13, 184), // 飮 (61-27)
+    (13, 76),  // 歇 (61-28)
+    (13, 76),  // 歃 (61-29)
+    (14, 76),  // 歉 (61-30)
+    (15, 76),  // 歐 (61-31)
+    (16, 76),  // 歙 (61-32)
+    (16, 76),  // 歔 (61-33)
+    (17, 76),  // 歛 (61-34)
+    (17, 76),  // 歟 (61-35)
+    (21, 76),  // 歡 (61-36)
+    (18, 77),  // 歸 (61-37)
+    (4, 78),   // 歹 (61-38)
+    (8, 78),   // 歿 (61-39)
+    (8, 78),   // 殀 (61-40)
+    (9, 78),   // 殄 (61-41)
+    (9, 78),   // 殃 (61-42)
+    (11, 78),  // 殍 (61-43)
+    (12, 78),  // 殘 (61-44)
+    (12, 78),  // 殕 (61-45)
+    (14, 78),  // 殞 (61-46)
+    (15, 78),  // 殤 (61-47)
+    (16, 78),  // 殪 (61-48)
+    (16, 78),  // 殫 (61-49)
+    (18, 78),  // 殯 (61-50)
+    (21, 78),  // 殲 (61-51)
+    (19, 78),  // 殱 (61-52)
+    (4, 79),   // 殳 (61-53)
+    (10, 79),  // 殷 (61-54)
+    (12, 79),  // 殼 (61-55)
+    (15, 79),  // 毆 (61-56)
+    (4, 80),   // 毋 (61-57)
+    (14, 80),  // 毓 (61-58)
+    (8, 82),   // 毟 (61-59)
+    (11, 82),  // 毬 (61-60)
+    (11, 82),  // 毫 (61-61)
+    (12, 82),  // 毳 (61-62)
+    (12, 82),  // 毯 (61-63)
+    (15, 200), // 麾 (61-64)
+    (17, 82),  // 氈 (61-65)
+    (8, 83),   // 氓 (61-66)
+    (4, 84),   // 气 (61-67)
+    (8, 84),   // 氛 (61-68)
+    (10, 84),  // 氤 (61-69)
+    (10, 84),  // 氣 (61-70)
+    (7, 85),   // 汞 (61-71)
+    (6, 85),   // 汕 (61-72)
+    (6, 85),   // 汢 (61-73)
+    (7, 85),   // 汪 (61-74)
+    (7, 85),   // 沂 (61-75)
+    (7, 85),   // 沍 (61-76)
+    (7, 85),   // 沚 (61-77)
+    (7, 85),   // 沁 (61-78)
+    (8, 85),   // 沛 (61-79)
+    (7, 85),   // 汾 (61-80)
+    (7, 85),   // 汨 (61-81)
+    (7, 85),   // 汳 (61-82)
+    (7, 85),   // 沒 (61-83)
+    (7, 85),   // 沐 (61-84)
+    (8, 85),   // 泄 (61-85)
+    (8, 85),   // 泱 (61-86)
+    (8, 85),   // 泓 (61-87)
+    (8, 85),   // 沽 (61-88)
+    (8, 85),   // 泗 (61-89)
+    (8, 85),   // 泅 (61-90)
+    (8, 85),   // 泝 (61-91)
+    (8, 85),   // 沮 (61-92)
+    (8, 85),   // 沱 (61-93)
+    (8, 85),   // 沾 (61-94)
+    (8, 85),   // 沺 (62-01)
+    (7, 85),   // 泛 (62-02)
+    (8, 85),   // 泯 (62-03)
+    (8, 85),   // 泙 (62-04)
+    (8, 85),   // 泪 (62-05)
+    (9, 85),   // 洟 (62-06)
+    (9, 144),  // 衍 (62-07)
+    (9, 85),   // 洶 (62-08)
+    (9, 85),   // 洫 (62-09)
+    (9, 85),   // 洽 (62-10)
+    (9, 85),   // 洸 (62-11)
+    (9, 85),   // 洙 (62-12)
+    (9, 85),   // 洵 (62-13)
+    (9, 85),   // 洳 (62-14)
+    (9, 85),   // 洒 (62-15)
+    (9, 85),   // 洌 (62-16)
+    (10, 85),  // 浣 (62-17)
+    (10, 85),  // 涓 (62-18)
+    (10, 85),  // 浤 (62-19)
+    (10, 85),  // 浚 (62-20)
+    (10, 85),  // 浹 (62-21)
+    (10, 85),  // 浙 (62-22)
+    (10, 85),  // 涎 (62-23)
+    (10, 85),  // 涕 (62-24)
+    (17, 85),  // 濤 (62-25)
+    (10, 85),  // 涅 (62-26)
+    (11, 85),  // 淹 (62-27)
+    (11, 85),  // 渕 (62-28)
+    (11, 85),  // 渊 (62-29)
+    (11, 85),  // 涵 (62-30)
+    (11, 85),  // 淇 (62-31)
+    (11, 85),  // 淦 (62-32)
+    (11, 85),  // 涸 (62-33)
+    (11, 85),  // 淆 (62-34)
+    (11, 85),  // 淬 (62-35)
+    (11, 85),  // 淞 (62-36)
+    (11, 85),  // 淌 (62-37)
+    (11, 85),  // 淨 (62-38)
+    (11, 85),  // 淒 (62-39)
+    (11, 85),  // 淅 (62-40)
+    (11, 85),  // 淺 (62-41)
+    (11, 85),  // 淙 (62-42)
+    (11, 85),  // 淤 (62-43)
+    (11, 85),  // 淕 (62-44)
+    (11, 85),  // 淪 (62-45)
+    (11, 85),  // 淮 (62-46)
+    (12, 85),  // 渭 (62-47)
+    (12, 85),  // 湮 (62-48)
+    (11, 85),  // 渮 (62-49)
+    (12, 85),  // 渙 (62-50)
+    (12, 85),  // 湲 (62-51)
+    (12, 85),  // 湟 (62-52)
+    (12, 85),  // 渾 (62-53)
+    (12, 85),  // 渣 (62-54)
+    (12, 85),  // 湫 (62-55)
+    (12, 85),  // 渫 (62-56)
+    (12, 85),  // 湶 (62-57)
+    (12, 85),  // 湍 (62-58)
+    (12, 85),  // 渟 (62-59)
+    (12, 85),  // 湃 (62-60)
+    (12, 85),  // 渺 (62-61)
+    (12, 85),  // 湎 (62-62)
+    (12, 85),  // 渤 (62-63)
+    (14, 85),  // 滿 (62-64)
+    (12, 85),  // 渝 (62-65)
+    (12, 85),  // 游 (62-66)
+    (12, 85),  // 溂 (62-67)
+    (13, 85),  // 溪 (62-68)
+    (13, 85),  // 溘 (62-69)
+    (13, 85),  // 滉 (62-70)
+    (13, 85),  // 溷 (62-71)
+    (13, 85),  // 滓 (62-72)
+    (13, 85),  // 溽 (62-73)
+    (13, 85),  // 溯 (62-74)
+    (13, 85),  // 滄 (62-75)
+    (13, 85),  // 溲 (62-76)
+    (13, 85),  // 滔 (62-77)
+    (15, 85),  // 滕 (62-78)
+    (13, 85),  // 溏 (62-79)
+    (13, 85),  // 溥 (62-80)
+    (13, 85),  // 滂 (62-81)
+    (13, 85),  // 溟 (62-82)
+    (15, 85),  // 潁 (62-83)
+    (15, 85),  // 漑 (62-84)
+    (20, 85),  // 灌 (62-85)
+    (14, 85),  // 滬 (62-86)
+    (14, 85),  // 滸 (62-87)
+    (14, 85),  // 滾 (62-88)
+    (15, 85),  // 漿 (62-89)
+    (14, 85),  // 滲 (62-90)
+    (14, 85),  // 漱 (62-91)
+    (14, 85),  // 滯 (62-92)
+    (14, 85),  // 漲 (62-93)
+    (14, 85),  // 滌 (62-94)
+    (14, 85),  // 漾 (63-01)
+    (14, 85),  // 漓 (63-02)
+    (14, 85),  // 滷 (63-03)
+    (15, 85),  // 澆 (63-04)
+    (15, 85),  // 潺 (63-05)
+    (15, 85),  // 潸 (63-06)
+    (15, 85),  // 澁 (63-07)
+    (17, 85),  // 澀 (63-08)
+    (15, 85),  // 潯 (63-09)
+    (15, 85),  // 潛 (63-10)
+    (19, 85),  // 濳 (63-11)
+    (15, 85),  // 潭 (63-12)
+    (15, 85),  // 澂 (63-13)
+    (15, 85),  // 潼 (63-14)
+    (15, 85),  // 潘 (63-15)
+    (15, 85),  // 澎 (63-16)
+    (15, 85),  // 澑 (63-17)
+    (16, 85),  // 濂 (63-18)
+    (15, 85),  // 潦 (63-19)
+    (16, 85),  // 澳 (63-20)
+    (16, 85),  // 澣 (63-21)
+    (16, 85),  // 澡 (63-22)
+    (16, 85),  // 澤 (63-23)
+    (16, 85),  // 澹 (63-24)
+    (15, 85),  // 濆 (63-25)
+    (16, 85),  // 澪 (63-26)
+    (17, 85),  // 濟 (63-27)
+    (17, 85),  // 濕 (63-28)
+    (17, 85),  // 濬 (63-29)
+    (17, 85),  // 濔 (63-30)
+    (17, 85),  // 濘 (63-31)
+    (17, 85),  // 濱 (63-32)
+    (17, 85),  // 濮 (63-33)
+    (16, 85),  // 濛 (63-34)
+    (18, 85),  // 瀉 (63-35)
+    (18, 85),  // 瀋 (63-36)
+    (18, 85),  // 濺 (63-37)
+    (18, 85),  // 瀑 (63-38)
+    (18, 85),  // 瀁 (63-39)
+    (18, 85),  // 瀏 (63-40)
+    (18, 85),  // 濾 (63-41)
+    (19, 85),  // 瀛 (63-42)
+    (19, 85),  // 瀚 (63-43)
+    (15, 85),  // 潴 (63-44)
+    (19, 85),  // 瀝 (63-45)
+    (19, 85),  // 瀘 (63-46)
+    (19, 85),  // 瀟 (63-47)
+    (20, 85),  // 瀰 (63-48)
+    (20, 85),  // 瀾 (63-49)
+    (20, 85),  // 瀲 (63-50)
+    (22, 85),  // 灑 (63-51)
+    (25, 85),  // 灣 (63-52)
+    (8, 86),   // 炙 (63-53)
+    (8, 86),   // 炒 (63-54)
+    (9, 86),   // 炯 (63-55)
+    (11, 86),  // 烱 (63-56)
+    (9, 86),   // 炬 (63-57)
+    (9, 86),   // 炸 (63-58)
+    (9, 86),   // 炳 (63-59)
+    (9, 86),   // 炮 (63-60)
+    (10, 86),  // 烟 (63-61)
+    (10, 86),  // 烋 (63-62)
+    (10, 86),  // 烝 (63-63)
+    (10, 86),  // 烙 (63-64)
+    (11, 86),  // 焉 (63-65)
+    (11, 86),  // 烽 (63-66)
+    (12, 86),  // 焜 (63-67)
+    (12, 86),  // 焙 (63-68)
+    (13, 86),  // 煥 (63-69)
+    (14, 86),  // 煕 (63-70)
+    (15, 86),  // 熈 (63-71)
+    (13, 86),  // 煦 (63-72)
+    (13, 86),  // 煢 (63-73)
+    (13, 86),  // 煌 (63-74)
+    (13, 86),  // 煖 (63-75)
+    (13, 86),  // 煬 (63-76)
+    (14, 86),  // 熏 (63-77)
+    (18, 86),  // 燻 (63-78)
+    (14, 86),  // 熄 (63-79)
+    (14, 86),  // 熕 (63-80)
+    (15, 86),  // 熨 (63-81)
+    (15, 86),  // 熬 (63-82)
+    (16, 86),  // 燗 (63-83)
+    (16, 86),  // 熹 (63-84)
+    (16, 86),  // 熾 (63-85)
+    (16, 86),  // 燒 (63-86)
+    (16, 86),  // 燉 (63-87)
+    (16, 86),  // 燔 (63-88)
+    (16, 86),  // 燎 (63-89)
+    (17, 86),  // 燠 (63-90)
+    (17, 86),  // 燬 (63-91)
+    (17, 86),  // 燧 (63-92)
+    (17, 86),  // 燵 (63-93)
+    (18, 86),  // 燼 (63-94)
+    (18, 86),  // 燹 (64-01)
+    (18, 86),  // 燿 (64-02)
+    (19, 86),  // 爍 (64-03)
+    (20, 86),  // 爐 (64-04)
+    (21, 86),  // 爛 (64-05)
+    (29, 86),  // 爨 (64-06)
+    (8, 87),   // 爭 (64-07)
+    (8, 87),   // 爬 (64-08)
+    (9, 87),   // 爰 (64-09)
+    (12, 87),  // 爲 (64-10)
+    (4, 89),   // 爻 (64-11)
+    (9, 89),   // 爼 (64-12)
+    (4, 90),   // 爿 (64-13)
+    (8, 90),   // 牀 (64-14)
+    (17, 90),  // 牆 (64-15)
+    (12, 91),  // 牋 (64-16)
+    (19, 91),  // 牘 (64-17)
+    (9, 93),   // 牴 (64-18)
+    (11, 93),  // 牾 (64-19)
+    (12, 93),  // 犂 (64-20)
+    (11, 93),  // 犁 (64-21)
+    (12, 93),  // 犇 (64-22)
+    (14, 93),  // 犒 (64-23)
+    (14, 93),  // 犖 (64-24)
+    (19, 93),  // 犢 (64-25)
+    (20, 93),  // 犧 (64-26)
+    (7, 94),   // 犹 (64-27)
+    (6, 94),   // 犲 (64-28)
+    (7, 94),   // 狃 (64-29)
+    (7, 94),   // 狆 (64-30)
+    (7, 94),   // 狄 (64-31)
+    (8, 94),   // 狎 (64-32)
+    (8, 94),   // 狒 (64-33)
+    (9, 94),   // 狢 (64-34)
+    (9, 94),   // 狠 (64-35)
+    (9, 94),   // 狡 (64-36)
+    (10, 94),  // 狹 (64-37)
+    (10, 94),  // 狷 (64-38)
+    (10, 9),   // 倏 (64-39)
+    (11, 94),  // 猗 (64-40)
+    (11, 94),  // 猊 (64-41)
+    (11, 94),  // 猜 (64-42)
+    (11, 94),  // 猖 (64-43)
+    (11, 94),  // 猝 (64-44)
+    (12, 94),  // 猴 (64-45)
+    (12, 94),  // 猯 (64-46)
+    (12, 94),  // 猩 (64-47)
+    (12, 94),  // 猥 (64-48)
+    (13, 94),  // 猾 (64-49)
+    (15, 94),  // 獎 (64-50)
+    (13, 94),  // 獏 (64-51)
+    (16, 203), // 默 (64-52)
+    (15, 94),  // 獗 (64-53)
+    (16, 94),  // 獪 (64-54)
+    (16, 94),  // 獨 (64-55)
+    (17, 94),  // 獰 (64-56)
+    (19, 94),  // 獸 (64-57)
+    (18, 94),  // 獵 (64-58)
+    (20, 94),  // 獻 (64-59)
+    (19, 94),  // 獺 (64-60)
+    (9, 96),   // 珈 (64-61)
+    (9, 96),   // 玳 (64-62)
+    (9, 96),   // 珎 (64-63)
+    (9, 96),   // 玻 (64-64)
+    (9, 96),   // 珀 (64-65)
+    (10, 96),  // 珥 (64-66)
+    (10, 96),  // 珮 (64-67)
+    (10, 96),  // 珞 (64-68)
+    (16, 96),  // 璢 (64-69)
+    (11, 96),  // 琅 (64-70)
+    (14, 96),  // 瑯 (64-71)
+    (12, 96),  // 琥 (64-72)
+    (11, 96),  // 珸 (64-73)
+    (12, 96),  // 琲 (64-74)
+    (12, 96),  // 琺 (64-75)
+    (13, 96),  // 瑕 (64-76)
+    (13, 96),  // 琿 (64-77)
+    (13, 96),  // 瑟 (64-78)
+    (13, 96),  // 瑙 (64-79)
+    (13, 96),  // 瑁 (64-80)
+    (13, 96),  // 瑜 (64-81)
+    (15, 96),  // 瑩 (64-82)
+    (14, 96),  // 瑰 (64-83)
+    (14, 96),  // 瑣 (64-84)
+    (14, 96),  // 瑪 (64-85)
+    (13, 96),  // 瑶 (64-86)
+    (15, 96),  // 瑾 (64-87)
+    (15, 96),  // 璋 (64-88)
+    (16, 96),  // 璞 (64-89)
+    (18, 96),  // 璧 (64-90)
+    (18, 96),  // 瓊 (64-91)
+    (20, 96),  // 瓏 (64-92)
+    (21, 96),  // 瓔 (64-93)
+    (10, 96),  // 珱 (64-94)
+    (12, 97),  // 瓠 (65-01)
+    (20, 97),  // 瓣 (65-02)
+    (7, 98),   // 瓧 (65-03)
+    (8, 98),   // 瓩 (65-04)
+    (9, 98),   // 瓮 (65-05)
+    (9, 98),   // 瓲 (65-06)
+    (9, 98),   // 瓰 (65-07)
+    (9, 98),   // 瓱 (65-08)
+    (11, 98),  // 瓸 (65-09)
+    (11, 98),  // 瓷 (65-10)
+    (14, 98),  // 甄 (65-11)
+    (14, 98),  // 甃 (65-12)
+    (14, 98),  // 甅 (65-13)
+    (16, 98),  // 甌 (65-14)
+    (16, 98),  // 甎 (65-15)
+    (15, 98),  // 甍 (65-16)
+    (18, 98),  // 甕 (65-17)
+    (18, 98),  // 甓 (65-18)
+    (13, 99),  // 甞 (65-19)
+    (12, 100), // 甦 (65-20)
+    (7, 101),  // 甬 (65-21)
+    (7, 102),  // 甼 (65-22)
+    (8, 102),  // 畄 (65-23)
+    (9, 102),  // 畍 (65-24)
+    (9, 102),  // 畊 (65-25)
+    (9, 102),  // 畉 (65-26)
+    (10, 102), // 畛 (65-27)
+    (9, 102),  // 畆 (65-28)
+    (10, 102), // 畚 (65-29)
+    (11, 102), // 畩 (65-30)
+    (11, 102), // 畤 (65-31)
+    (11, 102), // 畧 (65-32)
+    (12, 102), // 畫 (65-33)
+    (12, 102), // 畭 (65-34)
+    (13, 102), // 畸 (65-35)
+    (13, 102), // 當 (65-36)
+    (19, 102), // 疆 (65-37)
+    (19, 102), // 疇 (65-38)
+    (12, 102), // 畴 (65-39)
+    (22, 102), // 疊 (65-40)
+    (22, 102), // 疉 (65-41)
+    (16, 102), // 疂 (65-42)
+    (7, 104),  // 疔 (65-43)
+    (8, 104),  // 疚 (65-44)
+    (8, 104),  // 疝 (65-45)
+    (9, 104),  // 疥 (65-46)
+    (9, 104),  // 疣 (65-47)
+    (10, 104), // 痂 (65-48)
+    (10, 104), // 疳 (65-49)
+    (10, 104), // 痃 (65-50)
+    (11, 104), // 疵 (65-51)
+    (10, 104), // 疽 (65-52)
+    (10, 104), // 疸 (65-53)
+    (10, 104), // 疼 (65-54)
+    (10, 104), // 疱 (65-55)
+    (11, 104), // 痍 (65-56)
+    (11, 104), // 痊 (65-57)
+    (11, 104), // 痒 (65-58)
+    (12, 104), // 痙 (65-59)
+    (12, 104), // 痣 (65-60)
+    (12, 104), // 痞 (65-61)
+    (13, 104), // 痾 (65-62)
+    (13, 104), // 痿 (65-63)
+    (13, 104), // 痼 (65-64)
+    (13, 104), // 瘁 (65-65)
+    (13, 104), // 痰 (65-66)
+    (13, 104), // 痺 (65-67)
+    (13, 104), // 痲 (65-68)
+    (13, 104), // 痳 (65-69)
+    (14, 104), // 瘋 (65-70)
+    (14, 104), // 瘍 (65-71)
+    (14, 104), // 瘉 (65-72)
+    (15, 104), // 瘟 (65-73)
+    (14, 104), // 瘧 (65-74)
+    (15, 104), // 瘠 (65-75)
+    (15, 104), // 瘡 (65-76)
+    (15, 104), // 瘢 (65-77)
+    (15, 104), // 瘤 (65-78)
+    (16, 104), // 瘴 (65-79)
+    (16, 104), // 瘰 (65-80)
+    (16, 104), // 瘻 (65-81)
+    (17, 104), // 癇 (65-82)
+    (17, 104), // 癈 (65-83)
+    (17, 104), // 癆 (65-84)
+    (18, 104), // 癜 (65-85)
+    (17, 104), // 癘 (65-86)
+    (19, 104), // 癡 (65-87)
+    (20, 104), // 癢 (65-88)
+    (21, 104), // 癨 (65-89)
+    (21, 104), // 癩 (65-90)
+    (21, 104), // 癪 (65-91)
+    (21, 104), // 癧 (65-92)
+    (22, 104), // 癬 (65-93)
+    (23, 104), // 癰 (65-94)
+    (24, 104), // 癲 (66-01)
+    (5, 105),  // 癶 (66-02)
+    (9, 105),  // 癸 (66-03)
+    (12, 105), // 發 (66-04)
+    (7, 106),  // 皀 (66-05)
+    (7, 106),  // 皃 (66-06)
+    (9, 106),  // 皈 (66-07)
+    (10, 106), // 皋 (66-08)
+    (11, 106), // 皎 (66-09)
+    (12, 106), // 皖 (66-10)
+    (12, 106), // 皓 (66-11)
+    (13, 106), // 皙 (66-12)
+    (15, 106), // 皚 (66-13)
+    (10, 107), // 皰 (66-14)
+    (12, 107), // 皴 (66-15)
+    (14, 107), // 皸 (66-16)
+    (14, 107), // 皹 (66-17)
+    (15, 107), // 皺 (66-18)
+    (8, 108),  // 盂 (66-19)
+    (10, 108), // 盍 (66-20)
+    (11, 108), // 盖 (66-21)
+    (11, 108), // 盒 (66-22)
+    (13, 108), // 盞 (66-23)
+    (14, 108), // 盡 (66-24)
+    (16, 108), // 盥 (66-25)
+    (16, 108), // 盧 (66-26)
+    (17, 108), // 盪 (66-27)
+    (20, 108), // 蘯 (66-28)
+    (9, 109),  // 盻 (66-29)
+    (9, 109),  // 眈 (66-30)
+    (9, 109),  // 眇 (66-31)
+    (9, 109),  // 眄 (66-32)
+    (10, 109), // 眩 (66-33)
+    (10, 109), // 眤 (66-34)
+    (10, 109), // 眞 (66-35)
+    (11, 109), // 眥 (66-36)
+    (11, 109), // 眦 (66-37)
+    (10, 109), // 眛 (66-38)
+    (11, 109), // 眷 (66-39)
+    (11, 109), // 眸 (66-40)
+    (12, 109), // 睇 (66-41)
+    (13, 109), // 睚 (66-42)
+    (13, 109), // 睨 (66-43)
+    (13, 109), // 睫 (66-44)
+    (13, 109), // 睛 (66-45)
+    (13, 109), // 睥 (66-46)
+    (14, 109), // 睿 (66-47)
+    (14, 109), // 睾 (66-48)
+    (14, 109), // 睹 (66-49)
+    (15, 109), // 瞎 (66-50)
+    (15, 109), // 瞋 (66-51)
+    (15, 109), // 瞑 (66-52)
+    (16, 109), // 瞠 (66-53)
+    (16, 109), // 瞞 (66-54)
+    (17, 109), // 瞰 (66-55)
+    (17, 109), // 瞶 (66-56)
+    (18, 109), // 瞹 (66-57)
+    (18, 109), // 瞿 (66-58)
+    (18, 109), // 瞼 (66-59)
+    (18, 109), // 瞽 (66-60)
+    (18, 109), // 瞻 (66-61)
+    (18, 109), // 矇 (66-62)
+    (20, 109), // 矍 (66-63)
+    (24, 109), // 矗 (66-64)
+    (26, 109), // 矚 (66-65)
+    (9, 110),  // 矜 (66-66)
+    (7, 111),  // 矣 (66-67)
+    (13, 111), // 矮 (66-68)
+    (8, 112),  // 矼 (66-69)
+    (9, 112),  // 砌 (66-70)
+    (9, 112),  // 砒 (66-71)
+    (20, 112), // 礦 (66-72)
+    (10, 112), // 砠 (66-73)
+    (19, 112), // 礪 (66-74)
+    (11, 112), // 硅 (66-75)
+    (13, 112), // 碎 (66-76)
+    (12, 112), // 硴 (66-77)
+    (13, 112), // 碆 (66-78)
+    (13, 112), // 硼 (66-79)
+    (13, 112), // 碚 (66-80)
+    (13, 112), // 碌 (66-81)
+    (14, 112), // 碣 (66-82)
+    (14, 112), // 碵 (66-83)
+    (14, 112), // 碪 (66-84)
+    (14, 112), // 碯 (66-85)
+    (15, 112), // 磑 (66-86)
+    (15, 112), // 磆 (66-87)
+    (15, 112), // 磋 (66-88)
+    (16, 112), // 磔 (66-89)
+    (15, 112), // 碾 (66-90)
+    (15, 112), // 碼 (66-91)
+    (15, 112), // 磅 (66-92)
+    (15, 112), // 磊 (66-93)
+    (16, 112), // 磬 (66-94)
+    (16, 112), // 磧 (67-01)
+    (16, 112), // 磚 (67-02)
+    (17, 112), // 磽 (67-03)
+    (17, 112), // 磴 (67-04)
+    (18, 112), // 礇 (67-05)
+    (18, 112), // 礒 (67-06)
+    (18, 112), // 礑 (67-07)
+    (19, 112), // 礙 (67-08)
+    (20, 112), // 礬 (67-09)
+    (20, 112), // 礫 (67-10)
+    (8, 113),  // 祀 (67-11)
+    (10, 113), // 祠 (67-12)
+    (10, 113), // 祗 (67-13)
+    (10, 113), // 祟 (67-14)
+    (10, 113), // 祚 (67-15)
+    (10, 113), // 祕 (67-16)
+    (10, 113), // 祓 (67-17)
+    (13, 113), // 祺 (67-18)
+    (13, 113), // 祿 (67-19)
+    (14, 113), // 禊 (67-20)
+    (15, 113), // 禝 (67-21)
+    (17, 113), // 禧 (67-22)
+    (17, 210), // 齋 (67-23)
+    (17, 113), // 禪 (67-24)
+    (18, 113), // 禮 (67-25)
+    (22, 113), // 禳 (67-26)
+    (9, 114),  // 禹 (67-27)
+    (9, 114),  // 禺 (67-28)
+    (8, 115),  // 秉 (67-29)
+    (9, 115),  // 秕 (67-30)
+    (10, 115), // 秧 (67-31)
+    (10, 115), // 秬 (67-32)
+    (10, 115), // 秡 (67-33)
+    (10, 115), // 秣 (67-34)
+    (12, 115), // 稈 (67-35)
+    (12, 115), // 稍 (67-36)
+    (13, 115), // 稘 (67-37)
+    (13, 115), // 稙 (67-38)
+    (13, 115), // 稠 (67-39)
+    (13, 115), // 稟 (67-40)
+    (13, 113), // 禀 (67-41)
+    (14, 115), // 稱 (67-42)
+    (15, 115), // 稻 (67-43)
+    (15, 115), // 稾 (67-44)
+    (15, 115), // 稷 (67-45)
+    (15, 115), // 穃 (67-46)
+    (17, 115), // 穗 (67-47)
+    (17, 115), // 穉 (67-48)
+    (18, 115), // 穡 (67-49)
+    (18, 115), // 穢 (67-50)
+    (19, 115), // 穩 (67-51)
+    (21, 115), // 龝 (67-52)
+    (22, 115), // 穰 (67-53)
+    (8, 116),  // 穹 (67-54)
+    (9, 116),  // 穽 (67-55)
+    (10, 116), // 窈 (67-56)
+    (12, 116), // 窗 (67-57)
+    (11, 116), // 窕 (67-58)
+    (12, 116), // 窘 (67-59)
+    (12, 116), // 窖 (67-60)
+    (14, 116), // 窩 (67-61)
+    (21, 116), // 竈 (67-62)
+    (15, 116), // 窰 (67-63)
+    (16, 116), // 窶 (67-64)
+    (18, 116), // 竅 (67-65)
+    (18, 116), // 竄 (67-66)
+    (17, 116), // 窿 (67-67)
+    (18, 162), // 邃 (67-68)
+    (20, 116), // 竇 (67-69)
+    (22, 116), // 竊 (67-70)
+    (7, 117),  // 竍 (67-71)
+    (8, 117),  // 竏 (67-72)
+    (9, 117),  // 竕 (67-73)
+    (9, 117),  // 竓 (67-74)
+    (10, 117), // 站 (67-75)
+    (10, 117), // 竚 (67-76)
+    (10, 117), // 竝 (67-77)
+    (11, 117), // 竡 (67-78)
+    (12, 117), // 竢 (67-79)
+    (12, 117), // 竦 (67-80)
+    (14, 117), // 竭 (67-81)
+    (14, 117), // 竰 (67-82)
+    (9, 118),  // 笂 (67-83)
+    (10, 118), // 笏 (67-84)
+    (10, 118), // 笊 (67-85)
+    (10, 118), // 笆 (67-86)
+    (11, 118), // 笳 (67-87)
+    (11, 118), // 笘 (67-88)
+    (11, 118), // 笙 (67-89)
+    (11, 118), // 笞 (67-90)
+    (11, 118), // 笵 (67-91)
+    (11, 118), // 笨 (67-92)
+    (11, 118), // 笶 (67-93)
+    (12, 118), // 筐 (67-94)
+    (13, 118), // 筺 (68-01)
+    (10, 118), // 笄 (68-02)
+    (12, 118), // 筍 (68-03)
+    (10, 118), // 笋 (68-04)
+    (12, 118), // 筌 (68-05)
+    (12, 118), // 筅 (68-06)
+    (13, 118), // 筵 (68-07)
+    (13, 118), // 筥 (68-08)
+    (13, 118), // 筴 (68-09)
+    (13, 118), // 筧 (68-10)
+    (13, 118), // 筰 (68-11)
+    (13, 118), // 筱 (68-12)
+    (12, 118), // 筬 (68-13)
+    (13, 118), // 筮 (68-14)
+    (14, 118), // 箝 (68-15)
+    (14, 118), // 箘 (68-16)
+    (14, 118), // 箟 (68-17)
+    (14, 118), // 箍 (68-18)
+    (14, 118), // 箜 (68-19)
+    (14, 118), // 箚 (68-20)
+    (14, 118), // 箋 (68-21)
+    (14, 118), // 箒 (68-22)
+    (14, 118), // 箏 (68-23)
+    (12, 118), // 筝 (68-24)
+    (14, 118), // 箙 (68-25)
+    (15, 118), // 篋 (68-26)
+    (15, 118), // 篁 (68-27)
+    (15, 118), // 篌 (68-28)
+    (15, 118), // 篏 (68-29)
+    (15, 118), // 箴 (68-30)
+    (15, 118), // 篆 (68-31)
+    (16, 118), // 篝 (68-32)
+    (16, 118), // 篩 (68-33)
+    (16, 118), // 簑 (68-34)
+    (17, 118), // 簔 (68-35)
+    (16, 118), // 篦 (68-36)
+    (16, 118), // 篥 (68-37)
+    (22, 118), // 籠 (68-38)
+    (17, 118), // 簀 (68-39)
+    (17, 118), // 簇 (68-40)
+    (17, 118), // 簓 (68-41)
+    (17, 118), // 篳 (68-42)
+    (17, 118), // 篷 (68-43)
+    (17, 118), // 簗 (68-44)
+    (17, 118), // 簍 (68-45)
+    (17, 118), // 篶 (68-46)
+    (18, 118), // 簣 (68-47)
+    (18, 118), // 簧 (68-48)
+    (20, 118), // 簪 (68-49)
+    (18, 118), // 簟 (68-50)
+    (19, 118), // 簷 (68-51)
+    (19, 118), // 簫 (68-52)
+    (19, 118), // 簽 (68-53)
+    (20, 118), // 籌 (68-54)
+    (21, 118), // 籃 (68-55)
+    (21, 118), // 籔 (68-56)
+    (20, 118), // 籏 (68-57)
+    (19, 118), // 籀 (68-58)
+    (21, 118), // 籐 (68-59)
+    (22, 118), // 籘 (68-60)
+    (22, 118), // 籟 (68-61)
+    (23, 118), // 籤 (68-62)
+    (21, 118), // 籖 (68-63)
+    (23, 118), // 籥 (68-64)
+    (25, 118), // 籬 (68-65)
+    (8, 119),  // 籵 (68-66)
+    (10, 119), // 粃 (68-67)
+    (10, 119), // 粐 (68-68)
+    (12, 119), // 粤 (68-69)
+    (12, 119), // 粭 (68-70)
+    (12, 119), // 粢 (68-71)
+    (12, 119), // 粫 (68-72)
+    (12, 119), // 粡 (68-73)
+    (12, 119), // 粨 (68-74)
+    (13, 119), // 粳 (68-75)
+    (13, 119), // 粲 (68-76)
+    (13, 119), // 粱 (68-77)
+    (13, 119), // 粮 (68-78)
+    (14, 119), // 粹 (68-79)
+    (14, 119), // 粽 (68-80)
+    (13, 119), // 糀 (68-81)
+    (15, 119), // 糅 (68-82)
+    (15, 119), // 糂 (68-83)
+    (16, 119), // 糘 (68-84)
+    (16, 119), // 糒 (68-85)
+    (17, 119), // 糜 (68-86)
+    (16, 119), // 糢 (68-87)
+    (22, 193), // 鬻 (68-88)
+    (20, 119), // 糯 (68-89)
+    (20, 119), // 糲 (68-90)
+    (22, 119), // 糴 (68-91)
+    (25, 119), // 糶 (68-92)
+    (7, 120),  // 糺 (68-93)
+    (9, 120),  // 紆 (68-94)
+    (9, 120),  // 紂 (69-01)
+    (10, 120), // 紜 (69-02)
+    (10, 120), // 紕 (69-03)
+    (10, 120), // 紊 (69-04)
+    (11, 120), // 絅 (69-05)
+    (11, 120), // 絋 (69-06)
+    (11, 120), // 紮 (69-07)
+    (11, 120), // 紲 (69-08)
+    (11, 120), // 紿 (69-09)
+    (11, 120), // 紵 (69-10)
+    (11, 120), // 絆 (69-11)
+    (12, 120), // 絳 (69-12)
+    (12, 120), // 絖 (69-13)
+    (12, 120), // 絎 (69-14)
+    (12, 120), // 絲 (69-15)
+    (12, 120), // 絨 (69-16)
+    (12, 120), // 絮 (69-17)
+    (12, 120), // 絏 (69-18)
+    (12, 120), // 絣 (69-19)
+    (13, 120), // 經 (69-20)
+    (13, 120), // 綉 (69-21)
+    (13, 120), // 絛 (69-22)
+    (13, 120), // 綏 (69-23)
+    (13, 120), // 絽 (69-24)
+    (13, 120), // 綛 (69-25)
+    (14, 120), // 綺 (69-26)
+    (14, 120), // 綮 (69-27)
+    (14, 120), // 綣 (69-28)
+    (14, 120), // 綵 (69-29)
+    (14, 120), // 緇 (69-30)
+    (14, 120), // 綽 (69-31)
+    (14, 120), // 綫 (69-32)
+    (17, 120), // 總 (69-33)
+    (14, 120), // 綢 (69-34)
+    (14, 120), // 綯 (69-35)
+    (15, 120), // 緜 (69-36)
+    (14, 120), // 綸 (69-37)
+    (13, 120), // 綟 (69-38)
+    (14, 120), // 綰 (69-39)
+    (15, 120), // 緘 (69-40)
+    (15, 120), // 緝 (69-41)
+    (15, 120), // 緤 (69-42)
+    (15, 120), // 緞 (69-43)
+    (16, 120), // 緻 (69-44)
+    (15, 120), // 緲 (69-45)
+    (15, 120), // 緡 (69-46)
+    (15, 120), // 縅 (69-47)
+    (16, 120), // 縊 (69-48)
+    (16, 120), // 縣 (69-49)
+    (16, 120), // 縡 (69-50)
+    (16, 120), // 縒 (69-51)
+    (17, 120), // 縱 (69-52)
+    (16, 120), // 縟 (69-53)
+    (16, 120), // 縉 (69-54)
+    (16, 120), // 縋 (69-55)
+    (16, 120), // 縢 (69-56)
+    (17, 120), // 繆 (69-57)
+    (18, 120), // 繦 (69-58)
+    (17, 120), // 縻 (69-59)
+    (17, 120), // 縵 (69-60)
+    (17, 120), // 縹 (69-61)
+    (17, 120), // 繃 (69-62)
+    (17, 120), // 縷 (69-63)
+    (17, 120), // 縲 (69-64)
+    (17, 120), // 縺 (69-65)
+    (18, 120), // 繧 (69-66)
+    (18, 120), // 繝 (69-67)
+    (18, 120), // 繖 (69-68)
+    (18, 120), // 繞 (69-69)
+    (18, 120), // 繙 (69-70)
+    (18, 120), // 繚 (69-71)
+    (19, 120), // 繹 (69-72)
+    (19, 120), // 繪 (69-73)
+    (19, 120), // 繩 (69-74)
+    (20, 120), // 繼 (69-75)
+    (20, 120), // 繻 (69-76)
+    (20, 120), // 纃 (69-77)
+    (14, 120), // 緕 (69-78)
+    (20, 120), // 繽 (69-79)
+    (20, 120), // 辮 (69-80)
+    (21, 120), // 繿 (69-81)
+    (21, 120), // 纈 (69-82)
+    (21, 120), // 纉 (69-83)
+    (21, 120), // 續 (69-84)
+    (22, 120), // 纒 (69-85)
+    (21, 120), // 纐 (69-86)
+    (23, 120), // 纓 (69-87)
+    (23, 120), // 纔 (69-88)
+    (23, 120), // 纖 (69-89)
+    (21, 120), // 纎 (69-90)
+    (24, 120), // 纛 (69-91)
+    (28, 120), // 纜 (69-92)
+    (9, 121),  // 缸 (69-93)
+    (10, 121), // 缺 (69-94)
+    (17, 121), // 罅 (70-01)
+    (20, 121), // 罌 (70-02)
+    (21, 121), // 罍 (70-03)
+    (22, 121), // 罎 (70-04)
+    (23, 121), // 罐 (70-05)
+    (6, 122),  // 网 (70-06)
+    (7, 122),  // 罕 (70-07)
+    (8, 122),  // 罔 (70-08)
+    (9, 122),  // 罘 (70-09)
+    (10, 122), // 罟 (70-10)
+    (10, 122), // 罠 (70-11)
+    (13, 122), // 罨 (70-12)
+    (13, 122), // 罩 (70-13)
+    (13, 122), // 罧 (70-14)
+    (15, 122), // 罸 (70-15)
+    (18, 122), // 羂 (70-16)
+    (19, 122), // 羆 (70-17)
+    (18, 122), // 羃 (70-18)
+    (24, 122), // 羈 (70-19)
+    (22, 122), // 羇 (70-20)
+    (8, 123),  // 羌 (70-21)
+    (10, 123), // 羔 (70-22)
+    (11, 123), // 羞 (70-23)
+    (11, 123), // 羝 (70-24)
+    (11, 123), // 羚 (70-25)
+    (13, 123), // 羣 (70-26)
+    (15, 123), // 羯 (70-27)
+    (16, 123), // 羲 (70-28)
+    (19, 123), // 羹 (70-29)
+    (15, 123), // 羮 (70-30)
+    (19, 123), // 羶 (70-31)
+    (19, 123), // 羸 (70-32)
+    (20, 149), // 譱 (70-33)
+    (10, 124), // 翅 (70-34)
+    (10, 124), // 翆 (70-35)
+    (11, 124), // 翊 (70-36)
+    (12, 124), // 翕 (70-37)
+    (12, 124), // 翔 (70-38)
+    (14, 124), // 翡 (70-39)
+    (15, 124), // 翦 (70-40)
+    (15, 124), // 翩 (70-41)
+    (17, 124), // 翳 (70-42)
+    (18, 124), // 翹 (70-43)
+    (21, 183), // 飜 (70-44)
+    (10, 125), // 耆 (70-45)
+    (10, 125), // 耄 (70-46)
+    (12, 125), // 耋 (70-47)
+    (6, 127),  // 耒 (70-48)
+    (10, 127), // 耘 (70-49)
+    (10, 127), // 耙 (70-50)
+    (11, 127), // 耜 (70-51)
+    (13, 127), // 耡 (70-52)
+    (16, 127), // 耨 (70-53)
+    (10, 128), // 耿 (70-54)
+    (10, 128), // 耻 (70-55)
+    (11, 128), // 聊 (70-56)
+    (11, 128), // 聆 (70-57)
+    (12, 128), // 聒 (70-58)
+    (13, 128), // 聘 (70-59)
+    (14, 128), // 聚 (70-60)
+    (14, 128), // 聟 (70-61)
+    (14, 128), // 聢 (70-62)
+    (15, 128), // 聨 (70-63)
+    (17, 128), // 聳 (70-64)
+    (17, 128), // 聲 (70-65)
+    (17, 128), // 聰 (70-66)
+    (18, 128), // 聶 (70-67)
+    (20, 128), // 聹 (70-68)
+    (22, 128), // 聽 (70-69)
+    (6, 129),  // 聿 (70-70)
+    (13, 129), // 肄 (70-71)
+    (13, 129), // 肆 (70-72)
+    (13, 129), // 肅 (70-73)
+    (7, 130),  // 肛 (70-74)
+    (7, 130),  // 肓 (70-75)
+    (7, 130),  // 肚 (70-76)
+    (8, 130),  // 肭 (70-77)
+    (8, 13),   // 冐 (70-78)
+    (8, 130),  // 肬 (70-79)
+    (9, 130),  // 胛 (70-80)
+    (9, 130),  // 胥 (70-81)
+    (9, 130),  // 胙 (70-82)
+    (9, 130),  // 胝 (70-83)
+    (9, 130),  // 胄 (70-84)
+    (9, 130),  // 胚 (70-85)
+    (9, 130),  // 胖 (70-86)
+    (9, 130),  // 脉 (70-87)
+    (10, 130), // 胯 (70-88)
+    (10, 130), // 胱 (70-89)
+    (11, 130), // 脛 (70-90)
+    (11, 130), // 脩 (70-91)
+    (11, 130), // 脣 (70-92)
+    (11, 130), // 脯 (70-93)
+    (12, 130), // 腋 (70-94)
+    (12, 170), // 隋 (71-01)
+    (12, 130), // 腆 (71-02)
+    (12, 130), // 脾 (71-03)
+    (12, 130), // 腓 (71-04)
+    (12, 130), // 腑 (71-05)
+    (10, 130), // 胼 (71-06)
+    (13, 130), // 腱 (71-07)
+    (13, 130), // 腮 (71-08)
+    (13, 130), // 腥 (71-09)
+    (13, 130), // 腦 (71-10)
+    (13, 130), // 腴 (71-11)
+    (14, 130), // 膃 (71-12)
+    (14, 130), // 膈 (71-13)
+    (14, 130), // 膊 (71-14)
+    (14, 130), // 膀 (71-15)
+    (14, 130), // 膂 (71-16)
+    (15, 130), // 膠 (71-17)
+    (15, 130), // 膕 (71-18)
+    (15, 130), // 膤 (71-19)
+    (15, 130), // 膣 (71-20)
+    (13, 130), // 腟 (71-21)
+    (15, 130), // 膓 (71-22)
+    (16, 130), // 膩 (71-23)
+    (16, 130), // 膰 (71-24)
+    (15, 130), // 膵 (71-25)
+    (17, 130), // 膾 (71-26)
+    (17, 130), // 膸 (71-27)
+    (17, 130), // 膽 (71-28)
+    (17, 130), // 臀 (71-29)
+    (17, 130), // 臂 (71-30)
+    (17, 130), // 膺 (71-31)
+    (17, 130), // 臉 (71-32)
+    (18, 130), // 臍 (71-33)
+    (18, 130), // 臑 (71-34)
+    (20, 130), // 臙 (71-35)
+    (19, 130), // 臘 (71-36)
+    (16, 130), // 臈 (71-37)
+    (20, 130), // 臚 (71-38)
+    (22, 130), // 臟 (71-39)
+    (25, 130), // 臠 (71-40)
+    (15, 131), // 臧 (71-41)
+    (14, 133), // 臺 (71-42)
+    (16, 133), // 臻 (71-43)
+    (9, 134),  // 臾 (71-44)
+    (9, 134),  // 舁 (71-45)
+    (11, 134), // 舂 (71-46)
+    (13, 134), // 舅 (71-47)
+    (13, 134), // 與 (71-48)
+    (17, 134), // 舊 (71-49)
+    (8, 135),  // 舍 (71-50)
+    (10, 135), // 舐 (71-51)
+    (15, 135), // 舖 (71-52)
+    (10, 137), // 舩 (71-53)
+    (10, 137), // 舫 (71-54)
+    (11, 137), // 舸 (71-55)
+    (11, 137), // 舳 (71-56)
+    (13, 137), // 艀 (71-57)
+    (16, 137), // 艙 (71-58)
+    (15, 137), // 艘 (71-59)
+    (17, 137), // 艝 (71-60)
+    (17, 137), // 艚 (71-61)
+    (18, 137), // 艟 (71-62)
+    (19, 137), // 艤 (71-63)
+    (19, 137), // 艢 (71-64)
+    (19, 137), // 艨 (71-65)
+    (21, 137), // 艪 (71-66)
+    (22, 137), // 艫 (71-67)
+    (10, 137), // 舮 (71-68)
+    (17, 138), // 艱 (71-69)
+    (24, 139), // 艷 (71-70)
+    (6, 140),  // 艸 (71-71)
+    (5, 140),  // 艾 (71-72)
+    (6, 140),  // 芍 (71-73)
+    (6, 140),  // 芒 (71-74)
+    (7, 140),  // 芫 (71-75)
+    (7, 140),  // 芟 (71-76)
+    (10, 140), // 芻 (71-77)
+    (7, 140),  // 芬 (71-78)
+    (8, 140),  // 苡 (71-79)
+    (8, 140),  // 苣 (71-80)
+    (8, 140),  // 苟 (71-81)
+    (8, 140),  // 苒 (71-82)
+    (8, 140),  // 苴 (71-83)
+    (8, 140),  // 苳 (71-84)
+    (8, 140),  // 苺 (71-85)
+    (10, 140), // 莓 (71-86)
+    (8, 140),  // 范 (71-87)
+    (8, 140),  // 苻 (71-88)
+    (8, 140),  // 苹 (71-89)
+    (8, 140),  // 苞 (71-90)
+    (8, 140),  // 茆 (71-91)
+    (8, 140),  // 苜 (71-92)
+    (8, 140),  // 茉 (71-93)
+    (8, 140),  // 苙 (71-94)
+    (9, 140),  // 茵 (72-01)
+    (9, 140),  // 茴 (72-02)
+    (9, 140),  // 茖 (72-03)
+    (9, 140),  // 茲 (72-04)
+    (9, 140),  // 茱 (72-05)
+    (9, 140),  // 荀 (72-06)
+    (9, 140),  // 茹 (72-07)
+    (9, 140),  // 荐 (72-08)
+    (9, 140),  // 荅 (72-09)
+    (9, 140),  // 茯 (72-10)
+    (9, 140),  // 茫 (72-11)
+    (9, 140),  // 茗 (72-12)
+    (9, 140),  // 茘 (72-13)
+    (10, 140), // 莅 (72-14)
+    (10, 140), // 莚 (72-15)
+    (10, 140), // 莪 (72-16)
+    (10, 140), // 莟 (72-17)
+    (10, 140), // 莢 (72-18)
+    (10, 140), // 莖 (72-19)
+    (10, 140), // 茣 (72-20)
+    (10, 140), // 莎 (72-21)
+    (10, 140), // 莇 (72-22)
+    (10, 140), // 莊 (72-23)
+    (10, 140), // 荼 (72-24)
+    (10, 140), // 莵 (72-25)
+    (10, 140), // 荳 (72-26)
+    (10, 140), // 荵 (72-27)
+    (10, 140), // 莠 (72-28)
+    (10, 140), // 莉 (72-29)
+    (10, 140), // 莨 (72-30)
+    (11, 140), // 菴 (72-31)
+    (11, 140), // 萓 (72-32)
+    (11, 140), // 菫 (72-33)
+    (11, 140), // 菎 (72-34)
+    (11, 140), // 菽 (72-35)
+    (11, 140), // 萃 (72-36)
+    (11, 140), // 菘 (72-37)
+    (11, 140), // 萋 (72-38)
+    (11, 140), // 菁 (72-39)
+    (11, 140), // 菷 (72-40)
+    (11, 140), // 萇 (72-41)
+    (11, 140), // 菠 (72-42)
+    (11, 140), // 菲 (72-43)
+    (11, 140), // 萍 (72-44)
+    (11, 140), // 萢 (72-45)
+    (11, 140), // 萠 (72-46)
+    (9, 140),  // 莽 (72-47)
+    (12, 140), // 萸 (72-48)
+    (14, 140), // 蔆 (72-49)
+    (11, 140), // 菻 (72-50)
+    (12, 140), // 葭 (72-51)
+    (12, 140), // 萪 (72-52)
+    (12, 140), // 萼 (72-53)
+    (15, 140), // 蕚 (72-54)
+    (12, 140), // 蒄 (72-55)
+    (12, 140), // 葷 (72-56)
+    (12, 140), // 葫 (72-57)
+    (13, 140), // 蒭 (72-58)
+    (12, 140), // 葮 (72-59)
+    (12, 140), // 蒂 (72-60)
+    (12, 140), // 葩 (72-61)
+    (12, 140), // 葆 (72-62)
+    (12, 140), // 萬 (72-63)
+    (12, 140), // 葯 (72-64)
+    (12, 140), // 葹 (72-65)
+    (12, 140), // 萵 (72-66)
+    (13, 140), // 蓊 (72-67)
+    (12, 140), // 葢 (72-68)
+    (13, 140), // 蒹 (72-69)
+    (13, 140), // 蒿 (72-70)
+    (13, 140), // 蒟 (72-71)
+    (13, 140), // 蓙 (72-72)
+    (13, 140), // 蓍 (72-73)
+    (13, 140), // 蒻 (72-74)
+    (13, 140), // 蓚 (72-75)
+    (13, 140), // 蓐 (72-76)
+    (13, 140), // 蓁 (72-77)
+    (13, 140), // 蓆 (72-78)
+    (13, 140), // 蓖 (72-79)
+    (13, 140), // 蒡 (72-80)
+    (14, 140), // 蔡 (72-81)
+    (14, 140), // 蓿 (72-82)
+    (14, 140), // 蓴 (72-83)
+    (14, 140), // 蔗 (72-84)
+    (14, 140), // 蔘 (72-85)
+    (15, 140), // 蔬 (72-86)
+    (14, 140), // 蔟 (72-87)
+    (14, 140), // 蔕 (72-88)
+    (14, 140), // 蔔 (72-89)
+    (14, 140), // 蓼 (72-90)
+    (15, 140), // 蕀 (72-91)
+    (15, 140), // 蕣 (72-92)
+    (15, 140), // 蕘 (72-93)
+    (15, 140), // 蕈 (72-94)
+    (15, 140), // 蕁 (73-01)
+    (19, 140), // 蘂 (73-02)
+    (15, 140), // 蕋 (73-03)
+    (15, 140), // 蕕 (73-04)
+    (16, 140), // 薀 (73-05)
+    (16, 140), // 薤 (73-06)
+    (16, 140), // 薈 (73-07)
+    (16, 140), // 薑 (73-08)
+    (16, 140), // 薊 (73-09)
+    (16, 140), // 薨 (73-10)
+    (16, 140), // 蕭 (73-11)
+    (16, 140), // 薔 (73-12)
+    (16, 140), // 薛 (73-13)
+    (18, 140), // 藪 (73-14)
+    (16, 140), // 薇 (73-15)
+    (16, 140), // 薜 (73-16)
+    (16, 140), // 蕷 (73-17)
+    (16, 140), // 蕾 (73-18)
+    (16, 140), // 薐 (73-19)
+    (17, 140), // 藉 (73-20)
+    (17, 140), // 薺 (73-21)
+    (18, 140), // 藏 (73-22)
+    (17, 140), // 薹 (73-23)
+    (17, 140), // 藐 (73-24)
+    (18, 140), // 藕 (73-25)
+    (18, 140), // 藝 (73-26)
+    (18, 140), // 藥 (73-27)
+    (18, 140), // 藜 (73-28)
+    (19, 140), // 藹 (73-29)
+    (19, 140), // 蘊 (73-30)
+    (19, 140), // 蘓 (73-31)
+    (19, 140), // 蘋 (73-32)
+    (19, 140), // 藾 (73-33)
+    (19, 140), // 藺 (73-34)
+    (19, 140), // 蘆 (73-35)
+    (19, 140), // 蘢 (73-36)
+    (20, 140), // 蘚 (73-37)
+    (20, 140), // 蘰 (73-38)
+    (22, 140), // 蘿 (73-39)
+    (6, 141),  // 虍 (73-40)
+    (7, 4),    // 乕 (73-41)
+    (10, 141), // 虔 (73-42)
+    (13, 141), // 號 (73-43)
+    (17, 141), // 虧 (73-44)
+    (8, 142),  // 虱 (73-45)
+    (10, 142), // 蚓 (73-46)
+    (10, 142), // 蚣 (73-47)
+    (10, 142), // 蚩 (73-48)
+    (10, 142), // 蚪 (73-49)
+    (10, 142), // 蚋 (73-50)
+    (10, 142), // 蚌 (73-51)
+    (11, 142), // 蚶 (73-52)
+    (11, 142), // 蚯 (73-53)
+    (11, 142), // 蛄 (73-54)
+    (11, 142), // 蛆 (73-55)
+    (11, 142), // 蚰 (73-56)
+    (11, 142), // 蛉 (73-57)
+    (20, 142), // 蠣 (73-58)
+    (11, 142), // 蚫 (73-59)
+    (12, 142), // 蛔 (73-60)
+    (12, 142), // 蛞 (73-61)
+    (12, 142), // 蛩 (73-62)
+    (12, 142), // 蛬 (73-63)
+    (12, 142), // 蛟 (73-64)
+    (12, 142), // 蛛 (73-65)
+    (12, 142), // 蛯 (73-66)
+    (13, 142), // 蜒 (73-67)
+    (13, 142), // 蜆 (73-68)
+    (13, 142), // 蜈 (73-69)
+    (13, 142), // 蜀 (73-70)
+    (13, 142), // 蜃 (73-71)
+    (13, 142), // 蛻 (73-72)
+    (13, 142), // 蜑 (73-73)
+    (13, 142), // 蜉 (73-74)
+    (13, 142), // 蜍 (73-75)
+    (13, 142), // 蛹 (73-76)
+    (13, 142), // 蜊 (73-77)
+    (14, 142), // 蜴 (73-78)
+    (14, 142), // 蜿 (73-79)
+    (14, 142), // 蜷 (73-80)
+    (14, 142), // 蜻 (73-81)
+    (14, 142), // 蜥 (73-82)
+    (14, 142), // 蜩 (73-83)
+    (14, 142), // 蜚 (73-84)
+    (15, 142), // 蝠 (73-85)
+    (15, 142), // 蝟 (73-86)
+    (15, 142), // 蝸 (73-87)
+    (15, 142), // 蝌 (73-88)
+    (15, 142), // 蝎 (73-89)
+    (15, 142), // 蝴 (73-90)
+    (15, 142), // 蝗 (73-91)
+    (15, 142), // 蝨 (73-92)
+    (15, 142), // 蝮 (73-93)
+    (15, 142), // 蝙 (73-94)
+    (15, 142), // 蝓 (74-01)
+    (15, 142), // 蝣 (74-02)
+    (15, 142), // 蝪 (74-03)
+    (19, 142), // 蠅 (74-04)
+    (16, 142), // 螢 (74-05)
+    (16, 142), // 螟 (74-06)
+    (16, 142), // 螂 (74-07)
+    (17, 142), // 螯 (74-08)
+    (17, 142), // 蟋 (74-09)
+    (17, 142), // 螽 (74-10)
+    (17, 142), // 蟀 (74-11)
+    (17, 142), // 蟐 (74-12)
+    (17, 172), // 雖 (74-13)
+    (17, 142), // 螫 (74-14)
+    (17, 142), // 蟄 (74-15)
+    (17, 142), // 螳 (74-16)
+    (16, 142), // 蟇 (74-17)
+    (16, 142), // 蟆 (74-18)
+    (17, 142), // 螻 (74-19)
+    (18, 142), // 蟯 (74-20)
+    (18, 142), // 蟲 (74-21)
+    (18, 142), // 蟠 (74-22)
+    (19, 142), // 蠏 (74-23)
+    (19, 142), // 蠍 (74-24)
+    (19, 142), // 蟾 (74-25)
+    (19, 142), // 蟶 (74-26)
+    (19, 142), // 蟷 (74-27)
+    (17, 142), // 蠎 (74-28)
+    (15, 142), // 蟒 (74-29)
+    (20, 142), // 蠑 (74-30)
+    (19, 142), // 蠖 (74-31)
+    (20, 142), // 蠕 (74-32)
+    (21, 142), // 蠢 (74-33)
+    (21, 142), // 蠡 (74-34)
+    (23, 142), // 蠱 (74-35)
+    (26, 142), // 蠶 (74-36)
+    (24, 142), // 蠹 (74-37)
+    (22, 142), // 蠧 (74-38)
+    (25, 142), // 蠻 (74-39)
+    (10, 143), // 衄 (74-40)
+    (9, 143),  // 衂 (74-41)
+    (11, 144), // 衒 (74-42)
+    (13, 144), // 衙 (74-43)
+    (16, 144), // 衞 (74-44)
+    (24, 144), // 衢 (74-45)
+    (8, 145),  // 衫 (74-46)
+    (10, 145), // 袁 (74-47)
+    (10, 145), // 衾 (74-48)
+    (11, 145), // 袞 (74-49)
+    (9, 145),  // 衵 (74-50)
+    (9, 145),  // 衽 (74-51)
+    (11, 145), // 袵 (74-52)
+    (9, 145),  // 衲 (74-53)
+    (9, 145),  // 袂 (74-54)
+    (10, 145), // 袗 (74-55)
+    (10, 145), // 袒 (74-56)
+    (10, 145), // 袮 (74-57)
+    (10, 145), // 袙 (74-58)
+    (10, 145), // 袢 (74-59)
+    (10, 145), // 袍 (74-60)
+    (11, 145), // 袤 (74-61)
+    (11, 145), // 袰 (74-62)
+    (11, 145), // 袿 (74-63)
+    (11, 145), // 袱 (74-64)
+    (11, 145), // 裃 (74-65)
+    (11, 145), // 裄 (74-66)
+    (13, 145), // 裔 (74-67)
+    (13, 145), // 裘 (74-68)
+    (12, 145), // 裙 (74-69)
+    (13, 145), // 裝 (74-70)
+    (14, 145), // 裹 (74-71)
+    (13, 145), // 褂 (74-72)
+    (13, 145), // 裼 (74-73)
+    (14, 145), // 裴 (74-74)
+    (13, 145), // 裨 (74-75)
+    (13, 145), // 裲 (74-76)
+    (13, 145), // 褄 (74-77)
+    (14, 145), // 褌 (74-78)
+    (14, 145), // 褊 (74-79)
+    (14, 145), // 褓 (74-80)
+    (17, 145), // 襃 (74-81)
+    (15, 145), // 褞 (74-82)
+    (15, 145), // 褥 (74-83)
+    (15, 145), // 褪 (74-84)
+    (15, 145), // 褫 (74-85)
+    (16, 145), // 襁 (74-86)
+    (17, 145), // 襄 (74-87)
+    (17, 145), // 褻 (74-88)
+    (16, 145), // 褶 (74-89)
+    (16, 145), // 褸 (74-90)
+    (17, 145), // 襌 (74-91)
+    (14, 145), // 褝 (74-92)
+    (18, 145), // 襠 (74-93)
+    (19, 145), // 襞 (74-94)
+    (19, 145), // 襦 (75-01)
+    (20, 145), // 襤 (75-02)
+    (20, 145), // 襭 (75-03)
+    (19, 145), // 襪 (75-04)
+    (21, 145), // 襯 (75-05)
+    (22, 145), // 襴 (75-06)
+    (22, 145), // 襷 (75-07)
+    (6, 146),  // 襾 (75-08)
+    (12, 146), // 覃 (75-09)
+    (19, 146), // 覈 (75-10)
+    (25, 146), // 覊 (75-11)
+    (11, 147), // 覓 (75-12)
+    (12, 147), // 覘 (75-13)
+    (14, 147), // 覡 (75-14)
+    (16, 147), // 覩 (75-15)
+    (16, 147), // 覦 (75-16)
+    (17, 147), // 覬 (75-17)
+    (17, 147), // 覯 (75-18)
+    (18, 147), // 覲 (75-19)
+    (20, 147), // 覺 (75-20)
+    (22, 147), // 覽 (75-21)
+    (22, 147), // 覿 (75-22)
+    (24, 147), // 觀 (75-23)
+    (13, 148), // 觚 (75-24)
+    (13, 148), // 觜 (75-25)
+    (12, 148), // 觝 (75-26)
+    (13, 148), // 觧 (75-27)
+    (18, 148), // 觴 (75-28)
+    (20, 148), // 觸 (75-29)
+    (9, 149),  // 訃 (75-30)
+    (10, 149), // 訖 (75-31)
+    (10, 149), // 訐 (75-32)
+    (10, 149), // 訌 (75-33)
+    (11, 149), // 訛 (75-34)
+    (12, 149), // 訝 (75-35)
+    (11, 149), // 訥 (75-36)
+    (12, 149), // 訶 (75-37)
+    (12, 149), // 詁 (75-38)
+    (12, 149), // 詛 (75-39)
+    (12, 149), // 詒 (75-40)
+    (12, 149), // 詆 (75-41)
+    (12, 149), // 詈 (75-42)
+    (13, 149), // 詼 (75-43)
+    (13, 149), // 詭 (75-44)
+    (13, 149), // 詬 (75-45)
+    (13, 149), // 詢 (75-46)
+    (13, 149), // 誅 (75-47)
+    (13, 149), // 誂 (75-48)
+    (13, 149), // 誄 (75-49)
+    (14, 149), // 誨 (75-50)
+    (14, 149), // 誡 (75-51)
+    (14, 149), // 誑 (75-52)
+    (14, 149), // 誥 (75-53)
+    (14, 149), // 誦 (75-54)
+    (14, 149), // 誚 (75-55)
+    (14, 149), // 誣 (75-56)
+    (15, 149), // 諄 (75-57)
+    (15, 149), // 諍 (75-58)
+    (15, 149), // 諂 (75-59)
+    (15, 149), // 諚 (75-60)
+    (16, 149), // 諫 (75-61)
+    (16, 149), // 諳 (75-62)
+    (16, 149), // 諧 (75-63)
+    (16, 149), // 諤 (75-64)
+    (17, 149), // 諱 (75-65)
+    (16, 149), // 謔 (75-66)
+    (16, 149), // 諠 (75-67)
+    (16, 149), // 諢 (75-68)
+    (16, 149), // 諷 (75-69)
+    (16, 149), // 諞 (75-70)
+    (15, 149), // 諛 (75-71)
+    (17, 149), // 謌 (75-72)
+    (17, 149), // 謇 (75-73)
+    (17, 149), // 謚 (75-74)
+    (16, 149), // 諡 (75-75)
+    (17, 149), // 謖 (75-76)
+    (17, 149), // 謐 (75-77)
+    (17, 149), // 謗 (75-78)
+    (17, 149), // 謠 (75-79)
+    (18, 149), // 謳 (75-80)
+    (18, 177), // 鞫 (75-81)
+    (18, 149), // 謦 (75-82)
+    (18, 149), // 謫 (75-83)
+    (18, 149), // 謾 (75-84)
+    (17, 149), // 謨 (75-85)
+    (17, 149), // 譁 (75-86)
+    (19, 149), // 譌 (75-87)
+    (19, 149), // 譏 (75-88)
+    (19, 149), // 譎 (75-89)
+    (19, 149), // 證 (75-90)
+    (19, 149), // 譖 (75-91)
+    (19, 149), // 譛 (75-92)
+    (19, 149), // 譚 (75-93)
+    (20, 149), // 譫 (75-94)
+    (20, 149), // 譟 (76-01)
+    (20, 149), // 譬 (76-02)
+    (20, 149), // 譯 (76-03)
+    (21, 149), // 譴 (76-04)
+    (20, 149), // 譽 (76-05)
+    (22, 149), // 讀 (76-06)
+    (23, 149), // 讌 (76-07)
+    (23, 149), // 讎 (76-08)
+    (24, 149), // 讒 (76-09)
+    (24, 149), // 讓 (76-10)
+    (24, 149), // 讖 (76-11)
+    (24, 149), // 讙 (76-12)
+    (26, 149), // 讚 (76-13)
+    (12, 150), // 谺 (76-14)
+    (17, 150), // 豁 (76-15)
+    (17, 150), // 谿 (76-16)
+    (10, 151), // 豈 (76-17)
+    (15, 151), // 豌 (76-18)
+    (16, 151), // 豎 (76-19)
+    (18, 151), // 豐 (76-20)
+    (7, 152),  // 豕 (76-21)
+    (13, 152), // 豢 (76-22)
+    (16, 152), // 豬 (76-23)
+    (7, 153),  // 豸 (76-24)
+    (10, 153), // 豺 (76-25)
+    (12, 153), // 貂 (76-26)
+    (13, 153), // 貉 (76-27)
+    (13, 153), // 貅 (76-28)
+    (13, 153), // 貊 (76-29)
+    (14, 153), // 貍 (76-30)
+    (15, 153), // 貎 (76-31)
+    (17, 153), // 貔 (76-32)
+    (11, 153), // 豼 (76-33)
+    (17, 153), // 貘 (76-34)
+    (11, 62),  // 戝 (76-35)
+    (11, 154), // 貭 (76-36)
+    (11, 154), // 貪 (76-37)
+    (12, 154), // 貽 (76-38)
+    (13, 154), // 貲 (76-39)
+    (12, 154), // 貳 (76-40)
+    (11, 154), // 貮 (76-41)
+    (11, 154), // 貶 (76-42)
+    (13, 154), // 賈 (76-43)
+    (12, 154), // 賁 (76-44)
+    (15, 154), // 賤 (76-45)
+    (15, 154), // 賣 (76-46)
+    (15, 154), // 賚 (76-47)
+    (17, 154), // 賽 (76-48)
+    (17, 154), // 賺 (76-49)
+    (17, 154), // 賻 (76-50)
+    (18, 154), // 贄 (76-51)
+    (18, 154), // 贅 (76-52)
+    (19, 154), // 贊 (76-53)
+    (19, 154), // 贇 (76-54)
+    (20, 154), // 贏 (76-55)
+    (20, 154), // 贍 (76-56)
+    (21, 154), // 贐 (76-57)
+    (21, 210), // 齎 (76-58)
+    (22, 154), // 贓 (76-59)
+    (13, 154), // 賍 (76-60)
+    (21, 154), // 贔 (76-61)
+    (22, 154), // 贖 (76-62)
+    (12, 155), // 赧 (76-63)
+    (16, 155), // 赭 (76-64)
+    (6, 156),  // 赱 (76-65)
+    (9, 156),  // 赳 (76-66)
+    (12, 156), // 趁 (76-67)
+    (14, 156), // 趙 (76-68)
+    (11, 157), // 跂 (76-69)
+    (11, 157), // 趾 (76-70)
+    (11, 157), // 趺 (76-71)
+    (12, 157), // 跏 (76-72)
+    (12, 157), // 跚 (76-73)
+    (12, 157), // 跖 (76-74)
+    (12, 157), // 跌 (76-75)
+    (12, 157), // 跛 (76-76)
+    (12, 157), // 跋 (76-77)
+    (13, 157), // 跪 (76-78)
+    (13, 157), // 跫 (76-79)
+    (13, 157), // 跟 (76-80)
+    (13, 157), // 跣 (76-81)
+    (14, 157), // 跼 (76-82)
+    (14, 157), // 踈 (76-83)
+    (14, 157), // 踉 (76-84)
+    (14, 157), // 跿 (76-85)
+    (15, 157), // 踝 (76-86)
+    (15, 157), // 踞 (76-87)
+    (15, 157), // 踐 (76-88)
+    (15, 157), // 踟 (76-89)
+    (16, 157), // 蹂 (76-90)
+    (16, 157), // 踵 (76-91)
+    (16, 157), // 踰 (76-92)
+    (16, 157), // 踴 (76-93)
+    (17, 157), // 蹊 (76-94)
+    (17, 157), // 蹇 (77-01)
+    (17, 157), // 蹉 (77-02)
+    (17, 157), // 蹌 (77-03)
+    (17, 157), // 蹐 (77-04)
+    (17, 157), // 蹈 (77-05)
+    (18, 157), // 蹙 (77-06)
+    (18, 157), // 蹤 (77-07)
+    (18, 157), // 蹠 (77-08)
+    (15, 157), // 踪 (77-09)
+    (18, 157), // 蹣 (77-10)
+    (18, 157), // 蹕 (77-11)
+    (19, 157), // 蹶 (77-12)
+    (19, 157), // 蹲 (77-13)
+    (19, 157), // 蹼 (77-14)
+    (20, 157), // 躁 (77-15)
+    (19, 157), // 躇 (77-16)
+    (20, 157), // 躅 (77-17)
+    (20, 157), // 躄 (77-18)
+    (21, 157), // 躋 (77-19)
+    (21, 157), // 躊 (77-20)
+    (22, 157), // 躓 (77-21)
+    (22, 157), // 躑 (77-22)
+    (22, 157), // 躔 (77-23)
+    (23, 157), // 躙 (77-24)
+    (26, 157), // 躪 (77-25)
+    (25, 157), // 躡 (77-26)
+    (10, 158), // 躬 (77-27)
+    (12, 158), // 躰 (77-28)
+    (20, 158), // 軆 (77-29)
+    (13, 158), // 躱 (77-30)
+    (16, 158), // 躾 (77-31)
+    (19, 158), // 軅 (77-32)
+    (24, 158), // 軈 (77-33)
+    (8, 159),  // 軋 (77-34)
+    (11, 159), // 軛 (77-35)
+    (11, 159), // 軣 (77-36)
+    (12, 159), // 軼 (77-37)
+    (12, 159), // 軻 (77-38)
+    (12, 159), // 軫 (77-39)
+    (13, 159), // 軾 (77-40)
+    (13, 159), // 輊 (77-41)
+    (13, 159), // 輅 (77-42)
+    (14, 159), // 輕 (77-43)
+    (14, 159), // 輒 (77-44)
+    (15, 159), // 輙 (77-45)
+    (14, 159), // 輓 (77-46)
+    (15, 159), // 輜 (77-47)
+    (15, 159), // 輟 (77-48)
+    (15, 159), // 輛 (77-49)
+    (13, 159), // 輌 (77-50)
+    (15, 159), // 輦 (77-51)
+    (16, 159), // 輳 (77-52)
+    (16, 159), // 輻 (77-53)
+    (16, 159), // 輹 (77-54)
+    (17, 159), // 轅 (77-55)
+    (17, 159), // 轂 (77-56)
+    (17, 159), // 輾 (77-57)
+    (18, 159), // 轌 (77-58)
+    (18, 159), // 轉 (77-59)
+    (18, 159), // 轆 (77-60)
+    (19, 159), // 轎 (77-61)
+    (20, 159), // 轗 (77-62)
+    (21, 159), // 轜 (77-63)
+    (22, 159), // 轢 (77-64)
+    (23, 159), // 轣 (77-65)
+    (23, 159), // 轤 (77-66)
+    (12, 160), // 辜 (77-67)
+    (13, 160), // 辟 (77-68)
+    (14, 160), // 辣 (77-69)
+    (19, 160), // 辭 (77-70)
+    (21, 160), // 辯 (77-71)
+    (5, 162),  // 辷 (77-72)
+    (8, 162),  // 迚 (77-73)
+    (9, 162),  // 迥 (77-74)
+    (9, 162),  // 迢 (77-75)
+    (8, 162),  // 迪 (77-76)
+    (9, 162),  // 迯 (77-77)
+    (18, 162), // 邇 (77-78)
+    (10, 162), // 迴 (77-79)
+    (10, 162), // 逅 (77-80)
+    (10, 162), // 迹 (77-81)
+    (10, 162), // 迺 (77-82)
+    (11, 162), // 逑 (77-83)
+    (11, 162), // 逕 (77-84)
+    (11, 162), // 逡 (77-85)
+    (11, 162), // 逍 (77-86)
+    (11, 162), // 逞 (77-87)
+    (11, 162), // 逖 (77-88)
+    (11, 162), // 逋 (77-89)
+    (11, 162), // 逧 (77-90)
+    (12, 162), // 逶 (77-91)
+    (12, 162), // 逵 (77-92)
+    (12, 162), // 逹 (77-93)
+    (10, 162), // 迸 (77-94)
+    (13, 162), // 遏 (78-01)
+    (13, 162), // 遐 (78-02)
+    (13, 162), // 遑 (78-03)
+    (13, 162), // 遒 (78-04)
+    (11, 162), // 逎 (78-05)
+    (13, 162), // 遉 (78-06)
+    (13, 162), // 逾 (78-07)
+    (13, 162), // 遖 (78-08)
+    (14, 162), // 遘 (78-09)
+    (14, 162), // 遞 (78-10)
+    (15, 162), // 遨 (78-11)
+    (15, 162), // 遯 (78-12)
+    (16, 162), // 遶 (78-13)
+    (16, 170), // 隨 (78-14)
+    (16, 162), // 遲 (78-15)
+    (17, 162), // 邂 (78-16)
+    (17, 162), // 遽 (78-17)
+    (16, 162), // 邁 (78-18)
+    (17, 162), // 邀 (78-19)
+    (19, 162), // 邊 (78-20)
+    (17, 162), // 邉 (78-21)
+    (23, 162), // 邏 (78-22)
+    (7, 163),  // 邨 (78-23)
+    (8, 163),  // 邯 (78-24)
+    (8, 163),  // 邱 (78-25)
+    (8, 163),  // 邵 (78-26)
+    (10, 163), // 郢 (78-27)
+    (10, 163), // 郤 (78-28)
+    (11, 63),  // 扈 (78-29)
+    (10, 163), // 郛 (78-30)
+    (12, 163), // 鄂 (78-31)
+    (13, 163), // 鄒 (78-32)
+    (14, 163), // 鄙 (78-33)
+    (15, 163), // 鄲 (78-34)
+    (15, 163), // 鄰 (78-35)
+    (9, 164),  // 酊 (78-36)
+    (11, 164), // 酖 (78-37)
+    (11, 164), // 酘 (78-38)
+    (12, 164), // 酣 (78-39)
+    (12, 164), // 酥 (78-40)
+    (13, 164), // 酩 (78-41)
+    (14, 164), // 酳 (78-42)
+    (14, 164), // 酲 (78-43)
+    (15, 164), // 醋 (78-44)
+    (15, 164), // 醉 (78-45)
+    (15, 164), // 醂 (78-46)
+    (17, 164), // 醢 (78-47)
+    (18, 164), // 醫 (78-48)
+    (19, 164), // 醯 (78-49)
+    (18, 164), // 醪 (78-50)
+    (20, 164), // 醵 (78-51)
+    (20, 164), // 醴 (78-52)
+    (21, 164), // 醺 (78-53)
+    (24, 164), // 釀 (78-54)
+    (25, 164), // 釁 (78-55)
+    (12, 165), // 釉 (78-56)
+    (20, 165), // 釋 (78-57)
+    (18, 166), // 釐 (78-58)
+    (10, 167), // 釖 (78-59)
+    (10, 167), // 釟 (78-60)
+    (10, 167), // 釡 (78-61)
+    (10, 167), // 釛 (78-62)
+    (11, 167), // 釼 (78-63)
+    (11, 167), // 釵 (78-64)
+    (11, 167), // 釶 (78-65)
+    (12, 167), // 鈞 (78-66)
+    (12, 167), // 釿 (78-67)
+    (12, 167), // 鈔 (78-68)
+    (12, 167), // 鈬 (78-69)
+    (12, 167), // 鈕 (78-70)
+    (12, 167), // 鈑 (78-71)
+    (13, 167), // 鉞 (78-72)
+    (13, 167), // 鉗 (78-73)
+    (13, 167), // 鉅 (78-74)
+    (13, 167), // 鉉 (78-75)
+    (13, 167), // 鉤 (78-76)
+    (13, 167), // 鉈 (78-77)
+    (14, 167), // 銕 (78-78)
+    (13, 167), // 鈿 (78-79)
+    (13, 167), // 鉋 (78-80)
+    (13, 167), // 鉐 (78-81)
+    (14, 167), // 銜 (78-82)
+    (14, 167), // 銖 (78-83)
+    (14, 167), // 銓 (78-84)
+    (14, 167), // 銛 (78-85)
+    (13, 167), // 鉚 (78-86)
+    (15, 167), // 鋏 (78-87)
+    (15, 167), // 銹 (78-88)
+    (15, 167), // 銷 (78-89)
+    (14, 167), // 鋩 (78-90)
+    (16, 167), // 錏 (78-91)
+    (16, 167), // 鋺 (78-92)
+    (16, 167), // 鍄 (78-93)
+    (16, 167), // 錮 (78-94)
+    (16, 167), // 錙 (79-01)
+    (16, 167), // 錢 (79-02)
+    (16, 167), // 錚 (79-03)
+    (16, 167), // 錣 (79-04)
+    (15, 167), // 錺 (79-05)
+    (15, 167), // 錵 (79-06)
+    (16, 167), // 錻 (79-07)
+    (17, 167), // 鍜 (79-08)
+    (17, 167), // 鍠 (79-09)
+    (17, 167), // 鍼 (79-10)
+    (17, 167), // 鍮 (79-11)
+    (17, 167), // 鍖 (79-12)
+    (18, 167), // 鎰 (79-13)
+    (18, 167), // 鎬 (79-14)
+    (18, 167), // 鎭 (79-15)
+    (18, 167), // 鎔 (79-16)
+    (18, 167), // 鎹 (79-17)
+    (19, 167), // 鏖 (79-18)
+    (20, 167), // 鏗 (79-19)
+    (19, 167), // 鏨 (79-20)
+    (19, 167), // 鏥 (79-21)
+    (19, 167), // 鏘 (79-22)
+    (19, 167), // 鏃 (79-23)
+    (19, 167), // 鏝 (79-24)
+    (19, 167), // 鏐 (79-25)
+    (19, 167), // 鏈 (79-26)
+    (19, 167), // 鏤 (79-27)
+    (20, 167), // 鐚 (79-28)
+    (20, 167), // 鐔 (79-29)
+    (20, 167), // 鐓 (79-30)
+    (20, 167), // 鐃 (79-31)
+    (20, 167), // 鐇 (79-32)
+    (20, 167), // 鐐 (79-33)
+    (21, 167), // 鐶 (79-34)
+    (20, 167), // 鐫 (79-35)
+    (21, 167), // 鐵 (79-36)
+    (20, 167), // 鐡 (79-37)
+    (21, 167), // 鐺 (79-38)
+    (21, 167), // 鑁 (79-39)
+    (23, 167), // 鑒 (79-40)
+    (22, 167), // 鑄 (79-41)
+    (23, 167), // 鑛 (79-42)
+    (23, 167), // 鑠 (79-43)
+    (23, 167), // 鑢 (79-44)
+    (23, 167), // 鑞 (79-45)
+    (24, 167), // 鑪 (79-46)
+    (12, 167), // 鈩 (79-47)
+    (25, 167), // 鑰 (79-48)
+    (25, 167), // 鑵 (79-49)
+    (26, 167), // 鑷 (79-50)
+    (27, 167), // 鑽 (79-51)
+    (23, 167), // 鑚 (79-52)
+    (27, 167), // 鑼 (79-53)
+    (27, 167), // 鑾 (79-54)
+    (28, 167), // 钁 (79-55)
+    (28, 167), // 鑿 (79-56)
+    (9, 169),  // 閂 (79-57)
+    (11, 169), // 閇 (79-58)
+    (11, 169), // 閊 (79-59)
+    (12, 169), // 閔 (79-60)
+    (12, 169), // 閖 (79-61)
+    (13, 169), // 閘 (79-62)
+    (13, 169), // 閙 (79-63)
+    (13, 169), // 閠 (79-64)
+    (14, 169), // 閨 (79-65)
+    (14, 169), // 閧 (79-66)
+    (15, 169), // 閭 (79-67)
+    (16, 169), // 閼 (79-68)
+    (16, 169), // 閻 (79-69)
+    (16, 169), // 閹 (79-70)
+    (16, 169), // 閾 (79-71)
+    (17, 169), // 闊 (79-72)
+    (17, 85),  // 濶 (79-73)
+    (17, 169), // 闃 (79-74)
+    (17, 169), // 闍 (79-75)
+    (17, 169), // 闌 (79-76)
+    (18, 169), // 闕 (79-77)
+    (18, 169), // 闔 (79-78)
+    (18, 169), // 闖 (79-79)
+    (19, 169), // 關 (79-80)
+    (20, 169), // 闡 (79-81)
+    (21, 169), // 闥 (79-82)
+    (21, 169), // 闢 (79-83)
+    (6, 170),  // 阡 (79-84)
+    (7, 170),  // 阨 (79-85)
+    (7, 170),  // 阮 (79-86)
+    (7, 170),  // 阯 (79-87)
+    (8, 170),  // 陂 (79-88)
+    (9, 170),  // 陌 (79-89)
+    (9, 170),  // 陏 (79-90)
+    (9, 170),  // 陋 (79-91)
+    (11, 170), // 陷 (79-92)
+    (10, 170), // 陜 (79-93)
+    (10, 170), // 陞 (79-94)
+    (10, 170), // 陝 (80-01)
+    (10, 170), // 陟 (80-02)
+    (10, 170), // 陦 (80-03)
+    (11, 170), // 陲 (80-04)
+    (11, 170), // 陬 (80-05)
+    (12, 170), // 隍 (80-06)
+    (13, 170), // 隘 (80-07)
+    (13, 170), // 隕 (80-08)
+    (13, 170), // 隗 (80-09)
+    (16, 170), // 險 (80-10)
+    (16, 170), // 隧 (80-11)
+    (17, 170), // 隱 (80-12)
+    (17, 170), // 隲 (80-13)
+    (17, 170), // 隰 (80-14)
+    (19, 170), // 隴 (80-15)
+    (8, 171),  // 隶 (80-16)
+    (17, 171), // 隸 (80-17)
+    (8, 172),  // 隹 (80-18)
+    (13, 172), // 雎 (80-19)
+    (12, 172), // 雋 (80-20)
+    (13, 172), // 雉 (80-21)
+    (13, 172), // 雍 (80-22)
+    (17, 145), // 襍 (80-23)
+    (18, 172), // 雜 (80-24)
+    (16, 173), // 霍 (80-25)
+    (16, 172), // 雕 (80-26)
+    (13, 173), // 雹 (80-27)
+    (15, 173), // 霄 (80-28)
+    (15, 173), // 霆 (80-29)
+    (15, 173), // 霈 (80-30)
+    (16, 173), // 霓 (80-31)
+    (16, 173), // 霎 (80-32)
+    (16, 173), // 霑 (80-33)
+    (16, 173), // 霏 (80-34)
+    (16, 173), // 霖 (80-35)
+    (16, 173), // 霙 (80-36)
+    (18, 173), // 霤 (80-37)
+    (19, 173), // 霪 (80-38)
+    (20, 173), // 霰 (80-39)
+    (21, 173), // 霹 (80-40)
+    (22, 173), // 霽 (80-41)
+    (22, 173), // 霾 (80-42)
+    (24, 173), // 靄 (80-43)
+    (24, 173), // 靆 (80-44)
+    (24, 173), // 靈 (80-45)
+    (24, 173), // 靂 (80-46)
+    (25, 173), // 靉 (80-47)
+    (16, 174), // 靜 (80-48)
+    (15, 175), // 靠 (80-49)
+    (14, 176), // 靤 (80-50)
+    (16, 176), // 靦 (80-51)
+    (23, 176), // 靨 (80-52)
+    (11, 19),  // 勒 (80-53)
+    (12, 177), // 靫 (80-54)
+    (12, 177), // 靱 (80-55)
+    (13, 177), // 靹 (80-56)
+    (14, 177), // 鞅 (80-57)
+    (14, 177), // 靼 (80-58)
+    (14, 177), // 鞁 (80-59)
+    (14, 177), // 靺 (80-60)
+    (14, 177), // 鞆 (80-61)
+    (15, 177), // 鞋 (80-62)
+    (15, 177), // 鞏 (80-63)
+    (15, 177), // 鞐 (80-64)
+    (17, 177), // 鞜 (80-65)
+    (18, 177), // 鞨 (80-66)
+    (18, 177), // 鞦 (80-67)
+    (18, 177), // 鞣 (80-68)
+    (18, 177), // 鞳 (80-69)
+    (19, 177), // 鞴 (80-70)
+    (22, 177), // 韃 (80-71)
+    (24, 177), // 韆 (80-72)
+    (23, 177), // 韈 (80-73)
+    (10, 178), // 韋 (80-74)
+    (20, 178), // 韜 (80-75)
+    (9, 179),  // 韭 (80-76)
+    (23, 210), // 齏 (80-77)
+    (19, 179), // 韲 (80-78)
+    (11, 117), // 竟 (80-79)
+    (14, 180), // 韶 (80-80)
+    (13, 180), // 韵 (80-81)
+    (13, 181), // 頏 (80-82)
+    (13, 181), // 頌 (80-83)
+    (16, 181), // 頸 (80-84)
+    (16, 181), // 頤 (80-85)
+    (15, 181), // 頡 (80-86)
+    (16, 181), // 頷 (80-87)
+    (16, 181), // 頽 (80-88)
+    (17, 181), // 顆 (80-89)
+    (18, 181), // 顏 (80-90)
+    (18, 181), // 顋 (80-91)
+    (22, 181), // 顫 (80-92)
+    (23, 181), // 顯 (80-93)
+    (24, 181), // 顰 (80-94)
+    (25, 181), // 顱 (81-01)
+    (26, 181), // 顴 (81-02)
+    (27, 181), // 顳 (81-03)
+    (12, 182), // 颪 (81-04)
+    (14, 182), // 颯 (81-05)
+    (14, 182), // 颱 (81-06)
+    (17, 182), // 颶 (81-07)
+    (20, 182), // 飄 (81-08)
+    (20, 182), // 飃 (81-09)
+    (21, 182), // 飆 (81-10)
+    (13, 184), // 飩 (81-11)
+    (13, 184), // 飫 (81-12)
+    (15, 184), // 餃 (81-13)
+    (15, 184), // 餉 (81-14)
+    (16, 184), // 餒 (81-15)
+    (16, 184), // 餔 (81-16)
+    (16, 184), // 餘 (81-17)
+    (17, 184), // 餡 (81-18)
+    (16, 184), // 餝 (81-19)
+    (17, 184), // 餞 (81-20)
+    (17, 184), // 餤 (81-21)
+    (17, 184), // 餠 (81-22)
+    (18, 184), // 餬 (81-23)
+    (18, 184), // 餮 (81-24)
+    (19, 184), // 餽 (81-25)
+    (19, 184), // 餾 (81-26)
+    (19, 184), // 饂 (81-27)
+    (20, 184), // 饉 (81-28)
+    (20, 184), // 饅 (81-29)
+    (21, 184), // 饐 (81-30)
+    (21, 184), // 饋 (81-31)
+    (21, 184), // 饑 (81-32)
+    (21, 184), // 饒 (81-33)
+    (21, 184), // 饌 (81-34)
+    (22, 184), // 饕 (81-35)
+    (11, 185), // 馗 (81-36)
+    (17, 185), // 馘 (81-37)
+    (18, 186), // 馥 (81-38)
+    (12, 187), // 馭 (81-39)
+    (12, 187), // 馮 (81-40)
+    (14, 187), // 馼 (81-41)
+    (15, 187), // 駟 (81-42)
+    (15, 187), // 駛 (81-43)
+    (15, 187), // 駝 (81-44)
+    (15, 187), // 駘 (81-45)
+    (15, 187), // 駑 (81-46)
+    (16, 187), // 駭 (81-47)
+    (16, 187), // 駮 (81-48)
+    (16, 187), // 駱 (81-49)
+    (16, 187), // 駲 (81-50)
+    (17, 187), // 駻 (81-51)
+    (17, 187), // 駸 (81-52)
+    (17, 187), // 騁 (81-53)
+    (18, 187), // 騏 (81-54)
+    (18, 187), // 騅 (81-55)
+    (16, 187), // 駢 (81-56)
+    (19, 187), // 騙 (81-57)
+    (20, 187), // 騫 (81-58)
+    (20, 187), // 騷 (81-59)
+    (21, 187), // 驅 (81-60)
+    (21, 187), // 驂 (81-61)
+    (20, 187), // 驀 (81-62)
+    (21, 187), // 驃 (81-63)
+    (21, 187), // 騾 (81-64)
+    (22, 187), // 驕 (81-65)
+    (22, 187), // 驍 (81-66)
+    (23, 187), // 驛 (81-67)
+    (23, 187), // 驗 (81-68)
+    (24, 187), // 驟 (81-69)
+    (26, 187), // 驢 (81-70)
+    (26, 187), // 驥 (81-71)
+    (27, 187), // 驤 (81-72)
+    (27, 187), // 驩 (81-73)
+    (30, 187), // 驫 (81-74)
+    (29, 187), // 驪 (81-75)
+    (13, 188), // 骭 (81-76)
+    (14, 188), // 骰 (81-77)
+    (16, 188), // 骼 (81-78)
+    (18, 188), // 髀 (81-79)
+    (21, 188), // 髏 (81-80)
+    (23, 188), // 髑 (81-81)
+    (23, 188), // 髓 (81-82)
+    (23, 188), // 體 (81-83)
+    (23, 189), // 髞 (81-84)
+    (10, 190), // 髟 (81-85)
+    (13, 190), // 髢 (81-86)
+    (14, 190), // 髣 (81-87)
+    (14, 190), // 髦 (81-88)
+    (15, 190), // 髯 (81-89)
+    (15, 190), // 髫 (81-90)
+    (15, 190), // 髮 (81-91)
+    (15, 190), // 髴 (81-92)
+    (15, 190), // 髱 (81-93)
+    (16, 190), // 髷 (81-94)
+    (16, 190), // 髻 (82-01)
+    (18, 190), // 鬆 (82-02)
+    (21, 190), // 鬘 (82-03)
+    (22, 190), // 鬚 (82-04)
+    (23, 190), // 鬟 (82-05)
+    (24, 190), // 鬢 (82-06)
+    (25, 190), // 鬣 (82-07)
+    (10, 191), // 鬥 (82-08)
+    (15, 191), // 鬧 (82-09)
+    (16, 191), // 鬨 (82-10)
+    (18, 191), // 鬩 (82-11)
+    (20, 191), // 鬪 (82-12)
+    (26, 191), // 鬮 (82-13)
+    (10, 192), // 鬯 (82-14)
+    (10, 193), // 鬲 (82-15)
+    (15, 194), // 魄 (82-16)
+    (15, 194), // 魃 (82-17)
+    (18, 194), // 魏 (82-18)
+    (18, 194), // 魍 (82-19)
+    (18, 194), // 魎 (82-20)
+    (21, 194), // 魑 (82-21)
+    (24, 194), // 魘 (82-22)
+    (15, 195), // 魴 (82-23)
+    (16, 195), // 鮓 (82-24)
+    (16, 195), // 鮃 (82-25)
+    (16, 195), // 鮑 (82-26)
+    (16, 195), // 鮖 (82-27)
+    (16, 195), // 鮗 (82-28)
+    (17, 195), // 鮟 (82-29)
+    (17, 195), // 鮠 (82-30)
+    (17, 195), // 鮨 (82-31)
+    (17, 195), // 鮴 (82-32)
+    (18, 195), // 鯀 (82-33)
+    (18, 195), // 鯊 (82-34)
+    (18, 195), // 鮹 (82-35)
+    (18, 195), // 鯆 (82-36)
+    (18, 195), // 鯏 (82-37)
+    (18, 195), // 鯑 (82-38)
+    (18, 195), // 鯒 (82-39)
+    (19, 195), // 鯣 (82-40)
+    (19, 195), // 鯢 (82-41)
+    (19, 195), // 鯤 (82-42)
+    (19, 195), // 鯔 (82-43)
+    (19, 195), // 鯡 (82-44)
+    (22, 195), // 鰺 (82-45)
+    (19, 195), // 鯲 (82-46)
+    (19, 195), // 鯱 (82-47)
+    (19, 195), // 鯰 (82-48)
+    (20, 195), // 鰕 (82-49)
+    (20, 195), // 鰔 (82-50)
+    (20, 195), // 鰉 (82-51)
+    (20, 195), // 鰓 (82-52)
+    (20, 195), // 鰌 (82-53)
+    (20, 195), // 鰆 (82-54)
+    (20, 195), // 鰈 (82-55)
+    (20, 195), // 鰒 (82-56)
+    (20, 195), // 鰊 (82-57)
+    (20, 195), // 鰄 (82-58)
+    (21, 195), // 鰮 (82-59)
+    (20, 195), // 鰛 (82-60)
+    (21, 195), // 鰥 (82-61)
+    (21, 195), // 鰤 (82-62)
+    (21, 195), // 鰡 (82-63)
+    (21, 195), // 鰰 (82-64)
+    (22, 195), // 鱇 (82-65)
+    (22, 195), // 鰲 (82-66)
+    (22, 195), // 鱆 (82-67)
+    (22, 195), // 鰾 (82-68)
+    (23, 195), // 鱚 (82-69)
+    (24, 195), // 鱠 (82-70)
+    (24, 195), // 鱧 (82-71)
+    (26, 195), // 鱶 (82-72)
+    (27, 195), // 鱸 (82-73)
+    (13, 196), // 鳧 (82-74)
+    (9, 196),  // 鳬 (82-75)
+    (13, 196), // 鳰 (82-76)
+    (16, 196), // 鴉 (82-77)
+    (15, 196), // 鴈 (82-78)
+    (13, 196), // 鳫 (82-79)
+    (15, 196), // 鴃 (82-80)
+    (15, 196), // 鴆 (82-81)
+    (16, 196), // 鴪 (82-82)
+    (16, 196), // 鴦 (82-83)
+    (21, 196), // 鶯 (82-84)
+    (16, 196), // 鴣 (82-85)
+    (16, 196), // 鴟 (82-86)
+    (17, 196), // 鵄 (82-87)
+    (16, 196), // 鴕 (82-88)
+    (16, 196), // 鴒 (82-89)
+    (17, 196), // 鵁 (82-90)
+    (17, 196), // 鴿 (82-91)
+    (17, 196), // 鴾 (82-92)
+    (17, 196), // 鵆 (82-93)
+    (17, 196), // 鵈 (82-94)
+    (18, 196), // 鵝 (83-01)
+    (18, 196), // 鵞 (83-02)
+    (18, 196), // 鵤 (83-03)
+    (18, 196), // 鵑 (83-04)
+    (18, 196), // 鵐 (83-05)
+    (18, 196), // 鵙 (83-06)
+    (19, 196), // 鵲 (83-07)
+    (19, 196), // 鶉 (83-08)
+    (19, 196), // 鶇 (83-09)
+    (20, 196), // 鶫 (83-10)
+    (19, 196), // 鵯 (83-11)
+    (19, 196), // 鵺 (83-12)
+    (20, 196), // 鶚 (83-13)
+    (20, 196), // 鶤 (83-14)
+    (20, 196), // 鶩 (83-15)
+    (21, 196), // 鶲 (83-16)
+    (21, 196), // 鷄 (83-17)
+    (21, 196), // 鷁 (83-18)
+    (21, 196), // 鶻 (83-19)
+    (21, 196), // 鶸 (83-20)
+    (21, 196), // 鶺 (83-21)
+    (21, 196), // 鷆 (83-22)
+    (21, 196), // 鷏 (83-23)
+    (21, 196), // 鷂 (83-24)
+    (22, 196), // 鷙 (83-25)
+    (22, 196), // 鷓 (83-26)
+    (23, 196), // 鷸 (83-27)
+    (23, 196), // 鷦 (83-28)
+    (23, 196), // 鷭 (83-29)
+    (23, 196), // 鷯 (83-30)
+    (24, 196), // 鷽 (83-31)
+    (28, 196), // 鸚 (83-32)
+    (28, 196), // 鸛 (83-33)
+    (30, 196), // 鸞 (83-34)
+    (11, 197), // 鹵 (83-35)
+    (20, 197), // 鹹 (83-36)
+    (25, 197), // 鹽 (83-37)
+    (13, 198), // 麁 (83-38)
+    (16, 198), // 麈 (83-39)
+    (17, 198), // 麋 (83-40)
+    (18, 198), // 麌 (83-41)
+    (19, 198), // 麒 (83-42)
+    (19, 198), // 麕 (83-43)
+    (19, 198), // 麑 (83-44)
+    (21, 198), // 麝 (83-45)
+    (11, 199), // 麥 (83-46)
+    (15, 199), // 麩 (83-47)
+    (11, 199), // 麸 (83-48)
+    (15, 199), // 麪 (83-49)
+    (16, 199), // 麭 (83-50)
+    (19, 175), // 靡 (83-51)
+    (25, 201), // 黌 (83-52)
+    (15, 202), // 黎 (83-53)
+    (17, 202), // 黏 (83-54)
+    (23, 202), // 黐 (83-55)
+    (16, 203), // 黔 (83-56)
+    (17, 203), // 黜 (83-57)
+    (17, 203), // 點 (83-58)
+    (17, 203), // 黝 (83-59)
+    (18, 203), // 黠 (83-60)
+    (20, 203), // 黥 (83-61)
+    (20, 203), // 黨 (83-62)
+    (21, 180), // 黯 (83-63)
+    (23, 203), // 黴 (83-64)
+    (26, 203), // 黶 (83-65)
+    (27, 203), // 黷 (83-66)
+    (12, 204), // 黹 (83-67)
+    (17, 204), // 黻 (83-68)
+    (19, 204), // 黼 (83-69)
+    (13, 205), // 黽 (83-70)
+    (24, 205), // 鼇 (83-71)
+    (25, 205), // 鼈 (83-72)
+    (14, 107), // 皷 (83-73)
+    (18, 207), // 鼕 (83-74)
+    (8, 208),  // 鼡 (83-75)
+    (18, 208), // 鼬 (83-76)
+    (17, 209), // 鼾 (83-77)
+    (14, 210), // 齊 (83-78)
+    (15, 211), // 齒 (83-79)
+    (17, 211), // 齔 (83-80)
+    (20, 211), // 齣 (83-81)
+    (20, 211), // 齟 (83-82)
+    (20, 211), // 齠 (83-83)
+    (20, 211), // 齡 (83-84)
+    (21, 211), // 齦 (83-85)
+    (21, 211), // 齧 (83-86)
+    (22, 211), // 齬 (83-87)
+    (22, 211), // 齪 (83-88)
+    (24, 211), // 齷 (83-89)
+    (24, 211), // 齲 (83-90)
+    (24, 211), // 齶 (83-91)
+    (22, 212), // 龕 (83-92)
+    (16, 213), // 龜 (83-93)
+    (17, 214), // 龠 (83-94)
+    (12, 32),  // 堯 (84-01)
+    (14, 75),  // 槇 (84-02)
+    (14, 162), // 遙 (84-03)
+    (14, 96),  // 瑤 (84-04)
+    (15, 15),  // 凜 (84-05)
+    (15, 86),  // 熙 (84-06)
+];
+
+// Every JIS X 0208 kanji has an entry.
+const _: () = assert!(JISX0208_KANJI_METADATA.len() == JISX0208_KANJI_COUNT);
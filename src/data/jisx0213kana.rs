@@ -0,0 +1,77 @@
+//! JIS X 0213 kana code points
+//!
+//! Kana added by JIS X 0213 on top of the JIS X 0208 hiragana and katakana
+//! rows.  Kuten are given as plane-row-cell.
+
+/// Hiragana added by JIS X 0213 (ゔ, small ゕ and ゖ)
+pub const HIRAGANA_ADDITIONS: &[u32] = &[
+    0x3094, // ゔ (1-04-84)
+    0x3095, // ゕ (1-04-85)
+    0x3096, // ゖ (1-04-86)
+];
+
+/// Number of entries in [`HIRAGANA_ADDITIONS`].
+pub const HIRAGANA_ADDITIONS_COUNT: usize = 3;
+const _: () = assert!(HIRAGANA_ADDITIONS.len() == HIRAGANA_ADDITIONS_COUNT);
+
+/// Katakana added by JIS X 0213 (ヷヸヹヺ and the small katakana ㇰ–ㇿ used
+/// for Ainu)
+pub const KATAKANA_ADDITIONS: &[u32] = &[
+    0x30F7, // ヷ (1-07-82)
+    0x30F8, // ヸ (1-07-83)
+    0x30F9, // ヹ (1-07-84)
+    0x30FA, // ヺ (1-07-85)
+    0x31F0, // ㇰ (1-06-78)
+    0x31F1, // ㇱ (1-06-79)
+    0x31F2, // ㇲ (1-06-80)
+    0x31F3, // ㇳ (1-06-81)
+    0x31F4, // ㇴ (1-06-82)
+    0x31F5, // ㇵ (1-06-83)
+    0x31F6, // ㇶ (1-06-84)
+    0x31F7, // ㇷ (1-06-85)
+    0x31F8, // ㇸ (1-06-86)
+    0x31F9, // ㇹ (1-06-87)
+    0x31FA, // ㇺ (1-06-88)
+    0x31FB, // ㇻ (1-06-89)
+    0x31FC, // ㇼ (1-06-90)
+    0x31FD, // ㇽ (1-06-91)
+    0x31FE, // ㇾ (1-06-92)
+    0x31FF, // ㇿ (1-06-93)
+];
+
+/// Number of entries in [`KATAKANA_ADDITIONS`].
+pub const KATAKANA_ADDITIONS_COUNT: usize = 20;
+const _: () = assert!(KATAKANA_ADDITIONS.len() == KATAKANA_ADDITIONS_COUNT);
+
+/// Katakana Phonetic Extensions (U+31F0–U+31FF), the small katakana added by
+/// JIS X 0213 for Ainu; a subset of [`KATAKANA_ADDITIONS`]
+pub const PHONETIC_EXTENSIONS: &[u32] = &[
+    0x31F0, // ㇰ (1-06-78)
+    0x31F1, // ㇱ (1-06-79)
+    0x31F2, // ㇲ (1-06-80)
+    0x31F3, // ㇳ (1-06-81)
+    0x31F4, // ㇴ (1-06-82)
+    0x31F5, // ㇵ (1-06-83)
+    0x31F6, // ㇶ (1-06-84)
+    0x31F7, // ㇷ (1-06-85)
+    0x31F8, // ㇸ (1-06-86)
+    0x31F9, // ㇹ (1-06-87)
+    0x31FA, // ㇺ (1-06-88)
+    0x31FB, // ㇻ (1-06-89)
+    0x31FC, // ㇼ (1-06-90)
+    0x31FD, // ㇽ (1-06-91)
+    0x31FE, // ㇾ (1-06-92)
+    0x31FF, // ㇿ (1-06-93)
+];
+
+/// Number of entries in [`PHONETIC_EXTENSIONS`].
+pub const PHONETIC_EXTENSIONS_COUNT: usize = 16;
+const _: () = assert!(PHONETIC_EXTENSIONS.len() == PHONETIC_EXTENSIONS_COUNT);
+
+/// [`PHONETIC_EXTENSIONS`] as `char`s, converted at compile time.
+pub const PHONETIC_EXTENSIONS_CHARS: &[char] =
+    &super::to_chars::<PHONETIC_EXTENSIONS_COUNT>(PHONETIC_EXTENSIONS);
+
+/// COMBINING KATAKANA-HIRAGANA SEMI-VOICED SOUND MARK, written after ㇷ to
+/// form ㇷ゚ (JIS X 0213 1-06-88), which has no precomposed code point
+pub const COMBINING_SEMI_VOICED_MARK: u32 = 0x309A;
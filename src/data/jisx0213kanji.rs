@@ -10057,3 +10057,48 @@ pub const JISX0213_KANJI: &[u32] = &[
     0x9F75,  // 齵 (2-94-85)
     0x2A6B2, // 𪚲 (2-94-86)
 ];
+
+/// Number of entries in [`JISX0213_KANJI`].
+pub const JISX0213_KANJI_COUNT: usize = 10050;
+const _: () = assert!(JISX0213_KANJI.len() == JISX0213_KANJI_COUNT);
+
+/// [`JISX0213_KANJI`] as `char`s, converted at compile time.
+pub const JISX0213_KANJI_CHARS: &[char] = &super::to_chars::<JISX0213_KANJI_COUNT>(JISX0213_KANJI);
+
+/// Number of plane-1 kanji: the 6 355 JIS X 0208 kanji (Levels 1–2) and
+/// the 1 259 Level 3 kanji.
+pub const JISX0213_PLANE1_COUNT: usize = 7614;
+
+/// Number of plane-2 kanji (Level 4).
+pub const JISX0213_PLANE2_COUNT: usize = 2436;
+
+/// The plane-1 kanji: the first [`JISX0213_PLANE1_COUNT`] entries of
+/// [`JISX0213_KANJI`], in table order.
+pub const JISX0213_KANJI_PLANE1: &[u32] =
+    &super::sub_table::<JISX0213_PLANE1_COUNT>(JISX0213_KANJI, 0);
+
+/// The plane-2 kanji (2-01-01 to 2-94-86): the remaining entries of
+/// [`JISX0213_KANJI`], in table order.
+pub const JISX0213_KANJI_PLANE2: &[u32] =
+    &super::sub_table::<JISX0213_PLANE2_COUNT>(JISX0213_KANJI, JISX0213_PLANE1_COUNT);
+
+const _: () = assert!(JISX0213_PLANE1_COUNT + JISX0213_PLANE2_COUNT == JISX0213_KANJI_COUNT);
+// Pin the boundary: 1-94-94 is 繫, 2-01-01 is 𠂉.
+const _: () = assert!(matches!(JISX0213_KANJI_PLANE1.last(), Some(&0x7E6B)));
+const _: () = assert!(matches!(JISX0213_KANJI_PLANE2.first(), Some(&0x20089)));
+
+/// The ten kanji added by JIS X 0213:2004, all in plane 1.
+/// [`JISX0213_KANJI`] follows the 2004 edition; without these it is the
+/// 10 040-kanji repertoire of JIS X 0213:2000.
+pub const JISX0213_2004_ADDITIONS: &[u32] = &[
+    0x4FF1,  // 俱 (1-14-01)
+    0x525D,  // 剝 (1-15-94)
+    0x20B9F, // 𠮟 (1-47-52)
+    0x541E,  // 吞 (1-47-94)
+    0x5653,  // 噓 (1-84-07)
+    0x59F8,  // 姸 (1-94-90)
+    0x5C5B,  // 屛 (1-94-91)
+    0x5E77,  // 幷 (1-94-92)
+    0x7626,  // 瘦 (1-94-93)
+    0x7E6B,  // 繫 (1-94-94)
+];
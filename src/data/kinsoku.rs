@@ -0,0 +1,147 @@
+//! Line-breaking (kinsoku) character classes of JIS X 4051
+//!
+//! JIS X 4051 (日本語文書の組版方法) sorts characters into classes by how
+//! they behave at line breaks.  The tables follow the class lists of the
+//! W3C note *Requirements for Japanese Text Layout* (JLREQ), which mirrors
+//! JIS X 4051.  Small kana (cl-11) and hyphens (cl-03) are included; JIS X
+//! 4051 allows relaxing those two classes in loose layouts.
+
+/// Characters that may not start a line: JIS X 4051 classes cl-02 to
+/// cl-07 and cl-09 to cl-11, in class order
+pub const LINE_START_PROHIBITED: &[u32] = &[
+    // closing brackets (cl-02)
+    0x2019, // ’ RIGHT SINGLE QUOTATION MARK
+    0x201D, // ” RIGHT DOUBLE QUOTATION MARK
+    0xFF09, // ） FULLWIDTH RIGHT PARENTHESIS
+    0x0029, // ) RIGHT PARENTHESIS
+    0x3015, // 〕 RIGHT TORTOISE SHELL BRACKET
+    0x005D, // ] RIGHT SQUARE BRACKET
+    0xFF3D, // ］ FULLWIDTH RIGHT SQUARE BRACKET
+    0xFF5D, // ｝ FULLWIDTH RIGHT CURLY BRACKET
+    0x007D, // } RIGHT CURLY BRACKET
+    0x3009, // 〉 RIGHT ANGLE BRACKET
+    0x300B, // 》 RIGHT DOUBLE ANGLE BRACKET
+    0x300D, // 」 RIGHT CORNER BRACKET
+    0x300F, // 』 RIGHT WHITE CORNER BRACKET
+    0x3011, // 】 RIGHT BLACK LENTICULAR BRACKET
+    0xFF60, // ｠ FULLWIDTH RIGHT WHITE PARENTHESIS
+    0x2986, // ⦆ RIGHT WHITE PARENTHESIS
+    0x3019, // 〙 RIGHT WHITE TORTOISE SHELL BRACKET
+    0x3017, // 〗 RIGHT WHITE LENTICULAR BRACKET
+    0x00BB, // » RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK
+    0x301F, // 〟 LOW DOUBLE PRIME QUOTATION MARK
+    // hyphens (cl-03)
+    0x2010, // ‐ HYPHEN
+    0x301C, // 〜 WAVE DASH
+    0x30A0, // ゠ KATAKANA-HIRAGANA DOUBLE HYPHEN
+    0x2013, // – EN DASH
+    // dividing punctuation marks (cl-04)
+    0xFF01, // ！ FULLWIDTH EXCLAMATION MARK
+    0xFF1F, // ？ FULLWIDTH QUESTION MARK
+    0x203C, // ‼ DOUBLE EXCLAMATION MARK
+    0x2047, // ⁇ DOUBLE QUESTION MARK
+    0x2048, // ⁈ QUESTION EXCLAMATION MARK
+    0x2049, // ⁉ EXCLAMATION QUESTION MARK
+    // middle dots (cl-05)
+    0x30FB, // ・ KATAKANA MIDDLE DOT
+    0xFF1A, // ： FULLWIDTH COLON
+    0xFF1B, // ； FULLWIDTH SEMICOLON
+    // full stops (cl-06)
+    0x3002, // 。 IDEOGRAPHIC FULL STOP
+    0xFF0E, // ． FULLWIDTH FULL STOP
+    // commas (cl-07)
+    0x3001, // 、 IDEOGRAPHIC COMMA
+    0xFF0C, // ， FULLWIDTH COMMA
+    // iteration marks (cl-09)
+    0x30FD, // ヽ KATAKANA ITERATION MARK
+    0x30FE, // ヾ KATAKANA VOICED ITERATION MARK
+    0x309D, // ゝ HIRAGANA ITERATION MARK
+    0x309E, // ゞ HIRAGANA VOICED ITERATION MARK
+    0x3005, // 々 IDEOGRAPHIC ITERATION MARK
+    0x303B, // 〻 VERTICAL IDEOGRAPHIC ITERATION MARK
+    // prolonged sound mark (cl-10)
+    0x30FC, // ー KATAKANA-HIRAGANA PROLONGED SOUND MARK
+    // small kana (cl-11)
+    0x3041, // ぁ HIRAGANA LETTER SMALL A
+    0x3043, // ぃ HIRAGANA LETTER SMALL I
+    0x3045, // ぅ HIRAGANA LETTER SMALL U
+    0x3047, // ぇ HIRAGANA LETTER SMALL E
+    0x3049, // ぉ HIRAGANA LETTER SMALL O
+    0x3063, // っ HIRAGANA LETTER SMALL TU
+    0x3083, // ゃ HIRAGANA LETTER SMALL YA
+    0x3085, // ゅ HIRAGANA LETTER SMALL YU
+    0x3087, // ょ HIRAGANA LETTER SMALL YO
+    0x308E, // ゎ HIRAGANA LETTER SMALL WA
+    0x3095, // ゕ HIRAGANA LETTER SMALL KA
+    0x3096, // ゖ HIRAGANA LETTER SMALL KE
+    0x30A1, // ァ KATAKANA LETTER SMALL A
+    0x30A3, // ィ KATAKANA LETTER SMALL I
+    0x30A5, // ゥ KATAKANA LETTER SMALL U
+    0x30A7, // ェ KATAKANA LETTER SMALL E
+    0x30A9, // ォ KATAKANA LETTER SMALL O
+    0x30C3, // ッ KATAKANA LETTER SMALL TU
+    0x30E3, // ャ KATAKANA LETTER SMALL YA
+    0x30E5, // ュ KATAKANA LETTER SMALL YU
+    0x30E7, // ョ KATAKANA LETTER SMALL YO
+    0x30EE, // ヮ KATAKANA LETTER SMALL WA
+    0x30F5, // ヵ KATAKANA LETTER SMALL KA
+    0x30F6, // ヶ KATAKANA LETTER SMALL KE
+    0x31F0, // ㇰ KATAKANA LETTER SMALL KU
+    0x31F1, // ㇱ KATAKANA LETTER SMALL SI
+    0x31F2, // ㇲ KATAKANA LETTER SMALL SU
+    0x31F3, // ㇳ KATAKANA LETTER SMALL TO
+    0x31F4, // ㇴ KATAKANA LETTER SMALL NU
+    0x31F5, // ㇵ KATAKANA LETTER SMALL HA
+    0x31F6, // ㇶ KATAKANA LETTER SMALL HI
+    0x31F7, // ㇷ KATAKANA LETTER SMALL HU
+    0x31F8, // ㇸ KATAKANA LETTER SMALL HE
+    0x31F9, // ㇹ KATAKANA LETTER SMALL HO
+    0x31FA, // ㇺ KATAKANA LETTER SMALL MU
+    0x31FB, // ㇻ KATAKANA LETTER SMALL RA
+    0x31FC, // ㇼ KATAKANA LETTER SMALL RI
+    0x31FD, // ㇽ KATAKANA LETTER SMALL RU
+    0x31FE, // ㇾ KATAKANA LETTER SMALL RE
+    0x31FF, // ㇿ KATAKANA LETTER SMALL RO
+];
+
+/// Number of entries in [`LINE_START_PROHIBITED`].
+pub const LINE_START_PROHIBITED_COUNT: usize = 84;
+const _: () = assert!(LINE_START_PROHIBITED.len() == LINE_START_PROHIBITED_COUNT);
+
+/// [`LINE_START_PROHIBITED`] as `char`s, converted at compile time.
+pub const LINE_START_PROHIBITED_CHARS: &[char] =
+    &super::to_chars::<LINE_START_PROHIBITED_COUNT>(LINE_START_PROHIBITED);
+
+/// Characters that may not end a line: JIS X 4051 class cl-01, the
+/// opening brackets
+pub const LINE_END_PROHIBITED: &[u32] = &[
+    // opening brackets (cl-01)
+    0x2018, // ‘ LEFT SINGLE QUOTATION MARK
+    0x201C, // “ LEFT DOUBLE QUOTATION MARK
+    0xFF08, // （ FULLWIDTH LEFT PARENTHESIS
+    0x0028, // ( LEFT PARENTHESIS
+    0x3014, // 〔 LEFT TORTOISE SHELL BRACKET
+    0x005B, // [ LEFT SQUARE BRACKET
+    0xFF3B, // ［ FULLWIDTH LEFT SQUARE BRACKET
+    0xFF5B, // ｛ FULLWIDTH LEFT CURLY BRACKET
+    0x007B, // { LEFT CURLY BRACKET
+    0x3008, // 〈 LEFT ANGLE BRACKET
+    0x300A, // 《 LEFT DOUBLE ANGLE BRACKET
+    0x300C, // 「 LEFT CORNER BRACKET
+    0x300E, // 『 LEFT WHITE CORNER BRACKET
+    0x3010, // 【 LEFT BLACK LENTICULAR BRACKET
+    0xFF5F, // ｟ FULLWIDTH LEFT WHITE PARENTHESIS
+    0x2985, // ⦅ LEFT WHITE PARENTHESIS
+    0x3018, // 〘 LEFT WHITE TORTOISE SHELL BRACKET
+    0x3016, // 〖 LEFT WHITE LENTICULAR BRACKET
+    0x00AB, // « LEFT-POINTING DOUBLE ANGLE QUOTATION MARK
+    0x301D, // 〝 REVERSED DOUBLE PRIME QUOTATION MARK
+];
+
+/// Number of entries in [`LINE_END_PROHIBITED`].
+pub const LINE_END_PROHIBITED_COUNT: usize = 20;
+const _: () = assert!(LINE_END_PROHIBITED.len() == LINE_END_PROHIBITED_COUNT);
+
+/// [`LINE_END_PROHIBITED`] as `char`s, converted at compile time.
+pub const LINE_END_PROHIBITED_CHARS: &[char] =
+    &super::to_chars::<LINE_END_PROHIBITED_COUNT>(LINE_END_PROHIBITED);
@@ -1,12 +1,124 @@
 //! Data modules for character code points
 //!
 //! This module contains the actual character data organized by standards.
+//! [`verify`] audits the tables for duplicates, overlaps and invalid values.
 
 pub mod ascii;
+mod audit;
+pub mod downgrade;
+pub mod enclosed;
+pub mod ibm;
 pub mod jisx0201;
 pub mod jisx0208;
 pub mod jisx0208kanji;
+#[cfg(feature = "kanji-metadata")]
+pub mod jisx0208kanji_metadata;
+pub mod jisx0213kana;
 pub mod jisx0213kanji;
+pub mod kinsoku;
+pub mod nec;
+pub mod presets;
+pub mod romaji_map;
+pub mod shift_jis;
+pub mod unicode;
+pub mod unicode_blocks;
+pub mod vertical;
+pub mod width;
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
+
+pub use audit::{verify, DataInconsistency};
+
+/// Converts a code-point table to `char`s at compile time.
+///
+/// An entry that is not a Unicode scalar value is a compile error, so the
+/// `*_CHARS` tables built with this can be used without any run-time check.
+#[allow(clippy::indexing_slicing, clippy::panic)] // compile-time only
+pub(crate) const fn to_chars<const N: usize>(table: &[u32]) -> [char; N] {
+    assert!(table.len() == N);
+    let mut out = ['\0'; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = match char::from_u32(table[i]) {
+            Some(c) => c,
+            None => panic!("data table contains a non-scalar value"),
+        };
+        i += 1;
+    }
+    out
+}
+
+/// Copies the `N` entries of `table` starting at `start`, at compile time.
+///
+/// `<[T]>::split_at` is only usable in constants from Rust 1.71.
+#[allow(clippy::indexing_slicing)] // compile-time only
+pub(crate) const fn sub_table<const N: usize>(table: &[u32], start: usize) -> [u32; N] {
+    assert!(start + N <= table.len());
+    let mut out = [0u32; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = table[start + i];
+        i += 1;
+    }
+    out
+}
+
+/// Pairs each entry of `keys` with the entry of `values` at the same index,
+/// at compile time.
+#[allow(clippy::indexing_slicing)] // compile-time only
+pub(crate) const fn zip_tables<const N: usize>(keys: &[u32], values: &[u32]) -> [(u32, u32); N] {
+    assert!(keys.len() == N && values.len() == N);
+    let mut out = [(0u32, 0u32); N];
+    let mut i = 0;
+    while i < N {
+        out[i] = (keys[i], values[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Sum of the lengths of `parts`, for sizing a [`sorted_union`] result.
+#[allow(clippy::indexing_slicing)] // compile-time only
+pub(crate) const fn total_len(parts: &[&[u32]]) -> usize {
+    let mut len = 0;
+    let mut p = 0;
+    while p < parts.len() {
+        len += parts[p].len();
+        p += 1;
+    }
+    len
+}
+
+/// Concatenates `parts` and sorts the result in ascending order, at compile
+/// time.
+///
+/// The parts must be pairwise disjoint: a code point that appears twice is a
+/// compile error, so the result is strictly ascending.
+#[allow(clippy::indexing_slicing, clippy::panic)] // compile-time only
+pub(crate) const fn sorted_union<const N: usize>(parts: &[&[u32]]) -> [u32; N] {
+    let mut concat = [0u32; N];
+    let mut len = 0;
+    let mut p = 0;
+    while p < parts.len() {
+        let mut i = 0;
+        while i < parts[p].len() {
+            concat[len] = parts[p][i];
+            len += 1;
+            i += 1;
+        }
+        p += 1;
+    }
+    assert!(len == N);
+
+    let out = crate::const_codepoints::sorted::<N>(&concat);
+    let mut i = 1;
+    while i < N {
+        if out[i - 1] >= out[i] {
+            panic!("duplicate code point in merged data tables");
+        }
+        i += 1;
+    }
+    out
+}
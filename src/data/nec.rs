@@ -0,0 +1,102 @@
+//! NEC special characters (JIS X 0208 row 13 extension)
+//!
+//! Row 13 is unassigned in JIS X 0208.  NEC filled it with circled digits,
+//! Roman numerals, squared unit symbols and a few mathematical operators;
+//! CP932 (Windows-31J) and therefore most Windows-originated Shift_JIS text
+//! inherited them.  Several of the operators duplicate characters of JIS X
+//! 0208 row 2.
+
+/// NEC row-13 characters, in kuten order, with the code points CP932 maps
+/// them to
+pub const NEC_ROW13: &[u32] = &[
+    0x2460, // ① (13-01) CIRCLED DIGIT ONE
+    0x2461, // ② (13-02) CIRCLED DIGIT TWO
+    0x2462, // ③ (13-03) CIRCLED DIGIT THREE
+    0x2463, // ④ (13-04) CIRCLED DIGIT FOUR
+    0x2464, // ⑤ (13-05) CIRCLED DIGIT FIVE
+    0x2465, // ⑥ (13-06) CIRCLED DIGIT SIX
+    0x2466, // ⑦ (13-07) CIRCLED DIGIT SEVEN
+    0x2467, // ⑧ (13-08) CIRCLED DIGIT EIGHT
+    0x2468, // ⑨ (13-09) CIRCLED DIGIT NINE
+    0x2469, // ⑩ (13-10) CIRCLED NUMBER TEN
+    0x246A, // ⑪ (13-11) CIRCLED NUMBER ELEVEN
+    0x246B, // ⑫ (13-12) CIRCLED NUMBER TWELVE
+    0x246C, // ⑬ (13-13) CIRCLED NUMBER THIRTEEN
+    0x246D, // ⑭ (13-14) CIRCLED NUMBER FOURTEEN
+    0x246E, // ⑮ (13-15) CIRCLED NUMBER FIFTEEN
+    0x246F, // ⑯ (13-16) CIRCLED NUMBER SIXTEEN
+    0x2470, // ⑰ (13-17) CIRCLED NUMBER SEVENTEEN
+    0x2471, // ⑱ (13-18) CIRCLED NUMBER EIGHTEEN
+    0x2472, // ⑲ (13-19) CIRCLED NUMBER NINETEEN
+    0x2473, // ⑳ (13-20) CIRCLED NUMBER TWENTY
+    0x2160, // Ⅰ (13-21) ROMAN NUMERAL ONE
+    0x2161, // Ⅱ (13-22) ROMAN NUMERAL TWO
+    0x2162, // Ⅲ (13-23) ROMAN NUMERAL THREE
+    0x2163, // Ⅳ (13-24) ROMAN NUMERAL FOUR
+    0x2164, // Ⅴ (13-25) ROMAN NUMERAL FIVE
+    0x2165, // Ⅵ (13-26) ROMAN NUMERAL SIX
+    0x2166, // Ⅶ (13-27) ROMAN NUMERAL SEVEN
+    0x2167, // Ⅷ (13-28) ROMAN NUMERAL EIGHT
+    0x2168, // Ⅸ (13-29) ROMAN NUMERAL NINE
+    0x2169, // Ⅹ (13-30) ROMAN NUMERAL TEN
+    0x3349, // ㍉ (13-32) SQUARE MIRI
+    0x3314, // ㌔ (13-33) SQUARE KIRO
+    0x3322, // ㌢ (13-34) SQUARE SENTI
+    0x334D, // ㍍ (13-35) SQUARE MEETORU
+    0x3318, // ㌘ (13-36) SQUARE GURAMU
+    0x3327, // ㌧ (13-37) SQUARE TON
+    0x3303, // ㌃ (13-38) SQUARE AARU
+    0x3336, // ㌶ (13-39) SQUARE HEKUTAARU
+    0x3351, // ㍑ (13-40) SQUARE RITTORU
+    0x3357, // ㍗ (13-41) SQUARE WATTO
+    0x330D, // ㌍ (13-42) SQUARE KARORII
+    0x3326, // ㌦ (13-43) SQUARE DORU
+    0x3323, // ㌣ (13-44) SQUARE SENTO
+    0x332B, // ㌫ (13-45) SQUARE PAASENTO
+    0x334A, // ㍊ (13-46) SQUARE MIRIBAARU
+    0x333B, // ㌻ (13-47) SQUARE PEEZI
+    0x339C, // ㎜ (13-48) SQUARE MM
+    0x339D, // ㎝ (13-49) SQUARE CM
+    0x339E, // ㎞ (13-50) SQUARE KM
+    0x338E, // ㎎ (13-51) SQUARE MG
+    0x338F, // ㎏ (13-52) SQUARE KG
+    0x33C4, // ㏄ (13-53) SQUARE CC
+    0x33A1, // ㎡ (13-54) SQUARE M SQUARED
+    0x337B, // ㍻ (13-63) SQUARE ERA NAME HEISEI
+    0x301D, // 〝 (13-64) REVERSED DOUBLE PRIME QUOTATION MARK
+    0x301F, // 〟 (13-65) LOW DOUBLE PRIME QUOTATION MARK
+    0x2116, // № (13-66) NUMERO SIGN
+    0x33CD, // ㏍ (13-67) SQUARE KK
+    0x2121, // ℡ (13-68) TELEPHONE SIGN
+    0x32A4, // ㊤ (13-69) CIRCLED IDEOGRAPH HIGH
+    0x32A5, // ㊥ (13-70) CIRCLED IDEOGRAPH CENTRE
+    0x32A6, // ㊦ (13-71) CIRCLED IDEOGRAPH LOW
+    0x32A7, // ㊧ (13-72) CIRCLED IDEOGRAPH LEFT
+    0x32A8, // ㊨ (13-73) CIRCLED IDEOGRAPH RIGHT
+    0x3231, // ㈱ (13-74) PARENTHESIZED IDEOGRAPH STOCK
+    0x3232, // ㈲ (13-75) PARENTHESIZED IDEOGRAPH HAVE
+    0x3239, // ㈹ (13-76) PARENTHESIZED IDEOGRAPH REPRESENT
+    0x337E, // ㍾ (13-77) SQUARE ERA NAME MEIZI
+    0x337D, // ㍽ (13-78) SQUARE ERA NAME TAISYOU
+    0x337C, // ㍼ (13-79) SQUARE ERA NAME SYOUWA
+    0x2252, // ≒ (13-80) APPROXIMATELY EQUAL TO OR THE IMAGE OF
+    0x2261, // ≡ (13-81) IDENTICAL TO
+    0x222B, // ∫ (13-82) INTEGRAL
+    0x222E, // ∮ (13-83) CONTOUR INTEGRAL
+    0x2211, // ∑ (13-84) N-ARY SUMMATION
+    0x221A, // √ (13-85) SQUARE ROOT
+    0x22A5, // ⊥ (13-86) UP TACK
+    0x2220, // ∠ (13-87) ANGLE
+    0x221F, // ∟ (13-88) RIGHT ANGLE
+    0x22BF, // ⊿ (13-89) RIGHT TRIANGLE
+    0x2235, // ∵ (13-90) BECAUSE
+    0x2229, // ∩ (13-91) INTERSECTION
+    0x222A, // ∪ (13-92) UNION
+];
+
+/// Number of entries in [`NEC_ROW13`].
+pub const NEC_ROW13_COUNT: usize = 83;
+const _: () = assert!(NEC_ROW13.len() == NEC_ROW13_COUNT);
+
+/// [`NEC_ROW13`] as `char`s, converted at compile time.
+pub const NEC_ROW13_CHARS: &[char] = &super::to_chars::<NEC_ROW13_COUNT>(NEC_ROW13);
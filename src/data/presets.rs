@@ -0,0 +1,3056 @@
+//! Character repertoires mandated by Japanese administrative rules
+//!
+//! Each table cites the rule it implements.  Repertoires built on the JIS
+//! tables list only what they add, e.g. the symbols of corporate names.
+
+/// Symbols allowed alongside katakana in corporate names
+///
+/// Source: 商業登記規則 第50条第1項 and 平成14年法務省告示第315号
+/// (商号の登記に用いることができる符号を定める件), which permits `＆ ’ ， ‐ ． ・`
+/// as separators.  `ー` is part of katakana orthography, and `－` is accepted
+/// as the fullwidth form of the hyphen commonly typed for `‐`.
+pub const CORPORATE_NAME_KANA_SYMBOLS: &[u32] = &[
+    0x2010, // ‐ HYPHEN (告示: ハイフン)
+    0x2019, // ’ RIGHT SINGLE QUOTATION MARK (告示: アポストロフィー)
+    0x30FB, // ・ KATAKANA MIDDLE DOT (告示: 中点)
+    0x30FC, // ー KATAKANA-HIRAGANA PROLONGED SOUND MARK (長音符)
+    0xFF06, // ＆ FULLWIDTH AMPERSAND (告示: アンパサンド)
+    0xFF0C, // ， FULLWIDTH COMMA (告示: コンマ)
+    0xFF0D, // － FULLWIDTH HYPHEN-MINUS (hyphen as typed on Japanese IMEs)
+    0xFF0E, // ． FULLWIDTH FULL STOP (告示: ピリオド)
+];
+
+/// Number of entries in [`CORPORATE_NAME_KANA_SYMBOLS`].
+pub const CORPORATE_NAME_KANA_SYMBOLS_COUNT: usize = 8;
+const _: () = assert!(CORPORATE_NAME_KANA_SYMBOLS.len() == CORPORATE_NAME_KANA_SYMBOLS_COUNT);
+
+/// The 常用漢字 (jōyō kanji), in the order of the table
+///
+/// Source: 常用漢字表 (平成22年内閣告示第2号), 2,136 characters.  Four of
+/// them — `𠮟 塡 剝 頰` — are JIS X 0213 only; the table lists them in
+/// those forms, not as the JIS X 0208 `叱 填 剥 頬`.
+pub const JOYO_KANJI: &[u32] = &[
+    0x4E9C,  // 亜
+    0x54C0,  // 哀
+    0x6328,  // 挨
+    0x611B,  // 愛
+    0x66D6,  // 曖
+    0x60AA,  // 悪
+    0x63E1,  // 握
+    0x5727,  // 圧
+    0x6271,  // 扱
+    0x5B9B,  // 宛
+    0x5D50,  // 嵐
+    0x5B89,  // 安
+    0x6848,  // 案
+    0x6697,  // 暗
+    0x4EE5,  // 以
+    0x8863,  // 衣
+    0x4F4D,  // 位
+    0x56F2,  // 囲
+    0x533B,  // 医
+    0x4F9D,  // 依
+    0x59D4,  // 委
+    0x5A01,  // 威
+    0x70BA,  // 為
+    0x754F,  // 畏
+    0x80C3,  // 胃
+    0x5C09,  // 尉
+    0x7570,  // 異
+    0x79FB,  // 移
+    0x840E,  // 萎
+    0x5049,  // 偉
+    0x6905,  // 椅
+    0x5F59,  // 彙
+    0x610F,  // 意
+    0x9055,  // 違
+    0x7DAD,  // 維
+    0x6170,  // 慰
+    0x907A,  // 遺
+    0x7DEF,  // 緯
+    0x57DF,  // 域
+    0x80B2,  // 育
+    0x4E00,  // 一
+    0x58F1,  // 壱
+    0x9038,  // 逸
+    0x8328,  // 茨
+    0x828B,  // 芋
+    0x5F15,  // 引
+    0x5370,  // 印
+    0x56E0,  // 因
+    0x54BD,  // 咽
+    0x59FB,  // 姻
+    0x54E1,  // 員
+    0x9662,  // 院
+    0x6DEB,  // 淫
+    0x9670,  // 陰
+    0x98F2,  // 飲
+    0x96A0,  // 隠
+    0x97FB,  // 韻
+    0x53F3,  // 右
+    0x5B87,  // 宇
+    0x7FBD,  // 羽
+    0x96E8,  // 雨
+    0x5504,  // 唄
+    0x9B31,  // 鬱
+    0x755D,  // 畝
+    0x6D66,  // 浦
+    0x904B,  // 運
+    0x96F2,  // 雲
+    0x6C38,  // 永
+    0x6CF3,  // 泳
+    0x82F1,  // 英
+    0x6620,  // 映
+    0x6804,  // 栄
+    0x55B6,  // 営
+    0x8A60,  // 詠
+    0x5F71,  // 影
+    0x92ED,  // 鋭
+    0x885B,  // 衛
+    0x6613,  // 易
+    0x75AB,  // 疫
+    0x76CA,  // 益
+    0x6DB2,  // 液
+    0x99C5,  // 駅
+    0x60A6,  // 悦
+    0x8D8A,  // 越
+    0x8B01,  // 謁
+    0x95B2,  // 閲
+    0x5186,  // 円
+    0x5EF6,  // 延
+    0x6CBF,  // 沿
+    0x708E,  // 炎
+    0x6028,  // 怨
+    0x5BB4,  // 宴
+    0x5A9B,  // 媛
+    0x63F4,  // 援
+    0x5712,  // 園
+    0x7159,  // 煙
+    0x733F,  // 猿
+    0x9060,  // 遠
+    0x925B,  // 鉛
+    0x5869,  // 塩
+    0x6F14,  // 演
+    0x7E01,  // 縁
+    0x8276,  // 艶
+    0x6C5A,  // 汚
+    0x738B,  // 王
+    0x51F9,  // 凹
+    0x592E,  // 央
+    0x5FDC,  // 応
+    0x5F80,  // 往
+    0x62BC,  // 押
+    0x65FA,  // 旺
+    0x6B27,  // 欧
+    0x6BB4,  // 殴
+    0x685C,  // 桜
+    0x7FC1,  // 翁
+    0x5965,  // 奥
+    0x6A2A,  // 横
+    0x5CA1,  // 岡
+    0x5C4B,  // 屋
+    0x5104,  // 億
+    0x61B6,  // 憶
+    0x81C6,  // 臆
+    0x865E,  // 虞
+    0x4E59,  // 乙
+    0x4FFA,  // 俺
+    0x5378,  // 卸
+    0x97F3,  // 音
+    0x6069,  // 恩
+    0x6E29,  // 温
+    0x7A4F,  // 穏
+    0x4E0B,  // 下
+    0x5316,  // 化
+    0x706B,  // 火
+    0x52A0,  // 加
+    0x53EF,  // 可
+    0x4EEE,  // 仮
+    0x4F55,  // 何
+    0x82B1,  // 花
+    0x4F73,  // 佳
+    0x4FA1,  // 価
+    0x679C,  // 果
+    0x6CB3,  // 河
+    0x82DB,  // 苛
+    0x79D1,  // 科
+    0x67B6,  // 架
+    0x590F,  // 夏
+    0x5BB6,  // 家
+    0x8377,  // 荷
+    0x83EF,  // 華
+    0x83D3,  // 菓
+    0x8CA8,  // 貨
+    0x6E26,  // 渦
+    0x904E,  // 過
+    0x5AC1,  // 嫁
+    0x6687,  // 暇
+    0x798D,  // 禍
+    0x9774,  // 靴
+    0x5BE1,  // 寡
+    0x6B4C,  // 歌
+    0x7B87,  // 箇
+    0x7A3C,  // 稼
+    0x8AB2,  // 課
+    0x868A,  // 蚊
+    0x7259,  // 牙
+    0x74E6,  // 瓦
+    0x6211,  // 我
+    0x753B,  // 画
+    0x82BD,  // 芽
+    0x8CC0,  // 賀
+    0x96C5,  // 雅
+    0x9913,  // 餓
+    0x4ECB,  // 介
+    0x56DE,  // 回
+    0x7070,  // 灰
+    0x4F1A,  // 会
+    0x5FEB,  // 快
+    0x6212,  // 戒
+    0x6539,  // 改
+    0x602A,  // 怪
+    0x62D0,  // 拐
+    0x6094,  // 悔
+    0x6D77,  // 海
+    0x754C,  // 界
+    0x7686,  // 皆
+    0x68B0,  // 械
+    0x7D75,  // 絵
+    0x958B,  // 開
+    0x968E,  // 階
+    0x584A,  // 塊
+    0x6977,  // 楷
+    0x89E3,  // 解
+    0x6F70,  // 潰
+    0x58CA,  // 壊
+    0x61D0,  // 懐
+    0x8AE7,  // 諧
+    0x8C9D,  // 貝
+    0x5916,  // 外
+    0x52BE,  // 劾
+    0x5BB3,  // 害
+    0x5D16,  // 崖
+    0x6DAF,  // 涯
+    0x8857,  // 街
+    0x6168,  // 慨
+    0x84CB,  // 蓋
+    0x8A72,  // 該
+    0x6982,  // 概
+    0x9AB8,  // 骸
+    0x57A3,  // 垣
+    0x67FF,  // 柿
+    0x5404,  // 各
+    0x89D2,  // 角
+    0x62E1,  // 拡
+    0x9769,  // 革
+    0x683C,  // 格
+    0x6838,  // 核
+    0x6BBB,  // 殻
+    0x90ED,  // 郭
+    0x899A,  // 覚
+    0x8F03,  // 較
+    0x9694,  // 隔
+    0x95A3,  // 閣
+    0x78BA,  // 確
+    0x7372,  // 獲
+    0x5687,  // 嚇
+    0x7A6B,  // 穫
+    0x5B66,  // 学
+    0x5CB3,  // 岳
+    0x697D,  // 楽
+    0x984D,  // 額
+    0x984E,  // 顎
+    0x639B,  // 掛
+    0x6F5F,  // 潟
+    0x62EC,  // 括
+    0x6D3B,  // 活
+    0x559D,  // 喝
+    0x6E07,  // 渇
+    0x5272,  // 割
+    0x845B,  // 葛
+    0x6ED1,  // 滑
+    0x8910,  // 褐
+    0x8F44,  // 轄
+    0x4E14,  // 且
+    0x682A,  // 株
+    0x91DC,  // 釜
+    0x938C,  // 鎌
+    0x5208,  // 刈
+    0x5E72,  // 干
+    0x520A,  // 刊
+    0x7518,  // 甘
+    0x6C57,  // 汗
+    0x7F36,  // 缶
+    0x5B8C,  // 完
+    0x809D,  // 肝
+    0x5B98,  // 官
+    0x51A0,  // 冠
+    0x5DFB,  // 巻
+    0x770B,  // 看
+    0x9665,  // 陥
+    0x4E7E,  // 乾
+    0x52D8,  // 勘
+    0x60A3,  // 患
+    0x8CAB,  // 貫
+    0x5BD2,  // 寒
+    0x559A,  // 喚
+    0x582A,  // 堪
+    0x63DB,  // 換
+    0x6562,  // 敢
+    0x68FA,  // 棺
+    0x6B3E,  // 款
+    0x9593,  // 間
+    0x9591,  // 閑
+    0x52E7,  // 勧
+    0x5BDB,  // 寛
+    0x5E79,  // 幹
+    0x611F,  // 感
+    0x6F22,  // 漢
+    0x6163,  // 慣
+    0x7BA1,  // 管
+    0x95A2,  // 関
+    0x6B53,  // 歓
+    0x76E3,  // 監
+    0x7DE9,  // 緩
+    0x61BE,  // 憾
+    0x9084,  // 還
+    0x9928,  // 館
+    0x74B0,  // 環
+    0x7C21,  // 簡
+    0x89B3,  // 観
+    0x97D3,  // 韓
+    0x8266,  // 艦
+    0x9451,  // 鑑
+    0x4E38,  // 丸
+    0x542B,  // 含
+    0x5CB8,  // 岸
+    0x5CA9,  // 岩
+    0x73A9,  // 玩
+    0x773C,  // 眼
+    0x9811,  // 頑
+    0x9854,  // 顔
+    0x9858,  // 願
+    0x4F01,  // 企
+    0x4F0E,  // 伎
+    0x5371,  // 危
+    0x673A,  // 机
+    0x6C17,  // 気
+    0x5C90,  // 岐
+    0x5E0C,  // 希
+    0x5FCC,  // 忌
+    0x6C7D,  // 汽
+    0x5947,  // 奇
+    0x7948,  // 祈
+    0x5B63,  // 季
+    0x7D00,  // 紀
+    0x8ECC,  // 軌
+    0x65E2,  // 既
+    0x8A18,  // 記
+    0x8D77,  // 起
+    0x98E2,  // 飢
+    0x9B3C,  // 鬼
+    0x5E30,  // 帰
+    0x57FA,  // 基
+    0x5BC4,  // 寄
+    0x898F,  // 規
+    0x4E80,  // 亀
+    0x559C,  // 喜
+    0x5E7E,  // 幾
+    0x63EE,  // 揮
+    0x671F,  // 期
+    0x68CB,  // 棋
+    0x8CB4,  // 貴
+    0x68C4,  // 棄
+    0x6BC0,  // 毀
+    0x65D7,  // 旗
+    0x5668,  // 器
+    0x757F,  // 畿
+    0x8F1D,  // 輝
+    0x6A5F,  // 機
+    0x9A0E,  // 騎
+    0x6280,  // 技
+    0x5B9C,  // 宜
+    0x507D,  // 偽
+    0x6B3A,  // 欺
+    0x7FA9,  // 義
+    0x7591,  // 疑
+    0x5100,  // 儀
+    0x622F,  // 戯
+    0x64EC,  // 擬
+    0x72A0,  // 犠
+    0x8B70,  // 議
+    0x83CA,  // 菊
+    0x5409,  // 吉
+    0x55AB,  // 喫
+    0x8A70,  // 詰
+    0x5374,  // 却
+    0x5BA2,  // 客
+    0x811A,  // 脚
+    0x9006,  // 逆
+    0x8650,  // 虐
+    0x4E5D,  // 九
+    0x4E45,  // 久
+    0x53CA,  // 及
+    0x5F13,  // 弓
+    0x4E18,  // 丘
+    0x65E7,  // 旧
+    0x4F11,  // 休
+    0x5438,  // 吸
+    0x673D,  // 朽
+    0x81FC,  // 臼
+    0x6C42,  // 求
+    0x7A76,  // 究
+    0x6CE3,  // 泣
+    0x6025,  // 急
+    0x7D1A,  // 級
+    0x7CFE,  // 糾
+    0x5BAE,  // 宮
+    0x6551,  // 救
+    0x7403,  // 球
+    0x7D66,  // 給
+    0x55C5,  // 嗅
+    0x7AAE,  // 窮
+    0x725B,  // 牛
+    0x53BB,  // 去
+    0x5DE8,  // 巨
+    0x5C45,  // 居
+    0x62D2,  // 拒
+    0x62E0,  // 拠
+    0x6319,  // 挙
+    0x865A,  // 虚
+    0x8A31,  // 許
+    0x8DDD,  // 距
+    0x9B5A,  // 魚
+    0x5FA1,  // 御
+    0x6F01,  // 漁
+    0x51F6,  // 凶
+    0x5171,  // 共
+    0x53EB,  // 叫
+    0x72C2,  // 狂
+    0x4EAC,  // 京
+    0x4EAB,  // 享
+    0x4F9B,  // 供
+    0x5354,  // 協
+    0x6CC1,  // 況
+    0x5CE1,  // 峡
+    0x631F,  // 挟
+    0x72ED,  // 狭
+    0x6050,  // 恐
+    0x606D,  // 恭
+    0x80F8,  // 胸
+    0x8105,  // 脅
+    0x5F37,  // 強
+    0x6559,  // 教
+    0x90F7,  // 郷
+    0x5883,  // 境
+    0x6A4B,  // 橋
+    0x77EF,  // 矯
+    0x93E1,  // 鏡
+    0x7AF6,  // 競
+    0x97FF,  // 響
+    0x9A5A,  // 驚
+    0x4EF0,  // 仰
+    0x6681,  // 暁
+    0x696D,  // 業
+    0x51DD,  // 凝
+    0x66F2,  // 曲
+    0x5C40,  // 局
+    0x6975,  // 極
+    0x7389,  // 玉
+    0x5DFE,  // 巾
+    0x65A4,  // 斤
+    0x5747,  // 均
+    0x8FD1,  // 近
+    0x91D1,  // 金
+    0x83CC,  // 菌
+    0x52E4,  // 勤
+    0x7434,  // 琴
+    0x7B4B,  // 筋
+    0x50C5,  // 僅
+    0x7981,  // 禁
+    0x7DCA,  // 緊
+    0x9326,  // 錦
+    0x8B39,  // 謹
+    0x895F,  // 襟
+    0x541F,  // 吟
+    0x9280,  // 銀
+    0x533A,  // 区
+    0x53E5,  // 句
+    0x82E6,  // 苦
+    0x99C6,  // 駆
+    0x5177,  // 具
+    0x60E7,  // 惧
+    0x611A,  // 愚
+    0x7A7A,  // 空
+    0x5076,  // 偶
+    0x9047,  // 遇
+    0x9685,  // 隅
+    0x4E32,  // 串
+    0x5C48,  // 屈
+    0x6398,  // 掘
+    0x7A9F,  // 窟
+    0x718A,  // 熊
+    0x7E70,  // 繰
+    0x541B,  // 君
+    0x8A13,  // 訓
+    0x52F2,  // 勲
+    0x85AB,  // 薫
+    0x8ECD,  // 軍
+    0x90E1,  // 郡
+    0x7FA4,  // 群
+    0x5144,  // 兄
+    0x5211,  // 刑
+    0x5F62,  // 形
+    0x7CFB,  // 系
+    0x5F84,  // 径
+    0x830E,  // 茎
+    0x4FC2,  // 係
+    0x578B,  // 型
+    0x5951,  // 契
+    0x8A08,  // 計
+    0x6075,  // 恵
+    0x5553,  // 啓
+    0x63B2,  // 掲
+    0x6E13,  // 渓
+    0x7D4C,  // 経
+    0x86CD,  // 蛍
+    0x656C,  // 敬
+    0x666F,  // 景
+    0x8EFD,  // 軽
+    0x50BE,  // 傾
+    0x643A,  // 携
+    0x7D99,  // 継
+    0x8A63,  // 詣
+    0x6176,  // 慶
+    0x61AC,  // 憬
+    0x7A3D,  // 稽
+    0x61A9,  // 憩
+    0x8B66,  // 警
+    0x9D8F,  // 鶏
+    0x82B8,  // 芸
+    0x8FCE,  // 迎
+    0x9BE8,  // 鯨
+    0x9699,  // 隙
+    0x5287,  // 劇
+    0x6483,  // 撃
+    0x6FC0,  // 激
+    0x6841,  // 桁
+    0x6B20,  // 欠
+    0x7A74,  // 穴
+    0x8840,  // 血
+    0x6C7A,  // 決
+    0x7D50,  // 結
+    0x5091,  // 傑
+    0x6F54,  // 潔
+    0x6708,  // 月
+    0x72AC,  // 犬
+    0x4EF6,  // 件
+    0x898B,  // 見
+    0x5238,  // 券
+    0x80A9,  // 肩
+    0x5EFA,  // 建
+    0x7814,  // 研
+    0x770C,  // 県
+    0x5039,  // 倹
+    0x517C,  // 兼
+    0x5263,  // 剣
+    0x62F3,  // 拳
+    0x8ED2,  // 軒
+    0x5065,  // 健
+    0x967A,  // 険
+    0x570F,  // 圏
+    0x5805,  // 堅
+    0x691C,  // 検
+    0x5ACC,  // 嫌
+    0x732E,  // 献
+    0x7D79,  // 絹
+    0x9063,  // 遣
+    0x6A29,  // 権
+    0x61B2,  // 憲
+    0x8CE2,  // 賢
+    0x8B19,  // 謙
+    0x9375,  // 鍵
+    0x7E6D,  // 繭
+    0x9855,  // 顕
+    0x9A13,  // 験
+    0x61F8,  // 懸
+    0x5143,  // 元
+    0x5E7B,  // 幻
+    0x7384,  // 玄
+    0x8A00,  // 言
+    0x5F26,  // 弦
+    0x9650,  // 限
+    0x539F,  // 原
+    0x73FE,  // 現
+    0x8237,  // 舷
+    0x6E1B,  // 減
+    0x6E90,  // 源
+    0x53B3,  // 厳
+    0x5DF1,  // 己
+    0x6238,  // 戸
+    0x53E4,  // 古
+    0x547C,  // 呼
+    0x56FA,  // 固
+    0x80A1,  // 股
+    0x864E,  // 虎
+    0x5B64,  // 孤
+    0x5F27,  // 弧
+    0x6545,  // 故
+    0x67AF,  // 枯
+    0x500B,  // 個
+    0x5EAB,  // 庫
+    0x6E56,  // 湖
+    0x96C7,  // 雇
+    0x8A87,  // 誇
+    0x9F13,  // 鼓
+    0x932E,  // 錮
+    0x9867,  // 顧
+    0x4E94,  // 五
+    0x4E92,  // 互
+    0x5348,  // 午
+    0x5449,  // 呉
+    0x5F8C,  // 後
+    0x5A2F,  // 娯
+    0x609F,  // 悟
+    0x7881,  // 碁
+    0x8A9E,  // 語
+    0x8AA4,  // 誤
+    0x8B77,  // 護
+    0x53E3,  // 口
+    0x5DE5,  // 工
+    0x516C,  // 公
+    0x52FE,  // 勾
+    0x5B54,  // 孔
+    0x529F,  // 功
+    0x5DE7,  // 巧
+    0x5E83,  // 広
+    0x7532,  // 甲
+    0x4EA4,  // 交
+    0x5149,  // 光
+    0x5411,  // 向
+    0x540E,  // 后
+    0x597D,  // 好
+    0x6C5F,  // 江
+    0x8003,  // 考
+    0x884C,  // 行
+    0x5751,  // 坑
+    0x5B5D,  // 孝
+    0x6297,  // 抗
+    0x653B,  // 攻
+    0x66F4,  // 更
+    0x52B9,  // 効
+    0x5E78,  // 幸
+    0x62D8,  // 拘
+    0x80AF,  // 肯
+    0x4FAF,  // 侯
+    0x539A,  // 厚
+    0x6052,  // 恒
+    0x6D2A,  // 洪
+    0x7687,  // 皇
+    0x7D05,  // 紅
+    0x8352,  // 荒
+    0x90CA,  // 郊
+    0x9999,  // 香
+    0x5019,  // 候
+    0x6821,  // 校
+    0x8015,  // 耕
+    0x822A,  // 航
+    0x8CA2,  // 貢
+    0x964D,  // 降
+    0x9AD8,  // 高
+    0x5EB7,  // 康
+    0x63A7,  // 控
+    0x6897,  // 梗
+    0x9EC4,  // 黄
+    0x5589,  // 喉
+    0x614C,  // 慌
+    0x6E2F,  // 港
+    0x786C,  // 硬
+    0x7D5E,  // 絞
+    0x9805,  // 項
+    0x6E9D,  // 溝
+    0x9271,  // 鉱
+    0x69CB,  // 構
+    0x7DB1,  // 綱
+    0x9175,  // 酵
+    0x7A3F,  // 稿
+    0x8208,  // 興
+    0x8861,  // 衡
+    0x92FC,  // 鋼
+    0x8B1B,  // 講
+    0x8CFC,  // 購
+    0x4E5E,  // 乞
+    0x53F7,  // 号
+    0x5408,  // 合
+    0x62F7,  // 拷
+    0x525B,  // 剛
+    0x50B2,  // 傲
+    0x8C6A,  // 豪
+    0x514B,  // 克
+    0x544A,  // 告
+    0x8C37,  // 谷
+    0x523B,  // 刻
+    0x56FD,  // 国
+    0x9ED2,  // 黒
+    0x7A40,  // 穀
+    0x9177,  // 酷
+    0x7344,  // 獄
+    0x9AA8,  // 骨
+    0x99D2,  // 駒
+    0x8FBC,  // 込
+    0x9803,  // 頃
+    0x4ECA,  // 今
+    0x56F0,  // 困
+    0x6606,  // 昆
+    0x6068,  // 恨
+    0x6839,  // 根
+    0x5A5A,  // 婚
+    0x6DF7,  // 混
+    0x75D5,  // 痕
+    0x7D3A,  // 紺
+    0x9B42,  // 魂
+    0x58BE,  // 墾
+    0x61C7,  // 懇
+    0x5DE6,  // 左
+    0x4F50,  // 佐
+    0x6C99,  // 沙
+    0x67FB,  // 査
+    0x7802,  // 砂
+    0x5506,  // 唆
+    0x5DEE,  // 差
+    0x8A50,  // 詐
+    0x9396,  // 鎖
+    0x5EA7,  // 座
+    0x632B,  // 挫
+    0x624D,  // 才
+    0x518D,  // 再
+    0x707D,  // 災
+    0x59BB,  // 妻
+    0x91C7,  // 采
+    0x7815,  // 砕
+    0x5BB0,  // 宰
+    0x683D,  // 栽
+    0x5F69,  // 彩
+    0x63A1,  // 採
+    0x6E08,  // 済
+    0x796D,  // 祭
+    0x658E,  // 斎
+    0x7D30,  // 細
+    0x83DC,  // 菜
+    0x6700,  // 最
+    0x88C1,  // 裁
+    0x50B5,  // 債
+    0x50AC,  // 催
+    0x585E,  // 塞
+    0x6B73,  // 歳
+    0x8F09,  // 載
+    0x969B,  // 際
+    0x57FC,  // 埼
+    0x5728,  // 在
+    0x6750,  // 材
+    0x5264,  // 剤
+    0x8CA1,  // 財
+    0x7F6A,  // 罪
+    0x5D0E,  // 崎
+    0x4F5C,  // 作
+    0x524A,  // 削
+    0x6628,  // 昨
+    0x67F5,  // 柵
+    0x7D22,  // 索
+    0x7B56,  // 策
+    0x9162,  // 酢
+    0x643E,  // 搾
+    0x932F,  // 錯
+    0x54B2,  // 咲
+    0x518A,  // 冊
+    0x672D,  // 札
+    0x5237,  // 刷
+    0x5239,  // 刹
+    0x62F6,  // 拶
+    0x6BBA,  // 殺
+    0x5BDF,  // 察
+    0x64AE,  // 撮
+    0x64E6,  // 擦
+    0x96D1,  // 雑
+    0x76BF,  // 皿
+    0x4E09,  // 三
+    0x5C71,  // 山
+    0x53C2,  // 参
+    0x685F,  // 桟
+    0x8695,  // 蚕
+    0x60E8,  // 惨
+    0x7523,  // 産
+    0x5098,  // 傘
+    0x6563,  // 散
+    0x7B97,  // 算
+    0x9178,  // 酸
+    0x8CDB,  // 賛
+    0x6B8B,  // 残
+    0x65AC,  // 斬
+    0x66AB,  // 暫
+    0x58EB,  // 士
+    0x5B50,  // 子
+    0x652F,  // 支
+    0x6B62,  // 止
+    0x6C0F,  // 氏
+    0x4ED5,  // 仕
+    0x53F2,  // 史
+    0x53F8,  // 司
+    0x56DB,  // 四
+    0x5E02,  // 市
+    0x77E2,  // 矢
+    0x65E8,  // 旨
+    0x6B7B,  // 死
+    0x7CF8,  // 糸
+    0x81F3,  // 至
+    0x4F3A,  // 伺
+    0x5FD7,  // 志
+    0x79C1,  // 私
+    0x4F7F,  // 使
+    0x523A,  // 刺
+    0x59CB,  // 始
+    0x59C9,  // 姉
+    0x679D,  // 枝
+    0x7949,  // 祉
+    0x80A2,  // 肢
+    0x59FF,  // 姿
+    0x601D,  // 思
+    0x6307,  // 指
+    0x65BD,  // 施
+    0x5E2B,  // 師
+    0x6063,  // 恣
+    0x7D19,  // 紙
+    0x8102,  // 脂
+    0x8996,  // 視
+    0x7D2B,  // 紫
+    0x8A5E,  // 詞
+    0x6B6F,  // 歯
+    0x55E3,  // 嗣
+    0x8A66,  // 試
+    0x8A69,  // 詩
+    0x8CC7,  // 資
+    0x98FC,  // 飼
+    0x8A8C,  // 誌
+    0x96CC,  // 雌
+    0x646F,  // 摯
+    0x8CDC,  // 賜
+    0x8AEE,  // 諮
+    0x793A,  // 示
+    0x5B57,  // 字
+    0x5BFA,  // 寺
+    0x6B21,  // 次
+    0x8033,  // 耳
+    0x81EA,  // 自
+    0x4F3C,  // 似
+    0x5150,  // 児
+    0x4E8B,  // 事
+    0x4F8D,  // 侍
+    0x6CBB,  // 治
+    0x6301,  // 持
+    0x6642,  // 時
+    0x6ECB,  // 滋
+    0x6148,  // 慈
+    0x8F9E,  // 辞
+    0x78C1,  // 磁
+    0x990C,  // 餌
+    0x74BD,  // 璽
+    0x9E7F,  // 鹿
+    0x5F0F,  // 式
+    0x8B58,  // 識
+    0x8EF8,  // 軸
+    0x4E03,  // 七
+    0x20B9F, // 𠮟
+    0x5931,  // 失
+    0x5BA4,  // 室
+    0x75BE,  // 疾
+    0x57F7,  // 執
+    0x6E7F,  // 湿
+    0x5AC9,  // 嫉
+    0x6F06,  // 漆
+    0x8CEA,  // 質
+    0x5B9F,  // 実
+    0x829D,  // 芝
+    0x5199,  // 写
+    0x793E,  // 社
+    0x8ECA,  // 車
+    0x820E,  // 舎
+    0x8005,  // 者
+    0x5C04,  // 射
+    0x6368,  // 捨
+    0x8D66,  // 赦
+    0x659C,  // 斜
+    0x716E,  // 煮
+    0x906E,  // 遮
+    0x8B1D,  // 謝
+    0x90AA,  // 邪
+    0x86C7,  // 蛇
+    0x5C3A,  // 尺
+    0x501F,  // 借
+    0x914C,  // 酌
+    0x91C8,  // 釈
+    0x7235,  // 爵
+    0x82E5,  // 若
+    0x5F31,  // 弱
+    0x5BC2,  // 寂
+    0x624B,  // 手
+    0x4E3B,  // 主
+    0x5B88,  // 守
+    0x6731,  // 朱
+    0x53D6,  // 取
+    0x72E9,  // 狩
+    0x9996,  // 首
+    0x6B8A,  // 殊
+    0x73E0,  // 珠
+    0x9152,  // 酒
+    0x816B,  // 腫
+    0x7A2E,  // 種
+    0x8DA3,  // 趣
+    0x5BFF,  // 寿
+    0x53D7,  // 受
+    0x546A,  // 呪
+    0x6388,  // 授
+    0x9700,  // 需
+    0x5112,  // 儒
+    0x6A39,  // 樹
+    0x53CE,  // 収
+    0x56DA,  // 囚
+    0x5DDE,  // 州
+    0x821F,  // 舟
+    0x79C0,  // 秀
+    0x5468,  // 周
+    0x5B97,  // 宗
+    0x62FE,  // 拾
+    0x79CB,  // 秋
+    0x81ED,  // 臭
+    0x4FEE,  // 修
+    0x8896,  // 袖
+    0x7D42,  // 終
+    0x7F9E,  // 羞
+    0x7FD2,  // 習
+    0x9031,  // 週
+    0x5C31,  // 就
+    0x8846,  // 衆
+    0x96C6,  // 集
+    0x6101,  // 愁
+    0x916C,  // 酬
+    0x919C,  // 醜
+    0x8E74,  // 蹴
+    0x8972,  // 襲
+    0x5341,  // 十
+    0x6C41,  // 汁
+    0x5145,  // 充
+    0x4F4F,  // 住
+    0x67D4,  // 柔
+    0x91CD,  // 重
+    0x5F93,  // 従
+    0x6E0B,  // 渋
+    0x9283,  // 銃
+    0x7363,  // 獣
+    0x7E26,  // 縦
+    0x53D4,  // 叔
+    0x795D,  // 祝
+    0x5BBF,  // 宿
+    0x6DD1,  // 淑
+    0x7C9B,  // 粛
+    0x7E2E,  // 縮
+    0x587E,  // 塾
+    0x719F,  // 熟
+    0x51FA,  // 出
+    0x8FF0,  // 述
+    0x8853,  // 術
+    0x4FCA,  // 俊
+    0x6625,  // 春
+    0x77AC,  // 瞬
+    0x65EC,  // 旬
+    0x5DE1,  // 巡
+    0x76FE,  // 盾
+    0x51C6,  // 准
+    0x6B89,  // 殉
+    0x7D14,  // 純
+    0x5FAA,  // 循
+    0x9806,  // 順
+    0x6E96,  // 準
+    0x6F64,  // 潤
+    0x9075,  // 遵
+    0x51E6,  // 処
+    0x521D,  // 初
+    0x6240,  // 所
+    0x66F8,  // 書
+    0x5EB6,  // 庶
+    0x6691,  // 暑
+    0x7F72,  // 署
+    0x7DD2,  // 緒
+    0x8AF8,  // 諸
+    0x5973,  // 女
+    0x5982,  // 如
+    0x52A9,  // 助
+    0x5E8F,  // 序
+    0x53D9,  // 叙
+    0x5F90,  // 徐
+    0x9664,  // 除
+    0x5C0F,  // 小
+    0x5347,  // 升
+    0x5C11,  // 少
+    0x53EC,  // 召
+    0x5320,  // 匠
+    0x5E8A,  // 床
+    0x6284,  // 抄
+    0x8096,  // 肖
+    0x5C1A,  // 尚
+    0x62DB,  // 招
+    0x627F,  // 承
+    0x6607,  // 昇
+    0x677E,  // 松
+    0x6CBC,  // 沼
+    0x662D,  // 昭
+    0x5BB5,  // 宵
+    0x5C06,  // 将
+    0x6D88,  // 消
+    0x75C7,  // 症
+    0x7965,  // 祥
+    0x79F0,  // 称
+    0x7B11,  // 笑
+    0x5531,  // 唱
+    0x5546,  // 商
+    0x6E09,  // 渉
+    0x7AE0,  // 章
+    0x7D39,  // 紹
+    0x8A1F,  // 訟
+    0x52DD,  // 勝
+    0x638C,  // 掌
+    0x6676,  // 晶
+    0x713C,  // 焼
+    0x7126,  // 焦
+    0x785D,  // 硝
+    0x7CA7,  // 粧
+    0x8A54,  // 詔
+    0x8A3C,  // 証
+    0x8C61,  // 象
+    0x50B7,  // 傷
+    0x5968,  // 奨
+    0x7167,  // 照
+    0x8A73,  // 詳
+    0x5F70,  // 彰
+    0x969C,  // 障
+    0x61A7,  // 憧
+    0x885D,  // 衝
+    0x8CDE,  // 賞
+    0x511F,  // 償
+    0x7901,  // 礁
+    0x9418,  // 鐘
+    0x4E0A,  // 上
+    0x4E08,  // 丈
+    0x5197,  // 冗
+    0x6761,  // 条
+    0x72B6,  // 状
+    0x4E57,  // 乗
+    0x57CE,  // 城
+    0x6D44,  // 浄
+    0x5270,  // 剰
+    0x5E38,  // 常
+    0x60C5,  // 情
+    0x5834,  // 場
+    0x7573,  // 畳
+    0x84B8,  // 蒸
+    0x7E04,  // 縄
+    0x58CC,  // 壌
+    0x5B22,  // 嬢
+    0x9320,  // 錠
+    0x8B72,  // 譲
+    0x91B8,  // 醸
+    0x8272,  // 色
+    0x62ED,  // 拭
+    0x98DF,  // 食
+    0x690D,  // 植
+    0x6B96,  // 殖
+    0x98FE,  // 飾
+    0x89E6,  // 触
+    0x5631,  // 嘱
+    0x7E54,  // 織
+    0x8077,  // 職
+    0x8FB1,  // 辱
+    0x5C3B,  // 尻
+    0x5FC3,  // 心
+    0x7533,  // 申
+    0x4F38,  // 伸
+    0x81E3,  // 臣
+    0x82AF,  // 芯
+    0x8EAB,  // 身
+    0x8F9B,  // 辛
+    0x4FB5,  // 侵
+    0x4FE1,  // 信
+    0x6D25,  // 津
+    0x795E,  // 神
+    0x5507,  // 唇
+    0x5A20,  // 娠
+    0x632F,  // 振
+    0x6D78,  // 浸
+    0x771F,  // 真
+    0x91DD,  // 針
+    0x6DF1,  // 深
+    0x7D33,  // 紳
+    0x9032,  // 進
+    0x68EE,  // 森
+    0x8A3A,  // 診
+    0x5BDD,  // 寝
+    0x614E,  // 慎
+    0x65B0,  // 新
+    0x5BE9,  // 審
+    0x9707,  // 震
+    0x85AA,  // 薪
+    0x89AA,  // 親
+    0x4EBA,  // 人
+    0x5203,  // 刃
+    0x4EC1,  // 仁
+    0x5C3D,  // 尽
+    0x8FC5,  // 迅
+    0x751A,  // 甚
+    0x9663,  // 陣
+    0x5C0B,  // 尋
+    0x814E,  // 腎
+    0x9808,  // 須
+    0x56F3,  // 図
+    0x6C34,  // 水
+    0x5439,  // 吹
+    0x5782,  // 垂
+    0x708A,  // 炊
+    0x5E25,  // 帥
+    0x7C8B,  // 粋
+    0x8870,  // 衰
+    0x63A8,  // 推
+    0x9154,  // 酔
+    0x9042,  // 遂
+    0x7761,  // 睡
+    0x7A42,  // 穂
+    0x968F,  // 随
+    0x9AC4,  // 髄
+    0x67A2,  // 枢
+    0x5D07,  // 崇
+    0x6570,  // 数
+    0x636E,  // 据
+    0x6749,  // 杉
+    0x88FE,  // 裾
+    0x5BF8,  // 寸
+    0x702C,  // 瀬
+    0x662F,  // 是
+    0x4E95,  // 井
+    0x4E16,  // 世
+    0x6B63,  // 正
+    0x751F,  // 生
+    0x6210,  // 成
+    0x897F,  // 西
+    0x58F0,  // 声
+    0x5236,  // 制
+    0x59D3,  // 姓
+    0x5F81,  // 征
+    0x6027,  // 性
+    0x9752,  // 青
+    0x6589,  // 斉
+    0x653F,  // 政
+    0x661F,  // 星
+    0x7272,  // 牲
+    0x7701,  // 省
+    0x51C4,  // 凄
+    0x901D,  // 逝
+    0x6E05,  // 清
+    0x76DB,  // 盛
+    0x5A7F,  // 婿
+    0x6674,  // 晴
+    0x52E2,  // 勢
+    0x8056,  // 聖
+    0x8AA0,  // 誠
+    0x7CBE,  // 精
+    0x88FD,  // 製
+    0x8A93,  // 誓
+    0x9759,  // 静
+    0x8ACB,  // 請
+    0x6574,  // 整
+    0x9192,  // 醒
+    0x7A0E,  // 税
+    0x5915,  // 夕
+    0x65A5,  // 斥
+    0x77F3,  // 石
+    0x8D64,  // 赤
+    0x6614,  // 昔
+    0x6790,  // 析
+    0x5E2D,  // 席
+    0x810A,  // 脊
+    0x96BB,  // 隻
+    0x60DC,  // 惜
+    0x621A,  // 戚
+    0x8CAC,  // 責
+    0x8DE1,  // 跡
+    0x7A4D,  // 積
+    0x7E3E,  // 績
+    0x7C4D,  // 籍
+    0x5207,  // 切
+    0x6298,  // 折
+    0x62D9,  // 拙
+    0x7A83,  // 窃
+    0x63A5,  // 接
+    0x8A2D,  // 設
+    0x96EA,  // 雪
+    0x6442,  // 摂
+    0x7BC0,  // 節
+    0x8AAC,  // 説
+    0x820C,  // 舌
+    0x7D76,  // 絶
+    0x5343,  // 千
+    0x5DDD,  // 川
+    0x4ED9,  // 仙
+    0x5360,  // 占
+    0x5148,  // 先
+    0x5BA3,  // 宣
+    0x5C02,  // 専
+    0x6CC9,  // 泉
+    0x6D45,  // 浅
+    0x6D17,  // 洗
+    0x67D3,  // 染
+    0x6247,  // 扇
+    0x6813,  // 栓
+    0x65CB,  // 旋
+    0x8239,  // 船
+    0x6226,  // 戦
+    0x714E,  // 煎
+    0x7FA8,  // 羨
+    0x817A,  // 腺
+    0x8A6E,  // 詮
+    0x8DF5,  // 践
+    0x7B8B,  // 箋
+    0x92AD,  // 銭
+    0x6F5C,  // 潜
+    0x7DDA,  // 線
+    0x9077,  // 遷
+    0x9078,  // 選
+    0x85A6,  // 薦
+    0x7E4A,  // 繊
+    0x9BAE,  // 鮮
+    0x5168,  // 全
+    0x524D,  // 前
+    0x5584,  // 善
+    0x7136,  // 然
+    0x7985,  // 禅
+    0x6F38,  // 漸
+    0x81B3,  // 膳
+    0x7E55,  // 繕
+    0x72D9,  // 狙
+    0x963B,  // 阻
+    0x7956,  // 祖
+    0x79DF,  // 租
+    0x7D20,  // 素
+    0x63AA,  // 措
+    0x7C97,  // 粗
+    0x7D44,  // 組
+    0x758E,  // 疎
+    0x8A34,  // 訴
+    0x5851,  // 塑
+    0x9061,  // 遡
+    0x790E,  // 礎
+    0x53CC,  // 双
+    0x58EE,  // 壮
+    0x65E9,  // 早
+    0x4E89,  // 争
+    0x8D70,  // 走
+    0x594F,  // 奏
+    0x76F8,  // 相
+    0x8358,  // 荘
+    0x8349,  // 草
+    0x9001,  // 送
+    0x5009,  // 倉
+    0x635C,  // 捜
+    0x633F,  // 挿
+    0x6851,  // 桑
+    0x5DE3,  // 巣
+    0x6383,  // 掃
+    0x66F9,  // 曹
+    0x66FD,  // 曽
+    0x723D,  // 爽
+    0x7A93,  // 窓
+    0x5275,  // 創
+    0x55AA,  // 喪
+    0x75E9,  // 痩
+    0x846C,  // 葬
+    0x88C5,  // 装
+    0x50E7,  // 僧
+    0x60F3,  // 想
+    0x5C64,  // 層
+    0x7DCF,  // 総
+    0x906D,  // 遭
+    0x69FD,  // 槽
+    0x8E2A,  // 踪
+    0x64CD,  // 操
+    0x71E5,  // 燥
+    0x971C,  // 霜
+    0x9A12,  // 騒
+    0x85FB,  // 藻
+    0x9020,  // 造
+    0x50CF,  // 像
+    0x5897,  // 増
+    0x618E,  // 憎
+    0x8535,  // 蔵
+    0x8D08,  // 贈
+    0x81D3,  // 臓
+    0x5373,  // 即
+    0x675F,  // 束
+    0x8DB3,  // 足
+    0x4FC3,  // 促
+    0x5247,  // 則
+    0x606F,  // 息
+    0x6349,  // 捉
+    0x901F,  // 速
+    0x5074,  // 側
+    0x6E2C,  // 測
+    0x4FD7,  // 俗
+    0x65CF,  // 族
+    0x5C5E,  // 属
+    0x8CCA,  // 賊
+    0x7D9A,  // 続
+    0x5352,  // 卒
+    0x7387,  // 率
+    0x5B58,  // 存
+    0x6751,  // 村
+    0x5B6B,  // 孫
+    0x5C0A,  // 尊
+    0x640D,  // 損
+    0x905C,  // 遜
+    0x4ED6,  // 他
+    0x591A,  // 多
+    0x6C70,  // 汰
+    0x6253,  // 打
+    0x59A5,  // 妥
+    0x553E,  // 唾
+    0x5815,  // 堕
+    0x60F0,  // 惰
+    0x99C4,  // 駄
+    0x592A,  // 太
+    0x5BFE,  // 対
+    0x4F53,  // 体
+    0x8010,  // 耐
+    0x5F85,  // 待
+    0x6020,  // 怠
+    0x80CE,  // 胎
+    0x9000,  // 退
+    0x5E2F,  // 帯
+    0x6CF0,  // 泰
+    0x5806,  // 堆
+    0x888B,  // 袋
+    0x902E,  // 逮
+    0x66FF,  // 替
+    0x8CB8,  // 貸
+    0x968A,  // 隊
+    0x6EDE,  // 滞
+    0x614B,  // 態
+    0x6234,  // 戴
+    0x5927,  // 大
+    0x4EE3,  // 代
+    0x53F0,  // 台
+    0x7B2C,  // 第
+    0x984C,  // 題
+    0x6EDD,  // 滝
+    0x5B85,  // 宅
+    0x629E,  // 択
+    0x6CA2,  // 沢
+    0x5353,  // 卓
+    0x62D3,  // 拓
+    0x8A17,  // 託
+    0x6FEF,  // 濯
+    0x8AFE,  // 諾
+    0x6FC1,  // 濁
+    0x4F46,  // 但
+    0x9054,  // 達
+    0x8131,  // 脱
+    0x596A,  // 奪
+    0x68DA,  // 棚
+    0x8AB0,  // 誰
+    0x4E39,  // 丹
+    0x65E6,  // 旦
+    0x62C5,  // 担
+    0x5358,  // 単
+    0x70AD,  // 炭
+    0x80C6,  // 胆
+    0x63A2,  // 探
+    0x6DE1,  // 淡
+    0x77ED,  // 短
+    0x5606,  // 嘆
+    0x7AEF,  // 端
+    0x7DBB,  // 綻
+    0x8A95,  // 誕
+    0x935B,  // 鍛
+    0x56E3,  // 団
+    0x7537,  // 男
+    0x6BB5,  // 段
+    0x65AD,  // 断
+    0x5F3E,  // 弾
+    0x6696,  // 暖
+    0x8AC7,  // 談
+    0x58C7,  // 壇
+    0x5730,  // 地
+    0x6C60,  // 池
+    0x77E5,  // 知
+    0x5024,  // 値
+    0x6065,  // 恥
+    0x81F4,  // 致
+    0x9045,  // 遅
+    0x75F4,  // 痴
+    0x7A1A,  // 稚
+    0x7F6E,  // 置
+    0x7DFB,  // 緻
+    0x7AF9,  // 竹
+    0x755C,  // 畜
+    0x9010,  // 逐
+    0x84C4,  // 蓄
+    0x7BC9,  // 築
+    0x79E9,  // 秩
+    0x7A92,  // 窒
+    0x8336,  // 茶
+    0x7740,  // 着
+    0x5AE1,  // 嫡
+    0x4E2D,  // 中
+    0x4EF2,  // 仲
+    0x866B,  // 虫
+    0x6C96,  // 沖
+    0x5B99,  // 宙
+    0x5FE0,  // 忠
+    0x62BD,  // 抽
+    0x6CE8,  // 注
+    0x663C,  // 昼
+    0x67F1,  // 柱
+    0x8877,  // 衷
+    0x914E,  // 酎
+    0x92F3,  // 鋳
+    0x99D0,  // 駐
+    0x8457,  // 著
+    0x8CAF,  // 貯
+    0x4E01,  // 丁
+    0x5F14,  // 弔
+    0x5E81,  // 庁
+    0x5146,  // 兆
+    0x753A,  // 町
+    0x9577,  // 長
+    0x6311,  // 挑
+    0x5E33,  // 帳
+    0x5F35,  // 張
+    0x5F6B,  // 彫
+    0x773A,  // 眺
+    0x91E3,  // 釣
+    0x9802,  // 頂
+    0x9CE5,  // 鳥
+    0x671D,  // 朝
+    0x8CBC,  // 貼
+    0x8D85,  // 超
+    0x8178,  // 腸
+    0x8DF3,  // 跳
+    0x5FB4,  // 徴
+    0x5632,  // 嘲
+    0x6F6E,  // 潮
+    0x6F84,  // 澄
+    0x8ABF,  // 調
+    0x8074,  // 聴
+    0x61F2,  // 懲
+    0x76F4,  // 直
+    0x52C5,  // 勅
+    0x6357,  // 捗
+    0x6C88,  // 沈
+    0x73CD,  // 珍
+    0x6715,  // 朕
+    0x9673,  // 陳
+    0x8CC3,  // 賃
+    0x93AE,  // 鎮
+    0x8FFD,  // 追
+    0x690E,  // 椎
+    0x589C,  // 墜
+    0x901A,  // 通
+    0x75DB,  // 痛
+    0x585A,  // 塚
+    0x6F2C,  // 漬
+    0x576A,  // 坪
+    0x722A,  // 爪
+    0x9DB4,  // 鶴
+    0x4F4E,  // 低
+    0x5448,  // 呈
+    0x5EF7,  // 廷
+    0x5F1F,  // 弟
+    0x5B9A,  // 定
+    0x5E95,  // 底
+    0x62B5,  // 抵
+    0x90B8,  // 邸
+    0x4EAD,  // 亭
+    0x8C9E,  // 貞
+    0x5E1D,  // 帝
+    0x8A02,  // 訂
+    0x5EAD,  // 庭
+    0x9013,  // 逓
+    0x505C,  // 停
+    0x5075,  // 偵
+    0x5824,  // 堤
+    0x63D0,  // 提
+    0x7A0B,  // 程
+    0x8247,  // 艇
+    0x7DE0,  // 締
+    0x8AE6,  // 諦
+    0x6CE5,  // 泥
+    0x7684,  // 的
+    0x7B1B,  // 笛
+    0x6458,  // 摘
+    0x6EF4,  // 滴
+    0x9069,  // 適
+    0x6575,  // 敵
+    0x6EBA,  // 溺
+    0x8FED,  // 迭
+    0x54F2,  // 哲
+    0x9244,  // 鉄
+    0x5FB9,  // 徹
+    0x64A4,  // 撤
+    0x5929,  // 天
+    0x5178,  // 典
+    0x5E97,  // 店
+    0x70B9,  // 点
+    0x5C55,  // 展
+    0x6DFB,  // 添
+    0x8EE2,  // 転
+    0x5861,  // 塡
+    0x7530,  // 田
+    0x4F1D,  // 伝
+    0x6BBF,  // 殿
+    0x96FB,  // 電
+    0x6597,  // 斗
+    0x5410,  // 吐
+    0x59AC,  // 妬
+    0x5F92,  // 徒
+    0x9014,  // 途
+    0x90FD,  // 都
+    0x6E21,  // 渡
+    0x5857,  // 塗
+    0x8CED,  // 賭
+    0x571F,  // 土
+    0x5974,  // 奴
+    0x52AA,  // 努
+    0x5EA6,  // 度
+    0x6012,  // 怒
+    0x5200,  // 刀
+    0x51AC,  // 冬
+    0x706F,  // 灯
+    0x5F53,  // 当
+    0x6295,  // 投
+    0x8C46,  // 豆
+    0x6771,  // 東
+    0x5230,  // 到
+    0x9003,  // 逃
+    0x5012,  // 倒
+    0x51CD,  // 凍
+    0x5510,  // 唐
+    0x5CF6,  // 島
+    0x6843,  // 桃
+    0x8A0E,  // 討
+    0x900F,  // 透
+    0x515A,  // 党
+    0x60BC,  // 悼
+    0x76D7,  // 盗
+    0x9676,  // 陶
+    0x5854,  // 塔
+    0x642D,  // 搭
+    0x68DF,  // 棟
+    0x6E6F,  // 湯
+    0x75D8,  // 痘
+    0x767B,  // 登
+    0x7B54,  // 答
+    0x7B49,  // 等
+    0x7B52,  // 筒
+    0x7D71,  // 統
+    0x7A32,  // 稲
+    0x8E0F,  // 踏
+    0x7CD6,  // 糖
+    0x982D,  // 頭
+    0x8B04,  // 謄
+    0x85E4,  // 藤
+    0x95D8,  // 闘
+    0x9A30,  // 騰
+    0x540C,  // 同
+    0x6D1E,  // 洞
+    0x80F4,  // 胴
+    0x52D5,  // 動
+    0x5802,  // 堂
+    0x7AE5,  // 童
+    0x9053,  // 道
+    0x50CD,  // 働
+    0x9285,  // 銅
+    0x5C0E,  // 導
+    0x77B3,  // 瞳
+    0x5CE0,  // 峠
+    0x533F,  // 匿
+    0x7279,  // 特
+    0x5F97,  // 得
+    0x7763,  // 督
+    0x5FB3,  // 徳
+    0x7BE4,  // 篤
+    0x6BD2,  // 毒
+    0x72EC,  // 独
+    0x8AAD,  // 読
+    0x6803,  // 栃
+    0x51F8,  // 凸
+    0x7A81,  // 突
+    0x5C4A,  // 届
+    0x5C6F,  // 屯
+    0x8C5A,  // 豚
+    0x9813,  // 頓
+    0x8CAA,  // 貪
+    0x920D,  // 鈍
+    0x66C7,  // 曇
+    0x4E3C,  // 丼
+    0x90A3,  // 那
+    0x5948,  // 奈
+    0x5185,  // 内
+    0x68A8,  // 梨
+    0x8B0E,  // 謎
+    0x934B,  // 鍋
+    0x5357,  // 南
+    0x8EDF,  // 軟
+    0x96E3,  // 難
+    0x4E8C,  // 二
+    0x5C3C,  // 尼
+    0x5F10,  // 弐
+    0x5302,  // 匂
+    0x8089,  // 肉
+    0x8679,  // 虹
+    0x65E5,  // 日
+    0x5165,  // 入
+    0x4E73,  // 乳
+    0x5C3F,  // 尿
+    0x4EFB,  // 任
+    0x598A,  // 妊
+    0x5FCD,  // 忍
+    0x8A8D,  // 認
+    0x5BE7,  // 寧
+    0x71B1,  // 熱
+    0x5E74,  // 年
+    0x5FF5,  // 念
+    0x637B,  // 捻
+    0x7C98,  // 粘
+    0x71C3,  // 燃
+    0x60A9,  // 悩
+    0x7D0D,  // 納
+    0x80FD,  // 能
+    0x8133,  // 脳
+    0x8FB2,  // 農
+    0x6FC3,  // 濃
+    0x628A,  // 把
+    0x6CE2,  // 波
+    0x6D3E,  // 派
+    0x7834,  // 破
+    0x8987,  // 覇
+    0x99AC,  // 馬
+    0x5A46,  // 婆
+    0x7F75,  // 罵
+    0x62DD,  // 拝
+    0x676F,  // 杯
+    0x80CC,  // 背
+    0x80BA,  // 肺
+    0x4FF3,  // 俳
+    0x914D,  // 配
+    0x6392,  // 排
+    0x6557,  // 敗
+    0x5EC3,  // 廃
+    0x8F29,  // 輩
+    0x58F2,  // 売
+    0x500D,  // 倍
+    0x6885,  // 梅
+    0x57F9,  // 培
+    0x966A,  // 陪
+    0x5A92,  // 媒
+    0x8CB7,  // 買
+    0x8CE0,  // 賠
+    0x767D,  // 白
+    0x4F2F,  // 伯
+    0x62CD,  // 拍
+    0x6CCA,  // 泊
+    0x8FEB,  // 迫
+    0x525D,  // 剝
+    0x8236,  // 舶
+    0x535A,  // 博
+    0x8584,  // 薄
+    0x9EA6,  // 麦
+    0x6F20,  // 漠
+    0x7E1B,  // 縛
+    0x7206,  // 爆
+    0x7BB1,  // 箱
+    0x7BB8,  // 箸
+    0x7551,  // 畑
+    0x808C,  // 肌
+    0x516B,  // 八
+    0x9262,  // 鉢
+    0x767A,  // 発
+    0x9AEA,  // 髪
+    0x4F10,  // 伐
+    0x629C,  // 抜
+    0x7F70,  // 罰
+    0x95A5,  // 閥
+    0x53CD,  // 反
+    0x534A,  // 半
+    0x6C3E,  // 氾
+    0x72AF,  // 犯
+    0x5E06,  // 帆
+    0x6C4E,  // 汎
+    0x4F34,  // 伴
+    0x5224,  // 判
+    0x5742,  // 坂
+    0x962A,  // 阪
+    0x677F,  // 板
+    0x7248,  // 版
+    0x73ED,  // 班
+    0x7554,  // 畔
+    0x822C,  // 般
+    0x8CA9,  // 販
+    0x6591,  // 斑
+    0x98EF,  // 飯
+    0x642C,  // 搬
+    0x7169,  // 煩
+    0x9812,  // 頒
+    0x7BC4,  // 範
+    0x7E41,  // 繁
+    0x85E9,  // 藩
+    0x6669,  // 晩
+    0x756A,  // 番
+    0x86EE,  // 蛮
+    0x76E4,  // 盤
+    0x6BD4,  // 比
+    0x76AE,  // 皮
+    0x5983,  // 妃
+    0x5426,  // 否
+    0x6279,  // 批
+    0x5F7C,  // 彼
+    0x62AB,  // 披
+    0x80A5,  // 肥
+    0x975E,  // 非
+    0x5351,  // 卑
+    0x98DB,  // 飛
+    0x75B2,  // 疲
+    0x79D8,  // 秘
+    0x88AB,  // 被
+    0x60B2,  // 悲
+    0x6249,  // 扉
+    0x8CBB,  // 費
+    0x7891,  // 碑
+    0x7F77,  // 罷
+    0x907F,  // 避
+    0x5C3E,  // 尾
+    0x7709,  // 眉
+    0x7F8E,  // 美
+    0x5099,  // 備
+    0x5FAE,  // 微
+    0x9F3B,  // 鼻
+    0x819D,  // 膝
+    0x8098,  // 肘
+    0x5339,  // 匹
+    0x5FC5,  // 必
+    0x6CCC,  // 泌
+    0x7B46,  // 筆
+    0x59EB,  // 姫
+    0x767E,  // 百
+    0x6C37,  // 氷
+    0x8868,  // 表
+    0x4FF5,  // 俵
+    0x7968,  // 票
+    0x8A55,  // 評
+    0x6F02,  // 漂
+    0x6A19,  // 標
+    0x82D7,  // 苗
+    0x79D2,  // 秒
+    0x75C5,  // 病
+    0x63CF,  // 描
+    0x732B,  // 猫
+    0x54C1,  // 品
+    0x6D5C,  // 浜
+    0x8CA7,  // 貧
+    0x8CD3,  // 賓
+    0x983B,  // 頻
+    0x654F,  // 敏
+    0x74F6,  // 瓶
+    0x4E0D,  // 不
+    0x592B,  // 夫
+    0x7236,  // 父
+    0x4ED8,  // 付
+    0x5E03,  // 布
+    0x6276,  // 扶
+    0x5E9C,  // 府
+    0x6016,  // 怖
+    0x961C,  // 阜
+    0x9644,  // 附
+    0x8A03,  // 訃
+    0x8CA0,  // 負
+    0x8D74,  // 赴
+    0x6D6E,  // 浮
+    0x5A66,  // 婦
+    0x7B26,  // 符
+    0x5BCC,  // 富
+    0x666E,  // 普
+    0x8150,  // 腐
+    0x6577,  // 敷
+    0x819A,  // 膚
+    0x8CE6,  // 賦
+    0x8B5C,  // 譜
+    0x4FAE,  // 侮
+    0x6B66,  // 武
+    0x90E8,  // 部
+    0x821E,  // 舞
+    0x5C01,  // 封
+    0x98A8,  // 風
+    0x4F0F,  // 伏
+    0x670D,  // 服
+    0x526F,  // 副
+    0x5E45,  // 幅
+    0x5FA9,  // 復
+    0x798F,  // 福
+    0x8179,  // 腹
+    0x8907,  // 複
+    0x8986,  // 覆
+    0x6255,  // 払
+    0x6CB8,  // 沸
+    0x4ECF,  // 仏
+    0x7269,  // 物
+    0x7C89,  // 粉
+    0x7D1B,  // 紛
+    0x96F0,  // 雰
+    0x5674,  // 噴
+    0x58B3,  // 墳
+    0x61A4,  // 憤
+    0x596E,  // 奮
+    0x5206,  // 分
+    0x6587,  // 文
+    0x805E,  // 聞
+    0x4E19,  // 丙
+    0x5E73,  // 平
+    0x5175,  // 兵
+    0x4F75,  // 併
+    0x4E26,  // 並
+    0x67C4,  // 柄
+    0x965B,  // 陛
+    0x9589,  // 閉
+    0x5840,  // 塀
+    0x5E63,  // 幣
+    0x5F0A,  // 弊
+    0x853D,  // 蔽
+    0x9905,  // 餅
+    0x7C73,  // 米
+    0x58C1,  // 壁
+    0x74A7,  // 璧
+    0x7656,  // 癖
+    0x5225,  // 別
+    0x8511,  // 蔑
+    0x7247,  // 片
+    0x8FBA,  // 辺
+    0x8FD4,  // 返
+    0x5909,  // 変
+    0x504F,  // 偏
+    0x904D,  // 遍
+    0x7DE8,  // 編
+    0x5F01,  // 弁
+    0x4FBF,  // 便
+    0x52C9,  // 勉
+    0x6B69,  // 歩
+    0x4FDD,  // 保
+    0x54FA,  // 哺
+    0x6355,  // 捕
+    0x88DC,  // 補
+    0x8217,  // 舗
+    0x6BCD,  // 母
+    0x52DF,  // 募
+    0x5893,  // 墓
+    0x6155,  // 慕
+    0x66AE,  // 暮
+    0x7C3F,  // 簿
+    0x65B9,  // 方
+    0x5305,  // 包
+    0x82B3,  // 芳
+    0x90A6,  // 邦
+    0x5949,  // 奉
+    0x5B9D,  // 宝
+    0x62B1,  // 抱
+    0x653E,  // 放
+    0x6CD5,  // 法
+    0x6CE1,  // 泡
+    0x80DE,  // 胞
+    0x4FF8,  // 俸
+    0x5023,  // 倣
+    0x5CF0,  // 峰
+    0x7832,  // 砲
+    0x5D29,  // 崩
+    0x8A2A,  // 訪
+    0x5831,  // 報
+    0x8702,  // 蜂
+    0x8C4A,  // 豊
+    0x98FD,  // 飽
+    0x8912,  // 褒
+    0x7E2B,  // 縫
+    0x4EA1,  // 亡
+    0x4E4F,  // 乏
+    0x5FD9,  // 忙
+    0x574A,  // 坊
+    0x59A8,  // 妨
+    0x5FD8,  // 忘
+    0x9632,  // 防
+    0x623F,  // 房
+    0x80AA,  // 肪
+    0x67D0,  // 某
+    0x5192,  // 冒
+    0x5256,  // 剖
+    0x7D21,  // 紡
+    0x671B,  // 望
+    0x508D,  // 傍
+    0x5E3D,  // 帽
+    0x68D2,  // 棒
+    0x8CBF,  // 貿
+    0x8C8C,  // 貌
+    0x66B4,  // 暴
+    0x81A8,  // 膨
+    0x8B00,  // 謀
+    0x9830,  // 頰
+    0x5317,  // 北
+    0x6728,  // 木
+    0x6734,  // 朴
+    0x7267,  // 牧
+    0x7766,  // 睦
+    0x50D5,  // 僕
+    0x58A8,  // 墨
+    0x64B2,  // 撲
+    0x6CA1,  // 没
+    0x52C3,  // 勃
+    0x5800,  // 堀
+    0x672C,  // 本
+    0x5954,  // 奔
+    0x7FFB,  // 翻
+    0x51E1,  // 凡
+    0x76C6,  // 盆
+    0x9EBB,  // 麻
+    0x6469,  // 摩
+    0x78E8,  // 磨
+    0x9B54,  // 魔
+    0x6BCE,  // 毎
+    0x59B9,  // 妹
+    0x679A,  // 枚
+    0x6627,  // 昧
+    0x57CB,  // 埋
+    0x5E55,  // 幕
+    0x819C,  // 膜
+    0x6795,  // 枕
+    0x53C8,  // 又
+    0x672B,  // 末
+    0x62B9,  // 抹
+    0x4E07,  // 万
+    0x6E80,  // 満
+    0x6162,  // 慢
+    0x6F2B,  // 漫
+    0x672A,  // 未
+    0x5473,  // 味
+    0x9B45,  // 魅
+    0x5CAC,  // 岬
+    0x5BC6,  // 密
+    0x871C,  // 蜜
+    0x8108,  // 脈
+    0x5999,  // 妙
+    0x6C11,  // 民
+    0x7720,  // 眠
+    0x77DB,  // 矛
+    0x52D9,  // 務
+    0x7121,  // 無
+    0x5922,  // 夢
+    0x9727,  // 霧
+    0x5A18,  // 娘
+    0x540D,  // 名
+    0x547D,  // 命
+    0x660E,  // 明
+    0x8FF7,  // 迷
+    0x51A5,  // 冥
+    0x76DF,  // 盟
+    0x9298,  // 銘
+    0x9CF4,  // 鳴
+    0x6EC5,  // 滅
+    0x514D,  // 免
+    0x9762,  // 面
+    0x7DBF,  // 綿
+    0x9EBA,  // 麺
+    0x8302,  // 茂
+    0x6A21,  // 模
+    0x6BDB,  // 毛
+    0x5984,  // 妄
+    0x76F2,  // 盲
+    0x8017,  // 耗
+    0x731B,  // 猛
+    0x7DB2,  // 網
+    0x76EE,  // 目
+    0x9ED9,  // 黙
+    0x9580,  // 門
+    0x7D0B,  // 紋
+    0x554F,  // 問
+    0x51B6,  // 冶
+    0x591C,  // 夜
+    0x91CE,  // 野
+    0x5F25,  // 弥
+    0x5384,  // 厄
+    0x5F79,  // 役
+    0x7D04,  // 約
+    0x8A33,  // 訳
+    0x85AC,  // 薬
+    0x8E8D,  // 躍
+    0x95C7,  // 闇
+    0x7531,  // 由
+    0x6CB9,  // 油
+    0x55A9,  // 喩
+    0x6109,  // 愉
+    0x8AED,  // 諭
+    0x8F38,  // 輸
+    0x7652,  // 癒
+    0x552F,  // 唯
+    0x53CB,  // 友
+    0x6709,  // 有
+    0x52C7,  // 勇
+    0x5E7D,  // 幽
+    0x60A0,  // 悠
+    0x90F5,  // 郵
+    0x6E67,  // 湧
+    0x7336,  // 猶
+    0x88D5,  // 裕
+    0x904A,  // 遊
+    0x96C4,  // 雄
+    0x8A98,  // 誘
+    0x6182,  // 憂
+    0x878D,  // 融
+    0x512A,  // 優
+    0x4E0E,  // 与
+    0x4E88,  // 予
+    0x4F59,  // 余
+    0x8A89,  // 誉
+    0x9810,  // 預
+    0x5E7C,  // 幼
+    0x7528,  // 用
+    0x7F8A,  // 羊
+    0x5996,  // 妖
+    0x6D0B,  // 洋
+    0x8981,  // 要
+    0x5BB9,  // 容
+    0x5EB8,  // 庸
+    0x63DA,  // 揚
+    0x63FA,  // 揺
+    0x8449,  // 葉
+    0x967D,  // 陽
+    0x6EB6,  // 溶
+    0x8170,  // 腰
+    0x69D8,  // 様
+    0x760D,  // 瘍
+    0x8E0A,  // 踊
+    0x7AAF,  // 窯
+    0x990A,  // 養
+    0x64C1,  // 擁
+    0x8B21,  // 謡
+    0x66DC,  // 曜
+    0x6291,  // 抑
+    0x6C83,  // 沃
+    0x6D74,  // 浴
+    0x6B32,  // 欲
+    0x7FCC,  // 翌
+    0x7FFC,  // 翼
+    0x62C9,  // 拉
+    0x88F8,  // 裸
+    0x7F85,  // 羅
+    0x6765,  // 来
+    0x96F7,  // 雷
+    0x983C,  // 頼
+    0x7D61,  // 絡
+    0x843D,  // 落
+    0x916A,  // 酪
+    0x8FA3,  // 辣
+    0x4E71,  // 乱
+    0x5375,  // 卵
+    0x89A7,  // 覧
+    0x6FEB,  // 濫
+    0x85CD,  // 藍
+    0x6B04,  // 欄
+    0x540F,  // 吏
+    0x5229,  // 利
+    0x91CC,  // 里
+    0x7406,  // 理
+    0x75E2,  // 痢
+    0x88CF,  // 裏
+    0x5C65,  // 履
+    0x7483,  // 璃
+    0x96E2,  // 離
+    0x9678,  // 陸
+    0x7ACB,  // 立
+    0x5F8B,  // 律
+    0x6144,  // 慄
+    0x7565,  // 略
+    0x67F3,  // 柳
+    0x6D41,  // 流
+    0x7559,  // 留
+    0x7ADC,  // 竜
+    0x7C92,  // 粒
+    0x9686,  // 隆
+    0x786B,  // 硫
+    0x4FB6,  // 侶
+    0x65C5,  // 旅
+    0x865C,  // 虜
+    0x616E,  // 慮
+    0x4E86,  // 了
+    0x4E21,  // 両
+    0x826F,  // 良
+    0x6599,  // 料
+    0x6DBC,  // 涼
+    0x731F,  // 猟
+    0x9675,  // 陵
+    0x91CF,  // 量
+    0x50DA,  // 僚
+    0x9818,  // 領
+    0x5BEE,  // 寮
+    0x7642,  // 療
+    0x77AD,  // 瞭
+    0x7CE7,  // 糧
+    0x529B,  // 力
+    0x7DD1,  // 緑
+    0x6797,  // 林
+    0x5398,  // 厘
+    0x502B,  // 倫
+    0x8F2A,  // 輪
+    0x96A3,  // 隣
+    0x81E8,  // 臨
+    0x7460,  // 瑠
+    0x6D99,  // 涙
+    0x7D2F,  // 累
+    0x5841,  // 塁
+    0x985E,  // 類
+    0x4EE4,  // 令
+    0x793C,  // 礼
+    0x51B7,  // 冷
+    0x52B1,  // 励
+    0x623B,  // 戻
+    0x4F8B,  // 例
+    0x9234,  // 鈴
+    0x96F6,  // 零
+    0x970A,  // 霊
+    0x96B7,  // 隷
+    0x9F62,  // 齢
+    0x9E97,  // 麗
+    0x66A6,  // 暦
+    0x6B74,  // 歴
+    0x5217,  // 列
+    0x52A3,  // 劣
+    0x70C8,  // 烈
+    0x88C2,  // 裂
+    0x604B,  // 恋
+    0x9023,  // 連
+    0x5EC9,  // 廉
+    0x7DF4,  // 練
+    0x932C,  // 錬
+    0x5442,  // 呂
+    0x7089,  // 炉
+    0x8CC2,  // 賂
+    0x8DEF,  // 路
+    0x9732,  // 露
+    0x8001,  // 老
+    0x52B4,  // 労
+    0x5F04,  // 弄
+    0x90CE,  // 郎
+    0x6717,  // 朗
+    0x6D6A,  // 浪
+    0x5ECA,  // 廊
+    0x697C,  // 楼
+    0x6F0F,  // 漏
+    0x7C60,  // 籠
+    0x516D,  // 六
+    0x9332,  // 録
+    0x9E93,  // 麓
+    0x8AD6,  // 論
+    0x548C,  // 和
+    0x8A71,  // 話
+    0x8CC4,  // 賄
+    0x8107,  // 脇
+    0x60D1,  // 惑
+    0x67A0,  // 枠
+    0x6E7E,  // 湾
+    0x8155,  // 腕
+];
+
+/// Number of entries in [`JOYO_KANJI`].
+pub const JOYO_KANJI_COUNT: usize = 2136;
+const _: () = assert!(JOYO_KANJI.len() == JOYO_KANJI_COUNT);
+
+/// The 人名用漢字 (jinmeiyō kanji): kanji allowed in given names besides
+/// the [`JOYO_KANJI`]
+///
+/// Source: 戸籍法施行規則 第60条 別表第二, as amended through 平成29年
+/// (the addition of `渾`).  Part 1 (651 characters, by radical) comes
+/// first, then part 2 (212 traditional forms of jōyō kanji, in jōyō
+/// order).  Where the regulation shows a form that Unicode only encodes as
+/// a CJK compatibility ideograph (e.g. `渚` beside `渚`), the compatibility
+/// code point is listed, as JIS X 0213 maps it.
+pub const JINMEIYO_KANJI: &[u32] = &[
+    0x4E11, // 丑
+    0x4E1E, // 丞
+    0x4E43, // 乃
+    0x4E4B, // 之
+    0x4E4E, // 乎
+    0x4E5F, // 也
+    0x4E91, // 云
+    0x4E98, // 亘
+    0x4E99, // 亙
+    0x4E9B, // 些
+    0x4EA6, // 亦
+    0x4EA5, // 亥
+    0x4EA8, // 亨
+    0x4EAE, // 亮
+    0x4ED4, // 仔
+    0x4F0A, // 伊
+    0x4F0D, // 伍
+    0x4F3D, // 伽
+    0x4F43, // 佃
+    0x4F51, // 佑
+    0x4F36, // 伶
+    0x4F83, // 侃
+    0x4F91, // 侑
+    0x4FC4, // 俄
+    0x4FE0, // 俠
+    0x4FE3, // 俣
+    0x4FD0, // 俐
+    0x502D, // 倭
+    0x4FF1, // 俱
+    0x5026, // 倦
+    0x5016, // 倖
+    0x5072, // 偲
+    0x50AD, // 傭
+    0x5132, // 儲
+    0x5141, // 允
+    0x514E, // 兎
+    0x515C, // 兜
+    0x5176, // 其
+    0x51B4, // 冴
+    0x51CC, // 凌
+    0x51DC, // 凜
+    0x51DB, // 凛
+    0x51E7, // 凧
+    0x51EA, // 凪
+    0x51F0, // 凰
+    0x51F1, // 凱
+    0x51FD, // 函
+    0x5289, // 劉
+    0x52AB, // 劫
+    0x52C1, // 勁
+    0x52FA, // 勺
+    0x52FF, // 勿
+    0x5301, // 匁
+    0x5321, // 匡
+    0x5EFF, // 廿
+    0x535C, // 卜
+    0x536F, // 卯
+    0x537F, // 卿
+    0x53A8, // 厨
+    0x53A9, // 厩
+    0x53C9, // 叉
+    0x53E1, // 叡
+    0x53E2, // 叢
+    0x53F6, // 叶
+    0x53EA, // 只
+    0x543E, // 吾
+    0x541E, // 吞
+    0x543B, // 吻
+    0x54C9, // 哉
+    0x54E8, // 哨
+    0x5544, // 啄
+    0x54E9, // 哩
+    0x55AC, // 喬
+    0x55A7, // 喧
+    0x55B0, // 喰
+    0x558B, // 喋
+    0x5629, // 嘩
+    0x5609, // 嘉
+    0x5617, // 嘗
+    0x564C, // 噌
+    0x5642, // 噂
+    0x5703, // 圃
+    0x572D, // 圭
+    0x5750, // 坐
+    0x5C2D, // 尭
+    0x582F, // 堯
+    0x5766, // 坦
+    0x57F4, // 埴
+    0x5830, // 堰
+    0x583A, // 堺
+    0x5835, // 堵
+    0x5859, // 塙
+    0x58D5, // 壕
+    0x58EC, // 壬
+    0x5937, // 夷
+    0x5944, // 奄
+    0x594E, // 奎
+    0x5957, // 套
+    0x5A03, // 娃
+    0x59EA, // 姪
+    0x59E5, // 姥
+    0x5A29, // 娩
+    0x5B09, // 嬉
+    0x5B5F, // 孟
+    0x5B8F, // 宏
+    0x5B8B, // 宋
+    0x5B95, // 宕
+    0x5BA5, // 宥
+    0x5BC5, // 寅
+    0x5BD3, // 寓
+    0x5BF5, // 寵
+    0x5C16, // 尖
+    0x5C24, // 尤
+    0x5C51, // 屑
+    0x5CE8, // 峨
+    0x5CFB, // 峻
+    0x5D1A, // 崚
+    0x5D6F, // 嵯
+    0x5D69, // 嵩
+    0x5DBA, // 嶺
+    0x5DCC, // 巌
+    0x5DD6, // 巖
+    0x5DEB, // 巫
+    0x5DF2, // 已
+    0x5DF3, // 巳
+    0x5DF4, // 巴
+    0x5DF7, // 巷
+    0x5DFD, // 巽
+    0x5E16, // 帖
+    0x5E4C, // 幌
+    0x5E61, // 幡
+    0x5E84, // 庄
+    0x5E87, // 庇
+    0x5E9A, // 庚
+    0x5EB5, // 庵
+    0x5EDF, // 廟
+    0x5EFB, // 廻
+    0x5F18, // 弘
+    0x5F1B, // 弛
+    0x5F57, // 彗
+    0x5F66, // 彦
+    0x5F6A, // 彪
+    0x5F6C, // 彬
+    0x5FA0, // 徠
+    0x5FFD, // 忽
+    0x601C, // 怜
+    0x6062, // 恢
+    0x6070, // 恰
+    0x6055, // 恕
+    0x608C, // 悌
+    0x60DF, // 惟
+    0x60DA, // 惚
+    0x6089, // 悉
+    0x60C7, // 惇
+    0x60F9, // 惹
+    0x60FA, // 惺
+    0x60E3, // 惣
+    0x6167, // 慧
+    0x6190, // 憐
+    0x620A, // 戊
+    0x6216, // 或
+    0x621F, // 戟
+    0x6258, // 托
+    0x6309, // 按
+    0x633A, // 挺
+    0x633D, // 挽
+    0x63AC, // 掬
+    0x6372, // 捲
+    0x6377, // 捷
+    0x637A, // 捺
+    0x6367, // 捧
+    0x63A0, // 掠
+    0x63C3, // 揃
+    0x6451, // 摑
+    0x647A, // 摺
+    0x6492, // 撒
+    0x64B0, // 撰
+    0x649E, // 撞
+    0x64AD, // 播
+    0x64AB, // 撫
+    0x64E2, // 擢
+    0x5B5C, // 孜
+    0x6566, // 敦
+    0x6590, // 斐
+    0x65A1, // 斡
+    0x65A7, // 斧
+    0x65AF, // 斯
+    0x65BC, // 於
+    0x65ED, // 旭
+    0x6602, // 昂
+    0x660A, // 昊
+    0x660F, // 昏
+    0x660C, // 昌
+    0x6634, // 昴
+    0x664F, // 晏
+    0x6643, // 晃
+    0x6644, // 晄
+    0x6652, // 晒
+    0x664B, // 晋
+    0x665F, // 晟
+    0x6666, // 晦
+    0x6668, // 晨
+    0x667A, // 智
+    0x6689, // 暉
+    0x66A2, // 暢
+    0x66D9, // 曙
+    0x66DD, // 曝
+    0x66F3, // 曳
+    0x670B, // 朋
+    0x6714, // 朔
+    0x674F, // 杏
+    0x6756, // 杖
+    0x675C, // 杜
+    0x674E, // 李
+    0x676D, // 杭
+    0x6775, // 杵
+    0x6777, // 杷
+    0x6787, // 枇
+    0x67D1, // 柑
+    0x67F4, // 柴
+    0x67D8, // 柘
+    0x67CA, // 柊
+    0x67CF, // 柏
+    0x67FE, // 柾
+    0x67DA, // 柚
+    0x6867, // 桧
+    0x6A9C, // 檜
+    0x681E, // 栞
+    0x6854, // 桔
+    0x6842, // 桂
+    0x6816, // 栖
+    0x6850, // 桐
+    0x6817, // 栗
+    0x68A7, // 梧
+    0x6893, // 梓
+    0x68A2, // 梢
+    0x689B, // 梛
+    0x68AF, // 梯
+    0x6876, // 桶
+    0x68B6, // 梶
+    0x691B, // 椛
+    0x6881, // 梁
+    0x68F2, // 棲
+    0x690B, // 椋
+    0x6900, // 椀
+    0x696F, // 楯
+    0x695A, // 楚
+    0x6955, // 楕
+    0x693F, // 椿
+    0x6960, // 楠
+    0x6953, // 楓
+    0x6930, // 椰
+    0x6962, // 楢
+    0x694A, // 楊
+    0x698E, // 榎
+    0x6A3A, // 樺
+    0x698A, // 榊
+    0x699B, // 榛
+    0x69D9, // 槙
+    0x69C7, // 槇
+    0x69CD, // 槍
+    0x69CC, // 槌
+    0x6A2B, // 樫
+    0x69FB, // 槻
+    0x6A1F, // 樟
+    0x6A0B, // 樋
+    0x6A58, // 橘
+    0x6A3D, // 樽
+    0x6A59, // 橙
+    0x6A8E, // 檎
+    0x6A80, // 檀
+    0x6AC2, // 櫂
+    0x6ADB, // 櫛
+    0x6AD3, // 櫓
+    0x6B23, // 欣
+    0x6B3D, // 欽
+    0x6B4E, // 歎
+    0x6B64, // 此
+    0x6B86, // 殆
+    0x6BC5, // 毅
+    0x6BD8, // 毘
+    0x6BEC, // 毬
+    0x6C40, // 汀
+    0x6C5D, // 汝
+    0x6C50, // 汐
+    0x6C72, // 汲
+    0x6C8C, // 沌
+    0x6C93, // 沓
+    0x6CAB, // 沫
+    0x6D38, // 洸
+    0x6D32, // 洲
+    0x6D35, // 洵
+    0x6D1B, // 洛
+    0x6D69, // 浩
+    0x6D6C, // 浬
+    0x6DF5, // 淵
+    0x6DF3, // 淳
+    0x6E1A, // 渚
+    0xFA46, // 渚 (compatibility ideograph)
+    0x6DC0, // 淀
+    0x6DCB, // 淋
+    0x6E25, // 渥
+    0x6E3E, // 渾
+    0x6E58, // 湘
+    0x6E4A, // 湊
+    0x6E5B, // 湛
+    0x6EA2, // 溢
+    0x6EC9, // 滉
+    0x6E9C, // 溜
+    0x6F31, // 漱
+    0x6F15, // 漕
+    0x6F23, // 漣
+    0x6FAA, // 澪
+    0x6FE1, // 濡
+    0x7015, // 瀕
+    0x7058, // 灘
+    0x7078, // 灸
+    0x707C, // 灼
+    0x70CF, // 烏
+    0x7130, // 焰
+    0x711A, // 焚
+    0x714C, // 煌
+    0x7164, // 煤
+    0x7149, // 煉
+    0x7199, // 熙
+    0x71D5, // 燕
+    0x71CE, // 燎
+    0x71E6, // 燦
+    0x71ED, // 燭
+    0x71FF, // 燿
+    0x723E, // 爾
+    0x7252, // 牒
+    0x725F, // 牟
+    0x7261, // 牡
+    0x727D, // 牽
+    0x7280, // 犀
+    0x72FC, // 狼
+    0x732A, // 猪
+    0xFA16, // 猪 (compatibility ideograph)
+    0x7345, // 獅
+    0x7396, // 玖
+    0x73C2, // 珂
+    0x73C8, // 珈
+    0x73CA, // 珊
+    0x73C0, // 珀
+    0x73B2, // 玲
+    0x7422, // 琢
+    0xFA4A, // 琢 (compatibility ideograph)
+    0x7409, // 琉
+    0x745B, // 瑛
+    0x7425, // 琥
+    0x7436, // 琶
+    0x7435, // 琵
+    0x7433, // 琳
+    0x745A, // 瑚
+    0x745E, // 瑞
+    0x7476, // 瑶
+    0x7473, // 瑳
+    0x74DC, // 瓜
+    0x74E2, // 瓢
+    0x7525, // 甥
+    0x752B, // 甫
+    0x7560, // 畠
+    0x7562, // 畢
+    0x758B, // 疋
+    0x758F, // 疏
+    0x7690, // 皐
+    0x7693, // 皓
+    0x7738, // 眸
+    0x77A5, // 瞥
+    0x77E9, // 矩
+    0x7826, // 砦
+    0x7825, // 砥
+    0x7827, // 砧
+    0x786F, // 硯
+    0x7893, // 碓
+    0x7897, // 碗
+    0x78A9, // 碩
+    0x78A7, // 碧
+    0x78D0, // 磐
+    0x78EF, // 磯
+    0x7947, // 祇
+    0x7962, // 祢
+    0x79B0, // 禰
+    0x7950, // 祐
+    0xFA4F, // 祐 (compatibility ideograph)
+    0x7977, // 祷
+    0x79B1, // 禱
+    0x7984, // 禄
+    0x797F, // 祿
+    0x798E, // 禎
+    0xFA53, // 禎 (compatibility ideograph)
+    0x79BD, // 禽
+    0x79BE, // 禾
+    0x79E6, // 秦
+    0x79E4, // 秤
+    0x7A00, // 稀
+    0x7A14, // 稔
+    0x7A1F, // 稟
+    0x7A1C, // 稜
+    0x7A63, // 穣
+    0x7A70, // 穰
+    0x7A79, // 穹
+    0x7A7F, // 穿
+    0x7A84, // 窄
+    0x7AAA, // 窪
+    0x7ABA, // 窺
+    0x7AE3, // 竣
+    0x7AEA, // 竪
+    0x7AFA, // 竺
+    0x7AFF, // 竿
+    0x7B08, // 笈
+    0x7B39, // 笹
+    0x7B19, // 笙
+    0x7B20, // 笠
+    0x7B48, // 筈
+    0x7B51, // 筑
+    0x7B95, // 箕
+    0x7B94, // 箔
+    0x7BC7, // 篇
+    0x7BE0, // 篠
+    0x7C1E, // 簞
+    0x7C3E, // 簾
+    0x7C7E, // 籾
+    0x7CA5, // 粥
+    0x7C9F, // 粟
+    0x7CCA, // 糊
+    0x7D18, // 紘
+    0x7D17, // 紗
+    0x7D10, // 紐
+    0x7D43, // 絃
+    0x7D2C, // 紬
+    0x7D46, // 絆
+    0x7D62, // 絢
+    0x7DBA, // 綺
+    0x7D9C, // 綜
+    0x7DB4, // 綴
+    0x7DCB, // 緋
+    0x7DBE, // 綾
+    0x7DB8, // 綸
+    0x7E1E, // 縞
+    0x5FBD, // 徽
+    0x7E6B, // 繫
+    0x7E61, // 繡
+    0x7E82, // 纂
+    0x7E8F, // 纏
+    0x7F9A, // 羚
+    0x7FD4, // 翔
+    0x7FE0, // 翠
+    0x8000, // 耀
+    0x800C, // 而
+    0x8036, // 耶
+    0x803D, // 耽
+    0x8061, // 聡
+    0x8087, // 肇
+    0x808B, // 肋
+    0x80B4, // 肴
+    0x80E4, // 胤
+    0x80E1, // 胡
+    0x8129, // 脩
+    0x8139, // 脹
+    0x8154, // 腔
+    0x818F, // 膏
+    0x81E5, // 臥
+    0x821C, // 舜
+    0x8235, // 舵
+    0x82A5, // 芥
+    0x82B9, // 芹
+    0x82AD, // 芭
+    0x8299, // 芙
+    0x82A6, // 芦
+    0x82D1, // 苑
+    0x8304, // 茄
+    0x82D4, // 苔
+    0x82FA, // 苺
+    0x8305, // 茅
+    0x8309, // 茉
+    0x8338, // 茸
+    0x831C, // 茜
+    0x839E, // 莞
+    0x837B, // 荻
+    0x83AB, // 莫
+    0x8389, // 莉
+    0x83C5, // 菅
+    0x83EB, // 菫
+    0x83D6, // 菖
+    0x8404, // 萄
+    0x83E9, // 菩
+    0x840C, // 萌
+    0x8420, // 萠
+    0x840A, // 萊
+    0x83F1, // 菱
+    0x8466, // 葦
+    0x8475, // 葵
+    0x8431, // 萱
+    0x847A, // 葺
+    0x8429, // 萩
+    0x8463, // 董
+    0x8461, // 葡
+    0x84D1, // 蓑
+    0x8494, // 蒔
+    0x8490, // 蒐
+    0x84BC, // 蒼
+    0x84B2, // 蒲
+    0x8499, // 蒙
+    0x84C9, // 蓉
+    0x84EE, // 蓮
+    0x852D, // 蔭
+    0x8523, // 蔣
+    0x8526, // 蔦
+    0x84EC, // 蓬
+    0x8513, // 蔓
+    0x854E, // 蕎
+    0x8568, // 蕨
+    0x8549, // 蕉
+    0x8543, // 蕃
+    0x856A, // 蕪
+    0x8599, // 薙
+    0x857E, // 蕾
+    0x8557, // 蕗
+    0x85C1, // 藁
+    0x85A9, // 薩
+    0x8607, // 蘇
+    0x862D, // 蘭
+    0x8766, // 蝦
+    0x8776, // 蝶
+    0x87BA, // 螺
+    0x87EC, // 蟬
+    0x87F9, // 蟹
+    0x881F, // 蠟
+    0x887F, // 衿
+    0x8888, // 袈
+    0x88B4, // 袴
+    0x88E1, // 裡
+    0x88DF, // 裟
+    0x88F3, // 裳
+    0x8956, // 襖
+    0x8A0A, // 訊
+    0x8A23, // 訣
+    0x8A3B, // 註
+    0x8A62, // 詢
+    0x8A6B, // 詫
+    0x8ABC, // 誼
+    0x8ACF, // 諏
+    0x8AC4, // 諄
+    0x8AD2, // 諒
+    0x8B02, // 謂
+    0x8AFA, // 諺
+    0x8B83, // 讃
+    0x8C79, // 豹
+    0x8CB0, // 貰
+    0x8CD1, // 賑
+    0x8D73, // 赳
+    0x8DE8, // 跨
+    0x8E44, // 蹄
+    0x8E5F, // 蹟
+    0x8F14, // 輔
+    0x8F2F, // 輯
+    0x8F3F, // 輿
+    0x8F5F, // 轟
+    0x8FB0, // 辰
+    0x8FBB, // 辻
+    0x8FC2, // 迂
+    0x8FC4, // 迄
+    0x8FBF, // 辿
+    0x8FEA, // 迪
+    0x8FE6, // 迦
+    0x9019, // 這
+    0x901E, // 逞
+    0x9017, // 逗
+    0x9022, // 逢
+    0x9065, // 遥
+    0x9059, // 遙
+    0x9041, // 遁
+    0x907C, // 遼
+    0x9091, // 邑
+    0x7941, // 祁
+    0x90C1, // 郁
+    0x912D, // 鄭
+    0x9149, // 酉
+    0x9187, // 醇
+    0x9190, // 醐
+    0x918D, // 醍
+    0x91AC, // 醬
+    0x91C9, // 釉
+    0x91D8, // 釘
+    0x91E7, // 釧
+    0x9291, // 銑
+    0x92D2, // 鋒
+    0x92F8, // 鋸
+    0x9318, // 錘
+    0x9310, // 錐
+    0x9306, // 錆
+    0x932B, // 錫
+    0x936C, // 鍬
+    0x93A7, // 鎧
+    0x9583, // 閃
+    0x958F, // 閏
+    0x95A4, // 閤
+    0x963F, // 阿
+    0x9640, // 陀
+    0x9688, // 隈
+    0x96BC, // 隼
+    0x96C0, // 雀
+    0x96C1, // 雁
+    0x96DB, // 雛
+    0x96EB, // 雫
+    0x971E, // 霞
+    0x9756, // 靖
+    0x9784, // 鞄
+    0x978D, // 鞍
+    0x9798, // 鞘
+    0x97A0, // 鞠
+    0x97AD, // 鞭
+    0x9801, // 頁
+    0x980C, // 頌
+    0x9817, // 頗
+    0x9837, // 頷
+    0x98AF, // 颯
+    0x9957, // 饗
+    0x99A8, // 馨
+    0x99B4, // 馴
+    0x99B3, // 馳
+    0x99D5, // 駕
+    0x99FF, // 駿
+    0x9A4D, // 驍
+    0x9B41, // 魁
+    0x9B6F, // 魯
+    0x9B8E, // 鮎
+    0x9BC9, // 鯉
+    0x9BDB, // 鯛
+    0x9C2F, // 鰯
+    0x9C52, // 鱒
+    0x9C57, // 鱗
+    0x9CE9, // 鳩
+    0x9CF6, // 鳶
+    0x9CF3, // 鳳
+    0x9D28, // 鴨
+    0x9D3B, // 鴻
+    0x9D5C, // 鵜
+    0x9D6C, // 鵬
+    0x9DD7, // 鷗
+    0x9DF2, // 鷲
+    0x9DFA, // 鷺
+    0x9DF9, // 鷹
+    0x9E92, // 麒
+    0x9E9F, // 麟
+    0x9EBF, // 麿
+    0x9ECE, // 黎
+    0x9EDB, // 黛
+    0x9F0E, // 鼎
+    // 別表第二 二: traditional forms of jōyō kanji
+    0x4E9E, // 亞
+    0x60E1, // 惡
+    0x7232, // 爲
+    0xFA67, // 逸 (compatibility ideograph)
+    0x69AE, // 榮
+    0x885E, // 衞
+    0xFA62, // 謁 (compatibility ideograph)
+    0x5713, // 圓
+    0x7DE3, // 緣
+    0x8597, // 薗
+    0x61C9, // 應
+    0x6AFB, // 櫻
+    0x5967, // 奧
+    0x6A6B, // 橫
+    0x6EAB, // 溫
+    0x50F9, // 價
+    0xFA52, // 禍 (compatibility ideograph)
+    0xFA3D, // 悔 (compatibility ideograph)
+    0xFA45, // 海 (compatibility ideograph)
+    0x58DE, // 壞
+    0x61F7, // 懷
+    0x6A02, // 樂
+    0x6E34, // 渴
+    0x5377, // 卷
+    0x9677, // 陷
+    0x5BEC, // 寬
+    0xFA47, // 漢 (compatibility ideograph)
+    0x6C23, // 氣
+    0xFA4E, // 祈 (compatibility ideograph)
+    0xFA38, // 器 (compatibility ideograph)
+    0x50DE, // 僞
+    0x6232, // 戲
+    0x865B, // 虛
+    0x5CFD, // 峽
+    0x72F9, // 狹
+    0xFA69, // 響 (compatibility ideograph)
+    0x66C9, // 曉
+    0xFA34, // 勤 (compatibility ideograph)
+    0xFA63, // 謹 (compatibility ideograph)
+    0x99C8, // 駈
+    0x52F3, // 勳
+    0x85B0, // 薰
+    0x60E0, // 惠
+    0x63ED, // 揭
+    0x9DC4, // 鷄
+    0x85DD, // 藝
+    0x64CA, // 擊
+    0x7E23, // 縣
+    0x5109, // 儉
+    0x528D, // 劍
+    0x96AA, // 險
+    0x5708, // 圈
+    0x6AA2, // 檢
+    0x986F, // 顯
+    0x9A57, // 驗
+    0x56B4, // 嚴
+    0x5EE3, // 廣
+    0x6046, // 恆
+    0x9EC3, // 黃
+    0x570B, // 國
+    0x9ED1, // 黑
+    0xFA54, // 穀 (compatibility ideograph)
+    0x788E, // 碎
+    0x96DC, // 雜
+    0xFA4D, // 祉 (compatibility ideograph)
+    0xFA61, // 視 (compatibility ideograph)
+    0x5152, // 兒
+    0x6FD5, // 濕
+    0x5BE6, // 實
+    0xFA4C, // 社 (compatibility ideograph)
+    0xFA5B, // 者 (compatibility ideograph)
+    0xFA48, // 煮 (compatibility ideograph)
+    0x58FD, // 壽
+    0x6536, // 收
+    0xFA5C, // 臭 (compatibility ideograph)
+    0x5F9E, // 從
+    0x6F81, // 澁
+    0x7378, // 獸
+    0x7E31, // 縱
+    0xFA51, // 祝 (compatibility ideograph)
+    0xFA43, // 暑 (compatibility ideograph)
+    0xFA5A, // 署 (compatibility ideograph)
+    0x7DD6, // 緖
+    0xFA22, // 諸 (compatibility ideograph)
+    0x654D, // 敍
+    0x5C07, // 將
+    0xFA1A, // 祥 (compatibility ideograph)
+    0x6D89, // 涉
+    0x71D2, // 燒
+    0x596C, // 奬
+    0x689D, // 條
+    0x72C0, // 狀
+    0x4E58, // 乘
+    0x6DE8, // 淨
+    0x5269, // 剩
+    0x758A, // 疊
+    0x5B43, // 孃
+    0x8B93, // 讓
+    0x91C0, // 釀
+    0xFA19, // 神 (compatibility ideograph)
+    0x771E, // 眞
+    0x5BE2, // 寢
+    0x613C, // 愼
+    0x76E1, // 盡
+    0x7CB9, // 粹
+    0x9189, // 醉
+    0x7A57, // 穗
+    0x7028, // 瀨
+    0x9F4A, // 齊
+    0x975C, // 靜
+    0x651D, // 攝
+    0xFA56, // 節 (compatibility ideograph)
+    0x5C08, // 專
+    0x6230, // 戰
+    0x7E96, // 纖
+    0x79AA, // 禪
+    0xFA50, // 祖 (compatibility ideograph)
+    0x58EF, // 壯
+    0x722D, // 爭
+    0x838A, // 莊
+    0x641C, // 搜
+    0x5DE2, // 巢
+    0x66FE, // 曾
+    0x88DD, // 裝
+    0xFA31, // 僧 (compatibility ideograph)
+    0xFA3B, // 層 (compatibility ideograph)
+    0x7626, // 瘦
+    0x9A37, // 騷
+    0x589E, // 增
+    0xFA3F, // 憎 (compatibility ideograph)
+    0x85CF, // 藏
+    0xFA65, // 贈 (compatibility ideograph)
+    0x81DF, // 臟
+    0x537D, // 卽
+    0x5E36, // 帶
+    0x6EEF, // 滯
+    0x7027, // 瀧
+    0x55AE, // 單
+    0xFA37, // 嘆 (compatibility ideograph)
+    0x5718, // 團
+    0x5F48, // 彈
+    0x665D, // 晝
+    0x9444, // 鑄
+    0xFA5F, // 著 (compatibility ideograph)
+    0x5EF3, // 廳
+    0x5FB5, // 徵
+    0x807D, // 聽
+    0xFA40, // 懲 (compatibility ideograph)
+    0x93AD, // 鎭
+    0x8F49, // 轉
+    0x50B3, // 傳
+    0xFA26, // 都 (compatibility ideograph)
+    0x5D8B, // 嶋
+    0x71C8, // 燈
+    0x76DC, // 盜
+    0x7A3B, // 稻
+    0x5FB7, // 德
+    0xFA55, // 突 (compatibility ideograph)
+    0xFA68, // 難 (compatibility ideograph)
+    0x62DC, // 拜
+    0x76C3, // 盃
+    0x8CE3, // 賣
+    0xFA44, // 梅 (compatibility ideograph)
+    0x9AEE, // 髮
+    0x62D4, // 拔
+    0xFA59, // 繁 (compatibility ideograph)
+    0x665A, // 晚
+    0xFA35, // 卑 (compatibility ideograph)
+    0x7955, // 祕
+    0xFA4B, // 碑 (compatibility ideograph)
+    0xFA64, // 賓 (compatibility ideograph)
+    0xFA41, // 敏 (compatibility ideograph)
+    0x51A8, // 冨
+    0xFA30, // 侮 (compatibility ideograph)
+    0xFA1B, // 福 (compatibility ideograph)
+    0x62C2, // 拂
+    0x4F5B, // 佛
+    0xFA33, // 勉 (compatibility ideograph)
+    0x6B65, // 步
+    0x5CEF, // 峯
+    0xFA3A, // 墨 (compatibility ideograph)
+    0x98DC, // 飜
+    0x6BCF, // 每
+    0x842C, // 萬
+    0x9ED8, // 默
+    0x57DC, // 埜
+    0x5F4C, // 彌
+    0x85E5, // 藥
+    0x8207, // 與
+    0x6416, // 搖
+    0x6A23, // 樣
+    0x8B20, // 謠
+    0x4F86, // 來
+    0x8CF4, // 賴
+    0x89BD, // 覽
+    0xF91D, // 欄 (compatibility ideograph)
+    0x9F8D, // 龍
+    0xF936, // 虜 (compatibility ideograph)
+    0x51C9, // 凉
+    0x7DA0, // 綠
+    0x6DDA, // 淚
+    0x58D8, // 壘
+    0xF9D0, // 類 (compatibility ideograph)
+    0x79AE, // 禮
+    0x66C6, // 曆
+    0x6B77, // 歷
+    0xFA57, // 練 (compatibility ideograph)
+    0x934A, // 鍊
+    0x90DE, // 郞
+    0xF929, // 朗 (compatibility ideograph)
+    0xF928, // 廊 (compatibility ideograph)
+    0x9304, // 錄
+];
+
+/// Number of entries in [`JINMEIYO_KANJI`].
+pub const JINMEIYO_KANJI_COUNT: usize = 863;
+const _: () = assert!(JINMEIYO_KANJI.len() == JINMEIYO_KANJI_COUNT);
+
+/// Number of entries of part 1 of [`JINMEIYO_KANJI`]; the rest are part 2.
+pub const JINMEIYO_KANJI_PART1_COUNT: usize = 651;
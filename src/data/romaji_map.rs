@@ -0,0 +1,153 @@
+//! Kana to romaji (Hepburn) transliteration table
+//!
+//! This module contains the mapping used by
+//! `conversion::transliterate_to_ascii`.  Only hiragana are listed; katakana
+//! U+30A1–U+30F6 map onto hiragana by subtracting [`KATAKANA_OFFSET`].
+
+/// Distance between a katakana code point and its hiragana counterpart
+pub const KATAKANA_OFFSET: u32 = 0x60;
+
+/// Single hiragana to Hepburn romaji, sorted by code point
+pub const ROMAJI_MAP: &[(u32, &str)] = &[
+    (0x3041, "a"),   // ぁ
+    (0x3042, "a"),   // あ
+    (0x3043, "i"),   // ぃ
+    (0x3044, "i"),   // い
+    (0x3045, "u"),   // ぅ
+    (0x3046, "u"),   // う
+    (0x3047, "e"),   // ぇ
+    (0x3048, "e"),   // え
+    (0x3049, "o"),   // ぉ
+    (0x304A, "o"),   // お
+    (0x304B, "ka"),  // か
+    (0x304C, "ga"),  // が
+    (0x304D, "ki"),  // き
+    (0x304E, "gi"),  // ぎ
+    (0x304F, "ku"),  // く
+    (0x3050, "gu"),  // ぐ
+    (0x3051, "ke"),  // け
+    (0x3052, "ge"),  // げ
+    (0x3053, "ko"),  // こ
+    (0x3054, "go"),  // ご
+    (0x3055, "sa"),  // さ
+    (0x3056, "za"),  // ざ
+    (0x3057, "shi"), // し
+    (0x3058, "ji"),  // じ
+    (0x3059, "su"),  // す
+    (0x305A, "zu"),  // ず
+    (0x305B, "se"),  // せ
+    (0x305C, "ze"),  // ぜ
+    (0x305D, "so"),  // そ
+    (0x305E, "zo"),  // ぞ
+    (0x305F, "ta"),  // た
+    (0x3060, "da"),  // だ
+    (0x3061, "chi"), // ち
+    (0x3062, "ji"),  // ぢ
+    (0x3064, "tsu"), // つ
+    (0x3065, "zu"),  // づ
+    (0x3066, "te"),  // て
+    (0x3067, "de"),  // で
+    (0x3068, "to"),  // と
+    (0x3069, "do"),  // ど
+    (0x306A, "na"),  // な
+    (0x306B, "ni"),  // に
+    (0x306C, "nu"),  // ぬ
+    (0x306D, "ne"),  // ね
+    (0x306E, "no"),  // の
+    (0x306F, "ha"),  // は
+    (0x3070, "ba"),  // ば
+    (0x3071, "pa"),  // ぱ
+    (0x3072, "hi"),  // ひ
+    (0x3073, "bi"),  // び
+    (0x3074, "pi"),  // ぴ
+    (0x3075, "fu"),  // ふ
+    (0x3076, "bu"),  // ぶ
+    (0x3077, "pu"),  // ぷ
+    (0x3078, "he"),  // へ
+    (0x3079, "be"),  // べ
+    (0x307A, "pe"),  // ぺ
+    (0x307B, "ho"),  // ほ
+    (0x307C, "bo"),  // ぼ
+    (0x307D, "po"),  // ぽ
+    (0x307E, "ma"),  // ま
+    (0x307F, "mi"),  // み
+    (0x3080, "mu"),  // む
+    (0x3081, "me"),  // め
+    (0x3082, "mo"),  // も
+    (0x3083, "ya"),  // ゃ
+    (0x3084, "ya"),  // や
+    (0x3085, "yu"),  // ゅ
+    (0x3086, "yu"),  // ゆ
+    (0x3087, "yo"),  // ょ
+    (0x3088, "yo"),  // よ
+    (0x3089, "ra"),  // ら
+    (0x308A, "ri"),  // り
+    (0x308B, "ru"),  // る
+    (0x308C, "re"),  // れ
+    (0x308D, "ro"),  // ろ
+    (0x308E, "wa"),  // ゎ
+    (0x308F, "wa"),  // わ
+    (0x3090, "i"),   // ゐ
+    (0x3091, "e"),   // ゑ
+    (0x3092, "o"),   // を
+    (0x3093, "n"),   // ん
+    (0x3094, "vu"),  // ゔ
+    (0x3095, "ka"),  // ゕ
+    (0x3096, "ke"),  // ゖ
+];
+
+/// Hiragana + small ゃ/ゅ/ょ digraphs (yōon) to Hepburn romaji, sorted by
+/// code point pair
+pub const ROMAJI_DIGRAPHS: &[(u32, u32, &str)] = &[
+    (0x304D, 0x3083, "kya"), // きゃ
+    (0x304D, 0x3085, "kyu"), // きゅ
+    (0x304D, 0x3087, "kyo"), // きょ
+    (0x304E, 0x3083, "gya"), // ぎゃ
+    (0x304E, 0x3085, "gyu"), // ぎゅ
+    (0x304E, 0x3087, "gyo"), // ぎょ
+    (0x3057, 0x3083, "sha"), // しゃ
+    (0x3057, 0x3085, "shu"), // しゅ
+    (0x3057, 0x3087, "sho"), // しょ
+    (0x3058, 0x3083, "ja"),  // じゃ
+    (0x3058, 0x3085, "ju"),  // じゅ
+    (0x3058, 0x3087, "jo"),  // じょ
+    (0x3061, 0x3083, "cha"), // ちゃ
+    (0x3061, 0x3085, "chu"), // ちゅ
+    (0x3061, 0x3087, "cho"), // ちょ
+    (0x3062, 0x3083, "ja"),  // ぢゃ
+    (0x3062, 0x3085, "ju"),  // ぢゅ
+    (0x3062, 0x3087, "jo"),  // ぢょ
+    (0x306B, 0x3083, "nya"), // にゃ
+    (0x306B, 0x3085, "nyu"), // にゅ
+    (0x306B, 0x3087, "nyo"), // にょ
+    (0x3072, 0x3083, "hya"), // ひゃ
+    (0x3072, 0x3085, "hyu"), // ひゅ
+    (0x3072, 0x3087, "hyo"), // ひょ
+    (0x3073, 0x3083, "bya"), // びゃ
+    (0x3073, 0x3085, "byu"), // びゅ
+    (0x3073, 0x3087, "byo"), // びょ
+    (0x3074, 0x3083, "pya"), // ぴゃ
+    (0x3074, 0x3085, "pyu"), // ぴゅ
+    (0x3074, 0x3087, "pyo"), // ぴょ
+    (0x307F, 0x3083, "mya"), // みゃ
+    (0x307F, 0x3085, "myu"), // みゅ
+    (0x307F, 0x3087, "myo"), // みょ
+    (0x308A, 0x3083, "rya"), // りゃ
+    (0x308A, 0x3085, "ryu"), // りゅ
+    (0x308A, 0x3087, "ryo"), // りょ
+];
+
+/// Sokuon (small tsu), which doubles the following consonant
+pub const SOKUON: u32 = 0x3063; // っ
+
+/// Syllabic n, written `n'` before a vowel or `y`
+pub const SYLLABIC_N: u32 = 0x3093; // ん
+
+/// Japanese punctuation with an ASCII equivalent
+pub const PUNCTUATION_MAP: &[(u32, &str)] = &[
+    (0x3000, " "), // IDEOGRAPHIC SPACE
+    (0x3001, ","), // 、
+    (0x3002, "."), // 。
+    (0x30FB, "/"), // ・
+    (0x30FC, "-"), // ー
+];
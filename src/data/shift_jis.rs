@@ -0,0 +1,101 @@
+//! Shift_JIS encoding hazards
+//!
+//! Shift_JIS reuses ASCII values as second bytes, so some double-byte
+//! characters contain the byte 0x5C — the ASCII backslash, and the path
+//! separator on Windows.  Tools that scan bytes instead of characters
+//! split or escape these characters (the "ダメ文字" problem).
+//!
+//! CP932 (Windows-31J), and with it the WHATWG Encoding Standard, also maps a
+//! few JIS X 0208 positions to different code points than the JIS
+//! standard; [`JIS_MAPPINGS`] and [`CP932_MAPPINGS`] list both sides.
+
+/// JIS X 0208 characters whose Shift_JIS encoding ends in 0x5C, in kuten
+/// order: cell 29 of every odd row
+pub const TRAIL_BYTE_5C: &[u32] = &[
+    0x2014, // — (01-29, 0x815C) EM DASH
+    0x30BD, // ソ (05-29, 0x835C) KATAKANA LETTER SO
+    0x042B, // Ы (07-29, 0x845C) CYRILLIC CAPITAL LETTER YERU
+    0x5642, // 噂 (17-29, 0x895C) CJK UNIFIED IDEOGRAPH-5642
+    0x6D6C, // 浬 (19-29, 0x8A5C) CJK UNIFIED IDEOGRAPH-6D6C
+    0x6B3A, // 欺 (21-29, 0x8B5C) CJK UNIFIED IDEOGRAPH-6B3A
+    0x572D, // 圭 (23-29, 0x8C5C) CJK UNIFIED IDEOGRAPH-572D
+    0x69CB, // 構 (25-29, 0x8D5C) CJK UNIFIED IDEOGRAPH-69CB
+    0x8695, // 蚕 (27-29, 0x8E5C) CJK UNIFIED IDEOGRAPH-8695
+    0x5341, // 十 (29-29, 0x8F5C) CJK UNIFIED IDEOGRAPH-5341
+    0x7533, // 申 (31-29, 0x905C) CJK UNIFIED IDEOGRAPH-7533
+    0x66FE, // 曾 (33-29, 0x915C) CJK UNIFIED IDEOGRAPH-66FE
+    0x7BAA, // 箪 (35-29, 0x925C) CJK UNIFIED IDEOGRAPH-7BAA
+    0x8CBC, // 貼 (37-29, 0x935C) CJK UNIFIED IDEOGRAPH-8CBC
+    0x80FD, // 能 (39-29, 0x945C) CJK UNIFIED IDEOGRAPH-80FD
+    0x8868, // 表 (41-29, 0x955C) CJK UNIFIED IDEOGRAPH-8868
+    0x66B4, // 暴 (43-29, 0x965C) CJK UNIFIED IDEOGRAPH-66B4
+    0x4E88, // 予 (45-29, 0x975C) CJK UNIFIED IDEOGRAPH-4E88
+    0x7984, // 禄 (47-29, 0x985C) CJK UNIFIED IDEOGRAPH-7984
+    0x5154, // 兔 (49-29, 0x995C) CJK UNIFIED IDEOGRAPH-5154
+    0x5580, // 喀 (51-29, 0x9A5C) CJK UNIFIED IDEOGRAPH-5580
+    0x5ABE, // 媾 (53-29, 0x9B5C) CJK UNIFIED IDEOGRAPH-5ABE
+    0x5F4C, // 彌 (55-29, 0x9C5C) CJK UNIFIED IDEOGRAPH-5F4C
+    0x62FF, // 拿 (57-29, 0x9D5C) CJK UNIFIED IDEOGRAPH-62FF
+    0x6764, // 杤 (59-29, 0x9E5C) CJK UNIFIED IDEOGRAPH-6764
+    0x6B43, // 歃 (61-29, 0x9F5C) CJK UNIFIED IDEOGRAPH-6B43
+    0x6FEC, // 濬 (63-29, 0xE05C) CJK UNIFIED IDEOGRAPH-6FEC
+    0x755A, // 畚 (65-29, 0xE15C) CJK UNIFIED IDEOGRAPH-755A
+    0x79C9, // 秉 (67-29, 0xE25C) CJK UNIFIED IDEOGRAPH-79C9
+    0x7DB5, // 綵 (69-29, 0xE35C) CJK UNIFIED IDEOGRAPH-7DB5
+    0x81C0, // 臀 (71-29, 0xE45C) CJK UNIFIED IDEOGRAPH-81C0
+    0x85F9, // 藹 (73-29, 0xE55C) CJK UNIFIED IDEOGRAPH-85F9
+    0x89F8, // 觸 (75-29, 0xE65C) CJK UNIFIED IDEOGRAPH-89F8
+    0x8EC6, // 軆 (77-29, 0xE75C) CJK UNIFIED IDEOGRAPH-8EC6
+    0x9414, // 鐔 (79-29, 0xE85C) CJK UNIFIED IDEOGRAPH-9414
+    0x9945, // 饅 (81-29, 0xE95C) CJK UNIFIED IDEOGRAPH-9945
+    0x9DED, // 鷭 (83-29, 0xEA5C) CJK UNIFIED IDEOGRAPH-9DED
+];
+
+/// Number of entries in [`TRAIL_BYTE_5C`].
+pub const TRAIL_BYTE_5C_COUNT: usize = 37;
+const _: () = assert!(TRAIL_BYTE_5C.len() == TRAIL_BYTE_5C_COUNT);
+
+/// [`TRAIL_BYTE_5C`] as `char`s, converted at compile time.
+pub const TRAIL_BYTE_5C_CHARS: &[char] = &super::to_chars::<TRAIL_BYTE_5C_COUNT>(TRAIL_BYTE_5C);
+
+/// JIS X 0208 positions that CP932 and the WHATWG Encoding Standard map to
+/// different code points than this crate's tables, in kuten order
+///
+/// Entry *i* corresponds to entry *i* of [`CP932_MAPPINGS`].  01-32 is
+/// listed although U+005C also encodes as ASCII.
+pub const JIS_MAPPINGS: &[u32] = &[
+    0x2014, // — (01-29) EM DASH
+    0x005C, // \ (01-32) REVERSE SOLIDUS
+    0x301C, // 〜 (01-33) WAVE DASH
+    0x2016, // ‖ (01-34) DOUBLE VERTICAL LINE
+    0x2212, // − (01-61) MINUS SIGN
+    0x00A2, // ¢ (01-81) CENT SIGN
+    0x00A3, // £ (01-82) POUND SIGN
+    0x00AC, // ¬ (02-44) NOT SIGN
+];
+
+/// Number of entries in [`JIS_MAPPINGS`].
+pub const JIS_MAPPINGS_COUNT: usize = 8;
+const _: () = assert!(JIS_MAPPINGS.len() == JIS_MAPPINGS_COUNT);
+
+/// [`JIS_MAPPINGS`] as `char`s, converted at compile time.
+pub const JIS_MAPPINGS_CHARS: &[char] = &super::to_chars::<JIS_MAPPINGS_COUNT>(JIS_MAPPINGS);
+
+/// The code points CP932 maps the positions of [`JIS_MAPPINGS`] to
+pub const CP932_MAPPINGS: &[u32] = &[
+    0x2015, // ― (01-29) HORIZONTAL BAR
+    0xFF3C, // ＼ (01-32) FULLWIDTH REVERSE SOLIDUS
+    0xFF5E, // ～ (01-33) FULLWIDTH TILDE
+    0x2225, // ∥ (01-34) PARALLEL TO
+    0xFF0D, // － (01-61) FULLWIDTH HYPHEN-MINUS
+    0xFFE0, // ￠ (01-81) FULLWIDTH CENT SIGN
+    0xFFE1, // ￡ (01-82) FULLWIDTH POUND SIGN
+    0xFFE2, // ￢ (02-44) FULLWIDTH NOT SIGN
+];
+
+/// Number of entries in [`CP932_MAPPINGS`].
+pub const CP932_MAPPINGS_COUNT: usize = 8;
+const _: () = assert!(CP932_MAPPINGS.len() == CP932_MAPPINGS_COUNT);
+
+/// [`CP932_MAPPINGS`] as `char`s, converted at compile time.
+pub const CP932_MAPPINGS_CHARS: &[char] = &super::to_chars::<CP932_MAPPINGS_COUNT>(CP932_MAPPINGS);
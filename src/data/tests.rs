@@ -113,6 +113,92 @@ mod tests {
         assert!(!cp.contains("abc"));
     }
 
+    /// JIS X 0208 row 1, cells 01–94, as this crate maps them (01-29 is U+2014,
+    /// 01-61 is U+2212; see [`crate::data::shift_jis::JIS_MAPPINGS`]).
+    const ROW1_REFERENCE: &str = "\u{3000}、。，．・：；？！゛゜´｀¨＾￣＿ヽヾゝゞ〃仝々〆〇ー\u{2014}‐／\\\
+        〜‖｜…‥\u{2018}\u{2019}\u{201C}\u{201D}（）〔〕［］｛｝〈〉《》「」『』【】＋\u{2212}±×÷＝≠＜＞≦≧∞∴\
+        ♂♀°′″℃￥＄¢£％＃＆＊＠§☆★○●◎◇";
+
+    /// JIS X 0208 row 2, the assigned cells only.
+    const ROW2_REFERENCE: &[(u8, char)] = &[
+        (1, '◆'),
+        (2, '□'),
+        (3, '■'),
+        (4, '△'),
+        (5, '▲'),
+        (6, '▽'),
+        (7, '▼'),
+        (8, '※'),
+        (9, '〒'),
+        (10, '→'),
+        (11, '←'),
+        (12, '↑'),
+        (13, '↓'),
+        (14, '〓'),
+        (26, '∈'),
+        (27, '∋'),
+        (28, '⊆'),
+        (29, '⊇'),
+        (30, '⊂'),
+        (31, '⊃'),
+        (32, '∪'),
+        (33, '∩'),
+        (42, '∧'),
+        (43, '∨'),
+        (44, '¬'),
+        (45, '⇒'),
+        (46, '⇔'),
+        (47, '∀'),
+        (48, '∃'),
+        (60, '∠'),
+        (61, '⊥'),
+        (62, '⌒'),
+        (63, '∂'),
+        (64, '∇'),
+        (65, '≡'),
+        (66, '≒'),
+        (67, '≪'),
+        (68, '≫'),
+        (69, '√'),
+        (70, '∽'),
+        (71, '∝'),
+        (72, '∵'),
+        (73, '∫'),
+        (74, '∬'),
+        (82, '\u{212B}'), // Å ANGSTROM SIGN, not U+00C5
+        (83, '‰'),
+        (84, '♯'),
+        (85, '♭'),
+        (86, '♪'),
+        (87, '†'),
+        (88, '‡'),
+        (89, '¶'),
+        (94, '◯'),
+    ];
+
+    #[test]
+    fn test_jisx0208_special_chars_match_rows_1_and_2() {
+        use crate::data::jisx0208;
+
+        let row1: Vec<char> = ROW1_REFERENCE.chars().collect();
+        let row2: Vec<char> = ROW2_REFERENCE.iter().map(|&(_, c)| c).collect();
+        assert_eq!(row1.len(), jisx0208::SPECIAL_CHARS_ROW1_COUNT);
+        assert_eq!(row2.len(), jisx0208::SPECIAL_CHARS_ROW2_COUNT);
+        assert!(ROW2_REFERENCE.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // Every assigned cell is present, in kuten order; nothing is omitted.
+        let table: Vec<char> = jisx0208::SPECIAL_CHARS_AS_CHARS.to_vec();
+        assert_eq!(table, [row1, row2].concat());
+        assert_eq!(table.len(), 147);
+        assert!(CodePoints::new(JISX0208_SPECIAL_CHARS.to_vec()).contains("∩∵≡√"));
+
+        // Row 8 (box drawing) is a separate table, disjoint from rows 1–2.
+        assert_eq!(JISX0208_BOX_DRAWING_CHARS.len(), 32);
+        assert!(!JISX0208_BOX_DRAWING_CHARS
+            .iter()
+            .any(|cp| JISX0208_SPECIAL_CHARS.contains(cp)));
+    }
+
     #[test]
     fn test_jisx0208_kanji() {
         let cp = CodePoints::new(JISX0208_KANJI.to_vec());
@@ -142,4 +228,157 @@ mod tests {
         assert!(!cp.contains("あ"));
         assert!(!cp.contains("ア"));
     }
+
+    #[test]
+    fn test_jisx0213_to_jisx0208_fallbacks() {
+        use crate::data::downgrade::JISX0213_TO_JISX0208;
+
+        assert!(JISX0213_TO_JISX0208.windows(2).all(|w| w[0].0 < w[1].0));
+        for &(from, to) in JISX0213_TO_JISX0208 {
+            assert!(JISX0213_KANJI.contains(&from), "U+{:04X}", from);
+            assert!(!JISX0208_KANJI.contains(&from), "U+{:04X}", from);
+            assert!(JISX0208_KANJI.contains(&to), "U+{:04X}", to);
+        }
+    }
+
+    #[test]
+    fn test_char_tables_match_code_point_tables() {
+        use crate::data::{
+            ascii, ibm, jisx0201, jisx0208, jisx0208kanji, jisx0213kanji, kinsoku, nec, shift_jis,
+        };
+
+        let pairs: &[(&[char], &[u32])] = &[
+            (jisx0201::LATIN_LETTERS_CHARS, jisx0201::LATIN_LETTERS),
+            (jisx0201::KATAKANA_CHARS, jisx0201::KATAKANA),
+            (jisx0208::HIRAGANA_CHARS, jisx0208::HIRAGANA),
+            (jisx0208::KATAKANA_CHARS, jisx0208::KATAKANA),
+            (jisx0208::LATIN_LETTERS_CHARS, jisx0208::LATIN_LETTERS),
+            (jisx0208::GREEK_LETTERS_CHARS, jisx0208::GREEK_LETTERS),
+            (jisx0208::CYRILLIC_LETTERS_CHARS, jisx0208::CYRILLIC_LETTERS),
+            (jisx0208::SPECIAL_CHARS_AS_CHARS, jisx0208::SPECIAL_CHARS),
+            (
+                jisx0208::BOX_DRAWING_CHARS_AS_CHARS,
+                jisx0208::BOX_DRAWING_CHARS,
+            ),
+            (ascii::ALL_ASCII_CHARS, &ascii::ALL_ASCII),
+            (jisx0201::ALL_JISX0201_CHARS, &jisx0201::ALL_JISX0201),
+            (jisx0208::ALL_JISX0208_CHARS, &jisx0208::ALL_JISX0208),
+            (
+                jisx0208kanji::JISX0208_KANJI_CHARS,
+                jisx0208kanji::JISX0208_CHARS,
+            ),
+            (jisx0213kanji::JISX0213_KANJI_CHARS, JISX0213_KANJI),
+            (
+                kinsoku::LINE_START_PROHIBITED_CHARS,
+                kinsoku::LINE_START_PROHIBITED,
+            ),
+            (
+                kinsoku::LINE_END_PROHIBITED_CHARS,
+                kinsoku::LINE_END_PROHIBITED,
+            ),
+            (nec::NEC_ROW13_CHARS, nec::NEC_ROW13),
+            (ibm::IBM_EXTENSIONS_CHARS, ibm::IBM_EXTENSIONS),
+            (shift_jis::TRAIL_BYTE_5C_CHARS, shift_jis::TRAIL_BYTE_5C),
+            (shift_jis::JIS_MAPPINGS_CHARS, shift_jis::JIS_MAPPINGS),
+            (shift_jis::CP932_MAPPINGS_CHARS, shift_jis::CP932_MAPPINGS),
+        ];
+        assert_eq!(jisx0208::HIRAGANA_CHARS.len(), jisx0208::HIRAGANA.len());
+        for (chars, table) in pairs {
+            assert_eq!(chars.len(), table.len());
+            assert!(chars
+                .iter()
+                .zip(table.iter())
+                .all(|(&c, &cp)| c as u32 == cp));
+        }
+    }
+
+    /// Checks that `merged` is strictly ascending and holds exactly the code
+    /// points of `parts`.
+    fn assert_merged(merged: &[u32], parts: &[&[u32]]) {
+        assert!(
+            merged.windows(2).all(|w| w[0] < w[1]),
+            "not strictly ascending"
+        );
+        let mut expected: Vec<u32> = parts.iter().flat_map(|p| p.iter().copied()).collect();
+        expected.sort_unstable();
+        expected.dedup();
+        assert_eq!(merged, expected.as_slice());
+    }
+
+    #[test]
+    fn test_merged_tables_equal_union_of_parts() {
+        use crate::data::{ascii, jisx0201};
+
+        assert_merged(
+            &ascii::ALL_ASCII,
+            &[
+                ascii::CONTROL_CHARS,
+                ascii::PRINTABLE_CHARS,
+                ascii::CRLF_CHARS,
+            ],
+        );
+        assert_eq!(ascii::ALL_ASCII, std::array::from_fn(|i| i as u32));
+        assert_merged(
+            &jisx0201::ALL_JISX0201,
+            &[JISX0201_LATIN_LETTERS, JISX0201_KATAKANA],
+        );
+        let jisx0208_parts: &[&[u32]] = &[
+            JISX0208_SPECIAL_CHARS,
+            JISX0208_LATIN_LETTERS,
+            JISX0208_HIRAGANA,
+            JISX0208_KATAKANA,
+            JISX0208_GREEK_LETTERS,
+            JISX0208_CYRILLIC_LETTERS,
+            JISX0208_BOX_DRAWING_CHARS,
+        ];
+        assert_merged(&crate::data::jisx0208::ALL_JISX0208, jisx0208_parts);
+
+        #[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+        {
+            let with_kanji = [jisx0208_parts, &[JISX0208_KANJI]].concat();
+            assert_merged(&crate::data::jisx0208::ALL_JISX0208_WITH_KANJI, &with_kanji);
+            assert_eq!(
+                crate::data::jisx0208::ALL_JISX0208_WITH_KANJI_COUNT,
+                crate::data::jisx0208::ALL_JISX0208_COUNT
+                    + crate::data::jisx0208kanji::JISX0208_KANJI_COUNT
+            );
+        }
+    }
+
+    /// Expected [`CodePoints::fingerprint`] of each built-in table.  These pin
+    /// the fingerprint definition across platforms and releases; update a value
+    /// only together with a deliberate change to that table's data.
+    const FINGERPRINTS: &[(&str, u64)] = &[
+        ("ascii_printable", 0x4f13_3839_a309_16aa),
+        ("ascii_control", 0x8bfa_fbf8_acf8_c27a),
+        ("jisx0201_katakana", 0xe36d_a5ff_8668_4450),
+        ("jisx0201_latin_letters", 0xa86f_68d9_390d_e643),
+        ("jisx0208_hiragana", 0x31fd_7477_c6c9_aea5),
+        ("jisx0208_katakana", 0x164c_4761_71e6_07b2),
+        ("jisx0208_kanji", 0xa500_c0f2_3b51_012d),
+        ("jisx0213_kanji", 0xefb0_a9fc_9a73_973e),
+    ];
+
+    #[test]
+    fn test_builtin_set_fingerprints() {
+        let sets = [
+            CodePoints::ascii_printable(),
+            CodePoints::ascii_control(),
+            CodePoints::from_slice(JISX0201_KATAKANA),
+            CodePoints::from_slice(JISX0201_LATIN_LETTERS),
+            CodePoints::from_slice(JISX0208_HIRAGANA),
+            CodePoints::from_slice(JISX0208_KATAKANA),
+            CodePoints::from_slice(JISX0208_KANJI),
+            CodePoints::from_slice(JISX0213_KANJI),
+        ];
+        assert_eq!(sets.len(), FINGERPRINTS.len());
+        for (set, &(name, expected)) in sets.iter().zip(FINGERPRINTS) {
+            assert_eq!(
+                set.fingerprint(),
+                expected,
+                "fingerprint of {} changed; update FINGERPRINTS only if its data changed deliberately",
+                name
+            );
+        }
+    }
 }
@@ -0,0 +1,86 @@
+//! Vertical presentation forms and small form variants
+//!
+//! Text extracted from vertically typeset PDFs often keeps the vertical
+//! glyph variants of punctuation.  Each table pairs a presentation form with
+//! the horizontal character it is a variant of (its Unicode compatibility
+//! decomposition), so it can be read in either direction.
+
+/// `(vertical form, horizontal character)` pairs for the Vertical Forms
+/// block and the vertical forms of the CJK Compatibility Forms block
+///
+/// Sorted by vertical form.  Both ︳ and ︴ map to `_`.
+pub const VERTICAL_FORMS: &[(u32, u32)] = &[
+    (0xFE10, 0x002C), // ︐ → ,
+    (0xFE11, 0x3001), // ︑ → 、
+    (0xFE12, 0x3002), // ︒ → 。
+    (0xFE13, 0x003A), // ︓ → :
+    (0xFE14, 0x003B), // ︔ → ;
+    (0xFE15, 0x0021), // ︕ → !
+    (0xFE16, 0x003F), // ︖ → ?
+    (0xFE17, 0x3016), // ︗ → 〖
+    (0xFE18, 0x3017), // ︘ → 〗
+    (0xFE19, 0x2026), // ︙ → …
+    (0xFE30, 0x2025), // ︰ → ‥
+    (0xFE31, 0x2014), // ︱ → —
+    (0xFE32, 0x2013), // ︲ → –
+    (0xFE33, 0x005F), // ︳ → _
+    (0xFE34, 0x005F), // ︴ → _
+    (0xFE35, 0x0028), // ︵ → (
+    (0xFE36, 0x0029), // ︶ → )
+    (0xFE37, 0x007B), // ︷ → {
+    (0xFE38, 0x007D), // ︸ → }
+    (0xFE39, 0x3014), // ︹ → 〔
+    (0xFE3A, 0x3015), // ︺ → 〕
+    (0xFE3B, 0x3010), // ︻ → 【
+    (0xFE3C, 0x3011), // ︼ → 】
+    (0xFE3D, 0x300A), // ︽ → 《
+    (0xFE3E, 0x300B), // ︾ → 》
+    (0xFE3F, 0x3008), // ︿ → 〈
+    (0xFE40, 0x3009), // ﹀ → 〉
+    (0xFE41, 0x300C), // ﹁ → 「
+    (0xFE42, 0x300D), // ﹂ → 」
+    (0xFE43, 0x300E), // ﹃ → 『
+    (0xFE44, 0x300F), // ﹄ → 』
+    (0xFE47, 0x005B), // ﹇ → [
+    (0xFE48, 0x005D), // ﹈ → ]
+];
+
+/// Number of entries in [`VERTICAL_FORMS`].
+pub const VERTICAL_FORMS_COUNT: usize = 33;
+const _: () = assert!(VERTICAL_FORMS.len() == VERTICAL_FORMS_COUNT);
+
+/// `(small form, normal character)` pairs for the Small Form Variants block
+///
+/// Sorted by small form.
+pub const SMALL_FORM_VARIANTS: &[(u32, u32)] = &[
+    (0xFE50, 0x002C), // ﹐ → ,
+    (0xFE51, 0x3001), // ﹑ → 、
+    (0xFE52, 0x002E), // ﹒ → .
+    (0xFE54, 0x003B), // ﹔ → ;
+    (0xFE55, 0x003A), // ﹕ → :
+    (0xFE56, 0x003F), // ﹖ → ?
+    (0xFE57, 0x0021), // ﹗ → !
+    (0xFE58, 0x2014), // ﹘ → —
+    (0xFE59, 0x0028), // ﹙ → (
+    (0xFE5A, 0x0029), // ﹚ → )
+    (0xFE5B, 0x007B), // ﹛ → {
+    (0xFE5C, 0x007D), // ﹜ → }
+    (0xFE5D, 0x3014), // ﹝ → 〔
+    (0xFE5E, 0x3015), // ﹞ → 〕
+    (0xFE5F, 0x0023), // ﹟ → #
+    (0xFE60, 0x0026), // ﹠ → &
+    (0xFE61, 0x002A), // ﹡ → *
+    (0xFE62, 0x002B), // ﹢ → +
+    (0xFE63, 0x002D), // ﹣ → -
+    (0xFE64, 0x003C), // ﹤ → <
+    (0xFE65, 0x003E), // ﹥ → >
+    (0xFE66, 0x003D), // ﹦ → =
+    (0xFE68, 0x005C), // ﹨ → \
+    (0xFE69, 0x0024), // ﹩ → $
+    (0xFE6A, 0x0025), // ﹪ → %
+    (0xFE6B, 0x0040), // ﹫ → @
+];
+
+/// Number of entries in [`SMALL_FORM_VARIANTS`].
+pub const SMALL_FORM_VARIANTS_COUNT: usize = 26;
+const _: () = assert!(SMALL_FORM_VARIANTS.len() == SMALL_FORM_VARIANTS_COUNT);
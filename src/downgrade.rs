@@ -0,0 +1,232 @@
+//! Downgrading text to JIS X 0208 for systems that predate JIS X 0213.
+//!
+//! [`to_jisx0208`] sorts every character of a string into one of three
+//! outcomes and reports each:
+//!
+//! * **As-is** — JIS X 0208 characters (kanji and non-kanji) and ASCII,
+//!   which every JIS X 0208 encoding also carries.
+//! * **Substituted** — JIS X 0213 kanji with a conventional JIS X 0208
+//!   substitute (`𠮟` → `叱`), from the auditable table
+//!   [`data::downgrade::JISX0213_TO_JISX0208`](crate::data::downgrade::JISX0213_TO_JISX0208).
+//! * **Unresolvable** — everything else, replaced by [`GETA`] in the output.
+//!
+//! [`to_jisx0208_strict`] substitutes nothing and fails on the first
+//! character that is not as-is.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::downgrade::to_jisx0208;
+//!
+//! let report = to_jisx0208("𠮟る頰😀");
+//! assert_eq!(report.output, "叱る頬〓");
+//! assert_eq!(report.substituted, [('𠮟', '叱', 0), ('頰', '頬', 2)]);
+//! assert_eq!(report.unresolvable, [('😀', 3)]);
+//! ```
+
+use std::fmt;
+
+use crate::data::downgrade::JISX0213_TO_JISX0208;
+use crate::jisx0208::JisX0208;
+
+/// The geta mark (〓, JIS X 0208 2-14) written in place of characters with
+/// no JIS X 0208 representation.
+pub const GETA: char = '〓';
+
+/// The result of [`to_jisx0208`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DowngradeReport {
+    /// The input with substitutes applied and unresolvable characters
+    /// replaced by [`GETA`].
+    pub output: String,
+    /// `(original, substitute, position)` for each substituted character,
+    /// where `position` is the zero-based character index in the input.
+    pub substituted: Vec<(char, char, usize)>,
+    /// `(character, position)` for each character with no JIS X 0208
+    /// representation.
+    pub unresolvable: Vec<(char, usize)>,
+}
+
+impl DowngradeReport {
+    /// Returns `true` if every character passed as-is, so `output` equals
+    /// the input.
+    pub fn is_lossless(&self) -> bool {
+        self.substituted.is_empty() && self.unresolvable.is_empty()
+    }
+}
+
+/// A character rejected by [`to_jisx0208_strict`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DowngradeError {
+    /// The offending code point of the input.
+    pub code_point: u32,
+    /// Zero-based character index of the offending code point in the input.
+    pub position: usize,
+    /// The substitute [`to_jisx0208`] would have used, if any.
+    pub substitute: Option<char>,
+}
+
+impl fmt::Display for DowngradeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = char::from_u32(self.code_point).unwrap_or('\u{FFFD}');
+        write!(
+            f,
+            "character '{}' (U+{:04X}) at position {} is not in JIS X 0208",
+            ch, self.code_point, self.position
+        )?;
+        match self.substitute {
+            Some(substitute) => write!(f, " (substitute: '{}')", substitute),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for DowngradeError {}
+
+/// Returns the conventional JIS X 0208 substitute for a JIS X 0213 kanji,
+/// or `None` if `c` has none.
+///
+/// JIS X 0208 characters have no substitute; they pass as-is.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::downgrade::substitute_for;
+///
+/// assert_eq!(substitute_for('剝'), Some('剥'));
+/// assert_eq!(substitute_for('剥'), None);
+/// ```
+pub fn substitute_for(c: char) -> Option<char> {
+    JISX0213_TO_JISX0208
+        .binary_search_by_key(&(c as u32), |&(from, _)| from)
+        .ok()
+        .and_then(|i| JISX0213_TO_JISX0208.get(i))
+        .and_then(|&(_, to)| char::from_u32(to))
+}
+
+/// Returns `true` if `c` can be sent to a JIS X 0208 system unchanged.
+fn passes(c: char) -> bool {
+    c.is_ascii() || JisX0208::with_kanji().contains_char(c)
+}
+
+/// Converts `s` to JIS X 0208, substituting where a conventional
+/// substitute exists, and reports what was changed.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::downgrade::to_jisx0208;
+///
+/// let report = to_jisx0208("剝製の𠮷");
+/// assert_eq!(report.output, "剥製の〓");
+/// assert!(!report.is_lossless());
+/// assert!(to_jisx0208("叱る").is_lossless());
+/// ```
+pub fn to_jisx0208(s: &str) -> DowngradeReport {
+    let mut report = DowngradeReport {
+        output: String::with_capacity(s.len()),
+        ..DowngradeReport::default()
+    };
+    for (position, c) in s.chars().enumerate() {
+        if passes(c) {
+            report.output.push(c);
+        } else if let Some(substitute) = substitute_for(c) {
+            report.output.push(substitute);
+            report.substituted.push((c, substitute, position));
+        } else {
+            report.output.push(GETA);
+            report.unresolvable.push((c, position));
+        }
+    }
+    report
+}
+
+/// Checks that `s` needs no downgrading and returns it as a `String`.
+///
+/// # Errors
+///
+/// Returns a [`DowngradeError`] for the first character that is neither
+/// JIS X 0208 nor ASCII, whether or not it has a substitute.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::downgrade::to_jisx0208_strict;
+///
+/// assert_eq!(to_jisx0208_strict("叱る").unwrap(), "叱る");
+/// let err = to_jisx0208_strict("𠮟る").unwrap_err();
+/// assert_eq!((err.position, err.substitute), (0, Some('叱')));
+/// ```
+pub fn to_jisx0208_strict(s: &str) -> Result<String, DowngradeError> {
+    match s.chars().enumerate().find(|&(_, c)| !passes(c)) {
+        Some((position, c)) => Err(DowngradeError {
+            code_point: c as u32,
+            position,
+            substitute: substitute_for(c),
+        }),
+        None => Ok(s.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_is() {
+        let text = "漢字とカナ、ＡＢＣ。abc 123\n";
+        let report = to_jisx0208(text);
+        assert_eq!(report.output, text);
+        assert!(report.is_lossless());
+        assert_eq!(to_jisx0208_strict(text).unwrap(), text);
+        assert!(to_jisx0208("").is_lossless());
+    }
+
+    #[test]
+    fn test_substituted() {
+        let report = to_jisx0208("頰を𠮟る、剝がす");
+        assert_eq!(report.output, "頬を叱る、剥がす");
+        assert_eq!(
+            report.substituted,
+            [('頰', '頬', 0), ('𠮟', '叱', 2), ('剝', '剥', 5)]
+        );
+        assert!(report.unresolvable.is_empty());
+
+        let err = to_jisx0208_strict("あ頰").unwrap_err();
+        assert_eq!(err.code_point, 0x9830);
+        assert_eq!(err.position, 1);
+        assert_eq!(err.substitute, Some('頬'));
+        assert_eq!(
+            err.to_string(),
+            "character '頰' (U+9830) at position 1 is not in JIS X 0208 (substitute: '頬')"
+        );
+    }
+
+    #[test]
+    fn test_unresolvable() {
+        // 𠮷 and 㐂 are outside JIS X 0208 with no accepted substitute;
+        // halfwidth katakana is JIS X 0201 only.
+        let report = to_jisx0208("𠮷野㐂ｱ");
+        assert_eq!(report.output, "〓野〓〓");
+        assert!(report.substituted.is_empty());
+        assert_eq!(report.unresolvable, [('𠮷', 0), ('㐂', 2), ('ｱ', 3)]);
+
+        let err = to_jisx0208_strict("野𠮷").unwrap_err();
+        assert_eq!((err.position, err.substitute), (1, None));
+        assert_eq!(
+            err.to_string(),
+            "character '𠮷' (U+20BB7) at position 1 is not in JIS X 0208"
+        );
+    }
+
+    #[test]
+    fn test_substitute_for() {
+        for &(from, to) in JISX0213_TO_JISX0208 {
+            let from = char::from_u32(from).unwrap();
+            assert_eq!(substitute_for(from), char::from_u32(to));
+            assert!(!passes(from));
+        }
+        assert_eq!(substitute_for('叱'), None);
+        assert_eq!(substitute_for('a'), None);
+    }
+}
@@ -0,0 +1,116 @@
+//! Enclosed (circled and parenthesized) characters.
+//!
+//! Circled digits and abbreviations such as ① and ㈱ are everywhere in
+//! Japanese business documents, but none of them are in JIS X 0208.
+//! [`EnclosedChars`] offers two strictness levels:
+//!
+//! | Set | Contents | Size |
+//! |---|---|---|
+//! | [`EnclosedChars::jis_x0213`] | the enclosed characters standardized by JIS X 0213 | 139 |
+//! | [`EnclosedChars::extended`] | Enclosed Alphanumerics, Enclosed CJK Letters and Months, ❶–❿ | 425 |
+//!
+//! The extended set is a superset of the JIS X 0213 one, so a lenient
+//! validator can accept `extended()` while a strict one flags anything
+//! outside `jis_x0213()` (or outside JIS X 0208 altogether).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::enclosed::EnclosedChars;
+//!
+//! assert!(EnclosedChars::jis_x0213().contains("①㈱㊤"));
+//! assert!(!EnclosedChars::jis_x0213().contains("⓪"));
+//! assert!(EnclosedChars::extended().contains("⓪①㈱"));
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::data::enclosed;
+use crate::CodePoints;
+
+/// Namespace for the enclosed-character sets; see the [module
+/// documentation](self).
+#[derive(Debug, Clone, Copy)]
+pub struct EnclosedChars;
+
+impl EnclosedChars {
+    /// Number of characters in [`EnclosedChars::jis_x0213`], checked against
+    /// the data table at compile time.
+    pub const JIS_X0213_COUNT: usize = enclosed::JISX0213_ENCLOSED_COUNT;
+
+    /// Returns the enclosed characters standardized by JIS X 0213: ①–⑳,
+    /// ㉑–㉟, ㊱–㊿, ❶–❿, ⓫–⓴, ⓵–⓾, ⓐ–ⓩ, most of ㋐–㋾, and ㈱㈲㈹㊤–㊨.
+    ///
+    /// The set is initialized on first access.
+    pub fn jis_x0213() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(|| CodePoints::from_slice(enclosed::JISX0213_ENCLOSED))
+    }
+
+    /// Returns every assigned character of the Enclosed Alphanumerics
+    /// (U+2460–U+24FF) and Enclosed CJK Letters and Months (U+3200–U+32FF)
+    /// blocks, plus ❶–❿ (U+2776–U+277F).
+    ///
+    /// Always a superset of [`EnclosedChars::jis_x0213`].  The set is
+    /// initialized on first access.
+    pub fn extended() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(|| {
+            CodePoints::new(
+                enclosed::EXTENDED_RANGES
+                    .iter()
+                    .flat_map(|&(start, end)| start..=end)
+                    .collect(),
+            )
+        })
+    }
+
+    /// Returns the JIS X 0213 enclosed characters in code-point order, as a
+    /// static slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        enclosed::JISX0213_ENCLOSED_CHARS
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(EnclosedChars::jis_x0213().len(), 139);
+        assert_eq!(EnclosedChars::JIS_X0213_COUNT, 139);
+        assert_eq!(EnclosedChars::extended().len(), 425);
+        assert_eq!(EnclosedChars::chars().len(), 139);
+    }
+
+    #[test]
+    fn test_strictness_levels() {
+        let strict = EnclosedChars::jis_x0213();
+        let lenient = EnclosedChars::extended();
+        assert!(strict.difference(lenient).is_empty());
+
+        for s in ["①", "⑳", "㈱", "㊤", "㉑", "㊿", "❶", "ⓐ", "㋐"] {
+            assert!(strict.contains(s), "{} should be in JIS X 0213", s);
+            assert!(lenient.contains(s));
+        }
+        for s in ["⓪", "⑴", "⒜", "Ⓐ", "㈠", "㊀", "㋿"] {
+            assert!(!strict.contains(s), "{} should not be in JIS X 0213", s);
+            assert!(lenient.contains(s), "{} should be in the extended set", s);
+        }
+        assert!(!lenient.contains_char('\u{321F}')); // unassigned
+        assert!(!lenient.contains("①A"));
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    #[test]
+    fn test_not_in_jisx0208() {
+        // Pins current behaviour: circled digits are not JIS X 0208.
+        let jisx0208 = crate::jisx0208::JisX0208::cached();
+        assert!(!jisx0208.contains("①"));
+        assert!(!jisx0208.contains("㈱"));
+        assert!(EnclosedChars::jis_x0213().contains("①"));
+    }
+}
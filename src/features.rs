@@ -0,0 +1,335 @@
+//! Run-time view of the Cargo features this crate was compiled with.
+//!
+//! Crates that wrap this one can use [`compiled_features`] and
+//! [`has_feature`] to decide which options to offer, instead of mirroring
+//! the feature list in their own `cfg`s.  The answers come from `cfg!`, so
+//! they always match the build.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::{compiled_features, has_feature, Feature};
+//!
+//! if has_feature(Feature::Jisx0208) {
+//!     assert!(compiled_features().contains(&Feature::Jisx0208));
+//! }
+//! assert_eq!(Feature::Jisx0208.cargo_name(), "codepoints-jisx0208");
+//! ```
+
+use std::fmt;
+
+/// An optional Cargo feature of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `codepoints-jisx0201`
+    Jisx0201,
+    /// `codepoints-jisx0208`
+    Jisx0208,
+    /// `codepoints-jisx0208kanji`
+    Jisx0208Kanji,
+    /// `codepoints-jisx0213kanji`
+    Jisx0213Kanji,
+    /// `emoji`
+    Emoji,
+    /// `kanji-metadata`
+    KanjiMetadata,
+    /// `mmap`
+    Mmap,
+    /// `serde`
+    Serde,
+    /// `tokio`
+    Tokio,
+    /// `tracing`
+    Tracing,
+}
+
+impl Feature {
+    /// Every variant, in declaration order.
+    pub const ALL: [Feature; 10] = [
+        Feature::Jisx0201,
+        Feature::Jisx0208,
+        Feature::Jisx0208Kanji,
+        Feature::Jisx0213Kanji,
+        Feature::Emoji,
+        Feature::KanjiMetadata,
+        Feature::Mmap,
+        Feature::Serde,
+        Feature::Tokio,
+        Feature::Tracing,
+    ];
+
+    /// Returns the feature's name in `Cargo.toml`.
+    pub const fn cargo_name(self) -> &'static str {
+        match self {
+            Feature::Jisx0201 => "codepoints-jisx0201",
+            Feature::Jisx0208 => "codepoints-jisx0208",
+            Feature::Jisx0208Kanji => "codepoints-jisx0208kanji",
+            Feature::Jisx0213Kanji => "codepoints-jisx0213kanji",
+            Feature::Emoji => "emoji",
+            Feature::KanjiMetadata => "kanji-metadata",
+            Feature::Mmap => "mmap",
+            Feature::Serde => "serde",
+            Feature::Tokio => "tokio",
+            Feature::Tracing => "tracing",
+        }
+    }
+
+    /// Returns the feature named `name` in `Cargo.toml`.
+    ///
+    /// Usable in constants; an unknown name there is a compile error.  For
+    /// names from run-time input, use [`Self::try_from_cargo_name`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a feature of this crate.
+    #[allow(clippy::panic)] // intended as a compile-time check
+    pub const fn from_cargo_name(name: &str) -> Feature {
+        match Feature::try_from_cargo_name(name) {
+            Some(feature) => feature,
+            None => panic!("unknown feature name"),
+        }
+    }
+
+    /// Returns the feature named `name` in `Cargo.toml`, or `None` if there
+    /// is no such feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::Feature;
+    ///
+    /// assert_eq!(Feature::try_from_cargo_name("emoji"), Some(Feature::Emoji));
+    /// assert_eq!(Feature::try_from_cargo_name("no-such-feature"), None);
+    /// ```
+    pub const fn try_from_cargo_name(name: &str) -> Option<Feature> {
+        let mut rest = Feature::ALL.as_slice();
+        while let [feature, tail @ ..] = rest {
+            if str_eq(feature.cargo_name(), name) {
+                return Some(*feature);
+            }
+            rest = tail;
+        }
+        None
+    }
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+    loop {
+        match (a, b) {
+            ([], []) => return true,
+            ([x, a_tail @ ..], [y, b_tail @ ..]) if *x == *y => {
+                a = a_tail;
+                b = b_tail;
+            }
+            _ => return false,
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.cargo_name())
+    }
+}
+
+/// The features enabled in this build, in [`Feature::ALL`] order.
+const COMPILED: &[Feature] = &[
+    #[cfg(feature = "codepoints-jisx0201")]
+    Feature::Jisx0201,
+    #[cfg(feature = "codepoints-jisx0208")]
+    Feature::Jisx0208,
+    #[cfg(feature = "codepoints-jisx0208kanji")]
+    Feature::Jisx0208Kanji,
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    Feature::Jisx0213Kanji,
+    #[cfg(feature = "emoji")]
+    Feature::Emoji,
+    #[cfg(feature = "kanji-metadata")]
+    Feature::KanjiMetadata,
+    #[cfg(feature = "mmap")]
+    Feature::Mmap,
+    #[cfg(feature = "serde")]
+    Feature::Serde,
+    #[cfg(feature = "tokio")]
+    Feature::Tokio,
+    #[cfg(feature = "tracing")]
+    Feature::Tracing,
+];
+
+/// Returns the optional features this crate was compiled with, in
+/// [`Feature::ALL`] order.
+pub fn compiled_features() -> &'static [Feature] {
+    COMPILED
+}
+
+/// Returns `true` if this crate was compiled with `feature`.
+pub const fn has_feature(feature: Feature) -> bool {
+    match feature {
+        Feature::Jisx0201 => cfg!(feature = "codepoints-jisx0201"),
+        Feature::Jisx0208 => cfg!(feature = "codepoints-jisx0208"),
+        Feature::Jisx0208Kanji => cfg!(feature = "codepoints-jisx0208kanji"),
+        Feature::Jisx0213Kanji => cfg!(feature = "codepoints-jisx0213kanji"),
+        Feature::Emoji => cfg!(feature = "emoji"),
+        Feature::KanjiMetadata => cfg!(feature = "kanji-metadata"),
+        Feature::Mmap => cfg!(feature = "mmap"),
+        Feature::Serde => cfg!(feature = "serde"),
+        Feature::Tokio => cfg!(feature = "tokio"),
+        Feature::Tracing => cfg!(feature = "tracing"),
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiled_matches_has_feature() {
+        let expected: Vec<Feature> = Feature::ALL
+            .into_iter()
+            .filter(|&f| has_feature(f))
+            .collect();
+        assert_eq!(compiled_features(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_cargo_names_round_trip() {
+        for feature in Feature::ALL {
+            assert_eq!(Feature::from_cargo_name(feature.cargo_name()), feature);
+            assert_eq!(feature.to_string(), feature.cargo_name());
+        }
+    }
+
+    #[test]
+    fn test_manifest_features_round_trip() {
+        // Every optional feature in Cargo.toml, minus the default set and the
+        // `full` bundle, must have a variant.
+        let manifest = include_str!("../Cargo.toml");
+        let names: Vec<&str> = manifest
+            .lines()
+            .skip_while(|line| *line != "[features]")
+            .skip(1)
+            .take_while(|line| !line.starts_with('['))
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(name, _)| name)
+            .filter(|name| !matches!(*name, "default" | "codepoints" | "full"))
+            .collect();
+        assert_eq!(names.len(), Feature::ALL.len(), "{:?}", names);
+        for name in names {
+            assert_eq!(Feature::from_cargo_name(name).cargo_name(), name);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown feature name")]
+    fn test_unknown_cargo_name() {
+        Feature::from_cargo_name("codepoints-jisx0212");
+    }
+
+    #[cfg(not(any(
+        feature = "codepoints-jisx0201",
+        feature = "codepoints-jisx0208",
+        feature = "codepoints-jisx0208kanji",
+        feature = "codepoints-jisx0213kanji"
+    )))]
+    #[test]
+    fn test_default_features() {
+        assert!(!has_feature(Feature::Jisx0208));
+        assert!(!compiled_features().contains(&Feature::Jisx0201));
+    }
+
+    // Each composite API is gated on the item, so a build with any subset of
+    // the character-set features compiles; these check what such builds
+    // still offer.
+
+    #[cfg(all(
+        feature = "codepoints-jisx0213kanji",
+        not(feature = "codepoints-jisx0208")
+    ))]
+    #[test]
+    fn test_jisx0213kanji_without_jisx0208() {
+        use crate::{registry, CharClass};
+
+        assert!(crate::JisX0213Kanji::cached().contains("漢字𠮟"));
+        let sets = registry::which_sets('漢');
+        assert!(sets.contains(&"jisx0213kanji"));
+        assert!(!sets.contains(&"jisx0208"));
+        assert!(registry::which_sets('あ').is_empty());
+        assert!(registry::get("katakana_phonetic_extensions").is_none());
+
+        // The classifier follows Unicode blocks and needs no set feature.
+        assert_eq!(CharClass::of('漢' as u32), CharClass::Kanji);
+        assert_eq!(CharClass::of('あ' as u32), CharClass::Hiragana);
+        let classes = crate::CodePoints::from_string("漢あア").decompose();
+        assert_eq!(classes.len(), 3);
+    }
+
+    #[cfg(all(
+        feature = "codepoints-jisx0208kanji",
+        not(feature = "codepoints-jisx0208")
+    ))]
+    #[test]
+    fn test_jisx0208kanji_without_jisx0208() {
+        use crate::jisx0208kanji::{info, KanjiLevel};
+
+        assert!(crate::JisX0208Kanji::cached().contains("亜弌"));
+        assert_eq!(info('亜').map(|i| i.level), Some(KanjiLevel::Level1));
+        assert!(crate::registry::which_sets('亜').contains(&"jisx0208kanji"));
+        assert!(crate::registry::get("hiragana").is_none());
+    }
+
+    #[cfg(all(
+        feature = "codepoints-jisx0208",
+        not(feature = "codepoints-jisx0213kanji")
+    ))]
+    #[test]
+    fn test_jisx0208_without_jisx0213kanji() {
+        assert!(crate::Katakana::cached().contains("アヴ"));
+        assert!(crate::registry::get("katakana_phonetic_extensions").is_none());
+        assert_eq!(
+            crate::registry::required_features("katakana_phonetic_extensions"),
+            Some(&[Feature::Jisx0208, Feature::Jisx0213Kanji][..])
+        );
+    }
+
+    #[cfg(all(
+        feature = "codepoints-jisx0208",
+        not(feature = "codepoints-jisx0208kanji")
+    ))]
+    #[test]
+    fn test_jisx0208_without_kanji() {
+        assert!(crate::recipes::is_kana_with_prolonged("ラーメン"));
+        assert!(crate::registry::get("jisx0208kanji").is_none());
+        assert!(!crate::registry::which_sets('漢').contains(&"jisx0208kanji"));
+    }
+
+    #[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+    #[test]
+    fn test_jisx0208_with_kanji() {
+        let full = crate::JisX0208::with_kanji();
+        assert_eq!(
+            full,
+            &crate::JisX0208::cached()
+                .codepoints()
+                .union(crate::JisX0208Kanji::cached().codepoints())
+        );
+        assert!(std::ptr::eq(full, crate::JisX0208::with_kanji()));
+    }
+
+    #[cfg(feature = "full")]
+    #[test]
+    fn test_full_features() {
+        for feature in [
+            Feature::Jisx0201,
+            Feature::Jisx0208,
+            Feature::Jisx0208Kanji,
+            Feature::Jisx0213Kanji,
+            Feature::Emoji,
+        ] {
+            assert!(has_feature(feature), "{}", feature);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Vertical presentation forms and small form variants.
+//!
+//! Text extracted from vertically typeset documents often contains glyph
+//! variants such as `︒` (U+FE12) or `﹁` (U+FE41) that look like ordinary
+//! punctuation but belong to no JIS character set.  [`VerticalForms`] and
+//! [`SmallFormVariants`] identify them, and
+//! [`normalize_vertical_forms`](crate::conversion::normalize_vertical_forms)
+//! maps them back to their horizontal equivalents.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::conversion::normalize_vertical_forms;
+//! use japanese_codepoints::forms::VerticalForms;
+//!
+//! assert!(VerticalForms::codepoints().contains("﹁︒﹂"));
+//! assert_eq!(normalize_vertical_forms("﹁はい︒﹂"), "「はい。」");
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::data::vertical;
+use crate::CodePoints;
+
+fn keys(table: &[(u32, u32)]) -> CodePoints {
+    CodePoints::new(table.iter().map(|&(form, _)| form).collect())
+}
+
+/// The vertical presentation forms (U+FE10–U+FE19 and the vertical forms in
+/// U+FE30–U+FE48).
+#[derive(Debug, Clone, Copy)]
+pub struct VerticalForms;
+
+impl VerticalForms {
+    /// Number of characters in the set, checked against the data table at
+    /// compile time.
+    pub const COUNT: usize = vertical::VERTICAL_FORMS_COUNT;
+
+    /// Returns the set, initialized on first access.
+    pub fn codepoints() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(|| keys(vertical::VERTICAL_FORMS))
+    }
+}
+
+/// The Small Form Variants block (U+FE50–U+FE6B), e.g. `﹐` and `﹝`.
+#[derive(Debug, Clone, Copy)]
+pub struct SmallFormVariants;
+
+impl SmallFormVariants {
+    /// Number of characters in the set, checked against the data table at
+    /// compile time.
+    pub const COUNT: usize = vertical::SMALL_FORM_VARIANTS_COUNT;
+
+    /// Returns the set, initialized on first access.
+    pub fn codepoints() -> &'static CodePoints {
+        static INSTANCE: OnceLock<CodePoints> = OnceLock::new();
+        INSTANCE.get_or_init(|| keys(vertical::SMALL_FORM_VARIANTS))
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sets() {
+        assert_eq!(VerticalForms::codepoints().len(), 33);
+        assert_eq!(SmallFormVariants::codepoints().len(), 26);
+        assert!(VerticalForms::codepoints().contains("︐︑︒︙︵﹄﹈"));
+        assert!(!VerticalForms::codepoints().contains("﹅")); // sesame dot
+        assert!(SmallFormVariants::codepoints().contains("﹐﹑﹒﹫"));
+        assert!(!SmallFormVariants::codepoints().contains_char('\u{FE53}'));
+        assert!(VerticalForms::codepoints()
+            .intersection(SmallFormVariants::codepoints())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_tables_sorted() {
+        for table in [vertical::VERTICAL_FORMS, vertical::SMALL_FORM_VARIANTS] {
+            assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Iterator adapters that filter `char` streams by set membership.
+//!
+//! [`CodePointIterExt`] is implemented for every `Iterator<Item = char>`, so
+//! characters coming from a tokenizer, a decoder or a reader can be
+//! filtered or partitioned without first collecting them into a `String`.
+//! The adapters borrow the [`CodePoints`] set for their lifetime.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::iter::CodePointIterExt;
+//! use japanese_codepoints::CodePoints;
+//!
+//! let kana = CodePoints::from_char_range('ぁ'..='ん');
+//! let tokens = ["ひらがな", "と", "漢字"];
+//!
+//! let kept: String = tokens.iter().flat_map(|t| t.chars()).allowed_by(&kana).collect();
+//! assert_eq!(kept, "ひらがなと");
+//!
+//! let (kana_chars, rest) = "かな123".chars().partition_by_set(&kana);
+//! assert_eq!(kana_chars, ['か', 'な']);
+//! assert_eq!(rest, ['1', '2', '3']);
+//! ```
+//!
+//! Streams from [`std::io::BufRead`] compose the same way:
+//!
+//! ```rust
+//! use std::io::{BufRead, Cursor};
+//!
+//! use japanese_codepoints::iter::CodePointIterExt;
+//! use japanese_codepoints::CodePoints;
+//!
+//! let reader = Cursor::new("ｱｲｳ\nabc\n");
+//! let ascii = CodePoints::ascii_printable();
+//! let foreign: String = reader
+//!     .lines()
+//!     .map_while(Result::ok)
+//!     .flat_map(|line| line.chars().collect::<Vec<_>>())
+//!     .excluded_by(&ascii)
+//!     .collect();
+//! assert_eq!(foreign, "ｱｲｳ");
+//! ```
+
+use std::iter::FusedIterator;
+
+use crate::CodePoints;
+
+// ── adapters ──────────────────────────────────────────────────────────────────
+
+/// Iterator over the characters of `I` that are in a set, created by
+/// [`CodePointIterExt::allowed_by`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AllowedBy<'a, I> {
+    iter: I,
+    set: &'a CodePoints,
+}
+
+impl<I: Iterator<Item = char>> Iterator for AllowedBy<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let set = self.set;
+        self.iter.find(|&c| set.contains_char(c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for AllowedBy<'_, I> {
+    fn next_back(&mut self) -> Option<char> {
+        let set = self.set;
+        self.iter.rfind(|&c| set.contains_char(c))
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for AllowedBy<'_, I> {}
+
+/// Iterator over the characters of `I` that are not in a set, created by
+/// [`CodePointIterExt::excluded_by`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExcludedBy<'a, I> {
+    iter: I,
+    set: &'a CodePoints,
+}
+
+impl<I: Iterator<Item = char>> Iterator for ExcludedBy<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let set = self.set;
+        self.iter.find(|&c| !set.contains_char(c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for ExcludedBy<'_, I> {
+    fn next_back(&mut self) -> Option<char> {
+        let set = self.set;
+        self.iter.rfind(|&c| !set.contains_char(c))
+    }
+}
+
+impl<I: FusedIterator<Item = char>> FusedIterator for ExcludedBy<'_, I> {}
+
+// ── extension trait ───────────────────────────────────────────────────────────
+
+/// Set-membership adapters for any `Iterator<Item = char>`.
+///
+/// Bring the trait into scope with
+/// `use japanese_codepoints::iter::CodePointIterExt;`.
+pub trait CodePointIterExt: Iterator<Item = char> + Sized {
+    /// Keeps only the characters in `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::iter::CodePointIterExt;
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let digits = CodePoints::from_char_range('0'..='9');
+    /// assert_eq!("a1b2".chars().allowed_by(&digits).collect::<String>(), "12");
+    /// ```
+    fn allowed_by(self, set: &CodePoints) -> AllowedBy<'_, Self> {
+        AllowedBy { iter: self, set }
+    }
+
+    /// Keeps only the characters not in `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::iter::CodePointIterExt;
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let digits = CodePoints::from_char_range('0'..='9');
+    /// assert_eq!("a1b2".chars().excluded_by(&digits).collect::<String>(), "ab");
+    /// ```
+    fn excluded_by(self, set: &CodePoints) -> ExcludedBy<'_, Self> {
+        ExcludedBy { iter: self, set }
+    }
+
+    /// Splits the characters into those in `set` and those not in it,
+    /// preserving order within each.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::iter::CodePointIterExt;
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let digits = CodePoints::from_char_range('0'..='9');
+    /// let (inside, outside) = "a1b2".chars().partition_by_set(&digits);
+    /// assert_eq!(inside, ['1', '2']);
+    /// assert_eq!(outside, ['a', 'b']);
+    /// ```
+    fn partition_by_set(self, set: &CodePoints) -> (Vec<char>, Vec<char>) {
+        self.partition(|&c| set.contains_char(c))
+    }
+}
+
+impl<I: Iterator<Item = char>> CodePointIterExt for I {}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowed_and_excluded_are_complementary() {
+        let set = CodePoints::from_string("あいう𠮷");
+        let text = "あaい𠮷b\u{0}う";
+        let allowed: String = text.chars().allowed_by(&set).collect();
+        let excluded: String = text.chars().excluded_by(&set).collect();
+        assert_eq!(allowed, "あい𠮷う");
+        assert_eq!(excluded, "ab\u{0}");
+        assert_eq!(
+            text.chars().partition_by_set(&set),
+            (allowed.chars().collect(), excluded.chars().collect())
+        );
+    }
+
+    #[test]
+    fn test_double_ended_and_size_hint() {
+        let set = CodePoints::ascii_printable();
+        let mut iter = "aあbいc".chars().allowed_by(&set);
+        assert_eq!(iter.size_hint(), (0, "aあbいc".chars().size_hint().1));
+        assert_eq!(iter.next_back(), Some('c'));
+        assert_eq!(iter.next(), Some('a'));
+        assert_eq!(iter.next_back(), Some('b'));
+        assert_eq!(iter.next(), None);
+
+        let rev: String = "aあbいc".chars().excluded_by(&set).rev().collect();
+        assert_eq!(rev, "いあ");
+    }
+
+    #[test]
+    fn test_empty_and_chained() {
+        let empty = CodePoints::new(vec![]);
+        assert_eq!("abc".chars().allowed_by(&empty).count(), 0);
+        assert_eq!("abc".chars().excluded_by(&empty).count(), 3);
+
+        let lower = CodePoints::from_char_range('a'..='z');
+        let vowels = CodePoints::from_string("aeiou");
+        let consonants: String = "Hello, world"
+            .chars()
+            .allowed_by(&lower)
+            .excluded_by(&vowels)
+            .collect();
+        assert_eq!(consonants, "llwrld");
+    }
+}
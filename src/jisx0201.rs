@@ -8,6 +8,10 @@
 //! | [`Katakana`] | Halfwidth katakana (U+FF61–U+FF9F) |
 //! | [`JisX0201`] | Union of the two above |
 //!
+//! [`encode_katakana_bytes`] and [`decode_bytes`] convert between these
+//! characters and their single-byte codes, as used by Shift_JIS and by
+//! devices such as receipt printers that take JIS X 0201 directly.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -22,7 +26,7 @@
 macro_rules! charset {
     (
         $( #[$doc:meta] )*
-        $name:ident => $data:path
+        $name:ident => $data:path, $count:path, $chars:path, $label:literal
     ) => {
         $( #[$doc] )*
         pub struct $name {
@@ -30,6 +34,10 @@ macro_rules! charset {
         }
 
         impl $name {
+            /// Number of characters in this set, checked against the data
+            /// table at compile time.
+            pub const COUNT: usize = $count;
+
             /// Creates a new instance of this character set.
             pub fn new() -> Self {
                 Self {
@@ -48,10 +56,22 @@ macro_rules! charset {
             }
 
             /// Returns `true` if every character in `text` belongs to this set.
-            pub fn contains(&self, text: &str) -> bool {
+            pub fn contains(&self, text: impl AsRef<str>) -> bool {
                 self.codepoints.contains(text)
             }
 
+            /// Returns the human-readable name of this set, e.g. for error
+            /// messages.
+            pub fn name(&self) -> &'static str {
+                $label
+            }
+
+            /// Returns every character of this set in table order, as a
+            /// static slice converted at compile time.
+            pub fn chars() -> &'static [char] {
+                $chars
+            }
+
             /// Returns the underlying [`crate::CodePoints`] collection.
             pub fn codepoints(&self) -> &crate::CodePoints {
                 &self.codepoints
@@ -62,7 +82,15 @@ macro_rules! charset {
             /// Returns `Ok(())` on success, or a [`crate::ValidationError`]
             /// identifying the first character that does not belong.
             pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
-                self.codepoints.validate(text)
+                self.codepoints.validate_as(text, Some($label))
+            }
+
+            /// [`Self::validate`] for any [`crate::validation::ValidateInput`].
+            pub fn validate_input(
+                &self,
+                text: impl crate::validation::ValidateInput,
+            ) -> Result<(), crate::validation::ValidationError> {
+                text.validate_in(&self.codepoints, Some($label))
             }
         }
 
@@ -71,6 +99,8 @@ macro_rules! charset {
                 Self::new()
             }
         }
+
+        crate::codepoints::impl_character_set!($name);
     };
 }
 
@@ -94,7 +124,10 @@ charset! {
     /// assert!(!k.contains("あいうえお")); // fullwidth hiragana
     /// assert!(!k.contains("アイウエオ")); // fullwidth katakana
     /// ```
-    Katakana => crate::data::jisx0201::KATAKANA
+    Katakana => crate::data::jisx0201::KATAKANA,
+        crate::data::jisx0201::KATAKANA_COUNT,
+        crate::data::jisx0201::KATAKANA_CHARS,
+        "JIS X 0201 Katakana"
 }
 
 charset! {
@@ -117,7 +150,48 @@ charset! {
     /// assert!(l.contains("‾"));     // overline allowed
     /// assert!(!l.contains("\\")); // backslash NOT in JIS X 0201 Latin
     /// ```
-    LatinLetters => crate::data::jisx0201::LATIN_LETTERS
+    LatinLetters => crate::data::jisx0201::LATIN_LETTERS,
+        crate::data::jisx0201::LATIN_LETTERS_COUNT,
+        crate::data::jisx0201::LATIN_LETTERS_CHARS,
+        "JIS X 0201 Latin letters"
+}
+
+impl Katakana {
+    /// Converts halfwidth katakana in `s` to fullwidth and checks that the
+    /// result lies entirely within JIS X 0208 katakana.
+    ///
+    /// Conversion follows
+    /// [`halfwidth_to_fullwidth_katakana`](crate::conversion::halfwidth_to_fullwidth_katakana),
+    /// so sound marks are composed (`ｶﾞ` → `ガ`).  Characters whose image is
+    /// not a JIS X 0208 katakana — punctuation such as `｡`, the prolonged
+    /// sound mark `ｰ`, a sound mark with nothing to compose with, or any
+    /// non-katakana input — are reported with their position in `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0201::Katakana;
+    ///
+    /// assert_eq!(Katakana::convertible_to_fullwidth("ｶﾞｯｺｳ").unwrap(), "ガッコウ");
+    ///
+    /// let err = Katakana::convertible_to_fullwidth("ｱｲ｡").unwrap_err();
+    /// assert_eq!(err.code_point, 0xFF61); // ｡
+    /// assert_eq!(err.position, 2);
+    /// ```
+    pub fn convertible_to_fullwidth(s: &str) -> Result<String, crate::conversion::ConversionError> {
+        let katakana = crate::data::jisx0208::KATAKANA;
+        let mut out = String::with_capacity(s.len());
+        for (c, position, source) in crate::conversion::halfwidth_katakana_units(s) {
+            if katakana.binary_search(&(c as u32)).is_err() {
+                return Err(crate::conversion::ConversionError {
+                    code_point: source as u32,
+                    position,
+                });
+            }
+            out.push(c);
+        }
+        Ok(out)
+    }
 }
 
 // ── composite: full JIS X 0201 ────────────────────────────────────────────────
@@ -138,29 +212,38 @@ pub struct JisX0201 {
 }
 
 impl JisX0201 {
-    /// Creates a new JIS X 0201 character set by combining the Latin and
-    /// Katakana sub-tables.
-    pub fn new() -> Self {
-        use crate::data::jisx0201::*;
-        use std::collections::HashSet;
-
-        let mut all = HashSet::new();
-        all.extend(LATIN_LETTERS.iter());
-        all.extend(KATAKANA.iter());
+    /// Number of characters in the set (Latin letters plus katakana).
+    pub const COUNT: usize = crate::data::jisx0201::ALL_JISX0201_COUNT;
 
+    /// Creates a new JIS X 0201 character set from
+    /// [`ALL_JISX0201`](crate::data::jisx0201::ALL_JISX0201), the Latin and
+    /// Katakana sub-tables merged at compile time.
+    pub fn new() -> Self {
         Self {
-            codepoints: crate::CodePoints::new(all.into_iter().collect()),
+            codepoints: crate::CodePoints::from_slice(&crate::data::jisx0201::ALL_JISX0201),
         }
     }
 
+    /// Returns the human-readable name of this set, e.g. for error
+    /// messages.
+    pub fn name(&self) -> &'static str {
+        "JIS X 0201"
+    }
+
     /// Returns a cached static reference to the full JIS X 0201 character set.
     pub fn cached() -> &'static Self {
         static INSTANCE: std::sync::OnceLock<JisX0201> = std::sync::OnceLock::new();
         INSTANCE.get_or_init(Self::new)
     }
 
+    /// Returns every character of the set in ascending code-point order, as
+    /// a static slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        crate::data::jisx0201::ALL_JISX0201_CHARS
+    }
+
     /// Returns `true` if every character in `text` belongs to JIS X 0201.
-    pub fn contains(&self, text: &str) -> bool {
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
         self.codepoints.contains(text)
     }
 
@@ -171,7 +254,15 @@ impl JisX0201 {
 
     /// Validates that every character in `text` belongs to JIS X 0201.
     pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
-        self.codepoints.validate(text)
+        self.codepoints.validate_as(text, Some(self.name()))
+    }
+
+    /// [`Self::validate`] for any [`crate::validation::ValidateInput`].
+    pub fn validate_input(
+        &self,
+        text: impl crate::validation::ValidateInput,
+    ) -> Result<(), crate::validation::ValidationError> {
+        text.validate_in(&self.codepoints, Some(self.name()))
     }
 }
 
@@ -181,6 +272,145 @@ impl Default for JisX0201 {
     }
 }
 
+crate::codepoints::impl_character_set!(JisX0201);
+
+// ── single-byte codes ─────────────────────────────────────────────────────────
+
+/// First byte of the katakana area; U+FF61 `｡` encodes to it.
+const KATAKANA_FIRST_BYTE: u8 = 0xA1;
+const KATAKANA_LAST_BYTE: u8 = 0xDF;
+const HALFWIDTH_FIRST: u32 = 0xFF61;
+const YEN_BYTE: u8 = 0x5C;
+const OVERLINE_BYTE: u8 = 0x7E;
+
+/// A character with no JIS X 0201 byte, reported by
+/// [`encode_katakana_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodeError {
+    /// The offending code point of the input.
+    pub code_point: u32,
+    /// Zero-based character index of the offending code point in the input.
+    pub position: usize,
+}
+
+impl std::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ch = char::from_u32(self.code_point).unwrap_or('\u{FFFD}');
+        write!(
+            f,
+            "character '{}' (U+{:04X}) at position {} is not in JIS X 0201",
+            ch, self.code_point, self.position
+        )
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// A byte that is not a JIS X 0201 character code, reported by
+/// [`decode_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+    /// The offending byte.
+    pub byte: u8,
+    /// Zero-based index of the byte in the input.
+    pub position: usize,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "byte 0x{:02X} at position {} is not a JIS X 0201 character",
+            self.byte, self.position
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Returns the JIS X 0201 byte of `c`, if it has one.
+fn encode_char(c: char) -> Option<u8> {
+    match c {
+        '¥' => Some(YEN_BYTE),
+        '‾' => Some(OVERLINE_BYTE),
+        '\\' => None,
+        ' '..='}' => Some(c as u8),
+        '\u{FF61}'..='\u{FF9F}' => Some(KATAKANA_FIRST_BYTE + (c as u32 - HALFWIDTH_FIRST) as u8),
+        _ => None,
+    }
+}
+
+/// Returns the character of JIS X 0201 byte `b`, if it is one.
+fn decode_byte(b: u8) -> Option<char> {
+    match b {
+        YEN_BYTE => Some('¥'),
+        OVERLINE_BYTE => Some('‾'),
+        0x20..=0x7D => Some(b as char),
+        KATAKANA_FIRST_BYTE..=KATAKANA_LAST_BYTE => {
+            char::from_u32(HALFWIDTH_FIRST + u32::from(b - KATAKANA_FIRST_BYTE))
+        }
+        _ => None,
+    }
+}
+
+/// Encodes JIS X 0201 text as single-byte codes.
+///
+/// Halfwidth katakana U+FF61–U+FF9F become 0xA1–0xDF, and the Latin
+/// characters of [`LatinLetters`] become 0x20–0x7E, with `¥` at 0x5C and
+/// `‾` at 0x7E.  The bytes are also valid Shift_JIS.
+///
+/// # Errors
+///
+/// Returns an [`EncodeError`] for the first character outside JIS X 0201,
+/// including `\` and `~`, whose bytes mean `¥` and `‾`, and control
+/// characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0201::encode_katakana_bytes;
+///
+/// assert_eq!(encode_katakana_bytes("ｱｲｳ ¥100").unwrap(), b"\xB1\xB2\xB3 \x5C100");
+///
+/// let err = encode_katakana_bytes("ｱイ").unwrap_err();
+/// assert_eq!((err.code_point, err.position), (0x30A4, 1));
+/// ```
+pub fn encode_katakana_bytes(s: &str) -> Result<Vec<u8>, EncodeError> {
+    s.chars()
+        .enumerate()
+        .map(|(position, c)| {
+            encode_char(c).ok_or(EncodeError {
+                code_point: c as u32,
+                position,
+            })
+        })
+        .collect()
+}
+
+/// Decodes JIS X 0201 single-byte codes; the inverse of
+/// [`encode_katakana_bytes`].
+///
+/// # Errors
+///
+/// Returns a [`DecodeError`] for the first byte that is not a JIS X 0201
+/// character code: controls (0x00–0x1F, 0x7F), 0x80–0xA0 and 0xE0–0xFF.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0201::decode_bytes;
+///
+/// assert_eq!(decode_bytes(b"\xB6\xDE\x5C").unwrap(), "ｶﾞ¥");
+/// assert_eq!(decode_bytes(b"A\x81").unwrap_err().position, 1);
+/// ```
+pub fn decode_bytes(bytes: &[u8]) -> Result<String, DecodeError> {
+    bytes
+        .iter()
+        .enumerate()
+        .map(|(position, &byte)| decode_byte(byte).ok_or(DecodeError { byte, position }))
+        .collect()
+}
+
 // ── tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -221,6 +451,62 @@ mod tests {
         assert!(!full.contains("漢字"));
     }
 
+    #[test]
+    fn test_count_constants() {
+        assert_eq!(JisX0201::new().codepoints().len(), JisX0201::COUNT);
+        assert_eq!(Katakana::COUNT, 63);
+        assert_eq!(LatinLetters::COUNT, 95);
+    }
+
+    #[test]
+    fn test_convertible_to_fullwidth() {
+        assert!(crate::data::jisx0208::KATAKANA
+            .windows(2)
+            .all(|w| w[0] < w[1]));
+
+        assert_eq!(
+            Katakana::convertible_to_fullwidth("ﾊﾟﾋﾞｭｰ")
+                .unwrap_err()
+                .position,
+            5 // ｰ maps to ー, which is not JIS X 0208 katakana
+        );
+        assert_eq!(
+            Katakana::convertible_to_fullwidth("ｷｬﾍﾞﾂ").unwrap(),
+            "キャベツ"
+        );
+        assert_eq!(Katakana::convertible_to_fullwidth("").unwrap(), "");
+        assert_eq!(Katakana::convertible_to_fullwidth("ア").unwrap(), "ア");
+
+        for (i, punct) in ["｡", "｢", "｣", "､"].iter().enumerate() {
+            let err = Katakana::convertible_to_fullwidth(punct).unwrap_err();
+            assert_eq!(err.code_point, 0xFF61 + i as u32);
+            assert_eq!(err.position, 0);
+        }
+
+        // Orphan sound mark: nothing to compose with.
+        let err = Katakana::convertible_to_fullwidth("ﾞｱ").unwrap_err();
+        assert_eq!((err.code_point, err.position), (0xFF9E, 0));
+        let err = Katakana::convertible_to_fullwidth("ｱﾞ").unwrap_err();
+        assert_eq!((err.code_point, err.position), (0xFF9E, 1));
+        assert!(err.to_string().contains("U+FF9E"));
+
+        let err = Katakana::convertible_to_fullwidth("ｱA").unwrap_err();
+        assert_eq!((err.code_point, err.position), (0x41, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Katakana::cached().to_string(),
+            "JIS X 0201 Katakana (63 characters)"
+        );
+        assert_eq!(
+            JisX0201::cached().to_string(),
+            "JIS X 0201 (158 characters)"
+        );
+        assert_eq!(LatinLetters::cached().name(), "JIS X 0201 Latin letters");
+    }
+
     #[test]
     fn test_cached_identity() {
         assert!(std::ptr::eq(Katakana::cached(), Katakana::cached()));
@@ -235,4 +521,65 @@ mod tests {
         assert_eq!(err.code_point, 0x41); // 'A'
         assert_eq!(err.position, 2);
     }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let cp = JisX0201::cached().codepoints();
+        let bytes = cp.serialize_compact();
+        assert_eq!(&crate::CodePoints::deserialize_compact(&bytes).unwrap(), cp);
+        assert!(crate::CodePoints::deserialize_compact(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_byte_round_trip() {
+        let mut decodable = 0;
+        for b in 0..=u8::MAX {
+            match decode_bytes(&[b]) {
+                Ok(s) => {
+                    decodable += 1;
+                    assert!(JisX0201::cached().contains(&s), "0x{:02X}", b);
+                    assert_eq!(encode_katakana_bytes(&s).unwrap(), [b]);
+                }
+                Err(err) => {
+                    assert_eq!(
+                        err,
+                        DecodeError {
+                            byte: b,
+                            position: 0
+                        }
+                    );
+                    assert!(!(0x20..=0x7E).contains(&b) && !(0xA1..=0xDF).contains(&b));
+                }
+            }
+        }
+        assert_eq!(decodable, JisX0201::COUNT);
+
+        for &c in JisX0201::chars() {
+            let bytes = encode_katakana_bytes(&c.to_string()).unwrap();
+            assert_eq!(decode_bytes(&bytes).unwrap(), c.to_string());
+        }
+        assert_eq!(decode_bytes(b"\x7E\x5C").unwrap(), "‾¥");
+        assert_eq!(encode_katakana_bytes("").unwrap(), b"");
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let err = encode_katakana_bytes("ｶﾀｶﾅとカタカナ").unwrap_err();
+        assert_eq!(err.code_point, 0x3068); // と
+        assert_eq!(err.position, 4);
+        assert_eq!(
+            err.to_string(),
+            "character 'と' (U+3068) at position 4 is not in JIS X 0201"
+        );
+        assert_eq!(encode_katakana_bytes("ア").unwrap_err().position, 0);
+        assert_eq!(encode_katakana_bytes("a\\").unwrap_err().position, 1);
+        assert_eq!(encode_katakana_bytes("~").unwrap_err().code_point, 0x7E);
+        assert!(encode_katakana_bytes("ｱ\n").is_err());
+
+        let err = decode_bytes(b"\xB1\xE0").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "byte 0xE0 at position 1 is not a JIS X 0201 character"
+        );
+    }
 }
@@ -4,14 +4,24 @@
 //!
 //! | Type | Description |
 //! |---|---|
-//! | [`Hiragana`] | Hiragana (ひらがな), U+3041–U+3093 |
-//! | [`Katakana`] | Katakana (カタカナ), U+30A1–U+30F6 |
+//! | [`Hiragana`] | Hiragana (ひらがな), U+3041–U+3093 (83) |
+//! | [`Katakana`] | Katakana (カタカナ), U+30A1–U+30F6 (86, including ヴヵヶ) |
 //! | [`LatinLetters`] | Fullwidth Latin letters and digits |
 //! | [`GreekLetters`] | Uppercase and lowercase Greek letters |
 //! | [`CyrillicLetters`] | Uppercase and lowercase Cyrillic letters |
 //! | [`SpecialChars`] | Punctuation, symbols, arrows, stars … |
 //! | [`BoxDrawingChars`] | Box-drawing and line characters |
 //! | [`JisX0208`] | Union of all of the above |
+//! | [`NecSpecialChars`] | NEC row-13 extension (①, Ⅰ, ㍉, №, ℡ …), opt-in |
+//!
+//! Items that also draw on another standard are gated individually and
+//! say so in their documentation: `Hiragana::extended`, `Katakana::extended`
+//! and `KatakanaPhoneticExtensions` need `codepoints-jisx0213kanji`, and
+//! `JisX0208::with_kanji` needs `codepoints-jisx0208kanji`.
+//!
+//! [`iter_kuten_order`] lists the whole standard, kanji included, in
+//! kuten order; [`kuten`] and [`from_kuten`] convert between a character
+//! and its cell.
 //!
 //! Every type exposes the same four-method interface:
 //!
@@ -40,7 +50,7 @@
 macro_rules! charset {
     (
         $( #[$doc:meta] )*
-        $name:ident => $data:path
+        $name:ident => $data:path, $count:path, $chars:path, $label:literal
     ) => {
         $( #[$doc] )*
         pub struct $name {
@@ -48,6 +58,10 @@ macro_rules! charset {
         }
 
         impl $name {
+            /// Number of characters in this set, checked against the data
+            /// table at compile time.
+            pub const COUNT: usize = $count;
+
             /// Creates a new instance of this character set.
             pub fn new() -> Self {
                 Self {
@@ -66,10 +80,22 @@ macro_rules! charset {
             }
 
             /// Returns `true` if every character in `text` belongs to this set.
-            pub fn contains(&self, text: &str) -> bool {
+            pub fn contains(&self, text: impl AsRef<str>) -> bool {
                 self.codepoints.contains(text)
             }
 
+            /// Returns the human-readable name of this set, e.g. for error
+            /// messages.
+            pub fn name(&self) -> &'static str {
+                $label
+            }
+
+            /// Returns every character of this set in table order, as a
+            /// static slice converted at compile time.
+            pub fn chars() -> &'static [char] {
+                $chars
+            }
+
             /// Returns the underlying [`crate::CodePoints`] collection.
             pub fn codepoints(&self) -> &crate::CodePoints {
                 &self.codepoints
@@ -80,7 +106,15 @@ macro_rules! charset {
             /// Returns `Ok(())` on success, or a [`crate::ValidationError`]
             /// identifying the first character that does not belong.
             pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
-                self.codepoints.validate(text)
+                self.codepoints.validate_as(text, Some($label))
+            }
+
+            /// [`Self::validate`] for any [`crate::validation::ValidateInput`].
+            pub fn validate_input(
+                &self,
+                text: impl crate::validation::ValidateInput,
+            ) -> Result<(), crate::validation::ValidationError> {
+                text.validate_in(&self.codepoints, Some($label))
             }
         }
 
@@ -89,6 +123,8 @@ macro_rules! charset {
                 Self::new()
             }
         }
+
+        crate::codepoints::impl_character_set!($name);
     };
 }
 
@@ -97,7 +133,15 @@ macro_rules! charset {
 charset! {
     /// JIS X 0208 **Hiragana** (ひらがな) character set.
     ///
-    /// Contains all 83 hiragana characters from U+3041 to U+3093.
+    /// Contains all 83 hiragana characters from U+3041 (ぁ) to U+3093 (ん)
+    /// ([`Hiragana::EXPECTED_COUNT`]).
+    ///
+    /// # Boundaries
+    ///
+    /// * ゔ (U+3094), ゕ (U+3095) and ゖ (U+3096) are **not** included; they
+    ///   were added by JIS X 0213 (see `Hiragana::extended()`).
+    /// * The iteration marks ゝゞ and the sound marks ゛゜ are in
+    ///   [`SpecialChars`], not here.
     ///
     /// # Examples
     ///
@@ -108,13 +152,27 @@ charset! {
     /// assert!(h.contains("あいうえお"));
     /// assert!(!h.contains("アイウエオ")); // katakana
     /// ```
-    Hiragana => crate::data::jisx0208::HIRAGANA
+    Hiragana => crate::data::jisx0208::HIRAGANA,
+        crate::data::jisx0208::HIRAGANA_COUNT,
+        crate::data::jisx0208::HIRAGANA_CHARS,
+        "JIS X 0208 Hiragana"
 }
 
 charset! {
     /// JIS X 0208 **Katakana** (カタカナ) character set.
     ///
-    /// Contains katakana characters from U+30A1 to U+30F6.
+    /// Contains all 86 katakana characters from U+30A1 (ァ) to U+30F6 (ヶ)
+    /// ([`Katakana::EXPECTED_COUNT`]).
+    ///
+    /// # Boundaries
+    ///
+    /// * ヴ (U+30F4), ヵ (U+30F5) and ヶ (U+30F6) **are** included, so
+    ///   `"ヴァイオリン"` is valid.
+    /// * ヷヸヹヺ (U+30F7–U+30FA) and the small katakana ㇰ–ㇿ
+    ///   (U+31F0–U+31FF) are **not** included; they were added by JIS X 0213
+    ///   (see `Katakana::extended()`).
+    /// * The prolonged sound mark ー, the middle dot ・ and the iteration
+    ///   marks ヽヾ are in [`SpecialChars`], so `"コーヒー"` needs both sets.
     ///
     /// # Examples
     ///
@@ -125,7 +183,10 @@ charset! {
     /// assert!(k.contains("アイウエオ"));
     /// assert!(!k.contains("あいうえお")); // hiragana
     /// ```
-    Katakana => crate::data::jisx0208::KATAKANA
+    Katakana => crate::data::jisx0208::KATAKANA,
+        crate::data::jisx0208::KATAKANA_COUNT,
+        crate::data::jisx0208::KATAKANA_CHARS,
+        "JIS X 0208 Katakana"
 }
 
 charset! {
@@ -140,7 +201,10 @@ charset! {
     /// assert!(l.contains("ＡＢＣａｂｃ１２３"));
     /// assert!(!l.contains("ABC")); // halfwidth
     /// ```
-    LatinLetters => crate::data::jisx0208::LATIN_LETTERS
+    LatinLetters => crate::data::jisx0208::LATIN_LETTERS,
+        crate::data::jisx0208::LATIN_LETTERS_COUNT,
+        crate::data::jisx0208::LATIN_LETTERS_CHARS,
+        "JIS X 0208 Latin letters"
 }
 
 charset! {
@@ -153,7 +217,10 @@ charset! {
     ///
     /// assert!(GreekLetters::cached().contains("ΑΒΓαβγ"));
     /// ```
-    GreekLetters => crate::data::jisx0208::GREEK_LETTERS
+    GreekLetters => crate::data::jisx0208::GREEK_LETTERS,
+        crate::data::jisx0208::GREEK_LETTERS_COUNT,
+        crate::data::jisx0208::GREEK_LETTERS_CHARS,
+        "JIS X 0208 Greek letters"
 }
 
 charset! {
@@ -166,7 +233,10 @@ charset! {
     ///
     /// assert!(CyrillicLetters::cached().contains("АБВабв"));
     /// ```
-    CyrillicLetters => crate::data::jisx0208::CYRILLIC_LETTERS
+    CyrillicLetters => crate::data::jisx0208::CYRILLIC_LETTERS,
+        crate::data::jisx0208::CYRILLIC_LETTERS_COUNT,
+        crate::data::jisx0208::CYRILLIC_LETTERS_CHARS,
+        "JIS X 0208 Cyrillic letters"
 }
 
 charset! {
@@ -180,7 +250,10 @@ charset! {
     ///
     /// assert!(SpecialChars::cached().contains("、。☆★→←"));
     /// ```
-    SpecialChars => crate::data::jisx0208::SPECIAL_CHARS
+    SpecialChars => crate::data::jisx0208::SPECIAL_CHARS,
+        crate::data::jisx0208::SPECIAL_CHARS_COUNT,
+        crate::data::jisx0208::SPECIAL_CHARS_AS_CHARS,
+        "JIS X 0208 special characters"
 }
 
 charset! {
@@ -193,7 +266,178 @@ charset! {
     ///
     /// assert!(BoxDrawingChars::cached().contains("─│┌┐└┘├┤"));
     /// ```
-    BoxDrawingChars => crate::data::jisx0208::BOX_DRAWING_CHARS
+    BoxDrawingChars => crate::data::jisx0208::BOX_DRAWING_CHARS,
+        crate::data::jisx0208::BOX_DRAWING_CHARS_COUNT,
+        crate::data::jisx0208::BOX_DRAWING_CHARS_AS_CHARS,
+        "JIS X 0208 box-drawing characters"
+}
+
+impl Hiragana {
+    /// Number of characters in [`Hiragana::new`] (U+3041–U+3093); same as
+    /// [`Hiragana::COUNT`].
+    pub const EXPECTED_COUNT: usize = Self::COUNT;
+
+    /// Creates the JIS X 0208 hiragana set plus the JIS X 0213 additions
+    /// ゔ, ゕ and ゖ (86 characters; requires the `codepoints-jisx0213kanji`
+    /// feature).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::Hiragana;
+    ///
+    /// assert!(!Hiragana::cached().contains("ゔ"));
+    /// assert!(Hiragana::extended().contains("ゔ"));
+    /// ```
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    pub fn extended() -> Self {
+        Self {
+            codepoints: crate::CodePoints::from_slice(crate::data::jisx0208::HIRAGANA).union(
+                &crate::CodePoints::from_slice(crate::data::jisx0213kana::HIRAGANA_ADDITIONS),
+            ),
+        }
+    }
+}
+
+impl SpecialChars {
+    /// Returns the row 1 characters in cell order: entry *i* is JIS X 0208
+    /// 01-(*i* + 1), from `　` (01-01) to `◇` (01-94).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::SpecialChars;
+    ///
+    /// assert_eq!(SpecialChars::row1().len(), 94);
+    /// assert_eq!(SpecialChars::row1()[71], '∴'); // 01-72
+    /// ```
+    pub fn row1() -> &'static [char] {
+        &SPECIAL_CHARS_CHAR_ROWS.0
+    }
+
+    /// Returns the 53 assigned row 2 characters in cell order, from `◆`
+    /// (02-01) to `◯` (02-94).
+    ///
+    /// Row 2 is sparse: cells 01–14, 26–33, 42–48, 60–74, 82–89 and 94 are
+    /// assigned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::SpecialChars;
+    ///
+    /// assert_eq!(SpecialChars::row2().len(), 53);
+    /// assert!(SpecialChars::row2().contains(&'∵')); // 02-72
+    /// ```
+    pub fn row2() -> &'static [char] {
+        &SPECIAL_CHARS_CHAR_ROWS.1
+    }
+}
+
+/// [`SpecialChars::chars`] split into rows 1 and 2, at compile time.
+static SPECIAL_CHARS_CHAR_ROWS: (
+    [char; crate::data::jisx0208::SPECIAL_CHARS_ROW1_COUNT],
+    [char; crate::data::jisx0208::SPECIAL_CHARS_ROW2_COUNT],
+) = (
+    crate::data::to_chars(&SPECIAL_CHARS_ROWS.0),
+    crate::data::to_chars(&SPECIAL_CHARS_ROWS.1),
+);
+
+impl Katakana {
+    /// Number of characters in [`Katakana::new`] (U+30A1–U+30F6); same as
+    /// [`Katakana::COUNT`].
+    pub const EXPECTED_COUNT: usize = Self::COUNT;
+
+    /// Creates the JIS X 0208 katakana set plus the JIS X 0213 additions
+    /// ヷヸヹヺ and the small katakana ㇰ–ㇿ (106 characters; requires the
+    /// `codepoints-jisx0213kanji` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::Katakana;
+    ///
+    /// assert!(!Katakana::cached().contains("ヷ"));
+    /// assert!(Katakana::extended().contains("ヷㇰ"));
+    /// ```
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    pub fn extended() -> Self {
+        Self {
+            codepoints: crate::CodePoints::from_slice(crate::data::jisx0208::KATAKANA).union(
+                &crate::CodePoints::from_slice(crate::data::jisx0213kana::KATAKANA_ADDITIONS),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "codepoints-jisx0213kanji")]
+charset! {
+    /// **Katakana Phonetic Extensions** (U+31F0–U+31FF): the small katakana
+    /// ㇰ–ㇿ added by JIS X 0213 for Ainu (requires the
+    /// `codepoints-jisx0213kanji` feature).
+    ///
+    /// # Combining marks
+    ///
+    /// JIS X 0213 also defines ㇷ゚, which Unicode encodes as ㇷ (U+31F7)
+    /// followed by the combining semi-voiced mark U+309A.  Membership is
+    /// checked per code point, so U+309A must be allowed as well; use
+    /// [`KatakanaPhoneticExtensions::with_combining_mark`] for that.
+    ///
+    /// To accept these alongside ordinary katakana, union with
+    /// [`Katakana`] or use [`Katakana::extended`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::KatakanaPhoneticExtensions;
+    ///
+    /// let ext = KatakanaPhoneticExtensions::cached();
+    /// assert!(ext.contains("ㇱㇷㇿ"));
+    /// assert!(!ext.contains("ㇷ\u{309A}"));
+    /// assert!(KatakanaPhoneticExtensions::with_combining_mark().contains("ㇷ\u{309A}"));
+    /// ```
+    KatakanaPhoneticExtensions => crate::data::jisx0213kana::PHONETIC_EXTENSIONS,
+        crate::data::jisx0213kana::PHONETIC_EXTENSIONS_COUNT,
+        crate::data::jisx0213kana::PHONETIC_EXTENSIONS_CHARS,
+        "JIS X 0213 Katakana Phonetic Extensions"
+}
+
+#[cfg(feature = "codepoints-jisx0213kanji")]
+impl KatakanaPhoneticExtensions {
+    /// Returns the set plus the combining semi-voiced mark U+309A, so that
+    /// ㇷ゚ (U+31F7 U+309A) is accepted.
+    pub fn with_combining_mark() -> crate::CodePoints {
+        let mut codepoints = crate::data::jisx0213kana::PHONETIC_EXTENSIONS.to_vec();
+        codepoints.push(crate::data::jisx0213kana::COMBINING_SEMI_VOICED_MARK);
+        crate::CodePoints::new(codepoints)
+    }
+}
+
+// ── vendor extension: NEC row 13 ──────────────────────────────────────────────
+
+charset! {
+    /// **NEC special characters**: the 83 characters NEC assigned to the
+    /// otherwise empty JIS X 0208 row 13 — circled numbers ①–⑳, Roman
+    /// numerals Ⅰ–Ⅹ, squared units such as ㍉㌔㌢, ㊤㊥, №, ℡ and a few
+    /// mathematical operators.
+    ///
+    /// These are not part of JIS X 0208 and are deliberately left out of
+    /// [`JisX0208`].  CP932 (Windows-31J) includes them; use
+    /// [`JisX0208::cp932`] to accept both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::{JisX0208, NecSpecialChars};
+    ///
+    /// assert!(NecSpecialChars::cached().contains("①Ⅳ㍉"));
+    /// assert!(!JisX0208::cached().contains("①"));
+    /// assert!(JisX0208::cp932().contains("①の㈱"));
+    /// ```
+    NecSpecialChars => crate::data::nec::NEC_ROW13,
+        crate::data::nec::NEC_ROW13_COUNT,
+        crate::data::nec::NEC_ROW13_CHARS,
+        "NEC special characters"
 }
 
 // ── composite: full JIS X 0208 (non-kanji) ────────────────────────────────────
@@ -218,35 +462,123 @@ pub struct JisX0208 {
 }
 
 impl JisX0208 {
-    /// Creates a new JIS X 0208 (non-kanji) character set by combining all
-    /// sub-tables.
-    pub fn new() -> Self {
-        use crate::data::jisx0208::*;
-        use std::collections::HashSet;
-
-        let mut all = HashSet::new();
-        all.extend(HIRAGANA.iter());
-        all.extend(KATAKANA.iter());
-        all.extend(LATIN_LETTERS.iter());
-        all.extend(GREEK_LETTERS.iter());
-        all.extend(CYRILLIC_LETTERS.iter());
-        all.extend(SPECIAL_CHARS.iter());
-        all.extend(BOX_DRAWING_CHARS.iter());
+    /// Number of characters in the set (all seven sub-tables).
+    pub const COUNT: usize = crate::data::jisx0208::ALL_JISX0208_COUNT;
 
+    /// Creates a new JIS X 0208 (non-kanji) character set.
+    ///
+    /// The sub-tables are merged into
+    /// [`ALL_JISX0208`](crate::data::jisx0208::ALL_JISX0208) at compile time,
+    /// but every call still builds a fresh hash set.  Prefer
+    /// [`JisX0208::cached`] unless an owned instance is required.
+    pub fn new() -> Self {
         Self {
-            codepoints: crate::CodePoints::new(all.into_iter().collect()),
+            codepoints: crate::CodePoints::from_slice(&crate::data::jisx0208::ALL_JISX0208),
         }
     }
 
+    /// Returns the human-readable name of this set, e.g. for error
+    /// messages.
+    pub fn name(&self) -> &'static str {
+        "JIS X 0208 non-kanji"
+    }
+
     /// Returns a cached static reference to the full JIS X 0208 (non-kanji)
     /// character set.
+    ///
+    /// This is the recommended way to obtain the set: it is built once on
+    /// first access and shared afterwards.
     pub fn cached() -> &'static Self {
         static INSTANCE: std::sync::OnceLock<JisX0208> = std::sync::OnceLock::new();
         INSTANCE.get_or_init(Self::new)
     }
 
+    /// Returns every character of the set in ascending code-point order, as
+    /// a static slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        crate::data::jisx0208::ALL_JISX0208_CHARS
+    }
+
+    /// Returns the seven constituent sets in JIS row order: special
+    /// characters, Latin, hiragana, katakana, Greek, Cyrillic and box
+    /// drawing.
+    ///
+    /// The sets are pairwise disjoint and their union is [`JisX0208`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::JisX0208;
+    ///
+    /// let total: usize = JisX0208::component_sets().iter().map(|s| s.len()).sum();
+    /// assert_eq!(total, JisX0208::cached().codepoints().len());
+    /// ```
+    pub fn component_sets() -> [&'static crate::CodePoints; 7] {
+        [
+            SpecialChars::cached().codepoints(),
+            LatinLetters::cached().codepoints(),
+            Hiragana::cached().codepoints(),
+            Katakana::cached().codepoints(),
+            GreekLetters::cached().codepoints(),
+            CyrillicLetters::cached().codepoints(),
+            BoxDrawingChars::cached().codepoints(),
+        ]
+    }
+
+    /// Returns the non-kanji double-byte repertoire of CP932 (Windows-31J):
+    /// JIS X 0208 plus [`NecSpecialChars`].
+    ///
+    /// The IBM extensions of CP932 are kanji apart from a handful of
+    /// symbols and are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::{JisX0208, NecSpecialChars};
+    ///
+    /// let cp932 = JisX0208::cp932();
+    /// assert!(cp932.contains("あア①㍉"));
+    /// assert!(cp932.is_superset_of(JisX0208::cached().codepoints()));
+    /// assert!(cp932.is_superset_of(NecSpecialChars::cached().codepoints()));
+    /// ```
+    pub fn cp932() -> &'static crate::CodePoints {
+        static CP932: std::sync::OnceLock<crate::CodePoints> = std::sync::OnceLock::new();
+        CP932.get_or_init(|| {
+            JisX0208::cached()
+                .codepoints()
+                .union(NecSpecialChars::cached().codepoints())
+        })
+    }
+
+    /// Returns the whole JIS X 0208 repertoire: the non-kanji characters
+    /// plus the 6 355 Level 1 and 2 kanji (requires the
+    /// `codepoints-jisx0208kanji` feature).
+    ///
+    /// Built from
+    /// [`ALL_JISX0208_WITH_KANJI`](crate::data::jisx0208::ALL_JISX0208_WITH_KANJI),
+    /// which is merged and sorted at compile time, so no run-time union is
+    /// needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::JisX0208;
+    ///
+    /// let full = JisX0208::with_kanji();
+    /// assert!(full.contains("漢字とカナ、ＡＢＣ。"));
+    /// assert!(!full.contains("𠮷"));
+    /// assert_eq!(full.len(), JisX0208::COUNT + 6355);
+    /// ```
+    #[cfg(feature = "codepoints-jisx0208kanji")]
+    pub fn with_kanji() -> &'static crate::CodePoints {
+        static FULL: std::sync::OnceLock<crate::CodePoints> = std::sync::OnceLock::new();
+        FULL.get_or_init(|| {
+            crate::CodePoints::from_slice(&crate::data::jisx0208::ALL_JISX0208_WITH_KANJI)
+        })
+    }
+
     /// Returns `true` if every character in `text` belongs to JIS X 0208.
-    pub fn contains(&self, text: &str) -> bool {
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
         self.codepoints.contains(text)
     }
 
@@ -257,7 +589,79 @@ impl JisX0208 {
 
     /// Validates that every character in `text` belongs to JIS X 0208.
     pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
-        self.codepoints.validate(text)
+        self.codepoints.validate_as(text, Some(self.name()))
+    }
+
+    /// [`Self::validate`] for any [`crate::validation::ValidateInput`].
+    pub fn validate_input(
+        &self,
+        text: impl crate::validation::ValidateInput,
+    ) -> Result<(), crate::validation::ValidationError> {
+        text.validate_in(&self.codepoints, Some(self.name()))
+    }
+
+    /// Returns the non-kanji characters of JIS X 0208 rows (区)
+    /// `from_ku..=to_ku`.
+    ///
+    /// | Row | Contents |
+    /// |---|---|
+    /// | 1–2 | [`SpecialChars`] |
+    /// | 3 | [`LatinLetters`] |
+    /// | 4 | [`Hiragana`] |
+    /// | 5 | [`Katakana`] |
+    /// | 6 | [`GreekLetters`] |
+    /// | 7 | [`CyrillicLetters`] |
+    /// | 8 | [`BoxDrawingChars`] |
+    ///
+    /// Rows without non-kanji characters contribute nothing, and an empty
+    /// set is returned when `from_ku > to_ku`.  For the kanji rows 16–84, see
+    /// `JisX0208Kanji::rows_in_range` (`codepoints-jisx0208kanji` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208::{Hiragana, JisX0208, Katakana};
+    ///
+    /// let kana = JisX0208::rows_in_range(4, 5);
+    /// assert!(kana.contains("あア"));
+    /// assert_eq!(kana.len(), Hiragana::EXPECTED_COUNT + Katakana::EXPECTED_COUNT);
+    /// ```
+    pub fn rows_in_range(from_ku: u8, to_ku: u8) -> crate::CodePoints {
+        let mut codepoints = Vec::new();
+        for ku in from_ku..=to_ku {
+            codepoints.extend_from_slice(row(ku));
+        }
+        crate::CodePoints::new(codepoints)
+    }
+}
+
+/// [`SPECIAL_CHARS`](crate::data::jisx0208::SPECIAL_CHARS) split into rows 1
+/// and 2, at compile time.
+const SPECIAL_CHARS_ROWS: (
+    [u32; crate::data::jisx0208::SPECIAL_CHARS_ROW1_COUNT],
+    [u32; crate::data::jisx0208::SPECIAL_CHARS_ROW2_COUNT],
+) = (
+    crate::data::sub_table(crate::data::jisx0208::SPECIAL_CHARS, 0),
+    crate::data::sub_table(
+        crate::data::jisx0208::SPECIAL_CHARS,
+        crate::data::jisx0208::SPECIAL_CHARS_ROW1_COUNT,
+    ),
+);
+
+/// Returns the data for JIS X 0208 row `ku` (non-kanji rows only).
+fn row(ku: u8) -> &'static [u32] {
+    use crate::data::jisx0208::*;
+
+    match ku {
+        1 => &SPECIAL_CHARS_ROWS.0,
+        2 => &SPECIAL_CHARS_ROWS.1,
+        3 => LATIN_LETTERS,
+        4 => HIRAGANA,
+        5 => KATAKANA,
+        6 => GREEK_LETTERS,
+        7 => CYRILLIC_LETTERS,
+        8 => BOX_DRAWING_CHARS,
+        _ => &[],
     }
 }
 
@@ -267,12 +671,243 @@ impl Default for JisX0208 {
     }
 }
 
+crate::codepoints::impl_character_set!(JisX0208);
+
+// ── kuten order ───────────────────────────────────────────────────────────────
+
+/// Iterates over the whole JIS X 0208 standard in kuten order, yielding the
+/// row (区), cell (点) and character of every assigned cell.
+///
+/// Rows 1–8 (symbols, Latin letters, kana, Greek, Cyrillic, box drawing)
+/// come first, then the kanji of rows 16–84, so the output lists the
+/// characters as the printed code tables do.  The kanji are included
+/// without the `codepoints-jisx0208kanji` feature; NEC row 13 is not.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208::iter_kuten_order;
+///
+/// let mut cells = iter_kuten_order();
+/// assert_eq!(cells.next(), Some((1, 1, '\u{3000}')));
+/// assert_eq!(cells.next(), Some((1, 2, '、')));
+/// assert_eq!(iter_kuten_order().count(), 524 + 6355);
+/// assert_eq!(iter_kuten_order().last(), Some((84, 6, '熙')));
+/// ```
+pub fn iter_kuten_order() -> impl Iterator<Item = (u8, u8, char)> {
+    crate::data::jisx0208::kuten_order()
+        .filter_map(|(ku, ten, cp)| char::from_u32(cp).map(|c| (ku, ten, c)))
+}
+
+/// Returns the row (区) and cell (点) of `c`, or `None` if it is not a JIS
+/// X 0208 character.  Kanji are included, as in [`iter_kuten_order`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208::kuten;
+///
+/// assert_eq!(kuten('あ'), Some((4, 2)));
+/// assert_eq!(kuten('亜'), Some((16, 1)));
+/// assert_eq!(kuten('①'), None); // NEC row 13
+/// ```
+pub fn kuten(c: char) -> Option<(u8, u8)> {
+    crate::data::jisx0208::kuten_of(c as u32)
+}
+
+/// Returns the JIS X 0208 character at row `ku`, cell `ten`, or `None` if
+/// that cell is unassigned; the inverse of [`kuten`].
+///
+/// Covers rows 1–8 and the kanji rows 16–84 (see
+/// `jisx0208kanji::from_kuten` for kanji only).
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208::{from_kuten, kuten};
+///
+/// assert_eq!(from_kuten(4, 2), Some('あ'));
+/// assert_eq!(from_kuten(2, 94), Some('◯'));
+/// assert_eq!(from_kuten(2, 15), None); // unassigned
+/// assert_eq!(from_kuten(16, 1), Some('亜'));
+/// assert_eq!(kuten('Ё').and_then(|(ku, ten)| from_kuten(ku, ten)), Some('Ё'));
+/// ```
+pub fn from_kuten(ku: u8, ten: u8) -> Option<char> {
+    crate::data::jisx0208::at_kuten(ku, ten).and_then(char::from_u32)
+}
+
 // ── tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_kuten_round_trip() {
+        let key = |ku: u8, ten: u8| Some((u16::from(ku) - 1) * 94 + u16::from(ten) - 1);
+        let mut assigned = 0;
+        for ku in 0..=95 {
+            for ten in 0..=95 {
+                let Some(c) = from_kuten(ku, ten) else {
+                    continue;
+                };
+                assigned += 1;
+                assert!(
+                    (1..=8).contains(&ku) || (16..=84).contains(&ku),
+                    "{}-{}",
+                    ku,
+                    ten
+                );
+                assert_eq!(kuten(c), Some((ku, ten)), "{}", c);
+                assert_eq!(crate::CodePoints::sort_key_kuten(c), key(ku, ten), "{}", c);
+                #[cfg(feature = "codepoints-jisx0208kanji")]
+                if ku >= 16 {
+                    assert_eq!(crate::jisx0208kanji::from_kuten(ku, ten), Some(c));
+                    assert_eq!(
+                        crate::jisx0208kanji::info(c).map(|i| i.kuten),
+                        Some((ku, ten))
+                    );
+                }
+            }
+        }
+        assert_eq!(assigned, 524 + 6355);
+        assert!(iter_kuten_order().all(|(ku, ten, c)| from_kuten(ku, ten) == Some(c)));
+
+        // Gaps within rows and cells past the end of rows 47 and 84.
+        for (ku, ten) in [
+            (2, 15),
+            (2, 93),
+            (3, 1),
+            (4, 84),
+            (8, 33),
+            (47, 52),
+            (84, 7),
+        ] {
+            assert_eq!(from_kuten(ku, ten), None, "{}-{}", ku, ten);
+        }
+        assert_eq!(from_kuten(u8::MAX, u8::MAX), None);
+    }
+
+    #[test]
+    fn test_iter_kuten_order_golden() {
+        let cells: Vec<(u8, u8, char)> = iter_kuten_order().collect();
+        let row = |ku: u8| -> Vec<(u8, char)> {
+            cells
+                .iter()
+                .filter(|&&(k, _, _)| k == ku)
+                .map(|&(_, ten, c)| (ten, c))
+                .collect()
+        };
+        type Cells = Vec<(u8, char)>;
+        let ends = |ku: u8| -> (Cells, Cells) {
+            let r = row(ku);
+            (r[..3].to_vec(), r[r.len() - 3..].to_vec())
+        };
+
+        assert_eq!(
+            ends(1),
+            (
+                vec![(1, '\u{3000}'), (2, '、'), (3, '。')],
+                vec![(92, '●'), (93, '◎'), (94, '◇')]
+            )
+        );
+        assert_eq!(
+            ends(2),
+            (
+                vec![(1, '◆'), (2, '□'), (3, '■')],
+                vec![(88, '‡'), (89, '¶'), (94, '◯')]
+            )
+        );
+        assert_eq!(
+            ends(3),
+            (
+                vec![(16, '０'), (17, '１'), (18, '２')],
+                vec![(88, 'ｘ'), (89, 'ｙ'), (90, 'ｚ')]
+            )
+        );
+        assert_eq!(
+            ends(4),
+            (
+                vec![(1, 'ぁ'), (2, 'あ'), (3, 'ぃ')],
+                vec![(81, 'ゑ'), (82, 'を'), (83, 'ん')]
+            )
+        );
+        assert_eq!(
+            ends(5),
+            (
+                vec![(1, 'ァ'), (2, 'ア'), (3, 'ィ')],
+                vec![(84, 'ヴ'), (85, 'ヵ'), (86, 'ヶ')]
+            )
+        );
+        assert_eq!(
+            ends(6),
+            (
+                vec![(1, 'Α'), (2, 'Β'), (3, 'Γ')],
+                vec![(54, 'χ'), (55, 'ψ'), (56, 'ω')]
+            )
+        );
+        assert_eq!(
+            ends(7),
+            (
+                vec![(1, 'А'), (2, 'Б'), (3, 'В')],
+                vec![(79, 'э'), (80, 'ю'), (81, 'я')]
+            )
+        );
+        assert_eq!(&row(7)[6..8], &[(7, 'Ё'), (8, 'Ж')]);
+        assert_eq!(
+            ends(8),
+            (
+                vec![(1, '─'), (2, '│'), (3, '┌')],
+                vec![(30, '┥'), (31, '┸'), (32, '╂')]
+            )
+        );
+        assert_eq!(
+            ends(16),
+            (
+                vec![(1, '亜'), (2, '唖'), (3, '娃')],
+                vec![(92, '淫'), (93, '胤'), (94, '蔭')]
+            )
+        );
+        assert_eq!(
+            ends(47),
+            (
+                vec![(1, '蓮'), (2, '連'), (3, '錬')],
+                vec![(49, '湾'), (50, '碗'), (51, '腕')]
+            )
+        );
+        assert_eq!(
+            ends(48),
+            (
+                vec![(1, '弌'), (2, '丐'), (3, '丕')],
+                vec![(92, '傅'), (93, '傴'), (94, '傲')]
+            )
+        );
+        assert_eq!(
+            ends(83),
+            (
+                vec![(1, '鵝'), (2, '鵞'), (3, '鵤')],
+                vec![(92, '龕'), (93, '龜'), (94, '龠')]
+            )
+        );
+        assert_eq!(
+            ends(84),
+            (
+                vec![(1, '堯'), (2, '槇'), (3, '遙')],
+                vec![(4, '瑤'), (5, '凜'), (6, '熙')]
+            )
+        );
+        assert!(row(9).is_empty() && row(13).is_empty() && row(15).is_empty());
+
+        // Strictly increasing kuten; every character exactly once.
+        assert!(cells
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        let kanji = crate::CodePoints::from_slice(crate::data::jisx0208kanji::JISX0208_CHARS);
+        let all = JisX0208::cached().codepoints().union(&kanji);
+        assert_eq!(cells.len(), all.len());
+        assert!(cells.iter().all(|&(_, _, c)| all.contains_char(c)));
+    }
+
     // ── leaf sets ───────────────────────────────────────────────────────
 
     #[test]
@@ -283,6 +918,26 @@ mod tests {
         assert!(!h.contains("アイウエオ"));
     }
 
+    #[test]
+    fn test_contains_accepts_any_string_type() {
+        use std::borrow::Cow;
+
+        let h = Hiragana::cached();
+        let owned = String::from("あい");
+        assert!(h.contains("あい"));
+        assert!(h.contains(&owned));
+        assert!(h.contains(owned));
+        assert!(h.contains(Cow::Borrowed("あ")));
+        assert!(h.contains(Box::<str>::from("い")));
+        assert!(!JisX0208::new().contains(Cow::<str>::Owned("a".into())));
+
+        fn all_in<S: AsRef<str>>(set: &Hiragana, items: &[S]) -> bool {
+            items.iter().all(|item| set.contains(item))
+        }
+        assert!(all_in(h, &[Cow::Borrowed("あ"), Cow::Owned("い".into())]));
+        assert!(!all_in(h, &["ア"]));
+    }
+
     #[test]
     fn test_katakana() {
         let k = Katakana::new();
@@ -327,6 +982,48 @@ mod tests {
         assert!(b.contains("─│┌┐└┘├┤"));
     }
 
+    // ── kana boundaries ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_hiragana_boundaries() {
+        let h = Hiragana::cached();
+        assert_eq!(h.codepoints().len(), Hiragana::EXPECTED_COUNT);
+        assert!(h.contains("ぁん"));
+        for c in ['ゔ', 'ゕ', 'ゖ', 'ゝ', 'ゞ', '゛', '゜'] {
+            assert!(!h.codepoints().contains_char(c), "{} should be excluded", c);
+        }
+    }
+
+    #[test]
+    fn test_katakana_boundaries() {
+        let k = Katakana::cached();
+        assert_eq!(k.codepoints().len(), Katakana::EXPECTED_COUNT);
+        assert!(k.contains("ァヴヵヶ"));
+        assert!(k.contains("ヴァイオリン"));
+        for c in ['ヷ', 'ヸ', 'ヹ', 'ヺ', 'ー', '・', 'ヽ', 'ヾ', 'ㇰ'] {
+            assert!(!k.codepoints().contains_char(c), "{} should be excluded", c);
+        }
+        assert!(!k.contains("コーヒー"));
+        assert!(crate::contains_all_in_any(
+            "コーヒー",
+            &[k.codepoints(), SpecialChars::cached().codepoints()]
+        ));
+    }
+
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    #[test]
+    fn test_extended_kana() {
+        let h = Hiragana::extended();
+        assert_eq!(h.codepoints().len(), Hiragana::EXPECTED_COUNT + 3);
+        assert!(h.contains("ゔゕゖ"));
+        assert!(Hiragana::cached().codepoints().is_subset_of(h.codepoints()));
+
+        let k = Katakana::extended();
+        assert_eq!(k.codepoints().len(), Katakana::EXPECTED_COUNT + 20);
+        assert!(k.contains("ヷヸヹヺㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿ"));
+        assert!(Katakana::cached().codepoints().is_subset_of(k.codepoints()));
+    }
+
     // ── composite ───────────────────────────────────────────────────────
 
     #[test]
@@ -343,6 +1040,218 @@ mod tests {
         assert!(!full.contains("漢字")); // kanji excluded
     }
 
+    // ── rows ────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_rows_in_range_single_rows() {
+        assert_eq!(JisX0208::rows_in_range(4, 4), *Hiragana::new().codepoints());
+        assert_eq!(JisX0208::rows_in_range(5, 5), *Katakana::new().codepoints());
+        assert_eq!(
+            JisX0208::rows_in_range(3, 3),
+            *LatinLetters::new().codepoints()
+        );
+        assert_eq!(
+            JisX0208::rows_in_range(8, 8),
+            *BoxDrawingChars::new().codepoints()
+        );
+        assert_eq!(
+            JisX0208::rows_in_range(1, 2),
+            *SpecialChars::new().codepoints()
+        );
+    }
+
+    #[test]
+    fn test_rows_in_range_split_special() {
+        let row1 = JisX0208::rows_in_range(1, 1);
+        let row2 = JisX0208::rows_in_range(2, 2);
+        assert_eq!(row1.len(), 94);
+        assert_eq!(row2.len(), 53);
+        assert!(row1.contains("　、。◇")); // 1-01, 1-02, 1-03, 1-94
+        assert!(row2.contains("◆※〒◯")); // 2-01, 2-08, 2-09, 2-94
+    }
+
+    #[test]
+    fn test_rows_in_range_bounds() {
+        assert_eq!(
+            JisX0208::rows_in_range(1, 94),
+            *JisX0208::new().codepoints()
+        );
+        assert_eq!(JisX0208::rows_in_range(0, 8), *JisX0208::new().codepoints());
+        assert!(JisX0208::rows_in_range(9, 15).is_empty());
+        assert!(JisX0208::rows_in_range(5, 4).is_empty());
+        assert!(JisX0208::rows_in_range(u8::MAX, u8::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_union_equals_components() {
+        let components = JisX0208::component_sets();
+        let union = components
+            .iter()
+            .fold(crate::CodePoints::new(vec![]), |acc, set| acc.union(set));
+        assert_eq!(union, *JisX0208::cached().codepoints());
+
+        for (i, a) in components.iter().enumerate() {
+            for b in &components[i + 1..] {
+                assert!(a.intersection(b).is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_kana_and_greek_disjoint() {
+        let sets = [
+            Hiragana::new().codepoints().clone(),
+            Katakana::new().codepoints().clone(),
+            GreekLetters::new().codepoints().clone(),
+        ];
+        assert!(crate::CodePoints::disjoint_partition(&sets));
+        assert!(crate::CodePoints::overlapping_sets(&sets).is_empty());
+    }
+
+    #[cfg(feature = "codepoints-jisx0208kanji")]
+    #[test]
+    fn test_disjoint_from_kanji() {
+        let sets = [
+            JisX0208::new().codepoints().clone(),
+            crate::jisx0208kanji::JisX0208Kanji::new()
+                .codepoints()
+                .clone(),
+        ];
+        assert!(crate::CodePoints::disjoint_partition(&sets));
+    }
+
+    #[test]
+    fn test_count_constants() {
+        assert_eq!(Hiragana::COUNT, Hiragana::new().codepoints().len());
+        assert_eq!(Katakana::COUNT, Katakana::new().codepoints().len());
+        assert_eq!(LatinLetters::COUNT, LatinLetters::new().codepoints().len());
+        assert_eq!(GreekLetters::COUNT, GreekLetters::new().codepoints().len());
+        assert_eq!(
+            CyrillicLetters::COUNT,
+            CyrillicLetters::new().codepoints().len()
+        );
+        assert_eq!(SpecialChars::COUNT, SpecialChars::new().codepoints().len());
+        assert_eq!(
+            BoxDrawingChars::COUNT,
+            BoxDrawingChars::new().codepoints().len()
+        );
+        assert_eq!(JisX0208::COUNT, JisX0208::new().codepoints().len());
+        assert_eq!(Hiragana::EXPECTED_COUNT, Hiragana::COUNT);
+    }
+
+    #[test]
+    fn test_static_chars() {
+        assert_eq!(Hiragana::chars().len(), Hiragana::COUNT);
+        assert_eq!(Hiragana::chars()[0], 'ぁ');
+        assert_eq!(BoxDrawingChars::chars().len(), BoxDrawingChars::COUNT);
+        assert_eq!(JisX0208::chars().len(), JisX0208::COUNT);
+        assert!(JisX0208::chars()
+            .iter()
+            .all(|&c| JisX0208::cached().codepoints().contains_char(c)));
+    }
+
+    #[test]
+    fn test_display_and_debug() {
+        let h = Hiragana::cached();
+        assert_eq!(h.name(), "JIS X 0208 Hiragana");
+        assert_eq!(h.to_string(), "JIS X 0208 Hiragana (83 characters)");
+        assert_eq!(
+            format!("{:?}", h),
+            r#"Hiragana { name: "JIS X 0208 Hiragana", count: 83, min: U+3041, max: U+3093 }"#
+        );
+        assert_eq!(
+            JisX0208::cached().to_string(),
+            "JIS X 0208 non-kanji (524 characters)"
+        );
+        assert!(format!("{:?}", JisX0208::cached()).starts_with("JisX0208 { name: "));
+    }
+
+    #[test]
+    fn test_character_set_trait() {
+        use crate::CharacterSet;
+
+        let sets: [&dyn CharacterSet; 3] =
+            [Hiragana::cached(), Katakana::cached(), JisX0208::cached()];
+        let rejected: Vec<_> = sets
+            .iter()
+            .filter(|set| set.validate("あア").is_err())
+            .map(|set| set.name())
+            .collect();
+        assert_eq!(rejected, ["JIS X 0208 Hiragana", "JIS X 0208 Katakana"]);
+        assert!(sets[2].contains("あア"));
+        assert_eq!(sets[0].codepoints().len(), Hiragana::COUNT);
+    }
+
+    #[test]
+    fn test_latin_fold_case() {
+        let upper = crate::CodePoints::from_string("ＡＢＣ１");
+        let folded = upper.fold_fullwidth_case();
+        assert_eq!(folded, crate::CodePoints::from_string("ＡＢＣａｂｃ１"));
+        assert!(folded.is_subset_of(LatinLetters::cached().codepoints()));
+        assert_eq!(
+            LatinLetters::cached().codepoints().fold_fullwidth_case(),
+            *LatinLetters::cached().codepoints()
+        );
+
+        // Normalize, then validate strictly.
+        let input = crate::conversion::to_fullwidth_upper("ｃａｂ１");
+        assert!(upper.contains(&input));
+    }
+
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    #[test]
+    fn test_katakana_phonetic_extensions() {
+        let ext = KatakanaPhoneticExtensions::cached();
+        for cp in 0x31F0..=0x31FF {
+            let c = char::from_u32(cp).unwrap();
+            assert!(ext.codepoints().contains_char(c), "missing U+{:04X}", cp);
+            assert!(!Katakana::cached().codepoints().contains_char(c));
+            assert!(Katakana::extended().codepoints().contains_char(c));
+        }
+        assert_eq!(ext.codepoints().len(), 16);
+        assert!(!ext.contains("\u{31EF}ア"));
+
+        let union = Katakana::cached().codepoints().union(ext.codepoints());
+        assert!(union.contains("アイヌㇰㇱ"));
+
+        assert!(!ext.contains("ㇷ\u{309A}"));
+        let with_mark = KatakanaPhoneticExtensions::with_combining_mark();
+        assert!(with_mark.contains("ㇷ\u{309A}ㇱ"));
+        assert_eq!(with_mark.len(), 17);
+    }
+
+    #[test]
+    fn test_nec_special_chars() {
+        let nec = NecSpecialChars::cached();
+        assert_eq!(NecSpecialChars::COUNT, 83);
+        assert_eq!(nec.codepoints().len(), 83);
+        assert!(nec.contains("①⑳ⅠⅩ㍉㌔㌢㊤㊥№℡㈱"));
+        assert!(!nec.contains("⓪"));
+
+        // strictness of the base set is preserved
+        for c in ["①", "Ⅰ", "㍉", "㊤", "№", "℡"] {
+            assert!(!JisX0208::cached().contains(c), "{}", c);
+            assert!(JisX0208::cp932().contains(c), "{}", c);
+        }
+        assert_eq!(JisX0208::new().codepoints().len(), 524);
+
+        // ≒ ≡ ∫ √ ⊥ ∠ ∵ ∩ ∪ duplicate row 2; ∮ ∑ ∟ ⊿ do not
+        let shared = nec
+            .codepoints()
+            .intersection(JisX0208::cached().codepoints());
+        assert_eq!(shared.len(), 9);
+        assert_eq!(JisX0208::cp932().len(), 524 + 83 - 9);
+        assert_eq!(JisX0208::cp932().validate("あ⓪").unwrap_err().position, 1);
+    }
+
+    #[test]
+    fn test_all_jisx0208_sorted() {
+        let all = &crate::data::jisx0208::ALL_JISX0208;
+        assert_eq!(all.len(), 524);
+        assert!(all.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(JisX0208::new().codepoints().len(), all.len());
+    }
+
     // ── cached identity ─────────────────────────────────────────────────
 
     #[test]
@@ -363,6 +1272,11 @@ mod tests {
         assert!(std::ptr::eq(JisX0208::cached(), JisX0208::cached()));
     }
 
+    #[test]
+    fn test_cached_identity_new_sets() {
+        assert!(std::ptr::eq(JisX0208::cp932(), JisX0208::cp932()));
+    }
+
     #[test]
     fn test_cached_equals_new() {
         assert_eq!(Hiragana::cached().codepoints(), &Hiragana::new().codepoints);
@@ -378,4 +1292,31 @@ mod tests {
         assert_eq!(err.code_point, 0x41); // 'A'
         assert_eq!(err.position, 2);
     }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let cp = JisX0208::cached().codepoints();
+        let bytes = cp.serialize_compact();
+        assert_eq!(&crate::CodePoints::deserialize_compact(&bytes).unwrap(), cp);
+        assert!(crate::CodePoints::deserialize_compact(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_group_by_unicode_block() {
+        let groups = JisX0208::new().codepoints().group_by_unicode_block();
+        for block in [
+            "Hiragana",
+            "Katakana",
+            "Greek and Coptic",
+            "Cyrillic",
+            "Halfwidth and Fullwidth Forms",
+        ] {
+            assert!(groups.contains_key(block), "missing {}", block);
+        }
+        // The block also holds ゛゜ゝゞ, which JIS X 0208 files under special chars.
+        assert!(Hiragana::new()
+            .codepoints()
+            .is_subset_of(&groups["Hiragana"]));
+        assert!(!groups.contains_key("Other"));
+    }
 }
@@ -6,6 +6,11 @@
 //! * **Level 2** — 3 390 characters (rows 48–84), sorted by radical.
 //!
 //! [`JisX0208Kanji`] covers both tiers (6 355 characters in total).
+//! [`JisX0208Kanji::edition`] records the [`Edition`] a set was built for.
+//! [`info`] gives the level and kuten of a kanji, and [`sort_by_kuten`]
+//! sorts characters into table order.  With the `kanji-metadata` feature,
+//! [`info`] also gives the stroke count and radical, and `sort_by_strokes`
+//! sorts by stroke count.
 //!
 //! # Examples
 //!
@@ -17,24 +22,127 @@
 //! assert!(!kanji.contains("ABC"));
 //! ```
 
+use crate::data::jisx0208kanji::{
+    kuten_at, FIRST_ROW, JISX0208_1997_ADDITIONS, JISX0208_CHARS, ROW_47_LEN,
+};
 use crate::CodePoints;
 
 /// JIS X 0208 Kanji character set (Level 1 + Level 2).
 ///
-/// Contains 6 355 kanji characters as specified in JIS X 0208.
-#[derive(Debug, Clone)]
+/// Contains 6 355 kanji characters as specified in JIS X 0208:1997 (and
+/// :1990, which has the same repertoire).
+#[derive(Clone)]
 pub struct JisX0208Kanji {
     codepoints: CodePoints,
+    edition: Edition,
+}
+
+/// An edition of JIS X 0208, selected with [`JisX0208Kanji::edition`].
+///
+/// Only repertoire changes are modelled; revised example glyphs do not
+/// change any code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+pub enum Edition {
+    /// JIS X 0208:1990 — 6 355 kanji, including 凜 and 熙 (84-05, 84-06)
+    /// added by this edition.
+    Jis1990,
+    /// JIS X 0208:1997 — the same 6 355 kanji; the edition documented the
+    /// unification rules without adding characters.  The default.
+    #[default]
+    Jis1997,
+}
+
+impl Edition {
+    /// Returns the stable name of the edition, its year (e.g. `"1990"`);
+    /// [`str::parse`] accepts it back.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Edition::Jis1990 => "1990",
+            Edition::Jis1997 => "1997",
+        }
+    }
+
+    /// Returns the code points this edition adds to and removes from the
+    /// JIS X 0208:1997 table.
+    fn delta(self) -> (&'static [u32], &'static [u32]) {
+        match self {
+            Edition::Jis1990 => (&[], JISX0208_1997_ADDITIONS),
+            Edition::Jis1997 => (&[], &[]),
+        }
+    }
+}
+
+impl std::str::FromStr for Edition {
+    type Err = crate::ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1990" => Ok(Edition::Jis1990),
+            "1997" => Ok(Edition::Jis1997),
+            _ => Err(crate::ParseNameError::new("JIS X 0208 edition", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Edition::Jis1990 => "JIS X 0208:1990",
+            Edition::Jis1997 => "JIS X 0208:1997",
+        })
+    }
 }
 
 impl JisX0208Kanji {
+    /// Number of kanji in the set, checked against the data table at
+    /// compile time.
+    pub const COUNT: usize = crate::data::jisx0208kanji::JISX0208_KANJI_COUNT;
+
     /// Creates a new JIS X 0208 Kanji character set.
     pub fn new() -> Self {
+        Self::edition(Edition::default())
+    }
+
+    /// Creates the kanji set of a specific JIS X 0208 edition.
+    ///
+    /// The 1990 and 1997 editions share one repertoire, so the sets are
+    /// equal; the edition is kept for [`Self::selected_edition`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208kanji::{Edition, JisX0208Kanji};
+    ///
+    /// let jis1990 = JisX0208Kanji::edition(Edition::Jis1990);
+    /// assert!(jis1990.contains("凜熙"));
+    /// assert_eq!(jis1990.selected_edition().to_string(), "JIS X 0208:1990");
+    /// ```
+    pub fn edition(edition: Edition) -> Self {
+        let (added, removed) = edition.delta();
+        let codepoints = JISX0208_CHARS
+            .iter()
+            .filter(|cp| !removed.contains(cp))
+            .chain(added)
+            .copied()
+            .collect();
         Self {
-            codepoints: CodePoints::from_slice(crate::data::jisx0208kanji::JISX0208_CHARS),
+            codepoints: CodePoints::new(codepoints),
+            edition,
         }
     }
 
+    /// Returns the edition this set was built for, e.g. for logging.
+    pub fn selected_edition(&self) -> Edition {
+        self.edition
+    }
+
+    /// Returns the human-readable name of this set, e.g. for error
+    /// messages.
+    pub fn name(&self) -> &'static str {
+        "JIS X 0208 kanji"
+    }
+
     /// Returns a cached static reference to the JIS X 0208 Kanji set.
     ///
     /// The instance is initialized on first access; subsequent calls return
@@ -44,9 +152,39 @@ impl JisX0208Kanji {
         INSTANCE.get_or_init(Self::new)
     }
 
+    /// Returns every kanji of the set in table (kuten) order, as a static
+    /// slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        crate::data::jisx0208kanji::JISX0208_KANJI_CHARS
+    }
+
+    /// Iterates over every kanji of the set in kuten order, with its row
+    /// (区) and cell (点): Level 1 by reading from 16-01 亜, then Level 2 by
+    /// radical from 48-01 弌 to 84-06 熙.
+    ///
+    /// For the whole standard, non-kanji rows included, see
+    /// `jisx0208::iter_kuten_order`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208kanji::JisX0208Kanji;
+    ///
+    /// let mut cells = JisX0208Kanji::iter_kuten_order();
+    /// assert_eq!(cells.next(), Some((16, 1, '亜')));
+    /// assert_eq!(cells.nth(74), Some((16, 76, '一')));
+    /// assert_eq!(JisX0208Kanji::iter_kuten_order().last(), Some((84, 6, '熙')));
+    /// ```
+    pub fn iter_kuten_order() -> impl Iterator<Item = (u8, u8, char)> {
+        Self::chars().iter().enumerate().map(|(i, &c)| {
+            let (ku, ten) = kuten_at(i);
+            (ku, ten, c)
+        })
+    }
+
     /// Returns `true` if every character in `text` is a JIS X 0208 kanji.
-    pub fn contains(&self, s: &str) -> bool {
-        self.codepoints.contains(s)
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.codepoints.contains(text)
     }
 
     /// Returns the underlying [`CodePoints`] collection.
@@ -68,8 +206,219 @@ impl JisX0208Kanji {
     /// Returns `Ok(())` on success, or a [`crate::ValidationError`]
     /// identifying the first non-kanji character.
     pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
-        self.codepoints.validate(text)
+        self.codepoints.validate_as(text, Some(self.name()))
+    }
+
+    /// [`Self::validate`] for any [`crate::validation::ValidateInput`].
+    pub fn validate_input(
+        &self,
+        text: impl crate::validation::ValidateInput,
+    ) -> Result<(), crate::validation::ValidationError> {
+        text.validate_in(&self.codepoints, Some(self.name()))
     }
+
+    /// Returns the kanji of JIS X 0208 rows (区) `from_ku..=to_ku`.
+    ///
+    /// Kanji occupy rows 16–47 (Level 1; row 47 has 51 characters) and
+    /// rows 48–84 (Level 2; row 84 has 6 characters).  Other rows contribute
+    /// nothing, and an empty set is returned when `from_ku > to_ku`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0208kanji::JisX0208Kanji;
+    ///
+    /// let level1 = JisX0208Kanji::rows_in_range(16, 47);
+    /// assert_eq!(level1.len(), 2965);
+    /// assert!(level1.contains("亜腕"));
+    /// assert!(!level1.contains("弌")); // first Level 2 kanji
+    /// ```
+    pub fn rows_in_range(from_ku: u8, to_ku: u8) -> CodePoints {
+        let data = crate::data::jisx0208kanji::JISX0208_CHARS;
+        let from = row_start(from_ku.max(FIRST_ROW));
+        let to = row_start(to_ku.saturating_add(1).min(LAST_ROW + 1));
+        data.get(from..to)
+            .map_or_else(|| CodePoints::new(vec![]), CodePoints::from_slice)
+    }
+}
+
+const LAST_ROW: u8 = 84;
+
+/// Index into the kuten-ordered data of the first kanji of row `ku`
+/// (`FIRST_ROW..=LAST_ROW + 1`).  Every row has 94 kanji except row 47.
+fn row_start(ku: u8) -> usize {
+    let rows_before = ku.saturating_sub(FIRST_ROW) as usize;
+    if ku <= 47 {
+        rows_before * 94
+    } else {
+        (rows_before - 1) * 94 + ROW_47_LEN
+    }
+    .min(crate::data::jisx0208kanji::JISX0208_CHARS.len())
+}
+
+// ── kanji metadata ────────────────────────────────────────────────────────────
+
+/// The JIS X 0208 kanji level (水準) of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum KanjiLevel {
+    /// Level 1 (第1水準), rows 16–47, ordered by reading.
+    Level1,
+    /// Level 2 (第2水準), rows 48–84, ordered by radical.
+    Level2,
+}
+
+impl KanjiLevel {
+    /// Returns the stable name of the level, `"level1"` or `"level2"`;
+    /// [`str::parse`] accepts it back.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            KanjiLevel::Level1 => "level1",
+            KanjiLevel::Level2 => "level2",
+        }
+    }
+}
+
+impl std::str::FromStr for KanjiLevel {
+    type Err = crate::ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "level1" => Ok(KanjiLevel::Level1),
+            "level2" => Ok(KanjiLevel::Level2),
+            _ => Err(crate::ParseNameError::new("kanji level", s)),
+        }
+    }
+}
+
+/// Structural information about a JIS X 0208 kanji, returned by [`info`].
+///
+/// `strokes` and `radical` need the `kanji-metadata` feature; the
+/// `data::jisx0208kanji_metadata` module documents their source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct KanjiInfo {
+    /// Total stroke count, following Japanese glyph forms (5 for 込).
+    #[cfg(feature = "kanji-metadata")]
+    pub strokes: u8,
+    /// Kangxi radical number, 1–214 (162 辵 for 込).
+    #[cfg(feature = "kanji-metadata")]
+    pub radical: u8,
+    /// The kanji level.
+    pub level: KanjiLevel,
+    /// Row (区) and cell (点), e.g. `(16, 1)` for 亜.
+    pub kuten: (u8, u8),
+}
+
+/// Returns the position of `cp` in the kuten-ordered kanji table.
+fn kuten_index(cp: u32) -> Option<usize> {
+    let (ku, ten) = crate::data::jisx0208::kuten_of(cp)?;
+    (ku >= FIRST_ROW).then(|| row_start(ku) + usize::from(ten - 1))
+}
+
+/// Returns the level and kuten of `c` (and, with the `kanji-metadata`
+/// feature, its stroke count and radical), or `None` if it is not a JIS X
+/// 0208 kanji.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208kanji::{info, KanjiLevel};
+///
+/// let ichi = info('一').unwrap();
+/// assert_eq!(ichi.level, KanjiLevel::Level1);
+/// assert_eq!(ichi.kuten, (16, 76));
+/// assert_eq!(info('弌').unwrap().kuten, (48, 1));
+/// assert_eq!(info('あ'), None);
+/// ```
+pub fn info(c: char) -> Option<KanjiInfo> {
+    let index = kuten_index(c as u32)?;
+    let kuten = kuten_at(index);
+    let level = if kuten.0 < 48 {
+        KanjiLevel::Level1
+    } else {
+        KanjiLevel::Level2
+    };
+    #[cfg(feature = "kanji-metadata")]
+    let &(strokes, radical) =
+        crate::data::jisx0208kanji_metadata::JISX0208_KANJI_METADATA.get(index)?;
+    Some(KanjiInfo {
+        #[cfg(feature = "kanji-metadata")]
+        strokes,
+        #[cfg(feature = "kanji-metadata")]
+        radical,
+        level,
+        kuten,
+    })
+}
+
+/// Returns the JIS X 0208 kanji at row `ku`, cell `ten`, or `None` if that
+/// position is outside rows 16–84 or holds no kanji; the inverse of
+/// [`info`].  `jisx0208::from_kuten` also covers the non-kanji rows 1–8.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208kanji::{from_kuten, info};
+///
+/// assert_eq!(from_kuten(16, 1), Some('亜'));
+/// assert_eq!(from_kuten(84, 6), Some('熙'));
+/// assert_eq!(from_kuten(47, 52), None); // row 47 ends at 腕 (47-51)
+/// assert_eq!(from_kuten(4, 2), None); // hiragana, not kanji
+/// assert_eq!(info('一').map(|i| from_kuten(i.kuten.0, i.kuten.1)), Some(Some('一')));
+/// ```
+pub fn from_kuten(ku: u8, ten: u8) -> Option<char> {
+    if ku < FIRST_ROW {
+        return None;
+    }
+    crate::data::jisx0208::at_kuten(ku, ten).and_then(char::from_u32)
+}
+
+/// Sorts `chars` into JIS X 0208 kuten order — by reading for Level 1,
+/// then by radical for Level 2.
+///
+/// Characters that are not JIS X 0208 kanji sort after all kanji, by code
+/// point.  The sort is stable.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208kanji::sort_by_kuten;
+///
+/// let mut chars = ['弌', 'x', '愛', '亜', 'あ'];
+/// sort_by_kuten(&mut chars);
+/// assert_eq!(chars, ['亜', '愛', '弌', 'x', 'あ']);
+/// ```
+pub fn sort_by_kuten(chars: &mut [char]) {
+    chars.sort_by_key(|&c| (kuten_index(c as u32).unwrap_or(usize::MAX), c));
+}
+
+/// Sorts `chars` by stroke count, then into kuten order within each count.
+///
+/// Characters that are not JIS X 0208 kanji sort after all kanji, by code
+/// point.  The sort is stable.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::jisx0208kanji::sort_by_strokes;
+///
+/// let mut chars = ['鬱', 'x', '込', '一', '人'];
+/// sort_by_strokes(&mut chars);
+/// assert_eq!(chars, ['一', '人', '込', '鬱', 'x']);
+/// ```
+#[cfg(feature = "kanji-metadata")]
+pub fn sort_by_strokes(chars: &mut [char]) {
+    use crate::data::jisx0208kanji_metadata::JISX0208_KANJI_METADATA;
+
+    chars.sort_by_key(|&c| {
+        let key = kuten_index(c as u32).and_then(|i| {
+            JISX0208_KANJI_METADATA
+                .get(i)
+                .map(|&(strokes, _)| (strokes, i))
+        });
+        (key.unwrap_or((u8::MAX, usize::MAX)), c)
+    });
 }
 
 impl Default for JisX0208Kanji {
@@ -78,6 +427,8 @@ impl Default for JisX0208Kanji {
     }
 }
 
+crate::codepoints::impl_character_set!(JisX0208Kanji);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +439,15 @@ mod tests {
         assert_eq!(kanji.codepoints_vec().len(), 6355);
     }
 
+    #[test]
+    fn test_count_constants() {
+        assert_eq!(JisX0208Kanji::COUNT, 6355);
+        assert_eq!(
+            JisX0208Kanji::new().codepoints_vec().len(),
+            JisX0208Kanji::COUNT
+        );
+    }
+
     #[test]
     fn test_default_equals_new() {
         assert_eq!(
@@ -96,6 +456,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_editions() {
+        let jis1990 = JisX0208Kanji::edition(Edition::Jis1990);
+        let jis1997 = JisX0208Kanji::edition(Edition::Jis1997);
+        assert_eq!(jis1990.selected_edition(), Edition::Jis1990);
+        assert_eq!(JisX0208Kanji::new().selected_edition(), Edition::Jis1997);
+        // Same repertoire; the 1990 additions are in both.
+        assert_eq!(jis1990.codepoints(), jis1997.codepoints());
+        assert_eq!(jis1997.codepoints(), JisX0208Kanji::cached().codepoints());
+        assert!(jis1990.contains("凜熙"));
+    }
+
+    #[test]
+    fn test_names() {
+        for level in [KanjiLevel::Level1, KanjiLevel::Level2] {
+            assert_eq!(level.as_str().parse(), Ok(level));
+        }
+        for edition in [Edition::Jis1990, Edition::Jis1997] {
+            assert_eq!(edition.as_str().parse(), Ok(edition));
+        }
+        assert_eq!(Edition::Jis1990.as_str(), "1990");
+        let err = "1983".parse::<Edition>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown JIS X 0208 edition `1983`");
+    }
+
     #[test]
     fn test_common_level1_kanji() {
         let kanji = JisX0208Kanji::new();
@@ -140,6 +525,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_all_in_cjk_unified_ideographs() {
+        let kanji = JisX0208Kanji::new();
+        let cp = kanji.codepoints();
+        assert_eq!(cp.count_codepoints_in_range(0x4E00, 0x9FFF), cp.len());
+        assert!(!cp.has_any_in_range(0x3400, 0x4DBF)); // Extension A
+    }
+
     #[test]
     fn test_contains_strings() {
         let kanji = JisX0208Kanji::new();
@@ -148,6 +541,154 @@ mod tests {
         assert!(!kanji.contains("亜ABC愛")); // mixed → false
     }
 
+    #[test]
+    fn test_info_spot_checks() {
+        let check = |c: char| info(c).map(|i| (i.level, i.kuten));
+        assert_eq!(check('亜'), Some((KanjiLevel::Level1, (16, 1))));
+        assert_eq!(check('一'), Some((KanjiLevel::Level1, (16, 76))));
+        assert_eq!(check('腕'), Some((KanjiLevel::Level1, (47, 51))));
+        assert_eq!(check('弌'), Some((KanjiLevel::Level2, (48, 1))));
+        assert_eq!(check('熙'), Some((KanjiLevel::Level2, (84, 6))));
+        assert_eq!(check('𠮟'), None);
+        assert_eq!(check('ア'), None);
+    }
+
+    #[test]
+    fn test_info_is_complete() {
+        let mut seen = std::collections::HashSet::new();
+        for &c in JisX0208Kanji::chars() {
+            let kanji = info(c).unwrap_or_else(|| panic!("no info for {}", c));
+            let (ku, ten) = kanji.kuten;
+            assert!((1..=94).contains(&ten));
+            assert_eq!(kanji.level == KanjiLevel::Level1, ku <= 47);
+            assert!(JisX0208Kanji::rows_in_range(ku, ku).contains_char(c));
+            assert!(
+                seen.insert(kanji.kuten),
+                "duplicate kuten {:?}",
+                kanji.kuten
+            );
+        }
+        assert_eq!(seen.len(), JisX0208Kanji::COUNT);
+    }
+
+    #[test]
+    fn test_iter_kuten_order() {
+        let cells: Vec<(u8, u8, char)> = JisX0208Kanji::iter_kuten_order().collect();
+        assert_eq!(cells.len(), 6355);
+        assert_eq!(cells[..3], [(16, 1, '亜'), (16, 2, '唖'), (16, 3, '娃')]);
+        // Row 47 stops at cell 51; Level 2 starts on the next row.
+        let level2 = cells.iter().position(|&(ku, _, _)| ku == 48).unwrap();
+        assert_eq!(
+            cells[level2 - 3..level2 + 3],
+            [
+                (47, 49, '湾'),
+                (47, 50, '碗'),
+                (47, 51, '腕'),
+                (48, 1, '弌'),
+                (48, 2, '丐'),
+                (48, 3, '丕')
+            ]
+        );
+        assert_eq!(
+            cells[cells.len() - 3..],
+            [(84, 4, '瑤'), (84, 5, '凜'), (84, 6, '熙')]
+        );
+        for &(ku, ten, c) in &cells {
+            assert_eq!(info(c).map(|i| i.kuten), Some((ku, ten)));
+            assert_eq!(from_kuten(ku, ten), Some(c));
+        }
+    }
+
+    #[test]
+    fn test_from_kuten_spot_checks() {
+        assert_eq!(from_kuten(16, 76), Some('一'));
+        assert_eq!(from_kuten(47, 51), Some('腕'));
+        assert_eq!(from_kuten(48, 1), Some('弌'));
+        for (ku, ten) in [
+            (15, 94),
+            (16, 0),
+            (16, 95),
+            (47, 94),
+            (84, 7),
+            (85, 1),
+            (0, 0),
+        ] {
+            assert_eq!(from_kuten(ku, ten), None, "{}-{}", ku, ten);
+        }
+        assert_eq!(from_kuten(u8::MAX, u8::MAX), None);
+    }
+
+    /// Every kuten position and every `char`: lookups never panic and each
+    /// `Some` round-trips through the inverse.  Slow in debug builds; run
+    /// with `cargo test --all-features -- --ignored`.
+    #[test]
+    #[ignore]
+    fn test_kuten_round_trip_exhaustive() {
+        let mut found = 0;
+        for ku in 0..=u8::MAX {
+            for ten in 0..=u8::MAX {
+                if let Some(c) = from_kuten(ku, ten) {
+                    assert_eq!(info(c).map(|i| i.kuten), Some((ku, ten)), "{}", c);
+                    assert_eq!(
+                        CodePoints::sort_key_kuten(c),
+                        Some((u16::from(ku) - 1) * 94 + u16::from(ten) - 1)
+                    );
+                    found += 1;
+                }
+            }
+        }
+        assert_eq!(found, JisX0208Kanji::COUNT);
+
+        for c in (0..=u32::from(char::MAX)).filter_map(char::from_u32) {
+            if let Some(kanji) = info(c) {
+                assert_eq!(from_kuten(kanji.kuten.0, kanji.kuten.1), Some(c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_kuten() {
+        let mut chars: Vec<char> = JisX0208Kanji::chars().iter().rev().copied().collect();
+        chars.push('a');
+        chars.insert(0, 'b');
+        sort_by_kuten(&mut chars);
+        assert_eq!(&chars[..JisX0208Kanji::COUNT], JisX0208Kanji::chars());
+        assert_eq!(&chars[JisX0208Kanji::COUNT..], ['a', 'b']);
+    }
+
+    #[cfg(feature = "kanji-metadata")]
+    #[test]
+    fn test_info_metadata() {
+        let ichi = info('一').unwrap();
+        assert_eq!((ichi.strokes, ichi.radical), (1, 1));
+        let utsu = info('鬱').unwrap();
+        assert_eq!((utsu.strokes, utsu.radical), (29, 192));
+        // Japanese forms: 辶 is three strokes, 艹 three.
+        assert_eq!(info('込').map(|i| (i.strokes, i.radical)), Some((5, 162)));
+        assert_eq!(info('花').map(|i| i.strokes), Some(7));
+
+        for &c in JisX0208Kanji::chars() {
+            let info = info(c).unwrap();
+            assert!((1..=30).contains(&info.strokes), "{}", c);
+            assert!((1..=214).contains(&info.radical), "{}", c);
+        }
+    }
+
+    #[cfg(feature = "kanji-metadata")]
+    #[test]
+    fn test_sort_by_strokes() {
+        let mut chars: Vec<char> = JisX0208Kanji::chars().iter().rev().copied().collect();
+        chars.push('a');
+        sort_by_strokes(&mut chars);
+        assert_eq!(chars.last(), Some(&'a'));
+        let kanji = &chars[..JisX0208Kanji::COUNT];
+        assert_eq!(kanji.first(), Some(&'一'));
+        assert!(kanji.windows(2).all(|w| {
+            let (a, b) = (info(w[0]).unwrap(), info(w[1]).unwrap());
+            (a.strokes, a.kuten) < (b.strokes, b.kuten)
+        }));
+    }
+
     #[test]
     fn test_cached_identity() {
         assert!(std::ptr::eq(
@@ -163,4 +704,58 @@ mod tests {
         assert_eq!(err.code_point, 0x41); // 'A'
         assert_eq!(err.position, 1);
     }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let cp = JisX0208Kanji::cached().codepoints();
+        let bytes = cp.serialize_compact();
+        assert_eq!(bytes.len(), 8 + 6355 * 4);
+        assert_eq!(&CodePoints::deserialize_compact(&bytes).unwrap(), cp);
+        assert!(CodePoints::deserialize_compact(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_group_by_unicode_block() {
+        let groups = JisX0208Kanji::new().codepoints().group_by_unicode_block();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups["CJK Unified Ideographs"].len(), 6355);
+    }
+
+    #[test]
+    fn test_within_cjk_unified_block() {
+        let block = CodePoints::from_unicode_block(crate::Block::CjkUnifiedIdeographs);
+        assert!(JisX0208Kanji::new()
+            .codepoints()
+            .difference(&block)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rows_in_range() {
+        let all = JisX0208Kanji::rows_in_range(16, 84);
+        assert_eq!(all, *JisX0208Kanji::new().codepoints());
+        assert_eq!(JisX0208Kanji::rows_in_range(0, u8::MAX), all);
+
+        let row16 = JisX0208Kanji::rows_in_range(16, 16);
+        assert_eq!(row16.len(), 94);
+        assert!(row16.contains("亜唖娃阿"));
+        assert_eq!(JisX0208Kanji::rows_in_range(47, 47).len(), 51);
+        let row48 = JisX0208Kanji::rows_in_range(48, 48);
+        assert_eq!(row48.len(), 94);
+        assert!(row48.contains("弌"));
+        let row84 = JisX0208Kanji::rows_in_range(84, 84);
+        assert_eq!(row84.len(), 6);
+        assert!(row84.contains("熙"));
+
+        assert_eq!(JisX0208Kanji::rows_in_range(48, 84).len(), 3390);
+        assert!(JisX0208Kanji::rows_in_range(1, 15).is_empty());
+        assert!(JisX0208Kanji::rows_in_range(85, 94).is_empty());
+        assert!(JisX0208Kanji::rows_in_range(20, 19).is_empty());
+
+        // Rows below the first kanji row must not underflow.
+        assert!(JisX0208Kanji::rows_in_range(0, 0).is_empty());
+        assert!(JisX0208Kanji::rows_in_range(0, 5).is_empty());
+        assert_eq!(JisX0208Kanji::rows_in_range(0, 16), row16);
+        assert!(JisX0208Kanji::rows_in_range(u8::MAX, u8::MAX).is_empty());
+    }
 }
@@ -10,6 +10,8 @@
 //! | Level 4 | 2 436 | New in JIS X 0213 (Plane 2) |
 //!
 //! [`JisX0213Kanji`] covers all four tiers (10 050 characters in total).
+//! [`JisX0213Kanji::edition`] selects the repertoire of a specific
+//! [`Edition`]; the default is JIS X 0213:2004.
 //!
 //! # Examples
 //!
@@ -21,21 +23,126 @@
 //! assert!(kanji.contains("堯槇遙瑤凜熙"));   // Level 3–4 additions
 //! ```
 
+use crate::data::jisx0213kanji::{JISX0213_2004_ADDITIONS, JISX0213_KANJI};
 use crate::CodePoints;
 
 /// JIS X 0213 Kanji character set (Level 1 + 2 + 3 + 4).
 ///
-/// Contains 10 050 kanji characters as specified in JIS X 0213:2004.
-#[derive(Debug, Clone)]
+/// Contains 10 050 kanji characters as specified in JIS X 0213:2004, or
+/// the repertoire of another [`Edition`] when built with [`Self::edition`].
+#[derive(Clone)]
 pub struct JisX0213Kanji {
     codepoints: CodePoints,
+    edition: Edition,
+}
+
+/// An edition of JIS X 0213, selected with [`JisX0213Kanji::edition`].
+///
+/// Only repertoire changes are modelled; revised example glyphs do not
+/// change any code point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[non_exhaustive]
+pub enum Edition {
+    /// JIS X 0213:2000 — 10 040 kanji.
+    Y2000,
+    /// JIS X 0213:2004 — 10 050 kanji: adds the ten plane-1 kanji of
+    /// [`JISX0213_2004_ADDITIONS`], such as 俱, 剝 and 𠮟.  The default.
+    #[default]
+    Y2004,
+}
+
+impl Edition {
+    /// Returns the stable name of the edition, its year (e.g. `"2000"`);
+    /// [`str::parse`] accepts it back.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Edition::Y2000 => "2000",
+            Edition::Y2004 => "2004",
+        }
+    }
+
+    /// Returns the code points this edition adds to and removes from the
+    /// JIS X 0213:2004 table.
+    fn delta(self) -> (&'static [u32], &'static [u32]) {
+        match self {
+            Edition::Y2000 => (&[], JISX0213_2004_ADDITIONS),
+            Edition::Y2004 => (&[], &[]),
+        }
+    }
+}
+
+impl std::str::FromStr for Edition {
+    type Err = crate::ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2000" => Ok(Edition::Y2000),
+            "2004" => Ok(Edition::Y2004),
+            _ => Err(crate::ParseNameError::new("JIS X 0213 edition", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Edition::Y2000 => "JIS X 0213:2000",
+            Edition::Y2004 => "JIS X 0213:2004",
+        })
+    }
 }
 
 impl JisX0213Kanji {
+    /// Number of kanji in the set, checked against the data table at
+    /// compile time.
+    pub const COUNT: usize = crate::data::jisx0213kanji::JISX0213_KANJI_COUNT;
+
     /// Creates a new JIS X 0213 Kanji character set.
     pub fn new() -> Self {
+        Self::edition(Edition::default())
+    }
+
+    /// Creates the kanji set of a specific JIS X 0213 edition.
+    ///
+    /// [`Self::COUNT`], [`Self::chars`] and [`Self::cached`] always follow
+    /// the default edition, JIS X 0213:2004.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0213kanji::{Edition, JisX0213Kanji};
+    ///
+    /// let y2000 = JisX0213Kanji::edition(Edition::Y2000);
+    /// assert_eq!(y2000.codepoints().len(), 10040);
+    /// assert!(!y2000.contains("𠮟"));
+    /// assert!(JisX0213Kanji::edition(Edition::Y2004).contains("𠮟"));
+    /// assert_eq!(y2000.selected_edition().to_string(), "JIS X 0213:2000");
+    /// ```
+    pub fn edition(edition: Edition) -> Self {
+        let (added, removed) = edition.delta();
+        let codepoints = JISX0213_KANJI
+            .iter()
+            .filter(|cp| !removed.contains(cp))
+            .chain(added)
+            .copied()
+            .collect();
         Self {
-            codepoints: CodePoints::from_slice(crate::data::jisx0213kanji::JISX0213_KANJI),
+            codepoints: CodePoints::new(codepoints),
+            edition,
+        }
+    }
+
+    /// Returns the edition this set was built for, e.g. for logging.
+    pub fn selected_edition(&self) -> Edition {
+        self.edition
+    }
+
+    /// Returns the human-readable name of this set, including its edition
+    /// (e.g. `"JIS X 0213:2004 kanji"`), for error messages.
+    pub fn name(&self) -> &'static str {
+        match self.edition {
+            Edition::Y2000 => "JIS X 0213:2000 kanji",
+            Edition::Y2004 => "JIS X 0213:2004 kanji",
         }
     }
 
@@ -48,9 +155,15 @@ impl JisX0213Kanji {
         INSTANCE.get_or_init(Self::new)
     }
 
+    /// Returns every kanji of the set in table (kuten) order, as a static
+    /// slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        crate::data::jisx0213kanji::JISX0213_KANJI_CHARS
+    }
+
     /// Returns `true` if every character in `text` is a JIS X 0213 kanji.
-    pub fn contains(&self, s: &str) -> bool {
-        self.codepoints.contains(s)
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.codepoints.contains(text)
     }
 
     /// Returns the underlying [`CodePoints`] collection.
@@ -66,13 +179,89 @@ impl JisX0213Kanji {
         self.codepoints.iter().copied().collect()
     }
 
+    /// Returns the plane of `c` — `1` for Levels 1–3, `2` for Level 4 — or
+    /// `None` if `c` is not a JIS X 0213 kanji.
+    ///
+    /// Shift_JIS-2004 and EUC-JIS-2004 encode the two planes differently,
+    /// so an encoder may want to treat plane-2 characters separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0213kanji::JisX0213Kanji;
+    ///
+    /// let kanji = JisX0213Kanji::cached();
+    /// assert_eq!(kanji.plane_of('亜'), Some(1));
+    /// assert_eq!(kanji.plane_of('俱'), Some(1)); // Level 3
+    /// assert_eq!(kanji.plane_of('𪚲'), Some(2));
+    /// assert_eq!(kanji.plane_of('あ'), None);
+    /// ```
+    pub fn plane_of(&self, c: char) -> Option<u8> {
+        let (plane1, plane2) = planes();
+        if !self.codepoints.contains_char(c) {
+            None
+        } else if plane1.contains_char(c) {
+            Some(1)
+        } else if plane2.contains_char(c) {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    /// Splits the set into its plane-1 and plane-2 kanji.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::jisx0213kanji::JisX0213Kanji;
+    ///
+    /// let (plane1, plane2) = JisX0213Kanji::cached().split_by_plane();
+    /// assert_eq!((plane1.len(), plane2.len()), (7614, 2436));
+    ///
+    /// // Validate against the full set, but flag plane-2 characters.
+    /// let text = "𪚲と亜";
+    /// let plane2_chars: Vec<char> = text.chars().filter(|&c| plane2.contains_char(c)).collect();
+    /// assert_eq!(plane2_chars, ['𪚲']);
+    /// ```
+    #[must_use]
+    pub fn split_by_plane(&self) -> (CodePoints, CodePoints) {
+        let (plane1, plane2) = planes();
+        match self.edition {
+            Edition::Y2004 => (plane1.clone(), plane2.clone()),
+            _ => (
+                plane1.intersection(&self.codepoints),
+                plane2.intersection(&self.codepoints),
+            ),
+        }
+    }
+
     /// Validates that every character in `text` is a JIS X 0213 kanji.
     ///
     /// Returns `Ok(())` on success, or a [`crate::ValidationError`]
     /// identifying the first non-kanji character.
     pub fn validate(&self, text: &str) -> Result<(), crate::validation::ValidationError> {
-        self.codepoints.validate(text)
+        self.codepoints.validate_as(text, Some(self.name()))
     }
+
+    /// [`Self::validate`] for any [`crate::validation::ValidateInput`].
+    pub fn validate_input(
+        &self,
+        text: impl crate::validation::ValidateInput,
+    ) -> Result<(), crate::validation::ValidationError> {
+        text.validate_in(&self.codepoints, Some(self.name()))
+    }
+}
+
+/// Returns the plane-1 and plane-2 kanji, built on first use.
+fn planes() -> &'static (CodePoints, CodePoints) {
+    static PLANES: std::sync::OnceLock<(CodePoints, CodePoints)> = std::sync::OnceLock::new();
+    PLANES.get_or_init(|| {
+        (
+            CodePoints::from_slice(crate::data::jisx0213kanji::JISX0213_KANJI_PLANE1),
+            CodePoints::from_slice(crate::data::jisx0213kanji::JISX0213_KANJI_PLANE2),
+        )
+    })
 }
 
 impl Default for JisX0213Kanji {
@@ -81,6 +270,8 @@ impl Default for JisX0213Kanji {
     }
 }
 
+crate::codepoints::impl_character_set!(JisX0213Kanji);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,6 +281,15 @@ mod tests {
         assert_eq!(JisX0213Kanji::new().codepoints_vec().len(), 10050);
     }
 
+    #[test]
+    fn test_count_constants() {
+        assert_eq!(JisX0213Kanji::COUNT, 10050);
+        assert_eq!(
+            JisX0213Kanji::new().codepoints_vec().len(),
+            JisX0213Kanji::COUNT
+        );
+    }
+
     #[test]
     fn test_default_equals_new() {
         assert_eq!(
@@ -98,6 +298,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bmp_window() {
+        let cp = JisX0213Kanji::cached().codepoints();
+        let bmp = cp.restrict_to(..=0xFFFF);
+        let expected = cp.iter().filter(|&&c| c <= 0xFFFF).count();
+        assert_eq!(bmp.len(), expected);
+        assert_eq!(cp.count_in(..=0xFFFF), expected);
+        assert_eq!(cp.count_in(0x10000..) + expected, JisX0213Kanji::COUNT);
+        assert!(bmp.contains("亜叱"));
+        assert!(!bmp.contains("𠮟"));
+    }
+
+    #[test]
+    fn test_planes() {
+        let kanji = JisX0213Kanji::cached();
+        assert_eq!(kanji.plane_of('亜'), Some(1)); // 1-16-01
+        assert_eq!(kanji.plane_of('繫'), Some(1)); // 1-94-94
+        assert_eq!(kanji.plane_of('𠂉'), Some(2)); // 2-01-01
+        assert_eq!(kanji.plane_of('𪚲'), Some(2)); // 2-94-86
+        assert_eq!(kanji.plane_of('丂'), Some(2));
+        assert_eq!(kanji.plane_of('ア'), None);
+        assert_eq!(kanji.plane_of('𠮷'), None);
+
+        let (plane1, plane2) = kanji.split_by_plane();
+        assert!(plane1.intersection(&plane2).is_empty());
+        assert_eq!(&plane1.union(&plane2), kanji.codepoints());
+        #[cfg(feature = "codepoints-jisx0208kanji")]
+        assert!(plane1.is_superset_of(crate::JisX0208Kanji::cached().codepoints()));
+    }
+
+    #[test]
+    fn test_editions() {
+        let y2000 = JisX0213Kanji::edition(Edition::Y2000);
+        let y2004 = JisX0213Kanji::edition(Edition::Y2004);
+        assert_eq!(y2000.selected_edition(), Edition::Y2000);
+        assert_eq!(JisX0213Kanji::new().selected_edition(), Edition::Y2004);
+        assert_eq!(y2004.codepoints(), JisX0213Kanji::cached().codepoints());
+
+        let (added, removed) = y2000.codepoints().delta(y2004.codepoints());
+        assert!(removed.is_empty());
+        assert_eq!(added, CodePoints::from_string("俱剝𠮟吞噓姸屛幷瘦繫"));
+        assert_eq!(y2000.codepoints().len(), 10040);
+        assert!(y2000.contains("亜堯凜熙龕"));
+
+        assert_eq!(y2000.plane_of('繫'), None);
+        assert_eq!(y2004.plane_of('繫'), Some(1));
+        let (plane1, plane2) = y2000.split_by_plane();
+        assert_eq!((plane1.len(), plane2.len()), (7604, 2436));
+    }
+
+    #[test]
+    fn test_edition_names() {
+        for edition in [Edition::Y2000, Edition::Y2004] {
+            assert_eq!(edition.as_str().parse(), Ok(edition));
+        }
+        assert_eq!(Edition::Y2004.as_str(), "2004");
+        assert!("Y2004".parse::<Edition>().is_err());
+    }
+
     #[test]
     fn test_level1_kanji() {
         let kanji = JisX0213Kanji::new();
@@ -136,4 +395,60 @@ mod tests {
         assert_eq!(err.code_point, 0x78); // 'x'
         assert_eq!(err.position, 1);
     }
+
+    #[test]
+    fn test_outside_cjk_unified_block() {
+        use crate::{Block, CodePoints};
+
+        let kanji = JisX0213Kanji::new();
+        let outside = kanji
+            .codepoints()
+            .difference(&CodePoints::from_unicode_block(Block::CjkUnifiedIdeographs));
+        assert!(outside.contains("㐂𠮟"));
+        assert!(!outside.contains("亜"));
+        assert!(outside.has_any_in_range(0x20000, 0x2A6DF)); // Extension B
+    }
+
+    #[test]
+    fn test_display_and_debug() {
+        let kanji = JisX0213Kanji::cached();
+        assert_eq!(
+            kanji.to_string(),
+            "JIS X 0213:2004 kanji (10050 characters)"
+        );
+        let debug = format!("{:?}", kanji);
+        assert!(debug.starts_with(r#"JisX0213Kanji { name: "JIS X 0213:2004 kanji", count: 10050"#));
+        assert!(debug.len() < 120);
+
+        let y2000 = JisX0213Kanji::edition(Edition::Y2000);
+        assert_eq!(
+            y2000.to_string(),
+            "JIS X 0213:2000 kanji (10040 characters)"
+        );
+        assert!(format!("{:?}", y2000).contains(r#"name: "JIS X 0213:2000 kanji""#));
+    }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let cp = JisX0213Kanji::cached().codepoints();
+        let bytes = cp.serialize_compact();
+        assert_eq!(bytes.len(), 8 + 10050 * 4);
+        assert_eq!(&CodePoints::deserialize_compact(&bytes).unwrap(), cp);
+        assert!(CodePoints::deserialize_compact(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[cfg(feature = "codepoints-jisx0208kanji")]
+    #[test]
+    fn test_delta_from_jisx0208kanji() {
+        let jisx0208 = crate::jisx0208kanji::JisX0208Kanji::new();
+        let jisx0213 = JisX0213Kanji::new();
+        let (added, removed) = jisx0208.codepoints().delta(jisx0213.codepoints());
+
+        // JIS X 0213 is a strict superset: only Level 3 + 4 are added.
+        assert!(removed.is_empty());
+        assert_eq!(added.len(), 10050 - 6355);
+        assert!(added.contains("俱剝𠮟"));
+        assert!(!added.contains("堯")); // already in JIS X 0208 Level 2
+        assert!(!added.contains("亜"));
+    }
 }
@@ -0,0 +1,259 @@
+//! `serde::Serialize` for the report types (`serde` feature).
+//!
+//! The schema is documented in [`crate::validation`] under "JSON schema".
+//! It only grows: fields may be added, but existing fields keep their name,
+//! type and meaning.
+
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+
+use crate::codepoints::{SetDiff, SetManifest};
+use crate::validation::{ExclusionReport, ValidationError, ValidationErrorKind, Violation};
+
+/// A code point written as `"U+XXXX"`, with at least four hex digits.
+struct Hex(u32);
+
+impl Serialize for Hex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("U+{:04X}", self.0))
+    }
+}
+
+/// A code point as both an integer and its `"U+XXXX"` form.
+struct CodePointEntry(u32);
+
+impl Serialize for CodePointEntry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("CodePoint", 2)?;
+        s.serialize_field("code_point", &self.0)?;
+        s.serialize_field("code_point_hex", &Hex(self.0))?;
+        s.end()
+    }
+}
+
+/// A [`CodePoints::fingerprint`](crate::CodePoints::fingerprint) written as
+/// 16 lower-case hex digits.
+struct Fingerprint(u64);
+
+impl Serialize for Fingerprint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:016x}", self.0))
+    }
+}
+
+/// An inclusive range of a [`SetDiff`] or [`SetManifest`].
+struct RangeEntry(u32, u32);
+
+impl Serialize for RangeEntry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let RangeEntry(first, last) = *self;
+        let mut s = serializer.serialize_struct("Range", 5)?;
+        s.serialize_field("first", &first)?;
+        s.serialize_field("first_hex", &Hex(first))?;
+        s.serialize_field("last", &last)?;
+        s.serialize_field("last_hex", &Hex(last))?;
+        s.serialize_field("count", &(u64::from(last) - u64::from(first) + 1))?;
+        s.end()
+    }
+}
+
+impl Serialize for Violation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Violation", 4)?;
+        s.serialize_field("code_point", &self.code_point)?;
+        s.serialize_field("code_point_hex", &Hex(self.code_point))?;
+        s.serialize_field("char_index", &self.char_index)?;
+        s.serialize_field("byte_index", &self.byte_index)?;
+        s.end()
+    }
+}
+
+impl Serialize for ExclusionReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let unique: Vec<CodePointEntry> = self
+            .unique_excluded
+            .iter()
+            .map(|&cp| CodePointEntry(cp))
+            .collect();
+        let mut s = serializer.serialize_struct("ExclusionReport", 7)?;
+        s.serialize_field("total_chars", &self.total_chars)?;
+        s.serialize_field("violation_count", &self.violation_count)?;
+        s.serialize_field("truncated", &self.is_truncated())?;
+        s.serialize_field("first", &self.first)?;
+        s.serialize_field("violations", &self.violations)?;
+        s.serialize_field("unique_excluded", &unique)?;
+        match self.set_fingerprint {
+            Some(fingerprint) => s.serialize_field("set_fingerprint", &Fingerprint(fingerprint))?,
+            None => s.skip_field("set_fingerprint")?,
+        }
+        s.end()
+    }
+}
+
+impl Serialize for ValidationErrorKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for ValidationError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("ValidationError", 6)?;
+        s.serialize_field("kind", &self.kind)?;
+        s.serialize_field("code_point", &self.code_point)?;
+        s.serialize_field("code_point_hex", &Hex(self.code_point))?;
+        s.serialize_field("char_index", &self.position)?;
+        match self.byte_index {
+            Some(byte_index) => s.serialize_field("byte_index", &byte_index)?,
+            None => s.skip_field("byte_index")?,
+        }
+        s.serialize_field("message", &self.message)?;
+        s.end()
+    }
+}
+
+impl Serialize for SetDiff {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ranges = |ranges: &[(u32, u32)]| -> Vec<RangeEntry> {
+            ranges
+                .iter()
+                .map(|&(first, last)| RangeEntry(first, last))
+                .collect()
+        };
+        let mut s = serializer.serialize_struct("SetDiff", 2)?;
+        s.serialize_field("added", &ranges(self.added()))?;
+        s.serialize_field("removed", &ranges(self.removed()))?;
+        s.end()
+    }
+}
+
+impl Serialize for SetManifest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let ranges: Vec<RangeEntry> = self
+            .ranges
+            .iter()
+            .map(|&(first, last)| RangeEntry(first, last))
+            .collect();
+        let mut s = serializer.serialize_struct("SetManifest", 4)?;
+        s.serialize_field("fingerprint", &Fingerprint(self.fingerprint))?;
+        s.serialize_field("len", &self.len)?;
+        s.serialize_field("ranges", &ranges)?;
+        s.serialize_field("crate_data_version", self.crate_data_version)?;
+        s.end()
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use crate::validation::{validate_non_empty, ValidationError, ValidationErrorKind};
+    use crate::CodePoints;
+
+    // Golden outputs: a change here is a schema change and must be additive.
+
+    #[test]
+    fn test_exclusion_report_golden() {
+        let cp = CodePoints::ascii_printable();
+        let report = cp.check_with_limit("aあb𠮷あ", 2).unwrap_err();
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            concat!(
+                r#"{"total_chars":5,"violation_count":3,"truncated":true,"#,
+                r#""first":{"code_point":12354,"code_point_hex":"U+3042","char_index":1,"byte_index":1},"#,
+                r#""violations":["#,
+                r#"{"code_point":12354,"code_point_hex":"U+3042","char_index":1,"byte_index":1},"#,
+                r#"{"code_point":134071,"code_point_hex":"U+20BB7","char_index":3,"byte_index":5}],"#,
+                r#""unique_excluded":["#,
+                r#"{"code_point":12354,"code_point_hex":"U+3042"},"#,
+                r#"{"code_point":134071,"code_point_hex":"U+20BB7"}]}"#,
+            )
+        );
+    }
+
+    #[test]
+    fn test_validation_error_golden() {
+        let cp = CodePoints::ascii_printable();
+        let err = cp.validate("abあ").unwrap_err();
+        assert_eq!(
+            serde_json::to_string(&err).unwrap(),
+            concat!(
+                r#"{"kind":"invalid_char","code_point":12354,"code_point_hex":"U+3042","#,
+                r#""char_index":2,"byte_index":2,"#,
+                r#""message":"invalid character 'あ' (U+3042) at position 2"}"#,
+            )
+        );
+
+        let empty = validate_non_empty("", &cp).unwrap_err();
+        assert_eq!(empty.kind, ValidationErrorKind::EmptyInput);
+        let json = serde_json::to_value(&empty).unwrap();
+        assert_eq!(json["kind"], "empty_input");
+        assert_eq!(json["byte_index"], 0);
+
+        let built = ValidationError::new(0x3042, 2);
+        let json = serde_json::to_value(&built).unwrap();
+        assert!(json.get("byte_index").is_none());
+    }
+
+    #[test]
+    fn test_set_diff_golden() {
+        let old = CodePoints::from_string("あいうか");
+        let new = CodePoints::from_string("あいうぇえぉ");
+        assert_eq!(
+            serde_json::to_string(&old.diff(&new)).unwrap(),
+            concat!(
+                r#"{"added":[{"first":12359,"first_hex":"U+3047","#,
+                r#""last":12361,"last_hex":"U+3049","count":3}],"#,
+                r#""removed":[{"first":12363,"first_hex":"U+304B","#,
+                r#""last":12363,"last_hex":"U+304B","count":1}]}"#,
+            )
+        );
+        let none = serde_json::to_string(&new.diff(&new)).unwrap();
+        assert_eq!(none, r#"{"added":[],"removed":[]}"#);
+    }
+
+    #[test]
+    fn test_set_manifest_golden() {
+        let manifest = CodePoints::from_string("abcあ").export_manifest();
+        let json = serde_json::to_string(&manifest).unwrap();
+        let expected = format!(
+            concat!(
+                r#"{{"fingerprint":"{:016x}","len":4,"ranges":["#,
+                r#"{{"first":97,"first_hex":"U+0061","last":99,"last_hex":"U+0063","count":3}},"#,
+                r#"{{"first":12354,"first_hex":"U+3042","last":12354,"last_hex":"U+3042","count":1}}],"#,
+                r#""crate_data_version":"{}"}}"#,
+            ),
+            manifest.fingerprint,
+            crate::data_version()
+        );
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn test_exclusion_report_set_fingerprint() {
+        let cp = CodePoints::from_string("a");
+        let report = cp.check("b").unwrap_err();
+        let json = serde_json::to_value(&report).unwrap();
+        assert!(json.get("set_fingerprint").is_none());
+
+        let json = serde_json::to_value(report.with_fingerprint(&cp)).unwrap();
+        assert_eq!(json["set_fingerprint"], "ac804b820e4fe984");
+    }
+
+    #[test]
+    fn test_ndjson_lines() {
+        // One object per line, as a log pipeline consumes it.
+        let cp = CodePoints::ascii_printable();
+        let lines: Vec<String> = ["ok", "ｎｇ", "x\u{7F}"]
+            .iter()
+            .filter_map(|text| cp.check(text).err())
+            .map(|report| serde_json::to_string(&report).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(!line.contains('\n'));
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert!(value["first"]["code_point_hex"].is_string());
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//! Structural checks on kana strings (`codepoints-jisx0208` feature).
+//!
+//! Membership in [`Hiragana`] or [`Katakana`] says nothing about whether the
+//! characters are arranged sensibly.  [`OrthographyChecker`] catches the
+//! placement errors that commonly slip into furigana and reading fields:
+//!
+//! | [`Rule`] | Violation | Default |
+//! |---|---|---|
+//! | [`Rule::LongVowelAfterKana`] | `ー` at the start of a word | on |
+//! | [`Rule::NoInitialN`] | `ん`/`ン` at the start of a word | on |
+//! | [`Rule::NoFinalSokuon`] | `っ`/`ッ` at the end of a word | off (strict) |
+//!
+//! A *word* is a maximal run of kana and `ー`; any other character (spaces,
+//! punctuation, kanji) is a boundary.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::kana::{OrthographyChecker, Rule};
+//!
+//! let checker = OrthographyChecker::new();
+//! assert!(checker.is_valid("ラーメン"));
+//!
+//! let violations = checker.check("ーあ ん");
+//! assert_eq!(violations.len(), 2);
+//! assert_eq!(violations[0].rule, Rule::LongVowelAfterKana);
+//! assert_eq!(violations[1].rule, Rule::NoInitialN);
+//! assert_eq!(violations[1].position, 3);
+//! ```
+
+use std::fmt;
+
+use crate::jisx0208::{Hiragana, Katakana};
+
+const LONG_VOWEL: char = '\u{30FC}'; // ー
+const SOKUON: [char; 2] = ['\u{3063}', '\u{30C3}']; // っ ッ
+const SYLLABIC_N: [char; 2] = ['\u{3093}', '\u{30F3}']; // ん ン
+
+fn is_kana(c: char) -> bool {
+    Hiragana::cached().codepoints().contains_char(c)
+        || Katakana::cached().codepoints().contains_char(c)
+}
+
+fn is_word_char(c: char) -> bool {
+    c == LONG_VOWEL || is_kana(c)
+}
+
+// ── rules ─────────────────────────────────────────────────────────────────────
+
+/// An individually toggleable orthography rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Rule {
+    /// `ー` must follow a kana (or another `ー`).
+    LongVowelAfterKana,
+    /// `ん`/`ン` must not start a word.
+    NoInitialN,
+    /// `っ`/`ッ` must not end a word.
+    NoFinalSokuon,
+}
+
+impl Rule {
+    /// Returns a stable, snake_case name for logs and error messages;
+    /// [`str::parse`] accepts it back.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Rule::LongVowelAfterKana => "long_vowel_after_kana",
+            Rule::NoInitialN => "no_initial_n",
+            Rule::NoFinalSokuon => "no_final_sokuon",
+        }
+    }
+}
+
+impl std::str::FromStr for Rule {
+    type Err = crate::ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long_vowel_after_kana" => Ok(Rule::LongVowelAfterKana),
+            "no_initial_n" => Ok(Rule::NoInitialN),
+            "no_final_sokuon" => Ok(Rule::NoFinalSokuon),
+            _ => Err(crate::ParseNameError::new("orthography rule", s)),
+        }
+    }
+}
+
+/// A rule violation found by [`OrthographyChecker::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrthographyViolation {
+    /// The rule that was broken.
+    pub rule: Rule,
+    /// The offending code point.
+    pub code_point: u32,
+    /// Zero-based character index of the offending code point.
+    pub position: usize,
+}
+
+impl fmt::Display for OrthographyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = char::from_u32(self.code_point).unwrap_or('\u{FFFD}');
+        write!(
+            f,
+            "character '{}' (U+{:04X}) at position {} violates {}",
+            ch,
+            self.code_point,
+            self.position,
+            self.rule.as_str()
+        )
+    }
+}
+
+// ── checker ───────────────────────────────────────────────────────────────────
+
+/// Checks the placement of `ー`, `っ` and `ん` in kana strings.
+///
+/// [`new`](Self::new) enables the lenient rules; [`strict`](Self::strict)
+/// enables all of them.  Individual rules are toggled with
+/// [`rule`](Self::rule).
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::kana::{OrthographyChecker, Rule};
+///
+/// assert!(OrthographyChecker::new().is_valid("あっ"));
+/// assert!(!OrthographyChecker::strict().is_valid("あっ"));
+///
+/// let checker = OrthographyChecker::strict().rule(Rule::NoInitialN, false);
+/// assert!(checker.is_valid("ンジャメナ"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrthographyChecker {
+    long_vowel_after_kana: bool,
+    no_initial_n: bool,
+    no_final_sokuon: bool,
+}
+
+impl OrthographyChecker {
+    /// Creates a checker with [`Rule::LongVowelAfterKana`] and
+    /// [`Rule::NoInitialN`] enabled.
+    pub fn new() -> Self {
+        Self {
+            long_vowel_after_kana: true,
+            no_initial_n: true,
+            no_final_sokuon: false,
+        }
+    }
+
+    /// Creates a checker with every rule enabled.
+    pub fn strict() -> Self {
+        Self {
+            no_final_sokuon: true,
+            ..Self::new()
+        }
+    }
+
+    /// Enables or disables `rule`.
+    pub fn rule(mut self, rule: Rule, enabled: bool) -> Self {
+        *self.flag(rule) = enabled;
+        self
+    }
+
+    /// Returns `true` if `rule` is enabled.
+    pub fn is_enabled(&self, rule: Rule) -> bool {
+        match rule {
+            Rule::LongVowelAfterKana => self.long_vowel_after_kana,
+            Rule::NoInitialN => self.no_initial_n,
+            Rule::NoFinalSokuon => self.no_final_sokuon,
+        }
+    }
+
+    /// Returns every violation in `text`, ordered by position.
+    pub fn check(&self, text: &str) -> Vec<OrthographyViolation> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut violations = Vec::new();
+        for (position, &c) in chars.iter().enumerate() {
+            let prev = position.checked_sub(1).and_then(|i| chars.get(i)).copied();
+            let next = chars.get(position + 1).copied();
+            let starts_word = !prev.is_some_and(is_word_char);
+            let ends_word = !next.is_some_and(is_word_char);
+
+            let rule = if c == LONG_VOWEL && starts_word {
+                Some(Rule::LongVowelAfterKana)
+            } else if SYLLABIC_N.contains(&c) && starts_word {
+                Some(Rule::NoInitialN)
+            } else if SOKUON.contains(&c) && ends_word {
+                Some(Rule::NoFinalSokuon)
+            } else {
+                None
+            };
+            if let Some(rule) = rule.filter(|&r| self.is_enabled(r)) {
+                violations.push(OrthographyViolation {
+                    rule,
+                    code_point: c as u32,
+                    position,
+                });
+            }
+        }
+        violations
+    }
+
+    /// Returns `true` if `text` breaks none of the enabled rules.
+    pub fn is_valid(&self, text: &str) -> bool {
+        self.check(text).is_empty()
+    }
+
+    fn flag(&mut self, rule: Rule) -> &mut bool {
+        match rule {
+            Rule::LongVowelAfterKana => &mut self.long_vowel_after_kana,
+            Rule::NoInitialN => &mut self.no_initial_n,
+            Rule::NoFinalSokuon => &mut self.no_final_sokuon,
+        }
+    }
+}
+
+impl Default for OrthographyChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(checker: &OrthographyChecker, text: &str) -> Vec<(Rule, usize)> {
+        checker
+            .check(text)
+            .iter()
+            .map(|v| (v.rule, v.position))
+            .collect()
+    }
+
+    #[test]
+    fn test_long_vowel_after_kana() {
+        let checker = OrthographyChecker::new();
+        assert!(checker.is_valid("ラーメン"));
+        assert!(checker.is_valid("すごーーい"));
+        assert_eq!(rules(&checker, "ーあ"), vec![(Rule::LongVowelAfterKana, 0)]);
+        assert_eq!(
+            rules(&checker, "あ、ー"),
+            vec![(Rule::LongVowelAfterKana, 2)]
+        );
+        assert_eq!(rules(&checker, "漢ー"), vec![(Rule::LongVowelAfterKana, 1)]);
+    }
+
+    #[test]
+    fn test_no_initial_n() {
+        let checker = OrthographyChecker::new();
+        assert!(checker.is_valid("ほん"));
+        assert_eq!(rules(&checker, "んあ"), vec![(Rule::NoInitialN, 0)]);
+        assert_eq!(rules(&checker, "あ ンア"), vec![(Rule::NoInitialN, 2)]);
+    }
+
+    #[test]
+    fn test_no_final_sokuon() {
+        let lenient = OrthographyChecker::new();
+        let strict = OrthographyChecker::strict();
+        assert!(lenient.is_valid("あっ"));
+        assert!(strict.is_valid("がっこう"));
+        assert_eq!(rules(&strict, "あっ"), vec![(Rule::NoFinalSokuon, 1)]);
+        assert_eq!(rules(&strict, "アッ!"), vec![(Rule::NoFinalSokuon, 1)]);
+        // A following ー keeps the sokuon inside the word.
+        assert!(strict.is_valid("あっー"));
+    }
+
+    #[test]
+    fn test_toggle_rules() {
+        let none = OrthographyChecker::new()
+            .rule(Rule::LongVowelAfterKana, false)
+            .rule(Rule::NoInitialN, false);
+        assert!(none.is_valid("ーん"));
+        assert!(!none.is_enabled(Rule::NoFinalSokuon));
+
+        let only_sokuon = none.rule(Rule::NoFinalSokuon, true);
+        assert_eq!(
+            rules(&only_sokuon, "ーんっ"),
+            vec![(Rule::NoFinalSokuon, 2)]
+        );
+        assert_eq!(OrthographyChecker::default(), OrthographyChecker::new());
+    }
+
+    #[test]
+    fn test_rule_names() {
+        for rule in [
+            Rule::LongVowelAfterKana,
+            Rule::NoInitialN,
+            Rule::NoFinalSokuon,
+        ] {
+            assert_eq!(rule.as_str().parse(), Ok(rule));
+        }
+        assert!("no_final_n".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn test_violation_display() {
+        let v = OrthographyChecker::new().check("ん")[0];
+        assert_eq!(v.code_point, 0x3093);
+        assert_eq!(
+            v.to_string(),
+            "character 'ん' (U+3093) at position 0 violates no_initial_n"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Line-breaking rules (禁則処理) of JIS X 4051.
+//!
+//! Japanese typesetting forbids some characters at the start of a line —
+//! closing brackets, `。`, `、`, small kana, `ー` — and opening brackets at
+//! the end of one.  [`LineStartProhibited`] and [`LineEndProhibited`] hold
+//! those classes (see [`crate::data::kinsoku`] for the class-by-class
+//! tables), and [`violations`] checks already wrapped text against them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::kinsoku::{violations, KinsokuViolation, LineStartProhibited};
+//!
+//! assert!(LineStartProhibited::cached().contains("。」ゃー"));
+//!
+//! let wrapped = "吾輩は猫である\n。名前はまだ無い";
+//! assert_eq!(
+//!     violations(wrapped.lines()),
+//!     vec![KinsokuViolation::LineStart { line: 1, code_point: '。' as u32 }]
+//! );
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::data::kinsoku;
+use crate::CodePoints;
+
+// ── character classes ─────────────────────────────────────────────────────────
+
+/// Characters that may not start a line (行頭禁則): closing brackets,
+/// hyphens, `！？`, middle dots, full stops, commas, iteration marks, `ー`
+/// and small kana.
+#[derive(Debug, Clone)]
+pub struct LineStartProhibited {
+    codepoints: CodePoints,
+}
+
+impl LineStartProhibited {
+    /// Number of characters in this set, checked against the data table at
+    /// compile time.
+    pub const COUNT: usize = kinsoku::LINE_START_PROHIBITED_COUNT;
+
+    /// Creates a new instance of this character set.
+    pub fn new() -> Self {
+        Self {
+            codepoints: CodePoints::from_slice(kinsoku::LINE_START_PROHIBITED),
+        }
+    }
+
+    /// Returns a cached static reference to this character set.
+    pub fn cached() -> &'static Self {
+        static INSTANCE: OnceLock<LineStartProhibited> = OnceLock::new();
+        INSTANCE.get_or_init(Self::new)
+    }
+
+    /// Returns `true` if every character in `text` belongs to this set.
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.codepoints.contains(text)
+    }
+
+    /// Returns every character of this set in table order, as a static
+    /// slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        kinsoku::LINE_START_PROHIBITED_CHARS
+    }
+
+    /// Returns the underlying [`CodePoints`] collection.
+    pub fn codepoints(&self) -> &CodePoints {
+        &self.codepoints
+    }
+}
+
+impl Default for LineStartProhibited {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Characters that may not end a line (行末禁則): the opening brackets.
+#[derive(Debug, Clone)]
+pub struct LineEndProhibited {
+    codepoints: CodePoints,
+}
+
+impl LineEndProhibited {
+    /// Number of characters in this set, checked against the data table at
+    /// compile time.
+    pub const COUNT: usize = kinsoku::LINE_END_PROHIBITED_COUNT;
+
+    /// Creates a new instance of this character set.
+    pub fn new() -> Self {
+        Self {
+            codepoints: CodePoints::from_slice(kinsoku::LINE_END_PROHIBITED),
+        }
+    }
+
+    /// Returns a cached static reference to this character set.
+    pub fn cached() -> &'static Self {
+        static INSTANCE: OnceLock<LineEndProhibited> = OnceLock::new();
+        INSTANCE.get_or_init(Self::new)
+    }
+
+    /// Returns `true` if every character in `text` belongs to this set.
+    pub fn contains(&self, text: impl AsRef<str>) -> bool {
+        self.codepoints.contains(text)
+    }
+
+    /// Returns every character of this set in table order, as a static
+    /// slice converted at compile time.
+    pub fn chars() -> &'static [char] {
+        kinsoku::LINE_END_PROHIBITED_CHARS
+    }
+
+    /// Returns the underlying [`CodePoints`] collection.
+    pub fn codepoints(&self) -> &CodePoints {
+        &self.codepoints
+    }
+}
+
+impl Default for LineEndProhibited {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── line checks ───────────────────────────────────────────────────────────────
+
+/// A line break that breaks a kinsoku rule, reported by [`violations`].
+///
+/// `line` is the zero-based index of the offending line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KinsokuViolation {
+    /// The line starts with a [`LineStartProhibited`] character.
+    LineStart {
+        /// Zero-based line index.
+        line: usize,
+        /// The first character of the line.
+        code_point: u32,
+    },
+    /// The line ends with a [`LineEndProhibited`] character.
+    LineEnd {
+        /// Zero-based line index.
+        line: usize,
+        /// The last character of the line.
+        code_point: u32,
+    },
+}
+
+impl KinsokuViolation {
+    /// Returns the zero-based index of the offending line.
+    pub fn line(&self) -> usize {
+        match *self {
+            KinsokuViolation::LineStart { line, .. } => line,
+            KinsokuViolation::LineEnd { line, .. } => line,
+        }
+    }
+
+    /// Returns the offending code point.
+    pub fn code_point(&self) -> u32 {
+        match *self {
+            KinsokuViolation::LineStart { code_point, .. } => code_point,
+            KinsokuViolation::LineEnd { code_point, .. } => code_point,
+        }
+    }
+}
+
+/// Checks the first and last character of every line against
+/// [`LineStartProhibited`] and [`LineEndProhibited`].
+///
+/// Pass the lines as laid out, e.g. `text.lines()`.  Violations are returned
+/// in line order, with a line's start reported before its end.  Empty lines
+/// are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::kinsoku::{violations, KinsokuViolation};
+///
+/// let found = violations(["彼は言った。「", "待って」"]);
+/// assert_eq!(found, vec![KinsokuViolation::LineEnd { line: 0, code_point: '「' as u32 }]);
+/// assert!(violations(["彼は言った。", "「待って」"]).is_empty());
+/// ```
+pub fn violations<'a>(lines: impl IntoIterator<Item = &'a str>) -> Vec<KinsokuViolation> {
+    let start = LineStartProhibited::cached().codepoints();
+    let end = LineEndProhibited::cached().codepoints();
+    let mut found = Vec::new();
+    for (line, text) in lines.into_iter().enumerate() {
+        if let Some(c) = text.chars().next().filter(|&c| start.contains_char(c)) {
+            found.push(KinsokuViolation::LineStart {
+                line,
+                code_point: c as u32,
+            });
+        }
+        if let Some(c) = text.chars().next_back().filter(|&c| end.contains_char(c)) {
+            found.push(KinsokuViolation::LineEnd {
+                line,
+                code_point: c as u32,
+            });
+        }
+    }
+    found
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(LineStartProhibited::cached().codepoints().len(), 84);
+        assert_eq!(LineStartProhibited::COUNT, 84);
+        assert_eq!(LineEndProhibited::cached().codepoints().len(), 20);
+        assert_eq!(LineEndProhibited::chars().len(), 20);
+        assert!(LineStartProhibited::cached()
+            .codepoints()
+            .intersection(LineEndProhibited::cached().codepoints())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_classes() {
+        let start = LineStartProhibited::cached();
+        assert!(start.contains("。、」』）ゃゅょっァヵーゝ々・！？"));
+        for s in ["「", "あ", "ア", "漢", "a", "　"] {
+            assert!(!start.contains(s), "{:?}", s);
+        }
+        let end = LineEndProhibited::cached();
+        assert!(end.contains("「『（【〔《〈“‘"));
+        for s in ["」", "。", "あ", "-"] {
+            assert!(!end.contains(s), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_wrapped_paragraph() {
+        let paragraph = "吾輩は猫である。名前はまだ無い\n。どこで生れたかとんと見当がつかぬ。何でも薄暗い\n「じめじめした所でニャーニャー泣いていた事だけは記憶している。「\n」";
+        assert_eq!(
+            violations(paragraph.lines()),
+            vec![
+                KinsokuViolation::LineStart {
+                    line: 1,
+                    code_point: '。' as u32,
+                },
+                KinsokuViolation::LineEnd {
+                    line: 2,
+                    code_point: '「' as u32,
+                },
+                KinsokuViolation::LineStart {
+                    line: 3,
+                    code_point: '」' as u32,
+                },
+            ]
+        );
+        let v = violations(paragraph.lines())[1];
+        assert_eq!((v.line(), v.code_point()), (2, 0x300C));
+    }
+
+    #[test]
+    fn test_clean_and_empty_lines() {
+        assert!(violations(["", "「こんにちは」", "", "さようなら。"]).is_empty());
+        assert!(violations(std::iter::empty()).is_empty());
+        // A one-character line can break both rules.
+        assert_eq!(violations(["（"]).len(), 1);
+        assert_eq!(violations(["ー"]).len(), 1);
+    }
+}
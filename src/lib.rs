@@ -8,11 +8,33 @@
 //! | Feature | Module | Description |
 //! |---|---|---|
 //! | *(default)* | — | ASCII control / printable via [`CodePoints`] |
+//! | *(default)* | [`const_codepoints`] | `const fn` membership for compile-time checks; built-in sets follow the features below |
+//! | *(default)* | [`forms`] | Vertical forms and small form variants (e.g. PDF-extracted `︒`) |
+//! | *(default)* | [`width`] | Terminal display width with JIS-aware ambiguous-width handling |
+//! | *(default)* | [`enclosed`] | Circled / parenthesized characters (JIS X 0213 subset and Unicode) |
+//! | *(default)* | [`iter`] | `CodePointIterExt`: `allowed_by` / `excluded_by` / `partition_by_set` adapters for `char` iterators |
+//! | *(default)* | [`kinsoku`] | JIS X 4051 line-start / line-end prohibited characters and a wrapped-text checker |
+//! | *(default)* | [`pipeline`] | `Pipeline`: width and kana normalization stages followed by validation, reusable across threads |
+//! | *(default)* | [`sanitize`] | `Sanitizer`: width folding, JIS/CP932 equivalences and kana composition into a target set, with a fallback |
 //! | `codepoints-jisx0201` | [`jisx0201`] | Latin letters and halfwidth katakana |
 //! | `codepoints-jisx0208` | [`jisx0208`] | Hiragana, katakana, Latin, Greek, Cyrillic, symbols |
+//! | `codepoints-jisx0208` | `presets` | Field presets: katakana corporate names, postal codes, phone numbers, invisible-character deny-list, name-legal kanji |
+//! | `codepoints-jisx0208` | `recipes` | Ready-to-call checks: kana readings, JIS Level 1 text, Shift_JIS-safe file names, addresses |
+//! | `codepoints-jisx0208` | `kana` | `OrthographyChecker` for `ー` / `っ` / `ん` placement |
+//! | `codepoints-jisx0201` + `codepoints-jisx0208` | `lint` | Halfwidth/fullwidth mixing checks |
+//! | `codepoints-jisx0208` + `codepoints-jisx0208kanji` | `downgrade` | JIS X 0213 → JIS X 0208 downgrade report with conventional kanji substitutes (`𠮟` → `叱`) |
 //! | `codepoints-jisx0208kanji` | [`jisx0208kanji`] | 6 355 kanji (JIS X 0208 Level 1 & 2) |
 //! | `codepoints-jisx0213kanji` | [`jisx0213kanji`] | 10 050 kanji (JIS X 0213 Level 1–4) |
-//! | `full` | — | All of the above |
+//! | `kanji-metadata` | [`jisx0208kanji`] | Stroke counts and Kangxi radicals in `jisx0208kanji::info`, and `sort_by_strokes` |
+//! | `emoji` | [`validator`] | [`validator::Restriction`]`::no_emoji()` (Extended_Pictographic) |
+//! | `serde` | [`validation`] | `serde::Serialize` for [`validation::ExclusionReport`], [`ValidationError`] and [`SetDiff`] (see the JSON schema in [`validation`]) |
+//! | `tracing` | [`validation`] | `debug!` events for validation failures (see [`validation::ValidationHooks`]) |
+//! | `mmap` | — | [`CodePoints::from_sorted_file`] / `map_sorted_file` / `write_sorted_file`: sorted table files, optionally memory-mapped |
+//! | `tokio` | — | [`CodePoints::validate_async_reader`] / `filter_async_reader` for async streams |
+//! | `full` | — | All character sets and `emoji` |
+//!
+//! [`compiled_features`] and [`has_feature`] report the enabled features at
+//! run time.
 //!
 //! ## Quick start
 //!
@@ -39,10 +61,89 @@
 //!
 //! For a version that returns a structured error, see
 //! [`validation::validate_all_in_any`].
+//!
+//! ## Panics
+//!
+//! No function in this crate panics on any input string, byte slice or code
+//! point value, including surrogates and values above U+10FFFF. The only
+//! exceptions are `const fn`s such as [`Feature::from_cargo_name`] whose
+//! `# Panics` section documents a compile-time check.
+//!
+//! ## API stability
+//!
+//! Enums that may gain variants, such as [`CharClass`] and
+//! [`ValidationErrorKind`], are `#[non_exhaustive]`, so a `match` on them
+//! needs a wildcard arm:
+//!
+//! ```rust,compile_fail,E0004
+//! use japanese_codepoints::ValidationErrorKind;
+//!
+//! fn is_fixable(kind: ValidationErrorKind) -> bool {
+//!     match kind {
+//!         ValidationErrorKind::InvalidChar | ValidationErrorKind::DeniedCharacter => false,
+//!         ValidationErrorKind::EmptyInput | ValidationErrorKind::TooLong => true,
+//!     }
+//! }
+//! ```
+//!
+//! Those with a name for logs and configuration files have an `as_str`
+//! method and a [`FromStr`](std::str::FromStr) implementation that accepts
+//! the same names, failing with [`ParseNameError`]:
+//!
+//! ```rust
+//! use japanese_codepoints::CharClass;
+//!
+//! for class in CharClass::ALL {
+//!     assert_eq!(class.as_str().parse(), Ok(class));
+//! }
+//! assert!("kana".parse::<CharClass>().is_err());
+//! ```
+//!
+//! Methods that return a new set, such as [`CodePoints::union`] and
+//! [`CodePoints::without`], are `#[must_use]`: they never modify `self`.
+//!
+//! ```rust,compile_fail
+//! #![deny(unused_must_use)]
+//! use japanese_codepoints::CodePoints;
+//!
+//! let allowed = CodePoints::ascii_printable();
+//! allowed.union(&CodePoints::from_string("あ")); // result discarded
+//! ```
+
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing
+    )
+)]
 
 pub mod codepoints;
+pub mod const_codepoints;
+pub mod conversion;
 pub mod data;
+pub mod enclosed;
+pub mod features;
+pub mod forms;
+pub mod iter;
+pub mod kinsoku;
+pub mod pipeline;
+pub mod registry;
+pub mod sanitize;
 pub mod validation;
+pub mod validator;
+pub mod width;
+
+#[cfg(feature = "tokio")]
+mod async_io;
+
+#[cfg(feature = "serde")]
+mod json;
+
+#[cfg(feature = "mmap")]
+mod mapped;
 
 #[cfg(feature = "codepoints-jisx0201")]
 pub mod jisx0201;
@@ -50,16 +151,63 @@ pub mod jisx0201;
 #[cfg(feature = "codepoints-jisx0208")]
 pub mod jisx0208;
 
+#[cfg(feature = "codepoints-jisx0208")]
+pub mod kana;
+
+#[cfg(feature = "codepoints-jisx0208")]
+pub mod presets;
+
+#[cfg(feature = "codepoints-jisx0208")]
+pub mod recipes;
+
+#[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208"))]
+pub mod lint;
+
+#[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+pub mod downgrade;
+
 #[cfg(feature = "codepoints-jisx0208kanji")]
 pub mod jisx0208kanji;
 
 #[cfg(feature = "codepoints-jisx0213kanji")]
 pub mod jisx0213kanji;
 
+#[cfg(test)]
+mod tests;
+
+/// Identifies the editions of the standards the bundled tables follow.
+///
+/// Applications can log this to record which data a string was validated
+/// against; it changes whenever the tables are updated to a new edition.
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(japanese_codepoints::data_version().contains("JIS X 0213:2004"));
+/// ```
+pub const fn data_version() -> &'static str {
+    "JIS X 0201:1997, JIS X 0208:1997, JIS X 0213:2004"
+}
+
 // ── re-exports ────────────────────────────────────────────────────────────────
 
-pub use codepoints::{contains_all_in_any, CodePoints};
-pub use validation::ValidationError;
+pub use codepoints::{
+    contains_all_in_any, Backend, Block, CharClass, CharacterSet, CodePoints, CodePointsGroup,
+    CodePointsStats, ContainsOutcome, DeserializeError, ExcludedChar, ExcludedOptions,
+    LazyCodePoints, Order, ParseNameError, ParseSpecError, ParseUnicodeSetError, SetComparison,
+    SetDiff, SetManifest, UnicodeBlockId, Utf8ErrorAt,
+};
+pub use const_codepoints::{ConstCodePoints, RangeSet};
+pub use enclosed::EnclosedChars;
+pub use features::{compiled_features, has_feature, Feature};
+pub use forms::{SmallFormVariants, VerticalForms};
+pub use kinsoku::{LineEndProhibited, LineStartProhibited};
+#[cfg(feature = "mmap")]
+pub use mapped::SortedFileError;
+pub use registry::{explain, which_sets};
+pub use validation::{
+    ExclusionReport, SetMarker, ValidatedString, ValidationError, ValidationErrorKind,
+};
 
 #[cfg(feature = "codepoints-jisx0201")]
 pub use jisx0201::{JisX0201, Katakana as JisX0201Katakana, LatinLetters as JisX0201LatinLetters};
@@ -67,11 +215,19 @@ pub use jisx0201::{JisX0201, Katakana as JisX0201Katakana, LatinLetters as JisX0
 #[cfg(feature = "codepoints-jisx0208")]
 pub use jisx0208::{
     BoxDrawingChars, CyrillicLetters, GreekLetters, Hiragana, JisX0208, Katakana, LatinLetters,
-    SpecialChars,
+    NecSpecialChars, SpecialChars,
 };
 
+#[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0213kanji"))]
+pub use jisx0208::KatakanaPhoneticExtensions;
+
 #[cfg(feature = "codepoints-jisx0208kanji")]
-pub use jisx0208kanji::JisX0208Kanji;
+pub use jisx0208kanji::{JisX0208Kanji, KanjiInfo, KanjiLevel};
 
 #[cfg(feature = "codepoints-jisx0213kanji")]
 pub use jisx0213kanji::JisX0213Kanji;
+
+/// Runs the examples in `MIGRATION.md` as doctests.
+#[cfg(doctest)]
+#[doc = include_str!("../MIGRATION.md")]
+pub struct MigrationDoctests;
@@ -0,0 +1,270 @@
+//! Style checks that go beyond set membership (`codepoints-jisx0201` +
+//! `codepoints-jisx0208` features).
+//!
+//! Many style guides forbid mixing halfwidth and fullwidth forms of the same
+//! kind of character within one field, e.g. `ﾃｽﾄテスト` or `１23`.
+//! [`check_width_consistency`] reports such mixes; a [`WidthPolicy`] decides
+//! which of them are acceptable.
+//!
+//! | [`WidthClass`] | Halfwidth form | Fullwidth form |
+//! |---|---|---|
+//! | [`WidthClass::Katakana`] | JIS X 0201 katakana `ｦ`–`ﾟ` | JIS X 0208 katakana and `ー` |
+//! | [`WidthClass::Alphanumeric`] | ASCII letters and digits | JIS X 0208 `Ａ`–`ｚ`, `０`–`９` |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::lint::{check_width_consistency, WidthClass, WidthPolicy};
+//!
+//! let warnings = check_width_consistency("ﾃｽﾄテスト", &WidthPolicy::default());
+//! assert_eq!(warnings.len(), 1);
+//! assert_eq!(warnings[0].class, WidthClass::Katakana);
+//! assert_eq!(warnings[0].halfwidth_positions, vec![0, 1, 2]);
+//! assert_eq!(warnings[0].fullwidth_positions, vec![3, 4, 5]);
+//!
+//! assert!(check_width_consistency("テスト123", &WidthPolicy::default()).is_empty());
+//! ```
+
+use std::fmt;
+
+use crate::jisx0201::Katakana as HalfwidthKatakana;
+use crate::jisx0208::{Katakana, LatinLetters};
+
+/// First halfwidth katakana letter; `｡｢｣､･` before it are punctuation.
+const HALFWIDTH_WO: char = '\u{FF66}'; // ｦ
+const PROLONGED_SOUND_MARK: char = '\u{30FC}'; // ー
+
+// ── classes and policy ────────────────────────────────────────────────────────
+
+/// A kind of character that exists in both a halfwidth and a fullwidth form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WidthClass {
+    /// Halfwidth (JIS X 0201) versus fullwidth (JIS X 0208) katakana.
+    Katakana,
+    /// ASCII versus fullwidth Latin letters and digits.
+    Alphanumeric,
+}
+
+impl WidthClass {
+    /// Returns a stable, snake_case name for logs and error messages;
+    /// [`str::parse`] accepts it back.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WidthClass::Katakana => "katakana",
+            WidthClass::Alphanumeric => "alphanumeric",
+        }
+    }
+
+    /// Classifies `c`, returning its class and `true` if it is the
+    /// halfwidth form, or `None` if it belongs to neither class.
+    fn of(c: char) -> Option<(WidthClass, bool)> {
+        if c.is_ascii_alphanumeric() {
+            Some((WidthClass::Alphanumeric, true))
+        } else if LatinLetters::cached().codepoints().contains_char(c) {
+            Some((WidthClass::Alphanumeric, false))
+        } else if c >= HALFWIDTH_WO && HalfwidthKatakana::cached().codepoints().contains_char(c) {
+            Some((WidthClass::Katakana, true))
+        } else if c == PROLONGED_SOUND_MARK || Katakana::cached().codepoints().contains_char(c) {
+            Some((WidthClass::Katakana, false))
+        } else {
+            None
+        }
+    }
+}
+
+impl std::str::FromStr for WidthClass {
+    type Err = crate::ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "katakana" => Ok(WidthClass::Katakana),
+            "alphanumeric" => Ok(WidthClass::Alphanumeric),
+            _ => Err(crate::ParseNameError::new("width class", s)),
+        }
+    }
+}
+
+/// Which width mixes [`check_width_consistency`] reports.
+///
+/// The default reports every mix.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::lint::{check_width_consistency, WidthClass, WidthPolicy};
+///
+/// let policy = WidthPolicy::default().allow(WidthClass::Alphanumeric, true);
+/// assert!(check_width_consistency("ＡＢC", &policy).is_empty());
+/// assert!(!check_width_consistency("ｱア", &policy).is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WidthPolicy {
+    allow_mixed_katakana: bool,
+    allow_mixed_alphanumeric: bool,
+}
+
+impl WidthPolicy {
+    /// Allows (`true`) or reports (`false`) mixed widths within `class`.
+    pub fn allow(mut self, class: WidthClass, allowed: bool) -> Self {
+        match class {
+            WidthClass::Katakana => self.allow_mixed_katakana = allowed,
+            WidthClass::Alphanumeric => self.allow_mixed_alphanumeric = allowed,
+        }
+        self
+    }
+
+    /// Returns `true` if mixed widths within `class` are allowed.
+    pub fn is_allowed(&self, class: WidthClass) -> bool {
+        match class {
+            WidthClass::Katakana => self.allow_mixed_katakana,
+            WidthClass::Alphanumeric => self.allow_mixed_alphanumeric,
+        }
+    }
+}
+
+// ── width consistency ─────────────────────────────────────────────────────────
+
+/// A class of characters that appears in both widths, found by
+/// [`check_width_consistency`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WidthLintWarning {
+    /// The class whose widths are mixed.
+    pub class: WidthClass,
+    /// Zero-based character indices of the halfwidth forms.
+    pub halfwidth_positions: Vec<usize>,
+    /// Zero-based character indices of the fullwidth forms.
+    pub fullwidth_positions: Vec<usize>,
+}
+
+impl fmt::Display for WidthLintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mixed halfwidth and fullwidth {}", self.class.as_str())?;
+        match (
+            self.halfwidth_positions.first(),
+            self.fullwidth_positions.first(),
+        ) {
+            (Some(half), Some(full)) => write!(
+                f,
+                ": halfwidth at position {}, fullwidth at position {}",
+                half, full
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Reports every [`WidthClass`] that occurs in `s` in both its halfwidth and
+/// fullwidth form, unless `policy` allows the mix.
+///
+/// Warnings are ordered katakana first, then alphanumerics.  Characters of
+/// neither class (hiragana, kanji, punctuation, spaces) are ignored.
+pub fn check_width_consistency(s: &str, policy: &WidthPolicy) -> Vec<WidthLintWarning> {
+    let mut warnings =
+        [WidthClass::Katakana, WidthClass::Alphanumeric].map(|class| WidthLintWarning {
+            class,
+            halfwidth_positions: Vec::new(),
+            fullwidth_positions: Vec::new(),
+        });
+    for (position, c) in s.chars().enumerate() {
+        if let Some((class, halfwidth)) = WidthClass::of(c) {
+            let Some(warning) = warnings.get_mut(class as usize) else {
+                continue;
+            };
+            if halfwidth {
+                warning.halfwidth_positions.push(position);
+            } else {
+                warning.fullwidth_positions.push(position);
+            }
+        }
+    }
+    warnings
+        .into_iter()
+        .filter(|w| {
+            !policy.is_allowed(w.class)
+                && !w.halfwidth_positions.is_empty()
+                && !w.fullwidth_positions.is_empty()
+        })
+        .collect()
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(s: &str) -> Vec<WidthClass> {
+        check_width_consistency(s, &WidthPolicy::default())
+            .iter()
+            .map(|w| w.class)
+            .collect()
+    }
+
+    #[test]
+    fn test_katakana_mix_both_directions() {
+        assert_eq!(classes("ﾃｽﾄテスト"), vec![WidthClass::Katakana]);
+        let w = &check_width_consistency("テストﾃｽﾄ", &WidthPolicy::default())[0];
+        assert_eq!(w.halfwidth_positions, vec![3, 4, 5]);
+        assert_eq!(w.fullwidth_positions, vec![0, 1, 2]);
+        // ｰ and ー count as katakana of their width.
+        assert_eq!(classes("ｰー"), vec![WidthClass::Katakana]);
+    }
+
+    #[test]
+    fn test_alphanumeric_mix_both_directions() {
+        let w = &check_width_consistency("１23", &WidthPolicy::default())[0];
+        assert_eq!(w.class, WidthClass::Alphanumeric);
+        assert_eq!(w.halfwidth_positions, vec![1, 2]);
+        assert_eq!(w.fullwidth_positions, vec![0]);
+        assert_eq!(classes("abcＤ"), vec![WidthClass::Alphanumeric]);
+        assert_eq!(
+            classes("ｱア1１"),
+            vec![WidthClass::Katakana, WidthClass::Alphanumeric]
+        );
+    }
+
+    #[test]
+    fn test_single_width_strings_are_clean() {
+        for s in [
+            "",
+            "テスト",
+            "ﾃｽﾄ",
+            "abc123",
+            "ＡＢＣ１２３",
+            "テスト123",
+            "ﾃｽﾄＡＢＣ",
+            "ひらがな漢字",
+        ] {
+            assert!(classes(s).is_empty(), "{} should be clean", s);
+        }
+        // Halfwidth punctuation is not katakana.
+        assert!(classes("｢テスト｣").is_empty());
+    }
+
+    #[test]
+    fn test_policy() {
+        let policy = WidthPolicy::default().allow(WidthClass::Katakana, true);
+        assert!(policy.is_allowed(WidthClass::Katakana));
+        assert!(!policy.is_allowed(WidthClass::Alphanumeric));
+        assert!(check_width_consistency("ｱア", &policy).is_empty());
+        assert_eq!(check_width_consistency("ｱア1１", &policy).len(), 1);
+    }
+
+    #[test]
+    fn test_width_class_names() {
+        for class in [WidthClass::Katakana, WidthClass::Alphanumeric] {
+            assert_eq!(class.as_str().parse(), Ok(class));
+        }
+        assert!("kana".parse::<WidthClass>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let w = &check_width_consistency("ｱア", &WidthPolicy::default())[0];
+        assert_eq!(
+            w.to_string(),
+            "mixed halfwidth and fullwidth katakana: halfwidth at position 0, fullwidth at position 1"
+        );
+    }
+}
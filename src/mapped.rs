@@ -0,0 +1,180 @@
+//! Sorted table files, read or memory-mapped read-only (`mmap` feature).
+//!
+//! A sorted table file holds code points as little-endian `u32`s in strictly
+//! ascending order, with no header.  [`CodePoints::from_sorted_file`] reads
+//! it into memory; [`CodePoints::map_sorted_file`] maps it read-only, so
+//! worker processes loading the same table share its pages instead of each
+//! building a set at start-up.
+//!
+//! [`CodePoints::from_sorted_file`]: crate::CodePoints::from_sorted_file
+//! [`CodePoints::map_sorted_file`]: crate::CodePoints::map_sorted_file
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use memmap2::Mmap;
+
+/// Error returned by [`CodePoints::from_sorted_file`] and
+/// [`CodePoints::map_sorted_file`].
+///
+/// [`CodePoints::from_sorted_file`]: crate::CodePoints::from_sorted_file
+/// [`CodePoints::map_sorted_file`]: crate::CodePoints::map_sorted_file
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SortedFileError {
+    /// The file could not be opened, read or mapped.
+    Io(io::Error),
+    /// The file length in bytes is not a multiple of 4.
+    InvalidLength(u64),
+    /// An entry is not greater than the one before it.
+    Unsorted {
+        /// Zero-based index of the entry.
+        index: usize,
+    },
+    /// A stored value is not a Unicode scalar value.
+    InvalidCodePoint(u32),
+}
+
+impl fmt::Display for SortedFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortedFileError::Io(e) => write!(f, "cannot load sorted table: {}", e),
+            SortedFileError::InvalidLength(len) => {
+                write!(f, "sorted table length {} is not a multiple of 4", len)
+            }
+            SortedFileError::Unsorted { index } => {
+                write!(f, "sorted table entry {} is out of order", index)
+            }
+            SortedFileError::InvalidCodePoint(cp) => {
+                write!(f, "invalid code point U+{:04X}", cp)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SortedFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SortedFileError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SortedFileError {
+    fn from(e: io::Error) -> Self {
+        SortedFileError::Io(e)
+    }
+}
+
+/// A validated sorted table file, mapped read-only.
+pub(crate) struct MappedTable {
+    map: Mmap,
+}
+
+impl MappedTable {
+    /// Returns the members, in ascending order.
+    pub(crate) fn members(&self) -> &[u32] {
+        // SAFETY: every bit pattern is a valid `u32`, and `align_to` only
+        // puts aligned elements in the middle slice.  `open` only builds a
+        // table whose prefix and suffix are empty, on little-endian targets.
+        let (_, members, _) = unsafe { self.map.align_to::<u32>() };
+        members
+    }
+}
+
+/// A sorted table, mapped when the platform allows it.
+pub(crate) enum SortedTable {
+    Mapped(MappedTable),
+    /// Decoded copy, for big-endian targets or a misaligned map.
+    Owned(Vec<u32>),
+}
+
+/// Reads the file at `path` and validates every entry.
+pub(crate) fn read(path: &Path) -> Result<Vec<u32>, SortedFileError> {
+    let bytes = fs::read(path)?;
+    validate(&bytes)?;
+    Ok(bytes.chunks_exact(4).map(decode).collect())
+}
+
+/// Maps the file at `path` and validates every entry.
+///
+/// # Safety
+///
+/// The file must not be modified or truncated while the returned table is
+/// alive; see `CodePoints::map_sorted_file`.
+pub(crate) unsafe fn map(path: &Path) -> Result<SortedTable, SortedFileError> {
+    let file = File::open(path)?;
+    // SAFETY: the map is read-only, and the caller guarantees the file is
+    // not changed while it is alive.
+    let map = unsafe { Mmap::map(&file)? };
+    validate(&map)?;
+
+    // SAFETY: as in `MappedTable::members`; only the lengths are used here.
+    let (prefix, _, suffix) = unsafe { map.align_to::<u32>() };
+    if cfg!(target_endian = "little") && prefix.is_empty() && suffix.is_empty() {
+        Ok(SortedTable::Mapped(MappedTable { map }))
+    } else {
+        let members = map.chunks_exact(4).map(decode).collect();
+        Ok(SortedTable::Owned(members))
+    }
+}
+
+/// Checks that `bytes` is a sorted table: whole entries, strictly
+/// ascending, all Unicode scalar values.
+fn validate(bytes: &[u8]) -> Result<(), SortedFileError> {
+    let entries = bytes.chunks_exact(4);
+    if !entries.remainder().is_empty() {
+        return Err(SortedFileError::InvalidLength(bytes.len() as u64));
+    }
+    let mut prev = None;
+    for (index, entry) in entries.enumerate() {
+        let cp = decode(entry);
+        if char::from_u32(cp).is_none() {
+            return Err(SortedFileError::InvalidCodePoint(cp));
+        }
+        if prev.is_some_and(|prev| prev >= cp) {
+            return Err(SortedFileError::Unsorted { index });
+        }
+        prev = Some(cp);
+    }
+    Ok(())
+}
+
+/// Decodes one little-endian entry; `entry` is always a 4-byte chunk.
+fn decode(entry: &[u8]) -> u32 {
+    <[u8; 4]>::try_from(entry).map_or(0, u32::from_le_bytes)
+}
+
+/// Writes `members`, which must be sorted, as a sorted table file.
+///
+/// The table is written to a temporary file in the same directory and then
+/// renamed over `path`, so a process that has the old file mapped keeps
+/// reading the old contents instead of seeing it truncated.
+pub(crate) fn write(path: &Path, members: &[u32]) -> io::Result<()> {
+    let name = path.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "sorted table path has no file name",
+        )
+    })?;
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(name);
+    temp_name.push(format!(".{}.tmp", std::process::id()));
+    let temp = path.with_file_name(temp_name);
+
+    let written = (|| {
+        let mut out = BufWriter::new(File::create(&temp)?);
+        for cp in members {
+            out.write_all(&cp.to_le_bytes())?;
+        }
+        out.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        fs::rename(&temp, path)
+    })();
+    if written.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    written
+}
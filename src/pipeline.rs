@@ -0,0 +1,324 @@
+//! Normalize-then-validate pipelines.
+//!
+//! A [`Pipeline`] runs a fixed sequence of [`Normalize`] stages over a
+//! string and then, optionally, checks the result against a character set.
+//! It is built once and reused: it is `Send + Sync` and [`Pipeline::run`]
+//! takes `&self`.
+//!
+//! Ordering guarantees:
+//!
+//! * normalization stages run in the order they were added, each on the
+//!   output of the previous one;
+//! * validation always runs last, on the fully normalized string, however
+//!   the builder calls were ordered.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::pipeline::{Normalize, Pipeline};
+//! use japanese_codepoints::CodePoints;
+//!
+//! let allowed = CodePoints::ascii_printable().union(&CodePoints::from_string("ガギグゲゴアイウ"));
+//! let pipeline = Pipeline::new()
+//!     .normalize(Normalize::Widths)
+//!     .normalize(Normalize::ComposeKana)
+//!     .validate(&allowed);
+//!
+//! let outcome = pipeline.run("ＩＤ：ｶﾞｲｸ\u{3099}");
+//! assert_eq!(outcome.normalized, "ID:ガイグ");
+//! assert!(outcome.is_valid());
+//! assert_eq!(outcome.changed_by, [0, 1]); // both stages changed the text
+//!
+//! let outcome = pipeline.run("ガッ");
+//! assert_eq!(outcome.report.unwrap().first.char_index, 1);
+//! ```
+
+use crate::sanitize::compose_kana;
+use crate::validation::ExclusionReport;
+use crate::CodePoints;
+
+/// A normalization stage of a [`Pipeline`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Normalize {
+    /// Makes katakana fullwidth and alphanumerics halfwidth, the usual
+    /// convention for Japanese text: JIS X 0201 katakana and punctuation
+    /// become their JIS X 0208 forms (`ｶﾞ` → `ガ`, `｡` → `。`), fullwidth
+    /// ASCII (U+FF01–U+FF5E) becomes ASCII and `　` (U+3000) a space.
+    Widths,
+    /// Composes a kana and the sound mark after it — combining (U+3099,
+    /// U+309A), spacing (`゛`, `゜`) or halfwidth (`ﾞ`, `ﾟ`) — into the
+    /// precomposed kana (`か` + U+3099 → `が`).  Marks that cannot be
+    /// composed are kept.
+    ComposeKana,
+}
+
+impl Normalize {
+    /// Applies this stage to `s`.
+    pub fn apply(self, s: &str) -> String {
+        match self {
+            Normalize::Widths => crate::conversion::halfwidth_to_fullwidth_katakana(s)
+                .chars()
+                .map(|c| match c as u32 {
+                    0x3000 => ' ',
+                    cp @ 0xFF01..=0xFF5E => char::from_u32(cp - 0xFEE0).unwrap_or(c),
+                    _ => c,
+                })
+                .collect(),
+            Normalize::ComposeKana => {
+                let mut out = String::with_capacity(s.len());
+                let mut chars = s.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match chars.peek().and_then(|&mark| compose_kana(c, mark)) {
+                        Some(composed) => {
+                            out.push(composed);
+                            chars.next();
+                        }
+                        None => out.push(c),
+                    }
+                }
+                out
+            }
+        }
+    }
+}
+
+/// One stage of a [`Pipeline`], as listed by [`Pipeline::stages`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage<'a> {
+    /// A normalization stage.
+    Normalize(Normalize),
+    /// The final check against a character set.
+    Validate(&'a CodePoints),
+}
+
+/// The result of [`Pipeline::run`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PipelineOutcome {
+    /// The input after every normalization stage.
+    pub normalized: String,
+    /// Why `normalized` failed validation, or `None` if it passed or the
+    /// pipeline has no validation stage.
+    pub report: Option<ExclusionReport>,
+    /// Indices into [`Pipeline::stages`] of the normalization stages that
+    /// changed the string, in order.
+    pub changed_by: Vec<usize>,
+}
+
+impl PipelineOutcome {
+    /// Returns `true` if the normalized string passed validation.
+    pub fn is_valid(&self) -> bool {
+        self.report.is_none()
+    }
+
+    /// Returns `true` if no normalization stage changed the input.
+    pub fn is_unchanged(&self) -> bool {
+        self.changed_by.is_empty()
+    }
+}
+
+/// A reusable sequence of [`Normalize`] stages followed by an optional
+/// validation.
+///
+/// See the [module documentation](self) for the ordering guarantees.
+#[derive(Debug, Clone, Default)]
+pub struct Pipeline<'a> {
+    stages: Vec<Stage<'a>>,
+}
+
+impl<'a> Pipeline<'a> {
+    /// Creates an empty pipeline, which returns its input unchanged and
+    /// valid.
+    pub fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Appends a normalization stage, after those already added and before
+    /// validation.
+    #[must_use]
+    pub fn normalize(mut self, normalize: Normalize) -> Self {
+        let at = self
+            .stages
+            .iter()
+            .position(|stage| matches!(stage, Stage::Validate(_)))
+            .unwrap_or(self.stages.len());
+        self.stages.insert(at, Stage::Normalize(normalize));
+        self
+    }
+
+    /// Validates the normalized string against `set`, replacing any earlier
+    /// validation stage.
+    #[must_use]
+    pub fn validate(mut self, set: &'a CodePoints) -> Self {
+        self.stages
+            .retain(|stage| !matches!(stage, Stage::Validate(_)));
+        self.stages.push(Stage::Validate(set));
+        self
+    }
+
+    /// Returns the stages in the order they run; validation, if any, is
+    /// last.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::pipeline::{Normalize, Pipeline, Stage};
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let ascii = CodePoints::ascii_printable();
+    /// let pipeline = Pipeline::new().validate(&ascii).normalize(Normalize::Widths);
+    /// assert_eq!(
+    ///     pipeline.stages(),
+    ///     [Stage::Normalize(Normalize::Widths), Stage::Validate(&ascii)]
+    /// );
+    /// ```
+    pub fn stages(&self) -> &[Stage<'a>] {
+        &self.stages
+    }
+
+    /// Runs every stage over `s`.
+    pub fn run(&self, s: &str) -> PipelineOutcome {
+        let mut outcome = PipelineOutcome {
+            normalized: s.to_owned(),
+            ..PipelineOutcome::default()
+        };
+        for (index, stage) in self.stages.iter().enumerate() {
+            match *stage {
+                Stage::Normalize(normalize) => {
+                    let next = normalize.apply(&outcome.normalized);
+                    if next != outcome.normalized {
+                        outcome.normalized = next;
+                        outcome.changed_by.push(index);
+                    }
+                }
+                Stage::Validate(set) => {
+                    outcome.report = set.check(&outcome.normalized).err();
+                }
+            }
+        }
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kana() -> CodePoints {
+        CodePoints::from_slice(crate::data::jisx0208::KATAKANA)
+            .union(&CodePoints::from_slice(crate::data::jisx0208::HIRAGANA))
+    }
+
+    #[test]
+    fn test_normalize_widths() {
+        assert_eq!(
+            Normalize::Widths.apply("ＡＢＣ　１２３ ｶﾞｯｺｳ｡"),
+            "ABC 123 ガッコウ。"
+        );
+        // Already-normalized text and fullwidth kana are untouched.
+        assert_eq!(Normalize::Widths.apply("abc カナ。"), "abc カナ。");
+        assert_eq!(Normalize::Widths.apply(""), "");
+    }
+
+    #[test]
+    fn test_normalize_compose_kana() {
+        let stage = Normalize::ComposeKana;
+        assert_eq!(stage.apply("か\u{3099}は\u{309A}"), "がぱ");
+        assert_eq!(stage.apply("か゛ハ゜ｶﾞ"), "がパガ");
+        // Marks that do not compose are kept.
+        assert_eq!(stage.apply("あ\u{3099}゛"), "あ\u{3099}゛");
+        assert_eq!(stage.apply("\u{3099}か"), "\u{3099}か");
+    }
+
+    #[test]
+    fn test_stage_order() {
+        let kana = kana();
+        let pipeline = Pipeline::new()
+            .validate(&kana)
+            .normalize(Normalize::ComposeKana)
+            .normalize(Normalize::Widths);
+        assert_eq!(
+            pipeline.stages(),
+            [
+                Stage::Normalize(Normalize::ComposeKana),
+                Stage::Normalize(Normalize::Widths),
+                Stage::Validate(&kana),
+            ]
+        );
+
+        // A second validate replaces the first and stays last.
+        let ascii = CodePoints::ascii_printable();
+        let pipeline = pipeline.validate(&ascii);
+        assert_eq!(pipeline.stages().len(), 3);
+        assert_eq!(pipeline.stages()[2], Stage::Validate(&ascii));
+    }
+
+    #[test]
+    fn test_changed_by_records_each_stage() {
+        let widths_first = Pipeline::new()
+            .normalize(Normalize::Widths)
+            .normalize(Normalize::ComposeKana);
+        let compose_first = Pipeline::new()
+            .normalize(Normalize::ComposeKana)
+            .normalize(Normalize::Widths);
+
+        // ｶﾞ composes in whichever stage sees it first.
+        let outcome = widths_first.run("ｶﾞ");
+        assert_eq!(
+            (outcome.normalized.as_str(), outcome.changed_by),
+            ("ガ", vec![0])
+        );
+        let outcome = compose_first.run("ｶﾞ");
+        assert_eq!(
+            (outcome.normalized.as_str(), outcome.changed_by),
+            ("ガ", vec![0])
+        );
+
+        // With a combining mark, widening leaves カ + U+3099 for the
+        // composition stage; composing first handles both at once.
+        let outcome = widths_first.run("ｶ\u{3099}");
+        assert_eq!(outcome.normalized, "ガ");
+        assert_eq!(outcome.changed_by, [0, 1]);
+        let outcome = compose_first.run("ｶ\u{3099}");
+        assert_eq!(outcome.normalized, "ガ");
+        assert_eq!(outcome.changed_by, [0]);
+
+        let outcome = widths_first.run("ガ");
+        assert!(outcome.is_unchanged());
+        assert!(outcome.is_valid());
+    }
+
+    #[test]
+    fn test_validation_runs_on_normalized_text() {
+        let kana = kana();
+        let pipeline = Pipeline::new().normalize(Normalize::Widths).validate(&kana);
+        assert!(pipeline.run("ｱｲｳ").is_valid());
+
+        // The report's positions refer to the normalized string.
+        let outcome = pipeline.run("ｱＢｳ");
+        assert_eq!(outcome.normalized, "アBウ");
+        let report = outcome.report.unwrap();
+        assert_eq!(report.first.code_point, 'B' as u32);
+        assert_eq!(report.first.char_index, 1);
+
+        // Without a validation stage nothing is reported.
+        assert!(Pipeline::new().run("漢字").is_valid());
+        assert_eq!(Pipeline::new().run("ｱ").normalized, "ｱ");
+    }
+
+    #[test]
+    fn test_pipeline_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        let kana = kana();
+        let pipeline = Pipeline::new().normalize(Normalize::Widths).validate(&kana);
+        assert_send_sync(&pipeline);
+
+        std::thread::scope(|scope| {
+            for text in ["ｱ", "あ", "x"] {
+                let pipeline = &pipeline;
+                scope.spawn(move || pipeline.run(text));
+            }
+        });
+    }
+}
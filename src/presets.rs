@@ -0,0 +1,757 @@
+//! Ready-made character policies for common Japanese form fields
+//! (`codepoints-jisx0208` feature).
+//!
+//! Each preset pairs an audited repertoire with a validator and a
+//! normalizer for the near-misses users typically type.
+//!
+//! # Postal codes and phone numbers
+//!
+//! [`postal_code`] and [`phone_number`] return small validators that check
+//! both the character set and the structure of the field, reporting which
+//! of the two failed through [`FieldError`].
+//!
+//! ```rust
+//! use japanese_codepoints::presets::{postal_code, FieldError};
+//!
+//! let postal = postal_code();
+//! assert!(postal.validate("〒123-4567").is_ok());
+//! assert_eq!(postal.normalize("１２３−４５６７"), "123-4567");
+//! assert!(matches!(postal.validate("123-456"), Err(FieldError::BadFormat(_))));
+//! assert!(matches!(postal.validate("123-456a"), Err(FieldError::InvalidChar(_))));
+//! ```
+//!
+//! # Corporate names in katakana
+//!
+//! [`corporate_name_kana`] follows the commercial registration rules
+//! (商業登記規則 第50条, 平成14年法務省告示第315号): JIS X 0208 katakana plus
+//! `ー` and the separators `＆ ’ ， ‐ － ． ・`.  Only the repertoire is
+//! checked, not where the separators appear.
+//!
+//! ```rust
+//! use japanese_codepoints::presets::{corporate_name_kana, normalize_corporate_name_kana};
+//!
+//! assert!(!corporate_name_kana().contains("トヨタ自動車"));
+//! let name = normalize_corporate_name_kana("ｿﾆｰ ﾎｰﾙﾃﾞｨﾝｸﾞｽ");
+//! assert_eq!(name, "ソニー・ホールディングス");
+//! assert!(corporate_name_kana().contains(&name));
+//! ```
+//!
+//! # Kanji in given names
+//!
+//! [`legal_name_kanji`] accepts the jōyō and jinmeiyō kanji only and says
+//! whether a rejected character is a JIS kanji that names may not use or
+//! not a JIS kanji at all.
+//!
+//! ```rust
+//! use japanese_codepoints::presets::{legal_name_kanji, NameKanjiErrorKind};
+//!
+//! let names = legal_name_kanji();
+//! assert!(names.validate("凜").is_ok());
+//! assert_eq!(names.classify('腥'), Some(NameKanjiErrorKind::JisButNotNameLegal));
+//! ```
+//!
+//! # Invisible characters
+//!
+//! [`dangerous_invisibles`] is a deny-list of bidi controls, zero-width
+//! characters and interlinear annotations, for use with
+//! [`Validator::deny_set`](crate::validator::Validator::deny_set) on top of
+//! any allow-list.
+//!
+//! ```rust
+//! use japanese_codepoints::presets::dangerous_invisibles;
+//! use japanese_codepoints::validation::ValidationErrorKind;
+//! use japanese_codepoints::validator::Validator;
+//! use japanese_codepoints::CodePoints;
+//!
+//! let any = CodePoints::from_char_range('\0'..='\u{FFFF}');
+//! let validator = Validator::new().allow(&any).deny_set(dangerous_invisibles());
+//! let err = validator.validate("あ\u{202E}ん").unwrap_err();
+//! assert_eq!(err.kind, ValidationErrorKind::DeniedCharacter);
+//! assert_eq!(err.position, 1);
+//! ```
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::conversion::halfwidth_to_fullwidth_katakana;
+use crate::data::jisx0213kanji::JISX0213_KANJI;
+use crate::data::presets::{CORPORATE_NAME_KANA_SYMBOLS, JINMEIYO_KANJI, JOYO_KANJI};
+use crate::data::unicode;
+use crate::validation::ValidationError;
+use crate::CodePoints;
+
+const MIDDLE_DOT: char = '\u{30FB}'; // ・
+
+// ── corporate names ───────────────────────────────────────────────────────────
+
+/// Returns the characters allowed in a katakana corporate name: JIS X 0208
+/// katakana (`ァ`–`ヶ`), `ー`, and the registration separators
+/// `＆ ’ ， ‐ － ． ・` (see [`crate::data::presets::CORPORATE_NAME_KANA_SYMBOLS`]).
+pub fn corporate_name_kana() -> &'static CodePoints {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    SET.get_or_init(|| {
+        crate::jisx0208::Katakana::cached()
+            .codepoints()
+            .union(&CodePoints::from_slice(CORPORATE_NAME_KANA_SYMBOLS))
+    })
+}
+
+/// Validates `s` against [`corporate_name_kana`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::presets::validate_corporate_name_kana;
+///
+/// assert!(validate_corporate_name_kana("カブシキガイシャ　エー＆ビー").is_err());
+/// assert!(validate_corporate_name_kana("エー＆ビー").is_ok());
+/// ```
+pub fn validate_corporate_name_kana(s: &str) -> Result<(), ValidationError> {
+    corporate_name_kana().validate(s)
+}
+
+/// Maps common near-misses in `s` onto the [`corporate_name_kana`]
+/// repertoire:
+///
+/// * halfwidth katakana becomes fullwidth (`ｿﾆｰ` → `ソニー`, `･` → `・`);
+/// * each run of ASCII or fullwidth spaces between words becomes `・`, and
+///   leading or trailing spaces are dropped;
+/// * ASCII `& ' , - .` become `＆ ’ ， － ．`.
+///
+/// Anything else, including kanji, is left for the validator to reject.
+pub fn normalize_corporate_name_kana(s: &str) -> String {
+    let fullwidth = halfwidth_to_fullwidth_katakana(s);
+    let mut out = String::with_capacity(fullwidth.len());
+    let mut pending_space = false;
+    for c in fullwidth.chars() {
+        if c == ' ' || c == '\u{3000}' {
+            pending_space = !out.is_empty();
+            continue;
+        }
+        if pending_space {
+            out.push(MIDDLE_DOT);
+            pending_space = false;
+        }
+        out.push(match c {
+            '&' => '＆',
+            '\'' => '’',
+            ',' => '，',
+            '-' => '－',
+            '.' => '．',
+            other => other,
+        });
+    }
+    out
+}
+
+// ── field errors ──────────────────────────────────────────────────────────────
+
+/// Why a structured field such as a [postal code](postal_code) was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldError {
+    /// A character outside the field's repertoire.
+    InvalidChar(ValidationError),
+    /// Every character is allowed, but they do not form a valid value.
+    BadFormat(&'static str),
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::InvalidChar(err) => err.fmt(f),
+            FieldError::BadFormat(reason) => write!(f, "bad format: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FieldError::InvalidChar(err) => Some(err),
+            FieldError::BadFormat(_) => None,
+        }
+    }
+}
+
+/// Maps fullwidth digits to ASCII and the hyphen-like characters to `-`.
+fn normalize_digits(c: char) -> char {
+    match c {
+        '０'..='９' => char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap_or(c),
+        '－' | '−' | '‐' => '-',
+        '＋' => '+',
+        '（' => '(',
+        '）' => ')',
+        other => other,
+    }
+}
+
+fn field_set(symbols: &str) -> CodePoints {
+    CodePoints::from_string("0123456789０１２３４５６７８９-－−‐").with(symbols)
+}
+
+// ── postal codes ──────────────────────────────────────────────────────────────
+
+/// Validator for Japanese postal codes, returned by [`postal_code`].
+#[derive(Debug, Clone, Copy)]
+pub struct PostalCode;
+
+/// Returns the postal-code validator: an optional leading `〒`, then seven
+/// digits with an optional hyphen after the third (`〒123-4567`,
+/// `1234567`).
+///
+/// Digits may be fullwidth and the hyphen may be `-`, `－`, `−` or `‐`.
+pub fn postal_code() -> PostalCode {
+    PostalCode
+}
+
+impl PostalCode {
+    /// Returns the characters a postal code may contain.
+    pub fn codepoints(&self) -> &'static CodePoints {
+        static SET: OnceLock<CodePoints> = OnceLock::new();
+        SET.get_or_init(|| field_set("〒"))
+    }
+
+    /// Converts fullwidth digits and hyphens to ASCII and drops a leading
+    /// `〒`, e.g. `〒１２３−４５６７` → `123-4567`.
+    pub fn normalize(&self, s: &str) -> String {
+        s.strip_prefix('〒')
+            .unwrap_or(s)
+            .chars()
+            .map(normalize_digits)
+            .collect()
+    }
+
+    /// Checks the character set, then the structure.
+    pub fn validate(&self, s: &str) -> Result<(), FieldError> {
+        self.codepoints()
+            .validate(s)
+            .map_err(FieldError::InvalidChar)?;
+        let body: Vec<char> = self.normalize(s).chars().collect();
+        if body.contains(&'〒') {
+            return Err(FieldError::BadFormat("〒 is only allowed at the start"));
+        }
+        let digits = body.iter().filter(|c| c.is_ascii_digit()).count();
+        if digits != 7 {
+            return Err(FieldError::BadFormat("expected exactly 7 digits"));
+        }
+        match body.iter().position(|&c| c == '-') {
+            None if body.len() == 7 => Ok(()),
+            Some(3) if body.len() == 8 => Ok(()),
+            _ => Err(FieldError::BadFormat(
+                "a hyphen is only allowed after the third digit",
+            )),
+        }
+    }
+}
+
+// ── phone numbers ─────────────────────────────────────────────────────────────
+
+/// Validator for phone numbers, returned by [`phone_number`].
+#[derive(Debug, Clone, Copy)]
+pub struct PhoneNumber;
+
+/// Returns the phone-number validator.
+///
+/// A number is digits separated by single hyphens, with at most one pair of
+/// parentheses around a group of digits (`03-1234-5678`, `03(1234)5678`).
+/// Domestic numbers start with `0` and have 10 or 11 digits; international
+/// ones start with `+` and have 8 to 15 digits.  Fullwidth digits and
+/// symbols are accepted.
+pub fn phone_number() -> PhoneNumber {
+    PhoneNumber
+}
+
+impl PhoneNumber {
+    /// Returns the characters a phone number may contain.
+    pub fn codepoints(&self) -> &'static CodePoints {
+        static SET: OnceLock<CodePoints> = OnceLock::new();
+        SET.get_or_init(|| field_set("+＋()（）"))
+    }
+
+    /// Converts fullwidth digits and symbols to ASCII, e.g.
+    /// `０３−１２３４−５６７８` → `03-1234-5678`.
+    pub fn normalize(&self, s: &str) -> String {
+        s.chars().map(normalize_digits).collect()
+    }
+
+    /// Checks the character set, then the structure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::presets::phone_number;
+    ///
+    /// let phone = phone_number();
+    /// assert!(phone.validate("03-1234-5678").is_ok());
+    /// assert!(phone.validate("+81 3 1234 5678").is_err()); // spaces
+    /// assert!(phone.validate("+81-3-1234-5678").is_ok());
+    /// ```
+    pub fn validate(&self, s: &str) -> Result<(), FieldError> {
+        self.codepoints()
+            .validate(s)
+            .map_err(FieldError::InvalidChar)?;
+        let normalized = self.normalize(s);
+        let (international, body) = match normalized.strip_prefix('+') {
+            Some(rest) => (true, rest),
+            None => (false, normalized.as_str()),
+        };
+        if body.contains('+') {
+            return Err(FieldError::BadFormat("+ is only allowed at the start"));
+        }
+
+        let mut prev = None;
+        let mut open_paren = false;
+        let mut paren_pairs = 0;
+        let mut digits = String::new();
+        for c in body.chars() {
+            match c {
+                '0'..='9' => digits.push(c),
+                '-' if matches!(prev, Some('0'..='9' | ')')) => {}
+                '(' if !open_paren && paren_pairs == 0 && prev != Some(')') => open_paren = true,
+                ')' if open_paren && matches!(prev, Some('0'..='9')) => {
+                    open_paren = false;
+                    paren_pairs += 1;
+                }
+                _ => return Err(FieldError::BadFormat("misplaced separator")),
+            }
+            prev = Some(c);
+        }
+        if open_paren || matches!(prev, Some('-' | '(') | None) {
+            return Err(FieldError::BadFormat("misplaced separator"));
+        }
+
+        let ok = if international {
+            (8..=15).contains(&digits.len())
+        } else {
+            digits.starts_with('0') && (10..=11).contains(&digits.len())
+        };
+        if ok {
+            Ok(())
+        } else if international {
+            Err(FieldError::BadFormat("expected 8 to 15 digits after +"))
+        } else {
+            Err(FieldError::BadFormat(
+                "expected 10 or 11 digits starting with 0",
+            ))
+        }
+    }
+}
+
+// ── dangerous invisibles ──────────────────────────────────────────────────────
+
+/// Returns the invisible characters a text field should reject whatever its
+/// allow-list: bidi controls (`U+202A`–`U+202E`, `U+2066`–`U+2069` and the
+/// marks), zero-width characters ([`CodePoints::zero_width`]) and the
+/// interlinear annotation characters `U+FFF9`–`U+FFFB`.
+///
+/// Use it as a deny-list with [`Validator::deny_set`](crate::validator::Validator::deny_set).
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::presets::dangerous_invisibles;
+///
+/// assert!(dangerous_invisibles().contains("\u{202E}\u{200B}\u{FFF9}"));
+/// assert!(!dangerous_invisibles().contains("\u{3000}"));
+/// ```
+pub fn dangerous_invisibles() -> &'static CodePoints {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    SET.get_or_init(|| {
+        let mut codepoints = unicode::BIDI_CONTROL_CHARS.to_vec();
+        codepoints.extend(unicode::ZERO_WIDTH_CHARS);
+        codepoints.extend(unicode::INTERLINEAR_ANNOTATION_CHARS);
+        CodePoints::new(codepoints)
+    })
+}
+
+// ── addresses ─────────────────────────────────────────────────────────────────
+
+/// Returns the characters allowed in an address: JIS X 0208 (non-kanji and
+/// Level 1–2 kanji) plus printable ASCII, so both `１−２−３` and `1-2-3`
+/// pass (`codepoints-jisx0208kanji` feature).
+///
+/// For the fullwidth-only form expected by many back-office systems, see
+/// [`crate::recipes::normalize_then_validate_address`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::presets::address;
+///
+/// assert!(address().contains("東京都千代田区1-2-3 パークビル5F"));
+/// assert!(!address().contains("ﾊﾟｰｸﾋﾞﾙ"));
+/// assert!(!address().contains("髙島屋"));
+/// ```
+#[cfg(feature = "codepoints-jisx0208kanji")]
+pub fn address() -> &'static CodePoints {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    SET.get_or_init(|| crate::JisX0208::with_kanji().union(CodePoints::ascii_printable_cached()))
+}
+
+// ── name kanji ────────────────────────────────────────────────────────────────
+
+/// Why [`LegalNameKanji`] rejected a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NameKanjiErrorKind {
+    /// Not a JIS X 0208 or JIS X 0213 kanji at all: kana, Latin letters
+    /// and ideographs outside JIS alike.
+    NotJisKanji,
+    /// A JIS kanji that is neither jōyō nor jinmeiyō, such as `腥`.
+    JisButNotNameLegal,
+}
+
+/// A character rejected by [`LegalNameKanji::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NameKanjiError {
+    /// Which list the character failed.
+    pub kind: NameKanjiErrorKind,
+    /// The rejected code point.
+    pub code_point: u32,
+    /// Zero-based character index of the rejected code point in the input.
+    pub position: usize,
+}
+
+impl fmt::Display for NameKanjiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = char::from_u32(self.code_point).unwrap_or('\u{FFFD}');
+        let reason = match self.kind {
+            NameKanjiErrorKind::NotJisKanji => "is not a JIS kanji",
+            NameKanjiErrorKind::JisButNotNameLegal => {
+                "is a JIS kanji but neither jōyō nor jinmeiyō"
+            }
+        };
+        write!(
+            f,
+            "character '{}' (U+{:04X}) at position {} {}",
+            ch, self.code_point, self.position, reason
+        )
+    }
+}
+
+impl std::error::Error for NameKanjiError {}
+
+/// Validator for the kanji of given names, returned by
+/// [`legal_name_kanji`].
+///
+/// Holds the name-legal kanji and, precomputed, the JIS X 0208/0213 kanji
+/// that are not name-legal, so a single pass tells the two kinds of
+/// rejection apart.
+#[derive(Debug, Clone)]
+pub struct LegalNameKanji {
+    legal: CodePoints,
+    jis_not_legal: CodePoints,
+}
+
+/// Returns the validator for kanji allowed in given names: the jōyō and
+/// jinmeiyō kanji ([`crate::data::presets::JOYO_KANJI`],
+/// [`crate::data::presets::JINMEIYO_KANJI`]).
+///
+/// Rejections say whether the character is a JIS kanji that is not
+/// name-legal or not a JIS kanji at all.  Only kanji are accepted; check
+/// the kana of a name separately.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::presets::{legal_name_kanji, NameKanjiErrorKind};
+///
+/// let names = legal_name_kanji();
+/// assert!(names.validate("翔太").is_ok());
+/// let err = names.validate("翔腥").unwrap_err();
+/// assert_eq!((err.kind, err.position), (NameKanjiErrorKind::JisButNotNameLegal, 1));
+/// assert_eq!(names.classify('𠀀'), Some(NameKanjiErrorKind::NotJisKanji));
+/// ```
+pub fn legal_name_kanji() -> &'static LegalNameKanji {
+    static NAMES: OnceLock<LegalNameKanji> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        let legal =
+            CodePoints::from_slice(JOYO_KANJI).union(&CodePoints::from_slice(JINMEIYO_KANJI));
+        let jis_not_legal = CodePoints::from_slice(JISX0213_KANJI).difference(&legal);
+        LegalNameKanji {
+            legal,
+            jis_not_legal,
+        }
+    })
+}
+
+impl LegalNameKanji {
+    /// Returns the name-legal kanji.
+    pub fn codepoints(&self) -> &CodePoints {
+        &self.legal
+    }
+
+    /// Returns the JIS X 0208/0213 kanji that are not name-legal.
+    pub fn jis_not_legal(&self) -> &CodePoints {
+        &self.jis_not_legal
+    }
+
+    /// Returns why `c` is rejected, or `None` if it is name-legal.
+    pub fn classify(&self, c: char) -> Option<NameKanjiErrorKind> {
+        if self.legal.contains_char(c) {
+            None
+        } else if self.jis_not_legal.contains_char(c) {
+            Some(NameKanjiErrorKind::JisButNotNameLegal)
+        } else {
+            Some(NameKanjiErrorKind::NotJisKanji)
+        }
+    }
+
+    /// Checks that every character of `s` is a name-legal kanji.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NameKanjiError`] for the first character that is not.
+    pub fn validate(&self, s: &str) -> Result<(), NameKanjiError> {
+        for (position, (byte_index, c)) in s.char_indices().enumerate() {
+            if let Some(kind) = self.classify(c) {
+                let err = ValidationError::new(c as u32, position).with_byte_index(byte_index);
+                crate::validation::report_failure(Some("name-legal kanji"), &err, s.len());
+                return Err(NameKanjiError {
+                    kind,
+                    code_point: c as u32,
+                    position,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legal_name_kanji() {
+        let names = legal_name_kanji();
+        assert_eq!(names.codepoints().len(), 2136 + 863);
+        // Jōyō, jinmeiyō, and a jinmeiyō compatibility ideograph (渚).
+        assert!(names.validate("亜翔凜\u{FA46}").is_ok());
+        assert!(names.validate("").is_ok());
+
+        let check = |c: char| names.classify(c);
+        assert_eq!(check('あ'), Some(NameKanjiErrorKind::NotJisKanji));
+        assert_eq!(check('腥'), Some(NameKanjiErrorKind::JisButNotNameLegal));
+        assert_eq!(check('𠀋'), Some(NameKanjiErrorKind::JisButNotNameLegal)); // JIS X 0213 only
+        assert_eq!(check('𠀀'), Some(NameKanjiErrorKind::NotJisKanji));
+
+        let err = names.validate("太腥").unwrap_err();
+        assert_eq!(
+            (err.kind, err.code_point, err.position),
+            (NameKanjiErrorKind::JisButNotNameLegal, 0x8165, 1)
+        );
+        assert_eq!(
+            err.to_string(),
+            "character '腥' (U+8165) at position 1 is a JIS kanji but neither jōyō nor jinmeiyō"
+        );
+        let err = names.validate("花子ちゃん").unwrap_err();
+        assert_eq!(
+            (err.kind, err.position),
+            (NameKanjiErrorKind::NotJisKanji, 2)
+        );
+        assert_eq!(
+            err.to_string(),
+            "character 'ち' (U+3061) at position 2 is not a JIS kanji"
+        );
+    }
+
+    #[test]
+    fn test_name_kanji_sets_partition_jis_kanji() {
+        let names = legal_name_kanji();
+        let jis = CodePoints::from_slice(JISX0213_KANJI);
+        assert!(names.codepoints().is_subset_of(&jis));
+        assert!(names
+            .jis_not_legal()
+            .intersection(names.codepoints())
+            .is_empty());
+        assert_eq!(names.codepoints().union(names.jis_not_legal()), jis);
+    }
+
+    #[test]
+    fn test_corporate_name_kana_repertoire() {
+        let set = corporate_name_kana();
+        assert_eq!(set.len(), 86 + 8);
+        assert!(set.contains("ァヴヵヶー・＆’，‐－．"));
+        for s in ["あ", "A", "&", " ", "\u{3000}", "ｱ", "漢", "ヷ", "(", "（"] {
+            assert!(!set.contains(s), "{:?} should not be allowed", s);
+        }
+    }
+
+    #[test]
+    fn test_real_looking_names() {
+        let err = validate_corporate_name_kana("トヨタ自動車").unwrap_err();
+        assert_eq!((err.code_point, err.position), ('自' as u32, 3));
+        assert!(validate_corporate_name_kana("ソニー・ホールディングス").is_ok());
+        assert!(validate_corporate_name_kana("ソニー ホールディングス").is_err());
+        assert!(validate_corporate_name_kana("ジョンソン・エンド・ジョンソン").is_ok());
+        assert!(validate_corporate_name_kana("エー．ティー．カーニー").is_ok());
+    }
+
+    #[test]
+    fn test_normalize_corporate_name_kana() {
+        for input in [
+            "ソニー ホールディングス",
+            "ソニー\u{3000}ホールディングス",
+            "ｿﾆｰ･ﾎｰﾙﾃﾞｨﾝｸﾞｽ",
+            "  ｿﾆｰ  \u{3000}ﾎｰﾙﾃﾞｨﾝｸﾞｽ ",
+        ] {
+            assert_eq!(
+                normalize_corporate_name_kana(input),
+                "ソニー・ホールディングス",
+                "input {:?}",
+                input
+            );
+        }
+        assert_eq!(normalize_corporate_name_kana("ｴｲ&ﾋﾞｰ"), "エイ＆ビー");
+        assert_eq!(normalize_corporate_name_kana("ﾏｸﾄﾞﾅﾙﾄﾞ'ｽ"), "マクドナルド’ス");
+        assert_eq!(
+            normalize_corporate_name_kana("ｴｰ.ﾋﾞｰ,ｼｰ-ﾃﾞｨｰ"),
+            "エー．ビー，シー－ディー"
+        );
+        let kanji = normalize_corporate_name_kana("トヨタ自動車");
+        assert!(validate_corporate_name_kana(&kanji).is_err());
+    }
+
+    #[test]
+    fn test_postal_code() {
+        let postal = postal_code();
+        for ok in [
+            "〒123-4567",
+            "123-4567",
+            "1234567",
+            "１２３−４５６７",
+            "〒１２３－４５６７",
+        ] {
+            assert_eq!(postal.validate(ok), Ok(()), "{}", ok);
+        }
+        assert_eq!(postal.normalize("〒１２３−４５６７"), "123-4567");
+
+        for bad in [
+            "123-456",
+            "12-34567",
+            "1234-567",
+            "123--4567",
+            "123-45678",
+            "",
+            "1〒23-4567",
+        ] {
+            assert!(
+                matches!(postal.validate(bad), Err(FieldError::BadFormat(_))),
+                "{}",
+                bad
+            );
+        }
+        let err = postal.validate("123 4567").unwrap_err();
+        assert!(matches!(&err, FieldError::InvalidChar(e) if e.position == 3));
+        assert_eq!(
+            err.to_string(),
+            "invalid character ' ' (U+0020) at position 3"
+        );
+        assert_eq!(
+            postal.validate("123-456").unwrap_err().to_string(),
+            "bad format: expected exactly 7 digits"
+        );
+    }
+
+    #[test]
+    fn test_phone_number() {
+        let phone = phone_number();
+        for ok in [
+            "03-1234-5678",
+            "0312345678",
+            "090-1234-5678",
+            "03(1234)5678",
+            "(03)1234-5678",
+            "０３−１２３４−５６７８",
+            "＋８１−３−１２３４−５６７８",
+            "+81-90-1234-5678",
+        ] {
+            assert_eq!(phone.validate(ok), Ok(()), "{}", ok);
+        }
+        assert_eq!(
+            phone.normalize("（０３）１２３４−５６７８"),
+            "(03)1234-5678"
+        );
+
+        for bad in [
+            "3-1234-5678",
+            "03-1234-567",
+            "03--1234-5678",
+            "-03-1234-5678",
+            "03-1234-5678-",
+            "03(1234-5678",
+            "03)1234(5678",
+            "(03)(1234)5678",
+            "03+12345678",
+            "+81",
+            "",
+        ] {
+            assert!(
+                matches!(phone.validate(bad), Err(FieldError::BadFormat(_))),
+                "{}",
+                bad
+            );
+        }
+        assert!(matches!(
+            phone.validate("03-1234-ABCD"),
+            Err(FieldError::InvalidChar(e)) if e.position == 8
+        ));
+    }
+
+    #[test]
+    fn test_dangerous_invisibles() {
+        let set = dangerous_invisibles();
+        assert_eq!(set.len(), 12 + 5 + 3);
+        assert!(set.contains("\u{202A}\u{202E}\u{2066}\u{2069}\u{200E}\u{FEFF}\u{FFFB}"));
+        for s in [" ", "\u{3000}", "\t", "\u{00AD}", "あ"] {
+            assert!(!set.contains(s), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn test_deny_invisibles_after_allow_list() {
+        use crate::validation::ValidationErrorKind;
+        use crate::validator::{Validator, Violation};
+
+        let allowed = crate::jisx0208::Hiragana::cached()
+            .codepoints()
+            .union(CodePoints::zero_width_cached())
+            .union(&CodePoints::from_string("\u{202E}"));
+        let validator = Validator::new()
+            .allow(&allowed)
+            .deny_set(dangerous_invisibles());
+        assert!(validator.is_valid("あん"));
+
+        // Bidi spoofing: the override is allowed but denied.
+        let err = validator.validate("あ\u{202E}ん").unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::DeniedCharacter);
+        assert_eq!((err.code_point, err.position), (0x202E, 1));
+        assert_eq!(
+            err.to_string(),
+            "U+202E (bidi control) at position 1 is denied"
+        );
+
+        // ZWSP padding.
+        assert_eq!(
+            validator.first_violation("\u{200B}あん\u{200B}"),
+            Some(Violation::Denied {
+                code_point: 0x200B,
+                position: 0,
+                class: "zero-width",
+            })
+        );
+
+        // The allow-list is checked first.
+        assert_eq!(
+            validator.first_violation("ア\u{202E}"),
+            Some(Violation::NotAllowed {
+                code_point: 0x30A2,
+                position: 0,
+            })
+        );
+    }
+}
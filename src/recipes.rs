@@ -0,0 +1,291 @@
+//! Ready-to-call compositions of the crate's primitives
+//! (`codepoints-jisx0208` feature).
+//!
+//! Each recipe answers a question that comes up repeatedly and would
+//! otherwise be copy-pasted from the documentation.  They are small enough
+//! to read as examples, and some need further features:
+//!
+//! | Recipe | Extra features |
+//! |---|---|
+//! | [`is_kana_with_prolonged`] | — |
+//! | [`is_jis_level1_text`] | `codepoints-jisx0208kanji` |
+//! | [`normalize_then_validate_address`] | `codepoints-jisx0208kanji` |
+//! | [`is_shift_jis_safe_filename`] | `codepoints-jisx0201`, `codepoints-jisx0208kanji` |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::recipes::is_kana_with_prolonged;
+//!
+//! assert!(is_kana_with_prolonged("ラーメンとらーめん"));
+//! assert!(!is_kana_with_prolonged("ーメン"));
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::jisx0208::{Hiragana, Katakana};
+use crate::kana::{OrthographyChecker, Rule};
+use crate::CodePoints;
+
+const LONG_VOWEL: u32 = 0x30FC; // ー
+
+// ── kana ──────────────────────────────────────────────────────────────────────
+
+/// Returns `true` if `s` consists of JIS X 0208 hiragana, katakana and `ー`,
+/// with every `ー` following a kana.
+///
+/// The empty string passes, as with [`CodePoints::contains`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::recipes::is_kana_with_prolonged;
+///
+/// assert!(is_kana_with_prolonged("コーヒー"));
+/// assert!(!is_kana_with_prolonged("コーヒー牛乳"));
+/// assert!(!is_kana_with_prolonged("ｺｰﾋｰ"));
+/// ```
+pub fn is_kana_with_prolonged(s: &str) -> bool {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    let set = SET.get_or_init(|| {
+        Hiragana::cached()
+            .codepoints()
+            .union(Katakana::cached().codepoints())
+            .union(&CodePoints::new(vec![LONG_VOWEL]))
+    });
+    set.contains(s)
+        && OrthographyChecker::new()
+            .rule(Rule::NoInitialN, false)
+            .is_valid(s)
+}
+
+// ── JIS Level 1 ───────────────────────────────────────────────────────────────
+
+/// Returns the JIS X 0208 non-kanji characters plus the 2 965 Level 1 kanji
+/// (第1水準, rows 16–47).
+#[cfg(feature = "codepoints-jisx0208kanji")]
+fn jis_level1() -> &'static CodePoints {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    SET.get_or_init(|| {
+        crate::JisX0208::cached()
+            .codepoints()
+            .union(&crate::JisX0208Kanji::rows_in_range(16, 47))
+    })
+}
+
+/// Returns `true` if every character of `s` is a JIS X 0208 non-kanji
+/// character or a Level 1 kanji — the repertoire of systems limited to
+/// 第1水準.
+///
+/// ASCII is not included; normalize it to fullwidth first if needed.  The
+/// empty string passes.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::recipes::is_jis_level1_text;
+///
+/// assert!(is_jis_level1_text("東京都の天気。"));
+/// assert!(!is_jis_level1_text("弌")); // Level 2
+/// assert!(!is_jis_level1_text("Tokyo"));
+/// ```
+#[cfg(feature = "codepoints-jisx0208kanji")]
+pub fn is_jis_level1_text(s: &str) -> bool {
+    jis_level1().contains(s)
+}
+
+// ── addresses ─────────────────────────────────────────────────────────────────
+
+/// Normalizes an address to the fullwidth JIS X 0208 form expected by most
+/// Japanese back-office systems, then validates it against JIS X 0208
+/// (non-kanji and Level 1–2 kanji).
+///
+/// Normalization:
+///
+/// * halfwidth katakana becomes fullwidth (`ｶﾞ` → `ガ`);
+/// * hyphen-like characters (`-`, `－`, `‑`, `‒`, `–`) become `−`
+///   (JIS X 0208 01-61), as in `１−２−３`;
+/// * other printable ASCII becomes its fullwidth form, and the space `　`.
+///
+/// On success the normalized address is returned.  An error's position
+/// refers to the normalized string.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::recipes::normalize_then_validate_address;
+///
+/// assert_eq!(
+///     normalize_then_validate_address("東京都千代田区1-2-3 ﾊﾟｰｸﾋﾞﾙ5F").unwrap(),
+///     "東京都千代田区１−２−３　パークビル５Ｆ"
+/// );
+/// let err = normalize_then_validate_address("髙島屋ビル").unwrap_err();
+/// assert_eq!(err.code_point, '髙' as u32);
+/// ```
+#[cfg(feature = "codepoints-jisx0208kanji")]
+pub fn normalize_then_validate_address(
+    s: &str,
+) -> Result<String, crate::validation::ValidationError> {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    let set = SET.get_or_init(|| {
+        crate::JisX0208::cached()
+            .codepoints()
+            .union(crate::JisX0208Kanji::cached().codepoints())
+    });
+    let normalized: String = crate::conversion::halfwidth_to_fullwidth_katakana(s)
+        .chars()
+        .map(|c| match c {
+            '-' | '\u{FF0D}' | '\u{2011}' | '\u{2012}' | '\u{2013}' => '\u{2212}',
+            ' ' => '\u{3000}',
+            '!'..='~' => char::from_u32(c as u32 + 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .collect();
+    set.validate_as(&normalized, Some("JIS X 0208 address"))?;
+    Ok(normalized)
+}
+
+// ── file names ────────────────────────────────────────────────────────────────
+
+/// Characters Windows forbids in file names.
+#[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208kanji"))]
+const RESERVED_FILENAME_CHARS: &[char] = &['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// Device names Windows reserves regardless of extension.
+#[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208kanji"))]
+const RESERVED_FILENAME_STEMS: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Returns `true` if `s` can be used as a file name on a Shift_JIS
+/// (CP932) system and survives byte-oriented tools unchanged.
+///
+/// The name must:
+///
+/// * be non-empty and encodable in Shift_JIS — printable ASCII, halfwidth
+///   katakana, JIS X 0208;
+/// * contain no character whose second byte is 0x5C, such as `ソ` or `表`
+///   (see [`crate::data::shift_jis::TRAIL_BYTE_5C`]);
+/// * avoid the characters and device names Windows reserves
+///   (`<>:"/\|?*`, `CON`, `NUL.txt`, …) and not end in `.` or a space.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::recipes::is_shift_jis_safe_filename;
+///
+/// assert!(is_shift_jis_safe_filename("請求書_2024年ｶﾞｲﾄﾞ.txt"));
+/// assert!(!is_shift_jis_safe_filename("ソフト.txt")); // ソ is 0x835C
+/// assert!(!is_shift_jis_safe_filename("a?.txt"));
+/// assert!(!is_shift_jis_safe_filename("nul.txt"));
+/// ```
+#[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208kanji"))]
+pub fn is_shift_jis_safe_filename(s: &str) -> bool {
+    static SET: OnceLock<CodePoints> = OnceLock::new();
+    let set = SET.get_or_init(|| {
+        CodePoints::ascii_printable()
+            .union(crate::JisX0201Katakana::cached().codepoints())
+            .union(crate::JisX0208::cached().codepoints())
+            .union(crate::JisX0208Kanji::cached().codepoints())
+            .difference(&CodePoints::from_slice(
+                crate::data::shift_jis::TRAIL_BYTE_5C,
+            ))
+    });
+    let stem = s.split('.').next().unwrap_or(s);
+    !s.is_empty()
+        && set.contains(s)
+        && !s.contains(RESERVED_FILENAME_CHARS)
+        && !s.ends_with(['.', ' '])
+        && !RESERVED_FILENAME_STEMS
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(stem))
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kana_with_prolonged() {
+        for s in ["", "あ", "らーめん", "ラーメン", "ンジャメナ", "ゲーーム"] {
+            assert!(is_kana_with_prolonged(s), "{:?}", s);
+        }
+        for s in [
+            "ー",
+            "ーあ",
+            "ゲーム、ー",
+            "カタカナ ",
+            "ｶﾀｶﾅ",
+            "漢字",
+            "abc",
+        ] {
+            assert!(!is_kana_with_prolonged(s), "{:?}", s);
+        }
+    }
+
+    #[cfg(feature = "codepoints-jisx0208kanji")]
+    #[test]
+    fn test_jis_level1_text() {
+        assert!(is_jis_level1_text(""));
+        assert!(is_jis_level1_text("亜腕　ひらがな、カタカナ。ＡＢＣ"));
+        // 腕 is the last Level 1 kanji, 弌 the first Level 2 kanji.
+        assert!(!is_jis_level1_text("弌"));
+        assert!(!is_jis_level1_text("𠮷"));
+        assert!(!is_jis_level1_text("ABC"));
+        assert!(!is_jis_level1_text("ｱ"));
+    }
+
+    #[cfg(feature = "codepoints-jisx0208kanji")]
+    #[test]
+    fn test_normalize_then_validate_address() {
+        assert_eq!(
+            normalize_then_validate_address("大阪府大阪市北区梅田3‑1‑1 #201").unwrap(),
+            "大阪府大阪市北区梅田３−１−１　＃２０１"
+        );
+        assert_eq!(
+            normalize_then_validate_address("１−２−３").unwrap(),
+            "１−２−３"
+        );
+
+        let err = normalize_then_validate_address("ﾊﾟｰｸ~").unwrap_err();
+        assert_eq!(err.code_point, 0xFF5E); // ～ is not in JIS X 0208
+        assert_eq!(err.position, 3);
+        assert!(normalize_then_validate_address("𠮷野家").is_err());
+        assert!(normalize_then_validate_address("Tokyo\n").is_err());
+    }
+
+    #[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208kanji"))]
+    #[test]
+    fn test_shift_jis_safe_filename() {
+        for s in [
+            "report.txt",
+            "議事録 2024-04.docx",
+            "ﾃｽﾄ",
+            "COM10.log",
+            "console",
+        ] {
+            assert!(is_shift_jis_safe_filename(s), "{:?}", s);
+        }
+        for s in [
+            "",
+            "表計算.xlsx",
+            "能力.txt",
+            "a—b",
+            "a/b",
+            "a:b",
+            "name.",
+            "name ",
+            "CON",
+            "Aux.txt",
+            "lpt1",
+            "tab\there",
+            "🎉.txt",
+            "髙.txt",
+        ] {
+            assert!(!is_shift_jis_safe_filename(s), "{:?}", s);
+        }
+    }
+}
@@ -0,0 +1,421 @@
+//! Name-based lookup of character sets.
+//!
+//! Configuration files often refer to character sets by name.  This module
+//! maps stable, documented names to the crate's cached sets so applications
+//! do not need to maintain their own `match` statement.
+//!
+//! ## Built-in names
+//!
+//! | Name | Set | Feature |
+//! |---|---|---|
+//! | `ascii_control` | [`CodePoints::ascii_control`] | *(default)* |
+//! | `ascii_printable` | [`CodePoints::ascii_printable`] | *(default)* |
+//! | `ascii_all` | [`CodePoints::ascii_all`] | *(default)* |
+//! | `crlf` | [`CodePoints::crlf`] | *(default)* |
+//! | `zero_width` | [`CodePoints::zero_width`] | *(default)* |
+//! | `enclosed_jisx0213` | [`EnclosedChars::jis_x0213`](crate::EnclosedChars::jis_x0213) | *(default)* |
+//! | `enclosed_extended` | [`EnclosedChars::extended`](crate::EnclosedChars::extended) | *(default)* |
+//! | `vertical_forms` | [`VerticalForms`](crate::VerticalForms) | *(default)* |
+//! | `small_form_variants` | [`SmallFormVariants`](crate::SmallFormVariants) | *(default)* |
+//! | `jisx0201` | JIS X 0201 (Latin ∪ halfwidth katakana) | `codepoints-jisx0201` |
+//! | `jisx0201_katakana` | JIS X 0201 halfwidth katakana | `codepoints-jisx0201` |
+//! | `jisx0201_latin` | JIS X 0201 Latin letters | `codepoints-jisx0201` |
+//! | `jisx0208` | JIS X 0208 non-kanji | `codepoints-jisx0208` |
+//! | `hiragana` | JIS X 0208 hiragana | `codepoints-jisx0208` |
+//! | `katakana` | JIS X 0208 katakana | `codepoints-jisx0208` |
+//! | `jisx0208_latin` | JIS X 0208 fullwidth Latin | `codepoints-jisx0208` |
+//! | `greek` | JIS X 0208 Greek | `codepoints-jisx0208` |
+//! | `cyrillic` | JIS X 0208 Cyrillic | `codepoints-jisx0208` |
+//! | `jisx0208_special` | JIS X 0208 special characters | `codepoints-jisx0208` |
+//! | `jisx0208_box_drawing` | JIS X 0208 box drawing | `codepoints-jisx0208` |
+//! | `nec_special_chars` | NEC row-13 extension (①, Ⅰ, ㍉ …) | `codepoints-jisx0208` |
+//! | `jisx0208kanji` | JIS X 0208 kanji | `codepoints-jisx0208kanji` |
+//! | `jisx0213kanji` | JIS X 0213:2004 kanji | `codepoints-jisx0213kanji` |
+//! | `katakana_phonetic_extensions` | JIS X 0213 small katakana ㇰ–ㇿ | `codepoints-jisx0208` + `codepoints-jisx0213kanji` |
+//!
+//! Names of sets whose feature is disabled are not registered;
+//! [`required_features`] reports what they need.  Availability is decided by
+//! [`has_feature`], like every other feature check.
+//!
+//! ## Custom sets
+//!
+//! Applications can add their own named sets at startup with [`register`].
+//!
+//! ## Diagnostics
+//!
+//! [`which_sets`] and [`explain`] report which built-in sets contain a
+//! character — handy when logging why a string failed validation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::{registry, CodePoints};
+//!
+//! let printable = registry::get("ascii_printable").unwrap();
+//! assert!(printable.contains("Hello"));
+//! assert!(registry::get("no_such_set").is_none());
+//!
+//! assert!(registry::register("digits", CodePoints::from_string("0123456789")));
+//! assert!(registry::get("digits").unwrap().contains("42"));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use crate::features::{has_feature, Feature};
+use crate::CodePoints;
+
+type Accessor = fn() -> &'static CodePoints;
+
+/// A built-in set: its name, the features it needs and, when they are
+/// compiled in, its accessor.
+struct Builtin {
+    name: &'static str,
+    requires: &'static [Feature],
+    accessor: Option<Accessor>,
+}
+
+impl Builtin {
+    /// Returns the accessor if every required feature is compiled in.
+    fn available(&self) -> Option<Accessor> {
+        if self.requires.iter().all(|&f| has_feature(f)) {
+            self.accessor
+        } else {
+            None
+        }
+    }
+}
+
+/// Declares a [`Builtin`].  The Cargo feature names are the single source
+/// for both the `cfg` that compiles the accessor and the `requires` list.
+macro_rules! builtin {
+    ($name:literal, $accessor:expr) => {
+        Builtin {
+            name: $name,
+            requires: &[],
+            accessor: Some($accessor),
+        }
+    };
+    ($name:literal, [$($feature:literal),+], $accessor:expr) => {
+        Builtin {
+            name: $name,
+            requires: &[$(Feature::from_cargo_name($feature)),+],
+            accessor: {
+                #[cfg(all($(feature = $feature),+))]
+                let accessor: Option<Accessor> = Some($accessor);
+                #[cfg(not(all($(feature = $feature),+)))]
+                let accessor: Option<Accessor> = None;
+                accessor
+            },
+        }
+    };
+}
+
+/// Every built-in set, in documentation order, whether or not its features
+/// are enabled.
+const BUILTIN: &[Builtin] = &[
+    builtin!("ascii_control", CodePoints::ascii_control_cached),
+    builtin!("ascii_printable", CodePoints::ascii_printable_cached),
+    builtin!("ascii_all", CodePoints::ascii_all_cached),
+    builtin!("crlf", CodePoints::crlf_cached),
+    builtin!("zero_width", CodePoints::zero_width_cached),
+    builtin!("enclosed_jisx0213", crate::EnclosedChars::jis_x0213),
+    builtin!("enclosed_extended", crate::EnclosedChars::extended),
+    builtin!("vertical_forms", crate::VerticalForms::codepoints),
+    builtin!("small_form_variants", crate::SmallFormVariants::codepoints),
+    builtin!("jisx0201", ["codepoints-jisx0201"], || {
+        crate::jisx0201::JisX0201::cached().codepoints()
+    }),
+    builtin!("jisx0201_katakana", ["codepoints-jisx0201"], || {
+        crate::jisx0201::Katakana::cached().codepoints()
+    }),
+    builtin!("jisx0201_latin", ["codepoints-jisx0201"], || {
+        crate::jisx0201::LatinLetters::cached().codepoints()
+    }),
+    builtin!("jisx0208", ["codepoints-jisx0208"], || {
+        crate::jisx0208::JisX0208::cached().codepoints()
+    }),
+    builtin!("hiragana", ["codepoints-jisx0208"], || {
+        crate::jisx0208::Hiragana::cached().codepoints()
+    }),
+    builtin!("katakana", ["codepoints-jisx0208"], || {
+        crate::jisx0208::Katakana::cached().codepoints()
+    }),
+    builtin!("jisx0208_latin", ["codepoints-jisx0208"], || {
+        crate::jisx0208::LatinLetters::cached().codepoints()
+    }),
+    builtin!("greek", ["codepoints-jisx0208"], || {
+        crate::jisx0208::GreekLetters::cached().codepoints()
+    }),
+    builtin!("cyrillic", ["codepoints-jisx0208"], || {
+        crate::jisx0208::CyrillicLetters::cached().codepoints()
+    }),
+    builtin!("jisx0208_special", ["codepoints-jisx0208"], || {
+        crate::jisx0208::SpecialChars::cached().codepoints()
+    }),
+    builtin!("jisx0208_box_drawing", ["codepoints-jisx0208"], || {
+        crate::jisx0208::BoxDrawingChars::cached().codepoints()
+    }),
+    builtin!("nec_special_chars", ["codepoints-jisx0208"], || {
+        crate::jisx0208::NecSpecialChars::cached().codepoints()
+    }),
+    builtin!("jisx0208kanji", ["codepoints-jisx0208kanji"], || {
+        crate::jisx0208kanji::JisX0208Kanji::cached().codepoints()
+    }),
+    builtin!("jisx0213kanji", ["codepoints-jisx0213kanji"], || {
+        crate::jisx0213kanji::JisX0213Kanji::cached().codepoints()
+    }),
+    builtin!(
+        "katakana_phonetic_extensions",
+        ["codepoints-jisx0208", "codepoints-jisx0213kanji"],
+        || { crate::jisx0208::KatakanaPhoneticExtensions::cached().codepoints() }
+    ),
+];
+
+/// Returns the built-in sets whose features are enabled, with their
+/// accessors.
+fn available() -> impl Iterator<Item = (&'static str, Accessor)> {
+    BUILTIN
+        .iter()
+        .filter_map(|b| b.available().map(|accessor| (b.name, accessor)))
+}
+
+fn custom() -> &'static RwLock<HashMap<&'static str, &'static CodePoints>> {
+    static CUSTOM: OnceLock<RwLock<HashMap<&'static str, &'static CodePoints>>> = OnceLock::new();
+    CUSTOM.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Returns the set registered under `name`, or `None` if no such set exists
+/// (or its feature is disabled).
+///
+/// Built-in sets are initialized lazily on first lookup.
+pub fn get(name: &str) -> Option<&'static CodePoints> {
+    if let Some((_, accessor)) = available().find(|&(n, _)| n == name) {
+        return Some(accessor());
+    }
+    custom()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(name)
+        .copied()
+}
+
+/// Returns the names of every available set: built-in names first (in the
+/// documented order), followed by custom names in alphabetical order.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::registry;
+///
+/// assert!(registry::names().any(|n| n == "ascii_printable"));
+/// ```
+pub fn names() -> impl Iterator<Item = &'static str> {
+    let mut custom: Vec<&'static str> = custom()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .copied()
+        .collect();
+    custom.sort_unstable();
+    available().map(|(n, _)| n).chain(custom)
+}
+
+/// Registers an application-defined set under `name`.
+///
+/// The set lives for the rest of the program, so registration is intended for
+/// startup configuration.  Returns `false` (and leaves the registry unchanged)
+/// if `name` is already taken by a built-in or previously registered set.
+pub fn register(name: &'static str, codepoints: CodePoints) -> bool {
+    if available().any(|(n, _)| n == name) {
+        return false;
+    }
+    let mut custom = custom().write().unwrap_or_else(|e| e.into_inner());
+    if custom.contains_key(name) {
+        return false;
+    }
+    custom.insert(name, Box::leak(Box::new(codepoints)));
+    true
+}
+
+/// Returns the features the built-in set `name` needs, or `None` if there
+/// is no such built-in set.
+///
+/// Unlike [`get`], this also knows the sets whose features are disabled,
+/// so a failed lookup can say which feature to enable.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{registry, Feature};
+///
+/// assert_eq!(registry::required_features("crlf"), Some(&[][..]));
+/// assert_eq!(
+///     registry::required_features("hiragana"),
+///     Some(&[Feature::Jisx0208][..])
+/// );
+/// assert_eq!(registry::required_features("no_such_set"), None);
+/// ```
+pub fn required_features(name: &str) -> Option<&'static [Feature]> {
+    BUILTIN.iter().find(|b| b.name == name).map(|b| b.requires)
+}
+
+/// Returns the names of every built-in set containing `c`, in the documented
+/// order.
+///
+/// Only sets whose feature is enabled are consulted; custom sets added with
+/// [`register`] are not.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::which_sets;
+///
+/// let sets = which_sets('A');
+/// assert!(sets.contains(&"ascii_printable"));
+/// assert!(!sets.contains(&"ascii_control"));
+/// assert!(which_sets('\u{E000}').is_empty());
+/// ```
+pub fn which_sets(c: char) -> Vec<&'static str> {
+    available()
+        .filter(|(_, accessor)| accessor().contains_char(c))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Applies [`which_sets`] to every character of `s`, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::explain;
+///
+/// let report = explain("a\n");
+/// assert_eq!(report[0].0, 'a');
+/// assert!(report[0].1.contains(&"ascii_printable"));
+/// assert!(report[1].1.contains(&"crlf"));
+/// ```
+pub fn explain(s: &str) -> Vec<(char, Vec<&'static str>)> {
+    s.chars().map(|c| (c, which_sets(c))).collect()
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_builtin() {
+        assert!(std::ptr::eq(
+            get("ascii_printable").unwrap(),
+            CodePoints::ascii_printable_cached()
+        ));
+        assert!(get("crlf").unwrap().contains("\r\n"));
+        assert!(get("").is_none());
+        assert!(get("ASCII_PRINTABLE").is_none()); // names are case-sensitive
+    }
+
+    #[test]
+    fn test_names_resolve() {
+        let names: Vec<_> = names().collect();
+        assert!(names.contains(&"zero_width"));
+        for name in names {
+            assert!(get(name).is_some(), "unresolvable name {}", name);
+        }
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    #[test]
+    fn test_get_jisx0208() {
+        assert!(get("hiragana").unwrap().contains("あいう"));
+        assert!(get("jisx0208").unwrap().contains("あア"));
+        assert!(names().any(|n| n == "katakana"));
+    }
+
+    #[cfg(not(feature = "codepoints-jisx0208"))]
+    #[test]
+    fn test_disabled_feature_not_registered() {
+        assert!(get("hiragana").is_none());
+        assert!(!names().any(|n| n == "hiragana"));
+        assert_eq!(
+            required_features("hiragana"),
+            Some(&[Feature::Jisx0208][..])
+        );
+    }
+
+    #[test]
+    fn test_builtin_availability_matches_features() {
+        for b in BUILTIN {
+            let enabled = b.requires.iter().all(|&f| has_feature(f));
+            assert_eq!(b.accessor.is_some(), enabled, "{}", b.name);
+            assert_eq!(get(b.name).is_some(), enabled, "{}", b.name);
+        }
+        assert_eq!(
+            required_features("katakana_phonetic_extensions"),
+            Some(&[Feature::Jisx0208, Feature::Jisx0213Kanji][..])
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let set = CodePoints::from_string("xyz");
+        assert!(register("test_register_xyz", set.clone()));
+        assert_eq!(get("test_register_xyz"), Some(&set));
+        assert!(names().any(|n| n == "test_register_xyz"));
+
+        // Names cannot be reused or shadow built-ins.
+        assert!(!register("test_register_xyz", CodePoints::new(vec![])));
+        assert!(!register("ascii_printable", CodePoints::new(vec![])));
+        assert_eq!(get("test_register_xyz"), Some(&set));
+    }
+
+    #[test]
+    fn test_which_sets_ascii() {
+        let sets = which_sets('A');
+        assert!(sets.contains(&"ascii_printable"));
+        assert!(sets.contains(&"ascii_all"));
+        #[cfg(feature = "codepoints-jisx0201")]
+        assert!(sets.contains(&"jisx0201_latin"));
+        #[cfg(not(feature = "codepoints-jisx0201"))]
+        assert!(!sets.iter().any(|n| n.starts_with("jisx0201")));
+
+        assert_eq!(which_sets('\r'), vec!["ascii_control", "ascii_all", "crlf"]);
+    }
+
+    #[test]
+    fn test_which_sets_kana() {
+        let hiragana = which_sets('\u{3042}'); // あ
+        let long_vowel = which_sets('\u{30FC}'); // ー
+        #[cfg(feature = "codepoints-jisx0208")]
+        {
+            assert_eq!(hiragana, vec!["jisx0208", "hiragana"]);
+            assert_eq!(long_vowel, vec!["jisx0208", "jisx0208_special"]);
+        }
+        #[cfg(not(feature = "codepoints-jisx0208"))]
+        {
+            assert!(hiragana.is_empty());
+            assert!(long_vowel.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_which_sets_enclosed() {
+        let sets = which_sets('①');
+        assert!(sets.contains(&"enclosed_jisx0213"));
+        assert!(sets.contains(&"enclosed_extended"));
+        assert!(!sets.contains(&"jisx0208"));
+        #[cfg(feature = "codepoints-jisx0208")]
+        assert!(sets.contains(&"nec_special_chars"));
+        assert_eq!(which_sets('⓪'), vec!["enclosed_extended"]);
+    }
+
+    #[test]
+    fn test_explain() {
+        let report = explain("a\u{200B}");
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0], ('a', which_sets('a')));
+        assert_eq!(report[1], ('\u{200B}', vec!["zero_width"]));
+        assert!(explain("").is_empty());
+    }
+}
@@ -0,0 +1,501 @@
+//! Sanitizing text to a target character set through a pipeline of
+//! replacement steps.
+//!
+//! A [`Sanitizer`] keeps every character of its target set and tries to
+//! rewrite the rest.  Each [`Step`] proposes one replacement; the first
+//! proposal made entirely of target characters wins, and a character no
+//! step can place falls back to a [`Fallback`].  Steps are tried in the
+//! order they were added:
+//!
+//! * [`Step::WidthFold`] — fullwidth ↔ halfwidth: ASCII and its fullwidth
+//!   forms (`Ａ` ↔ `A`, `　` ↔ ` `), JIS X 0201 katakana (`ｱ` ↔ `ア`,
+//!   `ガ` → `ｶﾞ`).
+//! * [`Step::Equivalences`] — the JIS X 0208 positions CP932 maps to other
+//!   code points (`〜` ↔ `～`, `−` ↔ `－`), from
+//!   [`data::shift_jis`](crate::data::shift_jis).
+//! * [`Step::ComposeKana`] — a kana and a following sound mark, combining,
+//!   spacing or halfwidth, into the precomposed kana (`か゛` → `が`,
+//!   `ﾊﾟ` → `パ`).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::data::jisx0208::{HIRAGANA, KATAKANA, SPECIAL_CHARS};
+//! use japanese_codepoints::sanitize::{Fallback, Sanitizer, Step};
+//! use japanese_codepoints::CodePoints;
+//!
+//! let target = CodePoints::new([HIRAGANA, KATAKANA, SPECIAL_CHARS].concat());
+//! let sanitizer = Sanitizer::new(&target)
+//!     .with_step(Step::WidthFold)
+//!     .with_step(Step::Equivalences)
+//!     .with_step(Step::ComposeKana)
+//!     .fallback(Fallback::Char('〓'));
+//!
+//! let outcome = sanitizer.sanitize("ｶﾀｶﾅ～か\u{3099}漢");
+//! assert_eq!(outcome.output, "カタカナ〜が〓");
+//! assert_eq!(outcome.changes.len(), 7);
+//! assert_eq!(outcome.changes[4].original, "～");
+//! assert_eq!(outcome.changes[4].step, Some(Step::Equivalences));
+//! ```
+
+use crate::data::jisx0201::KATAKANA_TO_FULLWIDTH;
+use crate::data::jisx0208::{KANA_COMPOSE, KATAKANA_TO_HALFWIDTH};
+use crate::data::shift_jis::{CP932_MAPPINGS, JIS_MAPPINGS};
+use crate::CodePoints;
+
+/// A replacement strategy tried by a [`Sanitizer`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    /// Replaces a character with its fullwidth or halfwidth counterpart:
+    /// ASCII and U+FF01–U+FF5E, the space and U+3000, and JIS X 0201
+    /// katakana and JIS X 0208 katakana (a voiced kana becomes two
+    /// halfwidth characters).
+    WidthFold,
+    /// Replaces one side of a JIS/CP932 mapping difference with the other
+    /// (`〜` U+301C ↔ `～` U+FF5E).
+    Equivalences,
+    /// Composes a kana and the sound mark after it — U+3099/U+309A,
+    /// `゛`/`゜` or `ﾞ`/`ﾟ` — into one precomposed kana.
+    ComposeKana,
+}
+
+impl Step {
+    /// Returns the replacement for the start of `c` followed by `next`, and
+    /// how many characters it consumes, if the step has one.
+    fn apply(self, c: char, next: Option<char>) -> Option<(String, usize)> {
+        match self {
+            Step::WidthFold => width_fold(c).map(|folded| (folded, 1)),
+            Step::Equivalences => equivalent(c).map(|e| (e.to_string(), 1)),
+            Step::ComposeKana => compose_kana(c, next?).map(|k| (k.to_string(), 2)),
+        }
+    }
+}
+
+/// What a [`Sanitizer`] does with a character that no step can place in
+/// the target set.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallback {
+    /// Drops the character.
+    Remove,
+    /// Writes the given character instead, even if it is outside the
+    /// target set.
+    Char(char),
+}
+
+/// One substitution made by [`Sanitizer::sanitize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// Zero-based character index of the first replaced input character.
+    pub position: usize,
+    /// The replaced input characters: one, or two for
+    /// [`Step::ComposeKana`].
+    pub original: String,
+    /// What was written instead; empty for [`Fallback::Remove`].
+    pub replacement: String,
+    /// The step that made the substitution, or `None` for the fallback.
+    pub step: Option<Step>,
+}
+
+/// The result of [`Sanitizer::sanitize`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SanitizeOutcome {
+    /// The sanitized text.
+    pub output: String,
+    /// Every substitution, in input order.
+    pub changes: Vec<Change>,
+}
+
+impl SanitizeOutcome {
+    /// Returns `true` if nothing was substituted, so `output` equals the
+    /// input.
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Rewrites text into a target set with an ordered list of [`Step`]s and a
+/// [`Fallback`].
+///
+/// Without steps every character outside the target goes straight to the
+/// fallback, which defaults to [`Fallback::Remove`].
+#[derive(Debug, Clone)]
+pub struct Sanitizer<'a> {
+    target: &'a CodePoints,
+    steps: Vec<Step>,
+    fallback: Fallback,
+}
+
+impl<'a> Sanitizer<'a> {
+    /// Creates a sanitizer for `target` with no steps and
+    /// [`Fallback::Remove`].
+    pub fn new(target: &'a CodePoints) -> Self {
+        Self {
+            target,
+            steps: Vec::new(),
+            fallback: Fallback::Remove,
+        }
+    }
+
+    /// Appends `step`, to be tried after the steps already added.
+    #[must_use]
+    pub fn with_step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Sets what happens to characters no step can place.
+    #[must_use]
+    pub fn fallback(mut self, fallback: Fallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Returns the steps in the order they are tried.
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Sanitizes `s`, recording every substitution.
+    ///
+    /// Characters of the target set pass through, except that a step may
+    /// absorb one into a composition with the disallowed character after it
+    /// (`か` + U+3099 → `が`).  A step's replacement is used only if all of
+    /// it is in the target set; otherwise the next step is tried.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::sanitize::{Fallback, Sanitizer, Step};
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let ascii = CodePoints::ascii_printable_cached();
+    /// let sanitizer = Sanitizer::new(ascii)
+    ///     .with_step(Step::WidthFold)
+    ///     .fallback(Fallback::Char('?'));
+    ///
+    /// let outcome = sanitizer.sanitize("ＩＤ：４２　東");
+    /// assert_eq!(outcome.output, "ID:42 ?");
+    /// assert_eq!(outcome.changes[6].position, 6);
+    /// assert_eq!(outcome.changes[6].step, None);
+    /// assert!(sanitizer.sanitize("ok").is_unchanged());
+    /// ```
+    pub fn sanitize(&self, s: &str) -> SanitizeOutcome {
+        let chars: Vec<char> = s.chars().collect();
+        let mut outcome = SanitizeOutcome {
+            output: String::with_capacity(s.len()),
+            changes: Vec::new(),
+        };
+        let mut position = 0;
+        while let Some((&c, rest)) = chars.get(position..).and_then(<[char]>::split_first) {
+            let next = rest.first().copied();
+            let allowed = self.target.contains_char(c);
+            if allowed && next.map_or(true, |n| self.target.contains_char(n)) {
+                outcome.output.push(c);
+                position += 1;
+                continue;
+            }
+
+            let rewrite = self.steps.iter().find_map(|&step| {
+                let (replacement, consumed) = step.apply(c, next)?;
+                let fits = replacement.chars().all(|r| self.target.contains_char(r));
+                // An allowed character is only rewritten together with the
+                // disallowed one after it.
+                (fits && (consumed > 1 || !allowed)).then_some((step, replacement, consumed))
+            });
+            match rewrite {
+                Some((step, replacement, consumed)) => {
+                    outcome.output.push_str(&replacement);
+                    outcome.changes.push(Change {
+                        position,
+                        original: chars.iter().skip(position).take(consumed).collect(),
+                        replacement,
+                        step: Some(step),
+                    });
+                    position += consumed;
+                }
+                None if allowed => {
+                    outcome.output.push(c);
+                    position += 1;
+                }
+                None => {
+                    let replacement = match self.fallback {
+                        Fallback::Remove => String::new(),
+                        Fallback::Char(f) => f.to_string(),
+                    };
+                    outcome.output.push_str(&replacement);
+                    outcome.changes.push(Change {
+                        position,
+                        original: c.to_string(),
+                        replacement,
+                        step: None,
+                    });
+                    position += 1;
+                }
+            }
+        }
+        outcome
+    }
+}
+
+// ── steps ─────────────────────────────────────────────────────────────────────
+
+/// Distance from ASCII `!`–`~` to the fullwidth forms U+FF01–U+FF5E.
+const FULLWIDTH_ASCII_OFFSET: u32 = 0xFEE0;
+
+fn width_fold(c: char) -> Option<String> {
+    let cp = c as u32;
+    let folded: Vec<u32> = match cp {
+        0x20 => vec![0x3000],
+        0x3000 => vec![0x20],
+        0x21..=0x7E => vec![cp + FULLWIDTH_ASCII_OFFSET],
+        0xFF01..=0xFF5E => vec![cp - FULLWIDTH_ASCII_OFFSET],
+        _ => {
+            if let Ok(i) = KATAKANA_TO_FULLWIDTH.binary_search_by_key(&cp, |&(half, _)| half) {
+                vec![KATAKANA_TO_FULLWIDTH.get(i)?.1]
+            } else {
+                let i = KATAKANA_TO_HALFWIDTH
+                    .binary_search_by_key(&cp, |&(full, _)| full)
+                    .ok()?;
+                KATAKANA_TO_HALFWIDTH.get(i)?.1.to_vec()
+            }
+        }
+    };
+    folded.into_iter().map(char::from_u32).collect()
+}
+
+fn equivalent(c: char) -> Option<char> {
+    let cp = c as u32;
+    let (from, to) = if JIS_MAPPINGS.contains(&cp) {
+        (JIS_MAPPINGS, CP932_MAPPINGS)
+    } else {
+        (CP932_MAPPINGS, JIS_MAPPINGS)
+    };
+    let i = from.iter().position(|&m| m == cp)?;
+    to.get(i).copied().and_then(char::from_u32)
+}
+
+/// Returns the precomposed kana for `base` followed by the sound mark
+/// `mark` (combining, spacing or halfwidth), if there is one.
+pub(crate) fn compose_kana(base: char, mark: char) -> Option<char> {
+    let mark = match mark as u32 {
+        0x3099 | 0x309B | 0xFF9E => 0x3099,
+        0x309A | 0x309C | 0xFF9F => 0x309A,
+        _ => return None,
+    };
+    let base = base as u32;
+    // Halfwidth bases compose through their fullwidth form.
+    let base = KATAKANA_TO_FULLWIDTH
+        .iter()
+        .find(|&&(half, _)| half == base)
+        .map_or(base, |&(_, full)| full);
+    KANA_COMPOSE
+        .iter()
+        .find(|&&(b, m, _)| b == base && m == mark)
+        .and_then(|&(_, _, composed)| char::from_u32(composed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::jisx0201::KATAKANA_COMPOSE;
+    use crate::data::jisx0208::{HIRAGANA, KATAKANA, SPECIAL_CHARS};
+
+    fn jisx0208_kana() -> CodePoints {
+        CodePoints::new([HIRAGANA, KATAKANA, SPECIAL_CHARS].concat())
+    }
+
+    fn steps(changes: &[Change]) -> Vec<Option<Step>> {
+        changes.iter().map(|change| change.step).collect()
+    }
+
+    #[test]
+    fn test_width_fold() {
+        let ascii = CodePoints::ascii_printable_cached();
+        let sanitizer = Sanitizer::new(ascii).with_step(Step::WidthFold);
+        let outcome = sanitizer.sanitize("ａｂ　Ｃ1");
+        assert_eq!(outcome.output, "ab C1");
+        assert_eq!(
+            outcome.changes[2],
+            Change {
+                position: 2,
+                original: "　".into(),
+                replacement: " ".into(),
+                step: Some(Step::WidthFold),
+            }
+        );
+        assert_eq!(outcome.changes.len(), 4);
+
+        let kana = jisx0208_kana();
+        let outcome = Sanitizer::new(&kana)
+            .with_step(Step::WidthFold)
+            .sanitize("ｱｲ｡");
+        assert_eq!(outcome.output, "アイ。");
+
+        // Fullwidth to halfwidth, splitting voiced kana.
+        let halfwidth = CodePoints::new(crate::data::jisx0201::KATAKANA.to_vec());
+        let outcome = Sanitizer::new(&halfwidth)
+            .with_step(Step::WidthFold)
+            .sanitize("ガッ");
+        assert_eq!(outcome.output, "ｶﾞｯ");
+        assert_eq!(outcome.changes[0].replacement, "ｶﾞ");
+        assert_eq!(outcome.changes[1].position, 1);
+    }
+
+    #[test]
+    fn test_equivalences() {
+        let jis = CodePoints::new(crate::data::shift_jis::JIS_MAPPINGS.to_vec());
+        let outcome = Sanitizer::new(&jis)
+            .with_step(Step::Equivalences)
+            .sanitize("～－〜");
+        assert_eq!(outcome.output, "〜−〜");
+        assert_eq!(steps(&outcome.changes), [Some(Step::Equivalences); 2]);
+
+        let cp932 = CodePoints::new(crate::data::shift_jis::CP932_MAPPINGS.to_vec());
+        let outcome = Sanitizer::new(&cp932)
+            .with_step(Step::Equivalences)
+            .sanitize("〜‖");
+        assert_eq!(outcome.output, "～∥");
+    }
+
+    #[test]
+    fn test_compose_kana() {
+        let kana = jisx0208_kana();
+        let sanitizer = Sanitizer::new(&kana).with_step(Step::ComposeKana);
+
+        // Combining marks after allowed kana.
+        let outcome = sanitizer.sanitize("か\u{3099}は\u{309A}");
+        assert_eq!(outcome.output, "がぱ");
+        assert_eq!(
+            outcome.changes[1],
+            Change {
+                position: 2,
+                original: "は\u{309A}".into(),
+                replacement: "ぱ".into(),
+                step: Some(Step::ComposeKana),
+            }
+        );
+
+        // Halfwidth bases and marks; a spacing mark after an allowed kana
+        // is itself allowed and stays.
+        assert_eq!(sanitizer.sanitize("ｶﾞﾎﾟ").output, "ガポ");
+        assert_eq!(sanitizer.sanitize("か゛").output, "か゛");
+
+        // ゔ is not in JIS X 0208, and あ has no voiced form.
+        let outcome = sanitizer.sanitize("う\u{3099}あ\u{3099}");
+        assert_eq!(outcome.output, "うあ");
+        assert_eq!(steps(&outcome.changes), [None, None]);
+    }
+
+    #[test]
+    fn test_kana_compose_table() {
+        assert!(KANA_COMPOSE
+            .iter()
+            .all(|&(base, mark, composed)| compose_kana(
+                char::from_u32(base).unwrap(),
+                char::from_u32(mark).unwrap()
+            ) == char::from_u32(composed)));
+        // The halfwidth compositions agree with the fullwidth ones.
+        for &(base, mark, composed) in KATAKANA_COMPOSE {
+            let base = char::from_u32(base).unwrap();
+            let mark = char::from_u32(mark).unwrap();
+            assert_eq!(compose_kana(base, mark), char::from_u32(composed));
+        }
+    }
+
+    #[test]
+    fn test_step_ordering() {
+        // ～ folds to ASCII ~ and is equivalent to 〜; both are allowed.
+        let target = CodePoints::ascii_printable_cached().union(&jisx0208_kana());
+        let width_first = Sanitizer::new(&target)
+            .with_step(Step::WidthFold)
+            .with_step(Step::Equivalences);
+        let equivalences_first = Sanitizer::new(&target)
+            .with_step(Step::Equivalences)
+            .with_step(Step::WidthFold);
+        assert_eq!(width_first.sanitize("～").output, "~");
+        assert_eq!(equivalences_first.sanitize("～").output, "〜");
+        assert_eq!(
+            equivalences_first.steps(),
+            [Step::Equivalences, Step::WidthFold]
+        );
+
+        // Width folding first takes ｶ alone, leaving the mark spacing.
+        let kana = jisx0208_kana();
+        let fold_first = Sanitizer::new(&kana)
+            .with_step(Step::WidthFold)
+            .with_step(Step::ComposeKana);
+        let compose_first = Sanitizer::new(&kana)
+            .with_step(Step::ComposeKana)
+            .with_step(Step::WidthFold);
+        assert_eq!(fold_first.sanitize("ｶﾞ").output, "カ゛");
+        assert_eq!(compose_first.sanitize("ｶﾞ").output, "ガ");
+
+        // A step whose replacement falls outside the target passes to the
+        // next one.
+        let outcome = Sanitizer::new(&kana)
+            .with_step(Step::WidthFold)
+            .with_step(Step::Equivalences)
+            .sanitize("～");
+        assert_eq!(outcome.output, "〜");
+        assert_eq!(steps(&outcome.changes), [Some(Step::Equivalences)]);
+    }
+
+    #[test]
+    fn test_fallback() {
+        let kana = jisx0208_kana();
+        let text = "あ漢ア";
+        let removed = Sanitizer::new(&kana).sanitize(text);
+        assert_eq!(removed.output, "あア");
+        assert_eq!(
+            removed.changes,
+            [Change {
+                position: 1,
+                original: "漢".into(),
+                replacement: String::new(),
+                step: None,
+            }]
+        );
+
+        let geta = Sanitizer::new(&kana)
+            .with_step(Step::WidthFold)
+            .fallback(Fallback::Char('〓'))
+            .sanitize(text);
+        assert_eq!(geta.output, "あ〓ア");
+        assert_eq!(geta.changes[0].replacement, "〓");
+
+        let unchanged = Sanitizer::new(&kana).sanitize("あア");
+        assert!(unchanged.is_unchanged());
+        assert_eq!(unchanged.output, "あア");
+        assert!(Sanitizer::new(&kana).sanitize("").is_unchanged());
+    }
+
+    #[test]
+    fn test_output_stays_in_target() {
+        let kana = jisx0208_kana();
+        let ascii = CodePoints::ascii_printable_cached();
+        let text = "ＡＢＣ～ｶﾞｷﾞ か\u{3099}〜−漢字😀\u{309A}ﾟ";
+        for target in [&kana, ascii] {
+            let sanitizer = Sanitizer::new(target)
+                .with_step(Step::ComposeKana)
+                .with_step(Step::Equivalences)
+                .with_step(Step::WidthFold);
+            let outcome = sanitizer.sanitize(text);
+            assert!(target.contains(&outcome.output), "{}", outcome.output);
+            // Unchanged characters plus the changes account for the input.
+            let consumed: usize = outcome
+                .changes
+                .iter()
+                .map(|change| change.original.chars().count())
+                .sum();
+            let kept = outcome.output.chars().count()
+                - outcome
+                    .changes
+                    .iter()
+                    .map(|change| change.replacement.chars().count())
+                    .sum::<usize>();
+            assert_eq!(consumed + kept, text.chars().count());
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//! Crate-wide robustness tests: adversarial input through the public API.
+//!
+//! Every call here must return normally; a panic anywhere fails the test.
+//! Results are only checked where the right answer is obvious.
+
+use std::hint::black_box;
+
+use crate::codepoints::{Block, CharClass, ExcludedHistogram};
+use crate::iter::CodePointIterExt as _;
+use crate::sanitize::{Fallback, Sanitizer, Step};
+use crate::validation::{self, ValidateOptions, ValidationError};
+use crate::validator::{CachedValidator, EncodingLimit, Restriction, Validator};
+use crate::width::{self, Align, AmbiguousPolicy};
+use crate::{
+    contains_all_in_any, conversion, kinsoku, registry, Backend, CodePoints, CodePointsGroup,
+};
+
+/// Evaluates `value` for the calls that produced it; the result itself is
+/// not checked.
+fn consume<T>(value: T) {
+    black_box(value);
+}
+
+/// Strings that have tripped up text code elsewhere: empty input, scalar
+/// boundaries, lone combining and sound marks, invisible controls, and a
+/// large mixed string.
+fn texts() -> Vec<String> {
+    let mut texts: Vec<String> = [
+        "",
+        "\0",
+        "\u{7F}\u{80}",
+        "\u{D7FF}\u{E000}",
+        "\u{FFFF}\u{10000}",
+        "\u{10FFFF}",
+        "a\u{10FFFF}",
+        "\u{3099}\u{309A}",
+        "ﾞﾟ",
+        "ｶﾞﾟﾟ",
+        "っ",
+        "ーん",
+        "ん",
+        "\u{FEFF}\u{200B}\u{202E}",
+        "\r\n\t ",
+        " 　",
+        "𠮷野家🍚",
+        "ａＡ０アｱ",
+        "「」。、",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    texts.push("あ𠮷a\u{10FFFF}".repeat(1 << 13));
+    texts
+}
+
+/// Byte strings that are not UTF-8, or only barely: lone surrogates,
+/// overlong forms, values above U+10FFFF and truncated sequences.
+const BYTES: &[&[u8]] = &[
+    b"",
+    b"\xED\xA0\x80",
+    b"\xED\xBF\xBF",
+    b"\xC0\x80",
+    b"\xF4\x90\x80\x80",
+    b"\xFF",
+    b"\xE3\x81",
+    b"a\xE3",
+    b"\xF0\x9F\x98",
+    b"\x80",
+    "あ\u{10FFFF}".as_bytes(),
+];
+
+/// Sets with no members and with members that are not scalar values
+/// (surrogates, values above U+10FFFF) in every backend, plus a dense set
+/// spanning the surrogates, the BMP boundary and U+10FFFF.
+fn sets() -> Vec<CodePoints> {
+    let mut sets = Vec::new();
+    for backend in [
+        Backend::HashSet,
+        Backend::BTreeSet,
+        Backend::SortedVec,
+        Backend::Bitmap,
+    ] {
+        sets.push(CodePoints::with_backend(vec![], backend));
+        sets.push(CodePoints::with_backend(
+            vec![0x41, 0xD800, 0xDFFF, 0x10FFFF, 0x110000, u32::MAX],
+            backend,
+        ));
+    }
+    let mut dense = CodePoints::from_char_range('\u{D000}'..='\u{10100}');
+    dense.insert_char_range('\u{10F000}'..=char::MAX);
+    sets.push(dense);
+    sets
+}
+
+const PATTERNS: &[&str] = &[
+    "",
+    "[",
+    "]",
+    "[]",
+    "[\\",
+    "[\\u",
+    "[\\u{110000}]",
+    "[\\x{D800}]",
+    "[\\uD800]",
+    "[z-a]",
+    "[a-]",
+    "[-]",
+    "[[[[",
+    "[\\p{L}]",
+    "[\\N{X}]",
+    "[^a]",
+    "[\\u{FFFFFFFFFF}]",
+];
+
+const SPECS: &[&str] = &[
+    "",
+    ",",
+    "-",
+    "U+",
+    "U+110000",
+    "U+D800",
+    "U+0041-U+0040",
+    "U+0000-U+10FFFF",
+    "0x41..",
+    "zz",
+    "U+FFFFFFFFFF",
+];
+
+#[test]
+fn test_codepoints_api_never_panics() {
+    let texts = texts();
+    let sets = sets();
+    let ranges = [
+        (0, 0),
+        (0, u32::MAX),
+        (u32::MAX, 0),
+        (0xD800, 0xDFFF),
+        (0x10FFFF, 0x110000),
+    ];
+
+    for set in &sets {
+        let other = CodePoints::from_string("aあ\u{10FFFF}");
+        let group = CodePointsGroup::new(&[set, &other]);
+        for text in &texts {
+            let text = text.as_str();
+            consume((set.contains(text), set.contains_within(text, 0)));
+            consume(set.contains_within(text, usize::MAX));
+            consume(set.first_excluded_with_position(text));
+            consume(set.first_excluded_char(text));
+            consume(set.first_excluded_detail(text));
+            consume(set.all_excluded(text).len());
+            consume(set.iter_excluded(text).count());
+            consume(set.iter_excluded_unique(text).count());
+            consume(set.iter_excluded_chars(text).count());
+            consume(set.iter_valid_chars(text).count());
+            consume(set.first_included_with_position(text));
+            consume(set.all_included_with_positions(text).len());
+            consume(set.excluded_histogram(text).len());
+            consume(set.contains_ascii_case_insensitive(text));
+            consume(set.first_excluded_ascii_case_insensitive(text));
+            consume((set.mask(text).len(), set.mask_bytes(text).len()));
+            consume(set.similarity(text));
+            consume(set.weighted_similarity(text, &|c| f64::from(u32::from(c)) - 0x3000 as f64));
+            consume(set.validate(text).map_err(|e| e.to_string()));
+            consume(set.check(text).map_err(|e| e.to_string()));
+            consume(
+                set.check_with_limit(text, 0)
+                    .map_err(|e| e.valid_prefix_len()),
+            );
+            consume(set.check_with_limit(text, usize::MAX).is_ok());
+            consume(set.contains_all([text, "", text]));
+            consume(set.first_failing_item([text, ""]));
+            consume(set.check_all([text]).len());
+            consume(set.contains_utf8(text.as_bytes()));
+
+            let mut histogram = ExcludedHistogram::new(set);
+            histogram.feed(text);
+            histogram.feed(text);
+            consume((histogram.total(), histogram.into_sorted_vec()));
+
+            consume(contains_all_in_any(text, &[set, &other]));
+            consume(contains_all_in_any(text, &[]));
+            consume(group.contains(text));
+        }
+        for bytes in BYTES {
+            consume(set.contains_utf8(bytes).map_err(|e| e.to_string()));
+            consume(set.first_excluded_utf8(bytes));
+        }
+
+        for &(start, end) in &ranges {
+            consume(set.count_codepoints_in_range(start, end));
+            consume(set.has_any_in_range(start, end));
+            consume(set.find_included_in(start..=end));
+            consume(set.find_excluded_in(start..=end.min(0x10000)));
+        }
+
+        consume((set.chars().count(), set.try_chars().count()));
+        consume(set.chars().allowed_by(&other).count());
+        let short: String = texts
+            .iter()
+            .filter(|t| t.len() < 64)
+            .map(String::as_str)
+            .collect();
+        consume(set.without(&short).with(&short).len());
+        consume(set.to_string_of_all().len());
+        consume(set.to_spec_string().parse::<CodePoints>().ok());
+        consume(CodePoints::from_unicode_set_pattern(&set.to_unicode_set_pattern()).ok());
+        consume(CodePoints::deserialize_compact(&set.serialize_compact()).ok());
+        consume((
+            set.fold_ascii_case().len(),
+            set.group_by_unicode_block().len(),
+        ));
+        consume((set.decompose(), set.uncategorized(usize::MAX).len()));
+        consume(set.uncategorized(0));
+        consume((
+            set.block_spans(),
+            set.is_single_block(),
+            set.outliers(usize::MAX),
+        ));
+        consume((set.restrict_to(0xD800..=0xDFFF), set.count_in(0x10FFFF..)));
+        for class in CharClass::ALL {
+            consume(set.subset_of_class(class).len());
+        }
+        for block in Block::ALL {
+            consume(set.subset_matching_block(block).len());
+        }
+        consume(set.difference_from_named("no such set"));
+        consume(set.difference_from_named(""));
+        consume((set.union(&other), set.intersection(&other)));
+        consume((set.difference(&other), set.symmetric_difference(&other)));
+        consume((set.delta(&other), set.partition_by(&other)));
+        consume((set.compare(&other), set.diff(&other).is_empty()));
+        consume(set.without_codepoints(&[u32::MAX, 0xD800]).len());
+        consume((set.memory_usage(), set.stats()));
+
+        let mut converted = set.clone();
+        for backend in [Backend::Bitmap, Backend::SortedVec, Backend::HashSet] {
+            converted.convert_backend(backend);
+            converted.shrink_to_fit();
+        }
+        assert_eq!(&converted, set);
+    }
+
+    consume(CodePoints::overlapping_sets(&sets).len());
+    consume(CodePoints::disjoint_partition(&sets));
+    consume(CodePoints::disjoint_partition(&[]));
+
+    for pattern in PATTERNS {
+        consume(CodePoints::from_unicode_set_pattern(pattern).map_err(|e| e.to_string()));
+    }
+    for spec in SPECS {
+        consume(spec.parse::<CodePoints>().map_err(|e| e.to_string()));
+    }
+
+    let mut data = CodePoints::from_string("あ").serialize_compact();
+    for len in 0..=data.len() + 1 {
+        consume(CodePoints::deserialize_compact(data.get(..len).unwrap_or(&data)).ok());
+    }
+    data.iter_mut().skip(4).take(4).for_each(|b| *b = 0xFF);
+    assert!(CodePoints::deserialize_compact(&data).is_err());
+}
+
+#[test]
+fn test_text_utilities_never_panic() {
+    let sets = sets();
+    for text in &texts() {
+        let text = text.as_str();
+        consume(conversion::transliterate_to_ascii(text));
+        consume(conversion::halfwidth_to_fullwidth_katakana(text));
+        consume(conversion::to_fullwidth_upper(text));
+        consume(conversion::to_fullwidth_lower(text));
+        consume(conversion::normalize_vertical_forms(text));
+        consume(conversion::to_vertical_forms(text));
+
+        for policy in [AmbiguousPolicy::Wide, AmbiguousPolicy::Narrow] {
+            consume(policy.display_width(text));
+            for align in [Align::Left, Align::Right, Align::Center] {
+                consume(policy.pad_to_width(text, 0, align));
+                consume(policy.pad_to_width(text, 7, align));
+            }
+        }
+        consume(width::display_width(text));
+
+        consume(kinsoku::violations([text, "", text]));
+        consume(registry::explain(text).len());
+        for c in text.chars().take(64) {
+            consume(registry::which_sets(c));
+        }
+
+        let options = ValidateOptions {
+            trim: true,
+            ignore: Some(CodePoints::zero_width_cached()),
+            require_non_empty: true,
+        };
+        for set in &sets {
+            consume(validation::validate_all_in_any(text, &[set]).map_err(|e| e.to_string()));
+            consume(validation::collect_violations(text, set).len());
+            consume(validation::validate_with_options(text, &[set], &options));
+            consume(validation::validate_with_options(
+                text,
+                &[],
+                &ValidateOptions::default(),
+            ));
+            consume(validation::validate_non_empty(text, set));
+            consume(validation::validated(text, set).is_ok());
+            consume(validation::validated_owned(text.to_string(), set).is_ok());
+            let sanitizer = Sanitizer::new(set)
+                .with_step(Step::ComposeKana)
+                .with_step(Step::WidthFold)
+                .with_step(Step::Equivalences)
+                .fallback(Fallback::Char('\u{10FFFF}'));
+            consume(sanitizer.sanitize(text));
+            consume(Sanitizer::new(set).sanitize(text));
+
+            let validator = Validator::new()
+                .allow(set)
+                .restrict(Restriction::no_non_bmp())
+                .deny_set(CodePoints::zero_width_cached())
+                .max_encoded_bytes(EncodingLimit::ShiftJis, 0)
+                .require_non_empty(true);
+            consume(validator.first_violation(text).map(ValidationError::from));
+            consume(validator.validate(text).map_err(|e| e.to_string()));
+            let validator = Validator::new().max_encoded_bytes(EncodingLimit::EucJp, usize::MAX);
+            consume(validator.is_valid(text));
+        }
+
+        for capacity in [0, 1] {
+            let cached = CachedValidator::new(CodePoints::ascii_printable_cached(), capacity);
+            consume((cached.contains(text), cached.contains(text), cached.len()));
+            cached.clear();
+        }
+    }
+
+    for label in ["", " ", "\0", "shift_jis\u{10FFFF}", "ＳＪＩＳ"] {
+        consume(CodePoints::for_encoding(label));
+    }
+    for name in ["", "\u{10FFFF}", "ascii_printable "] {
+        consume((registry::get(name), registry::required_features(name)));
+        consume(crate::Feature::try_from_cargo_name(name));
+    }
+    for c in ['\0', '\u{D7FF}', '\u{10FFFF}'] {
+        consume((
+            EncodingLimit::ShiftJis.encoded_len(c),
+            EncodingLimit::EucJp.encoded_len(c),
+        ));
+    }
+
+    let empty = crate::ConstCodePoints::new(&[]);
+    consume((empty.contains(u32::MAX), empty.contains_str("\u{10FFFF}")));
+    let edge = crate::ConstCodePoints::new(&[0, 0xD800, u32::MAX]);
+    for text in &texts() {
+        consume(edge.contains_str(text));
+    }
+}
+
+#[cfg(feature = "codepoints-jisx0208")]
+#[test]
+fn test_jisx0208_api_never_panics() {
+    use crate::jisx0208::JisX0208;
+    use crate::kana::OrthographyChecker;
+
+    for (from, to) in [(0, 0), (0, 255), (255, 0), (255, 255), (8, 9)] {
+        consume(JisX0208::rows_in_range(from, to).len());
+    }
+    for text in &texts() {
+        let text = text.as_str();
+        consume(OrthographyChecker::strict().check(text));
+        consume(OrthographyChecker::new().is_valid(text));
+        consume(crate::presets::validate_corporate_name_kana(text));
+        consume(crate::presets::normalize_corporate_name_kana(text));
+        let postal = crate::presets::postal_code();
+        consume(
+            postal
+                .validate(&postal.normalize(text))
+                .map_err(|e| e.to_string()),
+        );
+        let phone = crate::presets::phone_number();
+        consume(
+            phone
+                .validate(&phone.normalize(text))
+                .map_err(|e| e.to_string()),
+        );
+        consume(crate::recipes::is_kana_with_prolonged(text));
+        #[cfg(feature = "codepoints-jisx0208kanji")]
+        {
+            consume(crate::recipes::is_jis_level1_text(text));
+            consume(crate::recipes::normalize_then_validate_address(text));
+        }
+        #[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208kanji"))]
+        consume(crate::recipes::is_shift_jis_safe_filename(text));
+    }
+}
+
+#[cfg(all(feature = "codepoints-jisx0201", feature = "codepoints-jisx0208"))]
+#[test]
+fn test_jisx0201_and_lint_never_panic() {
+    use crate::lint::{check_width_consistency, WidthLintWarning, WidthPolicy};
+
+    for text in &texts() {
+        consume(crate::jisx0201::Katakana::convertible_to_fullwidth(text));
+        consume(crate::jisx0201::encode_katakana_bytes(text));
+        consume(crate::jisx0201::decode_bytes(text.as_bytes()));
+        for warning in check_width_consistency(text, &WidthPolicy::default()) {
+            consume(warning.to_string());
+        }
+    }
+    let warning = WidthLintWarning {
+        class: crate::lint::WidthClass::Katakana,
+        halfwidth_positions: Vec::new(),
+        fullwidth_positions: vec![1],
+    };
+    assert_eq!(
+        warning.to_string(),
+        "mixed halfwidth and fullwidth katakana"
+    );
+}
+
+#[cfg(feature = "codepoints-jisx0208kanji")]
+#[test]
+fn test_jisx0208kanji_api_never_panics() {
+    use crate::jisx0208kanji::{info, sort_by_kuten, JisX0208Kanji};
+
+    for (from, to) in [
+        (0, 0),
+        (0, 15),
+        (0, 255),
+        (255, 0),
+        (255, 255),
+        (47, 48),
+        (84, 85),
+    ] {
+        consume(JisX0208Kanji::rows_in_range(from, to).len());
+    }
+    assert!(JisX0208Kanji::rows_in_range(0, 0).is_empty());
+    for text in &texts() {
+        let mut chars: Vec<char> = text.chars().take(4096).collect();
+        sort_by_kuten(&mut chars);
+        chars.iter().for_each(|&c| consume(info(c)));
+    }
+}
+
+#[cfg(feature = "codepoints-jisx0213kanji")]
+#[test]
+fn test_jisx0213kanji_api_never_panics() {
+    let kanji = crate::JisX0213Kanji::cached();
+    for text in &texts() {
+        consume(
+            text.chars()
+                .take(4096)
+                .filter_map(|c| kanji.plane_of(c))
+                .count(),
+        );
+        consume(kanji.validate(text).is_ok());
+    }
+}
@@ -6,10 +6,70 @@
 //!   characters outside an allowed set.
 //! * [`validate_all_in_any`] – validate text against the *union* of several
 //!   character sets simultaneously.
+//! * [`validate_with_options`] – the same check with trimming, an ignore
+//!   set and rejection of empty input (see [`ValidateOptions`]).
+//! * [`validate_non_empty`] – [`CodePoints::validate`] for required fields.
+//! * [`collect_violations`] – every invalid character rather than the first.
+//! * [`validate_multiline`] – every invalid character with its line and
+//!   column ([`LineColError`]), for textarea and file content.
+//! * [`ExclusionReport`] – the single-pass summary returned by
+//!   [`CodePoints::check`].
+//! * [`ValidatedString`] – a `String` that carries, in its type, the
+//!   [`SetMarker`] it was validated against (see [`markers`]).
+//! * [`ValidationHooks`] – a global hook observing every failure, for
+//!   telemetry (also emitted as `tracing` events with the `tracing` feature).
 //! * Convenience macros for common Japanese character-set checks.
+//!
+//! # JSON schema
+//!
+//! With the `serde` feature, [`ExclusionReport`], [`ValidationError`],
+//! [`SetDiff`](crate::SetDiff) and [`SetManifest`](crate::SetManifest) implement `serde::Serialize` with the schema
+//! below, suitable for one-object-per-line (NDJSON) logs.  Every code point
+//! appears both as an integer and as a `"U+XXXX"` string (four or more
+//! upper-case hex digits).  The schema only grows: later versions may add
+//! fields, but never rename, retype or remove one.
+//!
+//! `ExclusionReport`:
+//!
+//! | Field | Type | Meaning |
+//! |---|---|---|
+//! | `total_chars` | integer | characters in the input |
+//! | `violation_count` | integer | disallowed characters, including any not listed |
+//! | `truncated` | bool | `violations` is shorter than `violation_count` |
+//! | `first` | violation | the first disallowed character |
+//! | `violations` | array of violation | disallowed characters in input order |
+//! | `unique_excluded` | array of `{code_point, code_point_hex}` | distinct disallowed code points, ascending |
+//! | `set_fingerprint` | string | [`CodePoints::fingerprint`] of the set as 16 lower-case hex digits; only present if recorded |
+//!
+//! A violation is `{code_point, code_point_hex, char_index, byte_index}`,
+//! with zero-based indices into the input in characters and in UTF-8 bytes.
+//!
+//! `ValidationError` is `{kind, code_point, code_point_hex, char_index,
+//! byte_index, message}`, where `kind` is `"invalid_char"`, `"empty_input"`,
+//! `"denied_character"` or `"too_long"` (more kinds may be added),
+//! `char_index` is [`ValidationError::position`] and `byte_index` is
+//! [`ValidationError::byte_index`], only present if known.
+//!
+//! `SetDiff` is `{added, removed}`, each an array of inclusive ranges
+//! `{first, first_hex, last, last_hex, count}` in code-point order.
+//!
+//! [`SetManifest`](crate::SetManifest) is `{fingerprint, len, ranges,
+//! crate_data_version}`, with `fingerprint` as above and `ranges` in the
+//! same form as `SetDiff`'s.
+//!
+//! ```json
+//! {"total_chars":3,"violation_count":1,"truncated":false,
+//!  "first":{"code_point":12354,"code_point_hex":"U+3042","char_index":2,"byte_index":2},
+//!  "violations":[{"code_point":12354,"code_point_hex":"U+3042","char_index":2,"byte_index":2}],
+//!  "unique_excluded":[{"code_point":12354,"code_point_hex":"U+3042"}]}
+//! ```
 
 use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, Range};
+use std::sync::{Arc, RwLock};
 
+use crate::codepoints::ExcludedChar;
 use crate::CodePoints;
 
 // ── error type ────────────────────────────────────────────────────────────────
@@ -32,14 +92,80 @@ use crate::CodePoints;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ValidationError {
-    /// The Unicode code point that is not allowed by the character set.
+    /// What kind of failure this is.
+    pub kind: ValidationErrorKind,
+    /// The Unicode code point that is not allowed by the character set
+    /// (`0` for [`ValidationErrorKind::EmptyInput`]).
     pub code_point: u32,
-    /// Zero-based *character* index (not byte index) within the input string.
+    /// Zero-based *character* index (not byte index) within the input string
+    /// (`0` for [`ValidationErrorKind::EmptyInput`]).
     pub position: usize,
+    /// Zero-based UTF-8 byte index within the input, if known (`Some(0)` for
+    /// [`ValidationErrorKind::EmptyInput`]).
+    ///
+    /// Errors produced by this crate always set it; errors built with
+    /// [`ValidationError::new`] or [`ValidationError::with_message`] leave it
+    /// `None` until [`ValidationError::with_byte_index`] is called.
+    pub byte_index: Option<usize>,
     /// A human-readable description of the error.
     pub message: String,
 }
 
+/// The kind of a [`ValidationError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    /// A character is not in the allowed set.
+    InvalidChar,
+    /// The input was empty (after trimming, if enabled) but non-empty input
+    /// was required.
+    EmptyInput,
+    /// A character is in a deny-list, such as
+    /// `presets::dangerous_invisibles()`, or the text contains a denied
+    /// sequence.
+    DeniedCharacter,
+    /// The text is longer than allowed, e.g. by
+    /// [`Validator::max_encoded_bytes`](crate::validator::Validator::max_encoded_bytes).
+    TooLong,
+}
+
+impl ValidationErrorKind {
+    /// Returns the stable snake_case name of the kind, e.g.
+    /// `"invalid_char"`, as used in the JSON schema; [`str::parse`] accepts
+    /// it back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::ValidationErrorKind;
+    ///
+    /// assert_eq!(ValidationErrorKind::TooLong.as_str(), "too_long");
+    /// assert_eq!("too_long".parse(), Ok(ValidationErrorKind::TooLong));
+    /// ```
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ValidationErrorKind::InvalidChar => "invalid_char",
+            ValidationErrorKind::EmptyInput => "empty_input",
+            ValidationErrorKind::DeniedCharacter => "denied_character",
+            ValidationErrorKind::TooLong => "too_long",
+        }
+    }
+}
+
+impl std::str::FromStr for ValidationErrorKind {
+    type Err = crate::ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "invalid_char" => Ok(ValidationErrorKind::InvalidChar),
+            "empty_input" => Ok(ValidationErrorKind::EmptyInput),
+            "denied_character" => Ok(ValidationErrorKind::DeniedCharacter),
+            "too_long" => Ok(ValidationErrorKind::TooLong),
+            _ => Err(crate::ParseNameError::new("validation error kind", s)),
+        }
+    }
+}
+
 impl fmt::Display for ValidationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(&self.message)
@@ -48,13 +174,44 @@ impl fmt::Display for ValidationError {
 
 impl std::error::Error for ValidationError {}
 
+/// Yields the error message, for code that keeps `String` errors; see
+/// `MIGRATION.md`.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+///
+/// fn check(s: &str) -> Result<(), String> {
+///     CodePoints::ascii_printable().validate(s)?;
+///     Ok(())
+/// }
+///
+/// assert_eq!(check("aあ").unwrap_err(), "invalid character 'あ' (U+3042) at position 1");
+/// ```
+impl From<ValidationError> for String {
+    fn from(error: ValidationError) -> Self {
+        error.message
+    }
+}
+
 impl ValidationError {
     /// Creates a `ValidationError` for the given code point and character index.
+    ///
+    /// A `code_point` that is not a valid `char` is shown as U+FFFD in the
+    /// message; prefer converting an [`ExcludedChar`] when the character is
+    /// at hand.
     pub fn new(code_point: u32, position: usize) -> Self {
         let ch = char::from_u32(code_point).unwrap_or('\u{FFFD}');
+        Self::for_char(ch, code_point, position)
+    }
+
+    fn for_char(ch: char, code_point: u32, position: usize) -> Self {
         Self {
+            kind: ValidationErrorKind::InvalidChar,
             code_point,
             position,
+            byte_index: None,
             message: format!(
                 "invalid character '{}' (U+{:04X}) at position {}",
                 ch, code_point, position
@@ -66,11 +223,175 @@ impl ValidationError {
     /// default formatting.
     pub fn with_message(code_point: u32, position: usize, message: impl Into<String>) -> Self {
         Self {
+            kind: ValidationErrorKind::InvalidChar,
             code_point,
             position,
+            byte_index: None,
             message: message.into(),
         }
     }
+
+    /// Records the UTF-8 byte index of the offending character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validation::ValidationError;
+    ///
+    /// let err = ValidationError::new(0x3046, 2).with_byte_index(6);
+    /// assert_eq!(err.byte_index, Some(6));
+    /// ```
+    pub fn with_byte_index(mut self, byte_index: usize) -> Self {
+        self.byte_index = Some(byte_index);
+        self
+    }
+
+    /// Creates the error reported for empty input when non-empty input is
+    /// required.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validation::{ValidationError, ValidationErrorKind};
+    ///
+    /// let err = ValidationError::empty_input();
+    /// assert_eq!(err.kind, ValidationErrorKind::EmptyInput);
+    /// assert_eq!(err.to_string(), "input is empty");
+    /// ```
+    pub fn empty_input() -> Self {
+        Self {
+            kind: ValidationErrorKind::EmptyInput,
+            code_point: 0,
+            position: 0,
+            byte_index: Some(0),
+            message: "input is empty".to_string(),
+        }
+    }
+}
+
+impl From<ExcludedChar> for ValidationError {
+    fn from(excluded: ExcludedChar) -> Self {
+        Self::for_char(excluded.ch, excluded.code_point, excluded.position)
+            .with_byte_index(excluded.byte_index)
+    }
+}
+
+// ── exclusion report ──────────────────────────────────────────────────────────
+
+/// A single disallowed character found by [`CodePoints::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// The disallowed code point.
+    pub code_point: u32,
+    /// Zero-based character index within the input.
+    pub char_index: usize,
+    /// Zero-based byte index within the input.
+    pub byte_index: usize,
+}
+
+/// Everything [`CodePoints::check`] learned about an invalid string, gathered
+/// in one pass.
+///
+/// With the `serde` feature the report implements `serde::Serialize` (see
+/// the [JSON schema](self#json-schema)), so it can be returned directly from
+/// an API handler or written as a log line.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+///
+/// let cp = CodePoints::ascii_printable();
+/// let report = cp.check("abcあ").unwrap_err();
+/// assert_eq!(
+///     report.to_string(),
+///     "1 of 4 characters not allowed (1 unique); first: 'あ' (U+3042) at position 3"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExclusionReport {
+    /// Number of characters in the input.
+    pub total_chars: usize,
+    /// The first disallowed character.
+    pub first: Violation,
+    /// Disallowed characters in input order, truncated to the limit passed to
+    /// [`CodePoints::check_with_limit`].
+    pub violations: Vec<Violation>,
+    /// Total number of disallowed characters, including any not kept in
+    /// [`violations`](Self::violations).
+    pub violation_count: usize,
+    /// Distinct disallowed code points in ascending order.
+    pub unique_excluded: Vec<u32>,
+    /// [`CodePoints::fingerprint`] of the set checked against, if recorded
+    /// with [`with_fingerprint`](Self::with_fingerprint).
+    pub set_fingerprint: Option<u64>,
+}
+
+impl ExclusionReport {
+    /// Number of violations kept by [`CodePoints::check`].
+    pub const DEFAULT_LIMIT: usize = 32;
+
+    /// Returns the first disallowed character.
+    pub fn first(&self) -> &Violation {
+        &self.first
+    }
+
+    /// Returns the number of characters before the first violation.
+    ///
+    /// The same prefix in bytes is `self.first().byte_index`.
+    pub fn valid_prefix_len(&self) -> usize {
+        self.first.char_index
+    }
+
+    /// Returns `true` if [`violations`](Self::violations) does not list every
+    /// violation.
+    pub fn is_truncated(&self) -> bool {
+        self.violations.len() < self.violation_count
+    }
+
+    /// Records the [`fingerprint`](CodePoints::fingerprint) of `set`, the
+    /// set this report was produced with, for audit trails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    ///
+    /// let cp = CodePoints::ascii_printable();
+    /// let report = cp.check("abcあ").map_err(|r| r.with_fingerprint(&cp)).unwrap_err();
+    /// assert_eq!(report.set_fingerprint, Some(cp.fingerprint()));
+    /// ```
+    #[must_use]
+    pub fn with_fingerprint(mut self, set: &CodePoints) -> Self {
+        self.set_fingerprint = Some(set.fingerprint());
+        self
+    }
+}
+
+impl fmt::Display for ExclusionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = char::from_u32(self.first.code_point).unwrap_or('\u{FFFD}');
+        write!(
+            f,
+            "{} of {} characters not allowed ({} unique); first: '{}' (U+{:04X}) at position {}",
+            self.violation_count,
+            self.total_chars,
+            self.unique_excluded.len(),
+            ch,
+            self.first.code_point,
+            self.first.char_index
+        )
+    }
+}
+
+impl std::error::Error for ExclusionReport {}
+
+impl From<ExclusionReport> for ValidationError {
+    /// Converts the report into an error describing its first violation.
+    fn from(report: ExclusionReport) -> Self {
+        ValidationError::new(report.first.code_point, report.first.char_index)
+            .with_byte_index(report.first.byte_index)
+    }
 }
 
 // ── multi-set validation ──────────────────────────────────────────────────────
@@ -84,48 +405,1109 @@ impl ValidationError {
 ///
 /// # Edge cases
 ///
-/// * An empty `text` returns `Ok(())` (vacuously valid).
-/// * An empty `sets` slice returns `Err` for any non-empty `text`.
+/// * An empty `text` returns `Ok(())` (vacuously valid).
+/// * An empty `sets` slice returns `Err` for any non-empty `text`.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{CodePoints, validation::validate_all_in_any};
+///
+/// let hiragana = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+/// let katakana = CodePoints::new(vec![0x30A2]);          // ア
+///
+/// assert!(validate_all_in_any("あア", &[&hiragana, &katakana]).is_ok());
+/// assert!(validate_all_in_any("あx", &[&hiragana, &katakana]).is_err());
+/// ```
+pub fn validate_all_in_any(text: &str, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+    text.validate_in_any(sets)
+}
+
+/// Returns a [`ValidationError`] for **every** character of `text` that is not
+/// in `codepoints`, in input order.
+///
+/// Unlike [`CodePoints::validate`], scanning does not stop at the first
+/// failure.  An empty vector means `text` is valid.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+/// use japanese_codepoints::validation::collect_violations;
+///
+/// let cp = CodePoints::ascii_printable();
+/// let errors = collect_violations("aあbい", &cp);
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!((errors[0].code_point, errors[0].position), (0x3042, 1));
+/// assert_eq!((errors[1].code_point, errors[1].position), (0x3044, 3));
+/// ```
+pub fn collect_violations(text: &str, codepoints: &CodePoints) -> Vec<ValidationError> {
+    codepoints
+        .iter_excluded(text)
+        .map(ValidationError::from)
+        .collect()
+}
+
+// ── line and column positions ─────────────────────────────────────────────────
+
+/// A disallowed character located by line and column, returned by
+/// [`validate_multiline`].
+///
+/// The line itself is not copied: [`Self::line_text`] and [`Self::excerpt`]
+/// look it up in the validated input, so an error costs the same however
+/// long its line is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineColError {
+    /// One-based line number.
+    pub line: usize,
+    /// One-based column, counted in characters.
+    pub column: usize,
+    /// The disallowed code point.
+    pub code_point: u32,
+    /// Byte offset of the disallowed character within the input.
+    pub byte_index: usize,
+    /// Byte range of the offending line within the input, without its line
+    /// break.
+    pub line_range: Range<usize>,
+}
+
+impl LineColError {
+    /// Returns the offending line of `input`, the text passed to
+    /// [`validate_multiline`], without its line break.
+    ///
+    /// Returns `""` if `input` is not the validated text.
+    pub fn line_text<'a>(&self, input: &'a str) -> &'a str {
+        input.get(self.line_range.clone()).unwrap_or("")
+    }
+
+    /// Returns at most `max_chars` characters of the offending line of
+    /// `input` around the offending column, with `…` marking each cut end.
+    ///
+    /// Only the characters of the excerpt are visited, so excerpts of every
+    /// error on a very long line stay cheap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    /// use japanese_codepoints::validation::validate_multiline;
+    ///
+    /// let line = format!("{}あ{}", "a".repeat(50), "b".repeat(50));
+    /// let errors = validate_multiline(&line, &CodePoints::ascii_printable()).unwrap_err();
+    /// assert_eq!(errors[0].excerpt(&line, 5), "…aaあbb…");
+    /// assert_eq!(errors[0].excerpt(&line, 200), line);
+    /// ```
+    pub fn excerpt(&self, input: &str, max_chars: usize) -> String {
+        let line = self.line_text(input);
+        let offset = self.byte_index.saturating_sub(self.line_range.start);
+        let (Some(before), Some(after)) = (line.get(..offset), line.get(offset..)) else {
+            return String::new();
+        };
+        // Half the excerpt before the column, more if the line ends soon.
+        let after_len = after.chars().take(max_chars).count();
+        let before_len = (max_chars / 2).max(max_chars - after_len);
+        let start = before
+            .char_indices()
+            .rev()
+            .take(before_len)
+            .last()
+            .map_or(offset, |(i, _)| i);
+        let taken_before = before.get(start..).map_or(0, |b| b.chars().count());
+        let end = after
+            .char_indices()
+            .nth(max_chars - taken_before)
+            .map_or(line.len(), |(i, _)| offset + i);
+
+        let mut out = String::new();
+        if start > 0 {
+            out.push('…');
+        }
+        out.push_str(line.get(start..end).unwrap_or(""));
+        if end < line.len() {
+            out.push('…');
+        }
+        out
+    }
+}
+
+impl fmt::Display for LineColError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ch = char::from_u32(self.code_point).unwrap_or('\u{FFFD}');
+        write!(
+            f,
+            "{}:{}: invalid character '{}' (U+{:04X})",
+            self.line, self.column, ch, self.code_point
+        )
+    }
+}
+
+impl std::error::Error for LineColError {}
+
+/// Validates multi-line text, reporting **every** character that is not in
+/// `codepoints` by line and column.
+///
+/// Both `\n` and `\r\n` end a line and are never checked themselves; a
+/// lone `\r` is an ordinary character.  Errors are in input order.
+///
+/// # Errors
+///
+/// Returns a non-empty vector of [`LineColError`]s if any character is
+/// disallowed.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+/// use japanese_codepoints::validation::validate_multiline;
+///
+/// let cp = CodePoints::ascii_printable();
+/// assert!(validate_multiline("first\r\nsecond\n", &cp).is_ok());
+///
+/// let errors = validate_multiline("first\r\nsecあnd", &cp).unwrap_err();
+/// assert_eq!((errors[0].line, errors[0].column), (2, 4));
+/// assert_eq!(errors[0].line_text("first\r\nsecあnd"), "secあnd");
+/// assert_eq!(errors[0].to_string(), "2:4: invalid character 'あ' (U+3042)");
+/// ```
+pub fn validate_multiline(s: &str, codepoints: &CodePoints) -> Result<(), Vec<LineColError>> {
+    let mut errors = Vec::new();
+    let mut line_start = 0;
+    for (index, full_line) in s.split_inclusive('\n').enumerate() {
+        let line = full_line
+            .strip_suffix("\r\n")
+            .or_else(|| full_line.strip_suffix('\n'))
+            .unwrap_or(full_line);
+        let line_range = line_start..line_start + line.len();
+        for (column, (offset, c)) in line.char_indices().enumerate() {
+            if !codepoints.contains_char(c) {
+                errors.push(LineColError {
+                    line: index + 1,
+                    column: column + 1,
+                    code_point: c as u32,
+                    byte_index: line_start + offset,
+                    line_range: line_range.clone(),
+                });
+            }
+        }
+        line_start += full_line.len();
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+// ── validation with options ───────────────────────────────────────────────────
+
+/// Pre-processing options for [`validate_with_options`].
+///
+/// The default value (`trim: false`, `ignore: None`,
+/// `require_non_empty: false`) makes [`validate_with_options`] behave
+/// exactly like [`validate_all_in_any`].
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{CodePoints, validation::ValidateOptions};
+///
+/// let options = ValidateOptions {
+///     trim: true,
+///     ignore: Some(CodePoints::zero_width_cached()),
+///     require_non_empty: true,
+/// };
+/// assert!(options.trim);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidateOptions<'a> {
+    /// Skip leading and trailing whitespace (as defined by
+    /// [`str::trim`]) before checking membership.
+    pub trim: bool,
+    /// Characters that are skipped during membership checking, e.g.
+    /// [`CodePoints::zero_width`].  Skipped characters are reported in
+    /// [`ValidationOutcome`] instead of causing a failure.
+    pub ignore: Option<&'a CodePoints>,
+    /// Reject empty input — or, with `trim`, whitespace-only input — with
+    /// [`ValidationErrorKind::EmptyInput`], as required form fields need.
+    pub require_non_empty: bool,
+}
+
+/// The successful result of [`validate_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ValidationOutcome {
+    /// Number of characters skipped because they belong to
+    /// [`ValidateOptions::ignore`].
+    pub ignored_count: usize,
+    /// Zero-based character indices (within the original, untrimmed input) of
+    /// the skipped characters, in ascending order.
+    pub ignored_positions: Vec<usize>,
+}
+
+/// Validates `text` against the union of `sets` after applying `options`.
+///
+/// Characters in `options.ignore` are skipped and collected into the returned
+/// [`ValidationOutcome`]; with `options.trim`, surrounding whitespace is not
+/// checked at all.  Error and ignored positions always refer to character
+/// indices in the original `text`.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+/// use japanese_codepoints::validation::{validate_with_options, ValidateOptions};
+///
+/// let hiragana = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+/// let options = ValidateOptions {
+///     trim: true,
+///     ignore: Some(CodePoints::zero_width_cached()),
+///     require_non_empty: false,
+/// };
+///
+/// let outcome = validate_with_options(" \u{FEFF}あい ", &[&hiragana], &options).unwrap();
+/// assert_eq!(outcome.ignored_count, 1);
+/// assert_eq!(outcome.ignored_positions, vec![1]);
+///
+/// let err = validate_with_options(" あx", &[&hiragana], &options).unwrap_err();
+/// assert_eq!(err.position, 2);
+/// ```
+pub fn validate_with_options(
+    text: &str,
+    sets: &[&CodePoints],
+    options: &ValidateOptions<'_>,
+) -> Result<ValidationOutcome, ValidationError> {
+    let (body, offset, byte_offset) = if options.trim {
+        let offset = text.chars().take_while(|c| c.is_whitespace()).count();
+        let byte_offset = text.len() - text.trim_start().len();
+        (text.trim(), offset, byte_offset)
+    } else {
+        (text, 0, 0)
+    };
+    if options.require_non_empty && body.is_empty() {
+        let err = ValidationError::empty_input();
+        report_failure(None, &err, text.len());
+        return Err(err);
+    }
+
+    let mut outcome = ValidationOutcome::default();
+    for (i, (byte, c)) in body.char_indices().enumerate() {
+        let position = offset + i;
+        if options.ignore.is_some_and(|ignore| ignore.contains_char(c)) {
+            outcome.ignored_count += 1;
+            outcome.ignored_positions.push(position);
+        } else if !sets.iter().any(|set| set.contains_char(c)) {
+            let err = ValidationError::new(c as u32, position).with_byte_index(byte_offset + byte);
+            report_failure(None, &err, text.len());
+            return Err(err);
+        }
+    }
+    Ok(outcome)
+}
+
+/// Validates `text` against `codepoints`, rejecting empty input with
+/// [`ValidationErrorKind::EmptyInput`].
+///
+/// Equivalent to [`CodePoints::validate`] for non-empty input; use
+/// [`validate_with_options`] with [`ValidateOptions::require_non_empty`] to
+/// also trim.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::validation::{validate_non_empty, ValidationErrorKind};
+/// use japanese_codepoints::CodePoints;
+///
+/// let cp = CodePoints::ascii_printable();
+/// assert!(validate_non_empty("hi", &cp).is_ok());
+/// assert_eq!(validate_non_empty("", &cp).unwrap_err().kind, ValidationErrorKind::EmptyInput);
+/// assert_eq!(validate_non_empty("\t", &cp).unwrap_err().kind, ValidationErrorKind::InvalidChar);
+/// ```
+pub fn validate_non_empty(text: &str, codepoints: &CodePoints) -> Result<(), ValidationError> {
+    if text.is_empty() {
+        let err = ValidationError::empty_input();
+        report_failure(None, &err, text.len());
+        return Err(err);
+    }
+    codepoints.validate(text)
+}
+
+// ── pass-through validation ───────────────────────────────────────────────────
+
+/// Validates `text` against `codepoints` and returns it unchanged on success.
+///
+/// Lets a value be checked and bound in one expression, so the checked
+/// string and the stored one cannot drift apart.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::validation::validated;
+/// use japanese_codepoints::CodePoints;
+///
+/// let kana = CodePoints::from_char_range('ぁ'..='ん');
+/// assert_eq!(validated("やまだ", &kana), Ok("やまだ"));
+/// assert_eq!(validated("ヤマダ", &kana).unwrap_err().position, 0);
+/// ```
+pub fn validated<'a>(text: &'a str, codepoints: &CodePoints) -> Result<&'a str, ValidationError> {
+    codepoints.validate(text).map(|()| text)
+}
+
+/// Owned counterpart of [`validated`]: returns `text` on success, and hands
+/// it back alongside the error on failure so it is never lost.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::validation::validated_owned;
+/// use japanese_codepoints::CodePoints;
+///
+/// let kana = CodePoints::from_char_range('ぁ'..='ん');
+/// let (original, err) = validated_owned("やまダ".to_string(), &kana).unwrap_err();
+/// assert_eq!(original, "やまダ");
+/// assert_eq!(err.position, 2);
+/// ```
+pub fn validated_owned(
+    text: String,
+    codepoints: &CodePoints,
+) -> Result<String, (String, ValidationError)> {
+    match codepoints.validate(&text) {
+        Ok(()) => Ok(text),
+        Err(err) => Err((text, err)),
+    }
+}
+
+/// Values that [`validate_and_take!`](crate::validate_and_take) can pass
+/// through: `&str` via [`validated`] and `String` via [`validated_owned`].
+pub trait ValidateAndTake: Sized {
+    /// Error returned on failure.
+    type Error;
+
+    /// Validates `self` against `codepoints`, returning it unchanged on
+    /// success.
+    fn validate_and_take(self, codepoints: &CodePoints) -> Result<Self, Self::Error>;
+}
+
+impl ValidateAndTake for &str {
+    type Error = ValidationError;
+
+    fn validate_and_take(self, codepoints: &CodePoints) -> Result<Self, ValidationError> {
+        validated(self, codepoints)
+    }
+}
+
+impl ValidateAndTake for String {
+    type Error = (String, ValidationError);
+
+    fn validate_and_take(self, codepoints: &CodePoints) -> Result<Self, Self::Error> {
+        validated_owned(self, codepoints)
+    }
+}
+
+// ── validation inputs ─────────────────────────────────────────────────────────
+
+/// Values that [`CodePoints::validate_input`], the character-set types'
+/// `validate_input` methods and the `validate_*!` macros accept.
+///
+/// * `&str`, `String` and references to `String`, `Box<str>`, `Cow<str>`,
+///   `Rc<str>` and `Arc<str>` validate every character;
+/// * a `char` validates that single character, so a failure is always at
+///   position 0;
+/// * a `u32` validates one code point, failing if it is not a Unicode
+///   scalar value;
+/// * a `&[char]` validates each character, with positions as slice indices.
+///
+/// None of these allocate on success.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+///
+/// let kana = CodePoints::from_char_range('ぁ'..='ん');
+/// assert!(kana.validate_input('あ').is_ok());
+/// assert!(kana.validate_input(0x3042).is_ok());
+/// assert!(kana.validate_input(['あ', 'い'].as_slice()).is_ok());
+/// assert_eq!(kana.validate_input('ア').unwrap_err().position, 0);
+/// assert_eq!(kana.validate_input(0xD800).unwrap_err().code_point, 0xD800);
+/// ```
+pub trait ValidateInput {
+    /// Validates `self` against `codepoints`.  `set` names the set for
+    /// [`ValidationHooks`], if known.
+    fn validate_in(
+        self,
+        codepoints: &CodePoints,
+        set: Option<&'static str>,
+    ) -> Result<(), ValidationError>;
+
+    /// Validates that every character of `self` is in at least one of
+    /// `sets`, like [`validate_all_in_any`].
+    fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError>;
+}
+
+impl ValidateInput for &str {
+    fn validate_in(
+        self,
+        codepoints: &CodePoints,
+        set: Option<&'static str>,
+    ) -> Result<(), ValidationError> {
+        codepoints.validate_as(self, set)
+    }
+
+    fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+        for (i, (byte, c)) in self.char_indices().enumerate() {
+            if !sets.iter().any(|set| set.contains_char(c)) {
+                let err = ValidationError::for_char(c, c as u32, i).with_byte_index(byte);
+                report_failure(None, &err, self.len());
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Implements [`ValidateInput`] for references to string owners, which the
+/// macros cannot deref-coerce to `&str` themselves.
+macro_rules! validate_input_by_deref {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ValidateInput for &$ty {
+                fn validate_in(
+                    self,
+                    codepoints: &CodePoints,
+                    set: Option<&'static str>,
+                ) -> Result<(), ValidationError> {
+                    (**self).validate_in(codepoints, set)
+                }
+
+                fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+                    (**self).validate_in_any(sets)
+                }
+            }
+        )*
+    };
+}
+
+validate_input_by_deref!(
+    &str,
+    String,
+    Box<str>,
+    std::borrow::Cow<'_, str>,
+    std::rc::Rc<str>,
+    std::sync::Arc<str>,
+);
+
+impl ValidateInput for String {
+    fn validate_in(
+        self,
+        codepoints: &CodePoints,
+        set: Option<&'static str>,
+    ) -> Result<(), ValidationError> {
+        self.as_str().validate_in(codepoints, set)
+    }
+
+    fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+        self.as_str().validate_in_any(sets)
+    }
+}
+
+impl ValidateInput for char {
+    fn validate_in(
+        self,
+        codepoints: &CodePoints,
+        set: Option<&'static str>,
+    ) -> Result<(), ValidationError> {
+        std::slice::from_ref(&self).validate_in(codepoints, set)
+    }
+
+    fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+        std::slice::from_ref(&self).validate_in_any(sets)
+    }
+}
+
+/// The error for a `u32` that is not a Unicode scalar value.
+fn not_a_char(code_point: u32, set: Option<&'static str>) -> ValidationError {
+    let err = ValidationError::new(code_point, 0).with_byte_index(0);
+    report_failure(set, &err, 0);
+    err
+}
+
+impl ValidateInput for u32 {
+    fn validate_in(
+        self,
+        codepoints: &CodePoints,
+        set: Option<&'static str>,
+    ) -> Result<(), ValidationError> {
+        match char::from_u32(self) {
+            Some(c) => c.validate_in(codepoints, set),
+            None => Err(not_a_char(self, set)),
+        }
+    }
+
+    fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+        match char::from_u32(self) {
+            Some(c) => c.validate_in_any(sets),
+            None => Err(not_a_char(self, None)),
+        }
+    }
+}
+
+/// Checks `chars` against `sets`, reporting the first failure under `set`.
+fn validate_chars(
+    chars: &[char],
+    sets: &[&CodePoints],
+    set: Option<&'static str>,
+) -> Result<(), ValidationError> {
+    match chars
+        .iter()
+        .enumerate()
+        .find(|&(_, &c)| !sets.iter().any(|set| set.contains_char(c)))
+    {
+        Some((i, &c)) => {
+            let byte_index = chars.iter().take(i).map(|c| c.len_utf8()).sum();
+            let err = ValidationError::for_char(c, c as u32, i).with_byte_index(byte_index);
+            report_failure(set, &err, chars.iter().map(|c| c.len_utf8()).sum());
+            Err(err)
+        }
+        None => Ok(()),
+    }
+}
+
+impl ValidateInput for &[char] {
+    fn validate_in(
+        self,
+        codepoints: &CodePoints,
+        set: Option<&'static str>,
+    ) -> Result<(), ValidationError> {
+        validate_chars(self, &[codepoints], set)
+    }
+
+    fn validate_in_any(self, sets: &[&CodePoints]) -> Result<(), ValidationError> {
+        validate_chars(self, sets, None)
+    }
+}
+
+// ── failure reporting ─────────────────────────────────────────────────────────
+
+/// Receives every validation failure, for telemetry.
+///
+/// Install one with [`set_hook`].  It is called by [`CodePoints::validate`]
+/// and everything built on it (the character-set types, the `validate_*!`
+/// macros, [`ValidatedString`]), by [`validate_all_in_any`],
+/// [`validate_with_options`] and by
+/// [`Validator::validate`](crate::validator::Validator::validate).
+///
+/// Only the failing code point, its character index and the input length
+/// are passed on — never the input itself, which may contain personal data.
+///
+/// With the `tracing` feature, the same failures are also emitted as
+/// `debug!` events with the fields `set`, `code_point`, `char_index` and
+/// `input_len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use japanese_codepoints::validation::{clear_hook, set_hook, ValidationError, ValidationHooks};
+/// use japanese_codepoints::CodePoints;
+///
+/// static FAILURES: AtomicUsize = AtomicUsize::new(0);
+///
+/// struct Counter;
+///
+/// impl ValidationHooks for Counter {
+///     fn on_failure(&self, _set: Option<&'static str>, _error: &ValidationError, _input_len: usize) {
+///         FAILURES.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// set_hook(Counter);
+/// assert!(CodePoints::ascii_printable().validate("été").is_err());
+/// assert_eq!(FAILURES.load(Ordering::Relaxed), 1);
+/// clear_hook();
+/// ```
+pub trait ValidationHooks: Send + Sync {
+    /// Called once per failed validation.
+    ///
+    /// `set` is the name of the character set when known (e.g.
+    /// `"JIS X 0208 Hiragana"`), and `input_len` the input length in bytes.
+    fn on_failure(&self, set: Option<&'static str>, error: &ValidationError, input_len: usize);
+}
+
+static HOOK: RwLock<Option<Arc<dyn ValidationHooks>>> = RwLock::new(None);
+
+/// Installs `hook` as the global [`ValidationHooks`], replacing any
+/// previous one.
+pub fn set_hook(hook: impl ValidationHooks + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(hook));
+}
+
+/// Removes the global [`ValidationHooks`], if any.
+pub fn clear_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Passes a failure on to the `tracing` event and the installed hook.
+pub(crate) fn report_failure(set: Option<&'static str>, error: &ValidationError, input_len: usize) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        set = set.unwrap_or("unnamed"),
+        code_point = error.code_point,
+        char_index = error.position,
+        input_len,
+        "validation failed"
+    );
+
+    // Clone the hook out so it runs without the lock held.
+    let hook = HOOK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(hook) = hook {
+        hook.on_failure(set, error, input_len);
+    }
+}
+
+// ── typed validated strings ───────────────────────────────────────────────────
+
+/// A character set known at the type level, for use with
+/// [`ValidatedString`].
+///
+/// The built-in markers live in [`markers`]; define your own with
+/// [`set_marker!`](crate::set_marker) or by implementing the trait by hand.
+pub trait SetMarker {
+    /// Human-readable name of the set, e.g. for error messages.
+    const NAME: &'static str;
+
+    /// Returns the set that strings of this marker are validated against.
+    fn codepoints() -> &'static CodePoints;
+}
+
+/// Declares that every string valid for `Self` is also valid for `M`,
+/// which makes [`ValidatedString::widen`] available.
+///
+/// The trait is sealed: `widen` does not re-check, so only the built-in
+/// [`markers`], whose subset relations are tested, implement it.  Convert
+/// between custom markers with [`ValidatedString::try_convert`].
+///
+/// ```rust,compile_fail,E0277
+/// use japanese_codepoints::validation::markers::AsciiPrintable;
+/// use japanese_codepoints::validation::SubsetOf;
+/// use japanese_codepoints::{set_marker, CodePoints};
+///
+/// set_marker!(Digits, "digits", CodePoints::from_string("0123456789"));
+///
+/// impl SubsetOf<AsciiPrintable> for Digits {}
+/// ```
+pub trait SubsetOf<M: SetMarker>: SetMarker + sealed::Subset<M> {}
+
+mod sealed {
+    /// Supertrait of [`SubsetOf`](super::SubsetOf) that cannot be named
+    /// outside the crate.
+    pub trait Subset<M> {}
+}
+
+/// Implements [`SubsetOf`] for each `Sub => Super` pair of markers.
+macro_rules! subsets {
+    ($($sub:ident => $sup:ident),* $(,)?) => {
+        $(
+            impl crate::validation::sealed::Subset<$sup> for $sub {}
+            impl SubsetOf<$sup> for $sub {}
+        )*
+    };
+}
+
+/// A `String` that has been validated against the set of marker `S`.
+///
+/// The only way to obtain one is [`try_new`](Self::try_new) (or, with the
+/// `serde` feature, deserialization, which validates too), so holding a
+/// `ValidatedString<S>` proves the check was done.  It derefs to `str` and
+/// has the same size as `String`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "codepoints-jisx0208")]
+/// # {
+/// use japanese_codepoints::validation::markers::{Hiragana, JapaneseMixed};
+/// use japanese_codepoints::validation::ValidatedString;
+///
+/// let reading = ValidatedString::<Hiragana>::try_new("やまだ".to_string()).unwrap();
+/// assert_eq!(reading.len(), 9);
+///
+/// let (original, err) = ValidatedString::<Hiragana>::try_new("ヤマダ".to_string()).unwrap_err();
+/// assert_eq!(original, "ヤマダ");
+/// assert_eq!(err.position, 0);
+///
+/// let mixed: ValidatedString<JapaneseMixed> = reading.widen();
+/// assert_eq!(mixed.as_str(), "やまだ");
+/// # }
+/// ```
+pub struct ValidatedString<S: SetMarker> {
+    value: String,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S: SetMarker> ValidatedString<S> {
+    /// Validates `value` against `S`, returning it unchanged together with
+    /// the first violation on failure.
+    pub fn try_new(value: String) -> Result<Self, (String, ValidationError)> {
+        match S::codepoints().validate_as(&value, Some(S::NAME)) {
+            Ok(()) => Ok(Self {
+                value,
+                marker: PhantomData,
+            }),
+            Err(err) => Err((value, err)),
+        }
+    }
+
+    /// Returns the string as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the underlying `String`.
+    pub fn into_inner(self) -> String {
+        self.value
+    }
+
+    /// Converts to a marker whose set contains `S`'s, without re-checking.
+    pub fn widen<T: SetMarker>(self) -> ValidatedString<T>
+    where
+        S: SubsetOf<T>,
+    {
+        ValidatedString {
+            value: self.value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Re-validates the string against another marker, returning `self`
+    /// unchanged on failure.
+    pub fn try_convert<T: SetMarker>(self) -> Result<ValidatedString<T>, (Self, ValidationError)> {
+        match T::codepoints().validate_as(&self.value, Some(T::NAME)) {
+            Ok(()) => Ok(ValidatedString {
+                value: self.value,
+                marker: PhantomData,
+            }),
+            Err(err) => Err((self, err)),
+        }
+    }
+}
+
+impl<S: SetMarker> Deref for ValidatedString<S> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<S: SetMarker> AsRef<str> for ValidatedString<S> {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<S: SetMarker> fmt::Display for ValidatedString<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl<S: SetMarker> fmt::Debug for ValidatedString<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ValidatedString<{}>({:?})", S::NAME, self.value)
+    }
+}
+
+impl<S: SetMarker> Clone for ValidatedString<S> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S: SetMarker> PartialEq for ValidatedString<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<S: SetMarker> Eq for ValidatedString<S> {}
+
+impl<S: SetMarker> std::hash::Hash for ValidatedString<S> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<S: SetMarker> From<ValidatedString<S>> for String {
+    fn from(validated: ValidatedString<S>) -> Self {
+        validated.value
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: SetMarker> serde::Serialize for ValidatedString<S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.serialize_str(&self.value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: SetMarker> serde::Deserialize<'de> for ValidatedString<S> {
+    /// Deserializes a string and validates it, failing on the first
+    /// disallowed character.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Self::try_new(value).map_err(|(_, err)| serde::de::Error::custom(err))
+    }
+}
+
+/// Built-in [`SetMarker`] types for [`ValidatedString`].
+///
+/// | Marker | Set | Feature |
+/// |---|---|---|
+/// | [`AsciiPrintable`](markers::AsciiPrintable) | [`CodePoints::ascii_printable`] | *(default)* |
+/// | [`Hiragana`](markers::Hiragana) | JIS X 0208 hiragana | `codepoints-jisx0208` |
+/// | [`Katakana`](markers::Katakana) | JIS X 0208 katakana | `codepoints-jisx0208` |
+/// | [`JapaneseKana`](markers::JapaneseKana) | hiragana ∪ katakana | `codepoints-jisx0208` |
+/// | [`JapaneseMixed`](markers::JapaneseMixed) | hiragana ∪ katakana ∪ ASCII printable | `codepoints-jisx0208` |
+/// | [`Jisx0208Full`](markers::Jisx0208Full) | all of JIS X 0208, kanji included | `codepoints-jisx0208` + `codepoints-jisx0208kanji` |
+///
+/// Each marker implements [`SubsetOf`] for the markers that contain it, so
+/// e.g. `ValidatedString<Hiragana>` widens to `ValidatedString<JapaneseMixed>`.
+pub mod markers {
+    use super::{SetMarker, SubsetOf};
+    use crate::CodePoints;
+
+    /// ASCII printable characters (U+0020–U+007E).
+    pub enum AsciiPrintable {}
+
+    impl SetMarker for AsciiPrintable {
+        const NAME: &'static str = "ASCII printable";
+
+        fn codepoints() -> &'static CodePoints {
+            CodePoints::ascii_printable_cached()
+        }
+    }
+
+    subsets!(AsciiPrintable => AsciiPrintable);
+
+    /// JIS X 0208 hiragana.
+    #[cfg(feature = "codepoints-jisx0208")]
+    pub enum Hiragana {}
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    impl SetMarker for Hiragana {
+        const NAME: &'static str = "JIS X 0208 Hiragana";
+
+        fn codepoints() -> &'static CodePoints {
+            crate::jisx0208::Hiragana::cached().codepoints()
+        }
+    }
+
+    /// JIS X 0208 katakana.
+    #[cfg(feature = "codepoints-jisx0208")]
+    pub enum Katakana {}
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    impl SetMarker for Katakana {
+        const NAME: &'static str = "JIS X 0208 Katakana";
+
+        fn codepoints() -> &'static CodePoints {
+            crate::jisx0208::Katakana::cached().codepoints()
+        }
+    }
+
+    /// JIS X 0208 hiragana and katakana, as checked by
+    /// [`validate_japanese_kana!`](crate::validate_japanese_kana).
+    #[cfg(feature = "codepoints-jisx0208")]
+    pub enum JapaneseKana {}
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    impl SetMarker for JapaneseKana {
+        const NAME: &'static str = "Japanese kana";
+
+        fn codepoints() -> &'static CodePoints {
+            static SET: std::sync::OnceLock<CodePoints> = std::sync::OnceLock::new();
+            SET.get_or_init(|| Hiragana::codepoints().union(Katakana::codepoints()))
+        }
+    }
+
+    /// JIS X 0208 hiragana and katakana plus ASCII printable, as checked by
+    /// [`validate_japanese_mixed!`](crate::validate_japanese_mixed).
+    #[cfg(feature = "codepoints-jisx0208")]
+    pub enum JapaneseMixed {}
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    impl SetMarker for JapaneseMixed {
+        const NAME: &'static str = "Japanese kana and ASCII";
+
+        fn codepoints() -> &'static CodePoints {
+            static SET: std::sync::OnceLock<CodePoints> = std::sync::OnceLock::new();
+            SET.get_or_init(|| JapaneseKana::codepoints().union(AsciiPrintable::codepoints()))
+        }
+    }
+
+    /// Every JIS X 0208 character: the non-kanji rows and both kanji levels.
+    #[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+    pub enum Jisx0208Full {}
+
+    #[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+    impl SetMarker for Jisx0208Full {
+        const NAME: &'static str = "JIS X 0208";
+
+        fn codepoints() -> &'static CodePoints {
+            static SET: std::sync::OnceLock<CodePoints> = std::sync::OnceLock::new();
+            SET.get_or_init(|| {
+                crate::jisx0208::JisX0208::cached()
+                    .codepoints()
+                    .union(crate::jisx0208kanji::JisX0208Kanji::cached().codepoints())
+            })
+        }
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    mod kana_subsets {
+        use super::*;
+
+        subsets!(
+            Hiragana => Hiragana,
+            Hiragana => JapaneseKana,
+            Hiragana => JapaneseMixed,
+            Katakana => Katakana,
+            Katakana => JapaneseKana,
+            Katakana => JapaneseMixed,
+            JapaneseKana => JapaneseKana,
+            JapaneseKana => JapaneseMixed,
+            JapaneseMixed => JapaneseMixed,
+            AsciiPrintable => JapaneseMixed,
+        );
+    }
+
+    #[cfg(all(feature = "codepoints-jisx0208", feature = "codepoints-jisx0208kanji"))]
+    mod jisx0208_subsets {
+        use super::*;
+
+        subsets!(
+            Hiragana => Jisx0208Full,
+            Katakana => Jisx0208Full,
+            JapaneseKana => Jisx0208Full,
+            Jisx0208Full => Jisx0208Full,
+        );
+    }
+}
+
+// ── macros ────────────────────────────────────────────────────────────────────
+
+/// Validates that `$value` contains only code points present in `$codepoints`.
+///
+/// Returns `Ok(())` on success; `Err([`ValidationError`])` on failure.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{validate_codepoints, CodePoints};
+///
+/// let cp = CodePoints::ascii_printable();
+/// assert!(validate_codepoints!("hello", &cp).is_ok());
+/// assert!(validate_codepoints!("hello\0", &cp).is_err());
+/// ```
+///
+/// ## With [`ValidateOptions`]
+///
+/// Returns `Result<`[`ValidationOutcome`]`, ValidationError>`.
+///
+/// ```rust
+/// use japanese_codepoints::validation::ValidateOptions;
+/// use japanese_codepoints::{validate_codepoints, CodePoints};
+///
+/// let cp = CodePoints::ascii_printable();
+/// let options = ValidateOptions {
+///     ignore: Some(CodePoints::zero_width_cached()),
+///     ..Default::default()
+/// };
+/// let outcome = validate_codepoints!("\u{FEFF}hello", &cp, options = &options).unwrap();
+/// assert_eq!(outcome.ignored_count, 1);
+/// ```
+///
+/// ## Required (non-empty)
 ///
-/// # Examples
+/// Rejects `""` with [`ValidationErrorKind::EmptyInput`] via
+/// [`validate_non_empty`].
 ///
 /// ```rust
-/// use japanese_codepoints::{CodePoints, validation::validate_all_in_any};
+/// use japanese_codepoints::validation::ValidationErrorKind;
+/// use japanese_codepoints::{validate_codepoints, CodePoints};
 ///
-/// let hiragana = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
-/// let katakana = CodePoints::new(vec![0x30A2]);          // ア
+/// let cp = CodePoints::ascii_printable();
+/// assert!(validate_codepoints!("hello", &cp, non_empty).is_ok());
+/// let err = validate_codepoints!("", &cp, non_empty).unwrap_err();
+/// assert_eq!(err.kind, ValidationErrorKind::EmptyInput);
+/// ```
 ///
-/// assert!(validate_all_in_any("あア", &[&hiragana, &katakana]).is_ok());
-/// assert!(validate_all_in_any("あx", &[&hiragana, &katakana]).is_err());
+/// ## Full report
+///
+/// Returns `Result<(), `[`ExclusionReport`]`>` via [`CodePoints::check`].
+///
+/// ```rust
+/// use japanese_codepoints::{validate_codepoints, CodePoints};
+///
+/// let cp = CodePoints::ascii_printable();
+/// let report = validate_codepoints!("aあいb", &cp, report).unwrap_err();
+/// assert_eq!(report.violation_count, 2);
 /// ```
-pub fn validate_all_in_any(text: &str, sets: &[&CodePoints]) -> Result<(), ValidationError> {
-    for (i, c) in text.chars().enumerate() {
-        if !sets.iter().any(|set| set.contains_char(c)) {
-            return Err(ValidationError::new(c as u32, i));
-        }
-    }
-    Ok(())
-}
+#[macro_export]
+macro_rules! validate_codepoints {
+    ($value:expr, $codepoints:expr) => {
+        $codepoints.validate_input($value)
+    };
 
-// ── macros ────────────────────────────────────────────────────────────────────
+    ($value:expr, $codepoints:expr, options = $options:expr) => {
+        $crate::validation::validate_with_options($value, &[$codepoints], $options)
+    };
 
-/// Validates that `$value` contains only code points present in `$codepoints`.
+    ($value:expr, $codepoints:expr, report) => {
+        $codepoints.check($value)
+    };
+
+    ($value:expr, $codepoints:expr, non_empty) => {
+        $crate::validation::validate_non_empty($value, $codepoints)
+    };
+}
+
+/// Validates `$value` against `$codepoints` and yields the value itself on
+/// success, evaluating `$value` exactly once.
 ///
-/// Returns `Ok(())` on success; `Err([`ValidationError`])` on failure.
+/// A `&str` gives `Result<&str, `[`ValidationError`]`>`; a `String` gives
+/// `Result<String, (String, ValidationError)>`, returning the string with
+/// the error.  See [`ValidateAndTake`].
 ///
 /// # Examples
 ///
 /// ```rust
-/// use japanese_codepoints::{validate_codepoints, CodePoints};
+/// use japanese_codepoints::validation::ValidationError;
+/// use japanese_codepoints::{validate_and_take, CodePoints};
 ///
-/// let cp = CodePoints::ascii_printable();
-/// assert!(validate_codepoints!("hello", &cp).is_ok());
-/// assert!(validate_codepoints!("hello\0", &cp).is_err());
+/// struct User {
+///     name: String,
+/// }
+///
+/// fn parse(input: &str) -> Result<User, ValidationError> {
+///     let kana = CodePoints::from_char_range('ぁ'..='ん');
+///     let name = validate_and_take!(input, &kana)?;
+///     Ok(User { name: name.to_string() })
+/// }
+///
+/// assert_eq!(parse("たろう").unwrap().name, "たろう");
+/// assert!(parse("taro").is_err());
+///
+/// let ascii = CodePoints::ascii_printable();
+/// let owned = validate_and_take!(String::from("taro"), &ascii).unwrap();
+/// assert_eq!(owned, "taro");
 /// ```
 #[macro_export]
-macro_rules! validate_codepoints {
+macro_rules! validate_and_take {
     ($value:expr, $codepoints:expr) => {
-        $codepoints.validate($value)
+        $crate::validation::ValidateAndTake::validate_and_take($value, $codepoints)
     };
 }
 
@@ -154,7 +1536,7 @@ macro_rules! validate_codepoints {
 macro_rules! validate_codepoints_advanced {
     // Custom error message — overrides the default ValidationError message.
     ($value:expr, $codepoints:expr, $error_msg:expr) => {
-        $codepoints.validate($value).map_err(|mut e| {
+        $codepoints.validate_input($value).map_err(|mut e| {
             e.message = $error_msg.to_string();
             e
         })
@@ -162,7 +1544,128 @@ macro_rules! validate_codepoints_advanced {
 
     // Detailed — identical to validate_codepoints! but kept for symmetry.
     ($value:expr, detailed $codepoints:expr) => {
-        $codepoints.validate($value)
+        $codepoints.validate_input($value)
+    };
+}
+
+/// Validates several named fields, reporting the first error of each field
+/// that fails.
+///
+/// Each entry is `name => (value, codepoints)`, where `name` is a `&str`.
+/// Expands to one [`validate_codepoints!`] call per field and returns
+/// `Result<(), HashMap<&str, ValidationError>>`; the map holds only the
+/// failing fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{validate_field_map, CodePoints};
+///
+/// let ascii = CodePoints::ascii_printable();
+/// let digits = CodePoints::from_string("0123456789");
+///
+/// let errors = validate_field_map!({
+///     "name" => ("Taro", &ascii),
+///     "zip" => ("100-0001", &digits),
+/// })
+/// .unwrap_err();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors["zip"].code_point, '-' as u32);
+/// ```
+#[macro_export]
+macro_rules! validate_field_map {
+    ({ $($field:expr => ($value:expr, $codepoints:expr)),* $(,)? }) => {{
+        let mut errors: ::std::collections::HashMap<&str, $crate::validation::ValidationError> =
+            ::std::collections::HashMap::new();
+        $(
+            if let Err(e) = $crate::validate_codepoints!($value, $codepoints) {
+                errors.insert($field, e);
+            }
+        )*
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }};
+}
+
+/// Validates several named fields, reporting **every** invalid character of
+/// each failing field.
+///
+/// Takes the same input as [`validate_field_map!`] but uses
+/// [`collect_violations`](crate::validation::collect_violations), returning
+/// `Result<(), HashMap<&str, Vec<ValidationError>>>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::{validate_all_fields, CodePoints};
+///
+/// let digits = CodePoints::from_string("0123456789");
+/// let errors = validate_all_fields!({
+///     "zip" => ("100-00O1", &digits),
+///     "phone" => ("0312345678", &digits),
+/// })
+/// .unwrap_err();
+/// assert_eq!(errors["zip"].len(), 2); // '-' and 'O'
+/// assert!(!errors.contains_key("phone"));
+/// ```
+#[macro_export]
+macro_rules! validate_all_fields {
+    ({ $($field:expr => ($value:expr, $codepoints:expr)),* $(,)? }) => {{
+        let mut errors: ::std::collections::HashMap<
+            &str,
+            ::std::vec::Vec<$crate::validation::ValidationError>,
+        > = ::std::collections::HashMap::new();
+        $(
+            let violations = $crate::validation::collect_violations($value, $codepoints);
+            if !violations.is_empty() {
+                errors.insert($field, violations);
+            }
+        )*
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }};
+}
+
+/// Declares a custom [`SetMarker`](crate::validation::SetMarker) type
+/// bound to a lazily built [`CodePoints`](crate::CodePoints) set.
+///
+/// The expression is evaluated once, on first use.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::validation::ValidatedString;
+/// use japanese_codepoints::{set_marker, CodePoints};
+///
+/// set_marker! {
+///     /// ASCII digits.
+///     pub Digits, "digits", CodePoints::from_string("0123456789")
+/// }
+///
+/// assert!(ValidatedString::<Digits>::try_new("42".to_string()).is_ok());
+/// assert!(ValidatedString::<Digits>::try_new("4x".to_string()).is_err());
+/// ```
+#[macro_export]
+macro_rules! set_marker {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $label:literal, $set:expr) => {
+        $(#[$meta])*
+        $vis enum $name {}
+
+        impl $crate::validation::SetMarker for $name {
+            const NAME: &'static str = $label;
+
+            fn codepoints() -> &'static $crate::CodePoints {
+                static SET: ::std::sync::OnceLock<$crate::CodePoints> =
+                    ::std::sync::OnceLock::new();
+                SET.get_or_init(|| $set)
+            }
+        }
     };
 }
 
@@ -179,12 +1682,14 @@ macro_rules! validate_codepoints_advanced {
 /// assert!(validate_hiragana!("あいうえお").is_ok());
 /// # #[cfg(feature = "codepoints-jisx0208")]
 /// assert!(validate_hiragana!("Hello").is_err());
+/// # #[cfg(feature = "codepoints-jisx0208")]
+/// assert!(validate_hiragana!('あ').is_ok()); // any `ValidateInput`
 /// ```
 #[cfg(feature = "codepoints-jisx0208")]
 #[macro_export]
 macro_rules! validate_hiragana {
     ($value:expr) => {
-        $crate::jisx0208::Hiragana::cached().validate($value)
+        $crate::jisx0208::Hiragana::cached().validate_input($value)
     };
 }
 
@@ -204,7 +1709,7 @@ macro_rules! validate_hiragana {
 #[macro_export]
 macro_rules! validate_katakana {
     ($value:expr) => {
-        $crate::jisx0208::Katakana::cached().validate($value)
+        $crate::jisx0208::Katakana::cached().validate_input($value)
     };
 }
 
@@ -231,7 +1736,7 @@ macro_rules! validate_japanese_kana {
             $crate::jisx0208::Hiragana::cached().codepoints(),
             $crate::jisx0208::Katakana::cached().codepoints(),
         ];
-        $crate::validation::validate_all_in_any($value, sets)
+        $crate::validation::ValidateInput::validate_in_any($value, sets)
     }};
 }
 
@@ -257,10 +1762,31 @@ macro_rules! validate_japanese_mixed {
             $crate::jisx0208::Katakana::cached().codepoints(),
             $crate::CodePoints::ascii_printable_cached(),
         ];
-        $crate::validation::validate_all_in_any($value, sets)
+        $crate::validation::ValidateInput::validate_in_any($value, sets)
     }};
 }
 
+/// Validates that `$value` is a katakana **corporate name**, as defined by
+/// [`presets::corporate_name_kana`](crate::presets::corporate_name_kana).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "codepoints-jisx0208")]
+/// use japanese_codepoints::validate_corporate_name_kana;
+/// # #[cfg(feature = "codepoints-jisx0208")]
+/// assert!(validate_corporate_name_kana!("ソニー・ホールディングス").is_ok());
+/// # #[cfg(feature = "codepoints-jisx0208")]
+/// assert!(validate_corporate_name_kana!("トヨタ自動車").is_err());
+/// ```
+#[cfg(feature = "codepoints-jisx0208")]
+#[macro_export]
+macro_rules! validate_corporate_name_kana {
+    ($value:expr) => {
+        $crate::presets::corporate_name_kana().validate_input($value)
+    };
+}
+
 /// Validates that `$value` contains only JIS X 0201 **halfwidth katakana**.
 ///
 /// # Examples
@@ -277,7 +1803,7 @@ macro_rules! validate_japanese_mixed {
 #[macro_export]
 macro_rules! validate_jisx0201_katakana {
     ($value:expr) => {
-        $crate::jisx0201::Katakana::cached().validate($value)
+        $crate::jisx0201::Katakana::cached().validate_input($value)
     };
 }
 
@@ -297,7 +1823,7 @@ macro_rules! validate_jisx0201_katakana {
 #[macro_export]
 macro_rules! validate_jisx0201_latin {
     ($value:expr) => {
-        $crate::jisx0201::LatinLetters::cached().validate($value)
+        $crate::jisx0201::LatinLetters::cached().validate_input($value)
     };
 }
 
@@ -312,6 +1838,27 @@ mod tests {
         assert!(e.to_string().contains("position 2"));
     }
 
+    #[test]
+    fn test_validation_error_into_string() {
+        let e = ValidationError::new(0x3046, 2);
+        assert_eq!(String::from(e.clone()), e.to_string());
+        let e = ValidationError::with_message(0x3046, 2, "kana");
+        assert_eq!(String::from(e), "kana");
+    }
+
+    #[test]
+    fn test_kind_names() {
+        for kind in [
+            ValidationErrorKind::InvalidChar,
+            ValidationErrorKind::EmptyInput,
+            ValidationErrorKind::DeniedCharacter,
+            ValidationErrorKind::TooLong,
+        ] {
+            assert_eq!(kind.as_str().parse(), Ok(kind));
+        }
+        assert!("invalid".parse::<ValidationErrorKind>().is_err());
+    }
+
     #[test]
     fn test_validation_error_with_message() {
         let e = ValidationError::with_message(0x41, 0, "custom msg");
@@ -319,6 +1866,76 @@ mod tests {
         assert_eq!(e.code_point, 0x41);
     }
 
+    #[test]
+    fn test_validate_multiline() {
+        let cp = CodePoints::ascii_printable();
+        let at = |text: &str| -> Vec<(usize, usize, u32)> {
+            validate_multiline(text, &cp)
+                .unwrap_err()
+                .iter()
+                .map(|e| (e.line, e.column, e.code_point))
+                .collect()
+        };
+
+        // First line, last line (no trailing break), right after a break.
+        assert_eq!(at("aあ\r\nok\r\nzい"), [(1, 2, 0x3042), (3, 2, 0x3044)]);
+        assert_eq!(at("ok\nう"), [(2, 1, 0x3046)]);
+        assert_eq!(at("ok\r\nえ\r\n"), [(2, 1, 0x3048)]);
+        assert_eq!(at("お"), [(1, 1, 0x304A)]);
+
+        // CRLF and LF breaks are not checked; a lone CR is.
+        assert!(validate_multiline("a\r\nb\nc\r\n\n", &cp).is_ok());
+        assert!(validate_multiline("", &cp).is_ok());
+        assert_eq!(at("a\rb\n"), [(1, 2, 0x0D)]);
+        assert_eq!(at("a\r"), [(1, 2, 0x0D)]);
+
+        let input = "ok\r\nxあyい\r\n";
+        let errors = validate_multiline(input, &cp).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| e.line_text(input) == "xあyい"));
+        assert_eq!(errors[0].line_range, 4..12);
+        assert_eq!((errors[0].byte_index, errors[1].byte_index), (5, 9));
+        assert_eq!(errors[1].line_text("other"), "");
+    }
+
+    #[test]
+    fn test_multiline_long_invalid_line() {
+        // Every character of a 100 000-character line is invalid; errors
+        // must not each carry a copy of the line.
+        let line = "あ".repeat(100_000);
+        let input = format!("ok\n{}\nok", line);
+        let errors = validate_multiline(&input, &CodePoints::ascii_printable()).unwrap_err();
+        assert_eq!(errors.len(), 100_000);
+        assert!(std::mem::size_of::<LineColError>() <= 64);
+        assert!(errors.iter().all(|e| e.line_range == (3..3 + line.len())));
+
+        let last = errors.last().unwrap();
+        assert_eq!((last.line, last.column), (2, 100_000));
+        assert_eq!(last.byte_index, 3 + 3 * 99_999);
+        assert_eq!(last.line_text(&input).len(), line.len());
+        assert_eq!(errors[50_000].excerpt(&input, 5), "…あああああ…");
+        assert_eq!(last.excerpt(&input, 3), "…あああ");
+    }
+
+    #[test]
+    fn test_line_col_excerpt() {
+        let input = "abあcdefgh";
+        let errors = validate_multiline(input, &CodePoints::new(vec![])).unwrap_err();
+        let at = |column: usize| &errors[column - 1];
+        assert_eq!(at(3).excerpt(input, 100), "abあcdefgh");
+        assert_eq!(at(3).excerpt(input, 4), "abあc…");
+        assert_eq!(at(3).excerpt(input, 1), "…あ…");
+        assert_eq!(at(5).excerpt(input, 4), "…あcde…");
+        assert_eq!(at(9).excerpt(input, 4), "…efgh");
+        assert_eq!(at(1).excerpt(input, 4), "abあc…");
+
+        // Excerpts are taken from the error's own line.
+        let input = "first\nxyあz\nlast";
+        let err = &validate_multiline(input, &CodePoints::ascii_printable()).unwrap_err()[0];
+        assert_eq!(err.excerpt(input, 2), "…yあ…");
+        assert_eq!(err.excerpt(input, 10), "xyあz");
+    }
+
     #[test]
     fn test_validate_all_in_any() {
         let hira = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
@@ -349,4 +1966,607 @@ mod tests {
         // π (U+03C0) not in any set
         assert!(validate_all_in_any("あアAπ", &[&hira, &kata, &ascii]).is_err());
     }
+
+    // ── validate_with_options ───────────────────────────────────────────
+
+    #[test]
+    fn test_validate_with_options_bom_prefixed() {
+        let hira = CodePoints::new(vec![0x3042, 0x3044, 0x3046]); // あ, い, う
+        let text = "\u{FEFF}あいう";
+
+        let err = validate_with_options(text, &[&hira], &ValidateOptions::default()).unwrap_err();
+        assert_eq!(err.code_point, 0xFEFF);
+        assert_eq!(err.position, 0);
+
+        let options = ValidateOptions {
+            trim: false,
+            ignore: Some(CodePoints::zero_width_cached()),
+            require_non_empty: false,
+        };
+        let outcome = validate_with_options(text, &[&hira], &options).unwrap();
+        assert_eq!(outcome.ignored_count, 1);
+        assert_eq!(outcome.ignored_positions, vec![0]);
+    }
+
+    #[test]
+    fn test_validate_with_options_trim() {
+        let hira = CodePoints::new(vec![0x3042, 0x3044]); // あ, い
+        let trim = ValidateOptions {
+            trim: true,
+            ignore: None,
+            require_non_empty: false,
+        };
+
+        assert!(validate_with_options("  あい\n", &[&hira], &ValidateOptions::default()).is_err());
+        assert!(validate_with_options("  あい\n", &[&hira], &trim).is_ok());
+        assert!(validate_with_options("　あい　", &[&hira], &trim).is_ok()); // ideographic space
+
+        // Interior whitespace is still checked; position refers to the original text.
+        let err = validate_with_options("  あ い", &[&hira], &trim).unwrap_err();
+        assert_eq!(err.code_point, 0x20);
+        assert_eq!(err.position, 3);
+    }
+
+    #[test]
+    fn test_validate_with_options_positions_after_trim() {
+        let hira = CodePoints::new(vec![0x3042]); // あ
+        let options = ValidateOptions {
+            trim: true,
+            ignore: Some(CodePoints::zero_width_cached()),
+            require_non_empty: false,
+        };
+        let outcome = validate_with_options(" あ\u{200B}あ\u{FEFF} ", &[&hira], &options).unwrap();
+        assert_eq!(outcome.ignored_count, 2);
+        assert_eq!(outcome.ignored_positions, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_validate_codepoints_macro_options() {
+        let cp = CodePoints::ascii_printable();
+        let options = ValidateOptions {
+            trim: true,
+            ignore: Some(CodePoints::zero_width_cached()),
+            require_non_empty: false,
+        };
+        let outcome = crate::validate_codepoints!("\u{FEFF}hi ", &cp, options = &options).unwrap();
+        assert_eq!(outcome.ignored_count, 1);
+        assert!(crate::validate_codepoints!("\u{FEFF}hi", &cp).is_err());
+    }
+
+    #[test]
+    fn test_require_non_empty() {
+        let hira = CodePoints::new(vec![0x3042]); // あ
+        let required = ValidateOptions {
+            require_non_empty: true,
+            ..Default::default()
+        };
+        let trimmed = ValidateOptions {
+            trim: true,
+            ..required
+        };
+
+        // Defaults are unchanged: empty input is vacuously valid.
+        assert!(validate_with_options("", &[&hira], &ValidateOptions::default()).is_ok());
+        assert!(hira.validate("").is_ok());
+
+        let err = validate_with_options("", &[&hira], &required).unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::EmptyInput);
+        assert_eq!(err, ValidationError::empty_input());
+
+        // Whitespace is only "empty" once trimmed.
+        let err = validate_with_options(" 　", &[&hira], &required).unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::InvalidChar);
+        let err = validate_with_options(" 　", &[&hira], &trimmed).unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::EmptyInput);
+
+        assert!(validate_with_options(" あ ", &[&hira], &trimmed).is_ok());
+        assert!(validate_with_options("あ", &[&hira], &required).is_ok());
+        let err = validate_with_options("あい", &[&hira], &required).unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::InvalidChar);
+        assert_eq!(err.position, 1);
+
+        assert!(crate::validate_codepoints!("あ", &hira, non_empty).is_ok());
+        assert_eq!(
+            crate::validate_codepoints!("", &hira, non_empty)
+                .unwrap_err()
+                .kind,
+            ValidationErrorKind::EmptyInput
+        );
+    }
+
+    #[test]
+    fn test_validate_and_take_passes_value_through() {
+        let cp = CodePoints::from_string("あいう");
+        let input = String::from("あいう");
+        let taken = validated(&input, &cp).unwrap();
+        assert!(std::ptr::eq(taken, input.as_str()));
+        assert_eq!(
+            validated("あえ", &cp),
+            Err(cp.validate("あえ").unwrap_err())
+        );
+
+        let ptr = input.as_ptr();
+        let owned = validated_owned(input, &cp).unwrap();
+        assert_eq!((owned.as_ptr(), owned.as_str()), (ptr, "あいう"));
+
+        let bad = String::from("いえ");
+        let ptr = bad.as_ptr();
+        let (back, err) = validated_owned(bad, &cp).unwrap_err();
+        assert_eq!((back.as_ptr(), back.as_str()), (ptr, "いえ"));
+        assert_eq!((err.code_point, err.position), (0x3048, 1));
+
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            "あい"
+        };
+        assert_eq!(crate::validate_and_take!(next(), &cp), Ok("あい"));
+        assert_eq!(calls, 1);
+        let owned = crate::validate_and_take!(String::from("うあ"), &cp).unwrap();
+        assert_eq!(owned, "うあ");
+        let (back, _) = crate::validate_and_take!(String::from("x"), &cp).unwrap_err();
+        assert_eq!(back, "x");
+        assert!(crate::validate_and_take!("", &cp).is_ok());
+    }
+
+    #[test]
+    fn test_collect_violations() {
+        let cp = CodePoints::new(vec![0x3042]); // あ
+        let errors = collect_violations("いあうい", &cp);
+        let found: Vec<_> = errors.iter().map(|e| (e.code_point, e.position)).collect();
+        assert_eq!(found, vec![(0x3044, 0), (0x3046, 2), (0x3044, 3)]);
+        assert_eq!(
+            errors[0],
+            ValidationError::new(0x3044, 0).with_byte_index(0)
+        );
+        assert!(collect_violations("ああ", &cp).is_empty());
+    }
+
+    #[test]
+    fn test_validate_field_map_macro() {
+        let ascii = CodePoints::ascii_printable();
+        let kana = CodePoints::from_string("あいうえお");
+
+        let result = crate::validate_field_map!({
+            "name" => ("Taro", &ascii),
+            "reading" => ("あいか", &kana),
+        });
+        let errors = result.unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors.contains_key("reading"));
+        assert_eq!(
+            errors["reading"],
+            ValidationError::new('か' as u32, 2).with_byte_index(6)
+        );
+
+        let ok = crate::validate_field_map!({
+            "name" => ("Taro", &ascii),
+        });
+        assert!(ok.is_ok());
+    }
+
+    #[test]
+    fn test_validate_all_fields_macro() {
+        let kana = CodePoints::from_string("あいうえお");
+        let errors = crate::validate_all_fields!({
+            "a" => ("あかいき", &kana),
+            "b" => ("あい", &kana),
+        })
+        .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        let positions: Vec<_> = errors["a"].iter().map(|e| e.position).collect();
+        assert_eq!(positions, vec![1, 3]);
+    }
+
+    // ── ExclusionReport ─────────────────────────────────────────────────
+
+    #[test]
+    fn test_check_report() {
+        let cp = CodePoints::from_string("abc");
+        assert!(cp.check("").is_ok());
+        assert!(cp.check("cab").is_ok());
+
+        let report = cp.check("aあbいあ").unwrap_err();
+        assert_eq!(report.total_chars, 5);
+        assert_eq!(report.violation_count, 3);
+        assert_eq!(
+            report.first,
+            Violation {
+                code_point: 0x3042,
+                char_index: 1,
+                byte_index: 1
+            }
+        );
+        let positions: Vec<_> = report
+            .violations
+            .iter()
+            .map(|v| (v.char_index, v.byte_index))
+            .collect();
+        assert_eq!(positions, vec![(1, 1), (3, 5), (4, 8)]);
+        assert_eq!(report.unique_excluded, vec![0x3042, 0x3044]);
+        assert_eq!(report.valid_prefix_len(), 1);
+        assert!(!report.is_truncated());
+        assert_eq!(
+            ValidationError::from(report),
+            cp.validate("aあbいあ").unwrap_err()
+        );
+        // The macros share the same backend.
+        assert_eq!(
+            crate::validate_codepoints!("aあbいあ", &cp),
+            cp.check("aあbいあ").map_err(ValidationError::from)
+        );
+    }
+
+    #[test]
+    fn test_check_with_limit() {
+        let cp = CodePoints::from_string("a");
+        let report = cp.check_with_limit("xaxyz", 2).unwrap_err();
+        assert_eq!(report.violations.len(), 2);
+        assert_eq!(report.violation_count, 4);
+        assert!(report.is_truncated());
+        assert_eq!(report.unique_excluded.len(), 3);
+
+        let report = cp.check_with_limit("ax", 0).unwrap_err();
+        assert!(report.violations.is_empty());
+        assert_eq!(report.first().char_index, 1);
+    }
+
+    #[test]
+    fn test_exclusion_report_display() {
+        let cp = CodePoints::from_string("a");
+        let report = crate::validate_codepoints!("aaxx", &cp, report).unwrap_err();
+        assert_eq!(
+            report.to_string(),
+            "2 of 4 characters not allowed (1 unique); first: 'x' (U+0078) at position 2"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_exclusion_report_serialize() {
+        let cp = CodePoints::from_string("a");
+        let report = cp.check("ab").unwrap_err();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["total_chars"], 2);
+        assert_eq!(json["first"]["code_point"], 0x62);
+        assert_eq!(json["first"]["byte_index"], 1);
+        assert_eq!(json["violations"].as_array().unwrap().len(), 1);
+        assert_eq!(json["first"]["code_point_hex"], "U+0062");
+        assert_eq!(json["truncated"], false);
+        assert_eq!(
+            json["unique_excluded"],
+            serde_json::json!([{ "code_point": 0x62, "code_point_hex": "U+0062" }])
+        );
+    }
+
+    #[test]
+    fn test_macros_astral_characters() {
+        let cp = CodePoints::from_string("𠮷野家");
+        assert!(crate::validate_codepoints!("𠮷野家", &cp).is_ok());
+        let err = crate::validate_codepoints!("吉野家🍚", &cp).unwrap_err();
+        assert_eq!((err.code_point, err.position), (0x5409, 0));
+        let err = validate_all_in_any("𠮷🍚", &[&cp]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid character '🍚' (U+1F35A) at position 1"
+        );
+        let errors = collect_violations("🍚𠮷🍣", &cp);
+        assert_eq!(
+            errors[1].message,
+            "invalid character '🍣' (U+1F363) at position 2"
+        );
+    }
+
+    /// Runs `$macro!` on a `char`, `&str`, `String` and `&String` built
+    /// from `$ok`, and on the rejected character `$bad`.
+    macro_rules! check_inputs {
+        ($macro:ident $(, $set:expr)?; $ok:expr, $bad:expr) => {{
+            let ok: &str = $ok;
+            let owned = ok.to_string();
+            let first = ok.chars().next().unwrap();
+            assert!(crate::$macro!(first $(, $set)?).is_ok());
+            assert!(crate::$macro!(ok $(, $set)?).is_ok());
+            assert!(crate::$macro!(owned.clone() $(, $set)?).is_ok());
+            assert!(crate::$macro!(&owned $(, $set)?).is_ok());
+            let err = crate::$macro!($bad $(, $set)?).unwrap_err();
+            assert_eq!((err.code_point, err.position), ($bad as u32, 0));
+        }};
+    }
+
+    #[test]
+    fn test_macros_accept_chars_and_strings() {
+        let cp = CodePoints::from_string("あい𠮷");
+        check_inputs!(validate_codepoints, &cp; "あい𠮷", 'x');
+        assert!(crate::validate_codepoints_advanced!('𠮷', detailed cp).is_ok());
+        let err = crate::validate_codepoints_advanced!('x', &cp, "kana only").unwrap_err();
+        assert_eq!((err.position, err.message.as_str()), (0, "kana only"));
+        #[cfg(feature = "codepoints-jisx0208")]
+        {
+            check_inputs!(validate_hiragana; "あいう", 'ア');
+            check_inputs!(validate_katakana; "アイウ", 'あ');
+            check_inputs!(validate_japanese_kana; "あア", 'a');
+            check_inputs!(validate_japanese_mixed; "あアa", '漢');
+            check_inputs!(validate_corporate_name_kana; "エー＆ビー", 'a');
+        }
+        #[cfg(feature = "codepoints-jisx0201")]
+        {
+            check_inputs!(validate_jisx0201_katakana; "ｱｲｳ", 'ア');
+            check_inputs!(validate_jisx0201_latin; "Hello¥", 'あ');
+        }
+    }
+
+    #[test]
+    fn test_validate_input_code_points_and_slices() {
+        let cp = CodePoints::from_string("あい");
+        assert!(cp.validate_input(0x3042).is_ok());
+        assert_eq!(cp.validate_input(0x3046).unwrap_err().code_point, 0x3046);
+        let err = cp.validate_input(0xD800).unwrap_err();
+        assert_eq!((err.code_point, err.position), (0xD800, 0));
+        assert!(cp.validate_input(0x11_0000).is_err());
+
+        assert!(cp.validate_input(['あ', 'い', 'あ'].as_slice()).is_ok());
+        assert!(cp.validate_input([].as_slice()).is_ok());
+        let err = cp.validate_input(['あ', 'x', 'y'].as_slice()).unwrap_err();
+        assert_eq!((err.code_point, err.position), ('x' as u32, 1));
+        let err = ['あ', '🍚'].as_slice().validate_in_any(&[&cp]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid character '🍚' (U+1F35A) at position 1"
+        );
+        assert!(ValidateInput::validate_in_any('x', &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_keeps_deref_coercion() {
+        let cp = CodePoints::from_string("あい");
+        let cow = std::borrow::Cow::Borrowed("あい");
+        let boxed: Box<str> = "いあ".into();
+        let shared: std::rc::Rc<str> = "い".into();
+        assert!(cp.validate(&cow).is_ok());
+        assert!(cp.validate(&boxed).is_ok());
+        assert!(validate_all_in_any(&boxed, &[&cp]).is_ok());
+        assert!(cp.validate_input(&cow).is_ok());
+        assert!(cp.validate_input(&boxed).is_ok());
+        assert!(cp.validate_input(&shared).is_ok());
+        assert!(crate::validate_codepoints!(&cow, &cp).is_ok());
+        assert!(crate::validate_codepoints!(&&*boxed, &cp).is_ok());
+        #[cfg(feature = "codepoints-jisx0208")]
+        {
+            assert!(crate::validate_hiragana!(&boxed).is_ok());
+            assert!(crate::validate_japanese_kana!(&cow).is_ok());
+            assert!(crate::presets::validate_corporate_name_kana(&boxed).is_err());
+        }
+    }
+
+    // ── typed validated strings ───────────────────────────────────────────
+
+    crate::set_marker! {
+        Digits, "digits", CodePoints::from_string("0123456789")
+    }
+
+    #[test]
+    fn test_validated_string_custom_marker() {
+        let v = ValidatedString::<Digits>::try_new("2024".to_string()).unwrap();
+        assert_eq!(&*v, "2024");
+        assert_eq!(v.as_ref(), "2024");
+        assert_eq!(v.to_string(), "2024");
+        assert_eq!(format!("{:?}", v), "ValidatedString<digits>(\"2024\")");
+        assert_eq!(v.clone(), v);
+        assert_eq!(String::from(v), "2024");
+
+        let (original, err) = ValidatedString::<Digits>::try_new("20x4".to_string()).unwrap_err();
+        assert_eq!(original, "20x4");
+        assert_eq!((err.code_point, err.position), (0x78, 2));
+        assert_eq!(
+            std::mem::size_of::<ValidatedString<Digits>>(),
+            std::mem::size_of::<String>()
+        );
+    }
+
+    #[test]
+    fn test_validated_string_try_convert() {
+        let v = ValidatedString::<Digits>::try_new("12".to_string()).unwrap();
+        let ascii = v.try_convert::<markers::AsciiPrintable>().unwrap();
+        assert_eq!(ascii.as_str(), "12");
+        let back = ascii.try_convert::<Digits>().unwrap();
+        assert_eq!(back.into_inner(), "12");
+
+        let text = ValidatedString::<markers::AsciiPrintable>::try_new("ab".to_string()).unwrap();
+        let (text, err) = text.try_convert::<Digits>().unwrap_err();
+        assert_eq!(text.as_str(), "ab");
+        assert_eq!(err.position, 0);
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    #[test]
+    fn test_validated_string_widen() {
+        use markers::{Hiragana, JapaneseKana, JapaneseMixed, Katakana};
+
+        let h = ValidatedString::<Hiragana>::try_new("ひらがな".to_string()).unwrap();
+        let kana: ValidatedString<JapaneseKana> = h.clone().widen();
+        let mixed: ValidatedString<JapaneseMixed> = kana.widen();
+        assert_eq!(mixed.as_str(), "ひらがな");
+        assert!(ValidatedString::<Katakana>::try_new("ひらがな".to_string()).is_err());
+        assert!(ValidatedString::<JapaneseMixed>::try_new("カナabc".to_string()).is_ok());
+        assert!(h.try_convert::<Katakana>().is_err());
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    #[test]
+    fn test_marker_subset_impls_hold() {
+        use markers::*;
+
+        fn check<A: SubsetOf<B>, B: SetMarker>() {
+            assert!(
+                A::codepoints().is_subset_of(B::codepoints()),
+                "{} is not a subset of {}",
+                A::NAME,
+                B::NAME
+            );
+        }
+        check::<Hiragana, JapaneseKana>();
+        check::<Hiragana, JapaneseMixed>();
+        check::<Katakana, JapaneseKana>();
+        check::<Katakana, JapaneseMixed>();
+        check::<JapaneseKana, JapaneseMixed>();
+        check::<AsciiPrintable, JapaneseMixed>();
+        #[cfg(feature = "codepoints-jisx0208kanji")]
+        {
+            check::<Hiragana, Jisx0208Full>();
+            check::<Katakana, Jisx0208Full>();
+            check::<JapaneseKana, Jisx0208Full>();
+            assert!(Jisx0208Full::codepoints().contains("漢字かなカナ"));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_validated_string_serde() {
+        let v = ValidatedString::<Digits>::try_new("123".to_string()).unwrap();
+        assert_eq!(serde_json::to_string(&v).unwrap(), "\"123\"");
+        let back: ValidatedString<Digits> = serde_json::from_str("\"123\"").unwrap();
+        assert_eq!(back, v);
+        let err = serde_json::from_str::<ValidatedString<Digits>>("\"1a\"").unwrap_err();
+        assert!(err.to_string().contains("U+0061"));
+    }
+
+    // ── failure reporting ───────────────────────────────────────────────
+
+    type Failure = (Option<&'static str>, u32, usize, usize);
+
+    std::thread_local! {
+        static SEEN: std::cell::RefCell<Vec<Failure>> = const { std::cell::RefCell::new(Vec::new()) };
+    }
+
+    /// Records failures of the current thread only, so that tests running
+    /// in parallel do not see each other's failures.
+    struct Recorder;
+
+    impl ValidationHooks for Recorder {
+        fn on_failure(&self, set: Option<&'static str>, error: &ValidationError, input_len: usize) {
+            SEEN.with(|seen| {
+                seen.borrow_mut()
+                    .push((set, error.code_point, error.position, input_len))
+            });
+        }
+    }
+
+    /// Serializes the tests that install the process-wide hook.
+    static HOOK_TESTS: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    /// Installs a hook for the duration of a test and removes it on drop,
+    /// even if the test panics.
+    struct HookGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl HookGuard {
+        fn install(hook: impl ValidationHooks + 'static) -> Self {
+            let lock = HOOK_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+            set_hook(hook);
+            HookGuard { _lock: lock }
+        }
+    }
+
+    impl Drop for HookGuard {
+        fn drop(&mut self) {
+            clear_hook();
+        }
+    }
+
+    #[test]
+    fn test_validation_hook() {
+        let _hook = HookGuard::install(Recorder);
+        let ascii = CodePoints::ascii_printable_cached();
+        assert!(ascii.validate("ok").is_ok());
+        assert!(ascii.validate("abcあ").is_err());
+        assert!(validate_all_in_any("xい", &[ascii]).is_err());
+        let options = ValidateOptions::default();
+        assert!(validate_with_options("う", &[ascii], &options).is_err());
+        assert!(ascii.validate_input('え').is_err());
+        assert!(ascii.validate_input(['a', 'お'].as_slice()).is_err());
+        #[cfg(feature = "codepoints-jisx0208")]
+        assert!(crate::jisx0208::Hiragana::cached().validate("あa").is_err());
+
+        let seen = SEEN.with(|seen| seen.take());
+        assert_eq!(seen[0], (None, 0x3042, 3, 6));
+        assert_eq!(seen[1], (None, 0x3044, 1, 4));
+        assert_eq!(seen[2], (None, 0x3046, 0, 3));
+        assert_eq!(seen[3], (None, 0x3048, 0, 3));
+        assert_eq!(seen[4], (None, 0x304A, 1, 4));
+        #[cfg(feature = "codepoints-jisx0208")]
+        assert_eq!(seen[5], (Some("JIS X 0208 Hiragana"), 0x61, 1, 4));
+        assert_eq!(
+            seen.len(),
+            if cfg!(feature = "codepoints-jisx0208") {
+                6
+            } else {
+                5
+            }
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing_event_fields() {
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+
+        #[derive(Default)]
+        struct Fields(Vec<(String, String)>);
+
+        impl Visit for Fields {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0
+                    .push((field.name().to_string(), format!("{:?}", value)));
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct Collect(Arc<Mutex<Vec<Fields>>>);
+
+        impl tracing::Subscriber for Collect {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                assert_eq!(*event.metadata().level(), tracing::Level::DEBUG);
+                let mut fields = Fields::default();
+                event.record(&mut fields);
+                self.0.lock().unwrap().push(fields);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let collect = Collect::default();
+        tracing::subscriber::with_default(collect.clone(), || {
+            let set = CodePoints::from_string("abc");
+            assert!(set.validate("secret-ab").is_err());
+        });
+        let events = collect.0.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let fields: Vec<(&str, &str)> = events[0]
+            .0
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("message", "validation failed"),
+                ("set", "\"unnamed\""),
+                ("code_point", "115"),
+                ("char_index", "0"),
+                ("input_len", "9"),
+            ]
+        );
+    }
 }
@@ -0,0 +1,1549 @@
+//! Composable validator combining allow-lists with predicate restrictions.
+//!
+//! Some policies are easier to express as a *rule* than as a set: "no
+//! characters outside the BMP" would need a million-entry complement set.
+//! A [`Restriction`] is such a rule, and a [`Validator`] combines any number
+//! of them with an optional [`CodePoints`] allow-list.
+//!
+//! Characters that must be rejected even when an allow-list contains them,
+//! such as invisible bidi controls, go in a deny-list
+//! ([`Validator::deny_set`]).
+//!
+//! Short sequences that are harmless character by character, such as a
+//! base kana followed by a particular combining mark, go in a
+//! [`SequenceDenyList`] ([`Validator::deny_sequences`]).
+//!
+//! Fixed-width legacy interfaces limit fields by encoded bytes rather than
+//! characters; [`Validator::max_encoded_bytes`] checks such a limit for an
+//! [`EncodingLimit`].
+//!
+//! [`Validator::first_violation`] reports *why* a character was rejected
+//! through the [`Violation`] enum.
+//!
+//! [`CachedValidator`] memoizes [`CodePoints::contains`] for workloads that
+//! check the same strings repeatedly.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::CodePoints;
+//! use japanese_codepoints::validator::{Restriction, Validator, Violation};
+//!
+//! let allowed = CodePoints::from_string("あい𠀋");
+//! let validator = Validator::new()
+//!     .allow(&allowed)
+//!     .restrict(Restriction::no_non_bmp());
+//!
+//! assert!(validator.is_valid("あい"));
+//! assert!(matches!(
+//!     validator.first_violation("あ𠀋"),
+//!     Some(Violation::Restricted { position: 1, .. })
+//! ));
+//! assert!(matches!(
+//!     validator.first_violation("あう"),
+//!     Some(Violation::NotAllowed { position: 1, .. })
+//! ));
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::data::unicode::{BIDI_CONTROL_CHARS, INTERLINEAR_ANNOTATION_CHARS, ZERO_WIDTH_CHARS};
+use crate::validation::{ValidationError, ValidationErrorKind};
+use crate::CodePoints;
+
+// ── restriction ───────────────────────────────────────────────────────────────
+
+/// A named predicate that forbids characters without materializing a set.
+///
+/// Restrictions are cheap to copy and evaluate in constant time (or
+/// `O(log n)` over a range table).
+#[derive(Clone, Copy)]
+pub struct Restriction {
+    name: &'static str,
+    forbids: fn(char) -> bool,
+}
+
+impl Restriction {
+    /// Creates a custom restriction.  `forbids` returns `true` for every
+    /// character the restriction rejects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validator::Restriction;
+    ///
+    /// let no_digits = Restriction::new("no_digits", |c| c.is_ascii_digit());
+    /// assert!(no_digits.forbids('1'));
+    /// assert!(!no_digits.forbids('a'));
+    /// ```
+    pub fn new(name: &'static str, forbids: fn(char) -> bool) -> Self {
+        Self { name, forbids }
+    }
+
+    /// Rejects every character outside the Basic Multilingual Plane
+    /// (U+10000 and above), such as 𠮟 (U+20B9F) or 😀 (U+1F600).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validator::Restriction;
+    ///
+    /// let r = Restriction::no_non_bmp();
+    /// assert!(r.forbids('𠮟'));
+    /// assert!(!r.forbids('叱'));
+    /// ```
+    pub fn no_non_bmp() -> Self {
+        Self::new("no_non_bmp", |c| c as u32 > 0xFFFF)
+    }
+
+    /// Rejects every character with the Unicode `Extended_Pictographic`
+    /// property, including BMP symbols such as ☺ (U+263A) (requires the
+    /// `emoji` feature).
+    ///
+    /// Note that a few JIS X 0208 symbols are pictographic, e.g. ★ (U+2605)
+    /// and ♪ (U+266A).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validator::Restriction;
+    ///
+    /// let r = Restriction::no_emoji();
+    /// assert!(r.forbids('☺'));
+    /// assert!(r.forbids('😀'));
+    /// assert!(!r.forbids('、'));
+    /// ```
+    #[cfg(feature = "emoji")]
+    pub fn no_emoji() -> Self {
+        Self::new("no_emoji", |c| {
+            let cp = c as u32;
+            crate::data::unicode::EXTENDED_PICTOGRAPHIC
+                .binary_search_by(|&(start, end)| {
+                    if end < cp {
+                        std::cmp::Ordering::Less
+                    } else if start > cp {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .is_ok()
+        })
+    }
+
+    /// Returns the name of this restriction, e.g. `"no_non_bmp"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns `true` if this restriction rejects `c`.
+    pub fn forbids(&self, c: char) -> bool {
+        (self.forbids)(c)
+    }
+}
+
+impl fmt::Debug for Restriction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Restriction").field(&self.name).finish()
+    }
+}
+
+// ── encoded length ────────────────────────────────────────────────────────────
+
+/// A legacy encoding whose byte length a [`Validator`] can limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EncodingLimit {
+    /// Shift_JIS as CP932 (Windows-31J): ASCII and halfwidth katakana take
+    /// one byte, every other character two.
+    ShiftJis,
+    /// EUC-JP: ASCII takes one byte, every other character, halfwidth
+    /// katakana included, two.
+    EucJp,
+}
+
+impl EncodingLimit {
+    /// Returns the encoding's name, e.g. `"Shift_JIS"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EncodingLimit::ShiftJis => "Shift_JIS",
+            EncodingLimit::EucJp => "EUC-JP",
+        }
+    }
+
+    /// Returns the number of bytes `c` takes in this encoding, or `None` if
+    /// it cannot be encoded (see [`CodePoints::for_encoding`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validator::EncodingLimit;
+    ///
+    /// assert_eq!(EncodingLimit::ShiftJis.encoded_len('A'), Some(1));
+    /// assert_eq!(EncodingLimit::ShiftJis.encoded_len('ｱ'), Some(1));
+    /// assert_eq!(EncodingLimit::ShiftJis.encoded_len('ア'), Some(2));
+    /// assert_eq!(EncodingLimit::EucJp.encoded_len('ｱ'), Some(2));
+    /// assert_eq!(EncodingLimit::ShiftJis.encoded_len('𠮷'), None);
+    /// ```
+    pub fn encoded_len(&self, c: char) -> Option<usize> {
+        let (label, single_byte) = match self {
+            EncodingLimit::ShiftJis => (
+                "shift_jis",
+                c <= '\u{80}' || ('\u{FF61}'..='\u{FF9F}').contains(&c),
+            ),
+            EncodingLimit::EucJp => ("euc-jp", c.is_ascii()),
+        };
+        let encodable = CodePoints::for_encoding(label)?;
+        if !encodable.contains_char(c) {
+            None
+        } else if single_byte {
+            Some(1)
+        } else {
+            Some(2)
+        }
+    }
+}
+
+impl fmt::Display for EncodingLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+// ── violation ─────────────────────────────────────────────────────────────────
+
+/// The reason a [`Validator`] rejected a character.
+///
+/// `position` is the zero-based *character* index within the input string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Violation {
+    /// The character is not in any of the validator's allow-lists.
+    NotAllowed {
+        /// The rejected code point.
+        code_point: u32,
+        /// Zero-based character index of the rejected code point.
+        position: usize,
+    },
+    /// The character was rejected by a [`Restriction`].
+    Restricted {
+        /// The rejected code point.
+        code_point: u32,
+        /// Zero-based character index of the rejected code point.
+        position: usize,
+        /// Name of the restriction that rejected the character.
+        restriction: &'static str,
+    },
+    /// The character is in one of the validator's deny-lists.
+    Denied {
+        /// The rejected code point.
+        code_point: u32,
+        /// Zero-based character index of the rejected code point.
+        position: usize,
+        /// The character's class: `"bidi control"`, `"zero-width"`,
+        /// `"interlinear annotation"`, or `"denied"` for anything else.
+        class: &'static str,
+    },
+    /// A sequence in the validator's [`SequenceDenyList`] starts at this
+    /// character.
+    DeniedSequence {
+        /// The first code point of the matched sequence.
+        code_point: u32,
+        /// Zero-based character index of the start of the sequence.
+        position: usize,
+        /// Byte offset of the start of the sequence.
+        byte_offset: usize,
+        /// Index of the matched pattern in the list given to
+        /// [`SequenceDenyList::new`].
+        pattern: usize,
+    },
+    /// The character cannot be encoded in the encoding of the validator's
+    /// byte limit.
+    NotEncodable {
+        /// The rejected code point.
+        code_point: u32,
+        /// Zero-based character index of the rejected code point.
+        position: usize,
+        /// The encoding.
+        encoding: EncodingLimit,
+    },
+    /// The character takes the encoded length past the validator's byte
+    /// limit.
+    TooLong {
+        /// The first code point that does not fit.
+        code_point: u32,
+        /// Zero-based character index of that code point.
+        position: usize,
+        /// Encoded length of the text up to and including it.
+        bytes: usize,
+        /// The byte limit.
+        limit: usize,
+        /// The encoding the length is measured in.
+        encoding: EncodingLimit,
+    },
+}
+
+impl Violation {
+    /// Returns the rejected code point.
+    pub fn code_point(&self) -> u32 {
+        match *self {
+            Violation::NotAllowed { code_point, .. } => code_point,
+            Violation::Restricted { code_point, .. } => code_point,
+            Violation::Denied { code_point, .. } => code_point,
+            Violation::DeniedSequence { code_point, .. } => code_point,
+            Violation::NotEncodable { code_point, .. } => code_point,
+            Violation::TooLong { code_point, .. } => code_point,
+        }
+    }
+
+    /// Returns the zero-based character index of the rejected code point,
+    /// or of the start of a denied sequence.
+    pub fn position(&self) -> usize {
+        match *self {
+            Violation::NotAllowed { position, .. } => position,
+            Violation::Restricted { position, .. } => position,
+            Violation::Denied { position, .. } => position,
+            Violation::DeniedSequence { position, .. } => position,
+            Violation::NotEncodable { position, .. } => position,
+            Violation::TooLong { position, .. } => position,
+        }
+    }
+}
+
+impl From<Violation> for ValidationError {
+    fn from(violation: Violation) -> Self {
+        match violation {
+            Violation::NotAllowed {
+                code_point,
+                position,
+            } => ValidationError::new(code_point, position),
+            Violation::Restricted {
+                code_point,
+                position,
+                restriction,
+            } => {
+                let ch = char::from_u32(code_point).unwrap_or('\u{FFFD}');
+                ValidationError::with_message(
+                    code_point,
+                    position,
+                    format!(
+                        "character '{}' (U+{:04X}) at position {} is forbidden by {}",
+                        ch, code_point, position, restriction
+                    ),
+                )
+            }
+            // The character itself is left out of the message: a bidi
+            // override would garble the log line it ends up in.
+            Violation::Denied {
+                code_point,
+                position,
+                class,
+            } => ValidationError {
+                kind: ValidationErrorKind::DeniedCharacter,
+                ..ValidationError::with_message(
+                    code_point,
+                    position,
+                    format!(
+                        "U+{:04X} ({}) at position {} is denied",
+                        code_point, class, position
+                    ),
+                )
+            },
+            Violation::DeniedSequence {
+                code_point,
+                position,
+                byte_offset,
+                pattern,
+            } => ValidationError {
+                kind: ValidationErrorKind::DeniedCharacter,
+                ..ValidationError::with_message(
+                    code_point,
+                    position,
+                    format!(
+                        "sequence at position {} (byte {}) matches denied pattern {}",
+                        position, byte_offset, pattern
+                    ),
+                )
+                .with_byte_index(byte_offset)
+            },
+            Violation::NotEncodable {
+                code_point,
+                position,
+                encoding,
+            } => {
+                let ch = char::from_u32(code_point).unwrap_or('\u{FFFD}');
+                ValidationError::with_message(
+                    code_point,
+                    position,
+                    format!(
+                        "character '{}' (U+{:04X}) at position {} cannot be encoded in {}",
+                        ch, code_point, position, encoding
+                    ),
+                )
+            }
+            Violation::TooLong {
+                code_point,
+                position,
+                bytes,
+                limit,
+                encoding,
+            } => {
+                let ch = char::from_u32(code_point).unwrap_or('\u{FFFD}');
+                ValidationError {
+                    kind: ValidationErrorKind::TooLong,
+                    ..ValidationError::with_message(
+                        code_point,
+                        position,
+                        format!(
+                            "text exceeds {} bytes in {}: character '{}' (U+{:04X}) at position {} ends at byte {}",
+                            limit, encoding, ch, code_point, position, bytes
+                        ),
+                    )
+                }
+            }
+        }
+    }
+}
+
+// ── validator ─────────────────────────────────────────────────────────────────
+
+/// Validates text against allow-lists and [`Restriction`]s.
+///
+/// A character is valid when **no** restriction forbids it and it belongs to
+/// **at least one** allow-list.  A validator without allow-lists accepts any
+/// character that passes the restrictions.
+///
+/// Restrictions are checked before the allow-lists, so a character that fails
+/// both is reported as [`Violation::Restricted`].  Deny-lists are checked
+/// last and win over the allow-lists: a denied character is invalid even if
+/// an allow-list contains it.  A denied sequence is reported at its first
+/// character unless an earlier character is already invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::CodePoints;
+/// use japanese_codepoints::validator::{Restriction, Validator};
+///
+/// let validator = Validator::new().restrict(Restriction::no_non_bmp());
+/// assert!(validator.validate("あいう").is_ok());
+/// assert!(validator.validate("𠮟る").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Validator<'a> {
+    allow: Vec<&'a CodePoints>,
+    restrictions: Vec<Restriction>,
+    deny: Vec<&'a CodePoints>,
+    sequences: Option<&'a SequenceDenyList>,
+    byte_limit: Option<(EncodingLimit, usize)>,
+    require_non_empty: bool,
+}
+
+impl<'a> Validator<'a> {
+    /// Creates a validator with no allow-lists and no restrictions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an allow-list.  Multiple allow-lists are combined as a union.
+    pub fn allow(mut self, codepoints: &'a CodePoints) -> Self {
+        self.allow.push(codepoints);
+        self
+    }
+
+    /// Adds a restriction.  Every restriction must pass.
+    pub fn restrict(mut self, restriction: Restriction) -> Self {
+        self.restrictions.push(restriction);
+        self
+    }
+
+    /// Adds a deny-list, checked after the allow-lists.  A character in any
+    /// deny-list is reported as [`Violation::Denied`], and by
+    /// [`validate`](Self::validate) as
+    /// [`ValidationErrorKind::DeniedCharacter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::CodePoints;
+    /// use japanese_codepoints::validator::{Validator, Violation};
+    ///
+    /// let allowed = CodePoints::from_string("あん\u{202E}");
+    /// let invisibles = CodePoints::from_string("\u{202E}\u{200B}");
+    /// let validator = Validator::new().allow(&allowed).deny_set(&invisibles);
+    ///
+    /// assert!(validator.is_valid("あん"));
+    /// assert_eq!(
+    ///     validator.first_violation("あ\u{202E}ん"),
+    ///     Some(Violation::Denied { code_point: 0x202E, position: 1, class: "bidi control" })
+    /// );
+    /// assert_eq!(
+    ///     validator.validate("あ\u{202E}ん").unwrap_err().to_string(),
+    ///     "U+202E (bidi control) at position 1 is denied"
+    /// );
+    /// ```
+    pub fn deny_set(mut self, codepoints: &'a CodePoints) -> Self {
+        self.deny.push(codepoints);
+        self
+    }
+
+    /// Rejects text containing any sequence of `sequences`, reported as
+    /// [`Violation::DeniedSequence`] and by [`validate`](Self::validate) as
+    /// [`ValidationErrorKind::DeniedCharacter`].  Replaces any list set
+    /// before.
+    ///
+    /// The text is searched once, in linear time, after the per-character
+    /// rules; a character violation before the start of the sequence is
+    /// reported first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validator::{SequenceDenyList, Validator, Violation};
+    ///
+    /// let sequences = SequenceDenyList::new(&["ゟ\u{3099}", "ゝ\u{309A}"]);
+    /// let validator = Validator::new().deny_sequences(&sequences);
+    ///
+    /// assert!(validator.is_valid("ゟゝ"));
+    /// assert_eq!(
+    ///     validator.first_violation("あゝ\u{309A}"),
+    ///     Some(Violation::DeniedSequence {
+    ///         code_point: 0x309D,
+    ///         position: 1,
+    ///         byte_offset: 3,
+    ///         pattern: 1,
+    ///     })
+    /// );
+    /// ```
+    pub fn deny_sequences(mut self, sequences: &'a SequenceDenyList) -> Self {
+        self.sequences = Some(sequences);
+        self
+    }
+
+    /// Limits the text to `n` bytes in `encoding`, as fixed-width legacy
+    /// interfaces do ("20 bytes in CP932" is 10 fullwidth or 20 halfwidth
+    /// characters).
+    ///
+    /// Characters the encoding cannot represent are reported as
+    /// [`Violation::NotEncodable`]; the first character that does not fit is
+    /// reported as [`Violation::TooLong`], and by
+    /// [`validate`](Self::validate) as
+    /// [`ValidationErrorKind::TooLong`].  The length is checked after the
+    /// other rules, character by character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validator::{EncodingLimit, Validator, Violation};
+    ///
+    /// let validator = Validator::new().max_encoded_bytes(EncodingLimit::ShiftJis, 6);
+    /// assert!(validator.is_valid("ｶﾀｶﾅ12"));
+    /// assert!(validator.is_valid("カタカ"));
+    /// assert!(matches!(
+    ///     validator.first_violation("カタカナ"),
+    ///     Some(Violation::TooLong { position: 3, bytes: 8, .. })
+    /// ));
+    /// assert!(matches!(
+    ///     validator.first_violation("𠮷"),
+    ///     Some(Violation::NotEncodable { position: 0, .. })
+    /// ));
+    /// ```
+    pub fn max_encoded_bytes(mut self, encoding: EncodingLimit, n: usize) -> Self {
+        self.byte_limit = Some((encoding, n));
+        self
+    }
+
+    /// Rejects empty input when `required` is `true`; off by default.
+    ///
+    /// Empty input is then invalid for [`is_valid`](Self::is_valid) and
+    /// reported by [`validate`](Self::validate) as
+    /// [`ValidationErrorKind::EmptyInput`].
+    /// [`first_violation`](Self::first_violation) reports characters only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use japanese_codepoints::validation::ValidationErrorKind;
+    /// use japanese_codepoints::validator::Validator;
+    ///
+    /// let validator = Validator::new().require_non_empty(true);
+    /// assert!(!validator.is_valid(""));
+    /// assert_eq!(validator.validate("").unwrap_err().kind, ValidationErrorKind::EmptyInput);
+    /// assert!(Validator::new().is_valid(""));
+    /// ```
+    pub fn require_non_empty(mut self, required: bool) -> Self {
+        self.require_non_empty = required;
+        self
+    }
+
+    /// Returns the first character of `text` that violates this validator,
+    /// or `None` if the whole string is valid.
+    pub fn first_violation(&self, text: &str) -> Option<Violation> {
+        let mut bytes = 0;
+        let by_char = text.chars().enumerate().find_map(|(position, c)| {
+            self.check(c, position)
+                .or_else(|| self.check_length(c, position, &mut bytes))
+        });
+        let Some(sequences) = self.sequences else {
+            return by_char;
+        };
+        let by_sequence = sequences.find(text).map(|m| Violation::DeniedSequence {
+            code_point: m.code_point,
+            position: m.position,
+            byte_offset: m.byte_offset,
+            pattern: m.pattern_index,
+        });
+        match (by_char, by_sequence) {
+            (Some(c), Some(s)) if s.position() < c.position() => Some(s),
+            (None, s) => s,
+            (c, _) => c,
+        }
+    }
+
+    /// Returns `true` if every character in `text` satisfies this validator.
+    pub fn is_valid(&self, text: &str) -> bool {
+        !(self.require_non_empty && text.is_empty()) && self.first_violation(text).is_none()
+    }
+
+    /// Validates `text`, converting the first [`Violation`] into a
+    /// [`ValidationError`].
+    pub fn validate(&self, text: &str) -> Result<(), ValidationError> {
+        if self.require_non_empty && text.is_empty() {
+            let err = ValidationError::empty_input();
+            crate::validation::report_failure(None, &err, text.len());
+            return Err(err);
+        }
+        match self.first_violation(text) {
+            None => Ok(()),
+            Some(violation) => {
+                let byte_index = text
+                    .char_indices()
+                    .nth(violation.position())
+                    .map_or(text.len(), |(byte, _)| byte);
+                let err = ValidationError::from(violation).with_byte_index(byte_index);
+                crate::validation::report_failure(None, &err, text.len());
+                Err(err)
+            }
+        }
+    }
+
+    fn check(&self, c: char, position: usize) -> Option<Violation> {
+        let code_point = c as u32;
+        if let Some(r) = self.restrictions.iter().find(|r| r.forbids(c)) {
+            return Some(Violation::Restricted {
+                code_point,
+                position,
+                restriction: r.name,
+            });
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|set| set.contains_char(c)) {
+            return Some(Violation::NotAllowed {
+                code_point,
+                position,
+            });
+        }
+        if self.deny.iter().any(|set| set.contains_char(c)) {
+            return Some(Violation::Denied {
+                code_point,
+                position,
+                class: denied_class(code_point),
+            });
+        }
+        None
+    }
+
+    /// Adds the encoded length of `c` to `bytes` and checks the byte limit.
+    fn check_length(&self, c: char, position: usize, bytes: &mut usize) -> Option<Violation> {
+        let (encoding, limit) = self.byte_limit?;
+        let code_point = c as u32;
+        let Some(len) = encoding.encoded_len(c) else {
+            return Some(Violation::NotEncodable {
+                code_point,
+                position,
+                encoding,
+            });
+        };
+        *bytes += len;
+        (*bytes > limit).then_some(Violation::TooLong {
+            code_point,
+            position,
+            bytes: *bytes,
+            limit,
+            encoding,
+        })
+    }
+}
+
+/// Names the class of a denied character for [`Violation::Denied`].
+fn denied_class(code_point: u32) -> &'static str {
+    if BIDI_CONTROL_CHARS.contains(&code_point) {
+        "bidi control"
+    } else if ZERO_WIDTH_CHARS.contains(&code_point) {
+        "zero-width"
+    } else if INTERLINEAR_ANNOTATION_CHARS.contains(&code_point) {
+        "interlinear annotation"
+    } else {
+        "denied"
+    }
+}
+
+// ── sequence deny-list ────────────────────────────────────────────────────────
+
+/// A set of denied character sequences, searched in time linear in the
+/// length of the text however many patterns there are.
+///
+/// The patterns are compiled into an Aho–Corasick automaton over their
+/// UTF-8 bytes.  Because UTF-8 is self-synchronizing, every match starts on
+/// a character boundary, astral characters included.  Empty patterns are
+/// ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::validator::SequenceDenyList;
+///
+/// let list = SequenceDenyList::new(&["ab", "b𠮷"]);
+/// assert_eq!(list.first_match("xb𠮷ab"), Some((1, "b𠮷")));
+/// assert_eq!(list.first_match("𠮷b"), None);
+///
+/// let m = list.find("𠮷ab").unwrap();
+/// assert_eq!((m.position, m.byte_offset, m.pattern), (1, 4, "ab"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceDenyList {
+    patterns: Vec<Box<str>>,
+    /// Trie nodes; node 0 is the root.
+    nodes: Vec<SequenceNode>,
+    /// Byte length of the longest pattern.
+    max_len: usize,
+}
+
+/// A match found by [`SequenceDenyList::find`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceMatch<'a> {
+    /// The matched pattern.
+    pub pattern: &'a str,
+    /// Index of the matched pattern in the list given to
+    /// [`SequenceDenyList::new`].
+    pub pattern_index: usize,
+    /// The first code point of the match.
+    pub code_point: u32,
+    /// Zero-based character index of the start of the match.
+    pub position: usize,
+    /// Byte offset of the start of the match.
+    pub byte_offset: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct SequenceNode {
+    /// Trie edges, sorted by byte.
+    edges: Vec<(u8, usize)>,
+    /// Node of the longest proper suffix that is also a trie node.
+    fail: usize,
+    /// Pattern spelled by the path to this node.
+    pattern: Option<usize>,
+    /// Nearest node on the failure chain that ends a pattern.
+    output: Option<usize>,
+    /// Length in bytes of the path to this node.
+    depth: usize,
+}
+
+impl SequenceNode {
+    fn edge(&self, byte: u8) -> Option<usize> {
+        self.edges
+            .binary_search_by_key(&byte, |&(b, _)| b)
+            .ok()
+            .and_then(|i| self.edges.get(i))
+            .map(|&(_, next)| next)
+    }
+}
+
+impl SequenceDenyList {
+    /// Compiles `patterns`.  If a pattern occurs twice, matches report the
+    /// first.
+    pub fn new(patterns: &[&str]) -> Self {
+        let mut nodes = vec![SequenceNode::default()];
+        for (index, pattern) in patterns.iter().enumerate() {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut node = 0;
+            for &byte in pattern.as_bytes() {
+                node = match nodes.get(node).and_then(|n| n.edge(byte)) {
+                    Some(next) => next,
+                    None => {
+                        let next = nodes.len();
+                        nodes.push(SequenceNode {
+                            depth: nodes.get(node).map_or(0, |n| n.depth + 1),
+                            ..SequenceNode::default()
+                        });
+                        if let Some(n) = nodes.get_mut(node) {
+                            let at = n.edges.partition_point(|&(b, _)| b < byte);
+                            n.edges.insert(at, (byte, next));
+                        }
+                        next
+                    }
+                };
+            }
+            if let Some(n) = nodes.get_mut(node) {
+                n.pattern.get_or_insert(index);
+            }
+        }
+
+        // Failure and output links, breadth first so that a node's links are
+        // final before its children need them.
+        let mut queue = std::collections::VecDeque::from([0]);
+        while let Some(parent) = queue.pop_front() {
+            let (edges, parent_fail) = match nodes.get(parent) {
+                Some(n) => (n.edges.clone(), n.fail),
+                None => continue,
+            };
+            for (byte, child) in edges {
+                let fail = if parent == 0 {
+                    0
+                } else {
+                    let mut f = parent_fail;
+                    loop {
+                        if let Some(next) = nodes.get(f).and_then(|n| n.edge(byte)) {
+                            break next;
+                        }
+                        if f == 0 {
+                            break 0;
+                        }
+                        f = nodes.get(f).map_or(0, |n| n.fail);
+                    }
+                };
+                let output = nodes
+                    .get(fail)
+                    .and_then(|n| n.pattern.map(|_| fail).or(n.output));
+                if let Some(n) = nodes.get_mut(child) {
+                    n.fail = fail;
+                    n.output = output;
+                }
+                queue.push_back(child);
+            }
+        }
+
+        Self {
+            patterns: patterns.iter().map(|&p| p.into()).collect(),
+            max_len: patterns.iter().map(|p| p.len()).max().unwrap_or(0),
+            nodes,
+        }
+    }
+
+    /// Returns the patterns, including empty ones, in the order given.
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        self.patterns.iter().map(|p| &**p)
+    }
+
+    /// Returns the byte offset and pattern of the leftmost match in `s`, or
+    /// `None` if no pattern occurs.  Of several patterns starting at the
+    /// same place, the longest is reported.
+    pub fn first_match(&self, s: &str) -> Option<(usize, &str)> {
+        self.find(s).map(|m| (m.byte_offset, m.pattern))
+    }
+
+    /// Like [`first_match`](Self::first_match), but also reports the
+    /// character index of the match and which pattern it was.
+    pub fn find(&self, s: &str) -> Option<SequenceMatch<'_>> {
+        let (byte_offset, pattern_index) = self.leftmost_longest(s.as_bytes())?;
+        let pattern = self.patterns.get(pattern_index)?;
+        let rest = s.get(byte_offset..)?;
+        Some(SequenceMatch {
+            pattern,
+            pattern_index,
+            code_point: rest.chars().next().map_or(0, |c| c as u32),
+            position: s.get(..byte_offset)?.chars().count(),
+            byte_offset,
+        })
+    }
+
+    /// Returns `true` if any pattern occurs in `s`.
+    pub fn is_match(&self, s: &str) -> bool {
+        self.leftmost_longest(s.as_bytes()).is_some()
+    }
+
+    /// Scans `bytes` once and returns the start and pattern of the leftmost,
+    /// then longest, match.
+    fn leftmost_longest(&self, bytes: &[u8]) -> Option<(usize, usize)> {
+        let mut node = 0;
+        // (start, length, pattern) of the best match so far.
+        let mut best: Option<(usize, usize, usize)> = None;
+        for (i, &byte) in bytes.iter().enumerate() {
+            // Nothing ending here or later can start at or before `best`.
+            if best.is_some_and(|(start, _, _)| i >= start + self.max_len) {
+                break;
+            }
+            node = self.step(node, byte);
+            let Some(n) = self.nodes.get(node) else {
+                break;
+            };
+            // The longest match ending here starts leftmost among them.
+            let found = match n.pattern {
+                Some(pattern) => Some((n.depth, pattern)),
+                None => n
+                    .output
+                    .and_then(|o| self.nodes.get(o))
+                    .and_then(|o| o.pattern.map(|pattern| (o.depth, pattern))),
+            };
+            if let Some((len, pattern)) = found {
+                let start = i + 1 - len;
+                let better = best.map_or(true, |(best_start, best_len, _)| {
+                    start < best_start || (start == best_start && len > best_len)
+                });
+                if better {
+                    best = Some((start, len, pattern));
+                }
+            }
+        }
+        best.map(|(start, _, pattern)| (start, pattern))
+    }
+
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            let Some(n) = self.nodes.get(node) else {
+                return 0;
+            };
+            if let Some(next) = n.edge(byte) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = n.fail;
+        }
+    }
+}
+
+// ── memoized validator ────────────────────────────────────────────────────────
+
+const SHARD_COUNT: usize = 16;
+const NIL: usize = usize::MAX;
+
+/// Memoizing wrapper around [`CodePoints::contains`] for workloads that test
+/// the same strings over and over (prefecture names, fixed vocabularies).
+///
+/// Results are kept in a least-recently-used cache of at most `capacity`
+/// strings, split exactly across up to 16 independently locked shards (one
+/// per entry for capacities below 16) so that the validator
+/// is `Send + Sync` and can be shared between threads without a global lock.
+///
+/// # When not to use this
+///
+/// Caching only pays off when a small set of strings accounts for most
+/// lookups and the strings are longer than a handful of characters.  With
+/// unbounded keys (free-form user input, IDs, timestamps) nearly every lookup
+/// is a miss: each one then pays for hashing, locking and copying the string
+/// on top of the uncached check.  Use [`hits`](Self::hits) and
+/// [`misses`](Self::misses) to confirm the hit rate in production.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::validator::CachedValidator;
+/// use japanese_codepoints::CodePoints;
+///
+/// let validator = CachedValidator::new(CodePoints::ascii_printable_cached(), 1024);
+/// assert!(validator.contains("Tokyo"));
+/// assert!(validator.contains("Tokyo"));
+/// assert!(!validator.contains("東京"));
+/// assert_eq!((validator.hits(), validator.misses()), (1, 2));
+/// ```
+#[derive(Debug)]
+pub struct CachedValidator {
+    codepoints: &'static CodePoints,
+    hasher: RandomState,
+    shards: Vec<Mutex<LruShard>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CachedValidator {
+    /// Creates a validator for `codepoints` that remembers the results for
+    /// up to `capacity` distinct strings.  A capacity of zero disables
+    /// caching.
+    pub fn new(codepoints: &'static CodePoints, capacity: usize) -> Self {
+        // The shard capacities add up to exactly `capacity`.
+        let shard_count = capacity.clamp(1, SHARD_COUNT);
+        let (per_shard, extra) = (capacity / shard_count, capacity % shard_count);
+        Self {
+            codepoints,
+            hasher: RandomState::new(),
+            shards: (0..shard_count)
+                .map(|i| Mutex::new(LruShard::new(per_shard + usize::from(i < extra))))
+                .collect(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns `true` if every character of `s` belongs to the set, using a
+    /// cached result when available.
+    pub fn contains(&self, s: impl AsRef<str>) -> bool {
+        let s = s.as_ref();
+        // `BuildHasher::hash_one` needs Rust 1.71.
+        let mut hasher = self.hasher.build_hasher();
+        s.hash(&mut hasher);
+        let hash = hasher.finish();
+        let Some(shard) = self.shards.get(hash as usize % self.shards.len()) else {
+            return self.codepoints.contains(s);
+        };
+
+        if let Some(result) = lock(shard).get(hash, s) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return result;
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        // Computed outside the lock so other threads are not blocked on it.
+        let result = self.codepoints.contains(s);
+        lock(shard).insert(hash, s, result);
+        result
+    }
+
+    /// Returns the number of lookups answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of lookups that had to scan the string.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of strings currently cached.
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|s| lock(s).nodes.len()).sum()
+    }
+
+    /// Returns `true` if no strings are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Empties the cache and resets the hit/miss counters.
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            lock(shard).clear();
+        }
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Locks a shard, ignoring poisoning: a shard is always left consistent.
+fn lock(shard: &Mutex<LruShard>) -> MutexGuard<'_, LruShard> {
+    shard.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// One LRU shard: a hash index into a doubly-linked list stored in a `Vec`.
+#[derive(Debug)]
+struct LruShard {
+    capacity: usize,
+    index: HashMap<u64, usize>,
+    nodes: Vec<LruNode>,
+    /// Most recently used node.
+    head: usize,
+    /// Least recently used node.
+    tail: usize,
+}
+
+#[derive(Debug)]
+struct LruNode {
+    hash: u64,
+    key: Box<str>,
+    result: bool,
+    prev: usize,
+    next: usize,
+}
+
+impl LruShard {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            index: HashMap::new(),
+            nodes: Vec::new(),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    fn get(&mut self, hash: u64, key: &str) -> Option<bool> {
+        let i = *self.index.get(&hash)?;
+        let node = self.nodes.get(i)?;
+        // The hash is only an index; compare keys to rule out collisions.
+        if &*node.key != key {
+            return None;
+        }
+        let result = node.result;
+        self.move_to_front(i);
+        Some(result)
+    }
+
+    fn insert(&mut self, hash: u64, key: &str, result: bool) {
+        if self.capacity == 0 {
+            return;
+        }
+        let i = if let Some(&i) = self.index.get(&hash) {
+            // Same string inserted by a racing thread, or a hash collision.
+            i
+        } else if self.nodes.len() < self.capacity {
+            self.nodes.push(LruNode {
+                hash,
+                key: Box::from(""),
+                result,
+                prev: NIL,
+                next: NIL,
+            });
+            let i = self.nodes.len() - 1;
+            self.push_front(i);
+            self.index.insert(hash, i);
+            i
+        } else {
+            let i = self.tail;
+            if let Some(evicted) = self.nodes.get(i) {
+                self.index.remove(&evicted.hash);
+            }
+            self.index.insert(hash, i);
+            i
+        };
+        let Some(node) = self.nodes.get_mut(i) else {
+            return;
+        };
+        node.hash = hash;
+        node.key = Box::from(key);
+        node.result = result;
+        self.move_to_front(i);
+    }
+
+    fn clear(&mut self) {
+        self.index.clear();
+        self.nodes.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    fn move_to_front(&mut self, i: usize) {
+        if self.head == i {
+            return;
+        }
+        let Some(&LruNode { prev, next, .. }) = self.nodes.get(i) else {
+            return;
+        };
+        // `NIL` is never a valid index, so `get_mut` skips it.
+        if let Some(node) = self.nodes.get_mut(prev) {
+            node.next = next;
+        }
+        if let Some(node) = self.nodes.get_mut(next) {
+            node.prev = prev;
+        }
+        if self.tail == i {
+            self.tail = prev;
+        }
+        self.push_front(i);
+    }
+
+    fn push_front(&mut self, i: usize) {
+        let Some(node) = self.nodes.get_mut(i) else {
+            return;
+        };
+        node.prev = NIL;
+        node.next = self.head;
+        if let Some(head) = self.nodes.get_mut(self.head) {
+            head.prev = i;
+        }
+        self.head = i;
+        if self.tail == NIL {
+            self.tail = i;
+        }
+    }
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_non_bmp() {
+        let r = Restriction::no_non_bmp();
+        assert_eq!(r.name(), "no_non_bmp");
+        assert!(r.forbids('\u{10000}'));
+        assert!(r.forbids('😀'));
+        assert!(!r.forbids('\u{FFFF}'));
+        assert!(!r.forbids('あ'));
+    }
+
+    #[test]
+    fn test_require_non_empty() {
+        use crate::validation::ValidationErrorKind;
+
+        let allowed = CodePoints::from_string("あ ");
+        let validator = Validator::new().allow(&allowed).require_non_empty(true);
+        assert!(!validator.is_valid(""));
+        assert_eq!(
+            validator.validate("").unwrap_err(),
+            ValidationError::empty_input()
+        );
+        assert_eq!(validator.first_violation(""), None);
+
+        // Whitespace-only input is not empty.
+        assert!(validator.is_valid(" "));
+        assert!(validator.is_valid("あ"));
+        assert_eq!(
+            validator.validate("い").unwrap_err().kind,
+            ValidationErrorKind::InvalidChar
+        );
+
+        let optional = validator.require_non_empty(false);
+        assert!(optional.is_valid(""));
+        assert!(optional.validate("").is_ok());
+    }
+
+    #[cfg(feature = "codepoints-jisx0213kanji")]
+    #[test]
+    fn test_no_non_bmp_overrides_allow_list() {
+        let kanji = crate::jisx0213kanji::JisX0213Kanji::cached();
+        assert!(kanji.contains("𠮟"));
+
+        let validator = Validator::new()
+            .allow(kanji.codepoints())
+            .restrict(Restriction::no_non_bmp());
+        assert!(validator.is_valid("叱"));
+        assert_eq!(
+            validator.first_violation("叱𠮟"),
+            Some(Violation::Restricted {
+                code_point: 0x20B9F,
+                position: 1,
+                restriction: "no_non_bmp",
+            })
+        );
+    }
+
+    #[cfg(feature = "emoji")]
+    #[test]
+    fn test_no_emoji() {
+        let r = Restriction::no_emoji();
+        assert!(r.forbids('☺'));
+        assert!(r.forbids('©'));
+        assert!(r.forbids('🀄'));
+        assert!(r.forbids('\u{1FFFD}'));
+        assert!(!r.forbids('、'));
+        assert!(!r.forbids('☆'));
+        assert!(!r.forbids('あ'));
+
+        let validator = Validator::new().restrict(r);
+        assert!(validator.is_valid("、。"));
+        assert!(!validator.is_valid("こんにちは☺"));
+    }
+
+    #[test]
+    fn test_not_allowed() {
+        let allowed = CodePoints::new(vec![0x3042]); // あ
+        let validator = Validator::new().allow(&allowed);
+        assert_eq!(
+            validator.first_violation("ああい"),
+            Some(Violation::NotAllowed {
+                code_point: 0x3044,
+                position: 2,
+            })
+        );
+        assert!(validator.is_valid(""));
+    }
+
+    #[test]
+    fn test_multiple_allow_lists() {
+        let hira = CodePoints::new(vec![0x3042]); // あ
+        let kata = CodePoints::new(vec![0x30A2]); // ア
+        let validator = Validator::new().allow(&hira).allow(&kata);
+        assert!(validator.is_valid("あア"));
+        assert!(!validator.is_valid("あアx"));
+    }
+
+    #[test]
+    fn test_no_allow_list_accepts_anything_unrestricted() {
+        let validator = Validator::new();
+        assert!(validator.is_valid("anything 𠮟 goes"));
+    }
+
+    #[test]
+    fn test_validate_error_message() {
+        let validator = Validator::new().restrict(Restriction::no_non_bmp());
+        let err = validator.validate("ab𠮟").unwrap_err();
+        assert_eq!(err.code_point, 0x20B9F);
+        assert_eq!(err.position, 2);
+        assert!(err.to_string().contains("no_non_bmp"));
+
+        let allowed = CodePoints::ascii_printable();
+        let err = Validator::new()
+            .allow(&allowed)
+            .validate("aあ")
+            .unwrap_err();
+        assert_eq!(err, ValidationError::new(0x3042, 1).with_byte_index(1));
+    }
+
+    #[test]
+    fn test_max_encoded_bytes() {
+        let validator = Validator::new().max_encoded_bytes(EncodingLimit::ShiftJis, 20);
+        // 10 fullwidth or 20 halfwidth characters fit exactly.
+        assert!(validator.is_valid("１２３４５６７８９０"));
+        assert!(validator.is_valid("ｱｲｳｴｵｶｷｸｹｺ1234567890"));
+        assert!(validator.is_valid(""));
+        // 19 bytes, then a fullwidth character straddling the limit.
+        let err = validator.validate("ﾔﾏﾀﾞ 太郎 abcdefghi円").unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::TooLong);
+        assert_eq!((err.code_point, err.position), ('円' as u32, 17));
+        assert_eq!(
+            err.to_string(),
+            "text exceeds 20 bytes in Shift_JIS: character '円' (U+5186) at position 17 ends at byte 21"
+        );
+        // The same text fits when the last character is halfwidth.
+        assert!(validator.is_valid("ﾔﾏﾀﾞ 太郎 abcdefghiｴ"));
+
+        // Membership failures are reported before the limit is reached.
+        let err = validator.validate("髙橋〜").unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::InvalidChar);
+        assert_eq!((err.code_point, err.position), (0x301C, 2));
+        assert!(err.to_string().contains("cannot be encoded in Shift_JIS"));
+
+        // Halfwidth katakana take two bytes in EUC-JP.
+        let euc = Validator::new().max_encoded_bytes(EncodingLimit::EucJp, 4);
+        assert!(euc.is_valid("ab"));
+        assert!(euc.is_valid("ｱｲ"));
+        assert_eq!(
+            euc.first_violation("ｱｲｳ"),
+            Some(Violation::TooLong {
+                code_point: 0xFF73,
+                position: 2,
+                bytes: 6,
+                limit: 4,
+                encoding: EncodingLimit::EucJp,
+            })
+        );
+    }
+
+    #[test]
+    fn test_byte_limit_after_allow_list() {
+        let allowed = CodePoints::ascii_printable();
+        let validator = Validator::new()
+            .allow(&allowed)
+            .max_encoded_bytes(EncodingLimit::ShiftJis, 3);
+        assert!(matches!(
+            validator.first_violation("abあ"),
+            Some(Violation::NotAllowed { position: 2, .. })
+        ));
+        assert!(matches!(
+            validator.first_violation("abcd"),
+            Some(Violation::TooLong {
+                position: 3,
+                bytes: 4,
+                limit: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_custom_restriction() {
+        let no_space = Restriction::new("no_space", char::is_whitespace);
+        let v = Validator::new().restrict(no_space);
+        let violation = v.first_violation("a b").unwrap();
+        assert_eq!(violation.code_point(), 0x20);
+        assert_eq!(violation.position(), 1);
+    }
+
+    // ── sequence deny-list ────────────────────────────────────────────────
+
+    #[test]
+    fn test_sequence_deny_list_overlapping_patterns() {
+        let list = SequenceDenyList::new(&["he", "she", "hers", "his"]);
+        // "she" and "he" overlap; "she" starts first.
+        assert_eq!(list.first_match("ushers"), Some((1, "she")));
+        // "hers" and "he" start together; the longer one wins.
+        assert_eq!(list.first_match("xhers"), Some((1, "hers")));
+        assert_eq!(list.first_match("ahis"), Some((1, "his")));
+        assert_eq!(list.first_match("hxs"), None);
+
+        // A match found later can start earlier: "bc" ends before "abcd".
+        let list = SequenceDenyList::new(&["bc", "abcd"]);
+        assert_eq!(list.first_match("xabcd"), Some((1, "abcd")));
+        assert_eq!(list.first_match("xabce"), Some((2, "bc")));
+
+        // A pattern that is a proper suffix of another.
+        let list = SequenceDenyList::new(&["aab", "ab"]);
+        assert_eq!(list.first_match("aaab"), Some((1, "aab")));
+    }
+
+    #[test]
+    fn test_sequence_deny_list_astral() {
+        let list = SequenceDenyList::new(&["𠮷野", "野家", "𩸽\u{E0100}"]);
+        let m = list.find("吉𠮷野家").unwrap();
+        assert_eq!(
+            (
+                m.pattern,
+                m.pattern_index,
+                m.code_point,
+                m.position,
+                m.byte_offset
+            ),
+            ("𠮷野", 0, 0x20BB7, 1, 3)
+        );
+        let m = list.find("𠮷𩸽\u{E0100}").unwrap();
+        assert_eq!((m.pattern_index, m.position, m.byte_offset), (2, 1, 4));
+        // Shared UTF-8 lead bytes must not produce matches mid-character.
+        assert!(!list.is_match("𠮷𩸽野"));
+        assert!(!list.is_match("𠮷\u{E0100}"));
+    }
+
+    #[test]
+    fn test_sequence_deny_list_edge_cases() {
+        let list = SequenceDenyList::new(&["", "ab", "ab"]);
+        assert_eq!(list.patterns().count(), 3);
+        assert_eq!(list.find("ab").unwrap().pattern_index, 1);
+        assert_eq!(list.first_match(""), None);
+        assert!(!SequenceDenyList::new(&[]).is_match("anything"));
+        assert!(!SequenceDenyList::new(&[""]).is_match("anything"));
+
+        // Linear scan of a long document with one match at the end.
+        let text = format!("{}ゟ\u{3099}", "あ".repeat(100_000));
+        let list = SequenceDenyList::new(&["ゟ\u{3099}", "あい"]);
+        assert_eq!(list.find(&text).unwrap().position, 100_000);
+    }
+
+    #[test]
+    fn test_deny_sequences() {
+        let sequences = SequenceDenyList::new(&["ゟ\u{3099}", "𠮷野"]);
+        let allowed = CodePoints::from_string("あゟ\u{3099}𠮷野");
+        let validator = Validator::new().allow(&allowed).deny_sequences(&sequences);
+
+        assert!(validator.is_valid("あゟ𠮷"));
+        assert_eq!(
+            validator.first_violation("あ𠮷野"),
+            Some(Violation::DeniedSequence {
+                code_point: 0x20BB7,
+                position: 1,
+                byte_offset: 3,
+                pattern: 1,
+            })
+        );
+        let err = validator.validate("あ𠮷野").unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::DeniedCharacter);
+        assert_eq!(err.position, 1);
+        assert_eq!(
+            err.to_string(),
+            "sequence at position 1 (byte 3) matches denied pattern 1"
+        );
+
+        // The earlier violation wins.
+        assert!(matches!(
+            validator.first_violation("うゟ\u{3099}"),
+            Some(Violation::NotAllowed { position: 0, .. })
+        ));
+        assert!(matches!(
+            validator.first_violation("ゟ\u{3099}う"),
+            Some(Violation::DeniedSequence { position: 0, .. })
+        ));
+    }
+
+    // ── cached validator ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_cached_validator_hits_and_misses() {
+        let v = CachedValidator::new(CodePoints::ascii_printable_cached(), 64);
+        assert!(v.is_empty());
+        assert!(v.contains("abc"));
+        assert!(!v.contains("あ"));
+        assert!(v.contains("abc"));
+        assert!(!v.contains("あ"));
+        assert_eq!(v.hits(), 2);
+        assert_eq!(v.misses(), 2);
+        assert_eq!(v.len(), 2);
+
+        v.clear();
+        assert!(v.is_empty());
+        assert_eq!((v.hits(), v.misses()), (0, 0));
+    }
+
+    #[test]
+    fn test_cached_validator_zero_capacity() {
+        let v = CachedValidator::new(CodePoints::ascii_printable_cached(), 0);
+        assert!(v.contains("abc"));
+        assert!(v.contains("abc"));
+        assert_eq!(v.hits(), 0);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn test_lru_shard_eviction_order() {
+        let mut shard = LruShard::new(2);
+        shard.insert(1, "a", true);
+        shard.insert(2, "b", true);
+        assert_eq!(shard.get(1, "a"), Some(true)); // "b" is now LRU
+        shard.insert(3, "c", false);
+        assert_eq!(shard.get(2, "b"), None);
+        assert_eq!(shard.get(1, "a"), Some(true));
+        assert_eq!(shard.get(3, "c"), Some(false));
+        assert_eq!(shard.nodes.len(), 2);
+
+        // A colliding hash with a different key is a miss, then replaces it.
+        assert_eq!(shard.get(3, "x"), None);
+        shard.insert(3, "x", true);
+        assert_eq!(shard.get(3, "x"), Some(true));
+        assert_eq!(shard.get(3, "c"), None);
+    }
+
+    #[test]
+    fn test_cached_validator_bounded() {
+        let v = CachedValidator::new(CodePoints::ascii_printable_cached(), 32);
+        for i in 0..1000 {
+            assert!(v.contains(i.to_string()));
+        }
+        assert!(v.len() <= 32);
+    }
+
+    #[test]
+    fn test_cached_validator_exact_capacity() {
+        for capacity in [1, 15, 16, 17, 100] {
+            let v = CachedValidator::new(CodePoints::ascii_printable_cached(), capacity);
+            let total: usize = v.shards.iter().map(|s| lock(s).capacity).sum();
+            assert_eq!(total, capacity);
+            assert_eq!(v.shards.len(), capacity.min(SHARD_COUNT));
+            for i in 0..1000 {
+                assert!(v.contains(i.to_string()));
+            }
+            assert!(v.len() <= capacity, "capacity {capacity}: {}", v.len());
+        }
+
+        // A capacity of 1 remembers exactly the last string.
+        let v = CachedValidator::new(CodePoints::ascii_printable_cached(), 1);
+        assert!(v.contains("a"));
+        assert!(v.contains("b"));
+        assert!(v.contains("b"));
+        assert!(v.contains("a"));
+        assert_eq!((v.hits(), v.misses(), v.len()), (1, 3, 1));
+    }
+
+    #[test]
+    fn test_cached_validator_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CachedValidator>();
+
+        let v = CachedValidator::new(CodePoints::ascii_printable_cached(), 16);
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..100 {
+                        assert!(v.contains("shared"));
+                    }
+                });
+            }
+        });
+        assert_eq!(v.hits() + v.misses(), 400);
+        assert!(v.misses() >= 1);
+    }
+}
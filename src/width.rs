@@ -0,0 +1,206 @@
+//! Display-width calculation for fixed-width output.
+//!
+//! [`display_width`] counts the terminal columns a string occupies: one for
+//! ASCII and halfwidth katakana, two for fullwidth characters and kanji,
+//! zero for control characters and combining marks.
+//!
+//! Characters of Unicode's *ambiguous* East Asian Width class — `±`, `×`,
+//! Greek and Cyrillic letters, box drawing, `○` and the like — are wide in
+//! JIS fonts and CJK terminals but narrow elsewhere.  [`AmbiguousPolicy`]
+//! chooses between the two; the free functions use
+//! [`AmbiguousPolicy::Wide`], the East Asian treatment.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use japanese_codepoints::width::{display_width, pad_to_width, Align, AmbiguousPolicy};
+//!
+//! assert_eq!(display_width("ﾃｽﾄ"), 3);
+//! assert_eq!(display_width("テスト"), 6);
+//! assert_eq!(display_width("±"), 2);
+//! assert_eq!(AmbiguousPolicy::Narrow.display_width("±"), 1);
+//! assert_eq!(pad_to_width("テスト", 8, Align::Right), "  テスト");
+//! ```
+
+use crate::data::width;
+
+/// How to count characters of ambiguous East Asian Width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AmbiguousPolicy {
+    /// Two columns, as in JIS fonts and CJK terminal settings.
+    #[default]
+    Wide,
+    /// One column, as in most Western terminal settings.
+    Narrow,
+}
+
+/// Where [`pad_to_width`] puts the text within the padded field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Text first, padding after.
+    Left,
+    /// Padding first, text after.
+    Right,
+    /// Padding split around the text; an odd column goes after it.
+    Center,
+}
+
+fn in_ranges(table: &[(u32, u32)], cp: u32) -> bool {
+    table
+        .binary_search_by(|&(lo, hi)| {
+            if hi < cp {
+                std::cmp::Ordering::Less
+            } else if lo > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+impl AmbiguousPolicy {
+    /// Returns the number of columns `c` occupies under this policy.
+    pub fn char_width(self, c: char) -> usize {
+        let cp = c as u32;
+        if in_ranges(width::ZERO_WIDTH, cp) {
+            0
+        } else if in_ranges(width::WIDE, cp) {
+            2
+        } else if in_ranges(width::AMBIGUOUS, cp) {
+            match self {
+                AmbiguousPolicy::Wide => 2,
+                AmbiguousPolicy::Narrow => 1,
+            }
+        } else {
+            1
+        }
+    }
+
+    /// Returns the number of columns `s` occupies under this policy.
+    pub fn display_width(self, s: &str) -> usize {
+        s.chars().map(|c| self.char_width(c)).sum()
+    }
+
+    /// Pads `s` with ASCII spaces to `cols` columns under this policy.
+    ///
+    /// Strings already at least `cols` wide are returned unchanged; nothing
+    /// is truncated.
+    pub fn pad_to_width(self, s: &str, cols: usize, align: Align) -> String {
+        let padding = cols.saturating_sub(self.display_width(s));
+        let (before, after) = match align {
+            Align::Left => (0, padding),
+            Align::Right => (padding, 0),
+            Align::Center => (padding / 2, padding - padding / 2),
+        };
+        let mut out = String::with_capacity(s.len() + padding);
+        out.push_str(&" ".repeat(before));
+        out.push_str(s);
+        out.push_str(&" ".repeat(after));
+        out
+    }
+}
+
+/// Returns the number of columns `s` occupies, counting ambiguous-width
+/// characters as wide.
+///
+/// See [`AmbiguousPolicy::display_width`] for the narrow treatment.
+pub fn display_width(s: &str) -> usize {
+    AmbiguousPolicy::Wide.display_width(s)
+}
+
+/// Pads `s` with ASCII spaces to `cols` columns, counting ambiguous-width
+/// characters as wide.
+///
+/// Strings already at least `cols` wide are returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use japanese_codepoints::width::{pad_to_width, Align};
+///
+/// assert_eq!(pad_to_width("ｶﾅ", 4, Align::Left), "ｶﾅ  ");
+/// assert_eq!(pad_to_width("カナ", 7, Align::Center), " カナ  ");
+/// assert_eq!(pad_to_width("カナ", 3, Align::Left), "カナ");
+/// ```
+pub fn pad_to_width(s: &str, cols: usize, align: Align) -> String {
+    AmbiguousPolicy::Wide.pad_to_width(s, cols, align)
+}
+
+// ── tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kana() {
+        assert_eq!(display_width("ﾃｽﾄ"), 3);
+        assert_eq!(display_width("ﾃﾞｽﾄ"), 4); // halfwidth voiced mark is a column
+        assert_eq!(display_width("テスト"), 6);
+        assert_eq!(display_width("漢字abc"), 7);
+        assert_eq!(display_width("\u{3000}"), 2);
+        assert_eq!(display_width("𠮷"), 2);
+        assert_eq!(display_width(""), 0);
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        for s in ["±", "×", "Α", "ω", "Ж", "─", "○", "§", "−", "¢"] {
+            assert_eq!(AmbiguousPolicy::Wide.display_width(s), 2, "{}", s);
+            assert_eq!(AmbiguousPolicy::Narrow.display_width(s), 1, "{}", s);
+        }
+        assert_eq!(display_width("±"), 2);
+        assert_eq!(AmbiguousPolicy::default(), AmbiguousPolicy::Wide);
+        // fullwidth forms are never ambiguous
+        assert_eq!(AmbiguousPolicy::Narrow.display_width("＋Ａ"), 4);
+    }
+
+    #[test]
+    fn test_zero_width() {
+        assert_eq!(display_width("か\u{3099}"), 2);
+        assert_eq!(display_width("e\u{0301}"), 1);
+        assert_eq!(display_width("a\u{200B}b\u{FE0F}"), 2);
+        assert_eq!(display_width("\t\n"), 0);
+    }
+
+    #[test]
+    fn test_pad_to_width() {
+        assert_eq!(pad_to_width("ﾃｽﾄ", 6, Align::Left), "ﾃｽﾄ   ");
+        assert_eq!(pad_to_width("テスト", 7, Align::Right), " テスト");
+        assert_eq!(pad_to_width("±", 3, Align::Left), "± ");
+        assert_eq!(
+            AmbiguousPolicy::Narrow.pad_to_width("±", 3, Align::Left),
+            "±  "
+        );
+        assert_eq!(pad_to_width("テスト", 5, Align::Center), "テスト");
+    }
+
+    #[test]
+    fn test_tables_sorted() {
+        for table in [width::ZERO_WIDTH, width::WIDE, width::AMBIGUOUS] {
+            assert!(table.iter().all(|&(lo, hi)| lo <= hi));
+            assert!(table.windows(2).all(|w| w[0].1 < w[1].0));
+        }
+    }
+
+    #[cfg(feature = "codepoints-jisx0208")]
+    #[test]
+    fn test_jisx0208_is_wide() {
+        // every JIS X 0208 non-kanji character is two columns under the East
+        // Asian treatment, except the ASCII backslash the table maps ＼ to
+        for c in crate::jisx0208::JisX0208::chars() {
+            if !c.is_ascii() {
+                assert_eq!(AmbiguousPolicy::Wide.char_width(*c), 2, "{}", c);
+            }
+        }
+    }
+
+    #[cfg(feature = "codepoints-jisx0201")]
+    #[test]
+    fn test_jisx0201_katakana_is_narrow() {
+        for c in crate::jisx0201::Katakana::chars() {
+            assert_eq!(AmbiguousPolicy::Wide.char_width(*c), 1, "{}", c);
+        }
+    }
+}